@@ -87,6 +87,14 @@ pub mod legacy_core {
         pub use codex_core::connectors::*;
     }
 
+    pub mod exec_env {
+        pub use codex_core::exec_env::*;
+    }
+
+    pub mod file_watcher {
+        pub use codex_core::file_watcher::*;
+    }
+
     pub mod otel_init {
         pub use codex_core::otel_init::*;
     }
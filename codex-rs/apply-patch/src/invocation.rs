@@ -171,8 +171,10 @@ pub async fn maybe_parse_apply_patch_verified(
                         );
                     }
                     Hunk::DeleteFile { .. } => {
-                        let content = match fs.read_file_text(&path, sandbox).await {
-                            Ok(content) => content,
+                        // Read raw bytes so binary files can be deleted; the
+                        // content is only used to render the deletion diff.
+                        let content = match fs.read_file(&path, sandbox).await {
+                            Ok(bytes) => text_or_binary_placeholder(bytes),
                             Err(e) => {
                                 return MaybeApplyPatchVerified::CorrectnessError(
                                     ApplyPatchError::IoError(IoError {
@@ -190,15 +192,36 @@ pub async fn maybe_parse_apply_patch_verified(
                     Hunk::UpdateFile {
                         move_path, chunks, ..
                     } => {
+                        let update = if chunks.is_empty() {
+                            // Pure rename or mode change: the contents are
+                            // untouched, so read raw bytes instead of failing
+                            // on files that are not valid UTF-8.
+                            match fs.read_file(&path, sandbox).await {
+                                Ok(bytes) => ApplyPatchFileUpdate {
+                                    unified_diff: String::new(),
+                                    content: text_or_binary_placeholder(bytes),
+                                },
+                                Err(e) => {
+                                    return MaybeApplyPatchVerified::CorrectnessError(
+                                        ApplyPatchError::IoError(IoError {
+                                            context: format!("Failed to read {}", path.display()),
+                                            source: e,
+                                        }),
+                                    );
+                                }
+                            }
+                        } else {
+                            match unified_diff_from_chunks(&path, &chunks, fs, sandbox).await {
+                                Ok(diff) => diff,
+                                Err(e) => {
+                                    return MaybeApplyPatchVerified::CorrectnessError(e);
+                                }
+                            }
+                        };
                         let ApplyPatchFileUpdate {
                             unified_diff,
                             content: contents,
-                        } = match unified_diff_from_chunks(&path, &chunks, fs, sandbox).await {
-                            Ok(diff) => diff,
-                            Err(e) => {
-                                return MaybeApplyPatchVerified::CorrectnessError(e);
-                            }
-                        };
+                        } = update;
                         changes.insert(
                             path.into_path_buf(),
                             ApplyPatchFileChange::Update {
@@ -222,6 +245,13 @@ pub async fn maybe_parse_apply_patch_verified(
     }
 }
 
+/// Decodes file contents for diff rendering, substituting a placeholder for
+/// files that are not valid UTF-8 (binary files cannot be rendered in a diff).
+fn text_or_binary_placeholder(bytes: Vec<u8>) -> String {
+    let len = bytes.len();
+    String::from_utf8(bytes).unwrap_or_else(|_| format!("(binary file: {len} bytes)\n"))
+}
+
 /// Extract the heredoc body (and optional `cd` workdir) from a `bash -lc` script
 /// that invokes the apply_patch tool using a heredoc.
 ///
@@ -308,48 +308,76 @@ async fn apply_hunks_to_files(
                 deleted.push(affected_path);
             }
             Hunk::UpdateFile {
-                move_path, chunks, ..
+                move_path,
+                new_mode,
+                chunks,
+                ..
             } => {
-                let AppliedPatch { new_contents, .. } =
-                    derive_new_contents_from_chunks(&path_abs, chunks, fs, sandbox).await?;
-                if let Some(dest) = move_path {
-                    let dest_abs = AbsolutePathBuf::resolve_path_against_base(dest, cwd);
-                    write_file_with_missing_parent_retry(
-                        fs,
-                        &dest_abs,
-                        new_contents.into_bytes(),
-                        sandbox,
-                    )
-                    .await?;
-                    let result: io::Result<()> = async {
-                        let metadata = fs.get_metadata(&path_abs, sandbox).await?;
-                        if metadata.is_directory {
-                            return Err(io::Error::new(
-                                io::ErrorKind::InvalidInput,
-                                "path is a directory",
-                            ));
-                        }
-                        fs.remove(
-                            &path_abs,
-                            RemoveOptions {
-                                recursive: false,
-                                force: false,
-                            },
-                            sandbox,
-                        )
-                        .await
+                // A chunk-less hunk is a pure rename and/or mode change:
+                // carry the contents over byte-for-byte so binary files
+                // survive, rather than round-tripping them through UTF-8.
+                let new_contents = if chunks.is_empty() {
+                    if move_path.is_some() {
+                        Some(fs.read_file(&path_abs, sandbox).await.with_context(|| {
+                            format!("Failed to read file to move {}", path_abs.display())
+                        })?)
+                    } else {
+                        None
                     }
-                    .await;
-                    result.with_context(|| {
-                        format!("Failed to remove original {}", path_abs.display())
-                    })?;
-                    modified.push(affected_path);
                 } else {
-                    fs.write_file(&path_abs, new_contents.into_bytes(), sandbox)
+                    let AppliedPatch { new_contents, .. } =
+                        derive_new_contents_from_chunks(&path_abs, chunks, fs, sandbox).await?;
+                    Some(new_contents.into_bytes())
+                };
+                let dest_abs = move_path
+                    .as_ref()
+                    .map(|dest| AbsolutePathBuf::resolve_path_against_base(dest, cwd));
+                match (&dest_abs, new_contents) {
+                    (Some(dest_abs), Some(new_contents)) => {
+                        write_file_with_missing_parent_retry(fs, dest_abs, new_contents, sandbox)
+                            .await?;
+                        let result: io::Result<()> = async {
+                            let metadata = fs.get_metadata(&path_abs, sandbox).await?;
+                            if metadata.is_directory {
+                                return Err(io::Error::new(
+                                    io::ErrorKind::InvalidInput,
+                                    "path is a directory",
+                                ));
+                            }
+                            fs.remove(
+                                &path_abs,
+                                RemoveOptions {
+                                    recursive: false,
+                                    force: false,
+                                },
+                                sandbox,
+                            )
+                            .await
+                        }
+                        .await;
+                        result.with_context(|| {
+                            format!("Failed to remove original {}", path_abs.display())
+                        })?;
+                    }
+                    (None, Some(new_contents)) => {
+                        fs.write_file(&path_abs, new_contents, sandbox)
+                            .await
+                            .with_context(|| {
+                                format!("Failed to write file {}", path_abs.display())
+                            })?;
+                    }
+                    // Mode-only update: the contents are untouched.
+                    (_, None) => {}
+                }
+                if let Some(mode) = new_mode {
+                    let target = dest_abs.as_ref().unwrap_or(&path_abs);
+                    fs.set_file_mode(target, *mode, sandbox)
                         .await
-                        .with_context(|| format!("Failed to write file {}", path_abs.display()))?;
-                    modified.push(affected_path);
+                        .with_context(|| {
+                            format!("Failed to change mode of {}", target.display())
+                        })?;
                 }
+                modified.push(affected_path);
             }
         }
     }
@@ -840,6 +868,67 @@ mod tests {
         assert_eq!(contents, "line2\n");
     }
 
+    /// A chunk-less rename must not round-trip the contents through UTF-8, so
+    /// binary files survive byte-for-byte.
+    #[tokio::test]
+    async fn test_pure_rename_hunk_preserves_binary_contents() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("logo.png");
+        let dest = dir.path().join("old-logo.png");
+        let bytes: Vec<u8> = vec![0x89, 0x50, 0x4e, 0x47, 0x00, 0xff, 0xfe, 0x0a];
+        fs::write(&src, &bytes).unwrap();
+        let patch = wrap_patch(&format!(
+            "*** Update File: {}\n*** Move to: {}",
+            src.display(),
+            dest.display()
+        ));
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        apply_patch(
+            &patch,
+            &AbsolutePathBuf::from_absolute_path(dir.path()).unwrap(),
+            &mut stdout,
+            &mut stderr,
+            LOCAL_FS.as_ref(),
+            /*sandbox*/ None,
+        )
+        .await
+        .unwrap();
+        assert!(!src.exists());
+        assert_eq!(fs::read(&dest).unwrap(), bytes);
+        assert_eq!(String::from_utf8(stderr).unwrap(), "");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_change_mode_hunk_sets_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("run.sh");
+        fs::write(&path, "echo hi\n").unwrap();
+        let patch = wrap_patch(&format!(
+            "*** Update File: {}\n*** Change Mode: 755",
+            path.display()
+        ));
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        apply_patch(
+            &patch,
+            &AbsolutePathBuf::from_absolute_path(dir.path()).unwrap(),
+            &mut stdout,
+            &mut stderr,
+            LOCAL_FS.as_ref(),
+            /*sandbox*/ None,
+        )
+        .await
+        .unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+        // The contents are untouched by a mode-only update.
+        assert_eq!(fs::read_to_string(&path).unwrap(), "echo hi\n");
+    }
+
     /// Verify that a single `Update File` hunk with multiple change chunks can update different
     /// parts of a file and that the file is listed only once in the summary.
     #[tokio::test]
@@ -10,11 +10,12 @@
 //! hunk: add_hunk | delete_hunk | update_hunk
 //! add_hunk: "*** Add File: " filename LF add_line+
 //! delete_hunk: "*** Delete File: " filename LF
-//! update_hunk: "*** Update File: " filename LF change_move? change?
+//! update_hunk: "*** Update File: " filename LF change_move? change_mode? change?
 //! filename: /(.+)/
 //! add_line: "+" /(.+)/ LF -> line
 //!
 //! change_move: "*** Move to: " filename LF
+//! change_mode: "*** Change Mode: " mode LF
 //! change: (change_context | change_line)+ eof_line?
 //! change_context: ("@@" | "@@ " /(.+)/) LF
 //! change_line: ("+" | "-" | " ") /(.+)/ LF
@@ -37,6 +38,7 @@ const ADD_FILE_MARKER: &str = "*** Add File: ";
 const DELETE_FILE_MARKER: &str = "*** Delete File: ";
 const UPDATE_FILE_MARKER: &str = "*** Update File: ";
 const MOVE_TO_MARKER: &str = "*** Move to: ";
+const CHANGE_MODE_MARKER: &str = "*** Change Mode: ";
 const EOF_MARKER: &str = "*** End of File";
 const CHANGE_CONTEXT_MARKER: &str = "@@ ";
 const EMPTY_CHANGE_CONTEXT_MARKER: &str = "@@";
@@ -72,8 +74,16 @@ pub enum Hunk {
         path: PathBuf,
         move_path: Option<PathBuf>,
 
+        /// Unix permission bits to set on the file, parsed from an octal
+        /// `*** Change Mode: ` line (e.g. `755`).
+        new_mode: Option<u32>,
+
         /// Chunks should be in order, i.e. the `change_context` of one chunk
         /// should occur later in the file than the previous chunk.
+        ///
+        /// May be empty for a pure rename or mode change, in which case the
+        /// file contents are carried over byte-for-byte (so binary files are
+        /// safe to rename).
         chunks: Vec<UpdateFileChunk>,
     },
 }
@@ -347,6 +357,28 @@ fn parse_one_hunk(
             parsed_lines += 1;
         }
 
+        // Optional: change mode line
+        let mut new_mode = None;
+        if let Some(mode_str) = remaining_lines
+            .first()
+            .and_then(|x| x.strip_prefix(CHANGE_MODE_MARKER))
+        {
+            let mode = u32::from_str_radix(mode_str.trim(), 8).ok();
+            match mode {
+                Some(mode) if mode <= 0o7777 => {
+                    new_mode = Some(mode);
+                }
+                _ => {
+                    return Err(InvalidHunkError {
+                        message: format!("'{mode_str}' is not a valid octal file mode (e.g. 755)"),
+                        line_number: line_number + parsed_lines,
+                    });
+                }
+            }
+            remaining_lines = &remaining_lines[1..];
+            parsed_lines += 1;
+        }
+
         let mut chunks = Vec::new();
         // NOTE: we need to know to stop once we reach the next special marker header.
         while !remaining_lines.is_empty() {
@@ -382,7 +414,8 @@ fn parse_one_hunk(
             remaining_lines = &remaining_lines[chunk_lines..]
         }
 
-        if chunks.is_empty() {
+        // A rename or mode change needs no content chunks; anything else does.
+        if chunks.is_empty() && move_path.is_none() && new_mode.is_none() {
             return Err(InvalidHunkError {
                 message: format!("Update file hunk for path '{path}' is empty"),
                 line_number,
@@ -393,6 +426,7 @@ fn parse_one_hunk(
             UpdateFile {
                 path: PathBuf::from(path),
                 move_path: move_path.map(PathBuf::from),
+                new_mode,
                 chunks,
             },
             parsed_lines,
@@ -522,6 +556,7 @@ fn test_parse_patch_streaming() {
             hunks: vec![UpdateFile {
                 path: PathBuf::from("src/old.rs"),
                 move_path: Some(PathBuf::from("src/new.rs")),
+                new_mode: None,
                 chunks: vec![UpdateFileChunk {
                     change_context: None,
                     old_lines: vec!["old".to_string()],
@@ -743,6 +778,7 @@ fn test_parse_patch() {
             UpdateFile {
                 path: PathBuf::from("path/update.py"),
                 move_path: Some(PathBuf::from("path/update2.py")),
+                new_mode: None,
                 chunks: vec![UpdateFileChunk {
                     change_context: Some("def f():".to_string()),
                     old_lines: vec!["    pass".to_string()],
@@ -770,6 +806,7 @@ fn test_parse_patch() {
             UpdateFile {
                 path: PathBuf::from("file.py"),
                 move_path: None,
+                new_mode: None,
                 chunks: vec![UpdateFileChunk {
                     change_context: None,
                     old_lines: vec![],
@@ -800,6 +837,7 @@ fn test_parse_patch() {
         vec![UpdateFile {
             path: PathBuf::from("file2.py"),
             move_path: None,
+            new_mode: None,
             chunks: vec![UpdateFileChunk {
                 change_context: None,
                 old_lines: vec!["import foo".to_string()],
@@ -810,6 +848,80 @@ fn test_parse_patch() {
     );
 }
 
+#[test]
+fn test_parse_patch_mode_change_and_chunkless_hunks() {
+    // A rename or mode change needs no content chunks.
+    assert_eq!(
+        parse_patch_text(
+            "*** Begin Patch\n\
+             *** Update File: tools/run.sh\n\
+             *** Change Mode: 755\n\
+             *** Update File: assets/logo.png\n\
+             *** Move to: assets/old-logo.png\n\
+             *** End Patch",
+            ParseMode::Strict
+        )
+        .unwrap()
+        .hunks,
+        vec![
+            UpdateFile {
+                path: PathBuf::from("tools/run.sh"),
+                move_path: None,
+                new_mode: Some(0o755),
+                chunks: vec![],
+            },
+            UpdateFile {
+                path: PathBuf::from("assets/logo.png"),
+                move_path: Some(PathBuf::from("assets/old-logo.png")),
+                new_mode: None,
+                chunks: vec![],
+            },
+        ]
+    );
+
+    // Mode changes combine with renames and content chunks.
+    assert_eq!(
+        parse_patch_text(
+            "*** Begin Patch\n\
+             *** Update File: run\n\
+             *** Move to: run.sh\n\
+             *** Change Mode: 644\n\
+             @@\n\
+             -old\n\
+             +new\n\
+             *** End Patch",
+            ParseMode::Strict
+        )
+        .unwrap()
+        .hunks,
+        vec![UpdateFile {
+            path: PathBuf::from("run"),
+            move_path: Some(PathBuf::from("run.sh")),
+            new_mode: Some(0o644),
+            chunks: vec![UpdateFileChunk {
+                change_context: None,
+                old_lines: vec!["old".to_string()],
+                new_lines: vec!["new".to_string()],
+                is_end_of_file: false,
+            }],
+        }]
+    );
+
+    assert_eq!(
+        parse_patch_text(
+            "*** Begin Patch\n\
+             *** Update File: run.sh\n\
+             *** Change Mode: 9999\n\
+             *** End Patch",
+            ParseMode::Strict
+        ),
+        Err(InvalidHunkError {
+            message: "'9999' is not a valid octal file mode (e.g. 755)".to_string(),
+            line_number: 3,
+        })
+    );
+}
+
 #[test]
 fn test_parse_patch_accepts_relative_and_absolute_hunk_paths() {
     let dir = tempfile::tempdir().unwrap();
@@ -844,6 +956,7 @@ fn test_parse_patch_accepts_relative_and_absolute_hunk_paths() {
             UpdateFile {
                 path: absolute_update.to_path_buf(),
                 move_path: None,
+                new_mode: None,
                 chunks: vec![UpdateFileChunk {
                     change_context: None,
                     old_lines: vec!["old".to_string()],
@@ -882,6 +995,7 @@ fn test_hunk_resolve_path_accepts_relative_and_absolute_paths() {
             UpdateFile {
                 path: PathBuf::from("relative-update.py"),
                 move_path: None,
+                new_mode: None,
                 chunks: Vec::new(),
             },
             cwd.join("relative-update.py"),
@@ -903,6 +1017,7 @@ fn test_hunk_resolve_path_accepts_relative_and_absolute_paths() {
             UpdateFile {
                 path: absolute_update.to_path_buf(),
                 move_path: None,
+                new_mode: None,
                 chunks: Vec::new(),
             },
             absolute_update,
@@ -922,6 +1037,7 @@ fn test_parse_patch_lenient() {
     let expected_patch = vec![UpdateFile {
         path: PathBuf::from("file2.py"),
         move_path: None,
+        new_mode: None,
         chunks: vec![UpdateFileChunk {
             change_context: None,
             old_lines: vec!["import foo".to_string()],
@@ -0,0 +1,391 @@
+//! `codex diff-sessions`: compare two recorded sessions side by side.
+//!
+//! After forking a session to try two approaches, this renders each session's
+//! final file changes and final assistant answer in a two-column terminal
+//! view so the branches can be compared without replaying either one. File
+//! changes are reconstructed from the `apply_patch` calls recorded in the
+//! rollout and collapsed to a net per-file status (added, modified, deleted);
+//! paths touched by only one of the two sessions are marked with `*`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_apply_patch::Hunk;
+use codex_core::config::Config;
+use codex_core::find_thread_path_by_id_str;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use serde_json::Value;
+
+/// Below this the two columns degrade into unreadable slivers.
+const MIN_WIDTH: usize = 60;
+const SEPARATOR: &str = " │ ";
+
+pub async fn run_diff_sessions(
+    config: Config,
+    session_a: String,
+    session_b: String,
+    width: usize,
+) -> Result<()> {
+    let a = load_summary(&config, &session_a).await?;
+    let b = load_summary(&config, &session_b).await?;
+    print!("{}", render_comparison(&a, &b, width.max(MIN_WIDTH)));
+    Ok(())
+}
+
+/// Net effect of a session on one file, after merging every patch that
+/// touched it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ChangeKind {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl ChangeKind {
+    fn marker(self) -> char {
+        match self {
+            ChangeKind::Added => '+',
+            ChangeKind::Modified => '~',
+            ChangeKind::Deleted => '-',
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SessionSummary {
+    label: String,
+    changes: BTreeMap<PathBuf, ChangeKind>,
+    final_answer: Option<String>,
+}
+
+async fn load_summary(config: &Config, session: &str) -> Result<SessionSummary> {
+    let path = resolve_session_path(config, session).await?;
+    let raw =
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    Ok(summarize_rollout(session.to_string(), &raw))
+}
+
+/// A session argument is either a rollout file path or a thread id to look up
+/// under `sessions/`.
+async fn resolve_session_path(config: &Config, session: &str) -> Result<PathBuf> {
+    let as_path = Path::new(session);
+    if as_path.is_file() {
+        return Ok(as_path.to_path_buf());
+    }
+    match find_thread_path_by_id_str(config.codex_home.as_path(), session).await? {
+        Some(path) => Ok(path),
+        None => bail!("no session found for `{session}`"),
+    }
+}
+
+/// Walks the rollout keeping the last non-empty assistant message and the
+/// merged file changes from every recorded `apply_patch` call. Unparseable
+/// lines and patches are skipped so a partially written rollout still
+/// summarizes.
+fn summarize_rollout(label: String, raw: &str) -> SessionSummary {
+    let mut changes = BTreeMap::new();
+    let mut final_answer = None;
+    for line in raw.lines() {
+        let Ok(rollout_line) = serde_json::from_str::<RolloutLine>(line) else {
+            continue;
+        };
+        let RolloutItem::ResponseItem(item) = rollout_line.item else {
+            continue;
+        };
+        match item {
+            ResponseItem::Message { role, content, .. } if role == "assistant" => {
+                let text = content
+                    .iter()
+                    .filter_map(|item| match item {
+                        ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                            Some(text.as_str())
+                        }
+                        ContentItem::InputImage { .. } => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                if !text.trim().is_empty() {
+                    final_answer = Some(text);
+                }
+            }
+            ResponseItem::FunctionCall {
+                name, arguments, ..
+            } => {
+                if let Some(patch) = patch_from_function_call(&name, &arguments) {
+                    record_patch(&mut changes, &patch);
+                }
+            }
+            ResponseItem::CustomToolCall { name, input, .. } if name == "apply_patch" => {
+                record_patch(&mut changes, &input);
+            }
+            _ => {}
+        }
+    }
+    SessionSummary {
+        label,
+        changes,
+        final_answer,
+    }
+}
+
+/// Pulls the patch text out of a function call: either the freeform
+/// `apply_patch` tool (`{"input": …}`) or a shell-style call whose command is
+/// `["apply_patch", <patch>]`.
+fn patch_from_function_call(name: &str, arguments: &str) -> Option<String> {
+    let args: Value = serde_json::from_str(arguments).ok()?;
+    if name == "apply_patch" {
+        return Some(args.get("input")?.as_str()?.to_string());
+    }
+    let command = args.get("command")?.as_array()?;
+    match command.as_slice() {
+        [first, patch] if first.as_str() == Some("apply_patch") => {
+            Some(patch.as_str()?.to_string())
+        }
+        _ => None,
+    }
+}
+
+fn record_patch(changes: &mut BTreeMap<PathBuf, ChangeKind>, patch: &str) {
+    let Ok(parsed) = codex_apply_patch::parse_patch(patch) else {
+        return;
+    };
+    for hunk in parsed.hunks {
+        match hunk {
+            Hunk::AddFile { path, .. } => merge_change(changes, path, ChangeKind::Added),
+            Hunk::DeleteFile { path } => merge_change(changes, path, ChangeKind::Deleted),
+            Hunk::UpdateFile {
+                path, move_path, ..
+            } => merge_change(changes, move_path.unwrap_or(path), ChangeKind::Modified),
+        }
+    }
+}
+
+/// Collapses successive changes to one file into its net status: an added
+/// file stays "added" through later edits and disappears entirely if deleted;
+/// a deleted file that comes back counts as modified.
+fn merge_change(changes: &mut BTreeMap<PathBuf, ChangeKind>, path: PathBuf, kind: ChangeKind) {
+    let merged = match (changes.get(&path), kind) {
+        (Some(ChangeKind::Added), ChangeKind::Deleted) => {
+            changes.remove(&path);
+            return;
+        }
+        (Some(ChangeKind::Added), _) => ChangeKind::Added,
+        (Some(ChangeKind::Deleted), ChangeKind::Added) => ChangeKind::Modified,
+        _ => kind,
+    };
+    changes.insert(path, merged);
+}
+
+fn render_comparison(a: &SessionSummary, b: &SessionSummary, width: usize) -> String {
+    let col = (width - SEPARATOR.chars().count()) / 2;
+    let left = column_lines(a, b, col);
+    let right = column_lines(b, a, col);
+    let mut out = String::new();
+    for i in 0..left.len().max(right.len()) {
+        let l = left.get(i).map(String::as_str).unwrap_or_default();
+        let r = right.get(i).map(String::as_str).unwrap_or_default();
+        let line = format!("{l:<col$}{SEPARATOR}{r}");
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// Renders one session as a column: label, net file changes (files the other
+/// session did not touch are starred), then the final answer.
+fn column_lines(summary: &SessionSummary, other: &SessionSummary, col: usize) -> Vec<String> {
+    let mut lines = vec![summary.label.clone(), String::new()];
+    if summary.changes.is_empty() {
+        lines.push("Files changed: (none)".to_string());
+    } else {
+        lines.push("Files changed:".to_string());
+        for (path, kind) in &summary.changes {
+            let star = if other.changes.contains_key(path) {
+                ""
+            } else {
+                " *"
+            };
+            let entry = format!("  {} {}{star}", kind.marker(), path.display());
+            lines.extend(wrap(&entry, col));
+        }
+    }
+    lines.push(String::new());
+    lines.push("Final answer:".to_string());
+    match &summary.final_answer {
+        Some(answer) => {
+            for raw_line in answer.lines() {
+                lines.extend(wrap(&format!("  {raw_line}"), col));
+            }
+        }
+        None => lines.push("  (none)".to_string()),
+    }
+    for line in &mut lines {
+        truncate_to_width(line, col);
+    }
+    lines
+}
+
+/// Greedy word wrap that keeps the line's leading indentation on every
+/// continuation line; words longer than the column are hard-split so the
+/// column alignment survives pathological paths and URLs.
+fn wrap(text: &str, col: usize) -> Vec<String> {
+    let indent: String = text.chars().take_while(|c| c.is_whitespace()).collect();
+    let avail = col.saturating_sub(indent.chars().count()).max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > avail {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            lines.push(word.drain(..avail).collect());
+        }
+        if word.is_empty() {
+            continue;
+        }
+        let fits = current.is_empty() || current.chars().count() + 1 + word.len() <= avail;
+        if !fits {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.extend(word);
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+        .into_iter()
+        .map(|line| format!("{indent}{line}"))
+        .collect()
+}
+
+fn truncate_to_width(line: &mut String, col: usize) {
+    if line.chars().count() > col {
+        *line = line.chars().take(col.saturating_sub(1)).collect();
+        line.push('…');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn rollout_line(item: ResponseItem) -> String {
+        serde_json::to_string(&RolloutLine {
+            timestamp: "2026-01-01T00:00:00.000Z".to_string(),
+            item: RolloutItem::ResponseItem(item),
+        })
+        .expect("serialize rollout line")
+    }
+
+    fn assistant_message(text: &str) -> ResponseItem {
+        ResponseItem::Message {
+            id: None,
+            role: "assistant".to_string(),
+            content: vec![ContentItem::OutputText {
+                text: text.to_string(),
+            }],
+            end_turn: None,
+            phase: None,
+        }
+    }
+
+    #[test]
+    fn summarize_merges_patches_and_keeps_last_answer() {
+        let add_and_update = "*** Begin Patch\n\
+            *** Add File: scratch.txt\n\
+            +temp\n\
+            *** Update File: src/lib.rs\n\
+            @@\n\
+            -old\n\
+            +new\n\
+            *** End Patch";
+        let delete_scratch = "*** Begin Patch\n\
+            *** Delete File: scratch.txt\n\
+            *** End Patch";
+        let add_doc = "*** Begin Patch\n\
+            *** Add File: docs/a.md\n\
+            +hi\n\
+            *** End Patch";
+        let raw = [
+            rollout_line(assistant_message("first answer")),
+            rollout_line(ResponseItem::FunctionCall {
+                id: None,
+                name: "apply_patch".to_string(),
+                namespace: None,
+                arguments: serde_json::to_string(&serde_json::json!({ "input": add_and_update }))
+                    .expect("serialize arguments"),
+                call_id: "call-1".to_string(),
+            }),
+            rollout_line(ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "call-2".to_string(),
+                name: "apply_patch".to_string(),
+                input: delete_scratch.to_string(),
+            }),
+            rollout_line(ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                namespace: None,
+                arguments: serde_json::to_string(
+                    &serde_json::json!({ "command": ["apply_patch", add_doc] }),
+                )
+                .expect("serialize arguments"),
+                call_id: "call-3".to_string(),
+            }),
+            rollout_line(assistant_message("final answer")),
+        ]
+        .join("\n");
+
+        let summary = summarize_rollout("a".to_string(), &raw);
+        assert_eq!(summary.final_answer.as_deref(), Some("final answer"));
+        assert_eq!(
+            summary.changes,
+            BTreeMap::from([
+                (PathBuf::from("docs/a.md"), ChangeKind::Added),
+                (PathBuf::from("src/lib.rs"), ChangeKind::Modified),
+            ])
+        );
+    }
+
+    #[test]
+    fn render_stars_files_unique_to_one_session() {
+        let a = SessionSummary {
+            label: "session-a".to_string(),
+            changes: BTreeMap::from([
+                (PathBuf::from("shared.rs"), ChangeKind::Modified),
+                (PathBuf::from("only-a.rs"), ChangeKind::Added),
+            ]),
+            final_answer: Some("kept the cache".to_string()),
+        };
+        let b = SessionSummary {
+            label: "session-b".to_string(),
+            changes: BTreeMap::from([(PathBuf::from("shared.rs"), ChangeKind::Modified)]),
+            final_answer: None,
+        };
+
+        let rendered = render_comparison(&a, &b, 60);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], format!("{:<28} │ session-b", "session-a"));
+        assert!(rendered.contains("  + only-a.rs *"));
+        assert!(rendered.contains("  ~ shared.rs  "));
+        assert!(rendered.contains("  (none)"));
+        // Every row keeps the separator in the same column.
+        for line in &lines {
+            assert_eq!(line.chars().nth(29), Some('│'));
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! `codex doctor`: first-run diagnostics.
+//!
+//! Runs a series of environment checks — terminal capabilities, network
+//! reachability to the configured provider, auth validity, git availability,
+//! sandbox backend availability, and MCP server commands — and prints a
+//! pass/fail table with remediation hints for anything that looks off.
+
+use std::net::ToSocketAddrs;
+use std::time::Duration;
+
+use codex_config::mcp_types::McpServerTransportConfig;
+use codex_core::config::Config;
+use codex_models_manager::AuthManager;
+use owo_colors::OwoColorize;
+use supports_color::Stream;
+
+/// Outcome of one diagnostic check.
+enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+struct CheckResult {
+    name: &'static str,
+    status: CheckStatus,
+    detail: String,
+    hint: Option<String>,
+}
+
+impl CheckResult {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// Runs every diagnostic and prints the results. Returns an error (and a
+/// non-zero exit) when any check fails outright.
+pub(crate) async fn run_doctor(config: Config) -> anyhow::Result<()> {
+    let mut checks = vec![
+        truecolor_check(),
+        keyboard_protocol_check(),
+        osc52_check(),
+        git_check(),
+        sandbox_check(),
+    ];
+    checks.push(network_check(&config).await);
+    checks.push(auth_check(&config).await);
+    checks.extend(mcp_checks(&config));
+
+    let color_enabled = supports_color::on(Stream::Stdout).is_some();
+    let name_width = checks
+        .iter()
+        .map(|check| check.name.len())
+        .max()
+        .unwrap_or(0);
+    let mut failures = 0;
+    for check in &checks {
+        let label = match check.status {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Fail => {
+                failures += 1;
+                "FAIL"
+            }
+        };
+        let label = if color_enabled {
+            match check.status {
+                CheckStatus::Pass => label.green().to_string(),
+                CheckStatus::Warn => label.yellow().to_string(),
+                CheckStatus::Fail => label.red().to_string(),
+            }
+        } else {
+            label.to_string()
+        };
+        println!(
+            "{label}  {name:<name_width$}  {detail}",
+            name = check.name,
+            detail = check.detail
+        );
+        if let Some(hint) = &check.hint {
+            println!("      {empty:<name_width$}  ↳ {hint}", empty = "");
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{failures} check(s) failed");
+    }
+    Ok(())
+}
+
+fn truecolor_check() -> CheckResult {
+    let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+    if colorterm.contains("truecolor") || colorterm.contains("24bit") {
+        CheckResult::pass("truecolor", "COLORTERM advertises 24-bit color")
+    } else {
+        CheckResult::warn(
+            "truecolor",
+            "COLORTERM does not advertise 24-bit color",
+            "if your terminal supports it, export COLORTERM=truecolor for richer rendering",
+        )
+    }
+}
+
+/// Terminals known to implement the kitty keyboard protocol, which Codex uses
+/// for bindings like Shift+Enter.
+fn keyboard_protocol_check() -> CheckResult {
+    let term = std::env::var("TERM").unwrap_or_default();
+    let term_program = std::env::var("TERM_PROGRAM").unwrap_or_default();
+    let known = term.contains("kitty")
+        || term.contains("ghostty")
+        || term_program == "WezTerm"
+        || term_program == "iTerm.app"
+        || term_program == "ghostty"
+        || std::env::var_os("WT_SESSION").is_some();
+    if known {
+        CheckResult::pass(
+            "kitty keyboard",
+            format!(
+                "terminal ({}) supports enhanced key reporting",
+                terminal_name(&term, &term_program)
+            ),
+        )
+    } else {
+        CheckResult::warn(
+            "kitty keyboard",
+            format!(
+                "could not confirm enhanced key support for {}",
+                terminal_name(&term, &term_program)
+            ),
+            "bindings like Shift+Enter may not work; kitty, WezTerm, Ghostty, iTerm2, and Windows Terminal are known good",
+        )
+    }
+}
+
+fn osc52_check() -> CheckResult {
+    if std::env::var_os("TMUX").is_some() {
+        return CheckResult::warn(
+            "clipboard (OSC 52)",
+            "running inside tmux",
+            "add `set -g set-clipboard on` to tmux.conf so /copy can reach the system clipboard",
+        );
+    }
+    CheckResult::pass(
+        "clipboard (OSC 52)",
+        "no clipboard-blocking multiplexer detected",
+    )
+}
+
+fn terminal_name(term: &str, term_program: &str) -> String {
+    if !term_program.is_empty() {
+        term_program.to_string()
+    } else if !term.is_empty() {
+        term.to_string()
+    } else {
+        "unknown terminal".to_string()
+    }
+}
+
+fn git_check() -> CheckResult {
+    match std::process::Command::new("git").arg("--version").output() {
+        Ok(output) if output.status.success() => CheckResult::pass(
+            "git",
+            String::from_utf8_lossy(&output.stdout).trim().to_string(),
+        ),
+        _ => CheckResult::fail(
+            "git",
+            "`git --version` failed",
+            "install git; Codex uses it for diffs, reviews, and ghost snapshots",
+        ),
+    }
+}
+
+fn sandbox_check() -> CheckResult {
+    #[cfg(target_os = "macos")]
+    {
+        if std::path::Path::new("/usr/bin/sandbox-exec").exists() {
+            CheckResult::pass("sandbox", "Seatbelt (sandbox-exec) available")
+        } else {
+            CheckResult::fail(
+                "sandbox",
+                "/usr/bin/sandbox-exec not found",
+                "Seatbelt is required for sandboxed command execution on macOS",
+            )
+        }
+    }
+    #[cfg(target_os = "linux")]
+    {
+        match std::fs::read_to_string("/sys/kernel/security/lsm") {
+            Ok(lsm) if lsm.contains("landlock") => {
+                CheckResult::pass("sandbox", "Landlock enabled in the kernel")
+            }
+            _ => CheckResult::warn(
+                "sandbox",
+                "could not confirm Landlock support",
+                "kernel 5.13+ with the landlock LSM enabled is required for full sandboxing",
+            ),
+        }
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    {
+        CheckResult::pass("sandbox", "restricted-token sandbox available")
+    }
+}
+
+async fn network_check(config: &Config) -> CheckResult {
+    let base_url = config
+        .model_provider
+        .base_url
+        .clone()
+        .unwrap_or_else(|| "https://chatgpt.com".to_string());
+    let Some(host) = host_from_url(&base_url) else {
+        return CheckResult::warn(
+            "network",
+            format!("could not parse provider URL {base_url}"),
+            "check the `base_url` of the configured model provider",
+        );
+    };
+    let addr = format!("{host}:443");
+    let reachable = tokio::time::timeout(
+        Duration::from_secs(5),
+        tokio::task::spawn_blocking(move || {
+            addr.to_socket_addrs().ok().and_then(|mut addrs| {
+                addrs.next().and_then(|addr| {
+                    std::net::TcpStream::connect_timeout(&addr, Duration::from_secs(5)).ok()
+                })
+            })
+        }),
+    )
+    .await;
+    match reachable {
+        Ok(Ok(Some(_))) => CheckResult::pass("network", format!("{host} is reachable")),
+        _ => CheckResult::fail(
+            "network",
+            format!("could not reach {host}:443"),
+            "check connectivity, proxy settings, and firewall rules for the configured provider",
+        ),
+    }
+}
+
+async fn auth_check(config: &Config) -> CheckResult {
+    let auth_manager =
+        AuthManager::shared_from_config(config, /*enable_codex_api_key_env*/ true);
+    match auth_manager.auth().await {
+        Some(_) => CheckResult::pass("auth", "credentials found"),
+        None => CheckResult::fail(
+            "auth",
+            "no credentials found",
+            "run `codex login` (or set an API key for the configured provider)",
+        ),
+    }
+}
+
+fn mcp_checks(config: &Config) -> Vec<CheckResult> {
+    let mut results = Vec::new();
+    for (name, server) in config.mcp_servers.get() {
+        if !server.enabled {
+            continue;
+        }
+        match &server.transport {
+            McpServerTransportConfig::Stdio { command, .. } => {
+                if command_on_path(command) {
+                    results.push(CheckResult::pass(
+                        "mcp",
+                        format!("server '{name}' command `{command}` found"),
+                    ));
+                } else {
+                    results.push(CheckResult::fail(
+                        "mcp",
+                        format!("server '{name}' command `{command}` not found"),
+                        "fix the command path in config.toml or install the server binary",
+                    ));
+                }
+            }
+            _ => {
+                results.push(CheckResult::pass(
+                    "mcp",
+                    format!("server '{name}' uses an HTTP transport (not probed)"),
+                ));
+            }
+        }
+    }
+    results
+}
+
+/// Returns whether `command` resolves to an executable, either as a path or
+/// via `PATH` lookup.
+fn command_on_path(command: &str) -> bool {
+    let path = std::path::Path::new(command);
+    if path.components().count() > 1 {
+        return path.exists();
+    }
+    let Some(paths) = std::env::var_os("PATH") else {
+        return false;
+    };
+    std::env::split_paths(&paths).any(|dir| {
+        let candidate = dir.join(command);
+        if candidate.exists() {
+            return true;
+        }
+        if cfg!(windows) {
+            ["exe", "cmd", "bat"]
+                .iter()
+                .any(|ext| candidate.with_extension(ext).exists())
+        } else {
+            false
+        }
+    })
+}
+
+fn host_from_url(url: &str) -> Option<String> {
+    let rest = url.split_once("://")?.1;
+    let authority = rest.split(['/', '?']).next()?;
+    let host = authority.split(':').next()?;
+    (!host.is_empty() && !host.contains(' ')).then(|| host.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn host_parses_out_of_provider_urls() {
+        assert_eq!(
+            host_from_url("https://api.openai.com/v1"),
+            Some("api.openai.com".to_string())
+        );
+        assert_eq!(
+            host_from_url("http://localhost:11434/v1"),
+            Some("localhost".to_string())
+        );
+        assert_eq!(host_from_url("not a url"), None);
+    }
+}
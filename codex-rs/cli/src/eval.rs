@@ -0,0 +1,249 @@
+//! `codex eval`: run a directory of task specs through the headless agent and
+//! report pass rates.
+//!
+//! Each `*.toml` file in the task directory is one task: a prompt plus the
+//! assertions that decide whether the agent's run passed. Tasks run through
+//! `codex exec` (spawned as a subprocess so every run gets a fresh session),
+//! once per requested profile, so `codex eval tasks/ --profile a --profile b`
+//! compares two configurations on the same work before either becomes the
+//! default.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use regex_lite::Regex;
+use serde::Deserialize;
+
+/// One task spec file. `expect_regex` is matched against the agent's final
+/// message; `expect_command` runs in the task's working directory afterwards
+/// and passes on exit status 0 (e.g. `["cargo", "test"]` for "tests pass").
+/// A task with neither assertion passes whenever the agent produces output.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TaskSpec {
+    prompt: String,
+    #[serde(default)]
+    expect_regex: Option<String>,
+    #[serde(default)]
+    expect_command: Option<Vec<String>>,
+    /// Working directory for the agent and `expect_command`, resolved
+    /// relative to the spec file. Defaults to the directory `codex eval` runs
+    /// from.
+    #[serde(default)]
+    cwd: Option<PathBuf>,
+}
+
+struct Task {
+    name: String,
+    spec: TaskSpec,
+    cwd: Option<PathBuf>,
+}
+
+pub async fn run_eval(
+    task_dir: PathBuf,
+    profiles: Vec<String>,
+    model: Option<String>,
+    exec_args: Vec<String>,
+) -> Result<()> {
+    let tasks = load_tasks(&task_dir)?;
+    if tasks.is_empty() {
+        bail!("no *.toml task specs found in {}", task_dir.display());
+    }
+    // An empty profile name means "run with the default config".
+    let profiles = if profiles.is_empty() {
+        vec![String::new()]
+    } else {
+        profiles
+    };
+
+    let codex_bin = std::env::current_exe()?;
+    for profile in &profiles {
+        let label = if profile.is_empty() {
+            "default".to_string()
+        } else {
+            format!("profile `{profile}`")
+        };
+        let mut passed = 0_usize;
+        for task in &tasks {
+            let result = run_task(&codex_bin, task, profile, model.as_deref(), &exec_args).await;
+            match result {
+                Ok(()) => {
+                    passed += 1;
+                    println!("PASS {label} {name}", name = task.name);
+                }
+                Err(err) => println!("FAIL {label} {name}: {err:#}", name = task.name),
+            }
+        }
+        println!(
+            "{label}: {passed}/{total} passed ({percent}%)",
+            total = tasks.len(),
+            percent = passed * 100 / tasks.len(),
+        );
+    }
+    Ok(())
+}
+
+/// Loads every `*.toml` file in `task_dir`, sorted by file name so runs are
+/// deterministic and report lines line up across profiles.
+fn load_tasks(task_dir: &Path) -> Result<Vec<Task>> {
+    let entries =
+        fs::read_dir(task_dir).with_context(|| format!("failed to read {}", task_dir.display()))?;
+    let mut tasks = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("toml") {
+            continue;
+        }
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let spec: TaskSpec = toml::from_str(&raw)
+            .with_context(|| format!("{} is not a valid task spec", path.display()))?;
+        if let Some(pattern) = &spec.expect_regex {
+            Regex::new(pattern)
+                .with_context(|| format!("invalid expect_regex in {}", path.display()))?;
+        }
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        let cwd = spec
+            .cwd
+            .as_ref()
+            .map(|cwd| task_dir.join(cwd))
+            .map(|cwd| cwd.canonicalize().unwrap_or(cwd));
+        tasks.push(Task { name, spec, cwd });
+    }
+    tasks.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(tasks)
+}
+
+/// Runs one task through `codex exec` and applies its assertions. Any failure
+/// — non-zero exec exit, unmatched regex, failing expect_command — is an
+/// error describing what went wrong.
+async fn run_task(
+    codex_bin: &Path,
+    task: &Task,
+    profile: &str,
+    model: Option<&str>,
+    exec_args: &[String],
+) -> Result<()> {
+    let last_message = tempfile::NamedTempFile::new()?;
+    let mut command = tokio::process::Command::new(codex_bin);
+    command
+        .arg("exec")
+        .arg("--skip-git-repo-check")
+        .arg("--output-last-message")
+        .arg(last_message.path());
+    if !profile.is_empty() {
+        command.arg("--profile").arg(profile);
+    }
+    if let Some(model) = model {
+        command.arg("--model").arg(model);
+    }
+    if let Some(cwd) = &task.cwd {
+        command.arg("--cd").arg(cwd);
+        command.current_dir(cwd);
+    }
+    command.args(exec_args);
+    command.arg(&task.spec.prompt);
+    let output = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .context("failed to spawn codex exec")?;
+    if !output.status.success() {
+        bail!("codex exec exited with {}", output.status);
+    }
+
+    let final_message = fs::read_to_string(last_message.path()).unwrap_or_default();
+    if final_message.trim().is_empty() {
+        bail!("agent produced no final message");
+    }
+    if let Some(pattern) = &task.spec.expect_regex {
+        let regex = Regex::new(pattern).context("invalid expect_regex")?;
+        if !regex.is_match(&final_message) {
+            bail!("final message did not match expect_regex `{pattern}`");
+        }
+    }
+    if let Some(expect_command) = &task.spec.expect_command {
+        let [program, args @ ..] = expect_command.as_slice() else {
+            bail!("expect_command is empty");
+        };
+        let mut check = tokio::process::Command::new(program);
+        check.args(args);
+        if let Some(cwd) = &task.cwd {
+            check.current_dir(cwd);
+        }
+        let status = check
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .with_context(|| format!("failed to run expect_command `{program}`"))?;
+        if !status.success() {
+            bail!("expect_command exited with {status}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn tasks_load_sorted_with_resolved_cwd() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::create_dir(dir.path().join("fixture")).expect("mkdir");
+        fs::write(
+            dir.path().join("b_regex.toml"),
+            "prompt = \"say hi\"\nexpect_regex = \"(?i)hi\"\n",
+        )
+        .expect("write");
+        fs::write(
+            dir.path().join("a_command.toml"),
+            "prompt = \"fix it\"\nexpect_command = [\"true\"]\ncwd = \"fixture\"\n",
+        )
+        .expect("write");
+        fs::write(dir.path().join("notes.txt"), "ignored").expect("write");
+
+        let tasks = load_tasks(dir.path()).expect("load");
+        assert_eq!(
+            tasks
+                .iter()
+                .map(|task| task.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["a_command", "b_regex"]
+        );
+        assert_eq!(
+            tasks[0].cwd,
+            Some(
+                dir.path()
+                    .join("fixture")
+                    .canonicalize()
+                    .expect("canonicalize")
+            )
+        );
+        assert_eq!(tasks[1].spec.expect_regex.as_deref(), Some("(?i)hi"));
+    }
+
+    #[test]
+    fn invalid_expect_regex_is_rejected_at_load_time() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(
+            dir.path().join("bad.toml"),
+            "prompt = \"x\"\nexpect_regex = \"(\"\n",
+        )
+        .expect("write");
+        assert!(load_tasks(dir.path()).is_err());
+    }
+}
@@ -0,0 +1,320 @@
+//! `codex export`: convert recorded sessions into standard chat-format JSONL.
+//!
+//! The `jsonl-chat` format writes one JSON object per session, each holding a
+//! `messages` array in the shape fine-tuning and eval pipelines expect: user
+//! and assistant messages with plain-text `content`, assistant `tool_calls`
+//! entries for function calls, and `role: "tool"` messages carrying their
+//! outputs. All exported text passes through the best-effort secret redaction
+//! used elsewhere in Codex, so real sessions can be shared as eval sets
+//! without hand-scrubbing API keys first. When the rollout recorded per-turn
+//! request parameters they are exported alongside the messages under
+//! `request_params`.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_core::config::Config;
+use codex_core::find_thread_path_by_id_str;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use codex_secrets::redact_secrets;
+use serde_json::Value;
+use serde_json::json;
+
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// One JSON object per session with a chat-format `messages` array.
+    #[value(name = "jsonl-chat")]
+    JsonlChat,
+}
+
+pub async fn run_export(
+    config: Config,
+    _format: ExportFormat,
+    sessions: Vec<String>,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let mut lines = Vec::with_capacity(sessions.len());
+    for session in &sessions {
+        let path = resolve_session_path(&config, session).await?;
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let messages = chat_messages_from_rollout(&raw);
+        if messages.is_empty() {
+            bail!("{} contains no exportable messages", path.display());
+        }
+        let mut session_object = json!({ "messages": messages });
+        let request_params = turn_request_params_from_rollout(&raw);
+        if !request_params.is_empty() {
+            session_object["request_params"] = Value::Array(request_params);
+        }
+        lines.push(serde_json::to_string(&session_object)?);
+    }
+
+    let out = format!("{}\n", lines.join("\n"));
+    match output {
+        Some(path) => {
+            fs::write(&path, out).with_context(|| format!("failed to write {}", path.display()))?;
+            eprintln!("Exported {} session(s) to {}", lines.len(), path.display());
+        }
+        None => print!("{out}"),
+    }
+    Ok(())
+}
+
+/// A session argument is either a rollout file path or a thread id to look up
+/// under `sessions/`.
+async fn resolve_session_path(config: &Config, session: &str) -> Result<PathBuf> {
+    let as_path = Path::new(session);
+    if as_path.is_file() {
+        return Ok(as_path.to_path_buf());
+    }
+    match find_thread_path_by_id_str(config.codex_home.as_path(), session).await? {
+        Some(path) => Ok(path),
+        None => bail!("no session found for `{session}`"),
+    }
+}
+
+/// Maps the response items in a rollout onto chat-format messages. Reasoning,
+/// web-search and other non-conversational items are dropped; unparseable
+/// lines are skipped so a partially written rollout still exports.
+fn chat_messages_from_rollout(raw: &str) -> Vec<Value> {
+    let mut messages = Vec::new();
+    for line in raw.lines() {
+        let Ok(rollout_line) = serde_json::from_str::<RolloutLine>(line) else {
+            continue;
+        };
+        let RolloutItem::ResponseItem(item) = rollout_line.item else {
+            continue;
+        };
+        match item {
+            ResponseItem::Message { role, content, .. } => {
+                let text = content
+                    .iter()
+                    .filter_map(|item| match item {
+                        ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                            Some(text.as_str())
+                        }
+                        ContentItem::InputImage { .. } => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                if text.trim().is_empty() {
+                    continue;
+                }
+                messages.push(json!({
+                    "role": role,
+                    "content": redact_secrets(text),
+                }));
+            }
+            ResponseItem::FunctionCall {
+                name,
+                arguments,
+                call_id,
+                ..
+            } => {
+                messages.push(json!({
+                    "role": "assistant",
+                    "content": Value::Null,
+                    "tool_calls": [{
+                        "id": call_id,
+                        "type": "function",
+                        "function": {
+                            "name": name,
+                            "arguments": redact_secrets(arguments),
+                        },
+                    }],
+                }));
+            }
+            ResponseItem::FunctionCallOutput { call_id, output } => {
+                let text = output.body.to_text().unwrap_or_default();
+                messages.push(json!({
+                    "role": "tool",
+                    "tool_call_id": call_id,
+                    "content": redact_secrets(text),
+                }));
+            }
+            ResponseItem::CustomToolCall {
+                call_id,
+                name,
+                input,
+                ..
+            } => {
+                messages.push(json!({
+                    "role": "assistant",
+                    "content": Value::Null,
+                    "tool_calls": [{
+                        "id": call_id,
+                        "type": "function",
+                        "function": {
+                            "name": name,
+                            "arguments": redact_secrets(input),
+                        },
+                    }],
+                }));
+            }
+            ResponseItem::CustomToolCallOutput {
+                call_id, output, ..
+            } => {
+                let text = output.body.to_text().unwrap_or_default();
+                messages.push(json!({
+                    "role": "tool",
+                    "tool_call_id": call_id,
+                    "content": redact_secrets(text),
+                }));
+            }
+            _ => {}
+        }
+    }
+    messages
+}
+
+/// Collects the reproducibility metadata recorded with each completed turn:
+/// one entry per turn, in turn order, tagged with the turn id.
+fn turn_request_params_from_rollout(raw: &str) -> Vec<Value> {
+    let mut params = Vec::new();
+    for line in raw.lines() {
+        let Ok(rollout_line) = serde_json::from_str::<RolloutLine>(line) else {
+            continue;
+        };
+        let RolloutItem::EventMsg(EventMsg::TurnComplete(event)) = rollout_line.item else {
+            continue;
+        };
+        let Some(request_params) = event.request_params else {
+            continue;
+        };
+        let Ok(mut value) = serde_json::to_value(request_params) else {
+            continue;
+        };
+        value["turn_id"] = Value::String(event.turn_id);
+        params.push(value);
+    }
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use pretty_assertions::assert_eq;
+
+    fn rollout_line(item: ResponseItem) -> String {
+        serde_json::to_string(&RolloutLine {
+            timestamp: "2026-01-01T00:00:00.000Z".to_string(),
+            item: RolloutItem::ResponseItem(item),
+        })
+        .expect("serialize rollout line")
+    }
+
+    #[test]
+    fn messages_and_tool_calls_map_to_chat_format() {
+        let raw = [
+            rollout_line(ResponseItem::Message {
+                id: None,
+                role: "user".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "list the files".to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            }),
+            rollout_line(ResponseItem::FunctionCall {
+                id: None,
+                name: "shell_command".to_string(),
+                namespace: None,
+                arguments: "{\"command\":\"ls\"}".to_string(),
+                call_id: "call-1".to_string(),
+            }),
+            rollout_line(ResponseItem::FunctionCallOutput {
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload::from_text("README.md".to_string()),
+            }),
+        ]
+        .join("\n");
+
+        let messages = chat_messages_from_rollout(&raw);
+        assert_eq!(
+            messages,
+            vec![
+                json!({"role": "user", "content": "list the files"}),
+                json!({
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [{
+                        "id": "call-1",
+                        "type": "function",
+                        "function": {"name": "shell_command", "arguments": "{\"command\":\"ls\"}"},
+                    }],
+                }),
+                json!({"role": "tool", "tool_call_id": "call-1", "content": "README.md"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn secrets_are_redacted_and_junk_lines_skipped() {
+        let raw = [
+            "not json".to_string(),
+            rollout_line(ResponseItem::Message {
+                id: None,
+                role: "user".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "use api_key=supersecretvalue123".to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            }),
+        ]
+        .join("\n");
+
+        let messages = chat_messages_from_rollout(&raw);
+        assert_eq!(
+            messages,
+            vec![json!({"role": "user", "content": "use api_key=[REDACTED_SECRET]"})]
+        );
+    }
+
+    #[test]
+    fn turn_request_params_are_collected_in_turn_order() {
+        let raw = [
+            "not json".to_string(),
+            serde_json::to_string(&RolloutLine {
+                timestamp: "2026-01-01T00:00:00.000Z".to_string(),
+                item: RolloutItem::EventMsg(EventMsg::TurnComplete(
+                    codex_protocol::protocol::TurnCompleteEvent {
+                        turn_id: "turn-1".to_string(),
+                        last_agent_message: None,
+                        completed_at: None,
+                        duration_ms: None,
+                        request_params: Some(codex_protocol::protocol::TurnRequestParams {
+                            model: "gpt-5".to_string(),
+                            effort: None,
+                            temperature: None,
+                            seed: None,
+                            provider_request_id: Some("resp_123".to_string()),
+                        }),
+                    },
+                )),
+            })
+            .expect("serialize rollout line"),
+        ]
+        .join("\n");
+
+        let params = turn_request_params_from_rollout(&raw);
+        assert_eq!(
+            params,
+            vec![json!({
+                "turn_id": "turn-1",
+                "model": "gpt-5",
+                "provider_request_id": "resp_123",
+            })]
+        );
+    }
+}
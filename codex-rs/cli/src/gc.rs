@@ -0,0 +1,44 @@
+//! `codex gc`: prune stored sessions according to the `[storage]` policy.
+//!
+//! Walks the session directories under the Codex home, deletes rollouts that
+//! exceed the configured bounds (oldest first), and reports how much disk
+//! space was reclaimed. `--dry-run` reports without deleting.
+
+use codex_core::config::Config;
+use codex_core::storage_gc;
+
+pub async fn run_gc(config: Config, dry_run: bool) -> anyhow::Result<()> {
+    if storage_gc::policy_is_noop(&config.storage) {
+        println!(
+            "No [storage] policy configured; nothing to do.\n\
+             Set storage.max_sessions, storage.max_age_days, or storage.max_disk_mb in config.toml."
+        );
+        return Ok(());
+    }
+
+    let codex_home = config.codex_home.to_path_buf();
+    let policy = config.storage.clone();
+    let report = tokio::task::spawn_blocking(move || {
+        storage_gc::collect_garbage(&codex_home, &policy, dry_run)
+    })
+    .await??;
+
+    let verb = if dry_run { "Would remove" } else { "Removed" };
+    println!(
+        "Scanned {} session rollout(s); {verb} {} ({}).",
+        report.files_scanned,
+        report.files_removed,
+        format_bytes(report.bytes_reclaimed)
+    );
+    Ok(())
+}
+
+fn format_bytes(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+    } else {
+        format!("{bytes} B")
+    }
+}
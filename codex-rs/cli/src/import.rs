@@ -0,0 +1,324 @@
+//! `codex import`: convert transcripts exported from other tools into Codex
+//! session rollouts.
+//!
+//! Supported formats are ChatGPT conversation exports (`--format chatgpt`)
+//! and Claude conversation JSON (`--format claude-json`). User and assistant
+//! messages map onto regular rollout messages; tool calls and other
+//! non-text content map best-effort onto bracketed context notes so the
+//! imported session replays cleanly. The result is written under
+//! `sessions/` like a native rollout and can be continued with
+//! `codex resume <id>`.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use chrono::Local;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use codex_core::SESSIONS_SUBDIR;
+use codex_core::config::Config;
+use codex_protocol::ThreadId;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use codex_protocol::protocol::SessionMeta;
+use codex_protocol::protocol::SessionMetaLine;
+use codex_protocol::protocol::SessionSource;
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum ImportFormat {
+    /// A conversation from a ChatGPT data export (`conversations.json` entry).
+    Chatgpt,
+    /// A conversation from a Claude data export.
+    #[value(name = "claude-json")]
+    ClaudeJson,
+}
+
+/// One transcript entry after format-specific parsing.
+struct ImportedMessage {
+    role: &'static str,
+    text: String,
+}
+
+pub async fn run_import(config: Config, format: ImportFormat, file: PathBuf) -> Result<()> {
+    let raw =
+        fs::read_to_string(&file).with_context(|| format!("failed to read {}", file.display()))?;
+    let value: Value = serde_json::from_str(&raw)
+        .with_context(|| format!("{} is not valid JSON", file.display()))?;
+
+    let messages = match format {
+        ImportFormat::Chatgpt => parse_chatgpt(&value)?,
+        ImportFormat::ClaudeJson => parse_claude_json(&value)?,
+    };
+    if messages.is_empty() {
+        bail!("no importable messages found in {}", file.display());
+    }
+
+    let thread_id = ThreadId::new();
+    let path = write_rollout(config.codex_home.as_path(), thread_id, &messages)?;
+    println!(
+        "Imported {} message(s) into {}",
+        messages.len(),
+        path.display()
+    );
+    println!("Continue with: codex resume {thread_id}");
+    Ok(())
+}
+
+/// ChatGPT exports store a conversation as a `mapping` of nodes; order is
+/// recovered from each message's `create_time`.
+fn parse_chatgpt(value: &Value) -> Result<Vec<ImportedMessage>> {
+    let conversation = match value {
+        Value::Array(conversations) => match conversations.as_slice() {
+            [single] => single,
+            [] => bail!("export contains no conversations"),
+            many => bail!(
+                "export contains {} conversations; extract the one to import into its own file",
+                many.len()
+            ),
+        },
+        other => other,
+    };
+    let mapping = conversation
+        .get("mapping")
+        .and_then(Value::as_object)
+        .context("missing conversation mapping; is this a ChatGPT export?")?;
+
+    let mut nodes: Vec<(&Value, f64)> = mapping
+        .values()
+        .filter_map(|node| {
+            let message = node.get("message")?;
+            let create_time = message.get("create_time").and_then(Value::as_f64)?;
+            Some((message, create_time))
+        })
+        .collect();
+    nodes.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let mut messages = Vec::new();
+    for (message, _) in nodes {
+        let role = match message
+            .get("author")
+            .and_then(|author| author.get("role"))
+            .and_then(Value::as_str)
+        {
+            Some("user") => "user",
+            Some("assistant") | Some("tool") => "assistant",
+            _ => continue,
+        };
+        let content = message.get("content");
+        let content_type = content
+            .and_then(|c| c.get("content_type"))
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let text = match content_type {
+            "text" => content
+                .and_then(|c| c.get("parts"))
+                .and_then(Value::as_array)
+                .map(|parts| {
+                    parts
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                })
+                .unwrap_or_default(),
+            // Tool calls and other rich content become context notes.
+            other_type => format!("[imported {other_type} content from ChatGPT]"),
+        };
+        if text.trim().is_empty() {
+            continue;
+        }
+        messages.push(ImportedMessage { role, text });
+    }
+    Ok(messages)
+}
+
+/// Claude exports list `chat_messages` in order; each message carries either
+/// a flat `text` or a `content` array mixing text with tool use/results.
+fn parse_claude_json(value: &Value) -> Result<Vec<ImportedMessage>> {
+    let chat_messages = value
+        .get("chat_messages")
+        .and_then(Value::as_array)
+        .context("missing chat_messages; is this a Claude conversation export?")?;
+
+    let mut messages = Vec::new();
+    for message in chat_messages {
+        let role = match message.get("sender").and_then(Value::as_str) {
+            Some("human") => "user",
+            Some("assistant") => "assistant",
+            _ => continue,
+        };
+        let mut parts = Vec::new();
+        if let Some(text) = message.get("text").and_then(Value::as_str)
+            && !text.trim().is_empty()
+        {
+            parts.push(text.to_string());
+        } else if let Some(content) = message.get("content").and_then(Value::as_array) {
+            for item in content {
+                match item.get("type").and_then(Value::as_str) {
+                    Some("text") => {
+                        if let Some(text) = item.get("text").and_then(Value::as_str) {
+                            parts.push(text.to_string());
+                        }
+                    }
+                    Some(tool_type @ ("tool_use" | "tool_result")) => {
+                        let name = item.get("name").and_then(Value::as_str).unwrap_or("tool");
+                        parts.push(format!("[imported {tool_type} {name} from Claude]"));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let text = parts.join("\n");
+        if text.trim().is_empty() {
+            continue;
+        }
+        messages.push(ImportedMessage { role, text });
+    }
+    Ok(messages)
+}
+
+/// Writes the imported messages as a rollout file under
+/// `sessions/YYYY/MM/DD/`, mirroring the recorder's layout and naming.
+fn write_rollout(
+    codex_home: &Path,
+    thread_id: ThreadId,
+    messages: &[ImportedMessage],
+) -> Result<PathBuf> {
+    let now = Local::now();
+    let dir = codex_home
+        .join(SESSIONS_SUBDIR)
+        .join(now.format("%Y").to_string())
+        .join(now.format("%m").to_string())
+        .join(now.format("%d").to_string());
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "rollout-{}-{thread_id}.jsonl",
+        now.format("%Y-%m-%dT%H-%M-%S")
+    ));
+
+    let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+    let mut lines = Vec::with_capacity(messages.len() + 1);
+    lines.push(RolloutLine {
+        timestamp: timestamp.clone(),
+        item: RolloutItem::SessionMeta(SessionMetaLine {
+            meta: SessionMeta {
+                id: thread_id,
+                timestamp: timestamp.clone(),
+                cwd: std::env::current_dir().unwrap_or_default(),
+                originator: "codex_cli_import".to_string(),
+                cli_version: env!("CARGO_PKG_VERSION").to_string(),
+                source: SessionSource::Cli,
+                ..Default::default()
+            },
+            git: None,
+        }),
+    });
+    for message in messages {
+        let content = if message.role == "user" {
+            ContentItem::InputText {
+                text: message.text.clone(),
+            }
+        } else {
+            ContentItem::OutputText {
+                text: message.text.clone(),
+            }
+        };
+        lines.push(RolloutLine {
+            timestamp: timestamp.clone(),
+            item: RolloutItem::ResponseItem(ResponseItem::Message {
+                id: None,
+                role: message.role.to_string(),
+                content: vec![content],
+                end_turn: None,
+                phase: None,
+            }),
+        });
+    }
+
+    let mut out = String::new();
+    for line in &lines {
+        out.push_str(&serde_json::to_string(line)?);
+        out.push('\n');
+    }
+    fs::write(&path, out)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chatgpt_mapping_is_ordered_by_create_time() {
+        let export = serde_json::json!({
+            "title": "demo",
+            "mapping": {
+                "b": {"message": {
+                    "author": {"role": "assistant"},
+                    "create_time": 2.0,
+                    "content": {"content_type": "text", "parts": ["hi there"]}
+                }},
+                "a": {"message": {
+                    "author": {"role": "user"},
+                    "create_time": 1.0,
+                    "content": {"content_type": "text", "parts": ["hello"]}
+                }},
+                "root": {"message": null}
+            }
+        });
+        let messages = parse_chatgpt(&export).expect("parse");
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].role, "user");
+        assert_eq!(messages[0].text, "hello");
+        assert_eq!(messages[1].role, "assistant");
+    }
+
+    #[test]
+    fn chatgpt_tool_content_becomes_a_note() {
+        let export = serde_json::json!({
+            "mapping": {
+                "a": {"message": {
+                    "author": {"role": "assistant"},
+                    "create_time": 1.0,
+                    "content": {"content_type": "code", "text": "print(1)"}
+                }}
+            }
+        });
+        let messages = parse_chatgpt(&export).expect("parse");
+        assert_eq!(messages[0].text, "[imported code content from ChatGPT]");
+    }
+
+    #[test]
+    fn claude_content_array_maps_text_and_tools() {
+        let export = serde_json::json!({
+            "name": "demo",
+            "chat_messages": [
+                {"sender": "human", "text": "run the tests"},
+                {"sender": "assistant", "content": [
+                    {"type": "tool_use", "name": "bash"},
+                    {"type": "text", "text": "All tests pass."}
+                ]}
+            ]
+        });
+        let messages = parse_claude_json(&export).expect("parse");
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].text, "run the tests");
+        assert_eq!(
+            messages[1].text,
+            "[imported tool_use bash from Claude]\nAll tests pass."
+        );
+    }
+
+    #[test]
+    fn multi_conversation_chatgpt_exports_are_rejected() {
+        let export = serde_json::json!([{"mapping": {}}, {"mapping": {}}]);
+        assert!(parse_chatgpt(&export).is_err());
+    }
+}
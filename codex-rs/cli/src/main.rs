@@ -17,6 +17,7 @@ use codex_cli::run_login_with_chatgpt;
 use codex_cli::run_login_with_device_code;
 use codex_cli::run_logout;
 use codex_cloud_tasks::Cli as CloudTasksCli;
+use codex_config::types::UpdateChannel;
 use codex_exec::Cli as ExecCli;
 use codex_exec::Command as ExecCommand;
 use codex_exec::ReviewArgs;
@@ -38,9 +39,19 @@ use supports_color::Stream;
 mod app_cmd;
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 mod desktop_app;
+mod diff_sessions;
+mod doctor;
+mod eval;
+mod export;
+mod gc;
+mod import;
 mod marketplace_cmd;
 mod mcp_cmd;
+mod quiet;
 mod responses_cmd;
+mod self_update;
+mod themes;
+mod watch;
 #[cfg(not(windows))]
 mod wsl_paths;
 
@@ -91,6 +102,12 @@ struct MultitoolCli {
     #[clap(flatten)]
     remote: InteractiveRemoteOptions,
 
+    /// Run one turn headlessly and print only the final answer (quiet
+    /// single-shot mode). Runs read-only unless a sandbox flag says
+    /// otherwise.
+    #[clap(short = 'q', long = "quiet", default_value_t = false)]
+    quiet: bool,
+
     #[clap(flatten)]
     interactive: TuiCli,
 
@@ -104,6 +121,10 @@ enum Subcommand {
     #[clap(visible_alias = "e")]
     Exec(ExecCli),
 
+    /// Start a fresh interactive session, optionally seeded from a named
+    /// template defined under `[templates.<name>]` in config.toml.
+    New(NewCommand),
+
     /// Run a code review non-interactively.
     Review(ReviewArgs),
 
@@ -129,9 +150,29 @@ enum Subcommand {
     #[cfg(any(target_os = "macos", target_os = "windows"))]
     App(app_cmd::AppCommand),
 
+    /// Check terminal, network, auth, git, sandbox, and MCP setup.
+    Doctor(DoctorCommand),
+
+    /// UI snapshot tooling for theme and keymap contributors.
+    Ui(UiCommand),
+
+    /// Guided, offline walkthrough of the core keybindings.
+    Tutorial,
+
+    /// Inspect keybindings; `dump` prints the fully merged keymap.
+    Keybindings(KeybindingsCli),
+
+    /// Prune stored sessions according to the `[storage]` policy in
+    /// config.toml, reporting the disk space reclaimed.
+    Gc(GcCommand),
+
     /// Generate shell completion scripts.
     Completion(CompletionCommand),
 
+    /// Generate man pages from the CLI definitions.
+    #[clap(hide = true)]
+    GenerateMan(GenerateManCommand),
+
     /// Run commands within a Codex-provided sandbox.
     Sandbox(SandboxArgs),
 
@@ -152,6 +193,25 @@ enum Subcommand {
     /// Fork a previous interactive session (picker by default; use --last to fork the most recent).
     Fork(ForkCommand),
 
+    /// Import a conversation exported from another tool as a Codex session.
+    Import(ImportCommand),
+
+    /// Export recorded sessions as chat-format JSONL for fine-tuning or evals.
+    Export(ExportCommand),
+
+    /// Manage custom themes; `import` converts third-party color schemes.
+    Themes(ThemesCli),
+
+    /// Compare two sessions' final file changes and answers side by side.
+    DiffSessions(DiffSessionsCommand),
+
+    /// Run a directory of task specs through the headless agent and report
+    /// pass rates per profile.
+    Eval(EvalCommand),
+
+    /// Re-run a task through the headless agent whenever matching files change.
+    Watch(WatchCommand),
+
     /// [EXPERIMENTAL] Browse tasks from Codex Cloud and apply changes locally.
     #[clap(name = "cloud", alias = "cloud-tasks")]
     Cloud(CloudTasksCli),
@@ -173,6 +233,9 @@ enum Subcommand {
 
     /// Inspect feature flags.
     Features(FeaturesCli),
+
+    /// Update Codex in place using the install method it was installed with.
+    SelfUpdate(SelfUpdateCommand),
 }
 
 #[derive(Debug, Parser)]
@@ -190,6 +253,204 @@ enum PluginSubcommand {
     Marketplace(MarketplaceCli),
 }
 
+#[derive(Debug, Parser)]
+struct DoctorCommand {
+    #[clap(flatten)]
+    config_overrides: CliConfigOverrides,
+}
+
+#[derive(Debug, Parser)]
+struct KeybindingsCli {
+    #[clap(flatten)]
+    config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    subcommand: KeybindingsSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum KeybindingsSubcommand {
+    /// Print the merged keymap: the configured preset plus `[tui.keybindings]`
+    /// overrides, including per-context sub-tables.
+    Dump(KeybindingsDumpCommand),
+}
+
+#[derive(Debug, Parser)]
+struct KeybindingsDumpCommand {
+    /// Output format.
+    #[arg(long, value_enum, default_value = "toml")]
+    format: codex_tui::KeybindingsDumpFormat,
+}
+
+#[derive(Debug, Parser)]
+struct UiCommand {
+    #[command(subcommand)]
+    subcommand: UiSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum UiSubcommand {
+    /// Render a named screen to a VT100 snapshot for golden review.
+    Snapshot(UiSnapshotCommand),
+}
+
+#[derive(Debug, Parser)]
+struct UiSnapshotCommand {
+    /// Theme to render with; defaults to the built-in theme. Accepts bundled
+    /// theme names and custom `.tmTheme` files under `{CODEX_HOME}/themes/`.
+    #[arg(long, value_name = "NAME")]
+    theme: Option<String>,
+
+    /// Screen to render.
+    #[arg(long, value_enum)]
+    screen: codex_tui::UiSnapshotScreen,
+
+    /// Keep ANSI escape sequences so colors survive in the snapshot.
+    #[arg(long, default_value_t = false)]
+    ansi: bool,
+
+    /// Write the snapshot to this file instead of stdout.
+    #[arg(short = 'o', long = "out", value_name = "FILE")]
+    out: Option<PathBuf>,
+}
+
+#[derive(Debug, Parser)]
+struct ImportCommand {
+    #[clap(flatten)]
+    config_overrides: CliConfigOverrides,
+
+    /// Format of the exported conversation.
+    #[clap(long, value_enum)]
+    format: import::ImportFormat,
+
+    /// Path to the exported conversation file.
+    file: PathBuf,
+}
+
+#[derive(Debug, Parser)]
+struct ThemesCli {
+    #[clap(flatten)]
+    config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    subcommand: ThemesSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum ThemesSubcommand {
+    /// Convert a base16 YAML, iTerm2 .itermcolors, or Alacritty TOML color
+    /// scheme into a Codex theme under `$CODEX_HOME/themes/`.
+    Import(ThemesImportCommand),
+}
+
+#[derive(Debug, Parser)]
+struct ThemesImportCommand {
+    /// Path to the color scheme file (.yaml/.yml, .itermcolors, or .toml).
+    file: PathBuf,
+
+    /// Theme name to write; defaults to the scheme's own name or the input
+    /// file stem, kebab-cased.
+    #[clap(long, value_name = "NAME")]
+    name: Option<String>,
+
+    /// Overwrite an existing theme file with the same name.
+    #[clap(long, default_value_t = false)]
+    force: bool,
+}
+
+#[derive(Debug, Parser)]
+struct DiffSessionsCommand {
+    #[clap(flatten)]
+    config_overrides: CliConfigOverrides,
+
+    /// First session: a thread id or rollout file path.
+    session_a: String,
+
+    /// Second session: a thread id or rollout file path.
+    session_b: String,
+
+    /// Total width of the two-column view, in terminal columns.
+    #[clap(long, default_value_t = 120)]
+    width: usize,
+}
+
+#[derive(Debug, Parser)]
+struct EvalCommand {
+    #[clap(flatten)]
+    config_overrides: CliConfigOverrides,
+
+    /// Directory of `*.toml` task specs.
+    task_dir: PathBuf,
+
+    /// Configuration profile to evaluate (repeatable to compare several).
+    #[clap(long = "profile", short = 'p', value_name = "NAME")]
+    profiles: Vec<String>,
+
+    /// Model the agent should use for every run.
+    #[clap(long, short = 'm')]
+    model: Option<String>,
+
+    /// Extra arguments appended to every `codex exec` invocation.
+    #[clap(last = true, value_name = "EXEC_ARGS")]
+    exec_args: Vec<String>,
+}
+
+#[derive(Debug, Parser)]
+struct WatchCommand {
+    #[clap(flatten)]
+    config_overrides: CliConfigOverrides,
+
+    /// Task prompt to re-run on every matching change.
+    #[clap(long)]
+    task: String,
+
+    /// Glob of paths that trigger a re-run, relative to the working
+    /// directory (repeatable). Defaults to any change outside `.git/`.
+    #[clap(long = "trigger", value_name = "GLOB")]
+    triggers: Vec<String>,
+
+    /// Quiet period after a change burst before the task re-runs, in
+    /// milliseconds.
+    #[clap(long, default_value_t = 1000, value_name = "MS")]
+    debounce_ms: u64,
+
+    /// Maximum number of automatic runs before the watcher exits.
+    #[clap(long, default_value_t = 10, value_name = "N")]
+    max_runs: usize,
+
+    /// Extra arguments appended to every `codex exec` invocation.
+    #[clap(last = true, value_name = "EXEC_ARGS")]
+    exec_args: Vec<String>,
+}
+
+#[derive(Debug, Parser)]
+struct ExportCommand {
+    #[clap(flatten)]
+    config_overrides: CliConfigOverrides,
+
+    /// Output format for the exported sessions.
+    #[clap(long, value_enum)]
+    format: export::ExportFormat,
+
+    /// Write the JSONL here instead of stdout.
+    #[clap(long, short = 'o')]
+    output: Option<PathBuf>,
+
+    /// Sessions to export: thread ids or rollout file paths.
+    #[clap(required = true)]
+    sessions: Vec<String>,
+}
+
+#[derive(Debug, Parser)]
+struct GcCommand {
+    #[clap(flatten)]
+    config_overrides: CliConfigOverrides,
+
+    /// Report what would be removed without deleting anything.
+    #[clap(long)]
+    dry_run: bool,
+}
+
 #[derive(Debug, Parser)]
 struct CompletionCommand {
     /// Shell to generate completions for
@@ -197,6 +458,12 @@ struct CompletionCommand {
     shell: Shell,
 }
 
+#[derive(Debug, Parser)]
+struct GenerateManCommand {
+    /// Directory to write the generated man pages into.
+    out_dir: PathBuf,
+}
+
 #[derive(Debug, Parser)]
 struct DebugCommand {
     #[command(subcommand)]
@@ -255,6 +522,19 @@ struct DebugModelsCommand {
     bundled: bool,
 }
 
+#[derive(Debug, Parser)]
+struct NewCommand {
+    /// Template name defined under `[templates.<name>]` in config.toml.
+    #[arg(long = "template", value_name = "NAME")]
+    template: Option<String>,
+
+    #[clap(flatten)]
+    remote: InteractiveRemoteOptions,
+
+    #[clap(flatten)]
+    config_overrides: TuiCli,
+}
+
 #[derive(Debug, Parser)]
 struct ResumeCommand {
     /// Conversation/session id (UUID) or thread name. UUIDs take precedence if it parses.
@@ -521,6 +801,11 @@ fn handle_app_exit(exit_info: AppExitInfo) -> anyhow::Result<()> {
     match exit_info.exit_reason {
         ExitReason::Fatal(message) => {
             eprintln!("ERROR: {message}");
+            if let Ok(codex_home) = find_codex_home()
+                && let Some(hint) = self_update::recent_update_rollback_hint(&codex_home)
+            {
+                eprintln!("{hint}");
+            }
             std::process::exit(1);
         }
         ExitReason::UserRequested => { /* normal exit */ }
@@ -532,22 +817,22 @@ fn handle_app_exit(exit_info: AppExitInfo) -> anyhow::Result<()> {
         println!("{line}");
     }
     if let Some(action) = update_action {
-        run_update_action(action)?;
+        run_update_action(action, UpdateChannel::Stable)?;
     }
     Ok(())
 }
 
 /// Run the update action and print the result.
-fn run_update_action(action: UpdateAction) -> anyhow::Result<()> {
+fn run_update_action(action: UpdateAction, channel: UpdateChannel) -> anyhow::Result<()> {
     println!();
-    let cmd_str = action.command_str();
+    let cmd_str = action.command_str_for_channel(channel);
     println!("Updating Codex via `{cmd_str}`...");
 
     let status = {
         #[cfg(windows)]
         {
             if action == UpdateAction::StandaloneWindows {
-                let (cmd, args) = action.command_args();
+                let (cmd, args) = action.command_args_for_channel(channel);
                 // Run the standalone PowerShell installer with PowerShell
                 // itself. Routing this through `cmd.exe /C` would parse
                 // PowerShell metacharacters like `|` before PowerShell sees
@@ -562,7 +847,7 @@ fn run_update_action(action: UpdateAction) -> anyhow::Result<()> {
         }
         #[cfg(not(windows))]
         {
-            let (cmd, args) = action.command_args();
+            let (cmd, args) = action.command_args_for_channel(channel);
             let command_path = crate::wsl_paths::normalize_for_wsl(cmd);
             let normalized_args: Vec<String> = args
                 .iter()
@@ -642,6 +927,69 @@ impl FeatureToggles {
     }
 }
 
+#[derive(Debug, Parser)]
+struct SelfUpdateCommand {
+    /// Release channel to update to. Defaults to the configured
+    /// `update_channel`.
+    #[arg(long = "channel", value_enum, conflicts_with = "rollback")]
+    channel: Option<SelfUpdateChannel>,
+
+    /// Restore the Codex binary that was in place before the last
+    /// self-update.
+    #[arg(long = "rollback", default_value_t = false)]
+    rollback: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum SelfUpdateChannel {
+    Stable,
+    Beta,
+}
+
+impl From<SelfUpdateChannel> for UpdateChannel {
+    fn from(channel: SelfUpdateChannel) -> Self {
+        match channel {
+            SelfUpdateChannel::Stable => UpdateChannel::Stable,
+            SelfUpdateChannel::Beta => UpdateChannel::Beta,
+        }
+    }
+}
+
+async fn run_self_update(cmd: SelfUpdateCommand) -> anyhow::Result<()> {
+    if cmd.rollback {
+        let codex_home = find_codex_home()?;
+        let version = self_update::rollback(&codex_home)?;
+        match version {
+            Some(version) => println!("Rolled Codex back to version {version}."),
+            None => println!("Rolled Codex back to the previous binary."),
+        }
+        return Ok(());
+    }
+
+    let channel = match cmd.channel {
+        Some(channel) => channel.into(),
+        None => {
+            let config = Config::load_with_cli_overrides_and_harness_overrides(
+                Vec::new(),
+                ConfigOverrides::default(),
+            )
+            .await?;
+            config.update_channel
+        }
+    };
+    let Some(action) = codex_tui::resolve_update_action() else {
+        anyhow::bail!(
+            "could not determine how this Codex binary was installed; update it with the package manager you installed it with"
+        );
+    };
+    if let Ok(codex_home) = find_codex_home()
+        && let Err(err) = self_update::backup_current_binary(&codex_home)
+    {
+        eprintln!("warning: could not back up the current binary for rollback: {err}");
+    }
+    run_update_action(action, channel)
+}
+
 #[derive(Debug, Parser)]
 struct FeaturesCli {
     #[command(subcommand)]
@@ -686,6 +1034,7 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
         config_overrides: mut root_config_overrides,
         feature_toggles,
         remote,
+        quiet,
         mut interactive,
         subcommand,
     } = MultitoolCli::parse();
@@ -702,6 +1051,14 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
                 &mut interactive.config_overrides,
                 root_config_overrides.clone(),
             );
+            if quiet {
+                reject_remote_mode_for_subcommand(
+                    root_remote.as_deref(),
+                    root_remote_auth_token_env.as_deref(),
+                    "--quiet",
+                )?;
+                return quiet::run_quiet(interactive).await;
+            }
             let exit_info = run_interactive_tui(
                 interactive,
                 root_remote.clone(),
@@ -831,6 +1188,28 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
             )?;
             app_cmd::run_app(app_cli).await?;
         }
+        Some(Subcommand::New(NewCommand {
+            template,
+            remote,
+            config_overrides,
+        })) => {
+            interactive = finalize_new_interactive(
+                interactive,
+                root_config_overrides.clone(),
+                template,
+                config_overrides,
+            );
+            let exit_info = run_interactive_tui(
+                interactive,
+                remote.remote.or(root_remote.clone()),
+                remote
+                    .remote_auth_token_env
+                    .or(root_remote_auth_token_env.clone()),
+                arg0_paths.clone(),
+            )
+            .await?;
+            handle_app_exit(exit_info)?;
+        }
         Some(Subcommand::Resume(ResumeCommand {
             session_id,
             last,
@@ -933,6 +1312,245 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
             );
             run_logout(logout_cli.config_overrides).await;
         }
+        Some(Subcommand::Doctor(doctor_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "doctor",
+            )?;
+            let mut cli_kv_overrides = root_config_overrides
+                .parse_overrides()
+                .map_err(anyhow::Error::msg)?;
+            cli_kv_overrides.extend(
+                doctor_cli
+                    .config_overrides
+                    .parse_overrides()
+                    .map_err(anyhow::Error::msg)?,
+            );
+            let config = Config::load_with_cli_overrides(cli_kv_overrides).await?;
+            doctor::run_doctor(config).await?;
+        }
+        Some(Subcommand::Ui(ui_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "ui",
+            )?;
+            match ui_cli.subcommand {
+                UiSubcommand::Snapshot(snapshot_cli) => {
+                    let codex_home = find_codex_home().ok();
+                    let snapshot = codex_tui::run_ui_snapshot(
+                        snapshot_cli.screen,
+                        snapshot_cli.theme.as_deref(),
+                        codex_home.as_deref(),
+                        snapshot_cli.ansi,
+                    )
+                    .map_err(|err| anyhow::anyhow!("{err}"))?;
+                    match snapshot_cli.out {
+                        Some(path) => std::fs::write(&path, snapshot)?,
+                        None => print!("{snapshot}"),
+                    }
+                }
+            }
+        }
+        Some(Subcommand::Tutorial) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "tutorial",
+            )?;
+            let codex_home = find_codex_home().ok();
+            codex_tui::run_tutorial(codex_home.as_deref())
+                .map_err(|err| anyhow::anyhow!("{err}"))?;
+        }
+        Some(Subcommand::Keybindings(keybindings_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "keybindings",
+            )?;
+            let mut cli_kv_overrides = root_config_overrides
+                .parse_overrides()
+                .map_err(anyhow::Error::msg)?;
+            cli_kv_overrides.extend(
+                keybindings_cli
+                    .config_overrides
+                    .parse_overrides()
+                    .map_err(anyhow::Error::msg)?,
+            );
+            let config = Config::load_with_cli_overrides(cli_kv_overrides).await?;
+            match keybindings_cli.subcommand {
+                KeybindingsSubcommand::Dump(dump_cli) => {
+                    let dump = codex_tui::dump_keybindings(
+                        config.tui_keybindings.as_ref(),
+                        dump_cli.format,
+                    )
+                    .map_err(|err| anyhow::anyhow!(err))?;
+                    print!("{dump}");
+                }
+            }
+        }
+        Some(Subcommand::Gc(gc_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "gc",
+            )?;
+            let mut cli_kv_overrides = root_config_overrides
+                .parse_overrides()
+                .map_err(anyhow::Error::msg)?;
+            cli_kv_overrides.extend(
+                gc_cli
+                    .config_overrides
+                    .parse_overrides()
+                    .map_err(anyhow::Error::msg)?,
+            );
+            let config = Config::load_with_cli_overrides(cli_kv_overrides).await?;
+            gc::run_gc(config, gc_cli.dry_run).await?;
+        }
+        Some(Subcommand::Import(import_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "import",
+            )?;
+            let mut cli_kv_overrides = root_config_overrides
+                .parse_overrides()
+                .map_err(anyhow::Error::msg)?;
+            cli_kv_overrides.extend(
+                import_cli
+                    .config_overrides
+                    .parse_overrides()
+                    .map_err(anyhow::Error::msg)?,
+            );
+            let config = Config::load_with_cli_overrides(cli_kv_overrides).await?;
+            import::run_import(config, import_cli.format, import_cli.file).await?;
+        }
+        Some(Subcommand::Export(export_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "export",
+            )?;
+            let mut cli_kv_overrides = root_config_overrides
+                .parse_overrides()
+                .map_err(anyhow::Error::msg)?;
+            cli_kv_overrides.extend(
+                export_cli
+                    .config_overrides
+                    .parse_overrides()
+                    .map_err(anyhow::Error::msg)?,
+            );
+            let config = Config::load_with_cli_overrides(cli_kv_overrides).await?;
+            export::run_export(
+                config,
+                export_cli.format,
+                export_cli.sessions,
+                export_cli.output,
+            )
+            .await?;
+        }
+        Some(Subcommand::Themes(themes_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "themes",
+            )?;
+            let mut cli_kv_overrides = root_config_overrides
+                .parse_overrides()
+                .map_err(anyhow::Error::msg)?;
+            cli_kv_overrides.extend(
+                themes_cli
+                    .config_overrides
+                    .parse_overrides()
+                    .map_err(anyhow::Error::msg)?,
+            );
+            let config = Config::load_with_cli_overrides(cli_kv_overrides).await?;
+            match themes_cli.subcommand {
+                ThemesSubcommand::Import(import_cmd) => {
+                    themes::run_themes_import(
+                        config,
+                        import_cmd.file,
+                        import_cmd.name,
+                        import_cmd.force,
+                    )
+                    .await?;
+                }
+            }
+        }
+        Some(Subcommand::DiffSessions(diff_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "diff-sessions",
+            )?;
+            let mut cli_kv_overrides = root_config_overrides
+                .parse_overrides()
+                .map_err(anyhow::Error::msg)?;
+            cli_kv_overrides.extend(
+                diff_cli
+                    .config_overrides
+                    .parse_overrides()
+                    .map_err(anyhow::Error::msg)?,
+            );
+            let config = Config::load_with_cli_overrides(cli_kv_overrides).await?;
+            diff_sessions::run_diff_sessions(
+                config,
+                diff_cli.session_a,
+                diff_cli.session_b,
+                diff_cli.width,
+            )
+            .await?;
+        }
+        Some(Subcommand::Eval(eval_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "eval",
+            )?;
+            // Overrides are forwarded to each `codex exec` child rather than
+            // applied here; eval itself never loads a config.
+            let mut exec_args = Vec::new();
+            for overrides in [&root_config_overrides, &eval_cli.config_overrides] {
+                for raw in &overrides.raw_overrides {
+                    exec_args.push("-c".to_string());
+                    exec_args.push(raw.clone());
+                }
+            }
+            exec_args.extend(eval_cli.exec_args);
+            eval::run_eval(
+                eval_cli.task_dir,
+                eval_cli.profiles,
+                eval_cli.model,
+                exec_args,
+            )
+            .await?;
+        }
+        Some(Subcommand::Watch(watch_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "watch",
+            )?;
+            // Overrides are forwarded to each `codex exec` child rather than
+            // applied here; watch itself never loads a config.
+            let mut exec_args = Vec::new();
+            for overrides in [&root_config_overrides, &watch_cli.config_overrides] {
+                for raw in &overrides.raw_overrides {
+                    exec_args.push("-c".to_string());
+                    exec_args.push(raw.clone());
+                }
+            }
+            exec_args.extend(watch_cli.exec_args);
+            watch::run_watch(
+                watch_cli.task,
+                watch_cli.triggers,
+                watch_cli.debounce_ms,
+                watch_cli.max_runs,
+                exec_args,
+            )
+            .await?;
+        }
         Some(Subcommand::Completion(completion_cli)) => {
             reject_remote_mode_for_subcommand(
                 root_remote.as_deref(),
@@ -941,6 +1559,14 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
             )?;
             print_completion(completion_cli);
         }
+        Some(Subcommand::GenerateMan(generate_man_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "generate-man",
+            )?;
+            generate_man_pages(generate_man_cli)?;
+        }
         Some(Subcommand::Cloud(mut cloud_cli)) => {
             reject_remote_mode_for_subcommand(
                 root_remote.as_deref(),
@@ -1166,6 +1792,14 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
                 disable_feature_in_config(&interactive, &feature).await?;
             }
         },
+        Some(Subcommand::SelfUpdate(cmd)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "self-update",
+            )?;
+            run_self_update(cmd).await?;
+        }
     }
 
     Ok(())
@@ -1254,7 +1888,7 @@ async fn run_debug_prompt_input_command(
 
     let approval_policy = if interactive.full_auto {
         Some(AskForApproval::OnRequest)
-    } else if interactive.dangerously_bypass_approvals_and_sandbox {
+    } else if interactive.dangerously_bypass_approvals_and_sandbox || interactive.read_only {
         Some(AskForApproval::Never)
     } else {
         interactive.approval_policy.map(Into::into)
@@ -1263,6 +1897,8 @@ async fn run_debug_prompt_input_command(
         Some(codex_protocol::config_types::SandboxMode::WorkspaceWrite)
     } else if interactive.dangerously_bypass_approvals_and_sandbox {
         Some(codex_protocol::config_types::SandboxMode::DangerFullAccess)
+    } else if interactive.read_only {
+        Some(codex_protocol::config_types::SandboxMode::ReadOnly)
     } else {
         interactive.sandbox_mode.map(Into::into)
     };
@@ -1497,6 +2133,26 @@ fn confirm(prompt: &str) -> std::io::Result<bool> {
 }
 
 /// Build the final `TuiCli` for a `codex resume` invocation.
+/// Build the final `TuiCli` for a `codex new` invocation.
+fn finalize_new_interactive(
+    mut interactive: TuiCli,
+    root_config_overrides: CliConfigOverrides,
+    template: Option<String>,
+    new_cli: TuiCli,
+) -> TuiCli {
+    if template.is_some() {
+        interactive.template = template;
+    }
+
+    // Merge new-scoped flags and overrides with highest precedence.
+    merge_interactive_cli_flags(&mut interactive, new_cli);
+
+    // Propagate any root-level config overrides (e.g. `-c key=value`).
+    prepend_config_flags(&mut interactive.config_overrides, root_config_overrides);
+
+    interactive
+}
+
 fn finalize_resume_interactive(
     mut interactive: TuiCli,
     root_config_overrides: CliConfigOverrides,
@@ -1575,6 +2231,9 @@ fn merge_interactive_cli_flags(interactive: &mut TuiCli, subcommand_cli: TuiCli)
     if subcommand_cli.dangerously_bypass_approvals_and_sandbox {
         interactive.dangerously_bypass_approvals_and_sandbox = true;
     }
+    if subcommand_cli.read_only {
+        interactive.read_only = true;
+    }
     if let Some(cwd) = subcommand_cli.cwd {
         interactive.cwd = Some(cwd);
     }
@@ -1587,6 +2246,9 @@ fn merge_interactive_cli_flags(interactive: &mut TuiCli, subcommand_cli: TuiCli)
     if !subcommand_cli.add_dir.is_empty() {
         interactive.add_dir.extend(subcommand_cli.add_dir);
     }
+    if let Some(template) = subcommand_cli.template {
+        interactive.template = Some(template);
+    }
     if let Some(prompt) = subcommand_cli.prompt {
         // Normalize CRLF/CR to LF so CLI-provided text can't leak `\r` into TUI state.
         interactive.prompt = Some(prompt.replace("\r\n", "\n").replace('\r', "\n"));
@@ -1604,6 +2266,28 @@ fn print_completion(cmd: CompletionCommand) {
     generate(cmd.shell, &mut app, name, &mut std::io::stdout());
 }
 
+/// Write `codex.1` plus one page per visible subcommand into `out_dir`.
+fn generate_man_pages(cmd: GenerateManCommand) -> anyhow::Result<()> {
+    std::fs::create_dir_all(&cmd.out_dir)?;
+    let root = MultitoolCli::command().name("codex");
+    let mut write_page = |name: String, command: clap::Command| -> anyhow::Result<()> {
+        let mut buffer = Vec::new();
+        clap_mangen::Man::new(command).render(&mut buffer)?;
+        std::fs::write(cmd.out_dir.join(format!("{name}.1")), buffer)?;
+        Ok(())
+    };
+    for sub in root.get_subcommands() {
+        if sub.is_hide_set() {
+            continue;
+        }
+        let sub_name = format!("codex-{}", sub.get_name());
+        write_page(sub_name.clone(), sub.clone().name(sub_name))?;
+    }
+    write_page("codex".to_string(), root)?;
+    println!("Wrote man pages to {}", cmd.out_dir.display());
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
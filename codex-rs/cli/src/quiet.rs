@@ -0,0 +1,149 @@
+//! `codex -q`: quiet single-shot mode that prints only the final answer.
+//!
+//! `codex -q "question"` runs one turn through `codex exec` (spawned as a
+//! subprocess so the question gets a fresh headless session), discards the
+//! streamed event output, and prints just the agent's final markdown message
+//! — making Codex usable as a quick CLI oracle in scripts and pipelines.
+//! Unless the user picks a sandbox explicitly the turn runs read-only, so a
+//! quick question can never edit the workspace.
+
+use std::process::Stdio;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_tui::Cli as TuiCli;
+use codex_utils_cli::SandboxModeCliArg;
+
+pub async fn run_quiet(interactive: TuiCli) -> Result<()> {
+    let Some(prompt) = interactive
+        .prompt
+        .clone()
+        .filter(|prompt| !prompt.trim().is_empty())
+    else {
+        bail!("-q requires a prompt, e.g. `codex -q \"explain this error\"`");
+    };
+
+    let last_message = tempfile::NamedTempFile::new()?;
+    let codex_bin = std::env::current_exe().context("failed to locate the codex binary")?;
+    let mut command = tokio::process::Command::new(codex_bin);
+    command
+        .arg("exec")
+        .arg("--skip-git-repo-check")
+        .arg("--output-last-message")
+        .arg(last_message.path());
+    command.args(quiet_exec_args(&interactive));
+    command.arg(&prompt);
+
+    // exec's stdout is its stream of agent events; quiet mode exists to
+    // suppress it. stderr stays attached so auth and config errors surface.
+    let status = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .status()
+        .await
+        .context("failed to spawn codex exec")?;
+    if !status.success() {
+        bail!("codex exec exited with {status}");
+    }
+
+    let final_message = std::fs::read_to_string(last_message.path()).unwrap_or_default();
+    if final_message.trim().is_empty() {
+        bail!("agent produced no final message");
+    }
+    println!("{}", final_message.trim_end());
+    Ok(())
+}
+
+/// Forward the interactive flags that make sense for a one-shot question.
+/// Without an explicit sandbox or approvals choice the turn runs read-only:
+/// quiet mode is for asking, not editing.
+fn quiet_exec_args(interactive: &TuiCli) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(model) = &interactive.model {
+        args.push("--model".to_string());
+        args.push(model.clone());
+    }
+    if let Some(profile) = &interactive.config_profile {
+        args.push("--profile".to_string());
+        args.push(profile.clone());
+    }
+    if let Some(cwd) = &interactive.cwd {
+        args.push("--cd".to_string());
+        args.push(cwd.display().to_string());
+    }
+    for image in &interactive.images {
+        args.push("--image".to_string());
+        args.push(image.display().to_string());
+    }
+    if interactive.dangerously_bypass_approvals_and_sandbox {
+        args.push("--dangerously-bypass-approvals-and-sandbox".to_string());
+    } else if interactive.full_auto {
+        args.push("--full-auto".to_string());
+    } else {
+        let sandbox = match interactive
+            .sandbox_mode
+            .unwrap_or(SandboxModeCliArg::ReadOnly)
+        {
+            SandboxModeCliArg::ReadOnly => "read-only",
+            SandboxModeCliArg::WorkspaceWrite => "workspace-write",
+            SandboxModeCliArg::DangerFullAccess => "danger-full-access",
+        };
+        args.push("--sandbox".to_string());
+        args.push(sandbox.to_string());
+    }
+    for raw in &interactive.config_overrides.raw_overrides {
+        args.push("-c".to_string());
+        args.push(raw.clone());
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn defaults_to_a_read_only_sandbox() {
+        let interactive = TuiCli::try_parse_from(["codex", "how does config loading work?"])
+            .expect("parse TuiCli");
+        let args = quiet_exec_args(&interactive);
+        assert_eq!(args, vec!["--sandbox".to_string(), "read-only".to_string()]);
+    }
+
+    #[test]
+    fn forwards_explicit_model_sandbox_and_overrides() {
+        let interactive = TuiCli::try_parse_from([
+            "codex",
+            "-m",
+            "gpt-5",
+            "-s",
+            "workspace-write",
+            "-c",
+            "foo=\"bar\"",
+            "fix the tests",
+        ])
+        .expect("parse TuiCli");
+        let args = quiet_exec_args(&interactive);
+        assert_eq!(
+            args,
+            vec![
+                "--model".to_string(),
+                "gpt-5".to_string(),
+                "--sandbox".to_string(),
+                "workspace-write".to_string(),
+                "-c".to_string(),
+                "foo=\"bar\"".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn full_auto_replaces_the_sandbox_default() {
+        let interactive = TuiCli::try_parse_from(["codex", "--full-auto", "tidy the readme"])
+            .expect("parse TuiCli");
+        let args = quiet_exec_args(&interactive);
+        assert_eq!(args, vec!["--full-auto".to_string()]);
+    }
+}
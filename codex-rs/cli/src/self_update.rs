@@ -0,0 +1,142 @@
+//! Backup and rollback support for `codex self-update`.
+//!
+//! Before a self-update replaces the running binary, the current executable is
+//! copied into `$CODEX_HOME/self-update/` together with a small metadata file
+//! recording the version and timestamp. `codex self-update --rollback`
+//! restores that copy, and a fatal exit shortly after an update prints a hint
+//! pointing at it so a bad release doesn't strand users.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+/// Directory under `$CODEX_HOME` holding the pre-update binary.
+const BACKUP_SUBDIR: &str = "self-update";
+const BACKUP_BINARY: &str = "codex.previous";
+const BACKUP_METADATA: &str = "previous.json";
+
+/// How long after an update a fatal exit still suggests rolling back.
+const CRASH_HINT_WINDOW: Duration = Duration::from_secs(10 * 60);
+
+fn backup_binary_path(codex_home: &Path) -> PathBuf {
+    codex_home.join(BACKUP_SUBDIR).join(BACKUP_BINARY)
+}
+
+fn backup_metadata_path(codex_home: &Path) -> PathBuf {
+    codex_home.join(BACKUP_SUBDIR).join(BACKUP_METADATA)
+}
+
+/// Copies the currently running executable into the backup directory so the
+/// update that is about to run can be undone with `--rollback`.
+pub(crate) fn backup_current_binary(codex_home: &Path) -> anyhow::Result<()> {
+    let current = std::env::current_exe()?;
+    let backup = backup_binary_path(codex_home);
+    if let Some(parent) = backup.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::copy(&current, &backup)?;
+
+    let updated_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let metadata = serde_json::json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "updated_at": updated_at,
+    });
+    fs::write(
+        backup_metadata_path(codex_home),
+        serde_json::to_string_pretty(&metadata)?,
+    )?;
+    Ok(())
+}
+
+/// Restores the backed-up binary over the currently running executable.
+/// Returns the version the backup was taken from, when recorded.
+pub(crate) fn rollback(codex_home: &Path) -> anyhow::Result<Option<String>> {
+    let backup = backup_binary_path(codex_home);
+    if !backup.exists() {
+        anyhow::bail!(
+            "no previous Codex binary to roll back to; backups are kept only after `codex self-update` runs"
+        );
+    }
+
+    let current = std::env::current_exe()?;
+    // Stage the copy next to the destination so the final step is a rename on
+    // the same filesystem, never a partially written executable.
+    let staged = current.with_extension("rollback-staged");
+    fs::copy(&backup, &staged)?;
+    #[cfg(windows)]
+    {
+        // Windows refuses to overwrite a running executable, but renaming it
+        // aside is allowed.
+        let _ = fs::rename(&current, current.with_extension("exe.old"));
+    }
+    fs::rename(&staged, &current)?;
+
+    let version = previous_version(codex_home);
+    let _ = fs::remove_file(backup_metadata_path(codex_home));
+    Ok(version)
+}
+
+/// Returns a rollback hint when a backup exists and the last self-update ran
+/// recently enough that a fatal exit is plausibly caused by the new release.
+pub(crate) fn recent_update_rollback_hint(codex_home: &Path) -> Option<String> {
+    if !backup_binary_path(codex_home).exists() {
+        return None;
+    }
+    let metadata: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(backup_metadata_path(codex_home)).ok()?).ok()?;
+    let updated_at = UNIX_EPOCH + Duration::from_secs(metadata.get("updated_at")?.as_u64()?);
+    let since_update = SystemTime::now().duration_since(updated_at).ok()?;
+    if since_update > CRASH_HINT_WINDOW {
+        return None;
+    }
+    let version = metadata
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or("the previous version");
+    Some(format!(
+        "Codex was updated {} minute(s) ago. If this crash started with the new release, run `codex self-update --rollback` to restore {version}.",
+        since_update.as_secs() / 60
+    ))
+}
+
+fn previous_version(codex_home: &Path) -> Option<String> {
+    let metadata: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(backup_metadata_path(codex_home)).ok()?).ok()?;
+    Some(metadata.get("version")?.as_str()?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn hint_requires_a_backup_and_a_recent_update() {
+        let home = tempfile::tempdir().expect("home");
+        assert_eq!(recent_update_rollback_hint(home.path()), None);
+
+        backup_current_binary(home.path()).expect("backup");
+        let hint = recent_update_rollback_hint(home.path()).expect("hint");
+        assert!(hint.contains("self-update --rollback"), "hint: {hint}");
+    }
+
+    #[test]
+    fn stale_backups_do_not_suggest_rollback() {
+        let home = tempfile::tempdir().expect("home");
+        backup_current_binary(home.path()).expect("backup");
+
+        let metadata = serde_json::json!({
+            "version": "0.0.1",
+            "updated_at": 0,
+        });
+        fs::write(backup_metadata_path(home.path()), metadata.to_string()).expect("write metadata");
+
+        assert_eq!(recent_update_rollback_hint(home.path()), None);
+    }
+}
@@ -0,0 +1,57 @@
+//! `codex themes import`: convert third-party color schemes into Codex
+//! theme definitions.
+//!
+//! Accepts base16 YAML, iTerm2 `.itermcolors`, and Alacritty TOML schemes
+//! (detected from the file extension) and writes the converted theme to
+//! `{CODEX_HOME}/themes/{name}.toml`, where the TUI theme picker and
+//! `tui.theme` pick it up like any other custom theme. The format-specific
+//! parsing and role mapping live in [`codex_core::theme_import`].
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_core::config::Config;
+use codex_core::theme_import::SchemeFormat;
+use codex_core::theme_import::parse_scheme;
+use codex_core::theme_import::scheme_to_theme_toml;
+use codex_core::theme_import::theme_file_name;
+
+pub async fn run_themes_import(
+    config: Config,
+    file: PathBuf,
+    name: Option<String>,
+    force: bool,
+) -> Result<()> {
+    let Some(format) = SchemeFormat::detect(&file) else {
+        bail!(
+            "unrecognized scheme extension for {}; expected .yaml/.yml (base16), \
+             .itermcolors (iTerm2), or .toml (Alacritty)",
+            file.display()
+        );
+    };
+    let raw =
+        fs::read_to_string(&file).with_context(|| format!("failed to read {}", file.display()))?;
+    let scheme = parse_scheme(&raw, format)
+        .with_context(|| format!("failed to parse {}", file.display()))?;
+
+    let name = name.unwrap_or_else(|| theme_file_name(&scheme, &file));
+    let themes_dir = config.codex_home.join("themes");
+    fs::create_dir_all(&themes_dir)
+        .with_context(|| format!("failed to create {}", themes_dir.display()))?;
+    let out = themes_dir.join(format!("{name}.toml"));
+    if out.exists() && !force {
+        bail!(
+            "{} already exists; pass --force to overwrite it",
+            out.display()
+        );
+    }
+    let toml = scheme_to_theme_toml(&scheme, &name)?;
+    fs::write(&out, toml).with_context(|| format!("failed to write {}", out.display()))?;
+
+    println!("Imported theme \"{name}\" to {}", out.display());
+    println!("Select it with /theme or set `tui.theme = \"{name}\"` in config.toml.");
+    Ok(())
+}
@@ -0,0 +1,220 @@
+//! `codex watch`: re-run a task through the headless agent when files change.
+//!
+//! `codex watch --task "fix failing tests" --trigger "tests/**"` watches the
+//! working directory and re-runs the task through `codex exec` (spawned as a
+//! subprocess so every run gets a fresh session, like `codex eval`) whenever
+//! a changed path matches a trigger glob. Change bursts are debounced, the
+//! agent's own edits during a run are drained before watching resumes, and
+//! `--max-runs` caps automatic iterations so a run that keeps touching
+//! trigger paths cannot loop forever. A persistent status line on stderr
+//! shows what the watcher is waiting for.
+
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_core::file_watcher::FileWatcher;
+use codex_core::file_watcher::ThrottledWatchReceiver;
+use codex_core::file_watcher::WatchPath;
+use globset::Glob;
+use globset::GlobSet;
+use globset::GlobSetBuilder;
+
+pub async fn run_watch(
+    task: String,
+    triggers: Vec<String>,
+    debounce_ms: u64,
+    max_runs: usize,
+    exec_args: Vec<String>,
+) -> Result<()> {
+    if task.trim().is_empty() {
+        bail!("--task must not be empty");
+    }
+    if max_runs == 0 {
+        bail!("--max-runs must be at least 1");
+    }
+    let trigger_set = build_trigger_set(&triggers)?;
+    let cwd = std::env::current_dir().context("failed to resolve working directory")?;
+    let debounce = Duration::from_millis(debounce_ms.max(1));
+
+    let file_watcher = Arc::new(FileWatcher::new().context("failed to start file watcher")?);
+    let (subscriber, rx) = file_watcher.add_subscriber();
+    let _registration = subscriber.register_paths(vec![WatchPath {
+        path: cwd.clone(),
+        recursive: true,
+    }]);
+    let mut rx = ThrottledWatchReceiver::new(rx, debounce);
+
+    let codex_bin = std::env::current_exe()?;
+    // Run once up front so the watcher starts from a known state, then once
+    // per debounced batch of matching changes until the cap is reached.
+    for run in 1..=max_runs {
+        run_task(&codex_bin, &task, &cwd, &exec_args, run, max_runs).await;
+        // The agent's own edits arrive as watch events too; drain everything
+        // recorded during the run plus the debounce tail so only new saves
+        // trigger the next iteration.
+        drain_pending(&mut rx, debounce).await;
+        if run == max_runs {
+            break;
+        }
+        status(&format!(
+            "run {run}/{max_runs} done • waiting for changes matching {}",
+            describe_triggers(&triggers)
+        ));
+        loop {
+            let Some(event) = rx.recv().await else {
+                clear_status();
+                bail!("file watcher stopped unexpectedly");
+            };
+            if let Some(path) = first_matching_path(&trigger_set, &cwd, &event.paths) {
+                status(&format!("change in {} • re-running task", path.display()));
+                break;
+            }
+        }
+    }
+    clear_status();
+    eprintln!("[watch] reached --max-runs ({max_runs}); exiting");
+    Ok(())
+}
+
+/// Runs the task once through `codex exec`, streaming the agent's output.
+/// Run failures are reported but do not stop the watcher: the next change
+/// gets another attempt.
+async fn run_task(
+    codex_bin: &Path,
+    task: &str,
+    cwd: &Path,
+    exec_args: &[String],
+    run: usize,
+    max_runs: usize,
+) {
+    clear_status();
+    eprintln!("[watch] run {run}/{max_runs}: {task}");
+    let started = Instant::now();
+    let mut command = tokio::process::Command::new(codex_bin);
+    command.arg("exec").arg("--cd").arg(cwd);
+    command.args(exec_args);
+    command.arg(task);
+    let status = command.stdin(Stdio::null()).status().await;
+    match status {
+        Ok(status) if status.success() => {
+            eprintln!(
+                "[watch] run {run} finished in {elapsed}s",
+                elapsed = started.elapsed().as_secs()
+            );
+        }
+        Ok(status) => eprintln!("[watch] run {run} failed: codex exec exited with {status}"),
+        Err(err) => eprintln!("[watch] run {run} failed to spawn codex exec: {err}"),
+    }
+}
+
+/// Discards watch events until the receiver has been quiet for one debounce
+/// interval.
+async fn drain_pending(rx: &mut ThrottledWatchReceiver, debounce: Duration) {
+    while let Ok(Some(_)) = tokio::time::timeout(debounce, rx.recv()).await {}
+}
+
+/// Builds the trigger matcher. Without `--trigger`, any change outside
+/// `.git/` re-runs the task.
+fn build_trigger_set(triggers: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    if triggers.is_empty() {
+        builder.add(Glob::new("**").context("failed to build default trigger glob")?);
+    }
+    for trigger in triggers {
+        builder.add(
+            Glob::new(trigger).with_context(|| format!("invalid --trigger glob `{trigger}`"))?,
+        );
+    }
+    builder.build().context("failed to build trigger globs")
+}
+
+/// Returns the first changed path that matches a trigger glob, judged
+/// relative to the watch root. Changes under `.git/` never trigger a run so
+/// the agent's own commits cannot re-trigger it.
+fn first_matching_path<'a>(
+    triggers: &GlobSet,
+    cwd: &Path,
+    paths: &'a [PathBuf],
+) -> Option<&'a PathBuf> {
+    paths.iter().find(|path| {
+        let relative = path.strip_prefix(cwd).unwrap_or(path);
+        if relative
+            .components()
+            .next()
+            .is_some_and(|component| component.as_os_str() == ".git")
+        {
+            return false;
+        }
+        triggers.is_match(relative)
+    })
+}
+
+fn describe_triggers(triggers: &[String]) -> String {
+    if triggers.is_empty() {
+        "any file".to_string()
+    } else {
+        triggers.join(", ")
+    }
+}
+
+/// Rewrites the persistent status line in place on stderr.
+fn status(message: &str) {
+    eprint!("\r\x1b[2K[watch] {message}");
+    let _ = std::io::stderr().flush();
+}
+
+/// Clears the status line so regular output starts on a clean row.
+fn clear_status() {
+    eprint!("\r\x1b[2K");
+    let _ = std::io::stderr().flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn trigger_globs_match_relative_to_the_watch_root() {
+        let triggers = build_trigger_set(&["tests/**".to_string()]).expect("globs");
+        let cwd = Path::new("/repo");
+        let paths = vec![
+            PathBuf::from("/repo/src/lib.rs"),
+            PathBuf::from("/repo/tests/it.rs"),
+        ];
+        assert_eq!(
+            first_matching_path(&triggers, cwd, &paths),
+            Some(&PathBuf::from("/repo/tests/it.rs"))
+        );
+    }
+
+    #[test]
+    fn git_internals_never_trigger_a_run() {
+        let triggers = build_trigger_set(&[]).expect("default globs");
+        let cwd = Path::new("/repo");
+        let git_only = vec![PathBuf::from("/repo/.git/index")];
+        assert_eq!(first_matching_path(&triggers, cwd, &git_only), None);
+        let with_source = vec![
+            PathBuf::from("/repo/.git/index"),
+            PathBuf::from("/repo/README.md"),
+        ];
+        assert_eq!(
+            first_matching_path(&triggers, cwd, &with_source),
+            Some(&PathBuf::from("/repo/README.md"))
+        );
+    }
+
+    #[test]
+    fn invalid_trigger_glob_is_rejected() {
+        let err = build_trigger_set(&["tests/[".to_string()]).expect_err("invalid glob");
+        assert!(err.to_string().contains("tests/["));
+    }
+}
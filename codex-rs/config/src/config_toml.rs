@@ -3,6 +3,7 @@
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::path::Path;
+use std::path::PathBuf;
 
 use crate::permissions_toml::PermissionsToml;
 use crate::profile_toml::ConfigProfile;
@@ -46,6 +47,7 @@ use codex_protocol::config_types::WebSearchMode;
 use codex_protocol::config_types::WebSearchToolConfig;
 use codex_protocol::config_types::WindowsSandboxLevel;
 use codex_protocol::openai_models::ReasoningEffort;
+use codex_protocol::permissions::NetworkSandboxPolicy;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::ReadOnlyAccess;
 use codex_protocol::protocol::SandboxPolicy;
@@ -141,6 +143,16 @@ pub struct ConfigToml {
     /// sanctioned by Codex will likely degrade model performance.
     pub model_instructions_file: Option<AbsolutePathBuf>,
 
+    /// Extra text appended verbatim after the model instructions. Unlike
+    /// `model_instructions_file` this does not replace the built-in
+    /// instructions, so it is the safe place for small additions such as
+    /// house style or tone rules.
+    pub system_prompt_extra: Option<String>,
+
+    /// Like `system_prompt_extra`, but read from a file. Ignored when the
+    /// inline form is also set.
+    pub system_prompt_extra_file: Option<AbsolutePathBuf>,
+
     /// Compact prompt used for history compaction.
     pub compact_prompt: Option<String>,
 
@@ -234,6 +246,12 @@ pub struct ConfigToml {
     /// Defaults to `$CODEX_HOME/log`.
     pub log_dir: Option<AbsolutePathBuf>,
 
+    /// Directory that session rollouts are mirrored to and imported from, so
+    /// sessions recorded on one machine appear in the resume picker on
+    /// another when the directory is synced (e.g. via Syncthing or a network
+    /// share). Unset disables mirroring.
+    pub sessions_sync_dir: Option<AbsolutePathBuf>,
+
     /// Optional URI-based file opener. If set, citations to files in the model
     /// output will be hyperlinked using the specified URI scheme.
     pub file_opener: Option<UriBasedFileOpener>,
@@ -304,6 +322,26 @@ pub struct ConfigToml {
     pub experimental_realtime_start_instructions: Option<String>,
     pub projects: Option<HashMap<String, ProjectConfig>>,
 
+    /// Named `[terminal_profiles.<name>]` entries describing which shell runs
+    /// generated commands.
+    pub terminal_profiles: Option<HashMap<String, TerminalProfileToml>>,
+
+    /// Name of the terminal profile to use by default; projects can override
+    /// it via `terminal_profile` in their `[projects."..."]` entry.
+    pub terminal_profile: Option<String>,
+
+    /// Named `[container_sandboxes.<name>]` entries describing a container
+    /// image sandboxed commands run in instead of the platform sandbox.
+    pub container_sandboxes: Option<HashMap<String, ContainerSandboxToml>>,
+
+    /// Name of the container sandbox to use by default; projects can override
+    /// it via `container_sandbox` in their `[projects."..."]` entry.
+    pub container_sandbox: Option<String>,
+
+    /// Named `[approval_presets.<name>]` entries offered alongside the
+    /// built-in permission presets in the `/permissions` picker.
+    pub approval_presets: Option<HashMap<String, ApprovalPresetToml>>,
+
     /// Controls the web search tool mode: disabled, cached, or live.
     pub web_search: Option<WebSearchMode>,
 
@@ -343,6 +381,20 @@ pub struct ConfigToml {
     #[serde(default)]
     pub ghost_snapshot: Option<GhostSnapshotToml>,
 
+    /// Session budget guardrails (max cost, tokens, turns, wall clock).
+    pub limits: Option<LimitsToml>,
+
+    /// Retention policy for stored sessions (max count, age, disk usage).
+    pub storage: Option<StorageToml>,
+
+    /// Named conversation templates keyed by template name, selected at
+    /// startup with `codex new --template <name>`.
+    #[serde(default)]
+    pub templates: HashMap<String, TemplateToml>,
+
+    /// User-defined WASM tools keyed by tool name; see [`WasmToolToml`].
+    pub wasm_tools: Option<HashMap<String, WasmToolToml>>,
+
     /// Markers used to detect the project root when searching parent
     /// directories for `.codex` folders. Defaults to [".git"] when unset.
     #[serde(default)]
@@ -353,6 +405,9 @@ pub struct ConfigToml {
     /// Defaults to `true`.
     pub check_for_update_on_startup: Option<bool>,
 
+    /// Release channel for update checks: `stable` (default) or `beta`.
+    pub update_channel: Option<UpdateChannel>,
+
     /// When true, disables burst-paste detection for typed input entirely.
     /// All characters are inserted as they are received, and no buffering
     /// or placeholder replacement will occur for fast keypress bursts.
@@ -420,10 +475,22 @@ impl From<ConfigToml> for UserSavedConfig {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
 #[schemars(deny_unknown_fields)]
 pub struct ProjectConfig {
     pub trust_level: Option<TrustLevel>,
+    /// Default model for sessions started in this project.
+    pub model: Option<String>,
+    /// Default reasoning effort for sessions started in this project.
+    pub model_reasoning_effort: Option<ReasoningEffort>,
+    /// Default sandbox mode for sessions started in this project.
+    pub sandbox_mode: Option<SandboxMode>,
+    /// Default approval policy for sessions started in this project.
+    pub approval_policy: Option<AskForApproval>,
+    /// Named terminal profile for commands run in this project.
+    pub terminal_profile: Option<String>,
+    /// Named container sandbox for commands run in this project.
+    pub container_sandbox: Option<String>,
 }
 
 impl ProjectConfig {
@@ -436,6 +503,73 @@ impl ProjectConfig {
     }
 }
 
+/// A named `[terminal_profiles.<name>]` entry: which shell runs generated
+/// commands and whether rc files are sourced via a login shell. When no
+/// profile is selected, the user's login shell is detected and used.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct TerminalProfileToml {
+    /// Shell binary name (`bash`, `zsh`, `fish`, `pwsh`) or an absolute path.
+    pub shell: Option<PathBuf>,
+    /// Whether commands run under a login shell (sourcing rc/profile files).
+    /// Overrides the top-level `allow_login_shell`.
+    pub login: Option<bool>,
+}
+
+/// A named `[container_sandboxes.<name>]` entry: sandboxed commands run via
+/// `docker run`/`podman run` inside this image instead of under the native
+/// seatbelt/landlock sandboxes.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ContainerSandboxToml {
+    /// Image reference passed to the engine, e.g. `ubuntu:24.04`.
+    pub image: String,
+    /// Container engine to invoke; defaults to `docker`.
+    pub engine: Option<ContainerEngineToml>,
+    /// Bind mounts mapping host paths into the container. When omitted, the
+    /// command's working directory is mounted read-write at the same path.
+    pub mounts: Option<Vec<ContainerMountToml>>,
+    /// Whether the container gets network access; defaults to `restricted`
+    /// (the engine's `--network none`).
+    pub network: Option<NetworkSandboxPolicy>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerEngineToml {
+    #[default]
+    Docker,
+    Podman,
+}
+
+/// A named `[approval_presets.<name>]` entry: a custom permissions preset
+/// pairing an approval policy with a sandbox mode, offered alongside the
+/// built-in presets.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ApprovalPresetToml {
+    /// Display label shown in the picker; defaults to the entry name.
+    pub label: Option<String>,
+    /// Short description shown next to the label.
+    pub description: Option<String>,
+    /// Approval policy the preset switches to.
+    pub approval_policy: AskForApproval,
+    /// Sandbox mode the preset switches to.
+    pub sandbox_mode: SandboxMode,
+}
+
+/// One bind mount in a `[container_sandboxes.<name>]` entry.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ContainerMountToml {
+    /// Host path to expose inside the container.
+    pub host: PathBuf,
+    /// Path the mount appears at in the container; defaults to `host`.
+    pub container: Option<String>,
+    /// Mount read-only instead of read-write.
+    pub readonly: Option<bool>,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct RealtimeAudioConfig {
     pub microphone: Option<String>,
@@ -580,6 +714,98 @@ impl From<ToolsToml> for Tools {
     }
 }
 
+/// Session budget guardrails. Each limit is independent: the session warns as
+/// usage crosses 80% of a limit and refuses to start new turns once one is
+/// exhausted.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct LimitsToml {
+    /// Stop once the estimated session cost reaches this many US dollars.
+    /// Requires `cost_per_1m_tokens_usd` so spend can be estimated.
+    pub max_cost_usd: Option<f64>,
+    /// Blended price (USD per million tokens) used to estimate session cost
+    /// from cumulative token usage.
+    pub cost_per_1m_tokens_usd: Option<f64>,
+    /// Stop once cumulative token usage reaches this many tokens.
+    pub max_total_tokens: Option<i64>,
+    /// Stop after this many user turns.
+    pub max_turns: Option<i64>,
+    /// Stop after this much wall-clock time, in minutes.
+    pub max_wall_clock_minutes: Option<i64>,
+}
+
+/// Retention policy for on-disk session storage under `[storage]`. Each
+/// bound is independent and unset bounds impose no limit; `codex gc` (and
+/// startup, when `gc_on_startup` is set) prunes the oldest rollouts first.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct StorageToml {
+    /// Keep at most this many session rollouts.
+    pub max_sessions: Option<usize>,
+    /// Delete rollouts whose last activity is older than this many days.
+    pub max_age_days: Option<u64>,
+    /// Keep total rollout size under this many megabytes.
+    pub max_disk_mb: Option<u64>,
+    /// Run garbage collection automatically at startup.
+    pub gc_on_startup: Option<bool>,
+}
+
+/// One user-defined WASM tool declared under `[wasm_tools.<name>]`.
+///
+/// The module is a WASI binary executed by `codex-core`'s `wasm_tools`
+/// runtime. It receives the model's JSON arguments on stdin and replies on
+/// stdout. Capabilities are opt-in: the module sees only the preopened
+/// directories and environment variables granted here, and WASI gives it no
+/// network access at all.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct WasmToolToml {
+    /// Path to the compiled WASI module (`.wasm`).
+    #[serde(default)]
+    pub module: PathBuf,
+
+    /// Tool description shown to the model.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Directories the module may read (preopened read-only).
+    #[serde(default)]
+    pub fs_read: Vec<PathBuf>,
+
+    /// Directories the module may read and write.
+    #[serde(default)]
+    pub fs_write: Vec<PathBuf>,
+
+    /// Environment variables forwarded from the host.
+    #[serde(default)]
+    pub env: Vec<String>,
+
+    /// Fuel budget bounding how long the module may compute. Defaults to
+    /// the runtime's built-in budget.
+    #[serde(default)]
+    pub max_fuel: Option<u64>,
+}
+
+/// A conversation starter defined under `[templates.<name>]`. A template
+/// seeds a new session with extra instructions, pinned files, enabled skills,
+/// and an initial task checklist.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct TemplateToml {
+    /// Extra instructions appended to the session's user instructions.
+    pub instructions: Option<String>,
+    /// Files pinned to every turn, resolved against the session working
+    /// directory.
+    #[serde(default)]
+    pub pinned_files: Vec<PathBuf>,
+    /// Skill names enabled for the session.
+    #[serde(default)]
+    pub skills: Vec<String>,
+    /// Task checklist submitted as the start of the first turn.
+    #[serde(default)]
+    pub checklist: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
 #[schemars(deny_unknown_fields)]
 pub struct GhostSnapshotToml {
@@ -604,11 +830,14 @@ impl ConfigToml {
         active_project: Option<&ProjectConfig>,
         sandbox_policy_constraint: Option<&crate::Constrained<SandboxPolicy>>,
     ) -> SandboxPolicy {
+        let project_sandbox_mode = active_project.and_then(|p| p.sandbox_mode);
         let sandbox_mode_was_explicit = sandbox_mode_override.is_some()
             || profile_sandbox_mode.is_some()
+            || project_sandbox_mode.is_some()
             || self.sandbox_mode.is_some();
         let resolved_sandbox_mode = sandbox_mode_override
             .or(profile_sandbox_mode)
+            .or(project_sandbox_mode)
             .or(self.sandbox_mode)
             .or(if sandbox_mode_was_explicit {
                 None
@@ -725,6 +954,62 @@ impl ConfigToml {
             None => Ok(ConfigProfile::default()),
         }
     }
+
+    /// Resolves the terminal profile selected for this session: the active
+    /// project's `terminal_profile` wins over the top-level one. Referencing
+    /// an undefined profile is an error.
+    pub fn get_terminal_profile(
+        &self,
+        active_project: Option<&ProjectConfig>,
+    ) -> Result<Option<TerminalProfileToml>, std::io::Error> {
+        let name = active_project
+            .and_then(|project| project.terminal_profile.clone())
+            .or_else(|| self.terminal_profile.clone());
+
+        match name {
+            Some(name) => self
+                .terminal_profiles
+                .as_ref()
+                .and_then(|profiles| profiles.get(name.as_str()))
+                .cloned()
+                .map(Some)
+                .ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::NotFound,
+                        format!("terminal profile `{name}` not found"),
+                    )
+                }),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolves the container sandbox selected for this session: the active
+    /// project's `container_sandbox` wins over the top-level one. Referencing
+    /// an undefined entry is an error.
+    pub fn get_container_sandbox(
+        &self,
+        active_project: Option<&ProjectConfig>,
+    ) -> Result<Option<ContainerSandboxToml>, std::io::Error> {
+        let name = active_project
+            .and_then(|project| project.container_sandbox.clone())
+            .or_else(|| self.container_sandbox.clone());
+
+        match name {
+            Some(name) => self
+                .container_sandboxes
+                .as_ref()
+                .and_then(|sandboxes| sandboxes.get(name.as_str()))
+                .cloned()
+                .map(Some)
+                .ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::NotFound,
+                        format!("container sandbox `{name}` not found"),
+                    )
+                }),
+            None => Ok(None),
+        }
+    }
 }
 
 /// Canonicalize the path and convert it to a string to be used as a key in the
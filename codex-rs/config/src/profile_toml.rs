@@ -40,6 +40,11 @@ pub struct ConfigProfile {
     pub chatgpt_base_url: Option<String>,
     /// Optional path to a file containing model instructions.
     pub model_instructions_file: Option<AbsolutePathBuf>,
+    /// Extra text appended verbatim after the model instructions.
+    pub system_prompt_extra: Option<String>,
+    /// Like `system_prompt_extra`, but read from a file. Ignored when the
+    /// inline form is also set.
+    pub system_prompt_extra_file: Option<AbsolutePathBuf>,
     pub js_repl_node_path: Option<AbsolutePathBuf>,
     /// Ordered list of directories to search for Node modules in `js_repl`.
     pub js_repl_node_module_dirs: Option<Vec<AbsolutePathBuf>>,
@@ -532,6 +532,143 @@ pub struct ModelAvailabilityNuxConfig {
     pub shown_count: HashMap<String, u32>,
 }
 
+/// Limits on how much committed conversation history the TUI keeps resident
+/// in memory. Cells beyond the budget are spilled to a per-session spool file
+/// and rehydrated on demand. Leaving both limits unset keeps all history in
+/// memory (the default behavior).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct HistoryBudgetToml {
+    /// Maximum number of fully rendered history cells kept in memory.
+    #[serde(default)]
+    pub max_resident_cells: Option<usize>,
+
+    /// Approximate cap, in bytes, on the rendered text kept in memory for
+    /// committed history cells.
+    #[serde(default)]
+    pub max_resident_bytes: Option<u64>,
+}
+
+/// One external history-cell renderer registered under `[[tui.cell_renderers]]`.
+///
+/// The renderer command is run when a matching MCP tool call completes; it
+/// receives the tool's text output on stdin and its stdout replaces the
+/// default transcript rendering for that output.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct CellRendererToml {
+    /// Display name of the renderer, used in logs and error messages.
+    #[serde(default)]
+    pub name: String,
+
+    /// Command and arguments to run, e.g. `["junit2text"]`.
+    #[serde(default)]
+    pub command: Vec<String>,
+
+    /// Tool names this renderer applies to, either bare (`"query"`) or
+    /// qualified with the MCP server name (`"db.query"`).
+    #[serde(default)]
+    pub tools: Vec<String>,
+}
+
+/// Colorblind-safe palette selection under `[tui.accessibility]`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ColorblindPalette {
+    /// Red-green deficiency (most common); additions render blue, deletions
+    /// orange.
+    Deuteranopia,
+    /// Red-green deficiency; same blue/orange pairing as deuteranopia.
+    Protanopia,
+    /// Blue-yellow deficiency; additions render teal, deletions magenta.
+    Tritanopia,
+}
+
+/// `[tui.accessibility]` settings.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct AccessibilityToml {
+    /// Minimum WCAG contrast ratio (1.0–21.0) enforced between rendered
+    /// theme colors and the detected terminal background. When set, every
+    /// resolved color — including custom `.tmTheme` palettes — is nudged
+    /// toward white or black until it meets this ratio.
+    #[serde(default)]
+    pub min_contrast: Option<f64>,
+
+    /// Colorblind-safe palette for diff rendering; see [`ColorblindPalette`].
+    #[serde(default)]
+    pub colorblind: Option<ColorblindPalette>,
+}
+
+/// How the TUI emits colors, from `[tui] color_mode`.
+///
+/// Overrides terminal capability detection, for terminals that lie about
+/// truecolor support (or multiplexers that strip it).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    /// Emit 24-bit RGB escape sequences unconditionally.
+    Truecolor,
+    /// Quantize every color to the xterm 256-color palette.
+    #[serde(rename = "256")]
+    Ansi256,
+    /// Quantize every color to the 16 basic ANSI colors.
+    #[serde(rename = "16")]
+    Ansi16,
+    /// Detect capability from the environment (the default).
+    #[default]
+    Auto,
+}
+
+/// One `[tui.syntax]` entry: style overrides for a TextMate scope selector,
+/// layered on top of whichever syntax theme is active. Mirrors the `[styles]`
+/// schema of custom TOML theme files under `$CODEX_HOME/themes/`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct SyntaxStyleToml {
+    /// Foreground color as `"#RRGGBB"`.
+    #[serde(default)]
+    pub fg: Option<String>,
+
+    /// Background color as `"#RRGGBB"`.
+    #[serde(default)]
+    pub bg: Option<String>,
+
+    #[serde(default)]
+    pub bold: bool,
+
+    #[serde(default)]
+    pub italic: bool,
+
+    #[serde(default)]
+    pub underline: bool,
+}
+
+/// How transcript code blocks treat lines wider than the terminal.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum CodeBlockOverflow {
+    /// Hard-wrap long lines with no marker, keeping the exact text intact
+    /// for copy/paste (the default).
+    #[default]
+    Wrap,
+    /// Wrap long lines at the code's own indentation and mark each
+    /// continuation row with a dim glyph.
+    Indicator,
+}
+
+/// Release channel consulted by the startup update check and `codex
+/// self-update`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    /// Track the latest stable GitHub release (the default).
+    #[default]
+    Stable,
+    /// Track the newest release including pre-releases.
+    Beta,
+}
+
 /// Collection of settings that are specific to the TUI.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
 #[schemars(deny_unknown_fields)]
@@ -544,6 +681,14 @@ pub struct Tui {
     #[serde(default = "default_true")]
     pub animations: bool,
 
+    /// Welcome screen animation style (kebab-case): one of the bundled
+    /// variants (`default`, `codex`, `openai`, `blocks`, `dots`, `hash`,
+    /// `hbars`, `vbars`, `shapes`, `slug`), or `random` to pick one per
+    /// launch. `none` disables just the welcome animation for slow
+    /// terminals without turning off the other `animations`.
+    #[serde(default)]
+    pub welcome_cycle_animation: Option<String>,
+
     /// Show startup tooltips in the TUI welcome screen.
     /// Defaults to `true`.
     #[serde(default = "default_true")]
@@ -581,9 +726,270 @@ pub struct Tui {
     #[serde(default)]
     pub theme: Option<String>,
 
+    /// Per-scope syntax style overrides layered onto the active theme, keyed
+    /// by TextMate scope selector (e.g. `keyword`, `"markup.inserted"`); see
+    /// [`SyntaxStyleToml`]. Applies to every theme, bundled or custom.
+    #[serde(default)]
+    pub syntax: BTreeMap<String, SyntaxStyleToml>,
+
+    /// How colors are emitted (`truecolor`, `256`, `16`, or `auto`); see
+    /// [`ColorMode`]. Defaults to `auto` (capability detection).
+    #[serde(default)]
+    pub color_mode: ColorMode,
+
     /// Startup tooltip availability NUX state persisted by the TUI.
     #[serde(default)]
     pub model_availability_nux: ModelAvailabilityNuxConfig,
+
+    /// Memory budget for committed history cells; older cells are spilled to
+    /// disk and rehydrated when scrolled back into view.
+    #[serde(default)]
+    pub history_budget: Option<HistoryBudgetToml>,
+
+    /// Pause between queued `/batch` tasks until the user confirms with
+    /// `/batch continue`. Defaults to `false` (tasks run back to back).
+    #[serde(default)]
+    pub batch_pause_between_tasks: bool,
+
+    /// Default collapsed state for tool-call cells in the transcript, keyed
+    /// by tool type (`exec`, `mcp`, `patch`). Collapsed cells render only
+    /// their header line; `Ctrl+X` toggles all tool calls at runtime and the
+    /// full output stays available in the transcript overlay (`Ctrl+T`).
+    #[serde(default)]
+    pub collapsed_tool_calls: HashMap<String, bool>,
+
+    /// Custom key bindings; see [`KeybindingsToml`].
+    #[serde(default)]
+    pub keybindings: Option<KeybindingsToml>,
+
+    /// Mouse input; see [`MouseToml`]. When the section is absent the TUI
+    /// leaves the mouse to the terminal (text selection, native scrollback).
+    #[serde(default)]
+    pub mouse: Option<MouseToml>,
+
+    /// Fold command output by exit status: successful commands collapse to
+    /// the last few output lines while failed commands render their output
+    /// in full. Off by default; see [`OutputFoldingToml`].
+    #[serde(default)]
+    pub output_folding: OutputFoldingToml,
+
+    /// Spinner shown in the status row while the agent is working.
+    ///
+    /// Accepts a built-in name (`dots`, `line`, `moon`, or `none` for a
+    /// static reduced-motion marker) or a custom list of animation frames.
+    /// When unset, the TUI keeps its default shimmering bullet.
+    #[serde(default)]
+    pub spinner: Option<SpinnerToml>,
+
+    /// Seconds a turn must run before the status row shows the interrupt
+    /// hint next to the elapsed time. Defaults to `0` (shown immediately).
+    #[serde(default)]
+    pub interrupt_hint_after_seconds: Option<u64>,
+
+    /// Terminal bell rung on turn completion and approval requests,
+    /// complementing desktop notifications for visible-but-unfocused
+    /// terminals.
+    #[serde(default)]
+    pub bell: BellToml,
+
+    /// Force ASCII replacements for box-drawing, selection-marker, and
+    /// spinner glyphs. When unset, the TUI probes the locale for UTF-8
+    /// support at startup.
+    #[serde(default)]
+    pub ascii_only: Option<bool>,
+
+    /// Language for user-facing TUI strings (e.g. `"es"`). When unset, the
+    /// locale environment decides; unknown languages fall back to English.
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// External renderers for custom tool output; see [`CellRendererToml`].
+    #[serde(default)]
+    pub cell_renderers: Vec<CellRendererToml>,
+
+    /// Accessibility options; see [`AccessibilityToml`].
+    #[serde(default)]
+    pub accessibility: AccessibilityToml,
+
+    /// Assumed terminal background color as `"#RRGGBB"`, for terminals that
+    /// refuse OSC 11 background queries. Consumed by theme resolution and
+    /// contrast adaptation in place of the detected background.
+    #[serde(default)]
+    pub assume_background: Option<String>,
+
+    /// Show one-time contextual tips for undiscovered features. Defaults to
+    /// true; each tip is shown at most once (tracked under `[notice]`).
+    #[serde(default)]
+    pub tips: Option<bool>,
+
+    /// How transcript code blocks treat lines wider than the terminal; see
+    /// [`CodeBlockOverflow`].
+    #[serde(default)]
+    pub code_block_overflow: Option<CodeBlockOverflow>,
+}
+
+/// `[tui.output_folding]` settings: collapse command output in the transcript
+/// based on exit status; see [`Tui::output_folding`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct OutputFoldingToml {
+    /// Global toggle. Folding is off unless this is set to `true`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Trailing output lines kept for commands that exit 0. Defaults to the
+    /// TUI's standard tool-call preview length (5).
+    #[serde(default)]
+    pub success_tail_lines: Option<usize>,
+
+    /// Per-tool overrides keyed by tool type (`exec`, `user_shell`,
+    /// `unified_exec`). A `false` entry keeps that tool's default rendering.
+    #[serde(default)]
+    pub tools: HashMap<String, bool>,
+}
+
+/// `[tui.bell]` settings: how to ring on completion and approval requests.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct BellToml {
+    /// Bell rung when a turn completes.
+    #[serde(default)]
+    pub on_complete: BellStyle,
+
+    /// Bell rung when an approval or elicitation is requested.
+    #[serde(default)]
+    pub on_approval: BellStyle,
+}
+
+/// How a `[tui.bell]` event is surfaced.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BellStyle {
+    /// No bell (the default).
+    #[default]
+    None,
+    /// Briefly flash the screen with reverse video.
+    Visual,
+    /// Emit the terminal BEL.
+    Audio,
+}
+
+/// Value of `[tui.keybindings]`: flat `action = "key spec"` entries rebind an
+/// action everywhere, while the per-context sub-tables (`[tui.keybindings.pager]`,
+/// `[tui.keybindings.composer]`) shadow an action only in that context. Key
+/// specs are one or two space-separated chords, e.g. `"ctrl+t"` or `"g g"`;
+/// the special spec `"none"` removes the action's bindings instead, which is
+/// how a default shortcut is disabled outright.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+pub struct KeybindingsToml {
+    /// Base table the overrides below are merged into; see
+    /// [`KeybindingPreset`]. This reserves `preset`, so it cannot name an
+    /// action.
+    #[serde(default)]
+    pub preset: KeybindingPreset,
+    /// When true, a conflicting binding (one sequence mapped to two different
+    /// actions) is a startup error instead of being auto-resolved with a
+    /// warning. This reserves `strict`, so it cannot name an action.
+    #[serde(default)]
+    pub strict: bool,
+    /// Window in milliseconds for double-press bindings — the same chord
+    /// twice, such as `"esc esc"` or `"ctrl+c ctrl+c"`. Defaults to 500.
+    /// This reserves `double_press_ms`, so it cannot name an action.
+    #[serde(default)]
+    pub double_press_ms: Option<u64>,
+    /// Per-terminal `[tui.keybindings.profiles.<name>]` blocks, merged after
+    /// the base table when their matchers match the running terminal. This
+    /// reserves `profiles`, so it cannot name an action.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, KeybindingProfileToml>,
+    /// Bindings active only while a pager overlay (transcript, diff) is open.
+    #[serde(default)]
+    pub pager: BTreeMap<String, String>,
+    /// Bindings active only while the composer has focus.
+    #[serde(default)]
+    pub composer: BTreeMap<String, String>,
+    /// Bindings applied in every context unless shadowed by a sub-table.
+    #[serde(flatten)]
+    pub global: BTreeMap<String, String>,
+}
+
+/// A `[tui.keybindings.profiles.<name>]` block: bindings applied on top of
+/// the base `[tui.keybindings]` table, but only when the running terminal
+/// matches. This is how one config adjusts for environments that steal
+/// default chords (tmux's `ctrl+b` prefix, VS Code's terminal) without
+/// maintaining separate config files. A block with no matchers never applies.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+pub struct KeybindingProfileToml {
+    /// Matched case-insensitively against `$TERM_PROGRAM`; a trailing `*`
+    /// matches any suffix (e.g. `"vscode"`, `"iTerm*"`).
+    #[serde(default)]
+    pub term_program: Option<String>,
+    /// Matched case-insensitively against `$TERM`; a trailing `*` matches any
+    /// suffix (e.g. `"tmux*"`, `"screen*"`). When both matchers are present,
+    /// both must match.
+    #[serde(default)]
+    pub term: Option<String>,
+    /// Bindings active only while a pager overlay is open.
+    #[serde(default)]
+    pub pager: BTreeMap<String, String>,
+    /// Bindings active only while the composer has focus.
+    #[serde(default)]
+    pub composer: BTreeMap<String, String>,
+    /// Bindings applied in every context unless shadowed by a sub-table.
+    #[serde(flatten)]
+    pub global: BTreeMap<String, String>,
+}
+
+/// Value of `[tui.mouse]`: opts the TUI into capturing mouse input. Capture
+/// takes the mouse away from the terminal emulator (native text selection and
+/// scrollback stop working over the viewport), which is why it is off unless
+/// this section is present. Flat entries remap a gesture to an action, e.g.
+/// `left-click = "none"`; gesture and action names are defined by the TUI's
+/// mouse map.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+pub struct MouseToml {
+    /// Master switch for mouse capture; defaults to true when the section is
+    /// present. This reserves `enabled`, so it cannot name a gesture.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Gesture -> action remappings applied on top of the default mouse map.
+    #[serde(flatten)]
+    pub bindings: BTreeMap<String, String>,
+}
+
+impl Default for MouseToml {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            bindings: BTreeMap::new(),
+        }
+    }
+}
+
+/// Value of `[tui.keybindings] preset`: which built-in binding table the
+/// user's overrides are merged into. Presets swap the whole default table at
+/// once, so getting vim- or emacs-flavored shortcuts does not require
+/// rebinding every action by hand.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum KeybindingPreset {
+    /// The standard `ctrl+<key>` bindings.
+    #[default]
+    Default,
+    /// Space-leader sequences (`space t`, `space e`, ...) in the vim style.
+    Vim,
+    /// `ctrl+x`-prefixed sequences in the emacs/readline style.
+    Emacs,
+}
+
+/// Value of `[tui] spinner`: a built-in spinner name or a custom frame list.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[serde(untagged)]
+pub enum SpinnerToml {
+    /// Built-in spinner name: `dots`, `line`, `moon`, or `none`.
+    Named(String),
+    /// Custom list of animation frames, cycled at a fixed interval.
+    Frames(Vec<String>),
 }
 
 const fn default_true() -> bool {
@@ -628,6 +1034,9 @@ pub struct Notice {
     /// Tracks scopes where external config migration prompts should be suppressed.
     #[serde(default)]
     pub external_config_migration_prompts: ExternalConfigMigrationPrompts,
+    /// One-time TUI tips the user has already seen.
+    #[serde(default)]
+    pub seen_tips: Vec<String>,
 }
 
 pub use crate::skills_config::BundledSkillsConfig;
@@ -157,6 +157,7 @@ struct ModelClientState {
     session_source: SessionSource,
     model_verbosity: Option<VerbosityConfig>,
     enable_request_compression: bool,
+    enable_prompt_caching: bool,
     include_timing_metrics: bool,
     beta_features_header: Option<String>,
     disable_websockets: AtomicBool,
@@ -302,6 +303,7 @@ impl ModelClient {
         session_source: SessionSource,
         model_verbosity: Option<VerbosityConfig>,
         enable_request_compression: bool,
+        enable_prompt_caching: bool,
         include_timing_metrics: bool,
         beta_features_header: Option<String>,
     ) -> Self {
@@ -322,6 +324,7 @@ impl ModelClient {
                 session_source,
                 model_verbosity,
                 enable_request_compression,
+                enable_prompt_caching,
                 include_timing_metrics,
                 beta_features_header,
                 disable_websockets: AtomicBool::new(false),
@@ -923,7 +926,14 @@ impl ModelClientSession {
             None
         };
         let text = create_text_param_for_request(verbosity, &prompt.output_schema);
-        let prompt_cache_key = Some(self.client.state.conversation_id.to_string());
+        // The Responses API caches by stable prompt prefix (instructions and
+        // earlier items come first), keyed per conversation. Omitting the key
+        // opts the whole session out for privacy-sensitive deployments.
+        let prompt_cache_key = self
+            .client
+            .state
+            .enable_prompt_caching
+            .then(|| self.client.state.conversation_id.to_string());
         let request = ResponsesApiRequest {
             model: model_info.slug.clone(),
             instructions: instructions.clone(),
@@ -50,6 +50,7 @@ fn test_model_client(session_source: SessionSource) -> ModelClient {
         session_source,
         /*model_verbosity*/ None,
         /*enable_request_compression*/ false,
+        /*enable_prompt_caching*/ true,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
     )
@@ -158,6 +159,7 @@ async fn model_client_with_agent_task(
         SessionSource::Cli,
         /*model_verbosity*/ None,
         /*enable_request_compression*/ false,
+        /*enable_prompt_caching*/ true,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
     );
@@ -0,0 +1,67 @@
+//! Workspace-scoped ignore rules for agent file access.
+//!
+//! A `.codexignore` file at the workspace root uses gitignore syntax to hide
+//! matching paths from Codex's file tools and to warn when a patch targets
+//! them — keeping secrets and vendored blobs out of model context. The TUI
+//! file picker honors the same file through its directory walker.
+
+use std::path::Path;
+
+use ignore::gitignore::Gitignore;
+
+/// File name of the workspace ignore file, resolved against the session cwd.
+pub const CODEX_IGNORE_FILENAME: &str = ".codexignore";
+
+/// Compiled matcher for the workspace `.codexignore` file.
+pub struct CodexIgnore {
+    matcher: Gitignore,
+}
+
+impl CodexIgnore {
+    /// Loads `.codexignore` from `workspace_root`, or returns `None` when the
+    /// file does not exist. Invalid lines are skipped, matching gitignore
+    /// semantics.
+    pub fn load(workspace_root: &Path) -> Option<Self> {
+        let path = workspace_root.join(CODEX_IGNORE_FILENAME);
+        if !path.is_file() {
+            return None;
+        }
+        let (matcher, _error) = Gitignore::new(&path);
+        Some(Self { matcher })
+    }
+
+    /// Whether `path` — or any directory between it and the workspace root —
+    /// matches an ignore rule. Paths outside the workspace never match.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        self.matcher
+            .matched_path_or_any_parents(path, is_dir)
+            .is_ignore()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_globs_and_directories_like_gitignore() {
+        let root = tempfile::tempdir().expect("tempdir");
+        std::fs::write(
+            root.path().join(CODEX_IGNORE_FILENAME),
+            "*.pem\nvendor/\n!keep.pem\n",
+        )
+        .expect("write");
+
+        let ignore = CodexIgnore::load(root.path()).expect("load");
+        assert!(ignore.is_ignored(&root.path().join("secrets/key.pem"), /*is_dir*/ false));
+        assert!(ignore.is_ignored(&root.path().join("vendor/blob.bin"), /*is_dir*/ false));
+        assert!(!ignore.is_ignored(&root.path().join("keep.pem"), /*is_dir*/ false));
+        assert!(!ignore.is_ignored(&root.path().join("src/main.rs"), /*is_dir*/ false));
+    }
+
+    #[test]
+    fn load_returns_none_without_ignore_file() {
+        let root = tempfile::tempdir().expect("tempdir");
+        assert!(CodexIgnore::load(root.path()).is_none());
+    }
+}
@@ -11,6 +11,7 @@ use codex_config::CONFIG_TOML_FILE;
 use codex_config::config_toml::AgentRoleToml;
 use codex_config::config_toml::AgentsToml;
 use codex_config::config_toml::ConfigToml;
+use codex_config::config_toml::LimitsToml;
 use codex_config::config_toml::ProjectConfig;
 use codex_config::config_toml::RealtimeAudioConfig;
 use codex_config::config_toml::RealtimeConfig;
@@ -18,6 +19,7 @@ use codex_config::config_toml::RealtimeToml;
 use codex_config::config_toml::RealtimeTransport;
 use codex_config::config_toml::RealtimeWsMode;
 use codex_config::config_toml::RealtimeWsVersion;
+use codex_config::config_toml::StorageToml;
 use codex_config::config_toml::ToolsToml;
 use codex_config::permissions_toml::FilesystemPermissionToml;
 use codex_config::permissions_toml::FilesystemPermissionsToml;
@@ -29,7 +31,9 @@ use codex_config::permissions_toml::PermissionsToml;
 use codex_config::profile_toml::ConfigProfile;
 use codex_config::types::AppToolApproval;
 use codex_config::types::ApprovalsReviewer;
+use codex_config::types::BellToml;
 use codex_config::types::BundledSkillsConfig;
+use codex_config::types::ColorMode;
 use codex_config::types::FeedbackConfigToml;
 use codex_config::types::HistoryPersistence;
 use codex_config::types::McpServerEnvVar;
@@ -41,6 +45,7 @@ use codex_config::types::ModelAvailabilityNuxConfig;
 use codex_config::types::NotificationCondition;
 use codex_config::types::NotificationMethod;
 use codex_config::types::Notifications;
+use codex_config::types::OutputFoldingToml;
 use codex_config::types::SandboxWorkspaceWrite;
 use codex_config::types::SkillsConfig;
 use codex_config::types::ToolSuggestDiscoverableType;
@@ -384,6 +389,7 @@ fn config_toml_deserializes_model_availability_nux() {
         Tui {
             notification_settings: TuiNotificationSettings::default(),
             animations: true,
+            welcome_cycle_animation: None,
             show_tooltips: true,
             alternate_screen: AltScreenMode::default(),
             status_line: None,
@@ -1187,6 +1193,7 @@ fn tui_config_missing_notifications_field_defaults_to_enabled() {
         Tui {
             notification_settings: TuiNotificationSettings::default(),
             animations: true,
+            welcome_cycle_animation: None,
             show_tooltips: true,
             alternate_screen: AltScreenMode::Auto,
             status_line: None,
@@ -4870,6 +4877,8 @@ async fn test_precedence_fixture_with_o3_profile() -> std::io::Result<()> {
             js_repl_node_path: None,
             js_repl_node_module_dirs: Vec::new(),
             zsh_path: None,
+            user_shell_path: None,
+            container_sandbox: None,
             hide_agent_reasoning: false,
             show_raw_agent_reasoning: false,
             model_reasoning_effort: Some(ReasoningEffort::High),
@@ -4888,6 +4897,7 @@ async fn test_precedence_fixture_with_o3_profile() -> std::io::Result<()> {
             experimental_realtime_ws_backend_prompt: None,
             experimental_realtime_ws_startup_context: None,
             base_instructions: None,
+            system_prompt_extra: None,
             developer_instructions: None,
             guardian_policy_config: None,
             include_permissions_instructions: true,
@@ -4904,17 +4914,23 @@ async fn test_precedence_fixture_with_o3_profile() -> std::io::Result<()> {
             use_experimental_unified_exec_tool: !cfg!(windows),
             background_terminal_max_timeout: DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS,
             ghost_snapshot: GhostSnapshotConfig::default(),
+            limits: LimitsToml::default(),
+            storage: StorageToml::default(),
+            wasm_tools: HashMap::new(),
+            templates: HashMap::new(),
+            active_template: None,
             multi_agent_v2: MultiAgentV2Config::default(),
             features: Features::with_defaults().into(),
             suppress_unstable_features_warning: false,
             active_profile: Some("o3".to_string()),
-            active_project: ProjectConfig { trust_level: None },
+            active_project: ProjectConfig::default(),
             windows_wsl_setup_acknowledged: false,
             notices: Default::default(),
             check_for_update_on_startup: true,
             disable_paste_burst: false,
             tui_notifications: Default::default(),
             animations: true,
+            tui_welcome_cycle_animation: None,
             show_tooltips: true,
             model_availability_nux: ModelAvailabilityNuxConfig::default(),
             analytics_enabled: Some(true),
@@ -4922,8 +4938,26 @@ async fn test_precedence_fixture_with_o3_profile() -> std::io::Result<()> {
             tool_suggest: ToolSuggestConfig::default(),
             tui_alternate_screen: AltScreenMode::Auto,
             tui_status_line: None,
+            tui_batch_pause_between_tasks: false,
             tui_terminal_title: None,
             tui_theme: None,
+            tui_syntax_styles: BTreeMap::new(),
+            tui_color_mode: ColorMode::default(),
+            tui_collapsed_tool_calls: HashMap::new(),
+            tui_keybindings: None,
+            tui_mouse: None,
+            tui_output_folding: OutputFoldingToml::default(),
+            tui_spinner: None,
+            tui_interrupt_hint_after_seconds: None,
+            tui_bell: BellToml::default(),
+            tui_ascii_only: None,
+            tui_language: None,
+            tui_cell_renderers: Vec::new(),
+            tui_accessibility_min_contrast: None,
+            tui_accessibility_colorblind: None,
+            tui_assume_background: None,
+            tui_tips: None,
+            tui_code_block_overflow: None,
             otel: OtelConfig::default(),
         },
         o3_profile_config
@@ -5021,6 +5055,8 @@ async fn test_precedence_fixture_with_gpt3_profile() -> std::io::Result<()> {
         js_repl_node_path: None,
         js_repl_node_module_dirs: Vec::new(),
         zsh_path: None,
+        user_shell_path: None,
+        container_sandbox: None,
         hide_agent_reasoning: false,
         show_raw_agent_reasoning: false,
         model_reasoning_effort: None,
@@ -5039,6 +5075,7 @@ async fn test_precedence_fixture_with_gpt3_profile() -> std::io::Result<()> {
         experimental_realtime_ws_backend_prompt: None,
         experimental_realtime_ws_startup_context: None,
         base_instructions: None,
+        system_prompt_extra: None,
         developer_instructions: None,
         guardian_policy_config: None,
         include_permissions_instructions: true,
@@ -5055,17 +5092,23 @@ async fn test_precedence_fixture_with_gpt3_profile() -> std::io::Result<()> {
         use_experimental_unified_exec_tool: !cfg!(windows),
         background_terminal_max_timeout: DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS,
         ghost_snapshot: GhostSnapshotConfig::default(),
+        limits: LimitsToml::default(),
+        storage: StorageToml::default(),
+        wasm_tools: HashMap::new(),
+        templates: HashMap::new(),
+        active_template: None,
         multi_agent_v2: MultiAgentV2Config::default(),
         features: Features::with_defaults().into(),
         suppress_unstable_features_warning: false,
         active_profile: Some("gpt3".to_string()),
-        active_project: ProjectConfig { trust_level: None },
+        active_project: ProjectConfig::default(),
         windows_wsl_setup_acknowledged: false,
         notices: Default::default(),
         check_for_update_on_startup: true,
         disable_paste_burst: false,
         tui_notifications: Default::default(),
         animations: true,
+        tui_welcome_cycle_animation: None,
         show_tooltips: true,
         model_availability_nux: ModelAvailabilityNuxConfig::default(),
         analytics_enabled: Some(true),
@@ -5073,8 +5116,26 @@ async fn test_precedence_fixture_with_gpt3_profile() -> std::io::Result<()> {
         tool_suggest: ToolSuggestConfig::default(),
         tui_alternate_screen: AltScreenMode::Auto,
         tui_status_line: None,
+        tui_batch_pause_between_tasks: false,
         tui_terminal_title: None,
         tui_theme: None,
+        tui_syntax_styles: BTreeMap::new(),
+        tui_color_mode: ColorMode::default(),
+        tui_collapsed_tool_calls: HashMap::new(),
+        tui_keybindings: None,
+        tui_mouse: None,
+        tui_output_folding: OutputFoldingToml::default(),
+        tui_spinner: None,
+        tui_interrupt_hint_after_seconds: None,
+        tui_bell: BellToml::default(),
+        tui_ascii_only: None,
+        tui_language: None,
+        tui_cell_renderers: Vec::new(),
+        tui_accessibility_min_contrast: None,
+        tui_accessibility_colorblind: None,
+        tui_assume_background: None,
+        tui_tips: None,
+        tui_code_block_overflow: None,
         otel: OtelConfig::default(),
     };
 
@@ -5170,6 +5231,8 @@ async fn test_precedence_fixture_with_zdr_profile() -> std::io::Result<()> {
         js_repl_node_path: None,
         js_repl_node_module_dirs: Vec::new(),
         zsh_path: None,
+        user_shell_path: None,
+        container_sandbox: None,
         hide_agent_reasoning: false,
         show_raw_agent_reasoning: false,
         model_reasoning_effort: None,
@@ -5188,6 +5251,7 @@ async fn test_precedence_fixture_with_zdr_profile() -> std::io::Result<()> {
         experimental_realtime_ws_backend_prompt: None,
         experimental_realtime_ws_startup_context: None,
         base_instructions: None,
+        system_prompt_extra: None,
         developer_instructions: None,
         guardian_policy_config: None,
         include_permissions_instructions: true,
@@ -5204,17 +5268,23 @@ async fn test_precedence_fixture_with_zdr_profile() -> std::io::Result<()> {
         use_experimental_unified_exec_tool: !cfg!(windows),
         background_terminal_max_timeout: DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS,
         ghost_snapshot: GhostSnapshotConfig::default(),
+        limits: LimitsToml::default(),
+        storage: StorageToml::default(),
+        wasm_tools: HashMap::new(),
+        templates: HashMap::new(),
+        active_template: None,
         multi_agent_v2: MultiAgentV2Config::default(),
         features: Features::with_defaults().into(),
         suppress_unstable_features_warning: false,
         active_profile: Some("zdr".to_string()),
-        active_project: ProjectConfig { trust_level: None },
+        active_project: ProjectConfig::default(),
         windows_wsl_setup_acknowledged: false,
         notices: Default::default(),
         check_for_update_on_startup: true,
         disable_paste_burst: false,
         tui_notifications: Default::default(),
         animations: true,
+        tui_welcome_cycle_animation: None,
         show_tooltips: true,
         model_availability_nux: ModelAvailabilityNuxConfig::default(),
         analytics_enabled: Some(false),
@@ -5222,8 +5292,26 @@ async fn test_precedence_fixture_with_zdr_profile() -> std::io::Result<()> {
         tool_suggest: ToolSuggestConfig::default(),
         tui_alternate_screen: AltScreenMode::Auto,
         tui_status_line: None,
+        tui_batch_pause_between_tasks: false,
         tui_terminal_title: None,
         tui_theme: None,
+        tui_syntax_styles: BTreeMap::new(),
+        tui_color_mode: ColorMode::default(),
+        tui_collapsed_tool_calls: HashMap::new(),
+        tui_keybindings: None,
+        tui_mouse: None,
+        tui_output_folding: OutputFoldingToml::default(),
+        tui_spinner: None,
+        tui_interrupt_hint_after_seconds: None,
+        tui_bell: BellToml::default(),
+        tui_ascii_only: None,
+        tui_language: None,
+        tui_cell_renderers: Vec::new(),
+        tui_accessibility_min_contrast: None,
+        tui_accessibility_colorblind: None,
+        tui_assume_background: None,
+        tui_tips: None,
+        tui_code_block_overflow: None,
         otel: OtelConfig::default(),
     };
 
@@ -5304,6 +5392,8 @@ async fn test_precedence_fixture_with_gpt5_profile() -> std::io::Result<()> {
         js_repl_node_path: None,
         js_repl_node_module_dirs: Vec::new(),
         zsh_path: None,
+        user_shell_path: None,
+        container_sandbox: None,
         hide_agent_reasoning: false,
         show_raw_agent_reasoning: false,
         model_reasoning_effort: Some(ReasoningEffort::High),
@@ -5322,6 +5412,7 @@ async fn test_precedence_fixture_with_gpt5_profile() -> std::io::Result<()> {
         experimental_realtime_ws_backend_prompt: None,
         experimental_realtime_ws_startup_context: None,
         base_instructions: None,
+        system_prompt_extra: None,
         developer_instructions: None,
         guardian_policy_config: None,
         include_permissions_instructions: true,
@@ -5338,17 +5429,23 @@ async fn test_precedence_fixture_with_gpt5_profile() -> std::io::Result<()> {
         use_experimental_unified_exec_tool: !cfg!(windows),
         background_terminal_max_timeout: DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS,
         ghost_snapshot: GhostSnapshotConfig::default(),
+        limits: LimitsToml::default(),
+        storage: StorageToml::default(),
+        wasm_tools: HashMap::new(),
+        templates: HashMap::new(),
+        active_template: None,
         multi_agent_v2: MultiAgentV2Config::default(),
         features: Features::with_defaults().into(),
         suppress_unstable_features_warning: false,
         active_profile: Some("gpt5".to_string()),
-        active_project: ProjectConfig { trust_level: None },
+        active_project: ProjectConfig::default(),
         windows_wsl_setup_acknowledged: false,
         notices: Default::default(),
         check_for_update_on_startup: true,
         disable_paste_burst: false,
         tui_notifications: Default::default(),
         animations: true,
+        tui_welcome_cycle_animation: None,
         show_tooltips: true,
         model_availability_nux: ModelAvailabilityNuxConfig::default(),
         analytics_enabled: Some(true),
@@ -5356,8 +5453,26 @@ async fn test_precedence_fixture_with_gpt5_profile() -> std::io::Result<()> {
         tool_suggest: ToolSuggestConfig::default(),
         tui_alternate_screen: AltScreenMode::Auto,
         tui_status_line: None,
+        tui_batch_pause_between_tasks: false,
         tui_terminal_title: None,
         tui_theme: None,
+        tui_syntax_styles: BTreeMap::new(),
+        tui_color_mode: ColorMode::default(),
+        tui_collapsed_tool_calls: HashMap::new(),
+        tui_keybindings: None,
+        tui_mouse: None,
+        tui_output_folding: OutputFoldingToml::default(),
+        tui_spinner: None,
+        tui_interrupt_hint_after_seconds: None,
+        tui_bell: BellToml::default(),
+        tui_ascii_only: None,
+        tui_language: None,
+        tui_cell_renderers: Vec::new(),
+        tui_accessibility_min_contrast: None,
+        tui_accessibility_colorblind: None,
+        tui_assume_background: None,
+        tui_tips: None,
+        tui_code_block_overflow: None,
         otel: OtelConfig::default(),
     };
 
@@ -5550,6 +5665,7 @@ async fn active_project_does_not_match_configured_alias_for_canonical_cwd() -> a
             alias_root.to_string_lossy().to_string(),
             ProjectConfig {
                 trust_level: Some(TrustLevel::Trusted),
+                ..Default::default()
             },
         )])),
         ..Default::default()
@@ -5563,6 +5679,87 @@ async fn active_project_does_not_match_configured_alias_for_canonical_cwd() -> a
     Ok(())
 }
 
+#[tokio::test]
+async fn project_defaults_apply_when_cwd_matches_project() -> anyhow::Result<()> {
+    let codex_home = tempdir()?;
+    let project_dir = TempDir::new()?;
+    let project_path = project_dir.path().to_path_buf();
+    let cfg = ConfigToml {
+        projects: Some(HashMap::from([(
+            project_path.to_string_lossy().to_string(),
+            ProjectConfig {
+                trust_level: Some(TrustLevel::Trusted),
+                model: Some("project-model".to_string()),
+                model_reasoning_effort: Some(ReasoningEffort::High),
+                sandbox_mode: Some(SandboxMode::ReadOnly),
+                approval_policy: Some(AskForApproval::Never),
+            },
+        )])),
+        ..Default::default()
+    };
+
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides {
+            cwd: Some(project_path),
+            ..Default::default()
+        },
+        codex_home.abs(),
+    )
+    .await?;
+
+    assert_eq!(config.model.as_deref(), Some("project-model"));
+    assert_eq!(config.model_reasoning_effort, Some(ReasoningEffort::High));
+    assert_eq!(
+        config.permissions.approval_policy.value(),
+        AskForApproval::Never
+    );
+    assert_matches!(
+        config.permissions.sandbox_policy.get(),
+        SandboxPolicy::ReadOnly { .. }
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn cli_overrides_take_precedence_over_project_defaults() -> anyhow::Result<()> {
+    let codex_home = tempdir()?;
+    let project_dir = TempDir::new()?;
+    let project_path = project_dir.path().to_path_buf();
+    let cfg = ConfigToml {
+        projects: Some(HashMap::from([(
+            project_path.to_string_lossy().to_string(),
+            ProjectConfig {
+                trust_level: Some(TrustLevel::Trusted),
+                model: Some("project-model".to_string()),
+                model_reasoning_effort: None,
+                sandbox_mode: None,
+                approval_policy: Some(AskForApproval::Never),
+            },
+        )])),
+        ..Default::default()
+    };
+
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides {
+            cwd: Some(project_path),
+            model: Some("cli-model".to_string()),
+            approval_policy: Some(AskForApproval::OnRequest),
+            ..Default::default()
+        },
+        codex_home.abs(),
+    )
+    .await?;
+
+    assert_eq!(config.model.as_deref(), Some("cli-model"));
+    assert_eq!(
+        config.permissions.approval_policy.value(),
+        AskForApproval::OnRequest
+    );
+    Ok(())
+}
+
 #[test]
 fn test_set_default_oss_provider() -> std::io::Result<()> {
     let temp_dir = TempDir::new()?;
@@ -5654,6 +5851,7 @@ trust_level = "untrusted"
         .expect("TOML deserialization should succeed");
     let active_project = ProjectConfig {
         trust_level: Some(TrustLevel::Untrusted),
+        ..Default::default()
     };
 
     let resolution = cfg
@@ -5693,12 +5891,14 @@ async fn derive_sandbox_policy_falls_back_to_constraint_value_for_implicit_defau
             project_key,
             ProjectConfig {
                 trust_level: Some(TrustLevel::Trusted),
+                ..Default::default()
             },
         )])),
         ..Default::default()
     };
     let active_project = ProjectConfig {
         trust_level: Some(TrustLevel::Trusted),
+        ..Default::default()
     };
     let constrained = Constrained::new(SandboxPolicy::DangerFullAccess, |candidate| {
         if matches!(candidate, SandboxPolicy::DangerFullAccess) {
@@ -5738,12 +5938,14 @@ async fn derive_sandbox_policy_preserves_windows_downgrade_for_unsupported_fallb
             project_key,
             ProjectConfig {
                 trust_level: Some(TrustLevel::Trusted),
+                ..Default::default()
             },
         )])),
         ..Default::default()
     };
     let active_project = ProjectConfig {
         trust_level: Some(TrustLevel::Trusted),
+        ..Default::default()
     };
     let constrained = Constrained::new(SandboxPolicy::new_workspace_write_policy(), |candidate| {
         if matches!(candidate, SandboxPolicy::WorkspaceWrite { .. }) {
@@ -5937,6 +6139,134 @@ allow_login_shell = false
     Ok(())
 }
 
+#[tokio::test]
+async fn config_loads_terminal_profile_from_toml() -> std::io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+model = "gpt-5.4"
+terminal_profile = "fishy"
+
+[terminal_profiles.fishy]
+shell = "fish"
+login = false
+"#,
+    )
+    .expect("TOML deserialization should succeed for terminal_profiles");
+
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await?;
+
+    assert_eq!(
+        config.user_shell_path,
+        Some(std::path::PathBuf::from("fish"))
+    );
+    assert!(!config.permissions.allow_login_shell);
+    Ok(())
+}
+
+#[tokio::test]
+async fn config_loads_container_sandbox_from_toml() -> std::io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+model = "gpt-5.4"
+container_sandbox = "builder"
+
+[container_sandboxes.builder]
+image = "ubuntu:24.04"
+engine = "podman"
+network = "enabled"
+
+[[container_sandboxes.builder.mounts]]
+host = "/work/repo"
+container = "/workspace"
+readonly = true
+"#,
+    )
+    .expect("TOML deserialization should succeed for container_sandboxes");
+
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await?;
+
+    assert_eq!(
+        config.container_sandbox,
+        Some(ContainerSandboxConfig {
+            engine: ContainerEngine::Podman,
+            image: "ubuntu:24.04".to_string(),
+            mounts: vec![ContainerMount {
+                host: std::path::PathBuf::from("/work/repo"),
+                container: "/workspace".to_string(),
+                readonly: true,
+            }],
+            network: NetworkSandboxPolicy::Enabled,
+        })
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn config_loads_approval_presets_from_toml() -> std::io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+model = "gpt-5.4"
+
+[approval_presets.paranoid]
+label = "Paranoid"
+description = "Ask about everything."
+approval_policy = "untrusted"
+sandbox_mode = "read-only"
+"#,
+    )
+    .expect("TOML deserialization should succeed for approval_presets");
+
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await?;
+
+    let presets = config.approval_presets.expect("approval presets present");
+    let preset = presets.get("paranoid").expect("paranoid preset present");
+    assert_eq!(preset.label.as_deref(), Some("Paranoid"));
+    assert_eq!(preset.approval_policy, AskForApproval::UnlessTrusted);
+    assert_eq!(preset.sandbox_mode, SandboxMode::ReadOnly);
+    Ok(())
+}
+
+#[tokio::test]
+async fn config_rejects_unknown_terminal_profile() -> std::io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+model = "gpt-5.4"
+terminal_profile = "missing"
+"#,
+    )
+    .expect("TOML deserialization should succeed for terminal_profile");
+
+    let err = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await
+    .expect_err("missing terminal profile should be rejected");
+
+    assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+    Ok(())
+}
+
 #[tokio::test]
 async fn config_loads_mcp_oauth_callback_url_from_toml() -> std::io::Result<()> {
     let codex_home = TempDir::new()?;
@@ -5973,6 +6303,7 @@ async fn test_untrusted_project_gets_unless_trusted_approval_policy() -> anyhow:
                 test_path.to_string_lossy().to_string(),
                 ProjectConfig {
                     trust_level: Some(TrustLevel::Untrusted),
+                    ..Default::default()
                 },
             )])),
             ..Default::default()
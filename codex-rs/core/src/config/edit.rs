@@ -1023,6 +1023,24 @@ impl ConfigEditsBuilder {
         self
     }
 
+    /// Replace the `[notice].seen_tips` list with `tips`.
+    pub fn set_seen_tips(mut self, tips: &[String]) -> Self {
+        let array: toml_edit::Array = tips.iter().map(String::as_str).collect();
+        self.edits.push(ConfigEdit::SetPath {
+            segments: vec![NOTICE_TABLE_KEY.to_string(), "seen_tips".to_string()],
+            value: value(array),
+        });
+        self
+    }
+
+    /// Forget all seen tips so each one can show again (`/tips reset`).
+    pub fn clear_seen_tips(mut self) -> Self {
+        self.edits.push(ConfigEdit::ClearPath {
+            segments: vec![NOTICE_TABLE_KEY.to_string(), "seen_tips".to_string()],
+        });
+        self
+    }
+
     pub fn set_windows_wsl_setup_acknowledged(mut self, acknowledged: bool) -> Self {
         self.edits
             .push(ConfigEdit::SetWindowsWslSetupAcknowledged(acknowledged));
@@ -21,30 +21,49 @@ use crate::unified_exec::MIN_EMPTY_YIELD_TIME_MS;
 use crate::windows_sandbox::WindowsSandboxLevelExt;
 use crate::windows_sandbox::resolve_windows_sandbox_mode;
 use crate::windows_sandbox::resolve_windows_sandbox_private_desktop;
+use codex_config::config_toml::ApprovalPresetToml;
 use codex_config::config_toml::ConfigToml;
+use codex_config::config_toml::ContainerEngineToml;
+use codex_config::config_toml::ContainerSandboxToml;
+use codex_config::config_toml::LimitsToml;
 use codex_config::config_toml::ProjectConfig;
 use codex_config::config_toml::RealtimeAudioConfig;
 use codex_config::config_toml::RealtimeConfig;
+use codex_config::config_toml::StorageToml;
+use codex_config::config_toml::TemplateToml;
+use codex_config::config_toml::WasmToolToml;
 use codex_config::config_toml::validate_model_providers;
 use codex_config::profile_toml::ConfigProfile;
 use codex_config::types::ApprovalsReviewer;
 use codex_config::types::AuthCredentialsStoreMode;
+use codex_config::types::BellToml;
+use codex_config::types::CellRendererToml;
+use codex_config::types::CodeBlockOverflow;
+use codex_config::types::ColorMode;
+use codex_config::types::ColorblindPalette;
 use codex_config::types::DEFAULT_OTEL_ENVIRONMENT;
 use codex_config::types::History;
+use codex_config::types::HistoryBudgetToml;
+use codex_config::types::KeybindingsToml;
 use codex_config::types::McpServerConfig;
 use codex_config::types::McpServerDisabledReason;
 use codex_config::types::McpServerTransportConfig;
 use codex_config::types::MemoriesConfig;
 use codex_config::types::ModelAvailabilityNuxConfig;
+use codex_config::types::MouseToml;
 use codex_config::types::Notice;
 use codex_config::types::OAuthCredentialsStoreMode;
 use codex_config::types::OtelConfig;
 use codex_config::types::OtelConfigToml;
 use codex_config::types::OtelExporterKind;
+use codex_config::types::OutputFoldingToml;
 use codex_config::types::ShellEnvironmentPolicy;
+use codex_config::types::SpinnerToml;
+use codex_config::types::SyntaxStyleToml;
 use codex_config::types::ToolSuggestConfig;
 use codex_config::types::ToolSuggestDiscoverable;
 use codex_config::types::TuiNotificationSettings;
+use codex_config::types::UpdateChannel;
 use codex_config::types::UriBasedFileOpener;
 use codex_config::types::WindowsSandboxModeToml;
 use codex_exec_server::ExecutorFileSystem;
@@ -110,6 +129,9 @@ pub use codex_config::Constrained;
 pub use codex_config::ConstraintError;
 pub use codex_config::ConstraintResult;
 pub use codex_network_proxy::NetworkProxyAuditMetadata;
+pub use codex_sandboxing::container::ContainerEngine;
+pub use codex_sandboxing::container::ContainerMount;
+pub use codex_sandboxing::container::ContainerSandboxConfig;
 pub use codex_sandboxing::system_bwrap_warning;
 pub use managed_features::ManagedFeatures;
 pub use network_proxy_spec::NetworkProxySpec;
@@ -278,6 +300,10 @@ pub struct Config {
     /// Base instructions override.
     pub base_instructions: Option<String>,
 
+    /// Extra text appended after the base instructions, resolved from the
+    /// `system_prompt_extra`/`system_prompt_extra_file` config keys.
+    pub system_prompt_extra: Option<String>,
+
     /// Developer instructions override injected as a separate message.
     pub developer_instructions: Option<String>,
 
@@ -337,6 +363,10 @@ pub struct Config {
     /// Enable ASCII animations and shimmer effects in the TUI.
     pub animations: bool,
 
+    /// Welcome screen animation style name, `random`, or `none` to disable
+    /// just the welcome animation.
+    pub tui_welcome_cycle_animation: Option<String>,
+
     /// Show startup tooltips in the TUI welcome screen.
     pub show_tooltips: bool,
 
@@ -358,6 +388,9 @@ pub struct Config {
     /// When unset, the TUI defaults to: `model-with-reasoning` and `current-dir`.
     pub tui_status_line: Option<Vec<String>>,
 
+    /// Whether `/batch` pauses for confirmation between queued tasks.
+    pub tui_batch_pause_between_tasks: bool,
+
     /// Ordered list of terminal title item identifiers for the TUI.
     ///
     /// When unset, the TUI defaults to: `project` and `spinner`.
@@ -366,6 +399,65 @@ pub struct Config {
     /// Syntax highlighting theme override (kebab-case name).
     pub tui_theme: Option<String>,
 
+    /// Per-scope syntax style overrides layered onto the active TUI theme,
+    /// keyed by TextMate scope selector.
+    pub tui_syntax_styles: BTreeMap<String, SyntaxStyleToml>,
+
+    /// How the TUI emits colors (`truecolor`, `256`, `16`, or `auto`).
+    pub tui_color_mode: ColorMode,
+
+    /// Memory budget for committed TUI history cells; unset keeps all history
+    /// resident.
+    pub tui_history_budget: Option<HistoryBudgetToml>,
+
+    /// Default collapsed state for TUI tool-call cells, keyed by tool type
+    /// (`exec`, `mcp`, `patch`).
+    pub tui_collapsed_tool_calls: HashMap<String, bool>,
+
+    /// Custom TUI key bindings, including per-context override tables.
+    pub tui_keybindings: Option<KeybindingsToml>,
+
+    /// TUI mouse capture settings; `None` leaves the mouse to the terminal.
+    pub tui_mouse: Option<MouseToml>,
+
+    /// Fold TUI command output by exit status (successful commands collapse
+    /// to their last few lines; failures render in full).
+    pub tui_output_folding: OutputFoldingToml,
+
+    /// Spinner for the TUI status row: a built-in name or custom frame list.
+    pub tui_spinner: Option<SpinnerToml>,
+
+    /// Seconds before the TUI status row shows the interrupt hint.
+    pub tui_interrupt_hint_after_seconds: Option<u64>,
+
+    /// Terminal bell rung by the TUI on completion and approval requests.
+    pub tui_bell: BellToml,
+
+    /// Force ASCII glyphs in the TUI; unset probes the locale at startup.
+    pub tui_ascii_only: Option<bool>,
+
+    /// Language for user-facing TUI strings; unset defers to the locale.
+    pub tui_language: Option<String>,
+
+    /// External renderers for custom tool output in the TUI.
+    pub tui_cell_renderers: Vec<CellRendererToml>,
+
+    /// Minimum WCAG contrast ratio enforced by the TUI, when set.
+    pub tui_accessibility_min_contrast: Option<f64>,
+
+    /// Colorblind-safe palette used by the TUI diff renderer, when set.
+    pub tui_accessibility_colorblind: Option<ColorblindPalette>,
+
+    /// Assumed terminal background color (`"#RRGGBB"`) for terminals that
+    /// refuse background queries.
+    pub tui_assume_background: Option<String>,
+
+    /// Whether the TUI shows one-time contextual tips. Defaults to true.
+    pub tui_tips: Option<bool>,
+
+    /// How the TUI wraps code-block lines wider than the terminal, when set.
+    pub tui_code_block_overflow: Option<CodeBlockOverflow>,
+
     /// The absolute directory that should be treated as the current working
     /// directory for the session. All relative paths inside the business-logic
     /// layer are resolved against this path.
@@ -437,6 +529,10 @@ pub struct Config {
     /// Directory where Codex writes log files (defaults to `$CODEX_HOME/log`).
     pub log_dir: PathBuf,
 
+    /// Optional synced directory that session rollouts are mirrored to and
+    /// imported from at startup.
+    pub sessions_sync_dir: Option<PathBuf>,
+
     /// Settings that govern if and what will be written to `~/.codex/history.jsonl`.
     pub history: History,
 
@@ -473,6 +569,19 @@ pub struct Config {
     /// Optional absolute path to patched zsh used by zsh-exec-bridge-backed shell execution.
     pub zsh_path: Option<PathBuf>,
 
+    /// Shell for generated commands, resolved from the active terminal
+    /// profile. `None` detects and uses the user's login shell.
+    pub user_shell_path: Option<PathBuf>,
+
+    /// Container sandbox resolved from the active `container_sandboxes`
+    /// entry. When set, sandboxed commands run inside this container instead
+    /// of under the platform sandbox.
+    pub container_sandbox: Option<ContainerSandboxConfig>,
+
+    /// Custom `[approval_presets.<name>]` entries offered alongside the
+    /// built-in permission presets.
+    pub approval_presets: Option<HashMap<String, ApprovalPresetToml>>,
+
     /// Value to use for `reasoning.effort` when making a request using the
     /// Responses API.
     pub model_reasoning_effort: Option<ReasoningEffort>,
@@ -554,6 +663,21 @@ pub struct Config {
     /// Settings for ghost snapshots (used for undo).
     pub ghost_snapshot: GhostSnapshotConfig,
 
+    /// Session budget guardrails from `[limits]`.
+    pub limits: LimitsToml,
+
+    /// Retention policy for stored sessions from `[storage]`.
+    pub storage: StorageToml,
+    /// User-defined WASM tools keyed by tool name.
+    pub wasm_tools: HashMap<String, WasmToolToml>,
+
+    /// Named conversation templates from `[templates.<name>]`.
+    pub templates: HashMap<String, TemplateToml>,
+
+    /// Template applied to this session, resolved by the frontend from
+    /// `templates` when one was requested at startup.
+    pub active_template: Option<TemplateToml>,
+
     /// Settings specific to the task-path-based multi-agent tool surface.
     pub multi_agent_v2: MultiAgentV2Config,
 
@@ -581,6 +705,9 @@ pub struct Config {
     /// Defaults to `true`.
     pub check_for_update_on_startup: bool,
 
+    /// Release channel consulted when checking for updates.
+    pub update_channel: UpdateChannel,
+
     /// When true, disables burst-paste detection for typed input entirely.
     /// All characters are inserted as they are received, and no buffering
     /// or placeholder replacement will occur for fast keypress bursts.
@@ -1229,6 +1356,29 @@ fn resolve_tool_suggest_config(config_toml: &ConfigToml) -> ToolSuggestConfig {
     ToolSuggestConfig { discoverables }
 }
 
+fn resolve_container_sandbox(toml: ContainerSandboxToml) -> ContainerSandboxConfig {
+    ContainerSandboxConfig {
+        engine: match toml.engine.unwrap_or_default() {
+            ContainerEngineToml::Docker => ContainerEngine::Docker,
+            ContainerEngineToml::Podman => ContainerEngine::Podman,
+        },
+        image: toml.image,
+        mounts: toml
+            .mounts
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mount| ContainerMount {
+                container: mount
+                    .container
+                    .unwrap_or_else(|| mount.host.display().to_string()),
+                host: mount.host,
+                readonly: mount.readonly.unwrap_or(false),
+            })
+            .collect(),
+        network: toml.network.unwrap_or_default(),
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum PermissionConfigSyntax {
     Legacy,
@@ -1628,7 +1778,11 @@ impl Config {
                 resolved_cwd.as_path(),
                 repo_root.as_ref().map(AbsolutePathBuf::as_path),
             )
-            .unwrap_or(ProjectConfig { trust_level: None });
+            .unwrap_or_default();
+        let terminal_profile = cfg.get_terminal_profile(Some(&active_project))?;
+        let container_sandbox = cfg
+            .get_container_sandbox(Some(&active_project))?
+            .map(resolve_container_sandbox);
         let permission_config_syntax = resolve_permission_config_syntax(
             &config_layer_stack,
             &cfg,
@@ -1748,9 +1902,11 @@ impl Config {
         };
         let approval_policy_was_explicit = approval_policy_override.is_some()
             || config_profile.approval_policy.is_some()
+            || active_project.approval_policy.is_some()
             || cfg.approval_policy.is_some();
         let mut approval_policy = approval_policy_override
             .or(config_profile.approval_policy)
+            .or(active_project.approval_policy)
             .or(cfg.approval_policy)
             .unwrap_or_else(|| {
                 if active_project.is_trusted() {
@@ -1823,7 +1979,12 @@ impl Config {
             .clone();
 
         let shell_environment_policy = cfg.shell_environment_policy.into();
-        let allow_login_shell = cfg.allow_login_shell.unwrap_or(true);
+        let allow_login_shell = terminal_profile
+            .as_ref()
+            .and_then(|profile| profile.login)
+            .or(cfg.allow_login_shell)
+            .unwrap_or(true);
+        let user_shell_path = terminal_profile.and_then(|profile| profile.shell);
 
         let history = cfg.history.unwrap_or_default();
 
@@ -1913,7 +2074,10 @@ impl Config {
 
         let forced_login_method = cfg.forced_login_method;
 
-        let model = model.or(config_profile.model).or(cfg.model);
+        let model = model
+            .or(config_profile.model)
+            .or(active_project.model.clone())
+            .or(cfg.model);
         let service_tier = service_tier_override
             .unwrap_or_else(|| config_profile.service_tier.or(cfg.service_tier));
         let service_tier = match service_tier {
@@ -1949,6 +2113,27 @@ impl Config {
         )
         .await?;
         let base_instructions = base_instructions.or(file_base_instructions);
+
+        // Resolve the appended system prompt extension. Inline text wins over
+        // the file form, and the profile wins over the top-level keys.
+        let profile_system_prompt_extra = Self::try_read_non_empty_file(
+            fs,
+            config_profile.system_prompt_extra_file.as_ref(),
+            "system prompt extra file",
+        )
+        .await?;
+        let toml_system_prompt_extra = Self::try_read_non_empty_file(
+            fs,
+            cfg.system_prompt_extra_file.as_ref(),
+            "system prompt extra file",
+        )
+        .await?;
+        let system_prompt_extra = config_profile
+            .system_prompt_extra
+            .clone()
+            .or(profile_system_prompt_extra)
+            .or(cfg.system_prompt_extra.clone())
+            .or(toml_system_prompt_extra);
         let developer_instructions = developer_instructions.or(cfg.developer_instructions);
         let include_permissions_instructions = config_profile
             .include_permissions_instructions
@@ -2010,6 +2195,7 @@ impl Config {
         let review_model = override_review_model.or(cfg.review_model);
 
         let check_for_update_on_startup = cfg.check_for_update_on_startup.unwrap_or(true);
+        let update_channel = cfg.update_channel.unwrap_or_default();
         let model_catalog = load_model_catalog(
             config_profile
                 .model_catalog_json
@@ -2171,6 +2357,7 @@ impl Config {
             notify: cfg.notify,
             user_instructions,
             base_instructions,
+            system_prompt_extra,
             personality,
             developer_instructions,
             compact_prompt,
@@ -2218,6 +2405,10 @@ impl Config {
             codex_home,
             sqlite_home,
             log_dir,
+            sessions_sync_dir: cfg
+                .sessions_sync_dir
+                .as_ref()
+                .map(AbsolutePathBuf::to_path_buf),
             config_layer_stack,
             history,
             ephemeral: ephemeral.unwrap_or_default(),
@@ -2228,6 +2419,9 @@ impl Config {
             js_repl_node_path,
             js_repl_node_module_dirs,
             zsh_path,
+            user_shell_path,
+            container_sandbox,
+            approval_presets: cfg.approval_presets.clone(),
 
             hide_agent_reasoning: cfg.hide_agent_reasoning.unwrap_or(false),
             show_raw_agent_reasoning: cfg
@@ -2237,6 +2431,7 @@ impl Config {
             guardian_policy_config,
             model_reasoning_effort: config_profile
                 .model_reasoning_effort
+                .or(active_project.model_reasoning_effort)
                 .or(cfg.model_reasoning_effort),
             plan_mode_reasoning_effort: config_profile
                 .plan_mode_reasoning_effort
@@ -2289,8 +2484,14 @@ impl Config {
             active_profile: active_profile_name,
             active_project,
             windows_wsl_setup_acknowledged: cfg.windows_wsl_setup_acknowledged.unwrap_or(false),
+            limits: cfg.limits.clone().unwrap_or_default(),
+            storage: cfg.storage.clone().unwrap_or_default(),
+            wasm_tools: cfg.wasm_tools.clone().unwrap_or_default(),
+            templates: cfg.templates.clone(),
+            active_template: None,
             notices: cfg.notice.unwrap_or_default(),
             check_for_update_on_startup,
+            update_channel,
             disable_paste_burst: cfg.disable_paste_burst.unwrap_or(false),
             analytics_enabled: config_profile
                 .analytics
@@ -2309,6 +2510,10 @@ impl Config {
                 .map(|t| t.notification_settings.clone())
                 .unwrap_or_default(),
             animations: cfg.tui.as_ref().map(|t| t.animations).unwrap_or(true),
+            tui_welcome_cycle_animation: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.welcome_cycle_animation.clone()),
             show_tooltips: cfg.tui.as_ref().map(|t| t.show_tooltips).unwrap_or(true),
             model_availability_nux: cfg
                 .tui
@@ -2321,8 +2526,55 @@ impl Config {
                 .map(|t| t.alternate_screen)
                 .unwrap_or_default(),
             tui_status_line: cfg.tui.as_ref().and_then(|t| t.status_line.clone()),
+            tui_batch_pause_between_tasks: cfg
+                .tui
+                .as_ref()
+                .is_some_and(|t| t.batch_pause_between_tasks),
             tui_terminal_title: cfg.tui.as_ref().and_then(|t| t.terminal_title.clone()),
             tui_theme: cfg.tui.as_ref().and_then(|t| t.theme.clone()),
+            tui_syntax_styles: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.syntax.clone())
+                .unwrap_or_default(),
+            tui_color_mode: cfg.tui.as_ref().map(|t| t.color_mode).unwrap_or_default(),
+            tui_history_budget: cfg.tui.as_ref().and_then(|t| t.history_budget.clone()),
+            tui_spinner: cfg.tui.as_ref().and_then(|t| t.spinner.clone()),
+            tui_interrupt_hint_after_seconds: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.interrupt_hint_after_seconds),
+            tui_bell: cfg.tui.as_ref().map(|t| t.bell.clone()).unwrap_or_default(),
+            tui_ascii_only: cfg.tui.as_ref().and_then(|t| t.ascii_only),
+            tui_language: cfg.tui.as_ref().and_then(|t| t.language.clone()),
+            tui_cell_renderers: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.cell_renderers.clone())
+                .unwrap_or_default(),
+            tui_accessibility_min_contrast: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.accessibility.min_contrast),
+            tui_accessibility_colorblind: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.accessibility.colorblind),
+            tui_assume_background: cfg.tui.as_ref().and_then(|t| t.assume_background.clone()),
+            tui_tips: cfg.tui.as_ref().and_then(|t| t.tips),
+            tui_code_block_overflow: cfg.tui.as_ref().and_then(|t| t.code_block_overflow),
+            tui_collapsed_tool_calls: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.collapsed_tool_calls.clone())
+                .unwrap_or_default(),
+            tui_keybindings: cfg.tui.as_ref().and_then(|t| t.keybindings.clone()),
+            tui_mouse: cfg.tui.as_ref().and_then(|t| t.mouse.clone()),
+            tui_output_folding: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.output_folding.clone())
+                .unwrap_or_default(),
             otel: {
                 let t: OtelConfigToml = cfg.otel.unwrap_or_default();
                 let log_user_prompt = t.log_user_prompt.unwrap_or(false);
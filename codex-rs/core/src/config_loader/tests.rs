@@ -51,6 +51,7 @@ async fn make_config_for_test(
                 project_path.to_string_lossy().to_string(),
                 ProjectConfig {
                     trust_level: Some(trust_level),
+                    ..Default::default()
                 },
             )])),
             project_root_markers,
@@ -1140,6 +1141,51 @@ async fn cli_override_model_instructions_file_sets_base_instructions() -> std::i
     Ok(())
 }
 
+#[tokio::test]
+async fn profile_system_prompt_extra_overrides_top_level() -> std::io::Result<()> {
+    let tmp = tempdir()?;
+    let codex_home = tmp.path().join("home");
+    tokio::fs::create_dir_all(&codex_home).await?;
+
+    let extra_path = tmp.path().join("extra.md");
+    tokio::fs::write(&extra_path, "file extra\n").await?;
+    let cfg = format!(
+        r#"
+system_prompt_extra_file = {extra_path:?}
+
+[profiles.strict]
+system_prompt_extra = "profile extra"
+"#
+    );
+    tokio::fs::write(codex_home.join(CONFIG_TOML_FILE), cfg).await?;
+
+    let cwd = tmp.path().join("work");
+    tokio::fs::create_dir_all(&cwd).await?;
+
+    let config = ConfigBuilder::default()
+        .codex_home(codex_home.clone())
+        .harness_overrides(ConfigOverrides {
+            cwd: Some(cwd.clone()),
+            ..ConfigOverrides::default()
+        })
+        .build()
+        .await?;
+    assert_eq!(config.system_prompt_extra.as_deref(), Some("file extra"));
+
+    let config = ConfigBuilder::default()
+        .codex_home(codex_home)
+        .harness_overrides(ConfigOverrides {
+            cwd: Some(cwd),
+            config_profile: Some("strict".to_string()),
+            ..ConfigOverrides::default()
+        })
+        .build()
+        .await?;
+    assert_eq!(config.system_prompt_extra.as_deref(), Some("profile extra"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn project_layer_is_added_when_dot_codex_exists_without_config_toml() -> std::io::Result<()> {
     let tmp = tempdir()?;
@@ -1424,6 +1470,7 @@ async fn project_trust_does_not_match_configured_alias_for_canonical_cwd() -> st
                 alias_root.to_string_lossy().to_string(),
                 ProjectConfig {
                     trust_level: Some(TrustLevel::Trusted),
+                    ..Default::default()
                 },
             )])),
             ..Default::default()
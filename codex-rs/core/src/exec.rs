@@ -7,6 +7,8 @@ use std::io;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::ExitStatus;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::time::Duration;
 use std::time::Instant;
 
@@ -38,6 +40,7 @@ use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::ExecCommandOutputDeltaEvent;
 use codex_protocol::protocol::ExecOutputStream;
 use codex_protocol::protocol::SandboxPolicy;
+use codex_protocol::protocol::WarningEvent;
 use codex_sandboxing::SandboxCommand;
 use codex_sandboxing::SandboxManager;
 use codex_sandboxing::SandboxTransformRequest;
@@ -66,6 +69,15 @@ const AGGREGATE_BUFFER_INITIAL_CAPACITY: usize = 8 * 1024; // 8 KiB
 /// OOM the process by dumping huge amounts of data to stdout/stderr.
 const EXEC_OUTPUT_MAX_BYTES: usize = DEFAULT_OUTPUT_BYTES_CAP;
 
+/// How long a streamed exec may sit with no new output, while its output tail
+/// looks like an interactive prompt, before we warn the user that it is
+/// probably blocked waiting on stdin (which the shell tool does not provide).
+const PROMPT_STALL_WARNING_AFTER: Duration = Duration::from_secs(10);
+/// How often the prompt-stall watchdog re-checks a streamed exec.
+const PROMPT_STALL_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Output tail retained for the prompt-stall heuristic.
+const PROMPT_STALL_TAIL_BYTES: usize = 256;
+
 /// Limit the number of ExecCommandOutputDelta events emitted per exec call.
 /// Aggregation still collects full output; only the live event stream is capped.
 pub(crate) const MAX_EXEC_OUTPUT_DELTAS_PER_CALL: usize = 10_000;
@@ -310,6 +322,7 @@ pub fn build_exec_request(
             network: network.as_ref(),
             sandbox_policy_cwd: sandbox_cwd,
             codex_linux_sandbox_exe: codex_linux_sandbox_exe.as_deref(),
+            container: None,
             use_legacy_landlock,
             windows_sandbox_level,
             windows_sandbox_private_desktop,
@@ -1231,6 +1244,80 @@ fn has_reopened_writable_descendant(
 
 /// Consumes the output of a child process according to the configured capture
 /// policy.
+/// Shared between the stdout/stderr readers and the prompt-stall watchdog so
+/// the watchdog can tell "still producing output" apart from "silent with a
+/// prompt on screen".
+struct PromptStallTracker {
+    last_activity: Instant,
+    tail: Vec<u8>,
+}
+
+impl PromptStallTracker {
+    fn new() -> Self {
+        Self {
+            last_activity: Instant::now(),
+            tail: Vec::with_capacity(PROMPT_STALL_TAIL_BYTES),
+        }
+    }
+
+    fn record_chunk(&mut self, chunk: &[u8]) {
+        self.last_activity = Instant::now();
+        if chunk.len() >= PROMPT_STALL_TAIL_BYTES {
+            self.tail.clear();
+            self.tail
+                .extend_from_slice(&chunk[chunk.len() - PROMPT_STALL_TAIL_BYTES..]);
+        } else {
+            let overflow = (self.tail.len() + chunk.len()).saturating_sub(PROMPT_STALL_TAIL_BYTES);
+            self.tail.drain(..overflow);
+            self.tail.extend_from_slice(chunk);
+        }
+    }
+
+    /// Returns the prompt line when the stream has been silent long enough and
+    /// the tail looks like a question waiting for an answer.
+    fn stalled_prompt(&self) -> Option<String> {
+        if self.last_activity.elapsed() < PROMPT_STALL_WARNING_AFTER {
+            return None;
+        }
+        let tail = String::from_utf8_lossy(&self.tail);
+        looks_like_interactive_prompt(&tail).map(str::to_string)
+    }
+}
+
+/// Conservative heuristic for "this command printed a question and is now
+/// waiting for a reply": the output must not end with a newline, and the
+/// dangling line must end in a well-known prompt shape (`[y/N]`, `(yes/no)`,
+/// `password:`, a trailing `?`, ...).
+fn looks_like_interactive_prompt(tail: &str) -> Option<&str> {
+    if tail.is_empty() || tail.ends_with('\n') {
+        return None;
+    }
+    let line = tail.rsplit('\n').next().unwrap_or(tail).trim_end();
+    if line.is_empty() {
+        return None;
+    }
+    let lower = line.to_lowercase();
+    const PROMPT_SUFFIXES: &[&str] = &[
+        "[y/n]",
+        "[y/n]:",
+        "(y/n)",
+        "(y/n):",
+        "[yes/no]",
+        "(yes/no)",
+        "(yes/no):",
+        "password:",
+        "passphrase:",
+        "username:",
+        "login:",
+        "?",
+        "? ",
+    ];
+    PROMPT_SUFFIXES
+        .iter()
+        .any(|suffix| lower.ends_with(suffix))
+        .then_some(line)
+}
+
 async fn consume_output(
     mut child: Child,
     expiration: ExecExpiration,
@@ -1253,18 +1340,30 @@ async fn consume_output(
     })?;
 
     let retained_bytes_cap = capture_policy.retained_bytes_cap();
+    let stall_tracker = stdout_stream
+        .as_ref()
+        .map(|_| Arc::new(Mutex::new(PromptStallTracker::new())));
     let stdout_handle = tokio::spawn(read_output(
         BufReader::new(stdout_reader),
         stdout_stream.clone(),
         /*is_stderr*/ false,
         retained_bytes_cap,
+        stall_tracker.clone(),
     ));
     let stderr_handle = tokio::spawn(read_output(
         BufReader::new(stderr_reader),
         stdout_stream.clone(),
         /*is_stderr*/ true,
         retained_bytes_cap,
+        stall_tracker.clone(),
     ));
+    let stall_watchdog =
+        stall_tracker
+            .as_ref()
+            .zip(stdout_stream.as_ref())
+            .map(|(tracker, stream)| {
+                tokio::spawn(prompt_stall_watchdog(Arc::clone(tracker), stream.clone()))
+            });
 
     let expiration_wait = async {
         if capture_policy.uses_expiration() {
@@ -1291,6 +1390,10 @@ async fn consume_output(
         }
     };
 
+    if let Some(watchdog) = stall_watchdog {
+        watchdog.abort();
+    }
+
     // We need mutable bindings so we can `abort()` them on timeout.
     use tokio::task::JoinHandle;
 
@@ -1330,11 +1433,43 @@ async fn consume_output(
     })
 }
 
+/// Periodically checks a streamed exec for the "printed a prompt, then went
+/// quiet" pattern and warns the user once. The shell tool runs children with
+/// a null stdin, so a command waiting on `[y/N]` will otherwise hang silently
+/// until the timeout; the warning tells the user to kill it or rerun it with
+/// the answer supplied up front. Aborted by `consume_output` once the child
+/// exits.
+async fn prompt_stall_watchdog(tracker: Arc<Mutex<PromptStallTracker>>, stream: StdoutStream) {
+    let mut interval = tokio::time::interval(PROMPT_STALL_POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+        let prompt = tracker
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .stalled_prompt();
+        let Some(prompt) = prompt else {
+            continue;
+        };
+        let message = format!(
+            "The running command looks like it is waiting for input ({prompt:?}) but was started \
+             without stdin, so no answer will arrive. Interrupt it, or rerun it with the answer \
+             supplied on the command line (for example `--yes`)."
+        );
+        let event = Event {
+            id: stream.sub_id.clone(),
+            msg: EventMsg::Warning(WarningEvent { message }),
+        };
+        let _ = stream.tx_event.send(event).await;
+        return;
+    }
+}
+
 async fn read_output<R: AsyncRead + Unpin + Send + 'static>(
     mut reader: R,
     stream: Option<StdoutStream>,
     is_stderr: bool,
     max_bytes: Option<usize>,
+    stall_tracker: Option<Arc<Mutex<PromptStallTracker>>>,
 ) -> io::Result<StreamOutput<Vec<u8>>> {
     let mut buf = Vec::with_capacity(
         max_bytes.map_or(AGGREGATE_BUFFER_INITIAL_CAPACITY, |max_bytes| {
@@ -1350,6 +1485,13 @@ async fn read_output<R: AsyncRead + Unpin + Send + 'static>(
             break;
         }
 
+        if let Some(tracker) = &stall_tracker {
+            tracker
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .record_chunk(&tmp[..n]);
+        }
+
         if let Some(stream) = &stream
             && emitted_deltas < MAX_EXEC_OUTPUT_DELTAS_PER_CALL
         {
@@ -1,7 +1,7 @@
-#[cfg(test)]
 use codex_config::types::EnvironmentVariablePattern;
 use codex_config::types::ShellEnvironmentPolicy;
 use codex_protocol::ThreadId;
+use codex_protocol::protocol::ShellEnvOverrides;
 use std::collections::HashMap;
 
 pub use codex_config::shell_environment::CODEX_THREAD_ID_ENV_VAR;
@@ -24,6 +24,33 @@ pub fn create_env(
     codex_config::shell_environment::create_env(policy, thread_id.as_deref())
 }
 
+/// Applies session-scoped `/env` overrides to a shell environment policy.
+///
+/// `unset` names are dropped from the policy's `set` entries and excluded from
+/// the inherited environment. `set` entries are inserted into the policy's
+/// `set` map; when `include_only` is non-empty it is widened with the
+/// overridden names because that filter runs after `set` insertion and would
+/// otherwise silently remove an explicit override.
+pub fn apply_shell_env_overrides(
+    policy: &mut ShellEnvironmentPolicy,
+    overrides: &ShellEnvOverrides,
+) {
+    for name in &overrides.unset {
+        policy.r#set.remove(name);
+        policy
+            .exclude
+            .push(EnvironmentVariablePattern::new_case_insensitive(name));
+    }
+    for (name, value) in &overrides.set {
+        policy.r#set.insert(name.clone(), value.clone());
+        if !policy.include_only.is_empty() {
+            policy
+                .include_only
+                .push(EnvironmentVariablePattern::new_case_insensitive(name));
+        }
+    }
+}
+
 #[cfg(all(test, target_os = "windows"))]
 fn create_env_from_vars<I>(
     vars: I,
@@ -257,3 +257,75 @@ fn test_inherit_none() {
     expected.insert(CODEX_THREAD_ID_ENV_VAR.to_string(), thread_id.to_string());
     assert_eq!(result, expected);
 }
+
+#[test]
+fn apply_shell_env_overrides_sets_and_unsets() {
+    let vars = make_vars(&[("PATH", "/usr/bin"), ("FOO", "bar")]);
+
+    let mut policy = ShellEnvironmentPolicy {
+        ignore_default_excludes: true,
+        ..Default::default()
+    };
+    let mut overrides = ShellEnvOverrides::default();
+    overrides
+        .set
+        .insert("NEW_VAR".to_string(), "42".to_string());
+    overrides.unset.push("FOO".to_string());
+    apply_shell_env_overrides(&mut policy, &overrides);
+
+    let thread_id = ThreadId::new();
+    let result = populate_env(vars, &policy, Some(thread_id));
+    let mut expected: HashMap<String, String> = hashmap! {
+        "PATH".to_string() => "/usr/bin".to_string(),
+        "NEW_VAR".to_string() => "42".to_string(),
+    };
+    expected.insert(CODEX_THREAD_ID_ENV_VAR.to_string(), thread_id.to_string());
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn apply_shell_env_overrides_widens_include_only() {
+    let vars = make_vars(&[("PATH", "/usr/bin")]);
+
+    let mut policy = ShellEnvironmentPolicy {
+        ignore_default_excludes: true,
+        include_only: vec![EnvironmentVariablePattern::new_case_insensitive("*PATH")],
+        ..Default::default()
+    };
+    let mut overrides = ShellEnvOverrides::default();
+    overrides
+        .set
+        .insert("NEW_VAR".to_string(), "42".to_string());
+    apply_shell_env_overrides(&mut policy, &overrides);
+
+    let thread_id = ThreadId::new();
+    let result = populate_env(vars, &policy, Some(thread_id));
+    let mut expected: HashMap<String, String> = hashmap! {
+        "PATH".to_string() => "/usr/bin".to_string(),
+        "NEW_VAR".to_string() => "42".to_string(),
+    };
+    expected.insert(CODEX_THREAD_ID_ENV_VAR.to_string(), thread_id.to_string());
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn apply_shell_env_overrides_unset_removes_policy_set_entry() {
+    let vars = make_vars(&[("PATH", "/usr/bin")]);
+
+    let mut policy = ShellEnvironmentPolicy {
+        ignore_default_excludes: true,
+        ..Default::default()
+    };
+    policy.r#set.insert("FOO".to_string(), "bar".to_string());
+    let mut overrides = ShellEnvOverrides::default();
+    overrides.unset.push("FOO".to_string());
+    apply_shell_env_overrides(&mut policy, &overrides);
+
+    let thread_id = ThreadId::new();
+    let result = populate_env(vars, &policy, Some(thread_id));
+    let mut expected: HashMap<String, String> = hashmap! {
+        "PATH".to_string() => "/usr/bin".to_string(),
+    };
+    expected.insert(CODEX_THREAD_ID_ENV_VAR.to_string(), thread_id.to_string());
+    assert_eq!(result, expected);
+}
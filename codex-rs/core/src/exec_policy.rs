@@ -24,6 +24,7 @@ use codex_protocol::permissions::FileSystemSandboxKind;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::SandboxPolicy;
+use codex_shell_command::high_risk::command_high_risk;
 use codex_shell_command::is_dangerous_command::command_might_be_dangerous;
 use codex_shell_command::is_safe_command::is_known_safe_command;
 use thiserror::Error;
@@ -595,13 +596,16 @@ pub fn render_decision_for_unmatched_command(
     let environment_lacks_sandbox_protections =
         cfg!(windows) && matches!(sandbox_policy, SandboxPolicy::ReadOnly { .. });
 
-    // If the command is flagged as dangerous or we have no sandbox protection,
-    // we should never allow it to run without approval.
+    // If the command is flagged as dangerous or high-risk, or we have no
+    // sandbox protection, we should never allow it to run without approval.
     //
     // We prefer to prompt the user rather than outright forbid the command,
     // but if the user has explicitly disabled prompts, we must
     // forbid the command.
-    if command_might_be_dangerous(command) || environment_lacks_sandbox_protections {
+    if command_might_be_dangerous(command)
+        || command_high_risk(command).is_some()
+        || environment_lacks_sandbox_protections
+    {
         return match approval_policy {
             AskForApproval::Never => {
                 let sandbox_is_explicitly_disabled = matches!(
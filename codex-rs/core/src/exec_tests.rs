@@ -1065,3 +1065,44 @@ fn long_running_command() -> Vec<String> {
         "Start-Sleep -Seconds 30".to_string(),
     ]
 }
+
+#[test]
+fn interactive_prompt_detection_matches_common_prompt_shapes() {
+    assert_eq!(
+        looks_like_interactive_prompt("Proceed with install? [y/N]"),
+        Some("Proceed with install? [y/N]")
+    );
+    assert_eq!(
+        looks_like_interactive_prompt("step one\nPassword:"),
+        Some("Password:")
+    );
+    assert_eq!(
+        looks_like_interactive_prompt("Overwrite existing file?"),
+        Some("Overwrite existing file?")
+    );
+}
+
+#[test]
+fn interactive_prompt_detection_ignores_completed_lines() {
+    // A trailing newline means the command moved on; no prompt is dangling.
+    assert_eq!(
+        looks_like_interactive_prompt("Proceed with install? [y/N]\n"),
+        None
+    );
+    assert_eq!(looks_like_interactive_prompt("compiling foo v0.1.0"), None);
+    assert_eq!(looks_like_interactive_prompt(""), None);
+}
+
+#[test]
+fn prompt_stall_tracker_keeps_only_the_output_tail() {
+    let mut tracker = PromptStallTracker::new();
+    tracker.record_chunk(&[b'a'; PROMPT_STALL_TAIL_BYTES * 2]);
+    assert_eq!(tracker.tail.len(), PROMPT_STALL_TAIL_BYTES);
+
+    tracker.record_chunk(b"Continue? [y/N]");
+    assert_eq!(tracker.tail.len(), PROMPT_STALL_TAIL_BYTES);
+    assert!(tracker.tail.ends_with(b"Continue? [y/N]"));
+
+    // The warning only fires after the silence threshold.
+    assert_eq!(tracker.stalled_prompt(), None);
+}
@@ -0,0 +1,219 @@
+//! Speculative prefetch of files a prompt is likely to need.
+//!
+//! When a user turn starts, path-like tokens in the prompt are resolved
+//! against the session cwd and read in the background, together with their
+//! test counterparts (`foo.rs` ⇄ `foo_tests.rs`, matching this workspace's
+//! test layout). Reading warms the OS file cache so later tool calls that
+//! touch the same files come back faster; nothing is ever served from the
+//! prefetch itself, so a stale prefetch can never corrupt a tool result.
+//! Hit/miss counters accumulate per session and are logged at debug level
+//! when a turn completes.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use regex_lite::Regex;
+use tokio::sync::Mutex;
+
+/// Individual files larger than this are not worth warming.
+const MAX_PREFETCH_FILE_BYTES: u64 = 256 * 1024;
+/// Total bytes read per prefetch pass.
+const MAX_PREFETCH_TOTAL_BYTES: u64 = 4 * 1024 * 1024;
+/// Candidate cap per prefetch pass, applied after deduplication.
+const MAX_PREFETCH_FILES: usize = 32;
+
+/// Session-scoped prefetch bookkeeping. Shared through `SessionServices`.
+#[derive(Default)]
+pub(crate) struct FilePrefetcher {
+    state: Mutex<PrefetchState>,
+}
+
+#[derive(Default)]
+struct PrefetchState {
+    prefetched: HashSet<PathBuf>,
+    prefetched_bytes: u64,
+    hits: u64,
+    misses: u64,
+}
+
+/// Counters reported once per turn. Misses include paths that could never
+/// have been prefetched (e.g. files a patch creates), so treat the ratio as
+/// indicative rather than exact.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct FilePrefetchMetrics {
+    pub(crate) prefetched_files: usize,
+    pub(crate) prefetched_bytes: u64,
+    pub(crate) hits: u64,
+    pub(crate) misses: u64,
+}
+
+impl FilePrefetcher {
+    /// Kicks off a background prefetch pass for `prompt`; never blocks the
+    /// turn that triggered it.
+    pub(crate) fn spawn_prefetch(self: &Arc<Self>, prompt: String, cwd: PathBuf) {
+        let prefetcher = Arc::clone(self);
+        tokio::spawn(async move {
+            prefetcher.prefetch(&prompt, &cwd).await;
+        });
+    }
+
+    async fn prefetch(&self, prompt: &str, cwd: &Path) {
+        let mut total_bytes = 0_u64;
+        let mut prefetched_files = 0_usize;
+        for path in candidate_paths(prompt, cwd) {
+            if prefetched_files >= MAX_PREFETCH_FILES || total_bytes >= MAX_PREFETCH_TOTAL_BYTES {
+                break;
+            }
+            if self.state.lock().await.prefetched.contains(&path) {
+                continue;
+            }
+            let Ok(metadata) = tokio::fs::metadata(&path).await else {
+                continue;
+            };
+            if !metadata.is_file() || metadata.len() > MAX_PREFETCH_FILE_BYTES {
+                continue;
+            }
+            let Ok(bytes) = tokio::fs::read(&path).await else {
+                continue;
+            };
+            total_bytes += bytes.len() as u64;
+            prefetched_files += 1;
+            let mut state = self.state.lock().await;
+            state.prefetched.insert(path);
+            state.prefetched_bytes += bytes.len() as u64;
+        }
+        if prefetched_files > 0 {
+            tracing::debug!(prefetched_files, total_bytes, "prefetched prompt files");
+        }
+    }
+
+    /// Records that a tool touched `path`, counting it as a prefetch hit or
+    /// miss.
+    pub(crate) async fn note_access(&self, path: &Path) {
+        let mut state = self.state.lock().await;
+        if state.prefetched.contains(path) {
+            state.hits += 1;
+        } else {
+            state.misses += 1;
+        }
+    }
+
+    pub(crate) async fn metrics(&self) -> FilePrefetchMetrics {
+        let state = self.state.lock().await;
+        FilePrefetchMetrics {
+            prefetched_files: state.prefetched.len(),
+            prefetched_bytes: state.prefetched_bytes,
+            hits: state.hits,
+            misses: state.misses,
+        }
+    }
+}
+
+/// Path-like tokens mentioned in `prompt`, resolved against `cwd`, plus the
+/// test counterpart of every mentioned Rust source file. Order follows the
+/// prompt so the most prominent mentions win when budgets bite.
+fn candidate_paths(prompt: &str, cwd: &Path) -> Vec<PathBuf> {
+    static TOKEN_RE: LazyLock<Regex> =
+        LazyLock::new(
+            || match Regex::new(r"[A-Za-z0-9_][A-Za-z0-9_./-]*\.[A-Za-z0-9]+") {
+                Ok(regex) => regex,
+                Err(error) => panic!("invalid prefetch token regex: {error}"),
+            },
+        );
+    let mut seen = HashSet::new();
+    let mut candidates = Vec::new();
+    let mut push = |path: PathBuf| {
+        if seen.insert(path.clone()) {
+            candidates.push(path);
+        }
+    };
+    for token in TOKEN_RE.find_iter(prompt) {
+        let token = token.as_str().trim_end_matches(['.', ',']);
+        let path = Path::new(token);
+        let resolved = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            cwd.join(path)
+        };
+        push(resolved.clone());
+        if let Some(counterpart) = test_counterpart(&resolved) {
+            push(counterpart);
+        }
+    }
+    candidates
+}
+
+/// `foo.rs` ⇄ `foo_tests.rs`: mentioning one makes the other likely.
+fn test_counterpart(path: &Path) -> Option<PathBuf> {
+    let stem = path.file_stem()?.to_str()?;
+    if path.extension()?.to_str()? != "rs" {
+        return None;
+    }
+    let counterpart = match stem.strip_suffix("_tests") {
+        Some(base) => format!("{base}.rs"),
+        None => format!("{stem}_tests.rs"),
+    };
+    Some(path.with_file_name(counterpart))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn candidate_paths_resolve_mentions_and_test_counterparts() {
+        let cwd = Path::new("/repo");
+        let candidates = candidate_paths(
+            "please fix src/keymap.rs, the spec is in docs/keys.md.",
+            cwd,
+        );
+        assert_eq!(
+            candidates,
+            vec![
+                PathBuf::from("/repo/src/keymap.rs"),
+                PathBuf::from("/repo/src/keymap_tests.rs"),
+                PathBuf::from("/repo/docs/keys.md"),
+            ]
+        );
+    }
+
+    #[test]
+    fn mentioning_a_test_file_adds_the_source_file() {
+        let candidates = candidate_paths("src/app_tests.rs fails", Path::new("/repo"));
+        assert_eq!(
+            candidates,
+            vec![
+                PathBuf::from("/repo/src/app_tests.rs"),
+                PathBuf::from("/repo/src/app.rs"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn prefetch_counts_hits_and_skips_oversized_files() {
+        let root = tempfile::tempdir().expect("tempdir");
+        std::fs::write(root.path().join("small.rs"), "fn main() {}\n").expect("write");
+        std::fs::write(
+            root.path().join("big.rs"),
+            vec![b'x'; (MAX_PREFETCH_FILE_BYTES + 1) as usize],
+        )
+        .expect("write");
+
+        let prefetcher = FilePrefetcher::default();
+        prefetcher
+            .prefetch("look at small.rs and big.rs", root.path())
+            .await;
+
+        prefetcher.note_access(&root.path().join("small.rs")).await;
+        prefetcher.note_access(&root.path().join("other.rs")).await;
+
+        let metrics = prefetcher.metrics().await;
+        assert_eq!(metrics.prefetched_files, 1);
+        assert_eq!(metrics.hits, 1);
+        assert_eq!(metrics.misses, 1);
+    }
+}
@@ -22,6 +22,7 @@ pub use codex_thread::CodexThread;
 pub use codex_thread::ThreadConfigSnapshot;
 mod agent;
 mod codex_delegate;
+pub mod codex_ignore;
 mod command_canonicalization;
 mod commit_attribution;
 pub mod config;
@@ -34,6 +35,7 @@ pub mod exec;
 pub mod exec_env;
 mod exec_policy;
 pub mod external_agent_config;
+mod file_prefetch;
 pub mod file_watcher;
 mod flags;
 #[cfg(test)]
@@ -85,6 +87,7 @@ pub(crate) mod mentions {
 }
 mod sandbox_tags;
 pub mod sandboxing;
+mod session_limits;
 mod session_prefix;
 mod session_startup_prewarm;
 mod shell_detect;
@@ -107,9 +110,12 @@ pub(crate) use skills::render_skills_section;
 pub(crate) use skills::resolve_skill_dependencies_for_turn;
 pub(crate) use skills::skills_load_input_from_config;
 mod skills_watcher;
+pub mod storage_gc;
 mod stream_events_utils;
 pub mod test_support;
+pub mod theme_import;
 mod unified_exec;
+pub mod wasm_tools;
 pub mod windows_sandbox;
 pub use client::X_RESPONSESAPI_INCLUDE_TIMING_METRICS_HEADER;
 pub use codex_protocol::config_types::ModelProviderAuthInfo;
@@ -149,6 +155,7 @@ mod thread_rollout_truncation;
 mod tools;
 pub(crate) mod turn_diff_tracker;
 mod turn_metadata;
+mod turn_repro;
 mod turn_timing;
 mod unavailable_tool;
 pub use rollout::ARCHIVED_SESSIONS_SUBDIR;
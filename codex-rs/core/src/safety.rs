@@ -67,47 +67,74 @@ pub fn assess_patch_safety(
     // Even though the patch appears to be constrained to writable paths, it is
     // possible that paths in the patch are hard links to files outside the
     // writable roots, so we should still run `apply_patch` in a sandbox in that case.
-    if is_write_patch_constrained_to_writable_paths(action, file_system_sandbox_policy, cwd)
-        || matches!(policy, AskForApproval::OnFailure)
-    {
-        if matches!(
-            sandbox_policy,
-            SandboxPolicy::DangerFullAccess | SandboxPolicy::ExternalSandbox { .. }
-        ) {
-            // DangerFullAccess is intended to bypass sandboxing entirely.
-            SafetyCheck::AutoApprove {
-                sandbox_type: SandboxType::None,
-                user_explicitly_approved: false,
-            }
-        } else {
-            // Only auto‑approve when we can actually enforce a sandbox. Otherwise
-            // fall back to asking the user because the patch may touch arbitrary
-            // paths outside the project.
-            match get_platform_sandbox(windows_sandbox_level != WindowsSandboxLevel::Disabled) {
-                Some(sandbox_type) => SafetyCheck::AutoApprove {
-                    sandbox_type,
+    let verdict =
+        if is_write_patch_constrained_to_writable_paths(action, file_system_sandbox_policy, cwd)
+            || matches!(policy, AskForApproval::OnFailure)
+        {
+            if matches!(
+                sandbox_policy,
+                SandboxPolicy::DangerFullAccess | SandboxPolicy::ExternalSandbox { .. }
+            ) {
+                // DangerFullAccess is intended to bypass sandboxing entirely.
+                SafetyCheck::AutoApprove {
+                    sandbox_type: SandboxType::None,
                     user_explicitly_approved: false,
-                },
-                None => {
-                    if rejects_sandbox_approval {
-                        SafetyCheck::Reject {
-                            reason: patch_rejection_reason(sandbox_policy).to_string(),
+                }
+            } else {
+                // Only auto‑approve when we can actually enforce a sandbox. Otherwise
+                // fall back to asking the user because the patch may touch arbitrary
+                // paths outside the project.
+                match get_platform_sandbox(windows_sandbox_level != WindowsSandboxLevel::Disabled) {
+                    Some(sandbox_type) => SafetyCheck::AutoApprove {
+                        sandbox_type,
+                        user_explicitly_approved: false,
+                    },
+                    None => {
+                        if rejects_sandbox_approval {
+                            SafetyCheck::Reject {
+                                reason: patch_rejection_reason(sandbox_policy).to_string(),
+                            }
+                        } else {
+                            SafetyCheck::AskUser
                         }
-                    } else {
-                        SafetyCheck::AskUser
                     }
                 }
             }
+        } else if rejects_sandbox_approval {
+            SafetyCheck::Reject {
+                reason: patch_rejection_reason(sandbox_policy).to_string(),
+            }
+        } else {
+            SafetyCheck::AskUser
+        };
+
+    // Large writes get an explicit confirmation step: auto-approving would
+    // commit megabytes to disk without the user ever seeing a size. `Never`
+    // cannot ask, so it keeps the sandboxed auto-approval path.
+    match verdict {
+        SafetyCheck::AutoApprove { .. }
+            if patch_contains_large_write(action) && !matches!(policy, AskForApproval::Never) =>
+        {
+            SafetyCheck::AskUser
         }
-    } else if rejects_sandbox_approval {
-        SafetyCheck::Reject {
-            reason: patch_rejection_reason(sandbox_policy).to_string(),
-        }
-    } else {
-        SafetyCheck::AskUser
+        other => other,
     }
 }
 
+/// Writes at or above this size are always confirmed with the user rather
+/// than auto-approved into the sandbox.
+pub(crate) const LARGE_WRITE_CONFIRMATION_BYTES: usize = 5 * 1024 * 1024;
+
+fn patch_contains_large_write(action: &ApplyPatchAction) -> bool {
+    action.changes().values().any(|change| match change {
+        ApplyPatchFileChange::Add { content } => content.len() >= LARGE_WRITE_CONFIRMATION_BYTES,
+        ApplyPatchFileChange::Update { new_content, .. } => {
+            new_content.len() >= LARGE_WRITE_CONFIRMATION_BYTES
+        }
+        ApplyPatchFileChange::Delete { .. } => false,
+    })
+}
+
 fn patch_rejection_reason(sandbox_policy: &SandboxPolicy) -> &'static str {
     match sandbox_policy {
         SandboxPolicy::ReadOnly { .. } => PATCH_REJECTED_READ_ONLY_REASON,
@@ -89,6 +89,49 @@ fn external_sandbox_auto_approves_in_on_request() {
     );
 }
 
+#[test]
+fn large_write_downgrades_auto_approval_to_ask_user() {
+    let tmp = TempDir::new().unwrap();
+    let cwd = tmp.path().abs();
+    let add_inside_path = cwd.join("big.bin");
+    let large_content = "x".repeat(LARGE_WRITE_CONFIRMATION_BYTES);
+    let add_inside = ApplyPatchAction::new_add_for_test(&add_inside_path, large_content);
+
+    let policy = SandboxPolicy::ExternalSandbox {
+        network_access: codex_protocol::protocol::NetworkAccess::Enabled,
+    };
+
+    // An add this large is confirmed with the user even though a smaller one
+    // would be auto-approved (see external_sandbox_auto_approves_in_on_request).
+    assert_eq!(
+        assess_patch_safety(
+            &add_inside,
+            AskForApproval::OnRequest,
+            &policy,
+            &FileSystemSandboxPolicy::from(&policy),
+            &cwd,
+            WindowsSandboxLevel::Disabled
+        ),
+        SafetyCheck::AskUser
+    );
+
+    // `Never` has no way to ask, so it keeps the auto-approval path.
+    assert_eq!(
+        assess_patch_safety(
+            &add_inside,
+            AskForApproval::Never,
+            &policy,
+            &FileSystemSandboxPolicy::from(&policy),
+            &cwd,
+            WindowsSandboxLevel::Disabled
+        ),
+        SafetyCheck::AutoApprove {
+            sandbox_type: SandboxType::None,
+            user_explicitly_approved: false,
+        }
+    );
+}
+
 #[test]
 fn granular_with_all_flags_true_matches_on_request_for_out_of_root_patch() {
     let tmp = TempDir::new().unwrap();
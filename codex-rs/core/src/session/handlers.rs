@@ -211,6 +211,10 @@ pub(super) async fn user_input_or_turn_inner(
             current_context.session_telemetry.user_prompt(&items);
             sess.refresh_mcp_servers_if_requested(&current_context)
                 .await;
+            sess.services.file_prefetcher.spawn_prefetch(
+                UserMessageItem::new(&items).message(),
+                current_context.cwd.to_path_buf(),
+            );
             let accepted_items = items.clone();
             sess.spawn_task(
                 Arc::clone(&current_context),
@@ -1094,6 +1098,18 @@ pub(super) async fn submission_loop(
                     .await;
                     false
                 }
+                Op::OverrideShellEnv { overrides } => {
+                    override_turn_context(
+                        &sess,
+                        sub.id.clone(),
+                        SessionSettingsUpdate {
+                            shell_env_overrides: Some(overrides),
+                            ..Default::default()
+                        },
+                    )
+                    .await;
+                    false
+                }
                 Op::UserInput { .. } | Op::UserTurn { .. } => {
                     user_input_or_turn(&sess, sub.id.clone(), sub.op).await;
                     false
@@ -97,6 +97,7 @@ use codex_protocol::protocol::RawResponseItemEvent;
 use codex_protocol::protocol::ReviewRequest;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::SessionSource;
+use codex_protocol::protocol::ShellEnvOverrides;
 use codex_protocol::protocol::SubAgentSource;
 use codex_protocol::protocol::TurnAbortReason;
 use codex_protocol::protocol::TurnContextItem;
@@ -112,6 +113,7 @@ use codex_protocol::request_user_input::RequestUserInputResponse;
 use codex_rmcp_client::ElicitationResponse;
 use codex_rollout::RolloutConfig;
 use codex_rollout::state_db;
+use codex_shell_command::high_risk::command_high_risk;
 use codex_shell_command::parse_command::parse_command;
 use codex_terminal_detection::user_agent;
 use codex_thread_store::LocalThreadStore;
@@ -262,6 +264,7 @@ use crate::rollout::RolloutRecorderParams;
 use crate::rollout::map_session_init_error;
 use crate::rollout::metadata;
 use crate::rollout::policy::EventPersistenceMode;
+use crate::session_limits::LimitsCheck;
 use crate::session_startup_prewarm::SessionStartupPrewarmHandle;
 use crate::shell;
 use crate::shell_snapshot::ShellSnapshot;
@@ -287,6 +290,7 @@ use crate::tools::network_approval::build_network_policy_decider;
 #[cfg(test)]
 use crate::tools::parallel::ToolCallRuntime;
 use crate::tools::sandboxing::ApprovalStore;
+use crate::turn_repro::TurnReproState;
 use crate::turn_timing::TurnTimingState;
 use crate::turn_timing::record_turn_ttfm_metric;
 use crate::unified_exec::UnifiedExecProcessManager;
@@ -555,6 +559,15 @@ impl Codex {
             .clone()
             .or_else(|| conversation_history.get_base_instructions().map(|s| s.text))
             .unwrap_or_else(|| model_info.get_model_instructions(config.personality));
+        // Append the configured `system_prompt_extra`, whichever of the
+        // sources above won. Resumed threads already carry the appended text
+        // in their session meta, so skip the append rather than doubling it.
+        let base_instructions = match config.system_prompt_extra.as_deref() {
+            Some(extra) if !base_instructions.ends_with(extra) => {
+                format!("{base_instructions}\n\n{extra}")
+            }
+            _ => base_instructions,
+        };
 
         // Respect thread-start tools. When missing (resumed/forked threads), read from the db
         // first, then fall back to rollout-file tools.
@@ -621,6 +634,7 @@ impl Codex {
             persist_extended_history,
             inherited_shell_snapshot,
             user_shell_override,
+            shell_env_overrides: ShellEnvOverrides::default(),
         };
 
         // Generate a unique ID for the lifetime of this Codex session.
@@ -1093,6 +1107,15 @@ impl Session {
         state.get_total_token_usage(state.server_reasoning_included())
     }
 
+    /// Counts a new regular turn against the `[limits]` session budget and
+    /// returns the verdict evaluated against cumulative usage.
+    pub(crate) async fn check_session_limits_for_new_turn(&self) -> LimitsCheck {
+        let mut state = self.state.lock().await;
+        let total_tokens = state.get_total_token_usage(state.server_reasoning_included());
+        state.note_limits_turn_started();
+        state.check_limits(total_tokens)
+    }
+
     pub(crate) async fn get_total_token_usage_breakdown(&self) -> TotalTokenUsageBreakdown {
         let state = self.state.lock().await;
         state.history.get_total_token_usage_breakdown()
@@ -1819,6 +1842,15 @@ impl Session {
         }
 
         let parsed_cmd = parse_command(&command);
+        // High-risk commands must be confirmed one invocation at a time: never
+        // propose an execpolicy amendment for them, and strip any decisions
+        // that would blanket-approve future runs.
+        let high_risk = command_high_risk(&command).is_some();
+        let proposed_execpolicy_amendment = if high_risk {
+            None
+        } else {
+            proposed_execpolicy_amendment
+        };
         let proposed_network_policy_amendments = network_approval_context.as_ref().map(|context| {
             vec![
                 NetworkPolicyAmendment {
@@ -1831,7 +1863,7 @@ impl Session {
                 },
             ]
         });
-        let available_decisions = available_decisions.unwrap_or_else(|| {
+        let mut available_decisions = available_decisions.unwrap_or_else(|| {
             ExecApprovalRequestEvent::default_available_decisions(
                 network_approval_context.as_ref(),
                 proposed_execpolicy_amendment.as_ref(),
@@ -1839,6 +1871,15 @@ impl Session {
                 additional_permissions.as_ref(),
             )
         });
+        if high_risk {
+            available_decisions.retain(|decision| {
+                !matches!(
+                    decision,
+                    ReviewDecision::ApprovedForSession
+                        | ReviewDecision::ApprovedExecpolicyAmendment { .. }
+                )
+            });
+        }
         let event = EventMsg::ExecApprovalRequest(ExecApprovalRequestEvent {
             call_id,
             approval_id,
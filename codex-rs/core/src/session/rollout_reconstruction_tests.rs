@@ -151,6 +151,7 @@ async fn record_initial_history_resumed_hydrates_previous_turn_settings_from_lif
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
     ];
@@ -218,6 +219,7 @@ async fn reconstruct_history_rollback_keeps_history_and_metadata_in_sync_for_com
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -245,6 +247,7 @@ async fn reconstruct_history_rollback_keeps_history_and_metadata_in_sync_for_com
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::ThreadRolledBack(
@@ -314,6 +317,7 @@ async fn reconstruct_history_rollback_keeps_history_and_metadata_in_sync_for_inc
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -403,6 +407,7 @@ async fn reconstruct_history_rollback_skips_non_user_turns_for_history_and_metad
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -429,6 +434,7 @@ async fn reconstruct_history_rollback_skips_non_user_turns_for_history_and_metad
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -446,6 +452,7 @@ async fn reconstruct_history_rollback_skips_non_user_turns_for_history_and_metad
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::ThreadRolledBack(
@@ -518,6 +525,7 @@ async fn reconstruct_history_rollback_counts_inter_agent_assistant_turns() {
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -537,6 +545,7 @@ async fn reconstruct_history_rollback_counts_inter_agent_assistant_turns() {
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::ThreadRolledBack(
@@ -604,6 +613,7 @@ async fn reconstruct_history_rollback_clears_history_and_metadata_when_exceeding
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::ThreadRolledBack(
@@ -653,6 +663,7 @@ async fn record_initial_history_resumed_rollback_skips_only_user_turns() {
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         // Standalone task turn (no UserMessage) should not consume rollback skips.
@@ -670,6 +681,7 @@ async fn record_initial_history_resumed_rollback_skips_only_user_turns() {
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::ThreadRolledBack(
@@ -723,6 +735,7 @@ async fn record_initial_history_resumed_rollback_drops_incomplete_user_turn_comp
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -879,6 +892,7 @@ async fn reconstruct_history_legacy_compaction_without_replacement_history_clear
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
     ];
@@ -949,6 +963,7 @@ async fn record_initial_history_resumed_turn_context_after_compaction_reestablis
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
     ];
@@ -1052,6 +1067,7 @@ async fn record_initial_history_resumed_aborted_turn_without_id_clears_active_tu
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -1160,6 +1176,7 @@ async fn record_initial_history_resumed_unmatched_abort_preserves_active_turn_fo
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -1193,6 +1210,7 @@ async fn record_initial_history_resumed_unmatched_abort_preserves_active_turn_fo
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
     ];
@@ -1276,6 +1294,7 @@ async fn record_initial_history_resumed_trailing_incomplete_turn_compaction_clea
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -1427,6 +1446,7 @@ async fn record_initial_history_resumed_replaced_incomplete_compacted_turn_clear
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             },
         )),
         RolloutItem::EventMsg(EventMsg::TurnStarted(
@@ -84,6 +84,9 @@ pub(crate) struct SessionConfiguration {
     pub(super) persist_extended_history: bool,
     pub(super) inherited_shell_snapshot: Option<Arc<ShellSnapshot>>,
     pub(super) user_shell_override: Option<shell::Shell>,
+    /// Accumulated `/env`-style overrides applied to every spawned command's
+    /// environment for the rest of the session.
+    pub(super) shell_env_overrides: ShellEnvOverrides,
 }
 
 impl SessionConfiguration {
@@ -91,6 +94,10 @@ impl SessionConfiguration {
         &self.codex_home
     }
 
+    pub(crate) fn limits(&self) -> codex_config::config_toml::LimitsToml {
+        self.original_config_do_not_use.limits.clone()
+    }
+
     pub(super) fn thread_config_snapshot(&self) -> ThreadConfigSnapshot {
         ThreadConfigSnapshot {
             model: self.collaboration_mode.model().to_string(),
@@ -181,6 +188,11 @@ impl SessionConfiguration {
         if let Some(app_server_client_version) = updates.app_server_client_version.clone() {
             next_configuration.app_server_client_version = Some(app_server_client_version);
         }
+        if let Some(shell_env_overrides) = updates.shell_env_overrides.clone() {
+            next_configuration
+                .shell_env_overrides
+                .merge(shell_env_overrides);
+        }
         Ok(next_configuration)
     }
 }
@@ -199,6 +211,7 @@ pub(crate) struct SessionSettingsUpdate {
     pub(crate) personality: Option<Personality>,
     pub(crate) app_server_client_name: Option<String>,
     pub(crate) app_server_client_version: Option<String>,
+    pub(crate) shell_env_overrides: Option<ShellEnvOverrides>,
 }
 
 pub(crate) struct AppServerClientMetadata {
@@ -513,6 +526,13 @@ impl Session {
                     zsh_path.display()
                 )
             })?
+        } else if let Some(shell_path) = config.user_shell_path.as_ref() {
+            shell::get_shell_by_configured_path(shell_path).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "terminal profile shell `{}` is not usable; supported shells are bash, zsh, fish, sh, pwsh, and cmd",
+                    shell_path.display()
+                )
+            })?
         } else {
             shell::default_user_shell()
         };
@@ -685,6 +705,7 @@ impl Session {
                 session_configuration.session_source.clone(),
                 config.model_verbosity,
                 config.features.enabled(Feature::EnableRequestCompression),
+                config.features.enabled(Feature::PromptCaching),
                 config.features.enabled(Feature::RuntimeMetrics),
                 Self::build_model_client_beta_features_header(config.as_ref()),
             ),
@@ -692,6 +713,7 @@ impl Session {
                 config.js_repl_node_path.clone(),
             ),
             environment: environment.clone(),
+            file_prefetcher: Arc::new(crate::file_prefetch::FilePrefetcher::default()),
         };
         services
             .model_client
@@ -1277,6 +1277,7 @@ pub(crate) async fn built_tools(
             parallel_mcp_server_names,
             discoverable_tools,
             dynamic_tools: turn_context.dynamic_tools.as_slice(),
+            wasm_tools: turn_context.config.wasm_tools.clone(),
         },
     )))
 }
@@ -2095,9 +2096,13 @@ async fn try_run_sampling_request(
                 sess.services.models_manager.refresh_if_new_etag(etag).await;
             }
             ResponseEvent::Completed {
-                response_id: _,
+                response_id,
                 token_usage,
             } => {
+                turn_context
+                    .turn_repro_state
+                    .record_provider_request_id(&response_id)
+                    .await;
                 flush_assistant_text_segments_all(
                     &sess,
                     &turn_context,
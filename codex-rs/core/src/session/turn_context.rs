@@ -1,6 +1,7 @@
 use super::*;
 use codex_model_provider::SharedModelProvider;
 use codex_model_provider::create_model_provider;
+use codex_sandboxing::container::ContainerSandboxConfig;
 
 pub(super) fn image_generation_tool_auth_allowed(auth_manager: Option<&AuthManager>) -> bool {
     matches!(
@@ -64,12 +65,14 @@ pub(crate) struct TurnContext {
     pub(crate) final_output_json_schema: Option<Value>,
     pub(crate) codex_self_exe: Option<PathBuf>,
     pub(crate) codex_linux_sandbox_exe: Option<PathBuf>,
+    pub(crate) container_sandbox: Option<ContainerSandboxConfig>,
     pub(crate) tool_call_gate: Arc<ReadinessFlag>,
     pub(crate) truncation_policy: TruncationPolicy,
     pub(crate) js_repl: Arc<JsReplHandle>,
     pub(crate) dynamic_tools: Vec<DynamicToolSpec>,
     pub(crate) turn_metadata_state: Arc<TurnMetadataState>,
     pub(crate) turn_skills: TurnSkillsContext,
+    pub(crate) turn_repro_state: Arc<TurnReproState>,
     pub(crate) turn_timing_state: Arc<TurnTimingState>,
 }
 impl TurnContext {
@@ -190,12 +193,14 @@ impl TurnContext {
             final_output_json_schema: self.final_output_json_schema.clone(),
             codex_self_exe: self.codex_self_exe.clone(),
             codex_linux_sandbox_exe: self.codex_linux_sandbox_exe.clone(),
+            container_sandbox: self.container_sandbox.clone(),
             tool_call_gate: Arc::new(ReadinessFlag::new()),
             truncation_policy,
             js_repl: Arc::clone(&self.js_repl),
             dynamic_tools: self.dynamic_tools.clone(),
             turn_metadata_state: self.turn_metadata_state.clone(),
             turn_skills: self.turn_skills.clone(),
+            turn_repro_state: Arc::clone(&self.turn_repro_state),
             turn_timing_state: Arc::clone(&self.turn_timing_state),
         }
     }
@@ -401,6 +406,12 @@ impl Session {
             session_configuration.windows_sandbox_level,
         ));
         let (current_date, timezone) = local_time_context();
+        let mut shell_environment_policy =
+            per_turn_config.permissions.shell_environment_policy.clone();
+        crate::exec_env::apply_shell_env_overrides(
+            &mut shell_environment_policy,
+            &session_configuration.shell_env_overrides,
+        );
         TurnContext {
             sub_id,
             trace_id: current_span_trace_id(),
@@ -429,19 +440,21 @@ impl Session {
             network_sandbox_policy: session_configuration.network_sandbox_policy,
             network,
             windows_sandbox_level: session_configuration.windows_sandbox_level,
-            shell_environment_policy: per_turn_config.permissions.shell_environment_policy.clone(),
+            shell_environment_policy,
             tools_config,
             features: per_turn_config.features.clone(),
             ghost_snapshot: per_turn_config.ghost_snapshot.clone(),
             final_output_json_schema: None,
             codex_self_exe: per_turn_config.codex_self_exe.clone(),
             codex_linux_sandbox_exe: per_turn_config.codex_linux_sandbox_exe.clone(),
+            container_sandbox: per_turn_config.container_sandbox.clone(),
             tool_call_gate: Arc::new(ReadinessFlag::new()),
             truncation_policy: model_info.truncation_policy.into(),
             js_repl,
             dynamic_tools: session_configuration.dynamic_tools.clone(),
             turn_metadata_state,
             turn_skills: TurnSkillsContext::new(skills_outcome),
+            turn_repro_state: Arc::new(TurnReproState::default()),
             turn_timing_state: Arc::new(TurnTimingState::default()),
         }
     }
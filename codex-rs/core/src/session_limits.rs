@@ -0,0 +1,226 @@
+//! Session budget guardrails.
+//!
+//! Tracks cumulative usage against the optional `[limits]` config — estimated
+//! cost, total tokens, turns, and wall-clock time. The session warns once
+//! usage crosses [`WARN_FRACTION`] of any configured limit and refuses to
+//! start new turns once a limit is exhausted.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_config::config_toml::LimitsToml;
+
+/// Fraction of a limit at which a one-time warning is emitted.
+const WARN_FRACTION: f64 = 0.8;
+
+/// Result of evaluating the configured limits against current usage.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum LimitsCheck {
+    Ok,
+    /// Usage crossed the warning threshold of at least one limit.
+    Warn(String),
+    /// At least one limit is exhausted; the message summarizes the budget.
+    Exceeded(String),
+}
+
+/// Session-scoped usage tracker for the `[limits]` config.
+pub(crate) struct SessionLimitsTracker {
+    limits: LimitsToml,
+    started_at: Instant,
+    turns_started: i64,
+    warned: bool,
+}
+
+impl SessionLimitsTracker {
+    pub(crate) fn new(limits: LimitsToml) -> Self {
+        Self {
+            limits,
+            started_at: Instant::now(),
+            turns_started: 0,
+            warned: false,
+        }
+    }
+
+    pub(crate) fn any_limit_configured(&self) -> bool {
+        self.limits.max_total_tokens.is_some()
+            || self.limits.max_turns.is_some()
+            || self.limits.max_wall_clock_minutes.is_some()
+            || self.cost_limit().is_some()
+    }
+
+    /// Records the start of a user turn for `max_turns` accounting.
+    pub(crate) fn note_turn_started(&mut self) {
+        self.turns_started += 1;
+    }
+
+    /// Evaluates all configured limits against current usage. The warning is
+    /// emitted at most once per session; an exhausted limit is reported on
+    /// every check so each refused turn explains why it was stopped.
+    pub(crate) fn check(&mut self, total_tokens: i64) -> LimitsCheck {
+        if !self.any_limit_configured() {
+            return LimitsCheck::Ok;
+        }
+        let usages = self.limit_usages(total_tokens);
+        if let Some(usage) = usages.iter().find(|usage| usage.fraction >= 1.0) {
+            return LimitsCheck::Exceeded(format!(
+                "Session budget reached: {}. {}",
+                usage.describe(),
+                self.summary(total_tokens)
+            ));
+        }
+        if !self.warned
+            && let Some(usage) = usages.iter().find(|usage| usage.fraction >= WARN_FRACTION)
+        {
+            self.warned = true;
+            return LimitsCheck::Warn(format!("Approaching session budget: {}.", usage.describe()));
+        }
+        LimitsCheck::Ok
+    }
+
+    /// Human-readable per-limit usage summary for `/limits` and stop messages.
+    pub(crate) fn summary(&self, total_tokens: i64) -> String {
+        let usages = self.limit_usages(total_tokens);
+        if usages.is_empty() {
+            return "No session limits configured.".to_string();
+        }
+        let parts: Vec<String> = usages.iter().map(LimitUsage::describe).collect();
+        format!("Budget used: {}.", parts.join("; "))
+    }
+
+    fn cost_limit(&self) -> Option<(f64, f64)> {
+        match (self.limits.max_cost_usd, self.limits.cost_per_1m_tokens_usd) {
+            (Some(max_cost), Some(rate)) if max_cost > 0.0 && rate > 0.0 => Some((max_cost, rate)),
+            _ => None,
+        }
+    }
+
+    fn limit_usages(&self, total_tokens: i64) -> Vec<LimitUsage> {
+        let mut usages = Vec::new();
+        if let Some((max_cost, rate)) = self.cost_limit() {
+            let cost = rate * total_tokens.max(0) as f64 / 1_000_000.0;
+            usages.push(LimitUsage {
+                label: "cost",
+                used: format!("${cost:.2}"),
+                max: format!("${max_cost:.2}"),
+                fraction: cost / max_cost,
+            });
+        }
+        if let Some(max_tokens) = self.limits.max_total_tokens.filter(|max| *max > 0) {
+            usages.push(LimitUsage {
+                label: "tokens",
+                used: total_tokens.to_string(),
+                max: max_tokens.to_string(),
+                fraction: total_tokens as f64 / max_tokens as f64,
+            });
+        }
+        if let Some(max_turns) = self.limits.max_turns.filter(|max| *max > 0) {
+            usages.push(LimitUsage {
+                label: "turns",
+                used: self.turns_started.to_string(),
+                max: max_turns.to_string(),
+                fraction: self.turns_started as f64 / max_turns as f64,
+            });
+        }
+        if let Some(max_minutes) = self.limits.max_wall_clock_minutes.filter(|max| *max > 0) {
+            let elapsed = self.started_at.elapsed();
+            usages.push(LimitUsage {
+                label: "wall clock",
+                used: format!("{}m", elapsed.as_secs() / 60),
+                max: format!("{max_minutes}m"),
+                fraction: elapsed.as_secs_f64()
+                    / Duration::from_secs(max_minutes as u64 * 60).as_secs_f64(),
+            });
+        }
+        usages
+    }
+}
+
+struct LimitUsage {
+    label: &'static str,
+    used: String,
+    max: String,
+    fraction: f64,
+}
+
+impl LimitUsage {
+    fn describe(&self) -> String {
+        format!(
+            "{label} {used} of {max} ({percent:.0}%)",
+            label = self.label,
+            used = self.used,
+            max = self.max,
+            percent = self.fraction * 100.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn no_limits_means_no_verdicts() {
+        let mut tracker = SessionLimitsTracker::new(LimitsToml::default());
+        tracker.note_turn_started();
+        assert_eq!(tracker.check(/*total_tokens*/ 1_000_000), LimitsCheck::Ok);
+    }
+
+    #[test]
+    fn warns_once_then_stops_at_token_limit() {
+        let mut tracker = SessionLimitsTracker::new(LimitsToml {
+            max_total_tokens: Some(1_000),
+            ..Default::default()
+        });
+
+        assert_eq!(tracker.check(/*total_tokens*/ 100), LimitsCheck::Ok);
+        assert!(matches!(
+            tracker.check(/*total_tokens*/ 850),
+            LimitsCheck::Warn(_)
+        ));
+        // The warning is one-shot.
+        assert_eq!(tracker.check(/*total_tokens*/ 860), LimitsCheck::Ok);
+        assert!(matches!(
+            tracker.check(/*total_tokens*/ 1_000),
+            LimitsCheck::Exceeded(_)
+        ));
+    }
+
+    #[test]
+    fn turn_limit_counts_started_turns() {
+        let mut tracker = SessionLimitsTracker::new(LimitsToml {
+            max_turns: Some(2),
+            ..Default::default()
+        });
+
+        tracker.note_turn_started();
+        assert!(matches!(
+            tracker.check(/*total_tokens*/ 0),
+            LimitsCheck::Warn(_)
+        ));
+        tracker.note_turn_started();
+        assert!(matches!(
+            tracker.check(/*total_tokens*/ 0),
+            LimitsCheck::Exceeded(_)
+        ));
+    }
+
+    #[test]
+    fn cost_limit_requires_a_rate() {
+        let mut tracker = SessionLimitsTracker::new(LimitsToml {
+            max_cost_usd: Some(1.0),
+            ..Default::default()
+        });
+        assert_eq!(tracker.check(/*total_tokens*/ 100_000_000), LimitsCheck::Ok);
+
+        let mut tracker = SessionLimitsTracker::new(LimitsToml {
+            max_cost_usd: Some(1.0),
+            cost_per_1m_tokens_usd: Some(10.0),
+            ..Default::default()
+        });
+        assert!(matches!(
+            tracker.check(/*total_tokens*/ 100_000),
+            LimitsCheck::Exceeded(_)
+        ));
+    }
+}
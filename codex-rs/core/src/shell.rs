@@ -10,6 +10,7 @@ use tokio::sync::watch;
 pub enum ShellType {
     Zsh,
     Bash,
+    Fish,
     PowerShell,
     Sh,
     Cmd,
@@ -32,6 +33,7 @@ impl Shell {
         match self.shell_type {
             ShellType::Zsh => "zsh",
             ShellType::Bash => "bash",
+            ShellType::Fish => "fish",
             ShellType::PowerShell => "powershell",
             ShellType::Sh => "sh",
             ShellType::Cmd => "cmd",
@@ -50,6 +52,16 @@ impl Shell {
                     command.to_string(),
                 ]
             }
+            ShellType::Fish => {
+                // fish has no combined `-lc`; the login flag is separate.
+                let mut args = vec![self.shell_path.to_string_lossy().to_string()];
+                if use_login_shell {
+                    args.push("-l".to_string());
+                }
+                args.push("-c".to_string());
+                args.push(command.to_string());
+                args
+            }
             ShellType::PowerShell => {
                 let mut args = vec![self.shell_path.to_string_lossy().to_string()];
                 if !use_login_shell {
@@ -69,6 +81,39 @@ impl Shell {
         }
     }
 
+    /// Quote `arg` so this shell passes it through as a single literal
+    /// word. Quoting rules differ across the supported shells (POSIX single
+    /// quotes, fish backslash escapes, PowerShell doubled quotes, cmd double
+    /// quotes), so commands generated for one terminal profile keep working
+    /// under another.
+    pub fn quote_arg(&self, arg: &str) -> String {
+        if !arg.is_empty()
+            && arg
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "@%+=:,./-_".contains(c))
+        {
+            return arg.to_string();
+        }
+        match self.shell_type {
+            ShellType::Zsh | ShellType::Bash | ShellType::Sh => {
+                format!("'{}'", arg.replace('\'', "'\\''"))
+            }
+            ShellType::Fish => {
+                format!("'{}'", arg.replace('\\', "\\\\").replace('\'', "\\'"))
+            }
+            ShellType::PowerShell => format!("'{}'", arg.replace('\'', "''")),
+            ShellType::Cmd => format!("\"{}\"", arg.replace('"', "\"\"")),
+        }
+    }
+
+    /// Join an argv into one command string quoted for this shell.
+    pub fn quote_command(&self, args: &[String]) -> String {
+        args.iter()
+            .map(|arg| self.quote_arg(arg))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Return the shell snapshot if existing.
     pub fn shell_snapshot(&self) -> Option<Arc<ShellSnapshot>> {
         self.shell_snapshot.borrow().clone()
@@ -221,6 +266,21 @@ fn get_bash_shell(path: Option<&PathBuf>) -> Option<Shell> {
     })
 }
 
+#[cfg(target_os = "macos")]
+const FISH_FALLBACK_PATHS: &[&str] = &["/opt/homebrew/bin/fish", "/usr/local/bin/fish"];
+#[cfg(not(target_os = "macos"))]
+const FISH_FALLBACK_PATHS: &[&str] = &["/usr/bin/fish"];
+
+fn get_fish_shell(path: Option<&PathBuf>) -> Option<Shell> {
+    let shell_path = get_shell_path(ShellType::Fish, path, "fish", FISH_FALLBACK_PATHS);
+
+    shell_path.map(|shell_path| Shell {
+        shell_type: ShellType::Fish,
+        shell_path,
+        shell_snapshot: empty_shell_snapshot_receiver(),
+    })
+}
+
 const SH_FALLBACK_PATHS: &[&str] = &["/bin/sh"];
 
 fn get_sh_shell(path: Option<&PathBuf>) -> Option<Shell> {
@@ -293,6 +353,12 @@ fn ultimate_fallback_shell() -> Shell {
     }
 }
 
+/// Resolve the shell named by a terminal profile: a bare binary name
+/// (`fish`, `pwsh`) or a path. Returns `None` for unsupported shells.
+pub fn get_shell_by_configured_path(shell_path: &PathBuf) -> Option<Shell> {
+    detect_shell_type(shell_path).and_then(|shell_type| get_shell(shell_type, Some(shell_path)))
+}
+
 pub fn get_shell_by_model_provided_path(shell_path: &PathBuf) -> Shell {
     detect_shell_type(shell_path)
         .and_then(|shell_type| get_shell(shell_type, Some(shell_path)))
@@ -303,6 +369,7 @@ pub fn get_shell(shell_type: ShellType, path: Option<&PathBuf>) -> Option<Shell>
     match shell_type {
         ShellType::Zsh => get_zsh_shell(path),
         ShellType::Bash => get_bash_shell(path),
+        ShellType::Fish => get_fish_shell(path),
         ShellType::PowerShell => get_powershell_shell(path),
         ShellType::Sh => get_sh_shell(path),
         ShellType::Cmd => get_cmd_shell(path),
@@ -357,7 +424,14 @@ mod detect_shell_type_tests {
             detect_shell_type(&PathBuf::from("powershell")),
             Some(ShellType::PowerShell)
         );
-        assert_eq!(detect_shell_type(&PathBuf::from("fish")), None);
+        assert_eq!(
+            detect_shell_type(&PathBuf::from("fish")),
+            Some(ShellType::Fish)
+        );
+        assert_eq!(
+            detect_shell_type(&PathBuf::from("/usr/bin/fish")),
+            Some(ShellType::Fish)
+        );
         assert_eq!(detect_shell_type(&PathBuf::from("other")), None);
         assert_eq!(
             detect_shell_type(&PathBuf::from("/bin/zsh")),
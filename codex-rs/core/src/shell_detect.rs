@@ -8,6 +8,7 @@ pub(crate) fn detect_shell_type(shell_path: &PathBuf) -> Option<ShellType> {
         Some("sh") => Some(ShellType::Sh),
         Some("cmd") => Some(ShellType::Cmd),
         Some("bash") => Some(ShellType::Bash),
+        Some("fish") => Some(ShellType::Fish),
         Some("pwsh") => Some(ShellType::PowerShell),
         Some("powershell") => Some(ShellType::PowerShell),
         _ => {
@@ -14,8 +14,8 @@ fn detects_zsh() {
 
 #[test]
 #[cfg(target_os = "macos")]
-fn fish_fallback_to_zsh() {
-    let zsh_shell = default_user_shell_from_path(Some(PathBuf::from("/bin/fish")));
+fn unknown_shell_fallback_to_zsh() {
+    let zsh_shell = default_user_shell_from_path(Some(PathBuf::from("/bin/nushell")));
 
     let shell_path = zsh_shell.shell_path;
 
@@ -190,3 +190,52 @@ fn finds_powershell() {
 
     assert!(shell_path.ends_with("pwsh.exe") || shell_path.ends_with("powershell.exe"));
 }
+
+fn make_shell(shell_type: ShellType, shell_path: &str) -> Shell {
+    Shell {
+        shell_type,
+        shell_path: PathBuf::from(shell_path),
+        shell_snapshot: empty_shell_snapshot_receiver(),
+    }
+}
+
+#[test]
+fn fish_exec_args_use_separate_login_flag() {
+    let fish = make_shell(ShellType::Fish, "/usr/bin/fish");
+
+    assert_eq!(
+        fish.derive_exec_args("echo hi", /*use_login_shell*/ true),
+        vec!["/usr/bin/fish", "-l", "-c", "echo hi"]
+    );
+    assert_eq!(
+        fish.derive_exec_args("echo hi", /*use_login_shell*/ false),
+        vec!["/usr/bin/fish", "-c", "echo hi"]
+    );
+}
+
+#[test]
+fn quote_arg_matches_each_shell_dialect() {
+    let bash = make_shell(ShellType::Bash, "/bin/bash");
+    let fish = make_shell(ShellType::Fish, "/usr/bin/fish");
+    let pwsh = make_shell(ShellType::PowerShell, "pwsh");
+    let cmd = make_shell(ShellType::Cmd, "cmd.exe");
+
+    // Safe words pass through unquoted in every dialect.
+    assert_eq!(bash.quote_arg("a/b-c_1.txt"), "a/b-c_1.txt");
+    assert_eq!(fish.quote_arg("a/b-c_1.txt"), "a/b-c_1.txt");
+
+    assert_eq!(bash.quote_arg("it's"), r#"'it'\''s'"#);
+    assert_eq!(fish.quote_arg("it's"), r#"'it\'s'"#);
+    assert_eq!(fish.quote_arg(r"a\b"), r"'a\\b'");
+    assert_eq!(pwsh.quote_arg("it's"), "'it''s'");
+    assert_eq!(cmd.quote_arg(r#"say "hi""#), r#""say ""hi""""#);
+}
+
+#[test]
+fn quote_command_joins_quoted_args() {
+    let bash = make_shell(ShellType::Bash, "/bin/bash");
+    assert_eq!(
+        bash.quote_command(&["echo".to_string(), "hello world".to_string()]),
+        "echo 'hello world'"
+    );
+}
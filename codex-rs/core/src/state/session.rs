@@ -9,6 +9,8 @@ use std::collections::HashSet;
 use crate::context_manager::ContextManager;
 use crate::session::PreviousTurnSettings;
 use crate::session::session::SessionConfiguration;
+use crate::session_limits::LimitsCheck;
+use crate::session_limits::SessionLimitsTracker;
 use crate::session_startup_prewarm::SessionStartupPrewarmHandle;
 use codex_protocol::protocol::RateLimitSnapshot;
 use codex_protocol::protocol::SessionAgentTask;
@@ -36,12 +38,15 @@ pub(crate) struct SessionState {
     pub(crate) pending_session_start_source: Option<codex_hooks::SessionStartSource>,
     granted_permissions: Option<PermissionProfile>,
     next_turn_is_first: bool,
+    /// Usage tracker for the optional `[limits]` session budget.
+    limits: SessionLimitsTracker,
 }
 
 impl SessionState {
     /// Create a new session state mirroring previous `State::default()` semantics.
     pub(crate) fn new(session_configuration: SessionConfiguration) -> Self {
         let history = ContextManager::new();
+        let limits = SessionLimitsTracker::new(session_configuration.limits());
         Self {
             session_configuration,
             history,
@@ -56,9 +61,19 @@ impl SessionState {
             pending_session_start_source: None,
             granted_permissions: None,
             next_turn_is_first: true,
+            limits,
         }
     }
 
+    // Session budget helpers
+    pub(crate) fn note_limits_turn_started(&mut self) {
+        self.limits.note_turn_started();
+    }
+
+    pub(crate) fn check_limits(&mut self, total_tokens: i64) -> LimitsCheck {
+        self.limits.check(total_tokens)
+    }
+
     // History helpers
     pub(crate) fn record_items<I>(&mut self, items: I, policy: TruncationPolicy)
     where
@@ -0,0 +1,233 @@
+//! Garbage collection for on-disk session storage.
+//!
+//! The `[storage]` policy bounds how many rollouts Codex keeps, how old they
+//! may be, and how much disk they may use. `codex gc` (and, when
+//! `gc_on_startup` is set, TUI startup) walks `sessions/` and
+//! `archived_sessions/` under the Codex home, deletes rollouts that exceed
+//! any configured bound — newest files are always kept first — and sweeps
+//! the empty date directories left behind.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use codex_config::config_toml::StorageToml;
+
+use crate::rollout::ARCHIVED_SESSIONS_SUBDIR;
+use crate::rollout::SESSIONS_SUBDIR;
+
+/// What a garbage collection pass found and (unless dry-running) deleted.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GcReport {
+    /// Rollout files inspected across both session directories.
+    pub files_scanned: usize,
+    /// Rollout files selected for deletion by the policy.
+    pub files_removed: usize,
+    /// Total size of the removed files, in bytes.
+    pub bytes_reclaimed: u64,
+}
+
+/// Whether the policy imposes any bound at all; a no-op policy lets callers
+/// skip the filesystem walk entirely.
+pub fn policy_is_noop(policy: &StorageToml) -> bool {
+    policy.max_sessions.is_none() && policy.max_age_days.is_none() && policy.max_disk_mb.is_none()
+}
+
+/// Applies the retention policy to the session directories under
+/// `codex_home`. With `dry_run` the report states what would be removed but
+/// nothing is deleted.
+pub fn collect_garbage(
+    codex_home: &Path,
+    policy: &StorageToml,
+    dry_run: bool,
+) -> io::Result<GcReport> {
+    let mut report = GcReport::default();
+    if policy_is_noop(policy) {
+        return Ok(report);
+    }
+
+    let mut files = Vec::new();
+    let roots = [
+        codex_home.join(SESSIONS_SUBDIR),
+        codex_home.join(ARCHIVED_SESSIONS_SUBDIR),
+    ];
+    for root in &roots {
+        collect_rollout_files(root, &mut files)?;
+    }
+    report.files_scanned = files.len();
+
+    // Newest first: every bound keeps the most recent rollouts.
+    files.sort_by(|a, b| b.modified.cmp(&a.modified));
+
+    let now = SystemTime::now();
+    let max_age = policy.max_age_days.map(|days| days * 24 * 60 * 60);
+    let disk_budget = policy.max_disk_mb.map(|mb| mb * 1024 * 1024);
+    let mut kept_bytes: u64 = 0;
+    for (index, file) in files.iter().enumerate() {
+        let too_many = policy.max_sessions.is_some_and(|max| index >= max);
+        let too_old = max_age.is_some_and(|max| {
+            now.duration_since(file.modified)
+                .unwrap_or(Duration::ZERO)
+                .as_secs()
+                > max
+        });
+        let over_budget = disk_budget.is_some_and(|budget| kept_bytes + file.size > budget);
+        if too_many || too_old || over_budget {
+            if !dry_run {
+                fs::remove_file(&file.path)?;
+            }
+            report.files_removed += 1;
+            report.bytes_reclaimed += file.size;
+        } else {
+            kept_bytes += file.size;
+        }
+    }
+
+    if !dry_run && report.files_removed > 0 {
+        for root in &roots {
+            remove_empty_dirs(root)?;
+        }
+    }
+    Ok(report)
+}
+
+struct RolloutFile {
+    path: PathBuf,
+    modified: SystemTime,
+    size: u64,
+}
+
+/// Recursively collects `*.jsonl` rollouts under `root`; a missing root is
+/// treated as empty.
+fn collect_rollout_files(root: &Path, files: &mut Vec<RolloutFile>) -> io::Result<()> {
+    let entries = match fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            collect_rollout_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "jsonl") {
+            files.push(RolloutFile {
+                path,
+                modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                size: metadata.len(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Removes now-empty date directories below `root`, keeping `root` itself.
+fn remove_empty_dirs(root: &Path) -> io::Result<()> {
+    let entries = match fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.metadata()?.is_dir() {
+            remove_empty_dirs(&path)?;
+            if fs::read_dir(&path)?.next().is_none() {
+                fs::remove_dir(&path)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn write_rollout(home: &Path, rel: &str, len: usize, age: Duration) -> PathBuf {
+        let path = home.join(SESSIONS_SUBDIR).join(rel);
+        fs::create_dir_all(path.parent().expect("parent")).expect("mkdir");
+        fs::write(&path, vec![b'x'; len]).expect("write");
+        let mtime = SystemTime::now() - age;
+        let file = fs::File::options().write(true).open(&path).expect("open");
+        file.set_modified(mtime).expect("set mtime");
+        path
+    }
+
+    #[test]
+    fn noop_policy_scans_nothing() {
+        let home = TempDir::new().expect("home");
+        write_rollout(home.path(), "2026/01/01/a.jsonl", 10, Duration::ZERO);
+        let report = collect_garbage(home.path(), &StorageToml::default(), false).expect("collect");
+        assert_eq!(report, GcReport::default());
+    }
+
+    #[test]
+    fn max_sessions_keeps_the_newest_rollouts() {
+        let home = TempDir::new().expect("home");
+        let old = write_rollout(
+            home.path(),
+            "2026/01/01/old.jsonl",
+            10,
+            Duration::from_secs(600),
+        );
+        let new = write_rollout(home.path(), "2026/02/01/new.jsonl", 10, Duration::ZERO);
+        let policy = StorageToml {
+            max_sessions: Some(1),
+            ..Default::default()
+        };
+        let report = collect_garbage(home.path(), &policy, false).expect("collect");
+        assert_eq!(report.files_removed, 1);
+        assert_eq!(report.bytes_reclaimed, 10);
+        assert!(new.exists());
+        assert!(!old.exists());
+        // The emptied date directory is swept too.
+        assert!(!old.parent().expect("parent").exists());
+    }
+
+    #[test]
+    fn max_age_and_disk_budget_prune_old_and_oversized() {
+        let home = TempDir::new().expect("home");
+        let ancient = write_rollout(
+            home.path(),
+            "2025/01/01/ancient.jsonl",
+            10,
+            Duration::from_secs(10 * 24 * 60 * 60),
+        );
+        let recent = write_rollout(home.path(), "2026/02/01/recent.jsonl", 10, Duration::ZERO);
+        let policy = StorageToml {
+            max_age_days: Some(7),
+            ..Default::default()
+        };
+        let report = collect_garbage(home.path(), &policy, false).expect("collect");
+        assert_eq!(report.files_removed, 1);
+        assert!(recent.exists());
+        assert!(!ancient.exists());
+    }
+
+    #[test]
+    fn dry_run_reports_without_deleting() {
+        let home = TempDir::new().expect("home");
+        let old = write_rollout(
+            home.path(),
+            "2026/01/01/old.jsonl",
+            10,
+            Duration::from_secs(600),
+        );
+        write_rollout(home.path(), "2026/02/01/new.jsonl", 10, Duration::ZERO);
+        let policy = StorageToml {
+            max_sessions: Some(1),
+            ..Default::default()
+        };
+        let report = collect_garbage(home.path(), &policy, true).expect("collect");
+        assert_eq!(report.files_removed, 1);
+        assert!(old.exists());
+    }
+}
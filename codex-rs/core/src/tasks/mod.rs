@@ -15,6 +15,7 @@ use tokio::sync::Notify;
 use tokio_util::sync::CancellationToken;
 use tokio_util::task::AbortOnDropHandle;
 use tracing::Instrument;
+use tracing::debug;
 use tracing::info_span;
 use tracing::trace;
 use tracing::warn;
@@ -27,6 +28,7 @@ use crate::hook_runtime::record_additional_contexts;
 use crate::hook_runtime::record_pending_input;
 use crate::session::session::Session;
 use crate::session::turn_context::TurnContext;
+use crate::session_limits::LimitsCheck;
 use crate::state::ActiveTurn;
 use crate::state::RunningTask;
 use crate::state::TaskKind;
@@ -42,6 +44,7 @@ use codex_otel::TURN_TOOL_CALL_METRIC;
 use codex_protocol::models::ContentItem;
 use codex_protocol::models::ResponseInputItem;
 use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::ErrorEvent;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::TokenUsage;
@@ -269,6 +272,26 @@ impl Session {
     ) {
         let task: Arc<dyn AnySessionTask> = Arc::new(task);
         let task_kind = task.kind();
+        if task_kind == TaskKind::Regular {
+            match self.check_session_limits_for_new_turn().await {
+                LimitsCheck::Exceeded(message) => {
+                    self.send_event(
+                        turn_context.as_ref(),
+                        EventMsg::Error(ErrorEvent {
+                            message,
+                            codex_error_info: None,
+                        }),
+                    )
+                    .await;
+                    return;
+                }
+                LimitsCheck::Warn(message) => {
+                    self.notify_background_event(turn_context.as_ref(), message)
+                        .await;
+                }
+                LimitsCheck::Ok => {}
+            }
+        }
         let span_name = task.span_name();
         let started_at = Instant::now();
         turn_context
@@ -563,15 +586,30 @@ impl Session {
             turn_context.config.memories.use_memories,
             turn_had_memory_citation,
         );
+        {
+            let metrics = self.services.file_prefetcher.metrics().await;
+            debug!(
+                prefetched_files = metrics.prefetched_files,
+                prefetched_bytes = metrics.prefetched_bytes,
+                hits = metrics.hits,
+                misses = metrics.misses,
+                "file prefetch metrics"
+            );
+        }
         let (completed_at, duration_ms) = turn_context
             .turn_timing_state
             .completed_at_and_duration_ms()
             .await;
+        let request_params = turn_context
+            .turn_repro_state
+            .request_params(turn_context.as_ref())
+            .await;
         let event = EventMsg::TurnComplete(TurnCompleteEvent {
             turn_id: turn_context.sub_id.clone(),
             last_agent_message,
             completed_at,
             duration_ms,
+            request_params: Some(request_params),
         });
         self.send_event(turn_context.as_ref(), event).await;
 
@@ -0,0 +1,480 @@
+//! Convert third-party color schemes into Codex TOML theme definitions.
+//!
+//! Supported inputs are base16 YAML schemes (`base00`..`base0F`), iTerm2
+//! `.itermcolors` property lists, and Alacritty TOML `[colors]` sections.
+//! Each format is normalized into an [`ImportedScheme`] — background,
+//! foreground, and a bag of palette colors — and then mapped onto Codex
+//! theme roles (accent, success, error, brand, info) by nearest-color
+//! heuristics before being serialized as a `themes/{name}.toml` definition
+//! the TUI theme loader understands.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use serde::Serialize;
+
+/// A scheme format accepted by `codex themes import`, detected from the
+/// input file extension.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SchemeFormat {
+    /// base16 YAML (`scheme:` plus `base00`..`base0F` hex values).
+    Base16Yaml,
+    /// iTerm2 `.itermcolors` property list.
+    ItermColors,
+    /// Alacritty TOML with a `[colors]` section.
+    AlacrittyToml,
+}
+
+impl SchemeFormat {
+    /// Detect the scheme format from a file extension. Returns `None` for
+    /// unrecognized extensions.
+    pub fn detect(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "yaml" | "yml" => Some(Self::Base16Yaml),
+            "itermcolors" => Some(Self::ItermColors),
+            "toml" => Some(Self::AlacrittyToml),
+            _ => None,
+        }
+    }
+}
+
+type Rgb = (u8, u8, u8);
+
+/// A color scheme normalized from any supported input format.
+#[derive(Debug, Default)]
+pub struct ImportedScheme {
+    /// Scheme name when the source declares one (base16 `scheme:` key).
+    pub name: Option<String>,
+    pub foreground: Option<Rgb>,
+    pub background: Option<Rgb>,
+    /// Accent-capable palette colors, in source order. Role mapping picks
+    /// from these by nearest-color heuristics.
+    pub palette: Vec<Rgb>,
+}
+
+/// Parse scheme file contents in the given format.
+pub fn parse_scheme(contents: &str, format: SchemeFormat) -> Result<ImportedScheme> {
+    let scheme = match format {
+        SchemeFormat::Base16Yaml => parse_base16_yaml(contents)?,
+        SchemeFormat::ItermColors => parse_itermcolors(contents)?,
+        SchemeFormat::AlacrittyToml => parse_alacritty_toml(contents)?,
+    };
+    if scheme.palette.is_empty() {
+        bail!("no palette colors found in scheme");
+    }
+    Ok(scheme)
+}
+
+/// base16 schemes assign fixed semantics to slots: `base00` is the
+/// background, `base05` the default foreground, and `base08`..`base0F` the
+/// accent colors. `base03` (comments) is included so the muted role can
+/// find it.
+fn parse_base16_yaml(contents: &str) -> Result<ImportedScheme> {
+    let doc: serde_yaml::Mapping =
+        serde_yaml::from_str(contents).context("not a valid base16 YAML scheme")?;
+    let hex = |key: &str| -> Option<Rgb> {
+        let value = doc.get(serde_yaml::Value::String(key.to_string()))?;
+        parse_rgb(value.as_str()?)
+    };
+    let name = doc
+        .get(serde_yaml::Value::String("scheme".to_string()))
+        .and_then(|value| value.as_str())
+        .map(str::to_string);
+    let palette = [
+        "base08", "base09", "base0A", "base0B", "base0C", "base0D", "base0E", "base0F", "base03",
+    ]
+    .iter()
+    .filter_map(|key| hex(key))
+    .collect();
+    Ok(ImportedScheme {
+        name,
+        foreground: hex("base05"),
+        background: hex("base00"),
+        palette,
+    })
+}
+
+/// `.itermcolors` files are XML plists, but the structure is rigid enough to
+/// scan without a full plist parser: each top-level `<key>Name</key>` is
+/// followed by a `<dict>` holding `Red Component` / `Green Component` /
+/// `Blue Component` `<real>` values in `0.0..=1.0`.
+fn parse_itermcolors(contents: &str) -> Result<ImportedScheme> {
+    let mut scheme = ImportedScheme::default();
+    // `Ansi N Color` entries keyed by N so the palette comes out in ANSI
+    // order regardless of plist key order.
+    let mut ansi: BTreeMap<u8, Rgb> = BTreeMap::new();
+
+    let mut rest = contents;
+    while let Some(start) = rest.find("<key>") {
+        rest = &rest[start + "<key>".len()..];
+        let Some(end) = rest.find("</key>") else {
+            break;
+        };
+        let key = &rest[..end];
+        rest = &rest[end..];
+        if !key.ends_with("Color") {
+            continue;
+        }
+        let Some(dict_end) = rest.find("</dict>") else {
+            break;
+        };
+        let dict = &rest[..dict_end];
+        let component = |name: &str| -> Option<f64> {
+            let offset = dict.find(&format!("<key>{name} Component</key>"))?;
+            let tail = &dict[offset..];
+            let value_start = tail.find("<real>")? + "<real>".len();
+            let value_end = tail.find("</real>")?;
+            tail.get(value_start..value_end)?.trim().parse().ok()
+        };
+        let (Some(r), Some(g), Some(b)) = (component("Red"), component("Green"), component("Blue"))
+        else {
+            continue;
+        };
+        let to_byte = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let rgb = (to_byte(r), to_byte(g), to_byte(b));
+        match key {
+            "Foreground Color" => scheme.foreground = Some(rgb),
+            "Background Color" => scheme.background = Some(rgb),
+            _ => {
+                if let Some(index) = key
+                    .strip_prefix("Ansi ")
+                    .and_then(|k| k.strip_suffix(" Color"))
+                    .and_then(|n| n.parse::<u8>().ok())
+                {
+                    ansi.insert(index, rgb);
+                }
+            }
+        }
+    }
+    if scheme.foreground.is_none() && scheme.background.is_none() && ansi.is_empty() {
+        bail!("no color entries found; is this an iTerm2 .itermcolors file?");
+    }
+    // Skip black/white slots (0, 7, 8, 15): they make poor accents and would
+    // otherwise win the muted-comment role over a purpose-made gray.
+    scheme.palette = ansi
+        .iter()
+        .filter(|(index, _)| !matches!(index, 0 | 7 | 8 | 15))
+        .map(|(_, rgb)| *rgb)
+        .collect();
+    Ok(scheme)
+}
+
+/// Alacritty TOML themes keep colors under `[colors.primary]`,
+/// `[colors.normal]`, and `[colors.bright]`, as `#rrggbb` or `0xrrggbb`.
+fn parse_alacritty_toml(contents: &str) -> Result<ImportedScheme> {
+    let doc: toml::Value = toml::from_str(contents).context("not a valid Alacritty TOML theme")?;
+    let colors = doc
+        .get("colors")
+        .context("no [colors] section; is this an Alacritty theme?")?;
+    let lookup = |group: &str, key: &str| -> Option<Rgb> {
+        parse_rgb(colors.get(group)?.get(key)?.as_str()?)
+    };
+    let mut palette = Vec::new();
+    for group in ["normal", "bright"] {
+        for key in ["red", "green", "yellow", "blue", "magenta", "cyan"] {
+            palette.extend(lookup(group, key));
+        }
+    }
+    // `bright black` is the conventional muted/comment gray.
+    palette.extend(lookup("bright", "black"));
+    Ok(ImportedScheme {
+        name: None,
+        foreground: lookup("primary", "foreground"),
+        background: lookup("primary", "background"),
+        palette,
+    })
+}
+
+/// Parse `#rrggbb`, `0xrrggbb`, or bare `rrggbb` (base16 convention).
+fn parse_rgb(value: &str) -> Option<Rgb> {
+    let hex = value
+        .strip_prefix('#')
+        .or_else(|| value.strip_prefix("0x"))
+        .unwrap_or(value);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    Some((
+        u8::from_str_radix(&hex[0..2], 16).ok()?,
+        u8::from_str_radix(&hex[2..4], 16).ok()?,
+        u8::from_str_radix(&hex[4..6], 16).ok()?,
+    ))
+}
+
+/// Canonical colors for each Codex theme role. Role assignment picks the
+/// palette color nearest to the target, so a scheme's "red" wins the error
+/// role whether it is crimson or salmon.
+struct RoleTargets {
+    accent: Rgb,
+    success: Rgb,
+    error: Rgb,
+    brand: Rgb,
+    info: Rgb,
+    muted: Rgb,
+}
+
+const ROLE_TARGETS: RoleTargets = RoleTargets {
+    accent: (0xbf, 0x5a, 0xf2),  // magenta/violet
+    success: (0x00, 0xa0, 0x40), // green
+    error: (0xd0, 0x20, 0x20),   // red
+    brand: (0xe0, 0x90, 0x20),   // orange/yellow
+    info: (0x20, 0x80, 0xd0),    // blue/cyan
+    muted: (0x80, 0x80, 0x80),   // gray
+};
+
+/// Squared Euclidean RGB distance — crude but adequate for picking the
+/// nearest of a handful of accent candidates.
+fn distance(a: Rgb, b: Rgb) -> u32 {
+    let d = |x: u8, y: u8| {
+        let diff = i32::from(x) - i32::from(y);
+        (diff * diff) as u32
+    };
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+fn nearest(palette: &[Rgb], target: Rgb) -> Option<Rgb> {
+    palette
+        .iter()
+        .min_by_key(|candidate| distance(**candidate, target))
+        .copied()
+}
+
+/// On-disk schema of a Codex TOML theme definition (the format read by the
+/// TUI theme loader).
+#[derive(Serialize)]
+struct ThemeFile {
+    name: String,
+    palette: ThemePalette,
+    styles: BTreeMap<&'static str, ThemeStyle>,
+}
+
+#[derive(Serialize)]
+struct ThemePalette {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foreground: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    background: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    caret: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ThemeStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fg: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bg: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    bold: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    italic: bool,
+}
+
+/// Map an imported scheme onto Codex theme roles and serialize it as a TOML
+/// theme definition named `name`.
+pub fn scheme_to_theme_toml(scheme: &ImportedScheme, name: &str) -> Result<String> {
+    let hex = |rgb: Rgb| format!("#{:02x}{:02x}{:02x}", rgb.0, rgb.1, rgb.2);
+    let role = |target: Rgb| nearest(&scheme.palette, target).map(hex);
+
+    let accent = role(ROLE_TARGETS.accent);
+    let success = role(ROLE_TARGETS.success);
+    let error = role(ROLE_TARGETS.error);
+    let brand = role(ROLE_TARGETS.brand);
+    let info = role(ROLE_TARGETS.info);
+    let muted = role(ROLE_TARGETS.muted);
+
+    let fg_style = |color: &Option<String>| ThemeStyle {
+        fg: color.clone(),
+        bg: None,
+        bold: false,
+        italic: false,
+    };
+    let mut styles = BTreeMap::new();
+    styles.insert(
+        "keyword",
+        ThemeStyle {
+            bold: true,
+            ..fg_style(&accent)
+        },
+    );
+    styles.insert("string", fg_style(&success));
+    styles.insert("entity.name.function", fg_style(&brand));
+    styles.insert("constant.numeric", fg_style(&info));
+    styles.insert("support", fg_style(&info));
+    styles.insert(
+        "comment",
+        ThemeStyle {
+            italic: true,
+            ..fg_style(&muted)
+        },
+    );
+    styles.insert("markup.inserted", fg_style(&success));
+    styles.insert("markup.deleted", fg_style(&error));
+    styles.insert("invalid", fg_style(&error));
+
+    let file = ThemeFile {
+        name: name.to_string(),
+        palette: ThemePalette {
+            foreground: scheme.foreground.map(hex),
+            background: scheme.background.map(hex),
+            caret: accent,
+        },
+        styles,
+    };
+    toml::to_string_pretty(&file).context("failed to serialize theme")
+}
+
+/// Derive a kebab-case theme file name from the scheme name or, failing
+/// that, the input file stem.
+pub fn theme_file_name(scheme: &ImportedScheme, input: &Path) -> String {
+    let raw = scheme
+        .name
+        .clone()
+        .or_else(|| {
+            input
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| "imported".to_string());
+    let mut name = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c.to_ascii_lowercase());
+        } else if !name.ends_with('-') && !name.is_empty() {
+            name.push('-');
+        }
+    }
+    let name = name.trim_end_matches('-');
+    if name.is_empty() {
+        "imported".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const BASE16: &str = r#"scheme: "Test Scheme"
+author: "Someone"
+base00: "1a1b26"
+base03: "565f89"
+base05: "c0caf5"
+base08: "f7768e"
+base0B: "9ece6a"
+base0D: "7aa2f7"
+base0E: "bb9af7"
+"#;
+
+    #[test]
+    fn detects_format_from_extension() {
+        assert_eq!(
+            SchemeFormat::detect(Path::new("x.yaml")),
+            Some(SchemeFormat::Base16Yaml)
+        );
+        assert_eq!(
+            SchemeFormat::detect(Path::new("x.itermcolors")),
+            Some(SchemeFormat::ItermColors)
+        );
+        assert_eq!(
+            SchemeFormat::detect(Path::new("x.toml")),
+            Some(SchemeFormat::AlacrittyToml)
+        );
+        assert_eq!(SchemeFormat::detect(Path::new("x.json")), None);
+    }
+
+    #[test]
+    fn parses_base16_yaml() {
+        let scheme = parse_scheme(BASE16, SchemeFormat::Base16Yaml).unwrap();
+        assert_eq!(scheme.name.as_deref(), Some("Test Scheme"));
+        assert_eq!(scheme.foreground, Some((0xc0, 0xca, 0xf5)));
+        assert_eq!(scheme.background, Some((0x1a, 0x1b, 0x26)));
+        assert!(scheme.palette.contains(&(0xf7, 0x76, 0x8e)));
+    }
+
+    #[test]
+    fn parses_itermcolors_plist() {
+        let contents = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0"><dict>
+<key>Ansi 1 Color</key><dict>
+<key>Blue Component</key><real>0.25</real>
+<key>Green Component</key><real>0.0</real>
+<key>Red Component</key><real>1.0</real>
+</dict>
+<key>Background Color</key><dict>
+<key>Blue Component</key><real>0.1</real>
+<key>Green Component</key><real>0.1</real>
+<key>Red Component</key><real>0.1</real>
+</dict>
+<key>Foreground Color</key><dict>
+<key>Blue Component</key><real>0.9</real>
+<key>Green Component</key><real>0.9</real>
+<key>Red Component</key><real>0.9</real>
+</dict>
+</dict></plist>"#;
+        let scheme = parse_scheme(contents, SchemeFormat::ItermColors).unwrap();
+        assert_eq!(scheme.background, Some((26, 26, 26)));
+        assert_eq!(scheme.foreground, Some((230, 230, 230)));
+        assert_eq!(scheme.palette, vec![(255, 0, 64)]);
+    }
+
+    #[test]
+    fn parses_alacritty_toml() {
+        let contents = r##"[colors.primary]
+background = "#1a1b26"
+foreground = "#c0caf5"
+
+[colors.normal]
+red = "0xf7768e"
+green = "#9ece6a"
+blue = "#7aa2f7"
+"##;
+        let scheme = parse_scheme(contents, SchemeFormat::AlacrittyToml).unwrap();
+        assert_eq!(scheme.background, Some((0x1a, 0x1b, 0x26)));
+        assert_eq!(
+            scheme.palette,
+            vec![(0xf7, 0x76, 0x8e), (0x9e, 0xce, 0x6a), (0x7a, 0xa2, 0xf7)]
+        );
+    }
+
+    #[test]
+    fn rejects_scheme_without_palette() {
+        assert!(parse_scheme("author: nobody", SchemeFormat::Base16Yaml).is_err());
+        assert!(parse_scheme("[colors.primary]", SchemeFormat::AlacrittyToml).is_err());
+    }
+
+    #[test]
+    fn maps_roles_to_nearest_palette_colors() {
+        let scheme = parse_scheme(BASE16, SchemeFormat::Base16Yaml).unwrap();
+        let toml = scheme_to_theme_toml(&scheme, "test-scheme").unwrap();
+        // Red base08 should win the error role, green base0B the success
+        // role, and the violet base0E the keyword accent.
+        assert!(toml.contains("name = \"test-scheme\""));
+        assert!(toml.contains("[styles.\"markup.deleted\"]\nfg = \"#f7768e\""));
+        assert!(toml.contains("[styles.\"markup.inserted\"]\nfg = \"#9ece6a\""));
+        assert!(toml.contains("[styles.keyword]\nfg = \"#bb9af7\""));
+    }
+
+    #[test]
+    fn theme_file_name_kebab_cases_scheme_names() {
+        let scheme = ImportedScheme {
+            name: Some("Gruvbox Dark (Hard)".to_string()),
+            ..ImportedScheme::default()
+        };
+        assert_eq!(
+            theme_file_name(&scheme, Path::new("x.yaml")),
+            "gruvbox-dark-hard"
+        );
+        let unnamed = ImportedScheme::default();
+        assert_eq!(
+            theme_file_name(&unnamed, Path::new("/tmp/My_Theme.toml")),
+            "my-theme"
+        );
+    }
+}
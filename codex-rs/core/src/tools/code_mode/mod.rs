@@ -291,6 +291,7 @@ async fn build_nested_router(exec: &ExecContext) -> ToolRouter {
             parallel_mcp_server_names,
             discoverable_tools: None,
             dynamic_tools: exec.turn.dynamic_tools.as_slice(),
+            wasm_tools: exec.turn.config.wasm_tools.clone(),
         },
     )
 }
@@ -9,6 +9,7 @@ use std::time::Instant;
 use crate::apply_patch;
 use crate::apply_patch::InternalApplyPatchInvocation;
 use crate::apply_patch::convert_apply_patch_to_protocol;
+use crate::codex_ignore::CodexIgnore;
 use crate::function_tool::FunctionCallError;
 use crate::session::session::Session;
 use crate::session::turn_context::TurnContext;
@@ -239,6 +240,35 @@ fn write_permissions_for_paths(
     normalize_additional_permissions(permissions).ok()
 }
 
+/// Warns the model when a patch touches paths hidden by `.codexignore`, so
+/// secrets and vendored blobs are not silently pulled back into context.
+async fn warn_on_codex_ignored_paths(
+    session: &Session,
+    turn: &TurnContext,
+    action: &ApplyPatchAction,
+) {
+    let Some(codex_ignore) = CodexIgnore::load(&turn.cwd) else {
+        return;
+    };
+    let ignored: Vec<String> = file_paths_for_action(action)
+        .iter()
+        .filter(|path| codex_ignore.is_ignored(path.as_path(), /*is_dir*/ false))
+        .map(|path| path.as_path().display().to_string())
+        .collect();
+    if ignored.is_empty() {
+        return;
+    }
+    session
+        .record_model_warning(
+            format!(
+                "apply_patch targets path(s) ignored by .codexignore: {}",
+                ignored.join(", ")
+            ),
+            turn,
+        )
+        .await;
+}
+
 async fn effective_patch_permissions(
     session: &Session,
     turn: &TurnContext,
@@ -271,6 +301,69 @@ async fn effective_patch_permissions(
     )
 }
 
+/// Runs a verified patch action through the shared approval/runtime pipeline
+/// and returns the textual tool output. Shared by the `apply_patch` handler,
+/// the exec interception path, and tools that synthesize patches (e.g.
+/// `search_replace`) so every patch gets the same preview and single approval.
+pub(crate) async fn apply_action_via_runtime(
+    session: &Arc<Session>,
+    turn: &Arc<TurnContext>,
+    tracker: Option<&SharedTurnDiffTracker>,
+    call_id: &str,
+    tool_name: String,
+    action: ApplyPatchAction,
+) -> Result<String, FunctionCallError> {
+    warn_on_codex_ignored_paths(session.as_ref(), turn.as_ref(), &action).await;
+    let (file_paths, effective_additional_permissions, file_system_sandbox_policy) =
+        effective_patch_permissions(session.as_ref(), turn.as_ref(), &action).await;
+    for path in &file_paths {
+        session
+            .services
+            .file_prefetcher
+            .note_access(path.as_path())
+            .await;
+    }
+    match apply_patch::apply_patch(turn.as_ref(), &file_system_sandbox_policy, action).await {
+        InternalApplyPatchInvocation::Output(item) => item,
+        InternalApplyPatchInvocation::DelegateToRuntime(apply) => {
+            let changes = convert_apply_patch_to_protocol(&apply.action);
+            let emitter = ToolEmitter::apply_patch(changes.clone(), apply.auto_approved);
+            let event_ctx = ToolEventCtx::new(session.as_ref(), turn.as_ref(), call_id, tracker);
+            emitter.begin(event_ctx).await;
+
+            let req = ApplyPatchRequest {
+                action: apply.action,
+                file_paths,
+                changes,
+                exec_approval_requirement: apply.exec_approval_requirement,
+                additional_permissions: effective_additional_permissions.additional_permissions,
+                permissions_preapproved: effective_additional_permissions.permissions_preapproved,
+            };
+
+            let mut orchestrator = ToolOrchestrator::new();
+            let mut runtime = ApplyPatchRuntime::new();
+            let tool_ctx = ToolCtx {
+                session: session.clone(),
+                turn: turn.clone(),
+                call_id: call_id.to_string(),
+                tool_name,
+            };
+            let out = orchestrator
+                .run(
+                    &mut runtime,
+                    &req,
+                    &tool_ctx,
+                    turn.as_ref(),
+                    turn.approval_policy.value(),
+                )
+                .await
+                .map(|result| result.output);
+            let event_ctx = ToolEventCtx::new(session.as_ref(), turn.as_ref(), call_id, tracker);
+            emitter.finish(event_ctx, out).await
+        }
+    }
+}
+
 impl ToolHandler for ApplyPatchHandler {
     type Output = ApplyPatchToolOutput;
 
@@ -339,66 +432,16 @@ impl ToolHandler for ApplyPatchHandler {
         .await
         {
             codex_apply_patch::MaybeApplyPatchVerified::Body(changes) => {
-                let (file_paths, effective_additional_permissions, file_system_sandbox_policy) =
-                    effective_patch_permissions(session.as_ref(), turn.as_ref(), &changes).await;
-                match apply_patch::apply_patch(turn.as_ref(), &file_system_sandbox_policy, changes)
-                    .await
-                {
-                    InternalApplyPatchInvocation::Output(item) => {
-                        let content = item?;
-                        Ok(ApplyPatchToolOutput::from_text(content))
-                    }
-                    InternalApplyPatchInvocation::DelegateToRuntime(apply) => {
-                        let changes = convert_apply_patch_to_protocol(&apply.action);
-                        let emitter =
-                            ToolEmitter::apply_patch(changes.clone(), apply.auto_approved);
-                        let event_ctx = ToolEventCtx::new(
-                            session.as_ref(),
-                            turn.as_ref(),
-                            &call_id,
-                            Some(&tracker),
-                        );
-                        emitter.begin(event_ctx).await;
-
-                        let req = ApplyPatchRequest {
-                            action: apply.action,
-                            file_paths,
-                            changes,
-                            exec_approval_requirement: apply.exec_approval_requirement,
-                            additional_permissions: effective_additional_permissions
-                                .additional_permissions,
-                            permissions_preapproved: effective_additional_permissions
-                                .permissions_preapproved,
-                        };
-
-                        let mut orchestrator = ToolOrchestrator::new();
-                        let mut runtime = ApplyPatchRuntime::new();
-                        let tool_ctx = ToolCtx {
-                            session: session.clone(),
-                            turn: turn.clone(),
-                            call_id: call_id.clone(),
-                            tool_name: tool_name.display(),
-                        };
-                        let out = orchestrator
-                            .run(
-                                &mut runtime,
-                                &req,
-                                &tool_ctx,
-                                turn.as_ref(),
-                                turn.approval_policy.value(),
-                            )
-                            .await
-                            .map(|result| result.output);
-                        let event_ctx = ToolEventCtx::new(
-                            session.as_ref(),
-                            turn.as_ref(),
-                            &call_id,
-                            Some(&tracker),
-                        );
-                        let content = emitter.finish(event_ctx, out).await?;
-                        Ok(ApplyPatchToolOutput::from_text(content))
-                    }
-                }
+                let content = apply_action_via_runtime(
+                    &session,
+                    &turn,
+                    Some(&tracker),
+                    &call_id,
+                    tool_name.display(),
+                    changes,
+                )
+                .await?;
+                Ok(ApplyPatchToolOutput::from_text(content))
             }
             codex_apply_patch::MaybeApplyPatchVerified::CorrectnessError(parse_error) => {
                 Err(FunctionCallError::RespondToModel(format!(
@@ -448,65 +491,16 @@ pub(crate) async fn intercept_apply_patch(
                     turn.as_ref(),
                 )
                 .await;
-            let (approval_keys, effective_additional_permissions, file_system_sandbox_policy) =
-                effective_patch_permissions(session.as_ref(), turn.as_ref(), &changes).await;
-            match apply_patch::apply_patch(turn.as_ref(), &file_system_sandbox_policy, changes)
-                .await
-            {
-                InternalApplyPatchInvocation::Output(item) => {
-                    let content = item?;
-                    Ok(Some(FunctionToolOutput::from_text(content, Some(true))))
-                }
-                InternalApplyPatchInvocation::DelegateToRuntime(apply) => {
-                    let changes = convert_apply_patch_to_protocol(&apply.action);
-                    let emitter = ToolEmitter::apply_patch(changes.clone(), apply.auto_approved);
-                    let event_ctx = ToolEventCtx::new(
-                        session.as_ref(),
-                        turn.as_ref(),
-                        call_id,
-                        tracker.as_ref().copied(),
-                    );
-                    emitter.begin(event_ctx).await;
-
-                    let req = ApplyPatchRequest {
-                        action: apply.action,
-                        file_paths: approval_keys,
-                        changes,
-                        exec_approval_requirement: apply.exec_approval_requirement,
-                        additional_permissions: effective_additional_permissions
-                            .additional_permissions,
-                        permissions_preapproved: effective_additional_permissions
-                            .permissions_preapproved,
-                    };
-
-                    let mut orchestrator = ToolOrchestrator::new();
-                    let mut runtime = ApplyPatchRuntime::new();
-                    let tool_ctx = ToolCtx {
-                        session: session.clone(),
-                        turn: turn.clone(),
-                        call_id: call_id.to_string(),
-                        tool_name: tool_name.to_string(),
-                    };
-                    let out = orchestrator
-                        .run(
-                            &mut runtime,
-                            &req,
-                            &tool_ctx,
-                            turn.as_ref(),
-                            turn.approval_policy.value(),
-                        )
-                        .await
-                        .map(|result| result.output);
-                    let event_ctx = ToolEventCtx::new(
-                        session.as_ref(),
-                        turn.as_ref(),
-                        call_id,
-                        tracker.as_ref().copied(),
-                    );
-                    let content = emitter.finish(event_ctx, out).await?;
-                    Ok(Some(FunctionToolOutput::from_text(content, Some(true))))
-                }
-            }
+            let content = apply_action_via_runtime(
+                &session,
+                &turn,
+                tracker,
+                call_id,
+                tool_name.to_string(),
+                changes,
+            )
+            .await?;
+            Ok(Some(FunctionToolOutput::from_text(content, Some(true))))
         }
         codex_apply_patch::MaybeApplyPatchVerified::CorrectnessError(parse_error) => {
             Err(FunctionCallError::RespondToModel(format!(
@@ -5,6 +5,8 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use codex_protocol::permissions::ReadDenyMatcher;
+
+use crate::codex_ignore::CodexIgnore;
 use codex_utils_string::take_bytes_at_char_boundary;
 use serde::Deserialize;
 use tokio::fs;
@@ -21,6 +23,7 @@ pub struct ListDirHandler;
 
 const DENY_READ_POLICY_MESSAGE: &str =
     "access denied: reading this path is blocked by filesystem deny_read policy";
+const CODEX_IGNORE_MESSAGE: &str = "access denied: this path is ignored by .codexignore";
 const MAX_ENTRY_LENGTH: usize = 500;
 const INDENTATION_SPACES: usize = 2;
 
@@ -109,10 +112,26 @@ impl ToolHandler for ListDirHandler {
                 path.display()
             )));
         }
+        let codex_ignore = CodexIgnore::load(&turn.cwd);
+        if codex_ignore
+            .as_ref()
+            .is_some_and(|ignore| ignore.is_ignored(&path, /*is_dir*/ true))
+        {
+            return Err(FunctionCallError::RespondToModel(format!(
+                "{CODEX_IGNORE_MESSAGE}: `{}`",
+                path.display()
+            )));
+        }
 
-        let entries =
-            list_dir_slice_with_policy(&path, offset, limit, depth, read_deny_matcher.as_ref())
-                .await?;
+        let entries = list_dir_slice_with_policy(
+            &path,
+            offset,
+            limit,
+            depth,
+            read_deny_matcher.as_ref(),
+            codex_ignore.as_ref(),
+        )
+        .await?;
         let mut output = Vec::with_capacity(entries.len() + 1);
         output.push(format!("Absolute path: {}", path.display()));
         output.extend(entries);
@@ -126,9 +145,18 @@ async fn list_dir_slice_with_policy(
     limit: usize,
     depth: usize,
     read_deny_matcher: Option<&ReadDenyMatcher>,
+    codex_ignore: Option<&CodexIgnore>,
 ) -> Result<Vec<String>, FunctionCallError> {
     let mut entries = Vec::new();
-    collect_entries(path, Path::new(""), depth, read_deny_matcher, &mut entries).await?;
+    collect_entries(
+        path,
+        Path::new(""),
+        depth,
+        read_deny_matcher,
+        codex_ignore,
+        &mut entries,
+    )
+    .await?;
 
     if entries.is_empty() {
         return Ok(Vec::new());
@@ -165,6 +193,7 @@ async fn collect_entries(
     relative_prefix: &Path,
     depth: usize,
     read_deny_matcher: Option<&ReadDenyMatcher>,
+    codex_ignore: Option<&CodexIgnore>,
     entries: &mut Vec<DirEntry>,
 ) -> Result<(), FunctionCallError> {
     let mut queue = VecDeque::new();
@@ -191,6 +220,12 @@ async fn collect_entries(
                 FunctionCallError::RespondToModel(format!("failed to inspect entry: {err}"))
             })?;
 
+            if let Some(codex_ignore) = codex_ignore
+                && codex_ignore.is_ignored(&entry_path, file_type.is_dir())
+            {
+                continue;
+            }
+
             let file_name = entry.file_name();
             let relative_path = if prefix.as_os_str().is_empty() {
                 PathBuf::from(&file_name)
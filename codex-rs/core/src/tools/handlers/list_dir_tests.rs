@@ -13,7 +13,10 @@ async fn list_dir_slice(
     limit: usize,
     depth: usize,
 ) -> Result<Vec<String>, FunctionCallError> {
-    list_dir_slice_with_policy(path, offset, limit, depth, /*read_deny_matcher*/ None).await
+    list_dir_slice_with_policy(
+        path, offset, limit, depth, /*read_deny_matcher*/ None, /*codex_ignore*/ None,
+    )
+    .await
 }
 
 #[tokio::test]
@@ -320,6 +323,7 @@ async fn hides_denied_entries_and_prunes_denied_subtrees() {
         /*limit*/ 20,
         /*depth*/ 3,
         read_deny_matcher.as_ref(),
+        /*codex_ignore*/ None,
     )
     .await
     .expect("list directory");
@@ -329,3 +333,42 @@ async fn hides_denied_entries_and_prunes_denied_subtrees() {
         vec!["visible/".to_string(), "  ok.txt".to_string(),]
     );
 }
+
+#[tokio::test]
+async fn skips_entries_ignored_by_codexignore() {
+    let temp = tempdir().expect("create tempdir");
+    let dir_path = temp.path();
+
+    tokio::fs::write(
+        dir_path.join(crate::codex_ignore::CODEX_IGNORE_FILENAME),
+        "*.pem\nvendor/\n",
+    )
+    .await
+    .expect("write codexignore");
+    tokio::fs::write(dir_path.join("key.pem"), "secret")
+        .await
+        .expect("write ignored file");
+    tokio::fs::create_dir(dir_path.join("vendor"))
+        .await
+        .expect("create ignored dir");
+    tokio::fs::write(dir_path.join("ok.txt"), "ok")
+        .await
+        .expect("write visible file");
+
+    let codex_ignore = CodexIgnore::load(dir_path).expect("load codexignore");
+    let entries = list_dir_slice_with_policy(
+        dir_path,
+        /*offset*/ 1,
+        /*limit*/ 20,
+        /*depth*/ 3,
+        /*read_deny_matcher*/ None,
+        Some(&codex_ignore),
+    )
+    .await
+    .expect("list directory");
+
+    assert_eq!(
+        entries,
+        vec![".codexignore".to_string(), "ok.txt".to_string()]
+    );
+}
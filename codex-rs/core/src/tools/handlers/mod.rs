@@ -11,6 +11,7 @@ pub(crate) mod multi_agents_v2;
 mod plan;
 mod request_permissions;
 mod request_user_input;
+mod search_replace;
 mod shell;
 mod test_sync;
 mod tool_search;
@@ -18,6 +19,7 @@ mod tool_suggest;
 mod unavailable_tool;
 pub(crate) mod unified_exec;
 mod view_image;
+mod wasm_tool;
 
 use codex_sandboxing::policy_transforms::intersect_permission_profiles;
 use codex_sandboxing::policy_transforms::merge_permission_profiles;
@@ -45,6 +47,7 @@ pub use mcp_resource::McpResourceHandler;
 pub use plan::PlanHandler;
 pub use request_permissions::RequestPermissionsHandler;
 pub use request_user_input::RequestUserInputHandler;
+pub use search_replace::SearchReplaceHandler;
 pub use shell::ShellCommandHandler;
 pub use shell::ShellHandler;
 pub use test_sync::TestSyncHandler;
@@ -54,6 +57,7 @@ pub use unavailable_tool::UnavailableToolHandler;
 pub(crate) use unavailable_tool::unavailable_tool_message;
 pub use unified_exec::UnifiedExecHandler;
 pub use view_image::ViewImageHandler;
+pub use wasm_tool::WasmToolHandler;
 
 fn parse_arguments<T>(arguments: &str) -> Result<T, FunctionCallError>
 where
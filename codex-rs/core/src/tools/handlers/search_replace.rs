@@ -0,0 +1,286 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::permissions::ReadDenyMatcher;
+use ignore::WalkBuilder;
+use ignore::overrides::OverrideBuilder;
+use regex_lite::Regex;
+use serde::Deserialize;
+use similar::ChangeTag;
+use similar::TextDiff;
+
+use crate::codex_ignore::CODEX_IGNORE_FILENAME;
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::FunctionToolOutput;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolPayload;
+use crate::tools::handlers::apply_patch::apply_action_via_runtime;
+use crate::tools::handlers::parse_arguments;
+use crate::tools::registry::ToolHandler;
+use crate::tools::registry::ToolKind;
+
+/// Applies a regex search-and-replace across workspace files by synthesizing
+/// an `apply_patch` envelope from the edits and running it through the shared
+/// patch pipeline, so a whole-repo rename gets one preview and one approval
+/// instead of dozens of per-file patches.
+pub struct SearchReplaceHandler;
+
+/// Refusing past this point keeps a runaway pattern from producing a patch no
+/// reviewer could meaningfully approve.
+const MAX_CHANGED_FILES: usize = 64;
+/// Files larger than this are skipped rather than scanned.
+const MAX_SCANNED_FILE_BYTES: u64 = 1024 * 1024;
+/// Context lines kept around each edited region in the synthesized patch.
+const CHUNK_CONTEXT_RADIUS: usize = 3;
+
+#[derive(Deserialize)]
+struct SearchReplaceArgs {
+    pattern: String,
+    replacement: String,
+    #[serde(default)]
+    include: Option<String>,
+}
+
+struct FileEdit {
+    relative_path: PathBuf,
+    matches: usize,
+    old_contents: String,
+    new_contents: String,
+}
+
+impl ToolHandler for SearchReplaceHandler {
+    type Output = FunctionToolOutput;
+
+    fn kind(&self) -> ToolKind {
+        ToolKind::Function
+    }
+
+    async fn is_mutating(&self, _invocation: &ToolInvocation) -> bool {
+        true
+    }
+
+    async fn handle(&self, invocation: ToolInvocation) -> Result<Self::Output, FunctionCallError> {
+        let ToolInvocation {
+            session,
+            turn,
+            tracker,
+            call_id,
+            tool_name,
+            payload,
+        } = invocation;
+
+        let arguments = match payload {
+            ToolPayload::Function { arguments } => arguments,
+            _ => {
+                return Err(FunctionCallError::RespondToModel(
+                    "search_replace handler received unsupported payload".to_string(),
+                ));
+            }
+        };
+
+        let SearchReplaceArgs {
+            pattern,
+            replacement,
+            include,
+        } = parse_arguments(&arguments)?;
+
+        let regex = Regex::new(&pattern)
+            .map_err(|err| FunctionCallError::RespondToModel(format!("invalid pattern: {err}")))?;
+
+        let Some(environment) = turn.environment.as_ref() else {
+            return Err(FunctionCallError::RespondToModel(
+                "search_replace is unavailable in this session".to_string(),
+            ));
+        };
+
+        let cwd = turn.cwd.clone();
+        let read_deny_matcher = ReadDenyMatcher::new(&turn.file_system_sandbox_policy, &turn.cwd);
+        let edits = {
+            let root = cwd.clone();
+            tokio::task::spawn_blocking(move || {
+                collect_edits(
+                    root.as_path(),
+                    &regex,
+                    &replacement,
+                    include.as_deref(),
+                    read_deny_matcher.as_ref(),
+                )
+            })
+            .await
+            .map_err(|err| {
+                FunctionCallError::RespondToModel(format!("search_replace scan failed: {err}"))
+            })??
+        };
+
+        if edits.is_empty() {
+            return Ok(FunctionToolOutput::from_text(
+                "No matches found.".to_string(),
+                Some(true),
+            ));
+        }
+
+        // Re-verify the synthesized patch against the environment filesystem
+        // so the approval preview and the eventual write go through exactly
+        // the same pipeline as a model-authored apply_patch call.
+        let patch = build_patch(&edits);
+        let command = vec!["apply_patch".to_string(), patch];
+        let fs = environment.get_filesystem();
+        let sandbox = environment
+            .is_remote()
+            .then(|| turn.file_system_sandbox_context(/*additional_permissions*/ None));
+        match codex_apply_patch::maybe_parse_apply_patch_verified(
+            &command,
+            &cwd,
+            fs.as_ref(),
+            sandbox.as_ref(),
+        )
+        .await
+        {
+            codex_apply_patch::MaybeApplyPatchVerified::Body(action) => {
+                let content = apply_action_via_runtime(
+                    &session,
+                    &turn,
+                    Some(&tracker),
+                    &call_id,
+                    tool_name.display(),
+                    action,
+                )
+                .await?;
+                let total_matches: usize = edits.iter().map(|edit| edit.matches).sum();
+                Ok(FunctionToolOutput::from_text(
+                    format!(
+                        "Replaced {total_matches} match(es) across {} file(s).\n{content}",
+                        edits.len()
+                    ),
+                    Some(true),
+                ))
+            }
+            codex_apply_patch::MaybeApplyPatchVerified::CorrectnessError(parse_error) => {
+                Err(FunctionCallError::RespondToModel(format!(
+                    "search_replace could not verify the synthesized patch: {parse_error}"
+                )))
+            }
+            _ => Err(FunctionCallError::RespondToModel(
+                "search_replace could not verify the synthesized patch".to_string(),
+            )),
+        }
+    }
+}
+
+/// Walks `root` honoring gitignore and `.codexignore` rules, returning the
+/// regex edits for every readable UTF-8 file whose contents change.
+fn collect_edits(
+    root: &Path,
+    regex: &Regex,
+    replacement: &str,
+    include: Option<&str>,
+    read_deny_matcher: Option<&ReadDenyMatcher>,
+) -> Result<Vec<FileEdit>, FunctionCallError> {
+    let mut walk_builder = WalkBuilder::new(root);
+    walk_builder.add_custom_ignore_filename(CODEX_IGNORE_FILENAME);
+    if let Some(include) = include {
+        let mut overrides = OverrideBuilder::new(root);
+        overrides.add(include).map_err(|err| {
+            FunctionCallError::RespondToModel(format!("invalid include glob: {err}"))
+        })?;
+        let overrides = overrides.build().map_err(|err| {
+            FunctionCallError::RespondToModel(format!("invalid include glob: {err}"))
+        })?;
+        walk_builder.overrides(overrides);
+    }
+
+    let mut edits = Vec::new();
+    for entry in walk_builder.build() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry
+            .file_type()
+            .is_some_and(|file_type| file_type.is_file())
+        {
+            continue;
+        }
+        let path = entry.path();
+        if read_deny_matcher.is_some_and(|matcher| matcher.is_read_denied(path)) {
+            continue;
+        }
+        if !entry
+            .metadata()
+            .is_ok_and(|metadata| metadata.len() <= MAX_SCANNED_FILE_BYTES)
+        {
+            continue;
+        }
+        let Ok(bytes) = std::fs::read(path) else {
+            continue;
+        };
+        // Binary files are skipped rather than corrupted.
+        let Ok(old_contents) = String::from_utf8(bytes) else {
+            continue;
+        };
+        let matches = regex.find_iter(&old_contents).count();
+        if matches == 0 {
+            continue;
+        }
+        let new_contents = regex.replace_all(&old_contents, replacement).into_owned();
+        if new_contents == old_contents {
+            continue;
+        }
+        let relative_path = path.strip_prefix(root).unwrap_or(path).to_path_buf();
+        edits.push(FileEdit {
+            relative_path,
+            matches,
+            old_contents,
+            new_contents,
+        });
+        if edits.len() > MAX_CHANGED_FILES {
+            return Err(FunctionCallError::RespondToModel(format!(
+                "search_replace matched more than {MAX_CHANGED_FILES} files; narrow the pattern or add an include filter"
+            )));
+        }
+    }
+    edits.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    Ok(edits)
+}
+
+/// Renders the edits as a single `apply_patch` envelope with one update hunk
+/// per file, relative to the session cwd.
+fn build_patch(edits: &[FileEdit]) -> String {
+    let mut patch = String::from("*** Begin Patch\n");
+    for edit in edits {
+        patch.push_str(&format!(
+            "*** Update File: {}\n",
+            edit.relative_path.display()
+        ));
+        patch.push_str(&update_chunks(&edit.old_contents, &edit.new_contents));
+    }
+    patch.push_str("*** End Patch\n");
+    patch
+}
+
+/// Emits `@@`-delimited chunks in the apply_patch update format, keeping
+/// [`CHUNK_CONTEXT_RADIUS`] context lines around each edited region.
+fn update_chunks(old_contents: &str, new_contents: &str) -> String {
+    let diff = TextDiff::from_lines(old_contents, new_contents);
+    let mut chunks = String::new();
+    for group in diff.grouped_ops(CHUNK_CONTEXT_RADIUS) {
+        chunks.push_str("@@\n");
+        for op in &group {
+            for change in diff.iter_changes(op) {
+                let prefix = match change.tag() {
+                    ChangeTag::Equal => ' ',
+                    ChangeTag::Delete => '-',
+                    ChangeTag::Insert => '+',
+                };
+                let value = change.value();
+                chunks.push(prefix);
+                chunks.push_str(value.strip_suffix('\n').unwrap_or(value));
+                chunks.push('\n');
+            }
+        }
+    }
+    chunks
+}
+
+#[cfg(test)]
+#[path = "search_replace_tests.rs"]
+mod tests;
@@ -0,0 +1,90 @@
+use super::*;
+use codex_apply_patch::Hunk;
+use codex_apply_patch::parse_patch;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn update_chunks_keeps_context_around_each_edit() {
+    let old_contents = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nold\nk\n";
+    let new_contents = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nnew\nk\n";
+    assert_eq!(
+        update_chunks(old_contents, new_contents),
+        "@@\n h\n i\n j\n-old\n+new\n k\n"
+    );
+}
+
+#[test]
+fn build_patch_round_trips_through_the_parser() {
+    let edits = vec![FileEdit {
+        relative_path: PathBuf::from("src/lib.rs"),
+        matches: 1,
+        old_contents: "fn old_name() {}\nfn other() {}\n".to_string(),
+        new_contents: "fn new_name() {}\nfn other() {}\n".to_string(),
+    }];
+
+    let patch = build_patch(&edits);
+    let parsed = parse_patch(&patch).expect("synthesized patch should parse");
+    match parsed.hunks.as_slice() {
+        [Hunk::UpdateFile { path, chunks, .. }] => {
+            assert_eq!(path, &PathBuf::from("src/lib.rs"));
+            assert_eq!(chunks.len(), 1);
+            assert_eq!(
+                chunks[0].old_lines,
+                vec!["fn old_name() {}", "fn other() {}"]
+            );
+            assert_eq!(
+                chunks[0].new_lines,
+                vec!["fn new_name() {}", "fn other() {}"]
+            );
+        }
+        other => panic!("unexpected hunks: {other:?}"),
+    }
+}
+
+#[test]
+fn collect_edits_skips_ignored_and_binary_files() {
+    let root = tempfile::tempdir().expect("tempdir");
+    std::fs::write(root.path().join("a.txt"), "old value\n").expect("write");
+    std::fs::write(root.path().join("secret.txt"), "old value\n").expect("write");
+    std::fs::write(
+        root.path().join(crate::codex_ignore::CODEX_IGNORE_FILENAME),
+        "secret.txt\n",
+    )
+    .expect("write");
+    std::fs::write(root.path().join("blob.bin"), b"old \xff\xfe").expect("write");
+
+    let regex = Regex::new("old").expect("regex");
+    let edits = collect_edits(
+        root.path(),
+        &regex,
+        "new",
+        /*include*/ None,
+        /*read_deny_matcher*/ None,
+    )
+    .expect("collect");
+
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].relative_path, PathBuf::from("a.txt"));
+    assert_eq!(edits[0].matches, 1);
+    assert_eq!(edits[0].new_contents, "new value\n");
+}
+
+#[test]
+fn collect_edits_honors_the_include_glob() {
+    let root = tempfile::tempdir().expect("tempdir");
+    std::fs::write(root.path().join("a.rs"), "old\n").expect("write");
+    std::fs::write(root.path().join("a.md"), "old\n").expect("write");
+
+    let regex = Regex::new("old").expect("regex");
+    let edits = collect_edits(
+        root.path(),
+        &regex,
+        "new",
+        Some("*.rs"),
+        /*read_deny_matcher*/ None,
+    )
+    .expect("collect");
+
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].relative_path, PathBuf::from("a.rs"));
+}
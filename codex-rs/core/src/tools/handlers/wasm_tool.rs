@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use codex_config::config_toml::WasmToolToml;
+
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::FunctionToolOutput;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolPayload;
+use crate::tools::registry::ToolHandler;
+use crate::tools::registry::ToolKind;
+use crate::wasm_tools::run_wasm_tool;
+
+/// Handler for user-defined WASM tools declared under `[wasm_tools.<name>]`.
+/// One instance serves every configured tool; the invocation's tool name
+/// selects the policy.
+pub struct WasmToolHandler {
+    tools: Arc<HashMap<String, WasmToolToml>>,
+}
+
+impl WasmToolHandler {
+    pub fn new(tools: Arc<HashMap<String, WasmToolToml>>) -> Self {
+        Self { tools }
+    }
+}
+
+impl ToolHandler for WasmToolHandler {
+    type Output = FunctionToolOutput;
+
+    fn kind(&self) -> ToolKind {
+        ToolKind::Function
+    }
+
+    async fn is_mutating(&self, invocation: &ToolInvocation) -> bool {
+        self.tools
+            .get(&invocation.tool_name.display())
+            .is_some_and(|policy| !policy.fs_write.is_empty())
+    }
+
+    async fn handle(&self, invocation: ToolInvocation) -> Result<Self::Output, FunctionCallError> {
+        let tool_name = invocation.tool_name.display().to_string();
+        let policy = self.tools.get(&tool_name).cloned().ok_or_else(|| {
+            FunctionCallError::RespondToModel(format!("WASM tool {tool_name} is not configured"))
+        })?;
+        let arguments = match invocation.payload {
+            ToolPayload::Function { arguments } => arguments,
+            _ => {
+                return Err(FunctionCallError::RespondToModel(
+                    "WASM tool handler received unsupported payload".to_string(),
+                ));
+            }
+        };
+
+        let stdout =
+            tokio::task::spawn_blocking(move || run_wasm_tool(&tool_name, &policy, &arguments))
+                .await
+                .map_err(|err| {
+                    FunctionCallError::RespondToModel(format!(
+                        "WASM tool execution panicked: {err}"
+                    ))
+                })?
+                .map_err(|err| FunctionCallError::RespondToModel(format!("{err:#}")))?;
+
+        Ok(FunctionToolOutput::from_text(stdout, Some(true)))
+    }
+}
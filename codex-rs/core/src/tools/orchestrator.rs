@@ -197,6 +197,15 @@ impl ToolOrchestrator {
                 managed_network_active,
             ),
         };
+        // A configured container sandbox replaces the platform sandbox: any
+        // command that would have run under seatbelt/landlock runs in the
+        // container instead. Unsandboxed first attempts stay unsandboxed.
+        let initial_sandbox =
+            if initial_sandbox != SandboxType::None && turn_ctx.container_sandbox.is_some() {
+                SandboxType::Container
+            } else {
+                initial_sandbox
+            };
 
         // Platform-specific flag gating is handled by SandboxManager::select_initial.
         let use_legacy_landlock = turn_ctx.features.use_legacy_landlock();
@@ -209,6 +218,7 @@ impl ToolOrchestrator {
             manager: &self.sandbox,
             sandbox_cwd: &turn_ctx.cwd,
             codex_linux_sandbox_exe: turn_ctx.codex_linux_sandbox_exe.as_ref(),
+            container: turn_ctx.container_sandbox.as_ref(),
             use_legacy_landlock,
             windows_sandbox_level: turn_ctx.windows_sandbox_level,
             windows_sandbox_private_desktop: turn_ctx
@@ -350,6 +360,7 @@ impl ToolOrchestrator {
                     manager: &self.sandbox,
                     sandbox_cwd: &turn_ctx.cwd,
                     codex_linux_sandbox_exe: None,
+                    container: None,
                     use_legacy_landlock,
                     windows_sandbox_level: turn_ctx.windows_sandbox_level,
                     windows_sandbox_private_desktop: turn_ctx
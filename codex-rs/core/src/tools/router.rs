@@ -9,6 +9,7 @@ use crate::tools::registry::AnyToolResult;
 use crate::tools::registry::ToolArgumentDiffConsumer;
 use crate::tools::registry::ToolRegistry;
 use crate::tools::spec::build_specs_with_discoverable_tools;
+use codex_config::config_toml::WasmToolToml;
 use codex_mcp::ToolInfo;
 use codex_protocol::dynamic_tools::DynamicToolSpec;
 use codex_protocol::models::LocalShellAction;
@@ -49,6 +50,7 @@ pub(crate) struct ToolRouterParams<'a> {
     pub(crate) parallel_mcp_server_names: HashSet<String>,
     pub(crate) discoverable_tools: Option<Vec<DiscoverableTool>>,
     pub(crate) dynamic_tools: &'a [DynamicToolSpec],
+    pub(crate) wasm_tools: HashMap<String, WasmToolToml>,
 }
 
 impl ToolRouter {
@@ -60,6 +62,7 @@ impl ToolRouter {
             parallel_mcp_server_names,
             discoverable_tools,
             dynamic_tools,
+            wasm_tools,
         } = params;
         let builder = build_specs_with_discoverable_tools(
             config,
@@ -68,6 +71,7 @@ impl ToolRouter {
             unavailable_called_tools,
             discoverable_tools,
             dynamic_tools,
+            wasm_tools,
         );
         let (specs, registry) = builder.build();
         let model_visible_specs = if config.code_mode_only_enabled {
@@ -118,6 +118,7 @@ fn file_system_sandbox_context_uses_active_attempt() {
         manager: &manager,
         sandbox_cwd: &path,
         codex_linux_sandbox_exe: None,
+        container: None,
         use_legacy_landlock: true,
         windows_sandbox_level: WindowsSandboxLevel::RestrictedToken,
         windows_sandbox_private_desktop: true,
@@ -170,6 +171,7 @@ fn file_system_sandbox_context_omits_legacy_equivalent_policy() {
         manager: &manager,
         sandbox_cwd: &path,
         codex_linux_sandbox_exe: None,
+        container: None,
         use_legacy_landlock: true,
         windows_sandbox_level: WindowsSandboxLevel::RestrictedToken,
         windows_sandbox_private_desktop: true,
@@ -210,6 +212,7 @@ fn no_sandbox_attempt_has_no_file_system_context() {
         manager: &manager,
         sandbox_cwd: &path,
         codex_linux_sandbox_exe: None,
+        container: None,
         use_legacy_landlock: false,
         windows_sandbox_level: WindowsSandboxLevel::Disabled,
         windows_sandbox_private_desktop: false,
@@ -40,6 +40,7 @@ use codex_protocol::error::SandboxErr;
 use codex_protocol::models::PermissionProfile;
 use codex_protocol::protocol::ReviewDecision;
 use codex_sandboxing::SandboxablePreference;
+use codex_shell_command::high_risk::command_high_risk;
 use codex_shell_command::powershell::prefix_powershell_script_with_utf8;
 use codex_tools::UnifiedExecShellMode;
 use codex_utils_absolute_path::AbsolutePathBuf;
@@ -123,7 +124,14 @@ impl Approvable<UnifiedExecRequest> for UnifiedExecRuntime<'_> {
         req: &'b UnifiedExecRequest,
         ctx: ApprovalCtx<'b>,
     ) -> BoxFuture<'b, ReviewDecision> {
-        let keys = self.approval_keys(req);
+        // High-risk commands always reach the user: skip the session approval
+        // cache (empty key set) so a prior "approve for session" for the same
+        // command cannot silence the confirmation prompt.
+        let keys = if command_high_risk(&req.command).is_some() {
+            Vec::new()
+        } else {
+            self.approval_keys(req)
+        };
         let session = ctx.session;
         let turn = ctx.turn;
         let call_id = ctx.call_id.to_string();
@@ -5,6 +5,7 @@ use crate::tools::handlers::multi_agents_common::DEFAULT_WAIT_TIMEOUT_MS;
 use crate::tools::handlers::multi_agents_common::MAX_WAIT_TIMEOUT_MS;
 use crate::tools::handlers::multi_agents_common::MIN_WAIT_TIMEOUT_MS;
 use crate::tools::registry::ToolRegistryBuilder;
+use codex_config::config_toml::WasmToolToml;
 use codex_mcp::ToolInfo;
 use codex_protocol::dynamic_tools::DynamicToolSpec;
 use codex_tools::AdditionalProperties;
@@ -30,6 +31,7 @@ pub(crate) fn tool_user_shell_type(user_shell: &Shell) -> ToolUserShellType {
     match user_shell.shell_type {
         ShellType::Zsh => ToolUserShellType::Zsh,
         ShellType::Bash => ToolUserShellType::Bash,
+        ShellType::Fish => ToolUserShellType::Fish,
         ShellType::PowerShell => ToolUserShellType::PowerShell,
         ShellType::Sh => ToolUserShellType::Sh,
         ShellType::Cmd => ToolUserShellType::Cmd,
@@ -75,6 +77,7 @@ pub(crate) fn build_specs_with_discoverable_tools(
     unavailable_called_tools: Vec<ToolName>,
     discoverable_tools: Option<Vec<DiscoverableTool>>,
     dynamic_tools: &[DynamicToolSpec],
+    wasm_tools: HashMap<String, WasmToolToml>,
 ) -> ToolRegistryBuilder {
     use crate::tools::handlers::ApplyPatchHandler;
     use crate::tools::handlers::CodeModeExecuteHandler;
@@ -88,6 +91,7 @@ pub(crate) fn build_specs_with_discoverable_tools(
     use crate::tools::handlers::PlanHandler;
     use crate::tools::handlers::RequestPermissionsHandler;
     use crate::tools::handlers::RequestUserInputHandler;
+    use crate::tools::handlers::SearchReplaceHandler;
     use crate::tools::handlers::ShellCommandHandler;
     use crate::tools::handlers::ShellHandler;
     use crate::tools::handlers::TestSyncHandler;
@@ -96,6 +100,7 @@ pub(crate) fn build_specs_with_discoverable_tools(
     use crate::tools::handlers::UnavailableToolHandler;
     use crate::tools::handlers::UnifiedExecHandler;
     use crate::tools::handlers::ViewImageHandler;
+    use crate::tools::handlers::WasmToolHandler;
     use crate::tools::handlers::multi_agents::CloseAgentHandler;
     use crate::tools::handlers::multi_agents::ResumeAgentHandler;
     use crate::tools::handlers::multi_agents::SendInputHandler;
@@ -242,6 +247,9 @@ pub(crate) fn build_specs_with_discoverable_tools(
             ToolHandlerKind::ResumeAgentV1 => {
                 builder.register_handler(handler.name, Arc::new(ResumeAgentHandler));
             }
+            ToolHandlerKind::SearchReplace => {
+                builder.register_handler(handler.name, Arc::new(SearchReplaceHandler));
+            }
             ToolHandlerKind::SendInputV1 => {
                 builder.register_handler(handler.name, Arc::new(SendInputHandler));
             }
@@ -329,6 +337,39 @@ pub(crate) fn build_specs_with_discoverable_tools(
         }
         builder.register_handler(unavailable_tool, unavailable_tool_handler.clone());
     }
+
+    if !wasm_tools.is_empty() {
+        let wasm_tools = Arc::new(wasm_tools);
+        let wasm_tool_handler = Arc::new(WasmToolHandler::new(wasm_tools.clone()));
+        for (name, tool) in wasm_tools.iter() {
+            if !existing_spec_names.insert(name.clone()) {
+                tracing::warn!("skipping WASM tool {name}: a tool with that name already exists");
+                continue;
+            }
+            let spec = codex_tools::ToolSpec::Function(ResponsesApiTool {
+                name: name.clone(),
+                description: tool
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| format!("User-defined WASM tool `{name}`.")),
+                strict: false,
+                parameters: JsonSchema::object(
+                    Default::default(),
+                    /*required*/ None,
+                    Some(AdditionalProperties::Boolean(true)),
+                ),
+                output_schema: None,
+                defer_loading: None,
+            });
+            let spec = if config.code_mode_enabled {
+                augment_tool_spec_for_code_mode(spec)
+            } else {
+                spec
+            };
+            builder.push_spec(spec);
+            builder.register_handler(name.clone(), wasm_tool_handler.clone());
+        }
+    }
     builder
 }
 
@@ -37,6 +37,7 @@ use codex_utils_absolute_path::AbsolutePathBuf;
 use core_test_support::assert_regex_match;
 use pretty_assertions::assert_eq;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use super::*;
@@ -292,6 +293,7 @@ fn build_specs_with_unavailable_tools(
         unavailable_called_tools,
         /*discoverable_tools*/ None,
         dynamic_tools,
+        /*wasm_tools*/ HashMap::new(),
     )
 }
 
@@ -378,6 +380,7 @@ async fn assert_model_tools(
             parallel_mcp_server_names: std::collections::HashSet::new(),
             discoverable_tools: None,
             dynamic_tools: &[],
+            wasm_tools: HashMap::new(),
         },
     );
     let model_visible_specs = router.model_visible_specs();
@@ -814,6 +817,7 @@ async fn tool_suggest_requires_apps_and_plugins_features() {
             Vec::new(),
             discoverable_tools.clone(),
             &[],
+            /*wasm_tools*/ HashMap::new(),
         )
         .build();
 
@@ -0,0 +1,40 @@
+//! Per-turn reproducibility metadata.
+//!
+//! Records the request parameters behind a turn — model, reasoning effort,
+//! and the provider-assigned id of the turn's final response — so surprising
+//! outputs can be investigated later or reported to the provider. The
+//! sampling loop records the response id as streams complete; the turn's
+//! `TurnComplete` event then carries a snapshot of everything.
+
+use codex_protocol::protocol::TurnRequestParams;
+use tokio::sync::Mutex;
+
+use crate::session::turn_context::TurnContext;
+
+#[derive(Debug, Default)]
+pub(crate) struct TurnReproState {
+    provider_request_id: Mutex<Option<String>>,
+}
+
+impl TurnReproState {
+    /// Remembers the provider-assigned response id; the last completed
+    /// response of the turn wins.
+    pub(crate) async fn record_provider_request_id(&self, response_id: &str) {
+        if response_id.is_empty() {
+            return;
+        }
+        *self.provider_request_id.lock().await = Some(response_id.to_string());
+    }
+
+    /// Snapshot of the turn's request parameters. Temperature and seed stay
+    /// unset: the Responses API requests Codex sends do not carry either.
+    pub(crate) async fn request_params(&self, turn_context: &TurnContext) -> TurnRequestParams {
+        TurnRequestParams {
+            model: turn_context.model_info.slug.clone(),
+            effort: turn_context.reasoning_effort,
+            temperature: None,
+            seed: None,
+            provider_request_id: self.provider_request_id.lock().await.clone(),
+        }
+    }
+}
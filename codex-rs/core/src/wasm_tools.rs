@@ -0,0 +1,100 @@
+//! Runtime for user-defined WASM tools.
+//!
+//! Tools declared under `[wasm_tools.<name>]` are WASI modules run in a
+//! wasmtime sandbox: the module receives the model's JSON arguments on
+//! stdin, writes its result to stdout, and sees nothing of the host beyond
+//! the capability grants in its config entry — preopened directories
+//! (read-only for `fs_read`, read-write for `fs_write`), an explicit
+//! environment allowlist, and no network at all. Execution is bounded by a
+//! fuel budget so a runaway module cannot hang a turn.
+
+use std::path::Path;
+
+use anyhow::Context as _;
+use anyhow::Result;
+use anyhow::bail;
+use codex_config::config_toml::WasmToolToml;
+use wasmtime::Engine;
+use wasmtime::Linker;
+use wasmtime::Module;
+use wasmtime::Store;
+use wasmtime_wasi::DirPerms;
+use wasmtime_wasi::FilePerms;
+use wasmtime_wasi::WasiCtxBuilder;
+use wasmtime_wasi::pipe::MemoryInputPipe;
+use wasmtime_wasi::pipe::MemoryOutputPipe;
+use wasmtime_wasi::preview1::WasiP1Ctx;
+
+/// Default fuel budget when `max_fuel` is unset. Roughly a few seconds of
+/// compute; modules that exhaust it trap rather than hang the turn.
+const DEFAULT_FUEL: u64 = 2_000_000_000;
+
+/// Cap on the bytes a tool may write to stdout.
+const MAX_OUTPUT_BYTES: usize = 256 * 1024;
+
+/// Runs the WASI module declared by `policy` with `input` on stdin and
+/// returns its stdout. Blocking: callers on an async runtime should wrap
+/// this in `spawn_blocking`.
+pub fn run_wasm_tool(name: &str, policy: &WasmToolToml, input: &str) -> Result<String> {
+    let mut config = wasmtime::Config::new();
+    config.consume_fuel(true);
+    let engine = Engine::new(&config)?;
+    let module = Module::from_file(&engine, &policy.module).with_context(|| {
+        format!(
+            "failed to load WASM module for tool {name} from {}",
+            policy.module.display()
+        )
+    })?;
+
+    let stdout = MemoryOutputPipe::new(MAX_OUTPUT_BYTES);
+    let mut wasi = WasiCtxBuilder::new();
+    wasi.stdin(MemoryInputPipe::new(input.to_string()));
+    wasi.stdout(stdout.clone());
+    for dir in &policy.fs_read {
+        preopen(&mut wasi, dir, DirPerms::READ, FilePerms::READ)?;
+    }
+    for dir in &policy.fs_write {
+        preopen(&mut wasi, dir, DirPerms::all(), FilePerms::all())?;
+    }
+    for key in &policy.env {
+        if let Ok(value) = std::env::var(key) {
+            wasi.env(key, &value);
+        }
+    }
+
+    let mut linker: Linker<WasiP1Ctx> = Linker::new(&engine);
+    wasmtime_wasi::preview1::add_to_linker_sync(&mut linker, |ctx| ctx)?;
+    let mut store = Store::new(&engine, wasi.build_p1());
+    store.set_fuel(policy.max_fuel.unwrap_or(DEFAULT_FUEL))?;
+
+    let instance = linker.instantiate(&mut store, &module)?;
+    let start = instance
+        .get_typed_func::<(), ()>(&mut store, "_start")
+        .with_context(|| format!("tool {name} is not a WASI command module"))?;
+    if let Err(err) = start.call(&mut store, ()) {
+        // A zero exit is surfaced as an "error" by the WASI exit trap.
+        match err.downcast_ref::<wasmtime_wasi::I32Exit>() {
+            Some(exit) if exit.0 == 0 => {}
+            Some(exit) => bail!("tool {name} exited with status {}", exit.0),
+            None => return Err(err.context(format!("tool {name} trapped"))),
+        }
+    }
+    drop(store);
+
+    let bytes = stdout.contents();
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Preopens `dir` for the guest at the same path it has on the host, so
+/// paths in tool arguments work unchanged.
+fn preopen(
+    wasi: &mut WasiCtxBuilder,
+    dir: &Path,
+    dir_perms: DirPerms,
+    file_perms: FilePerms,
+) -> Result<()> {
+    let guest_path = dir.to_string_lossy();
+    wasi.preopened_dir(dir, guest_path.as_ref(), dir_perms, file_perms)
+        .with_context(|| format!("failed to preopen {}", dir.display()))?;
+    Ok(())
+}
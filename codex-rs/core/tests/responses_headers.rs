@@ -105,6 +105,7 @@ async fn responses_stream_includes_subagent_header_on_review() {
         session_source,
         config.model_verbosity,
         /*enable_request_compression*/ false,
+        /*enable_prompt_caching*/ true,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
     );
@@ -230,6 +231,7 @@ async fn responses_stream_includes_subagent_header_on_other() {
         session_source,
         config.model_verbosity,
         /*enable_request_compression*/ false,
+        /*enable_prompt_caching*/ true,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
     );
@@ -344,6 +346,7 @@ async fn responses_respects_model_info_overrides_from_config() {
         session_source,
         config.model_verbosity,
         /*enable_request_compression*/ false,
+        /*enable_prompt_caching*/ true,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
     );
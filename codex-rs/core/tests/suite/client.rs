@@ -887,6 +887,7 @@ async fn send_provider_auth_request(server: &MockServer, auth: ModelProviderAuth
         SessionSource::Exec,
         config.model_verbosity,
         /*enable_request_compression*/ false,
+        /*enable_prompt_caching*/ true,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
     );
@@ -2179,6 +2180,7 @@ async fn azure_responses_request_includes_store_and_reasoning_ids() {
         SessionSource::Exec,
         config.model_verbosity,
         /*enable_request_compression*/ false,
+        /*enable_prompt_caching*/ true,
         /*include_timing_metrics*/ false,
         /*beta_features_header*/ None,
     );
@@ -1822,6 +1822,7 @@ async fn websocket_harness_with_provider_options(
         SessionSource::Exec,
         config.model_verbosity,
         /*enable_request_compression*/ false,
+        /*enable_prompt_caching*/ true,
         runtime_metrics_enabled,
         /*beta_features_header*/ None,
     );
@@ -70,6 +70,7 @@ fn resume_history(
                 last_agent_message: None,
                 completed_at: None,
                 duration_ms: None,
+                request_params: None,
             })),
         ],
         rollout_path: rollout_path.to_path_buf(),
@@ -102,6 +102,21 @@ pub trait ExecutorFileSystem: Send + Sync {
         sandbox: Option<&FileSystemSandboxContext>,
     ) -> FileSystemResult<()>;
 
+    /// Sets the unix permission bits on `path`. File systems that cannot
+    /// express permissions report `ErrorKind::Unsupported`.
+    async fn set_file_mode(
+        &self,
+        path: &AbsolutePathBuf,
+        mode: u32,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        let _ = (path, mode, sandbox);
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "this file system does not support changing file modes",
+        ))
+    }
+
     async fn create_directory(
         &self,
         path: &AbsolutePathBuf,
@@ -98,6 +98,16 @@ impl ExecutorFileSystem for LocalFileSystem {
         file_system.write_file(path, contents, sandbox).await
     }
 
+    async fn set_file_mode(
+        &self,
+        path: &AbsolutePathBuf,
+        mode: u32,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        let (file_system, sandbox) = self.file_system_for(sandbox)?;
+        file_system.set_file_mode(path, mode, sandbox).await
+    }
+
     async fn create_directory(
         &self,
         path: &AbsolutePathBuf,
@@ -173,6 +183,18 @@ impl ExecutorFileSystem for UnsandboxedFileSystem {
             .await
     }
 
+    async fn set_file_mode(
+        &self,
+        path: &AbsolutePathBuf,
+        mode: u32,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        reject_platform_sandbox_context(sandbox)?;
+        self.file_system
+            .set_file_mode(path, mode, /*sandbox*/ None)
+            .await
+    }
+
     async fn create_directory(
         &self,
         path: &AbsolutePathBuf,
@@ -264,6 +286,27 @@ impl ExecutorFileSystem for DirectFileSystem {
         tokio::fs::write(path.as_path(), contents).await
     }
 
+    async fn set_file_mode(
+        &self,
+        path: &AbsolutePathBuf,
+        mode: u32,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        reject_sandbox_context(sandbox)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            tokio::fs::set_permissions(path.as_path(), std::fs::Permissions::from_mode(mode)).await
+        }
+        // Windows has no equivalent of unix permission bits; treat a mode
+        // change as a no-op rather than failing the whole patch.
+        #[cfg(not(unix))]
+        {
+            let _ = (path, mode);
+            Ok(())
+        }
+    }
+
     async fn create_directory(
         &self,
         path: &AbsolutePathBuf,
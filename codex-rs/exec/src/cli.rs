@@ -87,6 +87,9 @@ pub struct Cli {
     pub ignore_rules: bool,
 
     /// Path to a JSON Schema file describing the model's final response shape.
+    /// The final message is validated locally against the schema and the turn
+    /// is re-asked on violation; exec exits non-zero if the reply still does
+    /// not conform.
     #[arg(long = "output-schema", value_name = "FILE")]
     pub output_schema: Option<PathBuf>,
 
@@ -25,6 +25,10 @@ pub(crate) trait EventProcessor {
     /// Handle a local exec warning that is not represented as an app-server notification.
     fn process_warning(&mut self, message: String) -> CodexStatus;
 
+    /// Replace the final message emitted by [`Self::print_final_output`],
+    /// e.g. with the schema-validated JSON when `--output-schema` is in use.
+    fn override_final_message(&mut self, _message: String) {}
+
     fn print_final_output(&mut self) {}
 }
 
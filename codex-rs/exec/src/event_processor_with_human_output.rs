@@ -372,6 +372,13 @@ impl EventProcessor for EventProcessorWithHumanOutput {
         CodexStatus::Running
     }
 
+    fn override_final_message(&mut self, message: String) {
+        self.final_message_rendered =
+            self.final_message_rendered && self.final_message.as_deref() == Some(message.as_str());
+        self.final_message = Some(message);
+        self.emit_final_message_on_shutdown = true;
+    }
+
     fn print_final_output(&mut self) {
         if self.emit_final_message_on_shutdown
             && let Some(path) = self.last_message_path.as_deref()
@@ -9,6 +9,7 @@ mod event_processor;
 mod event_processor_with_human_output;
 pub(crate) mod event_processor_with_jsonl_output;
 pub(crate) mod exec_events;
+mod schema_validation;
 
 pub use cli::Cli;
 pub use cli::Command;
@@ -167,6 +168,8 @@ enum StdinPromptBehavior {
     Forced,
     /// If stdin is piped alongside a positional prompt, treat stdin as
     /// additional context to append rather than as the primary prompt.
+    /// Binary or oversized input is dropped with a warning instead of
+    /// attached (see [`MAX_STDIN_ATTACHMENT_BYTES`]).
     OptionalAppend,
 }
 
@@ -632,6 +635,13 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
         }
     };
 
+    // With an output schema, the final message is validated locally before
+    // exec finishes so stdout consumers always see conforming JSON.
+    let output_schema_for_validation = match &initial_operation {
+        InitialOperation::UserTurn { output_schema, .. } => output_schema.clone(),
+        InitialOperation::Review { .. } => None,
+    };
+
     // When --yolo (dangerously_bypass_approvals_and_sandbox) is set, also skip the git repo check
     // since the user is explicitly running in an externally sandboxed environment.
     if !skip_git_repo_check
@@ -726,7 +736,7 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
         }
     });
 
-    let task_id = match initial_operation {
+    let mut task_id = match initial_operation {
         InitialOperation::UserTurn {
             items,
             output_schema,
@@ -739,7 +749,7 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
                         thread_id: primary_thread_id_for_span.clone(),
                         input: items.into_iter().map(Into::into).collect(),
                         responsesapi_client_metadata: None,
-                        cwd: Some(default_cwd),
+                        cwd: Some(default_cwd.clone()),
                         approval_policy: Some(default_approval_policy.into()),
                         approvals_reviewer: None,
                         sandbox_policy: Some(default_sandbox_policy.clone().into()),
@@ -793,6 +803,8 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
     // exit with a non-zero status for automation-friendly signaling.
     let mut error_seen = false;
     let mut interrupt_channel_open = true;
+    let mut last_agent_message: Option<String> = None;
+    let mut schema_retries_left = schema_validation::MAX_SCHEMA_RETRIES;
     let primary_thread_id_for_requests = primary_thread_id.to_string();
     loop {
         let server_event = tokio::select! {
@@ -857,6 +869,13 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
                 )
                 .await;
 
+                if output_schema_for_validation.is_some()
+                    && let ServerNotification::ItemCompleted(payload) = &notification
+                    && let AppServerThreadItem::AgentMessage { text, .. } = &payload.item
+                {
+                    last_agent_message = Some(text.clone());
+                }
+
                 if should_process_notification(
                     &notification,
                     &primary_thread_id_for_requests,
@@ -865,6 +884,78 @@ async fn run_exec_session(args: ExecRunArgs) -> anyhow::Result<()> {
                     match event_processor.process_server_notification(notification) {
                         CodexStatus::Running => {}
                         CodexStatus::InitiateShutdown => {
+                            if let Some(schema) = output_schema_for_validation.as_ref()
+                                && !error_seen
+                            {
+                                match schema_validation::check_final_message(
+                                    schema,
+                                    last_agent_message.as_deref(),
+                                ) {
+                                    Ok(canonical) => {
+                                        event_processor.override_final_message(canonical);
+                                    }
+                                    Err(problems) if schema_retries_left > 0 => {
+                                        schema_retries_left -= 1;
+                                        let retry_text = schema_validation::retry_prompt(&problems);
+                                        event_processor.process_warning(format!(
+                                            "final message violated the output schema; asking the model to correct it ({} retr{} left)",
+                                            schema_retries_left,
+                                            if schema_retries_left == 1 { "y" } else { "ies" },
+                                        ));
+                                        let items = vec![UserInput::Text {
+                                            text: retry_text,
+                                            text_elements: Vec::new(),
+                                        }];
+                                        let response: TurnStartResponse =
+                                            send_request_with_response(
+                                                &client,
+                                                ClientRequest::TurnStart {
+                                                    request_id: request_ids.next(),
+                                                    params: TurnStartParams {
+                                                        thread_id: primary_thread_id_for_requests
+                                                            .clone(),
+                                                        input: items
+                                                            .into_iter()
+                                                            .map(Into::into)
+                                                            .collect(),
+                                                        responsesapi_client_metadata: None,
+                                                        cwd: Some(default_cwd.clone()),
+                                                        approval_policy: Some(
+                                                            default_approval_policy.into(),
+                                                        ),
+                                                        approvals_reviewer: None,
+                                                        sandbox_policy: Some(
+                                                            default_sandbox_policy.clone().into(),
+                                                        ),
+                                                        model: None,
+                                                        service_tier: None,
+                                                        effort: default_effort,
+                                                        summary: None,
+                                                        personality: None,
+                                                        output_schema: Some(schema.clone()),
+                                                        collaboration_mode: None,
+                                                    },
+                                                },
+                                                "turn/start",
+                                            )
+                                            .await
+                                            .map_err(anyhow::Error::msg)?;
+                                        task_id = response.turn.id;
+                                        exec_span.record("turn.id", task_id.as_str());
+                                        last_agent_message = None;
+                                        continue;
+                                    }
+                                    Err(problems) => {
+                                        eprintln!(
+                                            "ERROR: final message still violates the output schema:"
+                                        );
+                                        for problem in &problems {
+                                            eprintln!("  - {problem}");
+                                        }
+                                        error_seen = true;
+                                    }
+                                }
+                            }
                             if let Err(err) = request_shutdown(
                                 &client,
                                 &mut request_ids,
@@ -1638,6 +1729,11 @@ fn decode_utf16(
     String::from_utf16(&units).map_err(|_| PromptDecodeError::InvalidUtf16 { encoding })
 }
 
+/// Piped stdin appended as `<stdin>` context is dropped beyond this size so a
+/// stray `cat big.bin | codex exec "..."` cannot blow the first turn's
+/// context. Primary prompts (`RequiredIfPiped`/`Forced`) are not capped.
+const MAX_STDIN_ATTACHMENT_BYTES: usize = 1024 * 1024;
+
 fn read_prompt_from_stdin(behavior: StdinPromptBehavior) -> Option<String> {
     let stdin_is_terminal = std::io::stdin().is_terminal();
 
@@ -1664,14 +1760,33 @@ fn read_prompt_from_stdin(behavior: StdinPromptBehavior) -> Option<String> {
         std::process::exit(1);
     }
 
+    if matches!(behavior, StdinPromptBehavior::OptionalAppend)
+        && bytes.len() > MAX_STDIN_ATTACHMENT_BYTES
+    {
+        eprintln!("Ignoring piped stdin: input exceeds {MAX_STDIN_ATTACHMENT_BYTES} bytes.");
+        return None;
+    }
+
     let buffer = match decode_prompt_bytes(&bytes) {
         Ok(s) => s,
+        // When stdin only supplements a positional prompt, undecodable input
+        // is almost certainly binary: drop the attachment and keep going
+        // instead of failing the run.
+        Err(e) if matches!(behavior, StdinPromptBehavior::OptionalAppend) => {
+            eprintln!("Ignoring piped stdin: {e}");
+            return None;
+        }
         Err(e) => {
             eprintln!("Failed to read prompt from stdin: {e}");
             std::process::exit(1);
         }
     };
 
+    if matches!(behavior, StdinPromptBehavior::OptionalAppend) && buffer.contains('\0') {
+        eprintln!("Ignoring piped stdin: input looks binary (contains NUL bytes).");
+        return None;
+    }
+
     if buffer.trim().is_empty() {
         match behavior {
             StdinPromptBehavior::OptionalAppend => None,
@@ -0,0 +1,243 @@
+//! Local validation of the final answer against `--output-schema`.
+//!
+//! When an output schema is supplied, exec checks the final agent message
+//! against it before finishing: the message must parse as JSON (a fenced
+//! ```json block is tolerated) and satisfy the schema. On violation the
+//! turn is re-asked with the validation errors, up to a small retry budget,
+//! so pipelines consuming stdout always see conforming JSON or a non-zero
+//! exit.
+//!
+//! The validator covers the structural subset of JSON Schema that matters
+//! for output contracts: `type`, `properties`, `required`,
+//! `additionalProperties`, `items`, `enum`, `const`, numeric and length
+//! bounds, and `anyOf`. Unknown keywords are ignored rather than rejected.
+
+use serde_json::Value;
+
+/// How many corrective turns exec sends before giving up on the contract.
+pub(crate) const MAX_SCHEMA_RETRIES: u32 = 2;
+
+/// Parses the final agent message as JSON, tolerating a fenced code block
+/// around the payload.
+pub(crate) fn parse_final_message(message: &str) -> Result<Value, String> {
+    let trimmed = message.trim();
+    if let Ok(value) = serde_json::from_str(trimmed) {
+        return Ok(value);
+    }
+    if let Some(fenced) = extract_fenced_block(trimmed)
+        && let Ok(value) = serde_json::from_str(fenced)
+    {
+        return Ok(value);
+    }
+    Err("the reply is not valid JSON".to_string())
+}
+
+/// Corrective prompt sent when the previous reply violated the schema.
+pub(crate) fn retry_prompt(problems: &[String]) -> String {
+    let mut prompt =
+        String::from("Your previous reply did not conform to the required output schema:\n");
+    for problem in problems {
+        prompt.push_str("- ");
+        prompt.push_str(problem);
+        prompt.push('\n');
+    }
+    prompt.push_str(
+        "Reply again with only a JSON value that conforms to the schema, \
+         with no code fences or commentary.",
+    );
+    prompt
+}
+
+/// Checks the final agent message against `schema`. On success returns the
+/// canonical compact JSON to emit on stdout; on failure returns the problems
+/// to feed into [`retry_prompt`].
+pub(crate) fn check_final_message(
+    schema: &Value,
+    message: Option<&str>,
+) -> Result<String, Vec<String>> {
+    let Some(message) = message else {
+        return Err(vec!["the turn produced no final message".to_string()]);
+    };
+    let value = parse_final_message(message).map_err(|err| vec![err])?;
+    validate(schema, &value)?;
+    Ok(value.to_string())
+}
+
+/// Validates `value` against `schema`, returning every violation found.
+pub(crate) fn validate(schema: &Value, value: &Value) -> Result<(), Vec<String>> {
+    let mut problems = Vec::new();
+    validate_at(schema, value, "$", &mut problems);
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems)
+    }
+}
+
+fn validate_at(schema: &Value, value: &Value, path: &str, problems: &mut Vec<String>) {
+    let Value::Object(schema) = schema else {
+        return;
+    };
+
+    if let Some(type_spec) = schema.get("type")
+        && !type_matches(type_spec, value)
+    {
+        problems.push(format!(
+            "{path}: expected type {}, got {}",
+            type_spec_display(type_spec),
+            type_name(value)
+        ));
+        return;
+    }
+
+    if let Some(Value::Array(allowed)) = schema.get("enum")
+        && !allowed.contains(value)
+    {
+        problems.push(format!(
+            "{path}: value is not one of the allowed enum values"
+        ));
+    }
+    if let Some(expected) = schema.get("const")
+        && value != expected
+    {
+        problems.push(format!("{path}: value does not equal the required const"));
+    }
+
+    if let Some(variants) = schema.get("anyOf").and_then(Value::as_array)
+        && !variants
+            .iter()
+            .any(|variant| validate(variant, value).is_ok())
+    {
+        problems.push(format!("{path}: value matches none of the anyOf variants"));
+    }
+
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if !object.contains_key(key) {
+                        problems.push(format!("{path}: missing required property {key:?}"));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            if let Some(properties) = properties {
+                for (key, property_schema) in properties {
+                    if let Some(property) = object.get(key) {
+                        validate_at(
+                            property_schema,
+                            property,
+                            &format!("{path}.{key}"),
+                            problems,
+                        );
+                    }
+                }
+            }
+            if schema.get("additionalProperties") == Some(&Value::Bool(false)) {
+                for key in object.keys() {
+                    if !properties.is_some_and(|properties| properties.contains_key(key)) {
+                        problems.push(format!("{path}: unexpected property {key:?}"));
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_at(item_schema, item, &format!("{path}[{index}]"), problems);
+                }
+            }
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+                && (items.len() as u64) < min
+            {
+                problems.push(format!("{path}: fewer than {min} items"));
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+                && (items.len() as u64) > max
+            {
+                problems.push(format!("{path}: more than {max} items"));
+            }
+        }
+        Value::Number(number) => {
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64)
+                && number.as_f64().is_some_and(|n| n < min)
+            {
+                problems.push(format!("{path}: below minimum {min}"));
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64)
+                && number.as_f64().is_some_and(|n| n > max)
+            {
+                problems.push(format!("{path}: above maximum {max}"));
+            }
+        }
+        Value::String(string) => {
+            let length = string.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+                && length < min
+            {
+                problems.push(format!("{path}: shorter than minLength {min}"));
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+                && length > max
+            {
+                problems.push(format!("{path}: longer than maxLength {max}"));
+            }
+        }
+        Value::Bool(_) | Value::Null => {}
+    }
+}
+
+fn type_matches(type_spec: &Value, value: &Value) -> bool {
+    match type_spec {
+        Value::String(name) => single_type_matches(name, value),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| single_type_matches(name, value)),
+        _ => true,
+    }
+}
+
+fn single_type_matches(name: &str, value: &Value) -> bool {
+    match name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_spec_display(type_spec: &Value) -> String {
+    match type_spec {
+        Value::String(name) => name.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+    }
+}
+
+fn extract_fenced_block(message: &str) -> Option<&str> {
+    let start = message.find("```")?;
+    let after_fence = &message[start + 3..];
+    let body_start = after_fence.find('\n')? + 1;
+    let body = &after_fence[body_start..];
+    let end = body.rfind("```")?;
+    Some(body[..end].trim())
+}
+
+#[cfg(test)]
+#[path = "schema_validation_tests.rs"]
+mod tests;
@@ -0,0 +1,92 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use super::parse_final_message;
+use super::retry_prompt;
+use super::validate;
+
+#[test]
+fn parse_accepts_bare_and_fenced_json() {
+    assert_eq!(
+        parse_final_message("  {\"ok\": true} ").unwrap(),
+        json!({"ok": true})
+    );
+    assert_eq!(
+        parse_final_message("Here you go:\n```json\n{\"ok\": true}\n```").unwrap(),
+        json!({"ok": true})
+    );
+    assert!(parse_final_message("not json at all").is_err());
+}
+
+#[test]
+fn validate_checks_types_and_required_properties() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "name": {"type": "string"},
+            "count": {"type": "integer", "minimum": 0}
+        },
+        "required": ["name", "count"],
+        "additionalProperties": false
+    });
+    assert!(validate(&schema, &json!({"name": "a", "count": 2})).is_ok());
+
+    let problems = validate(&schema, &json!({"count": -1, "extra": 1})).unwrap_err();
+    assert!(
+        problems
+            .iter()
+            .any(|p| p.contains("missing required property \"name\""))
+    );
+    assert!(problems.iter().any(|p| p.contains("below minimum")));
+    assert!(
+        problems
+            .iter()
+            .any(|p| p.contains("unexpected property \"extra\""))
+    );
+}
+
+#[test]
+fn validate_checks_arrays_enums_and_any_of() {
+    let schema = json!({
+        "type": "array",
+        "items": {"enum": ["red", "green"]},
+        "minItems": 1,
+        "maxItems": 2
+    });
+    assert!(validate(&schema, &json!(["red"])).is_ok());
+    assert!(validate(&schema, &json!([])).is_err());
+    assert!(validate(&schema, &json!(["blue"])).is_err());
+
+    let any_of = json!({"anyOf": [{"type": "string"}, {"type": "null"}]});
+    assert!(validate(&any_of, &json!("x")).is_ok());
+    assert!(validate(&any_of, &json!(null)).is_ok());
+    assert!(validate(&any_of, &json!(3)).is_err());
+}
+
+#[test]
+fn validate_reports_paths_into_nested_values() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "items": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["id"]
+                }
+            }
+        }
+    });
+    let problems = validate(&schema, &json!({"items": [{"id": 1}, {}]})).unwrap_err();
+    assert_eq!(
+        problems,
+        vec!["$.items[1]: missing required property \"id\""]
+    );
+}
+
+#[test]
+fn retry_prompt_lists_every_problem() {
+    let prompt = retry_prompt(&["$: expected type object, got string".to_string()]);
+    assert!(prompt.contains("- $: expected type object, got string"));
+    assert!(prompt.contains("only a JSON value"));
+}
@@ -138,6 +138,9 @@ pub enum Feature {
     ChildAgentsMd,
     /// Compress request bodies (zstd) when sending streaming requests to codex-backend.
     EnableRequestCompression,
+    /// Let the provider reuse cached prompt prefixes across turns. Disable
+    /// when privacy policies forbid server-side prompt retention.
+    PromptCaching,
     /// Enable collab tools.
     Collab,
     /// Enable task-path-based multi-agent routing.
@@ -794,6 +797,12 @@ pub const FEATURES: &[FeatureSpec] = &[
         stage: Stage::Stable,
         default_enabled: true,
     },
+    FeatureSpec {
+        id: Feature::PromptCaching,
+        key: "prompt_caching",
+        stage: Stage::Stable,
+        default_enabled: true,
+    },
     FeatureSpec {
         id: Feature::Collab,
         key: "multi_agent",
@@ -34,6 +34,10 @@ use nucleo::pattern::AtomKind;
 #[cfg(test)]
 use nucleo::pattern::Pattern;
 
+/// Per-directory ignore file (gitignore syntax) that hides paths from the
+/// file picker without affecting git itself.
+pub const CODEX_IGNORE_FILENAME: &str = ".codexignore";
+
 mod cli;
 
 pub use cli::Cli;
@@ -431,6 +435,9 @@ fn walker_worker(
         // Keep ignore behavior aligned with git repositories: only apply
         // gitignore rules when a git context exists.
         .require_git(true);
+    // Workspace-scoped Codex ignore rules apply regardless of git context so
+    // secrets and vendored blobs stay out of the picker.
+    walk_builder.add_custom_ignore_filename(CODEX_IGNORE_FILENAME);
     if !inner.respect_gitignore {
         walk_builder
             .git_ignore(false)
@@ -381,6 +381,40 @@ pub struct ConversationTextParams {
     pub text: String,
 }
 
+/// Session-scoped environment variable overrides for spawned commands.
+///
+/// `unset` names are removed from the spawn environment even when the
+/// underlying policy would provide them; `set` entries are added last and win
+/// over everything, including `unset`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct ShellEnvOverrides {
+    #[serde(default)]
+    pub set: HashMap<String, String>,
+    #[serde(default)]
+    pub unset: Vec<String>,
+}
+
+impl ShellEnvOverrides {
+    /// Folds a newer batch of overrides into this one. Within `other`, `unset`
+    /// is applied before `set` so a key appearing in both ends up set.
+    pub fn merge(&mut self, other: ShellEnvOverrides) {
+        for name in other.unset {
+            self.set.remove(&name);
+            if !self.unset.contains(&name) {
+                self.unset.push(name);
+            }
+        }
+        for (name, value) in other.set {
+            self.unset.retain(|unset| unset != &name);
+            self.set.insert(name, value);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.unset.is_empty()
+    }
+}
+
 /// Submission operation
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -548,6 +582,11 @@ pub enum Op {
         personality: Option<Personality>,
     },
 
+    /// Adjust the environment variables passed to commands spawned for the
+    /// rest of the session. Overrides are applied on top of the configured
+    /// `shell_environment_policy` and accumulate across submissions.
+    OverrideShellEnv { overrides: ShellEnvOverrides },
+
     /// Approve a command execution
     ExecApproval {
         /// The id of the submission we are approving
@@ -778,6 +817,7 @@ impl Op {
             Self::UserTurn { .. } => "user_turn",
             Self::InterAgentCommunication { .. } => "inter_agent_communication",
             Self::OverrideTurnContext { .. } => "override_turn_context",
+            Self::OverrideShellEnv { .. } => "override_shell_env",
             Self::ExecApproval { .. } => "exec_approval",
             Self::PatchApproval { .. } => "patch_approval",
             Self::ResolveElicitation { .. } => "resolve_elicitation",
@@ -2090,6 +2130,28 @@ pub struct TurnCompleteEvent {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[ts(type = "number | null", optional)]
     pub duration_ms: Option<i64>,
+    /// Request parameters for reproducing this turn, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub request_params: Option<TurnRequestParams>,
+}
+
+/// Request parameters recorded with a completed turn so surprising outputs
+/// can be reproduced or reported to the provider.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct TurnRequestParams {
+    pub model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort: Option<ReasoningEffortConfig>,
+    /// Sampling temperature, for providers whose API accepts one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+    /// Sampling seed, for providers whose API accepts one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i64>,
+    /// Provider-assigned id of the turn's final response, for support reports.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_request_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
@@ -0,0 +1,217 @@
+//! Bidirectional mirroring of the sessions tree into a synced directory.
+//!
+//! When `sessions_sync_dir` is configured, the local
+//! `$CODEX_HOME/sessions` tree and `<sync_dir>/sessions` are reconciled with
+//! a newest-wins policy: a rollout file is copied whenever the counterpart is
+//! missing or has an older modification time. Copies go through a sibling
+//! `.lock` file created with `create_new` so two machines flushing into the
+//! same synced directory never interleave writes; a held lock simply skips
+//! that file until the next sync pass. When both sides changed since the last
+//! sync the newer file wins and the older one is preserved next to it with a
+//! `.conflict` suffix rather than silently discarded.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::SESSIONS_SUBDIR;
+
+/// Outcome of one sync pass, reported for logging.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SyncReport {
+    /// Files copied into the local sessions tree.
+    pub imported: usize,
+    /// Files copied out to the sync directory.
+    pub exported: usize,
+    /// Files skipped because another process held their lock.
+    pub locked: usize,
+    /// Conflicting older copies preserved with a `.conflict` suffix.
+    pub conflicts: usize,
+}
+
+/// Reconciles `$CODEX_HOME/sessions` with `<sync_dir>/sessions` in both
+/// directions. Missing directories are created on demand; an entirely missing
+/// side is treated as empty.
+pub fn sync_sessions(codex_home: &Path, sync_dir: &Path) -> io::Result<SyncReport> {
+    let local_root = codex_home.join(SESSIONS_SUBDIR);
+    let remote_root = sync_dir.join(SESSIONS_SUBDIR);
+    fs::create_dir_all(&local_root)?;
+    fs::create_dir_all(&remote_root)?;
+
+    let mut report = SyncReport::default();
+    copy_newer(&local_root, &remote_root, &mut report, Direction::Export)?;
+    copy_newer(&remote_root, &local_root, &mut report, Direction::Import)?;
+    Ok(report)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Import,
+    Export,
+}
+
+fn copy_newer(
+    from_root: &Path,
+    to_root: &Path,
+    report: &mut SyncReport,
+    direction: Direction,
+) -> io::Result<()> {
+    for relative in rollout_files(from_root)? {
+        let source = from_root.join(&relative);
+        let dest = to_root.join(&relative);
+
+        let source_mtime = modified(&source)?;
+        let dest_mtime = dest.exists().then(|| modified(&dest)).transpose()?;
+        match dest_mtime {
+            Some(dest_mtime) if dest_mtime >= source_mtime => continue,
+            Some(_) => {
+                // Both sides diverged; keep the losing copy for inspection.
+                let conflict = dest.with_extension("jsonl.conflict");
+                if fs::rename(&dest, &conflict).is_ok() {
+                    report.conflicts += 1;
+                }
+            }
+            None => {}
+        }
+
+        let Some(_guard) = LockGuard::acquire(&dest) else {
+            report.locked += 1;
+            continue;
+        };
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(&source, &dest)?;
+        match direction {
+            Direction::Import => report.imported += 1,
+            Direction::Export => report.exported += 1,
+        }
+    }
+    Ok(())
+}
+
+/// Collects rollout `.jsonl` paths relative to `root` from the
+/// `YYYY/MM/DD/rollout-*.jsonl` layout.
+fn rollout_files(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                stack.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "jsonl")
+                && let Ok(relative) = path.strip_prefix(root)
+            {
+                files.push(relative.to_path_buf());
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn modified(path: &Path) -> io::Result<SystemTime> {
+    fs::metadata(path)?.modified()
+}
+
+/// Exclusive advisory lock implemented as a sibling `.lock` file created with
+/// `create_new`. Dropped (and removed) when the copy finishes.
+struct LockGuard {
+    path: PathBuf,
+}
+
+impl LockGuard {
+    fn acquire(target: &Path) -> Option<Self> {
+        let path = target.with_extension("jsonl.lock");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).ok()?;
+        }
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(_) => Some(Self { path }),
+            Err(_) => None,
+        }
+    }
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn write_rollout(root: &Path, relative: &str, contents: &str) -> PathBuf {
+        let path = root.join(SESSIONS_SUBDIR).join(relative);
+        fs::create_dir_all(path.parent().expect("parent")).expect("create dirs");
+        fs::write(&path, contents).expect("write rollout");
+        path
+    }
+
+    #[test]
+    fn imports_sessions_missing_locally() {
+        let home = tempfile::tempdir().expect("home");
+        let sync = tempfile::tempdir().expect("sync");
+        write_rollout(sync.path(), "2026/08/29/rollout-a.jsonl", "remote");
+
+        let report = sync_sessions(home.path(), sync.path()).expect("sync");
+
+        assert_eq!(report.imported, 1);
+        assert_eq!(report.exported, 0);
+        let imported = home
+            .path()
+            .join(SESSIONS_SUBDIR)
+            .join("2026/08/29/rollout-a.jsonl");
+        assert_eq!(fs::read_to_string(imported).expect("read"), "remote");
+    }
+
+    #[test]
+    fn exports_local_sessions_to_sync_dir() {
+        let home = tempfile::tempdir().expect("home");
+        let sync = tempfile::tempdir().expect("sync");
+        write_rollout(home.path(), "2026/08/29/rollout-b.jsonl", "local");
+
+        let report = sync_sessions(home.path(), sync.path()).expect("sync");
+
+        assert_eq!(report.exported, 1);
+        let exported = sync
+            .path()
+            .join(SESSIONS_SUBDIR)
+            .join("2026/08/29/rollout-b.jsonl");
+        assert_eq!(fs::read_to_string(exported).expect("read"), "local");
+    }
+
+    #[test]
+    fn held_lock_skips_the_file() {
+        let home = tempfile::tempdir().expect("home");
+        let sync = tempfile::tempdir().expect("sync");
+        write_rollout(sync.path(), "2026/08/29/rollout-c.jsonl", "remote");
+        let dest = home
+            .path()
+            .join(SESSIONS_SUBDIR)
+            .join("2026/08/29/rollout-c.jsonl");
+        fs::create_dir_all(dest.parent().expect("parent")).expect("dirs");
+        fs::write(dest.with_extension("jsonl.lock"), "").expect("lock");
+
+        let report = sync_sessions(home.path(), sync.path()).expect("sync");
+
+        assert_eq!(report.imported, 0);
+        assert_eq!(report.locked, 1);
+    }
+}
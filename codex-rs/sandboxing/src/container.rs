@@ -0,0 +1,135 @@
+//! Container-backed sandbox: rewrites a command into a `docker run` /
+//! `podman run` invocation so it executes inside a configured image rather
+//! than under the native seatbelt/landlock sandboxes. The container provides
+//! the isolation boundary, so the engine invocation itself is spawned
+//! unsandboxed.
+
+use codex_protocol::permissions::NetworkSandboxPolicy;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Which container engine binary to invoke. Both accept the same `run`
+/// flags we emit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContainerEngine {
+    #[default]
+    Docker,
+    Podman,
+}
+
+impl ContainerEngine {
+    pub fn program(self) -> &'static str {
+        match self {
+            ContainerEngine::Docker => "docker",
+            ContainerEngine::Podman => "podman",
+        }
+    }
+}
+
+/// A bind mount from a host path to a path inside the container.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContainerMount {
+    pub host: PathBuf,
+    pub container: String,
+    pub readonly: bool,
+}
+
+/// Resolved container sandbox settings (see `[container_sandboxes]` in the
+/// user config). When `mounts` is empty the command's working directory is
+/// bind-mounted read-write at the same path so the workspace stays visible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContainerSandboxConfig {
+    pub engine: ContainerEngine,
+    pub image: String,
+    pub mounts: Vec<ContainerMount>,
+    pub network: NetworkSandboxPolicy,
+}
+
+/// Bundled arguments for [`create_container_command_args`].
+pub struct CreateContainerCommandArgsParams<'a> {
+    pub command: Vec<String>,
+    pub config: &'a ContainerSandboxConfig,
+    pub cwd: &'a Path,
+    pub env: &'a HashMap<String, String>,
+}
+
+/// Returns the arguments passed to the engine binary (everything after
+/// `docker`/`podman`) that run `command` inside the configured image.
+///
+/// Environment variables are forwarded by name only (`--env NAME`), so their
+/// values come from the engine process environment rather than appearing in
+/// the argv visible to other processes.
+pub fn create_container_command_args(params: CreateContainerCommandArgsParams<'_>) -> Vec<String> {
+    let CreateContainerCommandArgsParams {
+        command,
+        config,
+        cwd,
+        env,
+    } = params;
+
+    let mut args = vec!["run".to_string(), "--rm".to_string(), "-i".to_string()];
+    if !config.network.is_enabled() {
+        args.push("--network".to_string());
+        args.push("none".to_string());
+    }
+
+    let cwd_display = cwd.display().to_string();
+    if config.mounts.is_empty() {
+        args.push("--volume".to_string());
+        args.push(format!("{cwd_display}:{cwd_display}"));
+    } else {
+        for mount in &config.mounts {
+            args.push("--volume".to_string());
+            let host = mount.host.display().to_string();
+            let container = &mount.container;
+            if mount.readonly {
+                args.push(format!("{host}:{container}:ro"));
+            } else {
+                args.push(format!("{host}:{container}"));
+            }
+        }
+    }
+
+    args.push("--workdir".to_string());
+    args.push(container_workdir(cwd, &config.mounts));
+
+    let mut names: Vec<&String> = env.keys().collect();
+    names.sort();
+    for name in names {
+        args.push("--env".to_string());
+        args.push(name.clone());
+    }
+
+    args.push(config.image.clone());
+    args.extend(command);
+    args
+}
+
+/// Maps the host working directory through the configured mounts: the longest
+/// matching host prefix wins. Falls back to the host path itself, which is
+/// correct for the default workspace mount.
+fn container_workdir(cwd: &Path, mounts: &[ContainerMount]) -> String {
+    let mut best: Option<(&ContainerMount, &Path)> = None;
+    for mount in mounts {
+        if let Ok(suffix) = cwd.strip_prefix(&mount.host)
+            && best.is_none_or(|(chosen, _)| {
+                mount.host.as_os_str().len() > chosen.host.as_os_str().len()
+            })
+        {
+            best = Some((mount, suffix));
+        }
+    }
+    match best {
+        Some((mount, suffix)) if suffix.as_os_str().is_empty() => mount.container.clone(),
+        Some((mount, suffix)) => {
+            let container = Path::new(&mount.container).join(suffix);
+            container.display().to_string()
+        }
+        None => cwd.display().to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "container_tests.rs"]
+mod tests;
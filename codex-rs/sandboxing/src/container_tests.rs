@@ -0,0 +1,117 @@
+use super::ContainerEngine;
+use super::ContainerMount;
+use super::ContainerSandboxConfig;
+use super::CreateContainerCommandArgsParams;
+use super::create_container_command_args;
+use codex_protocol::permissions::NetworkSandboxPolicy;
+use pretty_assertions::assert_eq;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+fn config(mounts: Vec<ContainerMount>, network: NetworkSandboxPolicy) -> ContainerSandboxConfig {
+    ContainerSandboxConfig {
+        engine: ContainerEngine::Docker,
+        image: "ubuntu:24.04".to_string(),
+        mounts,
+        network,
+    }
+}
+
+#[test]
+fn default_mount_maps_workspace_and_disables_network() {
+    let config = config(Vec::new(), NetworkSandboxPolicy::Restricted);
+    let args = create_container_command_args(CreateContainerCommandArgsParams {
+        command: vec!["echo".to_string(), "hi".to_string()],
+        config: &config,
+        cwd: Path::new("/work/repo"),
+        env: &HashMap::new(),
+    });
+    assert_eq!(
+        args,
+        vec![
+            "run",
+            "--rm",
+            "-i",
+            "--network",
+            "none",
+            "--volume",
+            "/work/repo:/work/repo",
+            "--workdir",
+            "/work/repo",
+            "ubuntu:24.04",
+            "echo",
+            "hi",
+        ]
+    );
+}
+
+#[test]
+fn configured_mounts_remap_the_working_directory() {
+    let config = config(
+        vec![
+            ContainerMount {
+                host: PathBuf::from("/work/repo"),
+                container: "/workspace".to_string(),
+                readonly: false,
+            },
+            ContainerMount {
+                host: PathBuf::from("/work/cache"),
+                container: "/cache".to_string(),
+                readonly: true,
+            },
+        ],
+        NetworkSandboxPolicy::Enabled,
+    );
+    let args = create_container_command_args(CreateContainerCommandArgsParams {
+        command: vec!["ls".to_string()],
+        config: &config,
+        cwd: Path::new("/work/repo/crates"),
+        env: &HashMap::new(),
+    });
+    assert_eq!(
+        args,
+        vec![
+            "run",
+            "--rm",
+            "-i",
+            "--volume",
+            "/work/repo:/workspace",
+            "--volume",
+            "/work/cache:/cache:ro",
+            "--workdir",
+            "/workspace/crates",
+            "ubuntu:24.04",
+            "ls",
+        ]
+    );
+}
+
+#[test]
+fn environment_is_forwarded_by_name_in_sorted_order() {
+    let config = config(Vec::new(), NetworkSandboxPolicy::Enabled);
+    let env = HashMap::from([
+        ("PATH".to_string(), "/usr/bin".to_string()),
+        ("API_KEY".to_string(), "secret".to_string()),
+    ]);
+    let args = create_container_command_args(CreateContainerCommandArgsParams {
+        command: vec!["env".to_string()],
+        config: &config,
+        cwd: Path::new("/work/repo"),
+        env: &env,
+    });
+    let env_args: Vec<&String> = args
+        .iter()
+        .zip(args.iter().skip(1))
+        .filter(|(flag, _)| flag.as_str() == "--env")
+        .map(|(_, name)| name)
+        .collect();
+    assert_eq!(env_args, vec!["API_KEY", "PATH"]);
+    assert!(!args.iter().any(|arg| arg.contains("secret")));
+}
+
+#[test]
+fn engine_program_matches_variant() {
+    assert_eq!(ContainerEngine::Docker.program(), "docker");
+    assert_eq!(ContainerEngine::Podman.program(), "podman");
+}
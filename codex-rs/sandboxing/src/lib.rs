@@ -1,5 +1,6 @@
 #[cfg(target_os = "linux")]
 mod bwrap;
+pub mod container;
 pub mod landlock;
 mod manager;
 pub mod policy_transforms;
@@ -34,6 +35,9 @@ impl From<SandboxTransformError> for CodexErr {
             SandboxTransformError::MissingLinuxSandboxExecutable => {
                 CodexErr::LandlockSandboxExecutableNotProvided
             }
+            SandboxTransformError::ContainerConfigurationMissing => CodexErr::UnsupportedOperation(
+                "container sandbox selected but no container sandbox is configured".to_string(),
+            ),
             #[cfg(target_os = "linux")]
             SandboxTransformError::Wsl1UnsupportedForBubblewrap => {
                 CodexErr::UnsupportedOperation(crate::bwrap::WSL1_BWRAP_WARNING.to_string())
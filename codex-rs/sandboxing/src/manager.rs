@@ -2,6 +2,9 @@
 use crate::bwrap::WSL1_BWRAP_WARNING;
 #[cfg(target_os = "linux")]
 use crate::bwrap::is_wsl1;
+use crate::container::ContainerSandboxConfig;
+use crate::container::CreateContainerCommandArgsParams;
+use crate::container::create_container_command_args;
 use crate::landlock::CODEX_LINUX_SANDBOX_ARG0;
 use crate::landlock::allow_network_for_proxy;
 use crate::landlock::create_linux_sandbox_command_args_for_policies;
@@ -26,6 +29,7 @@ pub enum SandboxType {
     MacosSeatbelt,
     LinuxSeccomp,
     WindowsRestrictedToken,
+    Container,
 }
 
 impl SandboxType {
@@ -35,6 +39,7 @@ impl SandboxType {
             SandboxType::MacosSeatbelt => "seatbelt",
             SandboxType::LinuxSeccomp => "seccomp",
             SandboxType::WindowsRestrictedToken => "windows_sandbox",
+            SandboxType::Container => "container",
         }
     }
 }
@@ -101,6 +106,7 @@ pub struct SandboxTransformRequest<'a> {
     pub network: Option<&'a NetworkProxy>,
     pub sandbox_policy_cwd: &'a Path,
     pub codex_linux_sandbox_exe: Option<&'a Path>,
+    pub container: Option<&'a ContainerSandboxConfig>,
     pub use_legacy_landlock: bool,
     pub windows_sandbox_level: WindowsSandboxLevel,
     pub windows_sandbox_private_desktop: bool,
@@ -109,6 +115,7 @@ pub struct SandboxTransformRequest<'a> {
 #[derive(Debug)]
 pub enum SandboxTransformError {
     MissingLinuxSandboxExecutable,
+    ContainerConfigurationMissing,
     #[cfg(target_os = "linux")]
     Wsl1UnsupportedForBubblewrap,
     #[cfg(not(target_os = "macos"))]
@@ -121,6 +128,12 @@ impl std::fmt::Display for SandboxTransformError {
             Self::MissingLinuxSandboxExecutable => {
                 write!(f, "missing codex-linux-sandbox executable path")
             }
+            Self::ContainerConfigurationMissing => {
+                write!(
+                    f,
+                    "container sandbox selected but no container sandbox is configured"
+                )
+            }
             #[cfg(target_os = "linux")]
             Self::Wsl1UnsupportedForBubblewrap => write!(f, "{WSL1_BWRAP_WARNING}"),
             #[cfg(not(target_os = "macos"))]
@@ -182,6 +195,7 @@ impl SandboxManager {
             network,
             sandbox_policy_cwd,
             codex_linux_sandbox_exe,
+            container,
             use_legacy_landlock,
             windows_sandbox_level,
             windows_sandbox_private_desktop,
@@ -254,6 +268,20 @@ impl SandboxManager {
             SandboxType::WindowsRestrictedToken => (os_argv_to_strings(argv), None),
             #[cfg(not(target_os = "windows"))]
             SandboxType::WindowsRestrictedToken => (os_argv_to_strings(argv), None),
+            SandboxType::Container => {
+                let config =
+                    container.ok_or(SandboxTransformError::ContainerConfigurationMissing)?;
+                let mut args = create_container_command_args(CreateContainerCommandArgsParams {
+                    command: os_argv_to_strings(argv),
+                    config,
+                    cwd: command.cwd.as_path(),
+                    env: &command.env,
+                });
+                let mut full_command = Vec::with_capacity(1 + args.len());
+                full_command.push(config.engine.program().to_string());
+                full_command.append(&mut args);
+                (full_command, None)
+            }
         };
 
         Ok(SandboxExecRequest {
@@ -94,6 +94,7 @@ fn transform_preserves_unrestricted_file_system_policy_for_restricted_network()
             network: None,
             sandbox_policy_cwd: cwd.as_path(),
             codex_linux_sandbox_exe: None,
+            container: None,
             use_legacy_landlock: false,
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
             windows_sandbox_private_desktop: false,
@@ -146,6 +147,7 @@ fn transform_additional_permissions_enable_network_for_external_sandbox() {
             network: None,
             sandbox_policy_cwd: cwd.as_path(),
             codex_linux_sandbox_exe: None,
+            container: None,
             use_legacy_landlock: false,
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
             windows_sandbox_private_desktop: false,
@@ -214,6 +216,7 @@ fn transform_additional_permissions_preserves_denied_entries() {
             network: None,
             sandbox_policy_cwd: cwd.as_path(),
             codex_linux_sandbox_exe: None,
+            container: None,
             use_legacy_landlock: false,
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
             windows_sandbox_private_desktop: false,
@@ -268,6 +271,7 @@ fn transform_linux_seccomp_request(
             network: None,
             sandbox_policy_cwd: cwd.as_path(),
             codex_linux_sandbox_exe: Some(codex_linux_sandbox_exe),
+            container: None,
             use_legacy_landlock: false,
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
             windows_sandbox_private_desktop: false,
@@ -355,3 +359,80 @@ fn transform_linux_seccomp_uses_helper_alias_when_launcher_is_not_helper_path()
 
     assert_eq!(exec_request.arg0, Some("codex-linux-sandbox".to_string()));
 }
+
+#[test]
+fn transform_container_wraps_command_in_engine_invocation() {
+    let manager = SandboxManager::new();
+    let cwd = AbsolutePathBuf::current_dir().expect("current dir");
+    let container = crate::container::ContainerSandboxConfig {
+        engine: crate::container::ContainerEngine::Podman,
+        image: "ubuntu:24.04".to_string(),
+        mounts: Vec::new(),
+        network: NetworkSandboxPolicy::Enabled,
+    };
+    let exec_request = manager
+        .transform(SandboxTransformRequest {
+            command: SandboxCommand {
+                program: "true".into(),
+                args: Vec::new(),
+                cwd: cwd.clone(),
+                env: HashMap::new(),
+                additional_permissions: None,
+            },
+            policy: &SandboxPolicy::DangerFullAccess,
+            file_system_policy: &FileSystemSandboxPolicy::unrestricted(),
+            network_policy: NetworkSandboxPolicy::Enabled,
+            sandbox: SandboxType::Container,
+            enforce_managed_network: false,
+            network: None,
+            sandbox_policy_cwd: cwd.as_path(),
+            codex_linux_sandbox_exe: None,
+            container: Some(&container),
+            use_legacy_landlock: false,
+            windows_sandbox_level: WindowsSandboxLevel::Disabled,
+            windows_sandbox_private_desktop: false,
+        })
+        .expect("transform");
+
+    assert_eq!(
+        exec_request.command.first().map(String::as_str),
+        Some("podman")
+    );
+    assert_eq!(exec_request.command.get(1).map(String::as_str), Some("run"));
+    assert_eq!(
+        exec_request.command.last().map(String::as_str),
+        Some("true")
+    );
+}
+
+#[test]
+fn transform_container_without_config_is_an_error() {
+    let manager = SandboxManager::new();
+    let cwd = AbsolutePathBuf::current_dir().expect("current dir");
+    let result = manager.transform(SandboxTransformRequest {
+        command: SandboxCommand {
+            program: "true".into(),
+            args: Vec::new(),
+            cwd: cwd.clone(),
+            env: HashMap::new(),
+            additional_permissions: None,
+        },
+        policy: &SandboxPolicy::DangerFullAccess,
+        file_system_policy: &FileSystemSandboxPolicy::unrestricted(),
+        network_policy: NetworkSandboxPolicy::Enabled,
+        sandbox: SandboxType::Container,
+        enforce_managed_network: false,
+        network: None,
+        sandbox_policy_cwd: cwd.as_path(),
+        codex_linux_sandbox_exe: None,
+        container: None,
+        use_legacy_landlock: false,
+        windows_sandbox_level: WindowsSandboxLevel::Disabled,
+        windows_sandbox_private_desktop: false,
+    });
+
+    assert!(matches!(
+        result,
+        Err(super::SandboxTransformError::ContainerConfigurationMissing)
+    ));
+}
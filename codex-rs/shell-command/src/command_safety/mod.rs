@@ -1,5 +1,6 @@
 mod powershell_parser;
 
+pub mod high_risk;
 pub mod is_dangerous_command;
 pub mod is_safe_command;
 pub(crate) mod windows_safe_commands;
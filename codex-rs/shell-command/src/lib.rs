@@ -7,5 +7,6 @@ pub(crate) mod command_safety;
 pub mod parse_command;
 pub mod powershell;
 
+pub use command_safety::high_risk;
 pub use command_safety::is_dangerous_command;
 pub use command_safety::is_safe_command;
@@ -140,6 +140,7 @@ pub use tool_suggest::all_suggested_connectors_picked_up;
 pub use tool_suggest::build_tool_suggestion_elicitation_request;
 pub use tool_suggest::verified_connector_suggestion_completed;
 pub use utility_tool::create_list_dir_tool;
+pub use utility_tool::create_search_replace_tool;
 pub use utility_tool::create_test_sync_tool;
 pub use view_image::ViewImageToolOptions;
 pub use view_image::create_view_image_tool;
@@ -26,6 +26,7 @@ pub enum ShellCommandBackendConfig {
 pub enum ToolUserShellType {
     Zsh,
     Bash,
+    Fish,
     PowerShell,
     Sh,
     Cmd,
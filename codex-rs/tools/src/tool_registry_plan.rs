@@ -39,6 +39,7 @@ use crate::create_report_agent_job_result_tool;
 use crate::create_request_permissions_tool;
 use crate::create_request_user_input_tool;
 use crate::create_resume_agent_tool;
+use crate::create_search_replace_tool;
 use crate::create_send_input_tool_v1;
 use crate::create_send_message_tool;
 use crate::create_shell_command_tool;
@@ -343,6 +344,20 @@ pub fn build_tool_registry_plan(
         plan.register_handler("list_dir", ToolHandlerKind::ListDir);
     }
 
+    if config.has_environment
+        && config
+            .experimental_supported_tools
+            .iter()
+            .any(|tool| tool == "search_replace")
+    {
+        plan.push_spec(
+            create_search_replace_tool(),
+            /*supports_parallel_tool_calls*/ false,
+            config.code_mode_enabled,
+        );
+        plan.register_handler("search_replace", ToolHandlerKind::SearchReplace);
+    }
+
     if config
         .experimental_supported_tools
         .iter()
@@ -453,6 +453,9 @@ fn disabled_environment_omits_environment_backed_tools() {
     tools_config
         .experimental_supported_tools
         .push("list_dir".to_string());
+    tools_config
+        .experimental_supported_tools
+        .push("search_replace".to_string());
     let (tools, _) = build_specs(
         &tools_config,
         /*mcp_tools*/ None,
@@ -466,6 +469,7 @@ fn disabled_environment_omits_environment_backed_tools() {
     assert_lacks_tool_name(&tools, "js_repl_reset");
     assert_lacks_tool_name(&tools, "apply_patch");
     assert_lacks_tool_name(&tools, "list_dir");
+    assert_lacks_tool_name(&tools, "search_replace");
     assert_lacks_tool_name(&tools, VIEW_IMAGE_TOOL_NAME);
 }
 
@@ -39,6 +39,46 @@ pub fn create_list_dir_tool() -> ToolSpec {
     })
 }
 
+pub fn create_search_replace_tool() -> ToolSpec {
+    let properties = BTreeMap::from([
+        (
+            "pattern".to_string(),
+            JsonSchema::string(Some(
+                "Regular expression to search for in workspace files.".to_string(),
+            )),
+        ),
+        (
+            "replacement".to_string(),
+            JsonSchema::string(Some(
+                "Replacement text. Reference capture groups as $1, $2, ...; escape a literal dollar sign as $$."
+                    .to_string(),
+            )),
+        ),
+        (
+            "include".to_string(),
+            JsonSchema::string(Some(
+                "Optional glob limiting the search to matching paths, e.g. `src/**/*.rs`."
+                    .to_string(),
+            )),
+        ),
+    ]);
+
+    ToolSpec::Function(ResponsesApiTool {
+        name: "search_replace".to_string(),
+        description:
+            "Applies a regex search-and-replace across workspace files as a single patch with one approval."
+                .to_string(),
+        strict: false,
+        defer_loading: None,
+        parameters: JsonSchema::object(
+            properties,
+            Some(vec!["pattern".to_string(), "replacement".to_string()]),
+            Some(false.into()),
+        ),
+        output_schema: None,
+    })
+}
+
 pub fn create_test_sync_tool() -> ToolSpec {
     let barrier_properties = BTreeMap::from([
         (
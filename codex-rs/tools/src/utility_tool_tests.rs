@@ -47,6 +47,48 @@ fn list_dir_tool_matches_expected_spec() {
     );
 }
 
+#[test]
+fn search_replace_tool_matches_expected_spec() {
+    assert_eq!(
+        create_search_replace_tool(),
+        ToolSpec::Function(ResponsesApiTool {
+            name: "search_replace".to_string(),
+            description:
+                "Applies a regex search-and-replace across workspace files as a single patch with one approval."
+                    .to_string(),
+            strict: false,
+            defer_loading: None,
+            parameters: JsonSchema::object(
+                BTreeMap::from([
+                    (
+                        "include".to_string(),
+                        JsonSchema::string(Some(
+                            "Optional glob limiting the search to matching paths, e.g. `src/**/*.rs`."
+                                .to_string(),
+                        )),
+                    ),
+                    (
+                        "pattern".to_string(),
+                        JsonSchema::string(Some(
+                            "Regular expression to search for in workspace files.".to_string(),
+                        )),
+                    ),
+                    (
+                        "replacement".to_string(),
+                        JsonSchema::string(Some(
+                            "Replacement text. Reference capture groups as $1, $2, ...; escape a literal dollar sign as $$."
+                                .to_string(),
+                        )),
+                    ),
+                ]),
+                Some(vec!["pattern".to_string(), "replacement".to_string()]),
+                Some(false.into())
+            ),
+            output_schema: None,
+        })
+    );
+}
+
 #[test]
 fn test_sync_tool_matches_expected_spec() {
     assert_eq!(
@@ -34,8 +34,16 @@ use crate::external_editor;
 use crate::file_search::FileSearchManager;
 use crate::history_cell;
 use crate::history_cell::HistoryCell;
+use crate::history_cell::PlainHistoryCell;
 #[cfg(not(debug_assertions))]
 use crate::history_cell::UpdateAvailableHistoryCell;
+use crate::history_spill;
+use crate::keymap::ChordResolution;
+use crate::keymap::ChordResolver;
+use crate::keymap::KeymapAction;
+use crate::keymap::KeymapContext;
+use crate::keymap::TuiKeymap;
+use crate::keymap::build_keymap;
 use crate::legacy_core::append_message_history_entry;
 use crate::legacy_core::config::Config;
 use crate::legacy_core::config::ConfigBuilder;
@@ -46,10 +54,13 @@ use crate::legacy_core::lookup_message_history_entry;
 use crate::legacy_core::plugins::PluginsManager;
 #[cfg(target_os = "windows")]
 use crate::legacy_core::windows_sandbox::WindowsSandboxLevelExt;
+use crate::macros::MacroRecorder;
 use crate::model_catalog::ModelCatalog;
 use crate::model_migration::ModelMigrationOutcome;
 use crate::model_migration::migration_copy_for_models;
 use crate::model_migration::run_model_migration_prompt;
+use crate::mousemap::MouseAction;
+use crate::mousemap::MouseMap;
 use crate::multi_agents::agent_picker_status_dot_spans;
 use crate::multi_agents::format_agent_picker_item_name;
 use crate::multi_agents::next_agent_shortcut_matches;
@@ -111,6 +122,7 @@ use codex_app_server_protocol::TurnError as AppServerTurnError;
 use codex_app_server_protocol::TurnStatus;
 use codex_config::ConfigLayerStackOrdering;
 use codex_config::types::ApprovalsReviewer;
+use codex_config::types::BellStyle;
 use codex_config::types::ModelAvailabilityNuxConfig;
 use codex_exec_server::EnvironmentManager;
 use codex_features::Feature;
@@ -522,6 +534,22 @@ fn emit_project_config_warnings(app_event_tx: &AppEventSender, config: &Config)
     )));
 }
 
+fn emit_keymap_conflict_warnings(app_event_tx: &AppEventSender, warnings: &[String]) {
+    if warnings.is_empty() {
+        return;
+    }
+
+    let mut message = "Conflicting [tui.keybindings] entries were auto-resolved:\n".to_string();
+    for warning in warnings {
+        message.push_str(&format!("    {warning}\n"));
+    }
+    message.push_str("Set `strict = true` under [tui.keybindings] to make this an error.");
+
+    app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+        history_cell::new_warning_event(message),
+    )));
+}
+
 fn emit_system_bwrap_warning(app_event_tx: &AppEventSender, config: &Config) {
     let Some(message) =
         crate::legacy_core::config::system_bwrap_warning(config.permissions.sandbox_policy.get())
@@ -1039,11 +1067,35 @@ pub(crate) struct App {
 
     pub(crate) transcript_cells: Vec<Arc<dyn HistoryCell>>,
 
+    /// Configured memory budget for `transcript_cells`; `None` keeps all
+    /// history resident.
+    history_budget: Option<history_spill::HistoryBudget>,
+    /// Lazily created spool file backing spilled history cells.
+    history_spool: Option<Arc<history_spill::HistorySpool>>,
+
     // Pager overlay state (Transcript or Static like Diff)
     pub(crate) overlay: Option<Overlay>,
     pub(crate) deferred_history_lines: Vec<Line<'static>>,
     has_emitted_history_lines: bool,
 
+    /// Runtime override for tool-call collapsing toggled with `Ctrl+X`;
+    /// `None` defers to the `[tui] collapsed_tool_calls` config defaults.
+    tool_calls_collapsed_override: Option<bool>,
+
+    /// Sequence-capable shortcut table; single-chord defaults mirror the
+    /// historical hardcoded shortcuts.
+    keymap: TuiKeymap,
+    /// Pending-first-chord state for two-step sequences.
+    chord_resolver: ChordResolver,
+    /// Whether the footer currently shows a pending-chord hint that must be
+    /// cleared on the next resolved key.
+    chord_hint_active: bool,
+    /// In-flight keyboard macro recording, if any; see [`crate::macros`].
+    macro_recorder: MacroRecorder,
+    /// Gesture table for captured mouse input; only consulted while
+    /// `[tui.mouse]` has capture enabled.
+    mouse_map: MouseMap,
+
     pub(crate) enhanced_keys_supported: bool,
 
     /// Controls the animation thread that sends CommitTick events.
@@ -1305,6 +1357,49 @@ impl App {
         true
     }
 
+    /// Applies `/keybindings` rebinds: updates the in-memory config, rebuilds
+    /// the active keymap so the change takes effect immediately, and persists
+    /// the specs to the `[tui.keybindings]` per-context sub-tables.
+    async fn update_keybindings(&mut self, updates: Vec<(KeymapContext, &'static str, String)>) {
+        if updates.is_empty() {
+            return;
+        }
+
+        let keybindings = self
+            .config
+            .tui_keybindings
+            .get_or_insert_with(Default::default);
+        let mut edits = Vec::with_capacity(updates.len());
+        for (context, action, spec) in updates {
+            let table = match context {
+                KeymapContext::Composer => &mut keybindings.composer,
+                KeymapContext::Pager => &mut keybindings.pager,
+            };
+            table.insert(action.to_string(), spec.clone());
+            edits.push(ConfigEdit::SetPath {
+                segments: vec![
+                    "tui".to_string(),
+                    "keybindings".to_string(),
+                    context.config_key().to_string(),
+                    action.to_string(),
+                ],
+                value: spec.into(),
+            });
+        }
+        self.keymap = build_keymap(self.config.tui_keybindings.as_ref());
+        self.chat_widget.refresh_shortcut_key_hints(&self.keymap);
+
+        if let Err(err) = ConfigEditsBuilder::new(&self.config.codex_home)
+            .with_edits(edits)
+            .apply()
+            .await
+        {
+            tracing::error!(error = %err, "failed to persist keybinding update");
+            self.chat_widget
+                .add_error_message(format!("Failed to save keybindings: {err}"));
+        }
+    }
+
     async fn update_feature_flags(&mut self, updates: Vec<(Feature, bool)>) {
         if updates.is_empty() {
             return;
@@ -1959,6 +2054,7 @@ impl App {
                         .as_deref()
                         .map(split_command_string)
                         .unwrap_or_default(),
+                    cwd: params.cwd.clone(),
                     reason: params.reason.clone(),
                     available_decisions: params
                         .available_decisions
@@ -2527,6 +2623,43 @@ impl App {
         }
     }
 
+    /// Spills the oldest committed cells to the session spool when a
+    /// `[tui.history_budget]` limit is configured and exceeded.
+    /// Whether tool-call cells of `kind` should render collapsed in the main
+    /// viewport, honoring the `Ctrl+X` runtime override over the
+    /// `[tui] collapsed_tool_calls` config defaults.
+    fn tool_call_collapsed(&self, kind: &str) -> bool {
+        self.tool_calls_collapsed_override.unwrap_or_else(|| {
+            self.config
+                .tui_collapsed_tool_calls
+                .get(kind)
+                .copied()
+                .unwrap_or(false)
+        })
+    }
+
+    fn enforce_history_budget(&mut self) {
+        let Some(budget) = self.history_budget else {
+            return;
+        };
+        if self.history_spool.is_none() {
+            match history_spill::HistorySpool::new() {
+                Ok(spool) => self.history_spool = Some(Arc::new(spool)),
+                Err(err) => {
+                    // Without a spool file the budget cannot be enforced;
+                    // disable it for the rest of the session.
+                    tracing::warn!("failed to create history spool: {err}");
+                    self.history_budget = None;
+                    return;
+                }
+            }
+        }
+        let Some(spool) = self.history_spool.as_ref() else {
+            return;
+        };
+        history_spill::enforce_budget(&mut self.transcript_cells, spool, budget);
+    }
+
     /// Intercept composer-history operations and handle them locally against
     /// `$CODEX_HOME/history.jsonl`, bypassing the app-server RPC layer.
     async fn try_handle_local_history_op(
@@ -4055,6 +4188,11 @@ impl App {
         use tokio_stream::StreamExt;
         let (app_event_tx, mut app_event_rx) = unbounded_channel();
         let app_event_tx = AppEventSender::new(app_event_tx);
+        if let Some(lines) = crate::startup_profile::report_lines() {
+            app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+                PlainHistoryCell::new(lines),
+            )));
+        }
         emit_project_config_warnings(&app_event_tx, &config);
         emit_system_bwrap_warning(&app_event_tx, &config);
         tui.set_notification_settings(
@@ -4274,9 +4412,16 @@ impl App {
             .maybe_prompt_windows_sandbox_enable(should_prompt_windows_sandbox_nux_at_startup);
 
         let file_search = FileSearchManager::new(config.cwd.to_path_buf(), app_event_tx.clone());
+        let history_budget =
+            history_spill::HistoryBudget::from_toml(config.tui_history_budget.as_ref());
         #[cfg(not(debug_assertions))]
         let upgrade_version = crate::updates::get_upgrade_version(&config);
 
+        let (keymap, keymap_warnings) =
+            TuiKeymap::from_keybindings(config.tui_keybindings.as_ref())
+                .wrap_err("invalid [tui.keybindings] in config.toml")?;
+        emit_keymap_conflict_warnings(&app_event_tx, &keymap_warnings);
+        let mouse_map = MouseMap::from_mouse_toml(config.tui_mouse.as_ref());
         let mut app = Self {
             model_catalog,
             session_telemetry: session_telemetry.clone(),
@@ -4291,9 +4436,17 @@ impl App {
             file_search,
             enhanced_keys_supported,
             transcript_cells: Vec::new(),
+            history_budget,
+            history_spool: None,
             overlay: None,
             deferred_history_lines: Vec::new(),
             has_emitted_history_lines: false,
+            tool_calls_collapsed_override: None,
+            keymap,
+            chord_resolver: ChordResolver::default(),
+            chord_hint_active: false,
+            macro_recorder: MacroRecorder::default(),
+            mouse_map,
             commit_anim_running: Arc::new(AtomicBool::new(false)),
             status_line_invalid_items_warned: status_line_invalid_items_warned.clone(),
             terminal_title_invalid_items_warned: terminal_title_invalid_items_warned.clone(),
@@ -4354,6 +4507,12 @@ impl App {
         tokio::pin!(tui_events);
 
         tui.frame_requester().schedule_frame();
+        // Hot-swap the syntax theme when `config.toml` changes so `[tui]
+        // theme` edits apply without relaunching.
+        crate::theme_watcher::spawn_theme_watcher(
+            app.config.codex_home.to_path_buf(),
+            app.app_event_tx.clone(),
+        );
         app.refresh_startup_skills(&app_server);
         // Kick off a non-blocking rate-limit prefetch so the first `/status`
         // already has data, without delaying the initial frame render.
@@ -4493,8 +4652,62 @@ impl App {
             }
         }
 
+        // Captured mouse gestures are translated into the events the rest of
+        // the app already understands before the overlay/composer split, so
+        // the wheel drives whatever currently owns Up/Down.
+        let event = match event {
+            TuiEvent::Mouse(mouse_event) => {
+                match self
+                    .mouse_map
+                    .resolve(&mouse_event)
+                    .and_then(|action| self.mouse_action_event(tui, action))
+                {
+                    Some(event) => event,
+                    None => return Ok(AppRunControl::Continue),
+                }
+            }
+            other => other,
+        };
+
+        // Text-selection mode ends on the next key press, which is consumed
+        // by the restore so it does not also type into the composer or
+        // whatever overlay comes back with the alt screen.
+        if tui.text_selection_active()
+            && let TuiEvent::Key(key_event) = &event
+            && key_event.kind == KeyEventKind::Press
+        {
+            tui.end_text_selection();
+            self.chat_widget.set_footer_hint_override(/*items*/ None);
+            tui.frame_requester().schedule_frame();
+            return Ok(AppRunControl::Continue);
+        }
+
         if self.overlay.is_some() {
-            let _ = self.handle_backtrack_overlay_event(tui, event).await?;
+            // `[tui.keybindings.pager]` overrides run ahead of the overlay's
+            // own key handling; without any, the overlay keeps every key.
+            if self.keymap.has_context_bindings(KeymapContext::Pager)
+                && let TuiEvent::Key(key_event) = &event
+                && key_event.kind == KeyEventKind::Press
+            {
+                match self.chord_resolver.press(
+                    &self.keymap,
+                    KeymapContext::Pager,
+                    *key_event,
+                    Instant::now(),
+                ) {
+                    ChordResolution::Action(action) => {
+                        self.dispatch_keymap_action(tui, action);
+                    }
+                    // An armed first chord is swallowed here exactly as it is
+                    // in the composer.
+                    ChordResolution::Pending(_) => {}
+                    ChordResolution::Passthrough(_) => {
+                        let _ = self.handle_backtrack_overlay_event(tui, event).await?;
+                    }
+                }
+            } else {
+                let _ = self.handle_backtrack_overlay_event(tui, event).await?;
+            }
         } else {
             match event {
                 TuiEvent::Key(key_event) => {
@@ -4542,6 +4755,28 @@ impl App {
         Ok(AppRunControl::Continue)
     }
 
+    /// Translates a resolved mouse action into an event for the normal input
+    /// path. Scroll actions become arrow-key presses — the same translation
+    /// terminals apply for "alternate scroll" in the alt screen — so they
+    /// scroll the pager, move list selections, and step composer history
+    /// without any widget knowing about the mouse. `FocusComposer` is handled
+    /// here directly: it closes an open pager overlay and otherwise leaves
+    /// the composer (which always has focus) alone.
+    fn mouse_action_event(&mut self, tui: &mut tui::Tui, action: MouseAction) -> Option<TuiEvent> {
+        let arrow = |code| TuiEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        match action {
+            MouseAction::ScrollUp => Some(arrow(KeyCode::Up)),
+            MouseAction::ScrollDown => Some(arrow(KeyCode::Down)),
+            MouseAction::FocusComposer => {
+                if self.overlay.is_some() {
+                    self.close_transcript_overlay(tui);
+                    tui.frame_requester().schedule_frame();
+                }
+                None
+            }
+        }
+    }
+
     async fn resume_target_session(
         &mut self,
         tui: &mut tui::Tui,
@@ -4824,7 +5059,20 @@ impl App {
                     tui.frame_requester().schedule_frame();
                 }
                 self.transcript_cells.push(cell.clone());
-                let mut display = cell.display_lines(tui.terminal.last_known_screen_size.width);
+                self.enforce_history_budget();
+                let width = tui.terminal.last_known_screen_size.width;
+                if cell.tool_call_kind().is_some() {
+                    self.chat_widget
+                        .maybe_show_tip(crate::tips::Tip::CollapsedToolCalls);
+                }
+                let collapsed = cell
+                    .tool_call_kind()
+                    .is_some_and(|kind| self.tool_call_collapsed(kind));
+                let mut display = if collapsed {
+                    cell.collapsed_display_lines(width)
+                } else {
+                    cell.display_lines(width)
+                };
                 if !display.is_empty() {
                     // Only insert a separating blank line for new cells that are not
                     // part of an ongoing stream. Streaming continuations should not
@@ -4904,14 +5152,92 @@ impl App {
                 self.chat_widget.on_diff_complete();
                 // Enter alternate screen using TUI helper and build pager lines
                 let _ = tui.enter_alt_screen();
+                let mut hunks: Vec<crate::diff_render::UnifiedDiffHunk> = Vec::new();
                 let pager_lines: Vec<ratatui::text::Line<'static>> = if text.trim().is_empty() {
                     vec!["No changes detected.".italic().into()]
                 } else {
-                    text.lines().map(ansi_escape_line).collect()
+                    let styled: Vec<ratatui::text::Line<'static>> =
+                        text.lines().map(ansi_escape_line).collect();
+                    // Parse from the de-escaped text: `git diff --color` wraps
+                    // the markers the parsers rely on in ANSI sequences.
+                    let plain: Vec<String> = styled
+                        .iter()
+                        .map(|line| {
+                            line.spans
+                                .iter()
+                                .map(|span| span.content.as_ref())
+                                .collect()
+                        })
+                        .collect();
+                    // Multi-file diffs get a `git diff --stat`-style header so
+                    // the pager opens with the shape of the change; `f`/`F`
+                    // then jump between the per-file sections.
+                    let stat_rows =
+                        crate::diff_render::diff_stat_rows_from_unified(&plain.join("\n"));
+                    let mut lines: Vec<ratatui::text::Line<'static>> = Vec::new();
+                    if stat_rows.len() > 1 {
+                        let wrap_cols = match tui.terminal.viewport_area.width {
+                            0 => 80,
+                            w => w as usize,
+                        };
+                        lines.extend(crate::diff_render::render_diff_stat_block(
+                            &stat_rows, wrap_cols,
+                        ));
+                        lines.push("".into());
+                    }
+                    let header_len = lines.len();
+                    hunks = crate::diff_render::collect_unified_hunks(&plain);
+                    for hunk in &mut hunks {
+                        hunk.line_index += header_len;
+                    }
+                    lines.extend(styled);
+                    lines
                 };
-                self.overlay = Some(Overlay::new_static_with_lines(
+                self.overlay = Some(Overlay::new_static_diff(
                     pager_lines,
                     "D I F F".to_string(),
+                    hunks,
+                    self.app_event_tx.clone(),
+                ));
+                tui.frame_requester().schedule_frame();
+            }
+            AppEvent::StageDiffHunk { patch, reverse } => {
+                if let Err(err) = crate::get_git_diff::stage_patch(&patch, reverse).await {
+                    let verb = if reverse { "unstage" } else { "stage" };
+                    self.chat_widget
+                        .add_error_message(format!("Failed to {verb} hunk: {err}"));
+                }
+                // Recompute the diff either way so the pager reflects the
+                // current index state.
+                let tx = self.app_event_tx.clone();
+                tokio::spawn(async move {
+                    let text = match crate::get_git_diff::get_git_diff().await {
+                        Ok((true, diff_text)) => diff_text,
+                        Ok((false, _)) => "`/diff` — _not inside a git repository_".to_string(),
+                        Err(e) => format!("Failed to compute diff: {e}"),
+                    };
+                    tx.send(AppEvent::DiffResult(text));
+                });
+            }
+            AppEvent::AmendExecCommand { id, command } => {
+                self.chat_widget.amend_exec_command(id, command);
+            }
+            AppEvent::OpenHelpTopic(topic) => {
+                let (markdown, title) = match topic {
+                    Some(topic) => (topic.markdown().to_string(), topic.title().to_string()),
+                    None => (crate::help_topics::index_markdown(), "H E L P".to_string()),
+                };
+                let _ = tui.enter_alt_screen();
+                let lines = crate::markdown_render::render_markdown_text(&markdown).lines;
+                self.overlay = Some(Overlay::new_static_searchable(lines, title));
+                tui.frame_requester().schedule_frame();
+            }
+            AppEvent::UsageStatsResult(markdown) => {
+                let _ = tui.enter_alt_screen();
+                let lines = crate::markdown_render::render_markdown_text(&markdown).lines;
+                self.overlay = Some(Overlay::new_static_searchable(
+                    lines,
+                    "S T A T S".to_string(),
                 ));
                 tui.frame_requester().schedule_frame();
             }
@@ -5145,6 +5471,43 @@ impl App {
                     }
                 }
             },
+            AppEvent::RateLimitCooldownElapsed => {
+                self.chat_widget.on_rate_limit_cooldown_elapsed();
+            }
+            AppEvent::UnpinContextItem { index } => {
+                self.chat_widget.unpin_context_item(index);
+            }
+            AppEvent::ShowCitationPreview { index } => {
+                self.chat_widget.show_citation_preview(index);
+            }
+            AppEvent::RetryLastUserMessage => {
+                self.chat_widget.retry_last_user_message();
+            }
+            AppEvent::OpenModelPopup => {
+                self.chat_widget.open_model_popup();
+            }
+            AppEvent::ShowDebugLogTail => {
+                self.chat_widget.show_debug_log_tail();
+            }
+            AppEvent::CopyLastError => {
+                self.chat_widget.copy_last_error();
+            }
+            AppEvent::RingBell { style } => match style {
+                BellStyle::None => {}
+                BellStyle::Audio => tui.ring_audio_bell(),
+                BellStyle::Visual => {
+                    if tui.set_reverse_video(true) {
+                        let tx = self.app_event_tx.clone();
+                        std::thread::spawn(move || {
+                            std::thread::sleep(Duration::from_millis(100));
+                            tx.send(AppEvent::EndVisualBell);
+                        });
+                    }
+                }
+            },
+            AppEvent::EndVisualBell => {
+                tui.set_reverse_video(false);
+            }
             AppEvent::ConnectorsLoaded { result, is_final } => {
                 self.chat_widget.on_connectors_loaded(result, is_final);
             }
@@ -5857,6 +6220,26 @@ impl App {
             AppEvent::UpdateFeatureFlags { updates } => {
                 self.update_feature_flags(updates).await;
             }
+            AppEvent::UpdateKeybindings { updates } => {
+                self.update_keybindings(updates).await;
+            }
+            AppEvent::DispatchKeymapAction(action) => {
+                self.dispatch_keymap_action(tui, action);
+            }
+            AppEvent::PlayMacro(name) => {
+                let macros = crate::macros::load_macros(self.config.codex_home.as_path());
+                let Some(steps) = macros.get(&name) else {
+                    self.chat_widget
+                        .add_error_message(format!("No saved macro named `{name}`."));
+                    return Ok(AppRunControl::Continue);
+                };
+                // Replay deliberately bypasses the keymap: macro steps are
+                // composer input, and resolving them through `ChordResolver`
+                // could re-trigger macro actions and recurse.
+                for event in crate::macros::decode_macro(steps) {
+                    self.handle_unbound_key_event(tui, event);
+                }
+            }
             AppEvent::UpdateMemorySettings {
                 use_memories,
                 generate_memories,
@@ -5903,6 +6286,19 @@ impl App {
                     ));
                 }
             }
+            AppEvent::PersistSeenTips(seen) => {
+                let builder = ConfigEditsBuilder::new(&self.config.codex_home);
+                let builder = if seen.is_empty() {
+                    builder.clear_seen_tips()
+                } else {
+                    builder.set_seen_tips(&seen)
+                };
+                if let Err(err) = builder.apply().await {
+                    tracing::error!(error = %err, "failed to persist seen tips");
+                    self.chat_widget
+                        .add_error_message(format!("Failed to save tips state: {err}"));
+                }
+            }
             AppEvent::PersistWorldWritableWarningAcknowledged => {
                 if let Err(err) = ConfigEditsBuilder::new(&self.config.codex_home)
                     .set_hide_world_writable_warning(/*acknowledged*/ true)
@@ -6102,6 +6498,12 @@ impl App {
             AppEvent::OpenReviewCustomPrompt => {
                 self.chat_widget.show_review_custom_prompt();
             }
+            AppEvent::CommitSuggestionReady(suggestion) => {
+                self.chat_widget.open_commit_suggestion_popup(suggestion);
+            }
+            AppEvent::SubmitCommitMessage(message) => {
+                self.chat_widget.submit_commit_message(message);
+            }
             AppEvent::SubmitUserMessageWithMode {
                 text,
                 collaboration_mode,
@@ -6203,6 +6605,9 @@ impl App {
                     }
                 }
             }
+            AppEvent::AutoModeExpired { generation } => {
+                self.chat_widget.handle_auto_mode_expired(generation);
+            }
             AppEvent::StatusLineBranchUpdated { cwd, branch } => {
                 self.chat_widget.set_status_line_branch(cwd, branch);
                 self.refresh_status_line();
@@ -6255,6 +6660,10 @@ impl App {
                         ) {
                             crate::render::highlight::set_syntax_theme(theme);
                         }
+                        self.chat_widget.add_info_message(
+                            format!("Syntax theme set to {name} and saved to config.toml."),
+                            /*hint*/ None,
+                        );
                         self.sync_tui_theme_selection(name);
                     }
                     Err(err) => {
@@ -6265,6 +6674,15 @@ impl App {
                     }
                 }
             }
+            AppEvent::ThemeConfigFileChanged => {
+                let theme = crate::theme_watcher::read_tui_theme(&self.config.codex_home);
+                if theme != self.config.tui_theme {
+                    self.config.tui_theme = theme.clone();
+                    self.chat_widget.set_tui_theme(theme);
+                    self.restore_runtime_theme_from_config();
+                    tui.frame_requester().schedule_frame();
+                }
+            }
         }
         Ok(AppRunControl::Continue)
     }
@@ -6638,24 +7056,62 @@ impl App {
             return;
         }
 
-        match key_event {
-            KeyEvent {
-                code: KeyCode::Char('t'),
-                modifiers: KeyModifiers::CONTROL,
-                kind: KeyEventKind::Press,
-                ..
-            } => {
+        if key_event.kind == KeyEventKind::Press {
+            match self.chord_resolver.press(
+                &self.keymap,
+                KeymapContext::Composer,
+                key_event,
+                Instant::now(),
+            ) {
+                ChordResolution::Action(action) => {
+                    self.clear_pending_chord_hint(tui);
+                    self.dispatch_keymap_action(tui, action);
+                }
+                ChordResolution::Pending(chord) => {
+                    self.chord_hint_active = true;
+                    self.chat_widget.set_footer_hint_override(Some(vec![(
+                        format!("{chord} …"),
+                        "waiting for the rest of the chord".to_string(),
+                    )]));
+                    tui.frame_requester().schedule_frame();
+                }
+                ChordResolution::Passthrough(events) => {
+                    self.clear_pending_chord_hint(tui);
+                    for event in events {
+                        // Only passthrough keys are recorded, so the stop
+                        // chord and other shortcut activations never end up
+                        // inside a macro.
+                        self.macro_recorder.record_key(&event);
+                        self.handle_unbound_key_event(tui, event);
+                    }
+                }
+            }
+        } else {
+            self.handle_unbound_key_event(tui, key_event);
+        }
+    }
+
+    /// Clears the pending-chord footer hint if one is showing.
+    fn clear_pending_chord_hint(&mut self, tui: &mut tui::Tui) {
+        if self.chord_hint_active {
+            self.chord_hint_active = false;
+            self.chat_widget.set_footer_hint_override(/*items*/ None);
+            tui.frame_requester().schedule_frame();
+        }
+    }
+
+    /// Runs the action a key sequence resolved to. The bodies match the
+    /// shortcuts `handle_key_event` used to hardcode before bindings moved
+    /// into `TuiKeymap`.
+    fn dispatch_keymap_action(&mut self, tui: &mut tui::Tui, action: KeymapAction) {
+        match action {
+            KeymapAction::OpenTranscript => {
                 // Enter alternate screen and set viewport to full size.
                 let _ = tui.enter_alt_screen();
                 self.overlay = Some(Overlay::new_transcript(self.transcript_cells.clone()));
                 tui.frame_requester().schedule_frame();
             }
-            KeyEvent {
-                code: KeyCode::Char('l'),
-                modifiers: KeyModifiers::CONTROL,
-                kind: KeyEventKind::Press,
-                ..
-            } => {
+            KeymapAction::ClearScreen => {
                 if !self.chat_widget.can_run_ctrl_l_clear_now() {
                     return;
                 }
@@ -6669,12 +7125,7 @@ impl App {
                     tui.frame_requester().schedule_frame();
                 }
             }
-            KeyEvent {
-                code: KeyCode::Char('g'),
-                modifiers: KeyModifiers::CONTROL,
-                kind: KeyEventKind::Press,
-                ..
-            } => {
+            KeymapAction::LaunchExternalEditor => {
                 // Only launch the external editor if there is no overlay and the bottom pane is not in use.
                 // Note that it can be launched while a task is running to enable editing while the previous turn is ongoing.
                 if self.overlay.is_none()
@@ -6684,6 +7135,90 @@ impl App {
                     self.request_external_editor_launch(tui);
                 }
             }
+            KeymapAction::ToggleToolCallsCollapsed => {
+                let collapse = !self.tool_calls_collapsed_override.unwrap_or_else(|| {
+                    !self.config.tui_collapsed_tool_calls.is_empty()
+                        && self.config.tui_collapsed_tool_calls.values().all(|v| *v)
+                });
+                self.tool_calls_collapsed_override = Some(collapse);
+                let message = if collapse {
+                    match crate::key_hint::for_action(&self.keymap, KeymapAction::OpenTranscript) {
+                        Some(key) => format!(
+                            "Tool calls will render collapsed; press {} for the full transcript.",
+                            ratatui::text::Span::from(key).content
+                        ),
+                        None => "Tool calls will render collapsed.".to_string(),
+                    }
+                } else {
+                    "Tool calls will render expanded.".to_string()
+                };
+                self.chat_widget.add_info_message(message, /*hint*/ None);
+            }
+            KeymapAction::OpenActionPalette => {
+                self.chat_widget.open_action_palette();
+                tui.frame_requester().schedule_frame();
+            }
+            KeymapAction::MacroRecordStart => {
+                let message = if self.macro_recorder.is_recording() {
+                    "Restarted macro recording; run macro-record-stop to save it."
+                } else {
+                    "Recording macro; run macro-record-stop to save it."
+                };
+                self.macro_recorder.start();
+                self.chat_widget
+                    .add_info_message(message.to_string(), /*hint*/ None);
+            }
+            KeymapAction::MacroRecordStop => {
+                let Some(steps) = self.macro_recorder.stop() else {
+                    self.chat_widget.add_info_message(
+                        "No macro recording in progress.".to_string(),
+                        /*hint*/ None,
+                    );
+                    return;
+                };
+                if steps.is_empty() {
+                    self.chat_widget.add_info_message(
+                        "Discarded empty macro recording.".to_string(),
+                        /*hint*/ None,
+                    );
+                    return;
+                }
+                let codex_home = self.config.codex_home.as_path();
+                let name = crate::macros::next_macro_name(&crate::macros::load_macros(codex_home));
+                match crate::macros::save_macro(codex_home, &name, steps.clone()) {
+                    Ok(()) => self.chat_widget.add_info_message(
+                        format!(
+                            "Saved macro `{name}` ({} key(s)); rename it in macros.json.",
+                            steps.len()
+                        ),
+                        /*hint*/ None,
+                    ),
+                    Err(err) => self
+                        .chat_widget
+                        .add_error_message(format!("Failed to save macro: {err}")),
+                }
+            }
+            KeymapAction::MacroPlay => {
+                self.chat_widget.open_macro_picker();
+                tui.frame_requester().schedule_frame();
+            }
+            KeymapAction::ToggleTextSelection => {
+                // An active selection is already restored by the key press
+                // itself (see `handle_tui_event`), so reaching here always
+                // begins selection mode.
+                tui.begin_text_selection();
+                self.chat_widget.set_footer_hint_override(Some(vec![(
+                    "text selection".to_string(),
+                    "mouse capture suspended; press any key to restore".to_string(),
+                )]));
+                tui.frame_requester().schedule_frame();
+            }
+        }
+    }
+
+    /// Handles a key press that did not resolve through the keymap.
+    fn handle_unbound_key_event(&mut self, tui: &mut tui::Tui, key_event: KeyEvent) {
+        match key_event {
             // Esc primes/advances backtracking only in normal (not working) mode
             // with the composer focused and empty. In any other state, forward
             // Esc so the active UI (e.g. status indicator, modals, popups)
@@ -10862,6 +11397,7 @@ guardian_approval = true
         let file_search = FileSearchManager::new(config.cwd.to_path_buf(), app_event_tx.clone());
         let model = crate::legacy_core::test_support::get_model_offline(config.model.as_deref());
         let session_telemetry = test_session_telemetry(&config, model.as_str());
+        let keymap = build_keymap(config.tui_keybindings.as_ref());
 
         App {
             model_catalog: chat_widget.model_catalog(),
@@ -10876,9 +11412,17 @@ guardian_approval = true
             runtime_sandbox_policy_override: None,
             file_search,
             transcript_cells: Vec::new(),
+            history_budget: None,
+            history_spool: None,
             overlay: None,
             deferred_history_lines: Vec::new(),
             has_emitted_history_lines: false,
+            tool_calls_collapsed_override: None,
+            keymap,
+            chord_resolver: ChordResolver::default(),
+            chord_hint_active: false,
+            macro_recorder: MacroRecorder::default(),
+            mouse_map: MouseMap::from_mouse_toml(None),
             enhanced_keys_supported: false,
             commit_anim_running: Arc::new(AtomicBool::new(false)),
             status_line_invalid_items_warned: Arc::new(AtomicBool::new(false)),
@@ -10933,6 +11477,8 @@ guardian_approval = true
                 runtime_sandbox_policy_override: None,
                 file_search,
                 transcript_cells: Vec::new(),
+                history_budget: None,
+                history_spool: None,
                 overlay: None,
                 deferred_history_lines: Vec::new(),
                 has_emitted_history_lines: false,
@@ -21,6 +21,7 @@ use codex_app_server_protocol::PluginReadParams;
 use codex_app_server_protocol::PluginReadResponse;
 use codex_app_server_protocol::PluginUninstallResponse;
 use codex_app_server_protocol::SkillsListResponse;
+use codex_config::types::BellStyle;
 use codex_file_search::FileMatch;
 use codex_protocol::ThreadId;
 use codex_protocol::openai_models::ModelPreset;
@@ -34,6 +35,9 @@ use crate::bottom_pane::ApprovalRequest;
 use crate::bottom_pane::StatusLineItem;
 use crate::bottom_pane::TerminalTitleItem;
 use crate::chatwidget::UserMessage;
+use crate::help_topics::HelpTopic;
+use crate::keymap::KeymapAction;
+use crate::keymap::KeymapContext;
 use codex_config::types::ApprovalsReviewer;
 use codex_features::Feature;
 use codex_plugin::PluginCapabilitySummary;
@@ -193,6 +197,39 @@ pub(crate) enum AppEvent {
         result: Result<Vec<RateLimitSnapshot>, String>,
     },
 
+    /// A rate-limit cool-down window has elapsed; queued messages may send.
+    RateLimitCooldownElapsed,
+
+    /// Remove one entry from the pinned-context list by tray index.
+    UnpinContextItem {
+        index: usize,
+    },
+
+    /// Preview the cited source lines for one citation of the last response.
+    ShowCitationPreview {
+        index: usize,
+    },
+
+    /// Re-send the user message that preceded the last turn-ending error.
+    RetryLastUserMessage,
+
+    /// Open the model selection popup.
+    OpenModelPopup,
+
+    /// Insert the tail of the TUI debug log into the transcript.
+    ShowDebugLogTail,
+
+    /// Copy the last turn-ending error message to the clipboard.
+    CopyLastError,
+
+    /// Ring the configured terminal bell (audio BEL or visual flash).
+    RingBell {
+        style: BellStyle,
+    },
+
+    /// End the reverse-video flash started by a visual bell.
+    EndVisualBell,
+
     /// Send a user-confirmed request to notify the workspace owner.
     SendAddCreditsNudgeEmail {
         credit_type: AddCreditsNudgeCreditType,
@@ -212,6 +249,34 @@ pub(crate) enum AppEvent {
     /// Result of computing a `/diff` command.
     DiffResult(String),
 
+    /// Result of computing a `/suggest-commit` suggestion: opens the editable
+    /// commit message popup.
+    CommitSuggestionReady(String),
+
+    /// The user accepted a `/suggest-commit` message; hand it to the agent to
+    /// run the actual `git commit`.
+    SubmitCommitMessage(String),
+
+    /// Stage (or, with `reverse`, unstage) a single hunk in git's index and
+    /// refresh the `/diff` pager with the resulting diff.
+    StageDiffHunk {
+        patch: String,
+        reverse: bool,
+    },
+
+    /// Deny the pending exec approval `id` and ask the agent to run the
+    /// user-edited `command` in its place.
+    AmendExecCommand {
+        id: String,
+        command: Vec<String>,
+    },
+
+    /// Open a `/help` topic (or the topic index) in the pager overlay.
+    OpenHelpTopic(Option<HelpTopic>),
+
+    /// Result of computing `/stats`: rendered markdown for the pager.
+    UsageStatsResult(String),
+
     /// Open the app link view in the bottom pane.
     OpenAppLink {
         app_id: String,
@@ -523,6 +588,20 @@ pub(crate) enum AppEvent {
         updates: Vec<(Feature, bool)>,
     },
 
+    /// Persist rebound TUI shortcuts to `[tui.keybindings]` and rebuild the
+    /// active keymap. Each entry is `(context, action name, chord spec)`.
+    UpdateKeybindings {
+        updates: Vec<(KeymapContext, &'static str, String)>,
+    },
+
+    /// Run a keymap action as if its chord had been pressed. Sent by the
+    /// action palette when a row is accepted.
+    DispatchKeymapAction(KeymapAction),
+
+    /// Replay the named saved macro's key events into the composer. Sent by
+    /// the macro picker when a row is accepted.
+    PlayMacro(String),
+
     /// Update memory settings and persist them to config.toml.
     UpdateMemorySettings {
         use_memories: bool,
@@ -548,6 +627,10 @@ pub(crate) enum AppEvent {
     /// Persist the acknowledgement flag for the full access warning prompt.
     PersistFullAccessWarningAcknowledged,
 
+    /// Persist the set of one-time tips the user has seen. An empty set
+    /// clears the `[notice].seen_tips` entry entirely (`/tips reset`).
+    PersistSeenTips(Vec<String>),
+
     /// Persist the acknowledgement flag for the world-writable directories warning.
     #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
     PersistWorldWritableWarningAcknowledged,
@@ -651,6 +734,12 @@ pub(crate) enum AppEvent {
     /// Launch the external editor after a normal draw has completed.
     LaunchExternalEditor,
 
+    /// A time-boxed `/auto` grant reached its deadline. The generation guards
+    /// against stale timers after the grant was re-armed or ended early.
+    AutoModeExpired {
+        generation: u64,
+    },
+
     /// Async update of the current git branch for status line rendering.
     StatusLineBranchUpdated {
         cwd: PathBuf,
@@ -678,6 +767,10 @@ pub(crate) enum AppEvent {
     SyntaxThemeSelected {
         name: String,
     },
+
+    /// `config.toml` changed on disk; re-check the `[tui] theme` key and
+    /// hot-swap the runtime theme if it differs from the active one.
+    ThemeConfigFileChanged,
 }
 
 #[derive(Debug)]
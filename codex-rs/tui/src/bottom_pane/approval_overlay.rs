@@ -9,7 +9,11 @@ use crate::bottom_pane::CancellationEvent;
 use crate::bottom_pane::list_selection_view::ListSelectionView;
 use crate::bottom_pane::list_selection_view::SelectionItem;
 use crate::bottom_pane::list_selection_view::SelectionViewParams;
+use crate::bottom_pane::textarea::TextArea;
+use crate::bottom_pane::textarea::TextAreaState;
 use crate::diff_render::DiffSummary;
+use crate::exec_command::relativize_to_home;
+use crate::exec_command::split_command_string;
 use crate::exec_command::strip_bash_lc_and_escape;
 use crate::history_cell;
 use crate::key_hint;
@@ -34,6 +38,9 @@ use codex_protocol::protocol::Op;
 use codex_protocol::protocol::ReviewDecision;
 use codex_protocol::request_permissions::PermissionGrantScope;
 use codex_protocol::request_permissions::RequestPermissionProfile;
+use codex_shell_command::high_risk::HighRiskClass;
+use codex_shell_command::high_risk::command_high_risk;
+use codex_shell_command::parse_command::extract_shell_command;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
@@ -44,8 +51,12 @@ use ratatui::layout::Rect;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
 use ratatui::text::Span;
+use ratatui::widgets::Clear;
 use ratatui::widgets::Paragraph;
+use ratatui::widgets::StatefulWidgetRef;
+use ratatui::widgets::Widget;
 use ratatui::widgets::Wrap;
+use std::cell::RefCell;
 
 /// Request coming from the agent that needs user approval.
 #[derive(Clone, Debug)]
@@ -55,6 +66,7 @@ pub(crate) enum ApprovalRequest {
         thread_label: Option<String>,
         id: String,
         command: Vec<String>,
+        cwd: Option<AbsolutePathBuf>,
         reason: Option<String>,
         available_decisions: Vec<ReviewDecision>,
         network_approval_context: Option<NetworkApprovalContext>,
@@ -140,6 +152,8 @@ pub(crate) struct ApprovalOverlay {
     app_event_tx: AppEventSender,
     list: ListSelectionView,
     options: Vec<ApprovalOption>,
+    command_editor: Option<CommandEditor>,
+    high_risk_confirmation: Option<HighRiskConfirmation>,
     current_complete: bool,
     done: bool,
     features: Features,
@@ -153,6 +167,8 @@ impl ApprovalOverlay {
             app_event_tx: app_event_tx.clone(),
             list: ListSelectionView::new(Default::default(), app_event_tx),
             options: Vec::new(),
+            command_editor: None,
+            high_risk_confirmation: None,
             current_complete: false,
             done: false,
             features,
@@ -184,6 +200,8 @@ impl ApprovalOverlay {
 
     fn set_current(&mut self, request: ApprovalRequest) {
         self.current_complete = false;
+        self.command_editor = None;
+        self.high_risk_confirmation = None;
         let header = build_header(&request);
         let (options, params) = Self::build_options(&request, header, &self.features);
         self.current_request = Some(request);
@@ -264,9 +282,27 @@ impl ApprovalOverlay {
         if self.current_complete {
             return;
         }
-        let Some(option) = self.options.get(actual_idx) else {
+        let Some(option) = self.options.get(actual_idx).cloned() else {
             return;
         };
+        // High-risk commands get an extra confirmation tier: approving one
+        // requires typing the command back, even when the decision itself
+        // came from a single keypress.
+        if let Some(ApprovalRequest::Exec {
+            command,
+            network_approval_context: None,
+            ..
+        }) = self.current_request.as_ref()
+            && let ApprovalDecision::Review(decision) = &option.decision
+            && decision_requires_typed_confirmation(decision)
+            && let Some(class) = command_high_risk(command)
+        {
+            let expected = strip_bash_lc_and_escape(command);
+            let decision = decision.clone();
+            self.high_risk_confirmation =
+                Some(HighRiskConfirmation::new(expected, class, decision));
+            return;
+        }
         if let Some(request) = self.current_request.as_ref() {
             match (request, &option.decision) {
                 (ApprovalRequest::Exec { id, command, .. }, ApprovalDecision::Review(decision)) => {
@@ -406,6 +442,237 @@ impl ApprovalOverlay {
         }
     }
 
+    fn exec_cwd(&self) -> Option<&AbsolutePathBuf> {
+        match self.current_request.as_ref()? {
+            ApprovalRequest::Exec { cwd, .. } => cwd.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The command rendered for editing, when the current request supports it.
+    ///
+    /// Editing is limited to same-thread exec approvals: cross-thread requests
+    /// are answered out of band, and network approvals are about the host, not
+    /// the command text.
+    fn editable_command(&self) -> Option<String> {
+        match self.current_request.as_ref()? {
+            ApprovalRequest::Exec {
+                thread_label: None,
+                network_approval_context: None,
+                command,
+                ..
+            } => Some(strip_bash_lc_and_escape(command)),
+            _ => None,
+        }
+    }
+
+    fn open_command_editor(&mut self) -> bool {
+        let Some(script) = self.editable_command() else {
+            return false;
+        };
+        self.command_editor = Some(CommandEditor::new(&script));
+        true
+    }
+
+    fn handle_editor_key_event(&mut self, key_event: KeyEvent) {
+        let Some(editor) = self.command_editor.as_mut() else {
+            return;
+        };
+        match key_event {
+            KeyEvent {
+                code: KeyCode::Esc, ..
+            } => {
+                self.command_editor = None;
+            }
+            KeyEvent {
+                code: KeyCode::Enter,
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => {
+                let edited = editor.textarea.text().trim().to_string();
+                if edited.is_empty() {
+                    return;
+                }
+                self.command_editor = None;
+                self.submit_edited_command(&edited);
+            }
+            other => editor.textarea.input(other),
+        }
+    }
+
+    fn submit_edited_command(&mut self, edited: &str) {
+        if self.current_complete {
+            return;
+        }
+        let Some(ApprovalRequest::Exec { id, command, .. }) = self.current_request.as_ref() else {
+            return;
+        };
+        let (id, command) = (id.clone(), command.clone());
+        if edited == strip_bash_lc_and_escape(&command) {
+            // Nothing changed, so this is a plain approval.
+            self.handle_exec_decision(&id, &command, ReviewDecision::Approved);
+        } else {
+            self.app_event_tx.send(AppEvent::AmendExecCommand {
+                id,
+                command: rebuild_command(&command, edited),
+            });
+        }
+        self.current_complete = true;
+        self.advance_queue();
+    }
+
+    fn handle_confirmation_key_event(&mut self, key_event: KeyEvent) {
+        match key_event {
+            KeyEvent {
+                code: KeyCode::Esc, ..
+            } => {
+                self.high_risk_confirmation = None;
+            }
+            KeyEvent {
+                code: KeyCode::Enter,
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => {
+                let Some(confirmation) = self.high_risk_confirmation.as_mut() else {
+                    return;
+                };
+                if confirmation.textarea.text().trim() != confirmation.expected {
+                    confirmation.mismatch = true;
+                    return;
+                }
+                let decision = confirmation.decision.clone();
+                self.high_risk_confirmation = None;
+                if let Some(ApprovalRequest::Exec { id, command, .. }) =
+                    self.current_request.as_ref()
+                {
+                    let (id, command) = (id.clone(), command.clone());
+                    self.handle_exec_decision(&id, &command, decision);
+                }
+                self.current_complete = true;
+                self.advance_queue();
+            }
+            other => {
+                if let Some(confirmation) = self.high_risk_confirmation.as_mut() {
+                    confirmation.mismatch = false;
+                    confirmation.textarea.input(other);
+                }
+            }
+        }
+    }
+
+    fn render_confirmation(
+        &self,
+        confirmation: &HighRiskConfirmation,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let row = |y: u16| Rect {
+            x: area.x,
+            y,
+            width: area.width,
+            height: 1,
+        };
+        let mut y = area.y;
+        Paragraph::new(Line::from(vec![
+            gutter(),
+            "Type the command to confirm".bold(),
+        ]))
+        .render(row(y), buf);
+        y = y.saturating_add(1);
+        Paragraph::new(Line::from(vec![
+            gutter(),
+            format!("This command {}.", confirmation.class.description()).yellow(),
+        ]))
+        .render(row(y), buf);
+        y = y.saturating_add(1);
+        for line in confirmation.expected_lines() {
+            if y >= area.y.saturating_add(area.height) {
+                return;
+            }
+            let mut line = line;
+            line.spans.insert(0, gutter());
+            Paragraph::new(line).render(row(y), buf);
+            y = y.saturating_add(1);
+        }
+        let input_height = confirmation.input_height(area.width);
+        for offset in 0..input_height {
+            Paragraph::new(Line::from(vec![gutter()])).render(row(y.saturating_add(offset)), buf);
+        }
+        if area.width > 2 {
+            let textarea_rect = Rect {
+                x: area.x.saturating_add(2),
+                y,
+                width: area.width.saturating_sub(2),
+                height: input_height,
+            };
+            Clear.render(textarea_rect, buf);
+            let mut state = confirmation.textarea_state.borrow_mut();
+            StatefulWidgetRef::render_ref(
+                &(&confirmation.textarea),
+                textarea_rect,
+                buf,
+                &mut state,
+            );
+        }
+        y = y.saturating_add(input_height).saturating_add(1);
+        if y < area.y.saturating_add(area.height) {
+            Paragraph::new(confirmation_footer_hint(confirmation.mismatch)).render(row(y), buf);
+        }
+    }
+
+    fn render_editor(&self, editor: &CommandEditor, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+        let row = |y: u16| Rect {
+            x: area.x,
+            y,
+            width: area.width,
+            height: 1,
+        };
+        let mut y = area.y;
+        Paragraph::new(Line::from(vec![gutter(), "Edit command".bold()])).render(row(y), buf);
+        y = y.saturating_add(1);
+        if let Some(cwd) = self.exec_cwd() {
+            Paragraph::new(Line::from(vec![
+                gutter(),
+                format!("In {}", format_cwd(cwd)).cyan(),
+            ]))
+            .render(row(y), buf);
+            y = y.saturating_add(1);
+        }
+        let input_height = editor.input_height(area.width);
+        for offset in 0..input_height {
+            Paragraph::new(Line::from(vec![gutter()])).render(row(y.saturating_add(offset)), buf);
+        }
+        if area.width > 2 {
+            let textarea_rect = Rect {
+                x: area.x.saturating_add(2),
+                y,
+                width: area.width.saturating_sub(2),
+                height: input_height,
+            };
+            Clear.render(textarea_rect, buf);
+            let mut state = editor.textarea_state.borrow_mut();
+            StatefulWidgetRef::render_ref(&(&editor.textarea), textarea_rect, buf, &mut state);
+        }
+        y = y.saturating_add(input_height).saturating_add(1);
+        for line in editor.preview_lines() {
+            if y >= area.y.saturating_add(area.height) {
+                return;
+            }
+            Paragraph::new(line).render(row(y), buf);
+            y = y.saturating_add(1);
+        }
+        y = y.saturating_add(1);
+        if y < area.y.saturating_add(area.height) {
+            Paragraph::new(editor_footer_hint()).render(row(y), buf);
+        }
+    }
+
     fn try_handle_shortcut(&mut self, key_event: &KeyEvent) -> bool {
         match key_event {
             KeyEvent {
@@ -439,6 +706,12 @@ impl ApprovalOverlay {
                     false
                 }
             }
+            KeyEvent {
+                kind: KeyEventKind::Press,
+                code: KeyCode::Char('e'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => self.open_command_editor(),
             e => {
                 if let Some(idx) = self
                     .options
@@ -457,6 +730,14 @@ impl ApprovalOverlay {
 
 impl BottomPaneView for ApprovalOverlay {
     fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if self.high_risk_confirmation.is_some() {
+            self.handle_confirmation_key_event(key_event);
+            return;
+        }
+        if self.command_editor.is_some() {
+            self.handle_editor_key_event(key_event);
+            return;
+        }
         if self.try_handle_shortcut(&key_event) {
             return;
         }
@@ -467,6 +748,12 @@ impl BottomPaneView for ApprovalOverlay {
     }
 
     fn on_ctrl_c(&mut self) -> CancellationEvent {
+        if self.high_risk_confirmation.take().is_some() {
+            return CancellationEvent::Handled;
+        }
+        if self.command_editor.take().is_some() {
+            return CancellationEvent::Handled;
+        }
         if self.done {
             return CancellationEvent::Handled;
         }
@@ -524,15 +811,61 @@ impl BottomPaneView for ApprovalOverlay {
 
 impl Renderable for ApprovalOverlay {
     fn desired_height(&self, width: u16) -> u16 {
-        self.list.desired_height(width)
+        if let Some(confirmation) = &self.high_risk_confirmation {
+            let expected_rows = confirmation.expected_lines().len() as u16;
+            // Title, warning, expected command, input, blank, hint.
+            return 1 + 1 + expected_rows + confirmation.input_height(width) + 1 + 1;
+        }
+        match &self.command_editor {
+            Some(editor) => {
+                let cwd_rows = u16::from(self.exec_cwd().is_some());
+                let preview_rows = editor.preview_lines().len() as u16;
+                // Title, optional cwd, input, blank, preview, blank, hint.
+                1 + cwd_rows + editor.input_height(width) + 1 + preview_rows + 1 + 1
+            }
+            None => self.list.desired_height(width),
+        }
     }
 
     fn render(&self, area: Rect, buf: &mut Buffer) {
-        self.list.render(area, buf);
+        if let Some(confirmation) = &self.high_risk_confirmation {
+            self.render_confirmation(confirmation, area, buf);
+            return;
+        }
+        match &self.command_editor {
+            Some(editor) => self.render_editor(editor, area, buf),
+            None => self.list.render(area, buf),
+        }
     }
 
     fn cursor_pos(&self, area: Rect) -> Option<(u16, u16)> {
-        self.list.cursor_pos(area)
+        if let Some(confirmation) = &self.high_risk_confirmation {
+            let top = 2 + confirmation.expected_lines().len() as u16;
+            let textarea_rect = Rect {
+                x: area.x.saturating_add(2),
+                y: area.y.saturating_add(top),
+                width: area.width.saturating_sub(2),
+                height: confirmation.input_height(area.width),
+            };
+            let state = *confirmation.textarea_state.borrow();
+            return confirmation
+                .textarea
+                .cursor_pos_with_state(textarea_rect, state);
+        }
+        match &self.command_editor {
+            Some(editor) => {
+                let top = 1 + u16::from(self.exec_cwd().is_some());
+                let textarea_rect = Rect {
+                    x: area.x.saturating_add(2),
+                    y: area.y.saturating_add(top),
+                    width: area.width.saturating_sub(2),
+                    height: editor.input_height(area.width),
+                };
+                let state = *editor.textarea_state.borrow();
+                editor.textarea.cursor_pos_with_state(textarea_rect, state)
+            }
+            None => self.list.cursor_pos(area),
+        }
     }
 }
 
@@ -551,6 +884,20 @@ fn approval_footer_hint(request: &ApprovalRequest) -> Line<'static> {
             " to open thread".into(),
         ]);
     }
+    if matches!(
+        request,
+        ApprovalRequest::Exec {
+            thread_label: None,
+            network_approval_context: None,
+            ..
+        }
+    ) {
+        spans.extend([
+            " or ".into(),
+            key_hint::plain(KeyCode::Char('e')).into(),
+            " to edit the command".into(),
+        ]);
+    }
     Line::from(spans)
 }
 
@@ -560,6 +907,7 @@ fn build_header(request: &ApprovalRequest) -> Box<dyn Renderable> {
             thread_label,
             reason,
             command,
+            cwd,
             network_approval_context,
             additional_permissions,
             ..
@@ -576,6 +924,15 @@ fn build_header(request: &ApprovalRequest) -> Box<dyn Renderable> {
                 header.push(Line::from(vec!["Reason: ".into(), reason.clone().italic()]));
                 header.push(Line::from(""));
             }
+            if network_approval_context.is_none()
+                && let Some(class) = command_high_risk(command)
+            {
+                header.push(Line::from(vec![
+                    "High-risk: ".into(),
+                    format!("this command {}.", class.description()).yellow(),
+                ]));
+                header.push(Line::from(""));
+            }
             if let Some(additional_permissions) = additional_permissions
                 && let Some(rule_line) = format_additional_permissions_rule(additional_permissions)
             {
@@ -591,6 +948,9 @@ fn build_header(request: &ApprovalRequest) -> Box<dyn Renderable> {
                 first.spans.insert(0, Span::from("$ "));
             }
             if network_approval_context.is_none() {
+                if let Some(cwd) = cwd {
+                    header.push(Line::from(vec!["In: ".into(), format_cwd(cwd).cyan()]));
+                }
                 header.extend(full_cmd_lines);
             }
             Box::new(Paragraph::new(header).wrap(Wrap { trim: false }))
@@ -676,6 +1036,141 @@ fn build_header(request: &ApprovalRequest) -> Box<dyn Renderable> {
     }
 }
 
+/// Inline prompt shown in place of the option list after the user approves a
+/// high-risk command: the command must be typed back verbatim to run.
+struct HighRiskConfirmation {
+    textarea: TextArea,
+    textarea_state: RefCell<TextAreaState>,
+    /// The exact text the user must type, from `strip_bash_lc_and_escape`.
+    expected: String,
+    class: HighRiskClass,
+    /// The decision to submit once the typed text matches.
+    decision: ReviewDecision,
+    /// Whether the last Enter press failed to match, for the footer hint.
+    mismatch: bool,
+}
+
+impl HighRiskConfirmation {
+    fn new(expected: String, class: HighRiskClass, decision: ReviewDecision) -> Self {
+        Self {
+            textarea: TextArea::new(),
+            textarea_state: RefCell::new(TextAreaState::default()),
+            expected,
+            class,
+            decision,
+            mismatch: false,
+        }
+    }
+
+    /// Bash-highlighted rendering of the command the user must type.
+    fn expected_lines(&self) -> Vec<Line<'static>> {
+        let mut lines = highlight_bash_to_lines(&self.expected);
+        if let Some(first) = lines.first_mut() {
+            first.spans.insert(0, Span::from("$ "));
+        }
+        lines
+    }
+
+    fn input_height(&self, width: u16) -> u16 {
+        self.textarea
+            .desired_height(width.saturating_sub(2))
+            .clamp(1, 8)
+    }
+}
+
+fn decision_requires_typed_confirmation(decision: &ReviewDecision) -> bool {
+    matches!(
+        decision,
+        ReviewDecision::Approved
+            | ReviewDecision::ApprovedForSession
+            | ReviewDecision::ApprovedExecpolicyAmendment { .. }
+    )
+}
+
+fn confirmation_footer_hint(mismatch: bool) -> Line<'static> {
+    if mismatch {
+        return Line::from(vec![
+            "That doesn't match — type the command exactly, or press ".into(),
+            key_hint::plain(KeyCode::Esc).into(),
+            " to go back".into(),
+        ]);
+    }
+    Line::from(vec![
+        "Press ".into(),
+        key_hint::plain(KeyCode::Enter).into(),
+        " to run the command or ".into(),
+        key_hint::plain(KeyCode::Esc).into(),
+        " to go back".into(),
+    ])
+}
+
+/// Inline editor shown in place of the option list while the user amends the
+/// command.
+struct CommandEditor {
+    textarea: TextArea,
+    textarea_state: RefCell<TextAreaState>,
+}
+
+impl CommandEditor {
+    fn new(initial: &str) -> Self {
+        let mut textarea = TextArea::new();
+        textarea.set_text_clearing_elements(initial);
+        textarea.set_cursor(initial.len());
+        Self {
+            textarea,
+            textarea_state: RefCell::new(TextAreaState::default()),
+        }
+    }
+
+    /// Bash-highlighted rendering of the edited text, shown below the input.
+    fn preview_lines(&self) -> Vec<Line<'static>> {
+        let mut lines = highlight_bash_to_lines(self.textarea.text());
+        if let Some(first) = lines.first_mut() {
+            first.spans.insert(0, Span::from("$ "));
+        }
+        lines
+    }
+
+    fn input_height(&self, width: u16) -> u16 {
+        self.textarea
+            .desired_height(width.saturating_sub(2))
+            .clamp(1, 8)
+    }
+}
+
+fn editor_footer_hint() -> Line<'static> {
+    Line::from(vec![
+        "Press ".into(),
+        key_hint::plain(KeyCode::Enter).into(),
+        " to run the edited command or ".into(),
+        key_hint::plain(KeyCode::Esc).into(),
+        " to go back".into(),
+    ])
+}
+
+fn gutter() -> Span<'static> {
+    "▌ ".cyan()
+}
+
+fn format_cwd(cwd: &AbsolutePathBuf) -> String {
+    match relativize_to_home(cwd) {
+        Some(rel) if rel.as_os_str().is_empty() => "~".to_string(),
+        Some(rel) => format!("~{}{}", std::path::MAIN_SEPARATOR, rel.display()),
+        None => cwd.display().to_string(),
+    }
+}
+
+/// Rebuild an argv for the edited script, preserving the original
+/// `bash -lc`-style wrapper when there was one.
+fn rebuild_command(original: &[String], edited: &str) -> Vec<String> {
+    if let [shell, flag, _] = original
+        && extract_shell_command(original).is_some()
+    {
+        return vec![shell.clone(), flag.clone(), edited.to_string()];
+    }
+    split_command_string(edited)
+}
+
 #[derive(Clone)]
 enum ApprovalDecision {
     Review(ReviewDecision),
@@ -708,9 +1203,9 @@ fn exec_options(
         .filter_map(|decision| match decision {
             ReviewDecision::Approved => Some(ApprovalOption {
                 label: if network_approval_context.is_some() {
-                    "Yes, just this once".to_string()
+                    crate::i18n::tr("approval.yes_just_this_once")
                 } else {
-                    "Yes, proceed".to_string()
+                    crate::i18n::tr("approval.yes_proceed")
                 },
                 decision: ApprovalDecision::Review(ReviewDecision::Approved),
                 display_shortcut: None,
@@ -780,7 +1275,7 @@ fn exec_options(
             }),
             ReviewDecision::TimedOut => None,
             ReviewDecision::Abort => Some(ApprovalOption {
-                label: "No, and tell Codex what to do differently".to_string(),
+                label: crate::i18n::tr("approval.no_feedback"),
                 decision: ApprovalDecision::Review(ReviewDecision::Abort),
                 display_shortcut: Some(key_hint::plain(KeyCode::Esc)),
                 additional_shortcuts: vec![key_hint::plain(KeyCode::Char('n'))],
@@ -878,7 +1373,7 @@ fn path_label(base: &str, subpath: &Option<PathBuf>) -> String {
 fn patch_options() -> Vec<ApprovalOption> {
     vec![
         ApprovalOption {
-            label: "Yes, proceed".to_string(),
+            label: crate::i18n::tr("approval.yes_proceed"),
             decision: ApprovalDecision::Review(ReviewDecision::Approved),
             display_shortcut: None,
             additional_shortcuts: vec![key_hint::plain(KeyCode::Char('y'))],
@@ -890,7 +1385,7 @@ fn patch_options() -> Vec<ApprovalOption> {
             additional_shortcuts: vec![key_hint::plain(KeyCode::Char('a'))],
         },
         ApprovalOption {
-            label: "No, and tell Codex what to do differently".to_string(),
+            label: crate::i18n::tr("approval.no_feedback"),
             decision: ApprovalDecision::Review(ReviewDecision::Abort),
             display_shortcut: Some(key_hint::plain(KeyCode::Esc)),
             additional_shortcuts: vec![key_hint::plain(KeyCode::Char('n'))],
@@ -1000,6 +1495,27 @@ mod tests {
             command: vec!["echo".to_string(), "hi".to_string()],
             reason: Some("reason".to_string()),
             available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
+            cwd: None,
+            network_approval_context: None,
+            additional_permissions: None,
+        }
+    }
+
+    fn make_high_risk_exec_request() -> ApprovalRequest {
+        ApprovalRequest::Exec {
+            thread_id: ThreadId::new(),
+            thread_label: None,
+            id: "test".to_string(),
+            command: vec![
+                "git".to_string(),
+                "push".to_string(),
+                "--force".to_string(),
+                "origin".to_string(),
+                "main".to_string(),
+            ],
+            reason: None,
+            available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
+            cwd: None,
             network_approval_context: None,
             additional_permissions: None,
         }
@@ -1086,6 +1602,7 @@ mod tests {
                 command: vec!["echo".to_string(), "hi".to_string()],
                 reason: None,
                 available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
+                cwd: None,
                 network_approval_context: None,
                 additional_permissions: None,
             },
@@ -1114,6 +1631,7 @@ mod tests {
                 command: vec!["echo".to_string(), "hi".to_string()],
                 reason: None,
                 available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
+                cwd: None,
                 network_approval_context: None,
                 additional_permissions: None,
             },
@@ -1147,6 +1665,7 @@ mod tests {
                     },
                     ReviewDecision::Abort,
                 ],
+                cwd: None,
                 network_approval_context: None,
                 additional_permissions: None,
             },
@@ -1201,6 +1720,7 @@ mod tests {
                     },
                     ReviewDecision::Abort,
                 ],
+                cwd: None,
                 network_approval_context: Some(NetworkApprovalContext {
                     host: "example.com".to_string(),
                     protocol: NetworkApprovalProtocol::Https,
@@ -1230,6 +1750,7 @@ mod tests {
             command,
             reason: None,
             available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
+            cwd: None,
             network_approval_context: None,
             additional_permissions: None,
         };
@@ -1256,6 +1777,170 @@ mod tests {
         );
     }
 
+    #[test]
+    fn header_includes_resolved_cwd() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut request = make_exec_request();
+        let ApprovalRequest::Exec { cwd, .. } = &mut request else {
+            unreachable!()
+        };
+        *cwd = Some(absolute_path("/tmp/codex-work"));
+
+        let view = ApprovalOverlay::new(request, tx, Features::with_defaults());
+        let rendered = render_overlay_lines(&view, /*width*/ 80);
+        assert!(
+            rendered.contains("In: /tmp/codex-work"),
+            "expected header to show the resolved cwd, got {rendered}"
+        );
+    }
+
+    #[test]
+    fn edit_shortcut_opens_editor_and_unchanged_submit_approves() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view = ApprovalOverlay::new(make_exec_request(), tx, Features::with_defaults());
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE));
+        let rendered = render_overlay_lines(&view, /*width*/ 80);
+        assert!(
+            rendered.contains("Edit command"),
+            "expected editor view, got {rendered}"
+        );
+        assert!(
+            rendered.contains("$ echo hi"),
+            "expected bash preview of the command, got {rendered}"
+        );
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let mut decision = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::SubmitThreadOp {
+                op: Op::ExecApproval { decision: d, .. },
+                ..
+            } = ev
+            {
+                decision = Some(d);
+            }
+        }
+        assert_eq!(decision, Some(ReviewDecision::Approved));
+        assert!(view.is_complete());
+    }
+
+    #[test]
+    fn edited_command_submit_emits_amend_event() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view = ApprovalOverlay::new(make_exec_request(), tx, Features::with_defaults());
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE));
+        for ch in " there".chars() {
+            view.handle_key_event(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+        view.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        let mut amended = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::AmendExecCommand { id, command } = ev {
+                amended = Some((id, command));
+            }
+        }
+        assert_eq!(
+            amended,
+            Some((
+                "test".to_string(),
+                vec!["echo".to_string(), "hi".to_string(), "there".to_string()]
+            ))
+        );
+        assert!(view.is_complete());
+    }
+
+    #[test]
+    fn high_risk_approval_requires_typed_confirmation() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view =
+            ApprovalOverlay::new(make_high_risk_exec_request(), tx, Features::with_defaults());
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(rx.try_recv().is_err(), "approval must not be submitted yet");
+        let rendered = render_overlay_lines(&view, /*width*/ 80);
+        assert!(
+            rendered.contains("Type the command to confirm"),
+            "expected confirmation prompt, got {rendered}"
+        );
+
+        // An Enter press that doesn't match the command is rejected.
+        view.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(rx.try_recv().is_err(), "mismatch must not submit");
+        assert!(!view.is_complete());
+
+        for ch in "git push --force origin main".chars() {
+            view.handle_key_event(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+        view.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let mut decision = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::SubmitThreadOp {
+                op: Op::ExecApproval { decision: d, .. },
+                ..
+            } = ev
+            {
+                decision = Some(d);
+            }
+        }
+        assert_eq!(decision, Some(ReviewDecision::Approved));
+        assert!(view.is_complete());
+    }
+
+    #[test]
+    fn high_risk_confirmation_esc_returns_to_options() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view =
+            ApprovalOverlay::new(make_high_risk_exec_request(), tx, Features::with_defaults());
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(rx.try_recv().is_err(), "no decision should be submitted");
+        assert!(!view.is_complete());
+        let rendered = render_overlay_lines(&view, /*width*/ 80);
+        assert!(
+            rendered.contains("Would you like to run the following command?"),
+            "expected to return to the option list, got {rendered}"
+        );
+    }
+
+    #[test]
+    fn denying_high_risk_command_skips_confirmation() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view =
+            ApprovalOverlay::new(make_high_risk_exec_request(), tx, Features::with_defaults());
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE));
+        let mut decision = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::SubmitThreadOp {
+                op: Op::ExecApproval { decision: d, .. },
+                ..
+            } = ev
+            {
+                decision = Some(d);
+            }
+        }
+        assert_eq!(decision, Some(ReviewDecision::Abort));
+    }
+
+    #[test]
+    fn rebuild_command_preserves_shell_wrapper() {
+        let original = vec!["bash".to_string(), "-lc".to_string(), "ls".to_string()];
+        assert_eq!(
+            rebuild_command(&original, "ls -la"),
+            vec!["bash".to_string(), "-lc".to_string(), "ls -la".to_string()]
+        );
+    }
+
     #[test]
     fn network_exec_options_use_expected_labels_and_hide_execpolicy_amendment() {
         let network_context = NetworkApprovalContext {
@@ -1420,6 +2105,7 @@ mod tests {
             command: vec!["cat".into(), "/tmp/readme.txt".into()],
             reason: None,
             available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
+            cwd: None,
             network_approval_context: None,
             additional_permissions: Some(PermissionProfile {
                 network: Some(NetworkPermissions {
@@ -1470,6 +2156,7 @@ mod tests {
             command: vec!["cat".into(), "/tmp/readme.txt".into()],
             reason: Some("need filesystem access".into()),
             available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
+            cwd: None,
             network_approval_context: None,
             additional_permissions: Some(PermissionProfile {
                 network: Some(NetworkPermissions {
@@ -1521,6 +2208,7 @@ mod tests {
                 },
                 ReviewDecision::Abort,
             ],
+            cwd: None,
             network_approval_context: Some(NetworkApprovalContext {
                 host: "example.com".to_string(),
                 protocol: NetworkApprovalProtocol::Https,
@@ -319,6 +319,8 @@ pub(crate) struct ChatComposer {
     history: ChatComposerHistory,
     quit_shortcut_expires_at: Option<Instant>,
     quit_shortcut_key: KeyBinding,
+    external_editor_key: Option<KeyBinding>,
+    show_transcript_key: Option<KeyBinding>,
     esc_backtrack_hint: bool,
     use_shift_enter_hint: bool,
     dismissed_file_popup_token: Option<String>,
@@ -475,6 +477,8 @@ impl ChatComposer {
             history: ChatComposerHistory::new(),
             quit_shortcut_expires_at: None,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             esc_backtrack_hint: false,
             use_shift_enter_hint,
             dismissed_file_popup_token: None,
@@ -1294,6 +1298,17 @@ impl ChatComposer {
         self.set_has_focus(has_focus);
     }
 
+    /// Update the footer hints for rebindable shortcuts to match the live
+    /// keymap. `None` hides a hint whose action is unbound.
+    pub fn set_rebindable_shortcut_keys(
+        &mut self,
+        external_editor_key: Option<KeyBinding>,
+        show_transcript_key: Option<KeyBinding>,
+    ) {
+        self.external_editor_key = external_editor_key;
+        self.show_transcript_key = show_transcript_key;
+    }
+
     /// Clear the "press again to quit" hint immediately.
     pub fn clear_quit_shortcut_hint(&mut self, has_focus: bool) {
         self.quit_shortcut_expires_at = None;
@@ -2062,6 +2077,7 @@ impl ChatComposer {
     /// where the cursor is within the token and regardless of how many
     /// `@tokens` exist in the line.
     fn insert_selected_path(&mut self, path: &str) {
+        let path = &crate::windows_console::normalize_file_reference(path);
         let cursor_offset = self.textarea.cursor();
         let text = self.textarea.text();
         // Clamp to a valid char boundary to avoid panics when slicing.
@@ -3065,12 +3081,18 @@ impl ChatComposer {
 
     fn footer_props(&self) -> FooterProps {
         let mode = self.footer_mode();
+        // Plain Ctrl+V is intercepted by the hosting terminal both under WSL
+        // and in native Windows Terminal, so advertise Ctrl+Alt+V there.
         let is_wsl = {
             #[cfg(target_os = "linux")]
             {
                 mode == FooterMode::ShortcutOverlay && crate::clipboard_paste::is_probably_wsl()
             }
-            #[cfg(not(target_os = "linux"))]
+            #[cfg(windows)]
+            {
+                mode == FooterMode::ShortcutOverlay && crate::windows_console::is_windows_terminal()
+            }
+            #[cfg(not(any(target_os = "linux", windows)))]
             {
                 false
             }
@@ -3082,6 +3104,8 @@ impl ChatComposer {
             use_shift_enter_hint: self.use_shift_enter_hint,
             is_task_running: self.is_task_running,
             quit_shortcut_key: self.quit_shortcut_key,
+            external_editor_key: self.external_editor_key,
+            show_transcript_key: self.show_transcript_key,
             collaboration_modes_enabled: self.collaboration_modes_enabled,
             is_wsl,
             context_window_percent: self.context_window_percent,
@@ -4033,9 +4057,17 @@ impl ChatComposer {
             buf.set_style(textarea_rect, textarea_style);
         }
         if !textarea_rect.is_empty() {
+            // Themes can recolor the prompt chevron via the `composer_border`
+            // palette slot; otherwise keep the default bold/dim styling.
+            let border_fg = crate::style::composer_border_fg();
             let prompt = if self.input_enabled {
                 if is_zellij {
-                    Span::styled("›", style.fg(ratatui::style::Color::Cyan))
+                    Span::styled(
+                        "›",
+                        style.fg(border_fg.unwrap_or(ratatui::style::Color::Cyan)),
+                    )
+                } else if let Some(fg) = border_fg {
+                    "›".fg(fg).bold()
                 } else {
                     "›".bold()
                 }
@@ -74,6 +74,12 @@ pub(crate) struct FooterProps {
     ///
     /// This is rendered when `mode` is `FooterMode::QuitShortcutReminder`.
     pub(crate) quit_shortcut_key: KeyBinding,
+    /// Live binding shown for "edit in external editor" in the shortcut
+    /// overlay; `None` hides the entry when the action is unbound.
+    pub(crate) external_editor_key: Option<KeyBinding>,
+    /// Live binding shown for "view transcript" in the shortcut overlay;
+    /// `None` hides the entry when the action is unbound.
+    pub(crate) show_transcript_key: Option<KeyBinding>,
     pub(crate) context_window_percent: Option<i64>,
     pub(crate) context_window_used_tokens: Option<i64>,
     pub(crate) status_line_value: Option<Line<'static>>,
@@ -89,6 +95,7 @@ pub(crate) struct FooterProps {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) enum CollaborationModeIndicator {
     Plan,
+    ReadOnly,
     #[allow(dead_code)] // Hidden by current mode filtering; kept for future UI re-enablement.
     PairProgramming,
     #[allow(dead_code)] // Hidden by current mode filtering; kept for future UI re-enablement.
@@ -107,6 +114,9 @@ impl CollaborationModeIndicator {
         };
         match self {
             CollaborationModeIndicator::Plan => format!("Plan mode{suffix}"),
+            // The cycle hint does not apply: read-only mode is toggled with
+            // /readonly, not shift+tab.
+            CollaborationModeIndicator::ReadOnly => "Read-only mode".to_string(),
             CollaborationModeIndicator::PairProgramming => {
                 format!("Pair Programming mode{suffix}")
             }
@@ -118,6 +128,7 @@ impl CollaborationModeIndicator {
         let label = self.label(show_cycle_hint);
         match self {
             CollaborationModeIndicator::Plan => Span::from(label).magenta(),
+            CollaborationModeIndicator::ReadOnly => Span::from(label).yellow(),
             CollaborationModeIndicator::PairProgramming => Span::from(label).cyan(),
             CollaborationModeIndicator::Execute => Span::from(label).dim(),
         }
@@ -624,6 +635,8 @@ fn footer_from_props_lines(
                 esc_backtrack_hint: props.esc_backtrack_hint,
                 is_wsl: props.is_wsl,
                 collaboration_modes_enabled: props.collaboration_modes_enabled,
+                external_editor_key: props.external_editor_key,
+                show_transcript_key: props.show_transcript_key,
             };
             shortcut_overlay_lines(state)
         }
@@ -741,6 +754,8 @@ struct ShortcutsState {
     esc_backtrack_hint: bool,
     is_wsl: bool,
     collaboration_modes_enabled: bool,
+    external_editor_key: Option<KeyBinding>,
+    show_transcript_key: Option<KeyBinding>,
 }
 
 fn quit_shortcut_reminder_line(key: KeyBinding) -> Line<'static> {
@@ -940,7 +955,14 @@ impl ShortcutDescriptor {
 
     fn overlay_entry(&self, state: ShortcutsState) -> Option<Line<'static>> {
         let binding = self.binding_for(state)?;
-        let mut line = Line::from(vec![self.prefix.into(), binding.key.into()]);
+        // Rebindable shortcuts display whatever the live keymap resolved to
+        // instead of the descriptor's built-in default.
+        let key = match self.id {
+            ShortcutId::ExternalEditor => state.external_editor_key?,
+            ShortcutId::ShowTranscript => state.show_transcript_key?,
+            _ => binding.key,
+        };
+        let mut line = Line::from(vec![self.prefix.into(), key.into()]);
         match self.id {
             ShortcutId::EditPrevious => {
                 if state.esc_backtrack_hint {
@@ -1013,8 +1035,8 @@ const SHORTCUTS: &[ShortcutDescriptor] = &[
     },
     ShortcutDescriptor {
         id: ShortcutId::PasteImage,
-        // Show Ctrl+Alt+V when running under WSL (terminals often intercept plain
-        // Ctrl+V); otherwise fall back to Ctrl+V.
+        // Show Ctrl+Alt+V when running under WSL or native Windows Terminal
+        // (both intercept plain Ctrl+V); otherwise fall back to Ctrl+V.
         bindings: &[
             ShortcutBinding {
                 key: key_hint::ctrl_alt(KeyCode::Char('v')),
@@ -1304,6 +1326,8 @@ mod tests {
                 collaboration_modes_enabled: false,
                 is_wsl: false,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
                 context_window_percent: None,
                 context_window_used_tokens: None,
                 status_line_value: None,
@@ -1322,6 +1346,8 @@ mod tests {
                 collaboration_modes_enabled: false,
                 is_wsl: false,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
                 context_window_percent: None,
                 context_window_used_tokens: None,
                 status_line_value: None,
@@ -1340,6 +1366,8 @@ mod tests {
                 collaboration_modes_enabled: true,
                 is_wsl: false,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
                 context_window_percent: None,
                 context_window_used_tokens: None,
                 status_line_value: None,
@@ -1358,6 +1386,8 @@ mod tests {
                 collaboration_modes_enabled: false,
                 is_wsl: false,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
                 context_window_percent: None,
                 context_window_used_tokens: None,
                 status_line_value: None,
@@ -1376,6 +1406,8 @@ mod tests {
                 collaboration_modes_enabled: false,
                 is_wsl: false,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
                 context_window_percent: None,
                 context_window_used_tokens: None,
                 status_line_value: None,
@@ -1394,6 +1426,8 @@ mod tests {
                 collaboration_modes_enabled: false,
                 is_wsl: false,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
                 context_window_percent: None,
                 context_window_used_tokens: None,
                 status_line_value: None,
@@ -1412,6 +1446,8 @@ mod tests {
                 collaboration_modes_enabled: false,
                 is_wsl: false,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
                 context_window_percent: None,
                 context_window_used_tokens: None,
                 status_line_value: None,
@@ -1430,6 +1466,8 @@ mod tests {
                 collaboration_modes_enabled: false,
                 is_wsl: false,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
                 context_window_percent: Some(72),
                 context_window_used_tokens: None,
                 status_line_value: None,
@@ -1448,6 +1486,8 @@ mod tests {
                 collaboration_modes_enabled: false,
                 is_wsl: false,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
                 context_window_percent: None,
                 context_window_used_tokens: Some(123_456),
                 status_line_value: None,
@@ -1466,6 +1506,8 @@ mod tests {
                 collaboration_modes_enabled: false,
                 is_wsl: false,
                 quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
                 context_window_percent: None,
                 context_window_used_tokens: None,
                 status_line_value: None,
@@ -1482,6 +1524,8 @@ mod tests {
             collaboration_modes_enabled: true,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: None,
             context_window_used_tokens: None,
             status_line_value: None,
@@ -1511,6 +1555,8 @@ mod tests {
             collaboration_modes_enabled: true,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: None,
             context_window_used_tokens: None,
             status_line_value: None,
@@ -1533,6 +1579,8 @@ mod tests {
             collaboration_modes_enabled: false,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: None,
             context_window_used_tokens: None,
             status_line_value: Some(Line::from("Status line content".to_string())),
@@ -1550,6 +1598,8 @@ mod tests {
             collaboration_modes_enabled: false,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: None,
             context_window_used_tokens: None,
             status_line_value: Some(Line::from("Status line content".to_string())),
@@ -1567,6 +1617,8 @@ mod tests {
             collaboration_modes_enabled: false,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: None,
             context_window_used_tokens: None,
             status_line_value: Some(Line::from("Status line content".to_string())),
@@ -1584,6 +1636,8 @@ mod tests {
             collaboration_modes_enabled: true,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: Some(50),
             context_window_used_tokens: None,
             status_line_value: None, // command timed out / empty
@@ -1606,6 +1660,8 @@ mod tests {
             collaboration_modes_enabled: true,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: Some(50),
             context_window_used_tokens: None,
             status_line_value: None,
@@ -1628,6 +1684,8 @@ mod tests {
             collaboration_modes_enabled: false,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: Some(50),
             context_window_used_tokens: None,
             status_line_value: None,
@@ -1651,6 +1709,8 @@ mod tests {
             collaboration_modes_enabled: true,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: Some(50),
             context_window_used_tokens: None,
             status_line_value: Some(Line::from(
@@ -1675,6 +1735,8 @@ mod tests {
             collaboration_modes_enabled: false,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: None,
             context_window_used_tokens: None,
             status_line_value: None,
@@ -1692,6 +1754,8 @@ mod tests {
             collaboration_modes_enabled: false,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: None,
             context_window_used_tokens: None,
             status_line_value: Some(Line::from("Status line content".to_string())),
@@ -1712,6 +1776,8 @@ mod tests {
             collaboration_modes_enabled: true,
             is_wsl: false,
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
+            external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+            show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             context_window_percent: Some(50),
             context_window_used_tokens: None,
             status_line_value: Some(Line::from(
@@ -1772,6 +1838,8 @@ mod tests {
                 esc_backtrack_hint: false,
                 is_wsl,
                 collaboration_modes_enabled: false,
+                external_editor_key: Some(key_hint::ctrl(KeyCode::Char('g'))),
+                show_transcript_key: Some(key_hint::ctrl(KeyCode::Char('t'))),
             })
             .expect("shortcut binding")
             .key;
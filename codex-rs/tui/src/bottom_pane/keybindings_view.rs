@@ -0,0 +1,345 @@
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use crossterm::event::KeyModifiers;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Constraint;
+use ratatui::layout::Layout;
+use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Block;
+use ratatui::widgets::Widget;
+
+use crate::app_event::AppEvent;
+use crate::app_event_sender::AppEventSender;
+use crate::key_hint;
+use crate::keymap::KeyChord;
+use crate::keymap::KeymapContext;
+use crate::keymap::TUI_KEYBINDING_ACTIONS;
+use crate::keymap::TuiKeymap;
+use crate::render::Insets;
+use crate::render::RectExt as _;
+use crate::render::renderable::ColumnRenderable;
+use crate::render::renderable::Renderable;
+use crate::style::user_message_style;
+
+use super::CancellationEvent;
+use super::bottom_pane_view::BottomPaneView;
+use super::popup_consts::MAX_POPUP_ROWS;
+use super::scroll_state::ScrollState;
+use super::selection_popup_common::GenericDisplayRow;
+use super::selection_popup_common::measure_rows_height;
+use super::selection_popup_common::render_rows;
+
+pub(crate) struct KeybindingItem {
+    pub context: KeymapContext,
+    pub action_name: &'static str,
+    pub label: &'static str,
+    /// Current binding in config-file spec form, or `None` when unbound.
+    pub binding: Option<String>,
+    /// Whether the user rebound this row in this session of the overlay.
+    pub changed: bool,
+}
+
+/// `/keybindings` overlay: every action from [`TUI_KEYBINDING_ACTIONS`]
+/// grouped by context, with the currently bound chords. Selecting a row and
+/// pressing `enter` arms capture mode; the next key pressed becomes the
+/// binding. Changes are saved to `config.toml` when the overlay closes.
+pub(crate) struct KeybindingsView {
+    items: Vec<KeybindingItem>,
+    state: ScrollState,
+    capturing: bool,
+    complete: bool,
+    app_event_tx: AppEventSender,
+    header: Box<dyn Renderable>,
+}
+
+impl KeybindingsView {
+    pub(crate) fn new(keymap: &TuiKeymap, app_event_tx: AppEventSender) -> Self {
+        let mut items = Vec::new();
+        for context in [KeymapContext::Composer, KeymapContext::Pager] {
+            for (action_name, action) in TUI_KEYBINDING_ACTIONS.iter().copied() {
+                let sequences = keymap.sequences_for(context, action);
+                let binding = if sequences.is_empty() {
+                    None
+                } else {
+                    Some(
+                        sequences
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    )
+                };
+                items.push(KeybindingItem {
+                    context,
+                    action_name,
+                    label: action.label(),
+                    binding,
+                    changed: false,
+                });
+            }
+        }
+
+        let mut header = ColumnRenderable::new();
+        header.push(Line::from("Keybindings".bold()));
+        header.push(Line::from(
+            "Rebind TUI shortcuts per context. Changes are saved to config.toml.".dim(),
+        ));
+
+        let mut view = Self {
+            items,
+            state: ScrollState::new(),
+            capturing: false,
+            complete: false,
+            app_event_tx,
+            header: Box::new(header),
+        };
+        view.initialize_selection();
+        view
+    }
+
+    fn initialize_selection(&mut self) {
+        if self.items.is_empty() {
+            self.state.selected_idx = None;
+        } else if self.state.selected_idx.is_none() {
+            self.state.selected_idx = Some(0);
+        }
+    }
+
+    fn build_rows(&self) -> Vec<GenericDisplayRow> {
+        let mut rows = Vec::with_capacity(self.items.len());
+        let selected_idx = self.state.selected_idx;
+        for (idx, item) in self.items.iter().enumerate() {
+            let prefix = if selected_idx == Some(idx) {
+                '›'
+            } else {
+                ' '
+            };
+            let binding = if self.capturing && selected_idx == Some(idx) {
+                "press the new key…".to_string()
+            } else {
+                match &item.binding {
+                    Some(binding) if item.changed => format!("{binding} (unsaved)"),
+                    Some(binding) => binding.clone(),
+                    None => "unbound".to_string(),
+                }
+            };
+            let name = format!(
+                "{prefix} {context} · {action}  {binding}",
+                context = item.context.config_key(),
+                action = item.action_name,
+            );
+            rows.push(GenericDisplayRow {
+                name,
+                description: Some(item.label.to_string()),
+                ..Default::default()
+            });
+        }
+
+        rows
+    }
+
+    fn move_up(&mut self) {
+        let len = self.items.len();
+        if len == 0 {
+            return;
+        }
+        self.state.move_up_wrap(len);
+        self.state.ensure_visible(len, MAX_POPUP_ROWS.min(len));
+    }
+
+    fn move_down(&mut self) {
+        let len = self.items.len();
+        if len == 0 {
+            return;
+        }
+        self.state.move_down_wrap(len);
+        self.state.ensure_visible(len, MAX_POPUP_ROWS.min(len));
+    }
+
+    fn capture_chord(&mut self, key_event: KeyEvent) {
+        self.capturing = false;
+        let Some(selected_idx) = self.state.selected_idx else {
+            return;
+        };
+        if let Some(item) = self.items.get_mut(selected_idx) {
+            let chord = KeyChord::from_key_event(&key_event);
+            item.binding = Some(chord.to_string());
+            item.changed = true;
+        }
+    }
+
+    fn rows_width(total_width: u16) -> u16 {
+        total_width.saturating_sub(2)
+    }
+
+    fn footer_hint(&self) -> Line<'static> {
+        if self.capturing {
+            Line::from(vec![
+                "Press the new key, or ".into(),
+                key_hint::plain(KeyCode::Esc).into(),
+                " to keep the current binding".into(),
+            ])
+        } else {
+            Line::from(vec![
+                "Press ".into(),
+                key_hint::plain(KeyCode::Enter).into(),
+                " to rebind or ".into(),
+                key_hint::plain(KeyCode::Esc).into(),
+                " to save and close".into(),
+            ])
+        }
+    }
+}
+
+impl BottomPaneView for KeybindingsView {
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if self.capturing {
+            match key_event.code {
+                KeyCode::Esc => self.capturing = false,
+                _ => self.capture_chord(key_event),
+            }
+            return;
+        }
+        match key_event {
+            KeyEvent {
+                code: KeyCode::Up, ..
+            }
+            | KeyEvent {
+                code: KeyCode::Char('p'),
+                modifiers: KeyModifiers::CONTROL,
+                ..
+            }
+            | KeyEvent {
+                code: KeyCode::Char('k'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => self.move_up(),
+            KeyEvent {
+                code: KeyCode::Down,
+                ..
+            }
+            | KeyEvent {
+                code: KeyCode::Char('n'),
+                modifiers: KeyModifiers::CONTROL,
+                ..
+            }
+            | KeyEvent {
+                code: KeyCode::Char('j'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => self.move_down(),
+            KeyEvent {
+                code: KeyCode::Enter,
+                modifiers: KeyModifiers::NONE,
+                ..
+            } => self.capturing = self.state.selected_idx.is_some(),
+            KeyEvent {
+                code: KeyCode::Esc, ..
+            } => {
+                self.on_ctrl_c();
+            }
+            _ => {}
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn on_ctrl_c(&mut self) -> CancellationEvent {
+        let updates: Vec<(KeymapContext, &'static str, String)> = self
+            .items
+            .iter()
+            .filter(|item| item.changed)
+            .filter_map(|item| {
+                item.binding
+                    .clone()
+                    .map(|binding| (item.context, item.action_name, binding))
+            })
+            .collect();
+        if !updates.is_empty() {
+            self.app_event_tx
+                .send(AppEvent::UpdateKeybindings { updates });
+        }
+
+        self.complete = true;
+        CancellationEvent::Handled
+    }
+}
+
+impl Renderable for KeybindingsView {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 || area.width == 0 {
+            return;
+        }
+
+        let [content_area, footer_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+
+        Block::default()
+            .style(user_message_style())
+            .render(content_area, buf);
+
+        let header_height = self
+            .header
+            .desired_height(content_area.width.saturating_sub(4));
+        let rows = self.build_rows();
+        let rows_width = Self::rows_width(content_area.width);
+        let rows_height = measure_rows_height(
+            &rows,
+            &self.state,
+            MAX_POPUP_ROWS,
+            rows_width.saturating_add(1),
+        );
+        let [header_area, _, list_area] = Layout::vertical([
+            Constraint::Max(header_height),
+            Constraint::Max(1),
+            Constraint::Length(rows_height),
+        ])
+        .areas(content_area.inset(Insets::vh(/*v*/ 1, /*h*/ 2)));
+
+        self.header.render(header_area, buf);
+
+        if list_area.height > 0 {
+            let render_area = Rect {
+                x: list_area.x.saturating_sub(2),
+                y: list_area.y,
+                width: rows_width.max(1),
+                height: list_area.height,
+            };
+            render_rows(
+                render_area,
+                buf,
+                &rows,
+                &self.state,
+                MAX_POPUP_ROWS,
+                "  No rebindable actions",
+            );
+        }
+
+        let hint_area = Rect {
+            x: footer_area.x + 2,
+            y: footer_area.y,
+            width: footer_area.width.saturating_sub(2),
+            height: footer_area.height,
+        };
+        self.footer_hint().dim().render(hint_area, buf);
+    }
+
+    fn desired_height(&self, width: u16) -> u16 {
+        let rows = self.build_rows();
+        let rows_width = Self::rows_width(width);
+        let rows_height = measure_rows_height(
+            &rows,
+            &self.state,
+            MAX_POPUP_ROWS,
+            rows_width.saturating_add(1),
+        );
+
+        let mut height = self.header.desired_height(width.saturating_sub(4));
+        height = height.saturating_add(rows_height + 3);
+        height.saturating_add(1)
+    }
+}
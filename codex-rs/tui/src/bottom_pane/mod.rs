@@ -26,8 +26,9 @@ use crate::key_hint::KeyBinding;
 use crate::render::renderable::FlexRenderable;
 use crate::render::renderable::Renderable;
 use crate::render::renderable::RenderableItem;
+use crate::spinner::SpinnerStyle;
 use crate::tui::FrameRequester;
-use bottom_pane_view::BottomPaneView;
+pub(crate) use bottom_pane_view::BottomPaneView;
 use bottom_pane_view::ViewCompletion;
 use codex_core_skills::model::SkillMetadata;
 use codex_features::Features;
@@ -82,6 +83,7 @@ pub(crate) mod custom_prompt_view;
 mod experimental_features_view;
 mod file_search_popup;
 mod footer;
+mod keybindings_view;
 mod list_selection_view;
 mod memories_settings_view;
 pub(crate) mod prompt_args;
@@ -160,6 +162,7 @@ use crate::status_indicator_widget::StatusDetailsCapitalization;
 use crate::status_indicator_widget::StatusIndicatorWidget;
 pub(crate) use experimental_features_view::ExperimentalFeatureItem;
 pub(crate) use experimental_features_view::ExperimentalFeaturesView;
+pub(crate) use keybindings_view::KeybindingsView;
 pub(crate) use list_selection_view::SelectionAction;
 pub(crate) use list_selection_view::SelectionItem;
 
@@ -185,6 +188,13 @@ pub(crate) struct BottomPane {
     is_task_running: bool,
     esc_backtrack_hint: bool,
     animations_enabled: bool,
+    /// Configured status-row spinner; `None` uses the shimmer bullet.
+    spinner_style: Option<SpinnerStyle>,
+    /// Delay before the status row shows the interrupt hint.
+    interrupt_hint_after: Duration,
+    /// Queued stream lines behind the smooth drain, when large enough to
+    /// surface in the status row.
+    stream_backlog_lines: Option<usize>,
 
     /// Inline status indicator shown above the composer while a task is running.
     status: Option<StatusIndicatorWidget>,
@@ -209,6 +219,7 @@ pub(crate) struct BottomPaneParams {
     pub(crate) placeholder_text: String,
     pub(crate) disable_paste_burst: bool,
     pub(crate) animations_enabled: bool,
+    pub(crate) spinner_style: Option<SpinnerStyle>,
     pub(crate) skills: Option<Vec<SkillMetadata>>,
 }
 
@@ -222,6 +233,7 @@ impl BottomPane {
             placeholder_text,
             disable_paste_burst,
             animations_enabled,
+            spinner_style,
             skills,
         } = params;
         let mut composer = ChatComposer::new(
@@ -248,6 +260,9 @@ impl BottomPane {
             pending_thread_approvals: PendingThreadApprovals::new(),
             esc_backtrack_hint: false,
             animations_enabled,
+            spinner_style,
+            interrupt_hint_after: Duration::ZERO,
+            stream_backlog_lines: None,
             context_window_percent: None,
             context_window_used_tokens: None,
         }
@@ -732,6 +747,18 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    /// Update the footer hints for rebindable shortcuts to match the live
+    /// keymap. `None` hides a hint whose action is unbound.
+    pub(crate) fn set_rebindable_shortcut_keys(
+        &mut self,
+        external_editor_key: Option<KeyBinding>,
+        show_transcript_key: Option<KeyBinding>,
+    ) {
+        self.composer
+            .set_rebindable_shortcut_keys(external_editor_key, show_transcript_key);
+        self.request_redraw();
+    }
+
     /// Clear the "press again to quit" hint immediately.
     pub(crate) fn clear_quit_shortcut_hint(&mut self) {
         self.composer.clear_quit_shortcut_hint(self.has_input_focus);
@@ -781,9 +808,11 @@ impl BottomPane {
                         self.app_event_tx.clone(),
                         self.frame_requester.clone(),
                         self.animations_enabled,
+                        self.spinner_style.clone(),
                     ));
                 }
                 if let Some(status) = self.status.as_mut() {
+                    status.set_interrupt_hint_delay(self.interrupt_hint_after);
                     status.set_interrupt_hint_visible(/*visible*/ true);
                 }
                 self.sync_status_inline_message();
@@ -795,6 +824,15 @@ impl BottomPane {
         }
     }
 
+    /// Sets how long a turn must run before the status row shows the
+    /// interrupt hint.
+    pub(crate) fn set_interrupt_hint_delay(&mut self, delay: Duration) {
+        self.interrupt_hint_after = delay;
+        if let Some(status) = self.status.as_mut() {
+            status.set_interrupt_hint_delay(delay);
+        }
+    }
+
     /// Hide the status indicator while leaving task-running state untouched.
     pub(crate) fn hide_status_indicator(&mut self) {
         if self.status.take().is_some() {
@@ -808,7 +846,11 @@ impl BottomPane {
                 self.app_event_tx.clone(),
                 self.frame_requester.clone(),
                 self.animations_enabled,
+                self.spinner_style.clone(),
             ));
+            if let Some(status) = self.status.as_mut() {
+                status.set_interrupt_hint_delay(self.interrupt_hint_after);
+            }
             self.sync_status_inline_message();
             self.request_redraw();
         }
@@ -916,8 +958,30 @@ impl BottomPane {
     /// standalone unified-exec footer row to be visible.
     fn sync_status_inline_message(&mut self) {
         if let Some(status) = self.status.as_mut() {
-            status.update_inline_message(self.unified_exec_footer.summary_text());
+            let mut parts: Vec<String> = Vec::new();
+            if let Some(summary) = self.unified_exec_footer.summary_text() {
+                parts.push(summary);
+            }
+            if let Some(lines) = self.stream_backlog_lines {
+                parts.push(format!("{lines} lines behind — ctrl + s to catch up"));
+            }
+            status.update_inline_message((!parts.is_empty()).then(|| parts.join(" · ")));
+        }
+    }
+
+    /// Updates the stream-backlog indicator mirrored into the status row.
+    pub(crate) fn set_stream_backlog_lines(&mut self, lines: Option<usize>) {
+        if self.stream_backlog_lines == lines {
+            return;
         }
+        self.stream_backlog_lines = lines;
+        self.sync_status_inline_message();
+        self.request_redraw();
+    }
+
+    /// Whether the status row is currently showing the stream-backlog hint.
+    pub(crate) fn stream_backlog_hint_visible(&self) -> bool {
+        self.stream_backlog_lines.is_some()
     }
 
     pub(crate) fn composer_is_empty(&self) -> bool {
@@ -1379,6 +1443,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         })
     }
@@ -1394,6 +1459,7 @@ mod tests {
                 codex_protocol::protocol::ReviewDecision::Approved,
                 codex_protocol::protocol::ReviewDecision::Abort,
             ],
+            cwd: None,
             network_approval_context: None,
             additional_permissions: None,
         }
@@ -1479,6 +1545,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: true,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
         pane.push_approval_request(exec_request(), &features);
@@ -1499,6 +1566,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: true,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
         pane.insert_str("draft");
@@ -1527,6 +1595,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1644,6 +1713,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1711,6 +1781,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1738,6 +1809,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1769,6 +1841,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1792,6 +1865,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1821,6 +1895,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1858,6 +1933,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1890,6 +1966,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1921,6 +1998,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1950,6 +2028,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -1973,6 +2052,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(vec![SkillMetadata {
                 name: "test-skill".to_string(),
                 description: "test skill".to_string(),
@@ -2020,6 +2100,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -2055,6 +2136,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -2091,6 +2173,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -2139,6 +2222,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -2195,6 +2279,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -2243,6 +2328,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -2321,6 +2407,7 @@ mod tests {
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
             animations_enabled: true,
+            spinner_style: None,
             skills: Some(Vec::new()),
         });
 
@@ -316,10 +316,14 @@ fn wrap_row_lines(row: &GenericDisplayRow, desc_col: usize, width: u16) -> Vec<L
 
 fn apply_row_state_style(lines: &mut [Line<'static>], selected: bool, is_disabled: bool) {
     if selected {
+        let selection_bg = crate::style::selection_bg();
         for line in lines.iter_mut() {
             line.spans.iter_mut().for_each(|span| {
                 span.style = Style::default().fg(Color::Cyan).bold();
             });
+            if let Some(bg) = selection_bg {
+                line.style = line.style.bg(bg);
+            }
         }
     }
     if is_disabled {
@@ -727,6 +731,9 @@ pub(crate) fn render_rows_single_line_with_col_width_mode(
             full_line.spans.iter_mut().for_each(|span| {
                 span.style = Style::default().fg(Color::Cyan).bold();
             });
+            if let Some(bg) = crate::style::selection_bg() {
+                full_line.style = full_line.style.bg(bg);
+            }
         }
         if row.is_disabled {
             full_line.spans.iter_mut().for_each(|span| {
@@ -78,6 +78,9 @@ pub(crate) enum StatusLineItem {
     /// Remaining usage on the weekly rate limit.
     WeeklyLimit,
 
+    /// Countdown until an active rate-limit cool-down ends.
+    RateLimitCooldown,
+
     /// Codex application version.
     CodexVersion,
 
@@ -101,6 +104,9 @@ pub(crate) enum StatusLineItem {
 
     /// Current thread title (if set by user).
     ThreadTitle,
+
+    /// Active permission preset (approval policy + sandbox).
+    ApprovalPreset,
 }
 
 impl StatusLineItem {
@@ -124,6 +130,9 @@ impl StatusLineItem {
             StatusLineItem::WeeklyLimit => {
                 "Remaining usage on weekly usage limit (omitted when unavailable)"
             }
+            StatusLineItem::RateLimitCooldown => {
+                "Countdown until the rate-limit window resets (omitted when inactive)"
+            }
             StatusLineItem::CodexVersion => "Codex application version",
             StatusLineItem::ContextWindowSize => {
                 "Total context window size in tokens (omitted when unknown)"
@@ -136,6 +145,7 @@ impl StatusLineItem {
             }
             StatusLineItem::FastMode => "Whether Fast mode is currently active",
             StatusLineItem::ThreadTitle => "Current thread title (omitted unless changed by user)",
+            StatusLineItem::ApprovalPreset => "Active permission preset",
         }
     }
 }
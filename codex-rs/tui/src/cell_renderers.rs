@@ -0,0 +1,318 @@
+//! Pluggable renderers for custom tool output.
+//!
+//! [`CellRenderer`] is the extension point: a renderer claims the output of
+//! specific MCP tools and turns it into a transcript [`HistoryCell`]
+//! (e.g. tabulating SQL result sets or summarizing JUnit XML). The built-in
+//! implementation is [`CommandRenderer`], discovered from
+//! `[[tui.cell_renderers]]` in config: it pipes the tool's text output
+//! through an external command and shows the command's stdout instead of the
+//! default rendering. Renderer commands run in short-lived subprocesses with
+//! a scrubbed environment, a hard timeout, and a capped output size; a
+//! renderer that fails or times out falls back to the default rendering.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::Context as _;
+use anyhow::Result;
+use anyhow::bail;
+use codex_ansi_escape::ansi_escape_line;
+use codex_protocol::mcp::CallToolResult;
+use ratatui::text::Line;
+
+use crate::history_cell::HistoryCell;
+use crate::history_cell::PlainHistoryCell;
+use crate::legacy_core::config::Config;
+
+/// Longest a renderer subprocess may run before it is killed.
+const RENDER_TIMEOUT: Duration = Duration::from_secs(2);
+/// Cap on renderer stdout; output beyond this is discarded.
+const MAX_OUTPUT_BYTES: usize = 64 * 1024;
+/// Cap on the payload piped to a renderer's stdin.
+const MAX_PAYLOAD_BYTES: usize = 256 * 1024;
+
+/// Turns the text output of a matching tool call into a transcript cell.
+pub(crate) trait CellRenderer: Send + Sync {
+    /// Name shown in logs when the renderer fails.
+    fn name(&self) -> &str;
+
+    /// Whether this renderer claims output from `server`'s `tool`.
+    fn matches(&self, server: &str, tool: &str) -> bool;
+
+    /// Renders the tool's text output. Errors fall back to the default
+    /// rendering.
+    fn render(&self, payload: &str) -> Result<Box<dyn HistoryCell>>;
+}
+
+/// Ordered set of renderers; the first match wins.
+#[derive(Default)]
+pub(crate) struct CellRendererRegistry {
+    renderers: Vec<Box<dyn CellRenderer>>,
+}
+
+impl CellRendererRegistry {
+    /// Builds the registry from `[[tui.cell_renderers]]`. Malformed entries
+    /// are skipped with a warning rather than failing startup.
+    pub(crate) fn from_config(config: &Config) -> Self {
+        let mut registry = Self::default();
+        for entry in &config.tui_cell_renderers {
+            if entry.command.is_empty() || entry.tools.is_empty() {
+                tracing::warn!(
+                    "ignoring cell renderer {:?}: command and tools must both be set",
+                    entry.name
+                );
+                continue;
+            }
+            registry.register(Box::new(CommandRenderer {
+                name: entry.name.clone(),
+                command: entry.command.clone(),
+                tools: entry.tools.clone(),
+            }));
+        }
+        registry
+    }
+
+    pub(crate) fn register(&mut self, renderer: Box<dyn CellRenderer>) {
+        self.renderers.push(renderer);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.renderers.is_empty()
+    }
+
+    /// Renders a successful tool result through the first matching renderer.
+    /// Returns `None` — keeping the default rendering — when no renderer
+    /// matches, the result has no text content, or the renderer fails.
+    pub(crate) fn render_tool_result(
+        &self,
+        server: &str,
+        tool: &str,
+        result: &CallToolResult,
+    ) -> Option<Box<dyn HistoryCell>> {
+        if self.is_empty() || result.is_error.unwrap_or(false) {
+            return None;
+        }
+        let renderer = self
+            .renderers
+            .iter()
+            .find(|renderer| renderer.matches(server, tool))?;
+        let payload = text_payload(result)?;
+        match renderer.render(&payload) {
+            Ok(cell) => Some(cell),
+            Err(err) => {
+                tracing::warn!(
+                    "cell renderer {:?} failed for {server}.{tool}: {err:#}",
+                    renderer.name()
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Joins the text content blocks of a tool result; `None` when there are
+/// none or the combined payload is too large to pipe safely.
+fn text_payload(result: &CallToolResult) -> Option<String> {
+    let texts: Vec<&str> = result
+        .content
+        .iter()
+        .filter_map(|block| block.get("text").and_then(serde_json::Value::as_str))
+        .collect();
+    if texts.is_empty() {
+        return None;
+    }
+    let payload = texts.join("\n");
+    (payload.len() <= MAX_PAYLOAD_BYTES).then_some(payload)
+}
+
+/// Built-in renderer that shells out to a user-configured command.
+struct CommandRenderer {
+    name: String,
+    command: Vec<String>,
+    tools: Vec<String>,
+}
+
+impl CellRenderer for CommandRenderer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn matches(&self, server: &str, tool: &str) -> bool {
+        self.tools
+            .iter()
+            .any(|entry| entry == tool || *entry == format!("{server}.{tool}"))
+    }
+
+    fn render(&self, payload: &str) -> Result<Box<dyn HistoryCell>> {
+        let stdout = run_renderer_command(&self.command, payload)?;
+        let lines: Vec<Line<'static>> = stdout.lines().map(ansi_escape_line).collect();
+        if lines.is_empty() {
+            bail!("renderer produced no output");
+        }
+        Ok(Box::new(PlainHistoryCell::new(lines)))
+    }
+}
+
+/// Runs `command` with `payload` on stdin and returns its stdout. The child
+/// gets a scrubbed environment (only `PATH`, `HOME`, and `TERM` survive), is
+/// killed after [`RENDER_TIMEOUT`], and stdout is capped at
+/// [`MAX_OUTPUT_BYTES`].
+fn run_renderer_command(command: &[String], payload: &str) -> Result<String> {
+    use std::io::Read;
+    use std::io::Write;
+    use std::process::Command;
+    use std::process::Stdio;
+
+    let (program, args) = command.split_first().context("empty renderer command")?;
+    let mut builder = Command::new(program);
+    builder
+        .args(args)
+        .env_clear()
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null());
+    for key in ["PATH", "HOME", "TERM"] {
+        if let Ok(value) = std::env::var(key) {
+            builder.env(key, value);
+        }
+    }
+    let mut child = builder
+        .spawn()
+        .with_context(|| format!("failed to spawn {program}"))?;
+
+    let mut stdin = child.stdin.take().context("renderer stdin unavailable")?;
+    let payload = payload.to_string();
+    // Writer and reader run on their own threads so a child that fills its
+    // pipe buffers cannot deadlock against us.
+    let writer = std::thread::spawn(move || {
+        let _ = stdin.write_all(payload.as_bytes());
+    });
+    let mut stdout = child.stdout.take().context("renderer stdout unavailable")?;
+    let reader = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stdout
+            .by_ref()
+            .take(MAX_OUTPUT_BYTES as u64)
+            .read_to_end(&mut buf);
+        // Drain the rest so the child is not blocked on a full pipe.
+        let _ = std::io::copy(&mut stdout, &mut std::io::sink());
+        buf
+    });
+
+    let deadline = Instant::now() + RENDER_TIMEOUT;
+    let status = loop {
+        match child.try_wait()? {
+            Some(status) => break status,
+            None if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                let _ = writer.join();
+                let _ = reader.join();
+                bail!("renderer timed out after {RENDER_TIMEOUT:?}");
+            }
+            None => std::thread::sleep(Duration::from_millis(10)),
+        }
+    };
+    let _ = writer.join();
+    let stdout = reader.join().unwrap_or_default();
+    if !status.success() {
+        bail!("renderer exited with {status}");
+    }
+    Ok(String::from_utf8_lossy(&stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn text_result(text: &str) -> CallToolResult {
+        CallToolResult {
+            content: vec![serde_json::json!({"type": "text", "text": text})],
+            structured_content: None,
+            is_error: None,
+            meta: None,
+        }
+    }
+
+    #[test]
+    fn matching_honors_bare_and_qualified_tool_names() {
+        let renderer = CommandRenderer {
+            name: "sql".to_string(),
+            command: vec!["cat".to_string()],
+            tools: vec!["query".to_string(), "db.explain".to_string()],
+        };
+        assert!(renderer.matches("db", "query"));
+        assert!(renderer.matches("other", "query"));
+        assert!(renderer.matches("db", "explain"));
+        assert!(!renderer.matches("other", "explain"));
+    }
+
+    #[test]
+    fn text_payload_joins_text_blocks_only() {
+        let mut result = text_result("one");
+        result
+            .content
+            .push(serde_json::json!({"type": "image", "data": "..."}));
+        result
+            .content
+            .push(serde_json::json!({"type": "text", "text": "two"}));
+        assert_eq!(text_payload(&result), Some("one\ntwo".to_string()));
+        assert_eq!(text_payload(&text_result("")), Some(String::new()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn command_renderer_pipes_payload_through_the_command() {
+        let mut registry = CellRendererRegistry::default();
+        registry.register(Box::new(CommandRenderer {
+            name: "upper".to_string(),
+            command: vec!["sh".to_string(), "-c".to_string(), "tr a-z A-Z".to_string()],
+            tools: vec!["query".to_string()],
+        }));
+        let cell = registry
+            .render_tool_result("db", "query", &text_result("select 1"))
+            .expect("rendered cell");
+        let lines = cell.display_lines(80);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].to_string(), "SELECT 1");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failing_renderers_fall_back_to_default_rendering() {
+        let mut registry = CellRendererRegistry::default();
+        registry.register(Box::new(CommandRenderer {
+            name: "broken".to_string(),
+            command: vec!["false".to_string()],
+            tools: vec!["query".to_string()],
+        }));
+        assert!(
+            registry
+                .render_tool_result("db", "query", &text_result("select 1"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn error_results_and_unmatched_tools_are_ignored() {
+        let mut registry = CellRendererRegistry::default();
+        registry.register(Box::new(CommandRenderer {
+            name: "sql".to_string(),
+            command: vec!["cat".to_string()],
+            tools: vec!["query".to_string()],
+        }));
+        let mut error_result = text_result("boom");
+        error_result.is_error = Some(true);
+        assert!(
+            registry
+                .render_tool_result("db", "query", &error_result)
+                .is_none()
+        );
+        assert!(
+            registry
+                .render_tool_result("db", "other", &text_result("x"))
+                .is_none()
+        );
+    }
+}
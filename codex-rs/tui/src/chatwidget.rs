@@ -42,6 +42,8 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
 use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use self::realtime::PendingSteerCompareKey;
 use crate::app::app_server_requests::ResolvedAppServerRequest;
@@ -56,6 +58,7 @@ use crate::bottom_pane::StatusLinePreviewData;
 use crate::bottom_pane::StatusLineSetupView;
 use crate::bottom_pane::TerminalTitleItem;
 use crate::bottom_pane::TerminalTitleSetupView;
+use crate::citations::Citation;
 use crate::legacy_core::DEFAULT_AGENTS_MD_FILENAME;
 use crate::legacy_core::config::Config;
 use crate::legacy_core::config::Constrained;
@@ -66,6 +69,7 @@ use crate::mention_codec::LinkedMention;
 use crate::mention_codec::encode_history_mentions;
 use crate::model_catalog::ModelCatalog;
 use crate::multi_agents;
+use crate::spinner::SpinnerStyle;
 use crate::status::RateLimitWindowDisplay;
 use crate::status::StatusAccountDisplay;
 use crate::status::StatusHistoryHandle;
@@ -108,6 +112,7 @@ use codex_app_server_protocol::TurnStatus;
 use codex_chatgpt::connectors;
 use codex_config::ConfigLayerStackOrdering;
 use codex_config::types::ApprovalsReviewer;
+use codex_config::types::BellStyle;
 use codex_config::types::Notifications;
 use codex_config::types::WindowsSandboxModeToml;
 use codex_core_skills::model::SkillMetadata;
@@ -129,6 +134,7 @@ use codex_protocol::config_types::CollaborationMode;
 use codex_protocol::config_types::CollaborationModeMask;
 use codex_protocol::config_types::ModeKind;
 use codex_protocol::config_types::Personality;
+use codex_protocol::config_types::SandboxMode;
 use codex_protocol::config_types::ServiceTier;
 use codex_protocol::config_types::Settings;
 #[cfg(target_os = "windows")]
@@ -210,6 +216,7 @@ use codex_protocol::protocol::TurnAbortReason;
 use codex_protocol::protocol::TurnCompleteEvent;
 #[cfg(test)]
 use codex_protocol::protocol::TurnDiffEvent;
+use codex_protocol::protocol::TurnRequestParams;
 #[cfg(test)]
 use codex_protocol::protocol::UndoCompletedEvent;
 #[cfg(test)]
@@ -251,6 +258,8 @@ use tracing::debug;
 use tracing::warn;
 
 const DEFAULT_MODEL_DISPLAY_NAME: &str = "loading";
+/// How many recent user prompts `/suggest-commit` keeps for its summary heuristic.
+const MAX_RECENT_USER_PROMPTS: usize = 5;
 const MULTI_AGENT_ENABLE_TITLE: &str = "Enable subagents?";
 const MULTI_AGENT_ENABLE_YES: &str = "Yes, enable";
 const MULTI_AGENT_ENABLE_NO: &str = "Not now";
@@ -265,6 +274,8 @@ const PLAN_MODE_REASONING_SCOPE_PLAN_ONLY: &str = "Apply to Plan mode override";
 const PLAN_MODE_REASONING_SCOPE_ALL_MODES: &str = "Apply to global default and Plan mode override";
 const CONNECTORS_SELECTION_VIEW_ID: &str = "connectors-selection";
 const TUI_STUB_MESSAGE: &str = "Not available in TUI yet.";
+/// Queued stream lines before the status row calls out the drain backlog.
+const STREAM_BACKLOG_HINT_MIN_LINES: usize = 32;
 
 /// Choose the keybinding used to edit the most-recently queued message.
 ///
@@ -318,6 +329,7 @@ use crate::bottom_pane::DOUBLE_PRESS_QUIT_SHORTCUT_ENABLED;
 use crate::bottom_pane::ExperimentalFeatureItem;
 use crate::bottom_pane::ExperimentalFeaturesView;
 use crate::bottom_pane::InputResult;
+use crate::bottom_pane::KeybindingsView;
 use crate::bottom_pane::LocalImageAttachment;
 use crate::bottom_pane::McpServerElicitationFormRequest;
 use crate::bottom_pane::MemoriesSettingsView;
@@ -378,6 +390,18 @@ use self::plan_implementation::PLAN_IMPLEMENTATION_TITLE;
 mod realtime;
 use self::realtime::RealtimeConversationUiState;
 use self::realtime::RenderedUserMessageEvent;
+mod batch;
+use self::batch::BatchState;
+mod auto_title;
+mod citation_view;
+mod context_refresh;
+mod error_actions;
+mod turn_summary;
+use self::turn_summary::TurnActivity;
+mod env_overrides;
+use self::env_overrides::EnvOverridesState;
+mod pins;
+use self::pins::PinnedItem;
 mod side;
 mod status_surfaces;
 use self::status_surfaces::CachedProjectRootName;
@@ -405,7 +429,8 @@ use unicode_segmentation::UnicodeSegmentation;
 const USER_SHELL_COMMAND_HELP_TITLE: &str = "Prefix a command with ! to run it locally";
 const USER_SHELL_COMMAND_HELP_HINT: &str = "Example: !ls";
 const DEFAULT_OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
-const DEFAULT_STATUS_LINE_ITEMS: [&str; 2] = ["model-with-reasoning", "current-dir"];
+const DEFAULT_STATUS_LINE_ITEMS: [&str; 3] =
+    ["model-with-reasoning", "current-dir", "approval-preset"];
 // Track information about an in-flight exec command.
 struct RunningCommand {
     command: Vec<String>,
@@ -561,6 +586,78 @@ pub(crate) fn get_limits_duration(windows_minutes: i64) -> String {
     }
 }
 
+/// Earliest future reset timestamp across the snapshot's rate-limit windows.
+fn earliest_future_rate_limit_reset(snapshot: &RateLimitSnapshot) -> Option<SystemTime> {
+    let now = SystemTime::now();
+    [snapshot.primary.as_ref(), snapshot.secondary.as_ref()]
+        .into_iter()
+        .flatten()
+        .filter_map(|window| window.resets_at)
+        .filter(|seconds| *seconds > 0)
+        .map(|seconds| UNIX_EPOCH + Duration::from_secs(seconds as u64))
+        .filter(|reset_at| *reset_at > now)
+        .min()
+}
+
+/// Compact countdown label for the rate-limit cool-down ("2h 5m", "3m 20s", "45s").
+fn format_cooldown_remaining(remaining: Duration) -> String {
+    let total_seconds = remaining.as_secs();
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+    if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+/// Window granted by a bare `/auto` with no explicit duration.
+const DEFAULT_AUTO_MODE_DURATION: Duration = Duration::from_secs(15 * 60);
+
+/// Parse a `/auto` duration argument like "15m", "90s" or "2h"; a bare number
+/// is treated as minutes.
+fn parse_auto_duration(arg: &str) -> Option<Duration> {
+    let arg = arg.trim();
+    let (value, unit) = match arg.find(|c: char| !c.is_ascii_digit()) {
+        Some(0) => return None,
+        Some(split) => arg.split_at(split),
+        None => (arg, "m"),
+    };
+    let value: u64 = value.parse().ok()?;
+    if value == 0 {
+        return None;
+    }
+    let seconds = match unit {
+        "s" => value,
+        "m" => value.checked_mul(60)?,
+        "h" => value.checked_mul(3600)?,
+        _ => return None,
+    };
+    Some(Duration::from_secs(seconds))
+}
+
+/// Human-readable label for a `/auto` grant window ("15m", "1h 30m", "90s").
+fn format_auto_duration(duration: Duration) -> String {
+    let total_seconds = duration.as_secs();
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+    let mut parts = Vec::new();
+    if hours > 0 {
+        parts.push(format!("{hours}h"));
+    }
+    if minutes > 0 {
+        parts.push(format!("{minutes}m"));
+    }
+    if seconds > 0 || parts.is_empty() {
+        parts.push(format!("{seconds}s"));
+    }
+    parts.join(" ")
+}
+
 /// Common initialization parameters shared by all `ChatWidget` constructors.
 pub(crate) struct ChatWidgetInit {
     pub(crate) config: Config,
@@ -802,6 +899,23 @@ pub(crate) struct ChatWidget {
     /// may still return the response from before the rollback. Keeping this as
     /// a single cache avoids coupling copy state to the backtrack transcript.
     last_agent_markdown: Option<String>,
+    last_agent_citations: Vec<Citation>,
+    /// Message of the most recent turn-ending error, for the error tray's
+    /// copy action.
+    last_turn_error: Option<String>,
+    /// The most recently submitted user message, for the error tray's retry
+    /// action.
+    last_submitted_user_message: Option<UserMessage>,
+    /// Whether the auto-title heuristic already ran for this session.
+    thread_auto_title_attempted: bool,
+    /// Skip the rename confirmation cell for the next name update (set when
+    /// the name came from the auto-title heuristic, not the user).
+    suppress_rename_confirmation: bool,
+    /// Renderers for custom tool output, from `[[tui.cell_renderers]]`.
+    cell_renderers: crate::cell_renderers::CellRendererRegistry,
+    /// One-time contextual tips; see [`crate::tips`].
+    tips: crate::tips::TipTracker,
+    turn_activity: TurnActivity,
     /// Raw markdown of the most recently completed proposed plan.
     ///
     /// This is cached only for the approval popup. It is reset at the start of each new task so the
@@ -879,6 +993,10 @@ pub(crate) struct ChatWidget {
     suppress_queue_autosend: bool,
     thread_id: Option<ThreadId>,
     last_turn_id: Option<String>,
+    /// Request parameters from the most recent completed turn, shown by `/details`.
+    last_turn_request_params: Option<TurnRequestParams>,
+    /// Recent user prompts, oldest first; feeds the `/suggest-commit` summary.
+    recent_user_prompts: Vec<String>,
     thread_name: Option<String>,
     thread_rename_block_message: Option<String>,
     active_side_conversation: bool,
@@ -900,6 +1018,15 @@ pub(crate) struct ChatWidget {
     suppress_initial_user_message_submit: bool,
     // User inputs queued while a turn is in progress.
     queued_user_messages: VecDeque<QueuedUserMessage>,
+    // Permissions to restore when `/readonly` is toggled off: the (approval
+    // policy, sandbox policy) pair that was active before read-only mode.
+    read_only_restore: Option<(AskForApproval, SandboxPolicy)>,
+    // Approval policy to restore when a time-boxed `/auto` grant ends, plus
+    // the generation its expiry timer was armed with.
+    auto_mode_revert: Option<(AskForApproval, u64)>,
+    // Bumped whenever `/auto` is (re-)armed so stale expiry timers are
+    // ignored.
+    auto_mode_generation: u64,
     // A user turn has been submitted to core, but `TurnStarted` has not arrived yet.
     user_turn_pending_start: bool,
     // User messages that tried to steer a non-regular turn and must be retried first.
@@ -988,6 +1115,18 @@ pub(crate) struct ChatWidget {
     terminal_title_setup_original_items: Option<Option<Vec<String>>>,
     // Baseline instant used to animate spinner-prefixed title statuses.
     terminal_title_animation_origin: Instant,
+    // When the widget was created, for `/limits` wall-clock reporting.
+    session_start_time: Instant,
+    // Deadline of an active rate-limit cool-down; submissions queue until then.
+    rate_limit_cooldown_until: Option<SystemTime>,
+    // Active `/batch` run, if any.
+    batch: Option<BatchState>,
+    // Items re-sent with every turn via `/pin`.
+    pinned_context: Vec<PinnedItem>,
+    // Session `/env` overrides and transcript-redacted names.
+    env_overrides: EnvOverridesState,
+    // Last-sent content of files the model has seen, for stale detection.
+    sent_file_versions: HashMap<PathBuf, String>,
     // Cached project-root display name keyed by cwd for status/title rendering.
     status_line_project_root_name_cache: Option<CachedProjectRootName>,
     // Cached git branch name for the status line (None if unknown).
@@ -2046,6 +2185,7 @@ impl ChatWidget {
             return;
         }
         self.last_agent_markdown = Some(message.to_string());
+        self.last_agent_citations = crate::citations::strip_citations(message).1;
         self.saw_copy_source_this_turn = true;
     }
 
@@ -2067,6 +2207,8 @@ impl ChatWidget {
         self.session_network_proxy = event.network_proxy.clone();
         self.thread_id = Some(event.session_id);
         self.last_turn_id = None;
+        self.last_turn_request_params = None;
+        self.recent_user_prompts.clear();
         self.thread_name = event.thread_name.clone();
         self.forked_from = event.forked_from_id;
         self.current_rollout_path = event.rollout_path.clone();
@@ -2209,7 +2351,9 @@ impl ChatWidget {
 
     fn on_thread_name_updated(&mut self, event: codex_protocol::protocol::ThreadNameUpdatedEvent) {
         if self.thread_id == Some(event.thread_id) {
-            if let Some(name) = event.thread_name.as_deref() {
+            if let Some(name) = event.thread_name.as_deref()
+                && !std::mem::take(&mut self.suppress_rename_confirmation)
+            {
                 let cell = Self::rename_confirmation_cell(name, self.thread_id);
                 self.add_boxed_history(Box::new(cell));
             }
@@ -2424,6 +2568,7 @@ impl ChatWidget {
         self.adaptive_chunking.reset();
         self.plan_stream_controller = None;
         self.turn_runtime_metrics = RuntimeMetricsSummary::default();
+        self.turn_activity = TurnActivity::default();
         self.session_telemetry.reset_runtime_metrics();
         self.bottom_pane.clear_quit_shortcut_hint();
         self.quit_shortcut_expires_at = None;
@@ -2480,6 +2625,11 @@ impl ChatWidget {
         }
         self.flush_unified_exec_wait_streak();
         if !from_replay {
+            self.maybe_show_tip(crate::tips::Tip::TranscriptOverlay);
+            // Ledger first, then the separator that closes out the turn.
+            if let Some(cell) = self.turn_activity_summary_cell() {
+                self.add_to_history(cell);
+            }
             self.collect_runtime_metrics_delta();
             let runtime_metrics =
                 (!self.turn_runtime_metrics.is_empty()).then_some(self.turn_runtime_metrics);
@@ -2527,8 +2677,14 @@ impl ChatWidget {
         if !from_replay {
             self.saw_plan_item_this_turn = false;
         }
+        // Advance an active /batch run before draining the regular queue.
+        self.maybe_advance_batch();
         // If there is a queued user message, send exactly one now to begin the next turn.
         self.maybe_send_next_queued_input();
+        // Name the thread from its first exchange unless the user already did.
+        if !from_replay {
+            self.maybe_auto_generate_thread_title();
+        }
         // Emit a notification when the turn completes (suppressed if focused).
         self.notify(Notification::AgentTurnComplete {
             response: notification_response,
@@ -2847,6 +3003,13 @@ impl ChatWidget {
                 && let Some(rate_limit_reached_type) = snapshot.rate_limit_reached_type
             {
                 self.codex_rate_limit_reached_type = Some(rate_limit_reached_type);
+                if matches!(
+                    rate_limit_reached_type,
+                    RateLimitReachedType::RateLimitReached
+                ) && let Some(reset_at) = earliest_future_rate_limit_reset(&snapshot)
+                {
+                    self.begin_rate_limit_cooldown(reset_at);
+                }
             }
             let warnings = if is_codex_limit {
                 self.rate_limit_warnings.take_warnings(
@@ -2915,11 +3078,74 @@ impl ChatWidget {
         }
         self.refresh_status_line();
     }
+    /// Starts (or extends) a rate-limit cool-down ending at `reset_at` and
+    /// schedules the auto-send wakeup for queued messages.
+    fn begin_rate_limit_cooldown(&mut self, reset_at: SystemTime) {
+        if self.rate_limit_cooldown_until == Some(reset_at) {
+            return;
+        }
+        self.rate_limit_cooldown_until = Some(reset_at);
+        let Some(delay) = self.rate_limit_cooldown_remaining() else {
+            self.rate_limit_cooldown_until = None;
+            return;
+        };
+        let tx = self.app_event_tx.clone();
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move {
+                tokio::time::sleep(delay).await;
+                tx.send(AppEvent::RateLimitCooldownElapsed);
+            });
+        } else {
+            // In tests (and other non-Tokio contexts), fall back to a thread so
+            // queued messages still auto-send.
+            std::thread::spawn(move || {
+                std::thread::sleep(delay);
+                tx.send(AppEvent::RateLimitCooldownElapsed);
+            });
+        }
+        self.refresh_status_line();
+        self.request_redraw();
+    }
+
+    fn rate_limit_cooldown_remaining(&self) -> Option<Duration> {
+        let deadline = self.rate_limit_cooldown_until?;
+        deadline
+            .duration_since(SystemTime::now())
+            .ok()
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    fn rate_limit_cooldown_active(&self) -> bool {
+        self.rate_limit_cooldown_remaining().is_some()
+    }
+
+    /// Clears the cool-down and auto-sends any messages queued while waiting.
+    pub(crate) fn on_rate_limit_cooldown_elapsed(&mut self) {
+        if self.rate_limit_cooldown_until.is_none() {
+            return;
+        }
+        if self.rate_limit_cooldown_active() {
+            // A newer snapshot extended the window; the later wakeup handles it.
+            return;
+        }
+        self.rate_limit_cooldown_until = None;
+        if self.has_queued_follow_up_messages() {
+            self.add_info_message(
+                "Rate limit window reset — sending queued messages.".to_string(),
+                None,
+            );
+            self.maybe_send_next_queued_input();
+        }
+        self.refresh_status_line();
+        self.request_redraw();
+    }
+
     /// Finalize any active exec as failed and stop/clear agent-turn UI state.
     ///
     /// This does not clear MCP startup tracking, because MCP startup can overlap with turn cleanup
     /// and should continue to drive the bottom-pane running indicator while it is in progress.
     fn finalize_turn(&mut self) {
+        self.bottom_pane.set_stream_backlog_lines(None);
         // Ensure any spinner is replaced by a red ✗ and flushed into history.
         self.finalize_active_cell_as_failed();
         // Reset running state and clear streaming buffers.
@@ -2958,7 +3184,13 @@ impl ChatWidget {
     fn on_error(&mut self, message: String) {
         self.submit_pending_steers_after_interrupt = false;
         self.finalize_turn();
+        self.last_turn_error = Some(message.clone());
         self.add_to_history(history_cell::new_error_event(message));
+        // Offer follow-up actions unless a queued message is about to start
+        // the next turn anyway.
+        if self.queued_user_messages.is_empty() {
+            self.open_error_actions_tray();
+        }
         self.request_redraw();
 
         // After an error ends the turn, try sending the next queued input.
@@ -3836,6 +4068,11 @@ impl ChatWidget {
     }
 
     fn on_patch_apply_begin(&mut self, event: PatchApplyBeginEvent) {
+        if self.bottom_pane.is_task_running() {
+            self.set_status_header(String::from("Applying patch"));
+        }
+        self.turn_activity
+            .note_patch_begin(&event.call_id, &event.changes);
         self.add_to_history(history_cell::new_patch_event(
             event.changes,
             &self.config.cwd,
@@ -4506,6 +4743,50 @@ impl ChatWidget {
         if self.agent_turn_running {
             self.refresh_runtime_metrics();
         }
+        self.update_stream_backlog_hint();
+    }
+
+    /// Mirrors the queued-line backlog into the status row once the model is
+    /// far enough ahead of the smooth drain to be worth calling out.
+    fn update_stream_backlog_hint(&mut self) {
+        let queued = self
+            .stream_controller
+            .as_ref()
+            .map(StreamController::queued_lines)
+            .unwrap_or(0)
+            + self
+                .plan_stream_controller
+                .as_ref()
+                .map(PlanStreamController::queued_lines)
+                .unwrap_or(0);
+        let backlog = (queued >= STREAM_BACKLOG_HINT_MIN_LINES).then_some(queued);
+        self.bottom_pane.set_stream_backlog_lines(backlog);
+    }
+
+    /// Drains every queued stream line immediately, bypassing smooth pacing.
+    pub(crate) fn catch_up_stream_backlog(&mut self) {
+        let mut cells: Vec<Box<dyn HistoryCell>> = Vec::new();
+        if let Some(controller) = self.stream_controller.as_mut() {
+            let queued = controller.queued_lines();
+            if queued > 0
+                && let (Some(cell), _) = controller.on_commit_tick_batch(queued)
+            {
+                cells.push(cell);
+            }
+        }
+        if let Some(controller) = self.plan_stream_controller.as_mut() {
+            let queued = controller.queued_lines();
+            if queued > 0
+                && let (Some(cell), _) = controller.on_commit_tick_batch(queued)
+            {
+                cells.push(cell);
+            }
+        }
+        for cell in cells {
+            self.bottom_pane.hide_status_indicator();
+            self.add_boxed_history(cell);
+        }
+        self.update_stream_backlog_hint();
     }
 
     fn flush_interrupt_queue(&mut self) {
@@ -4620,6 +4901,10 @@ impl ChatWidget {
         let is_unified_exec_interaction =
             matches!(source, ExecCommandSource::UnifiedExecInteraction);
         let is_user_shell = source == ExecCommandSource::UserShell;
+        if !is_user_shell {
+            self.turn_activity
+                .note_exec_end(&strip_bash_lc_and_escape(&command), ev.exit_code);
+        }
         let end_target = match self.active_cell.as_ref() {
             Some(cell) => match cell.as_any().downcast_ref::<ExecCell>() {
                 Some(exec_cell)
@@ -4722,6 +5007,8 @@ impl ChatWidget {
         &mut self,
         event: codex_protocol::protocol::PatchApplyEndEvent,
     ) {
+        self.turn_activity
+            .note_patch_end(&event.call_id, event.success);
         // If the patch was successful, just let the "Edited" block stand.
         // Otherwise, add a failure block.
         if !event.success {
@@ -4743,6 +5030,7 @@ impl ChatWidget {
             thread_label: None,
             id: ev.effective_approval_id(),
             command: ev.command,
+            cwd: Some(ev.cwd),
             reason: ev.reason,
             available_decisions,
             network_approval_context: ev.network_approval_context,
@@ -4844,6 +5132,9 @@ impl ChatWidget {
     pub(crate) fn handle_exec_begin_now(&mut self, ev: ExecCommandBeginEvent) {
         // Ensure the status indicator is visible while the command runs.
         self.bottom_pane.ensure_status_indicator();
+        if ev.source == ExecCommandSource::Agent {
+            self.set_status_header(String::from("Running command"));
+        }
         let parsed_cmd = self.annotate_skill_reads_in_parsed_cmd(ev.parsed_cmd.clone());
         self.running_commands.insert(
             ev.call_id.clone(),
@@ -4928,6 +5219,15 @@ impl ChatWidget {
             ..
         } = ev;
 
+        let custom_cell = match &result {
+            Ok(call_result) => self.cell_renderers.render_tool_result(
+                &invocation.server,
+                &invocation.tool,
+                call_result,
+            ),
+            Err(_) => None,
+        };
+
         let extra_cell = match self
             .active_cell
             .as_mut()
@@ -4951,6 +5251,9 @@ impl ChatWidget {
         if let Some(extra) = extra_cell {
             self.add_boxed_history(extra);
         }
+        if let Some(custom) = custom_cell {
+            self.add_boxed_history(custom);
+        }
         // Mark that actual work was done (MCP tool call)
         self.had_work_activity = true;
     }
@@ -4987,6 +5290,19 @@ impl ChatWidget {
         let side_placeholder =
             SIDE_PLACEHOLDERS[rng.random_range(0..SIDE_PLACEHOLDERS.len())].to_string();
 
+        // Template pins behave exactly like `/pin <file>` pins made by hand.
+        let template_pinned_context: Vec<PinnedItem> = config
+            .active_template
+            .as_ref()
+            .map(|template| {
+                template
+                    .pinned_files
+                    .iter()
+                    .map(|path| PinnedItem::File(config.cwd.as_path().join(path)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
         let model_override = model.as_deref();
         let model_for_header = model
             .clone()
@@ -5024,10 +5340,16 @@ impl ChatWidget {
                 placeholder_text: placeholder.clone(),
                 disable_paste_burst: config.disable_paste_burst,
                 animations_enabled: config.animations,
+                spinner_style: SpinnerStyle::from_config(config.tui_spinner.as_ref()),
                 skills: None,
             }),
             active_cell,
             active_cell_revision: 0,
+            cell_renderers: crate::cell_renderers::CellRendererRegistry::from_config(&config),
+            tips: crate::tips::TipTracker::new(
+                config.tui_tips.unwrap_or(true),
+                &config.notices.seen_tips,
+            ),
             config,
             skills_all: Vec::new(),
             skills_initial_state: None,
@@ -5064,6 +5386,12 @@ impl ChatWidget {
             agent_turn_running: false,
             mcp_startup_status: None,
             last_agent_markdown: None,
+            last_agent_citations: Vec::new(),
+            last_turn_error: None,
+            last_submitted_user_message: None,
+            thread_auto_title_attempted: false,
+            suppress_rename_confirmation: false,
+            turn_activity: TurnActivity::default(),
             latest_proposed_plan_markdown: None,
             saw_copy_source_this_turn: false,
             mcp_startup_expected_servers: None,
@@ -5092,6 +5420,8 @@ impl ChatWidget {
             suppress_queue_autosend: false,
             thread_id: None,
             last_turn_id: None,
+            last_turn_request_params: None,
+            recent_user_prompts: Vec::new(),
             thread_name: None,
             thread_rename_block_message: None,
             active_side_conversation: false,
@@ -5100,6 +5430,9 @@ impl ChatWidget {
             forked_from: None,
             interrupted_turn_notice_mode: InterruptedTurnNoticeMode::Default,
             queued_user_messages: VecDeque::new(),
+            read_only_restore: None,
+            auto_mode_revert: None,
+            auto_mode_generation: 0,
             user_turn_pending_start: false,
             rejected_steers_queue: VecDeque::new(),
             pending_steers: VecDeque::new(),
@@ -5134,6 +5467,12 @@ impl ChatWidget {
             last_terminal_title: None,
             terminal_title_setup_original_items: None,
             terminal_title_animation_origin: Instant::now(),
+            session_start_time: Instant::now(),
+            rate_limit_cooldown_until: None,
+            batch: None,
+            pinned_context: template_pinned_context,
+            env_overrides: EnvOverridesState::default(),
+            sent_file_versions: HashMap::new(),
             status_line_project_root_name_cache: None,
             status_line_branch: None,
             status_line_branch_cwd: None,
@@ -5157,9 +5496,16 @@ impl ChatWidget {
         widget
             .bottom_pane
             .set_collaboration_modes_enabled(/*enabled*/ true);
+        widget
+            .bottom_pane
+            .set_interrupt_hint_delay(Duration::from_secs(
+                widget.config.tui_interrupt_hint_after_seconds.unwrap_or(0),
+            ));
         widget.sync_fast_command_enabled();
         widget.sync_personality_command_enabled();
         widget.sync_plugins_command_enabled();
+        let keymap = crate::keymap::build_keymap(widget.config.tui_keybindings.as_ref());
+        widget.refresh_shortcut_key_hints(&keymap);
         widget
             .bottom_pane
             .set_queued_message_edit_binding(widget.queued_message_edit_binding);
@@ -5183,6 +5529,16 @@ impl ChatWidget {
 
     pub(crate) fn handle_key_event(&mut self, key_event: KeyEvent) {
         match key_event {
+            // Ctrl+S - drain the queued stream backlog immediately.
+            KeyEvent {
+                code: KeyCode::Char('s'),
+                modifiers: KeyModifiers::CONTROL,
+                kind: KeyEventKind::Press,
+                ..
+            } if self.bottom_pane.stream_backlog_hint_visible() => {
+                self.catch_up_stream_backlog();
+                return;
+            }
             // Ctrl+O - copy last agent response from the main view.
             KeyEvent {
                 code: KeyCode::Char('o'),
@@ -5503,6 +5859,10 @@ impl ChatWidget {
     }
 
     pub(crate) fn handle_paste(&mut self, text: String) {
+        // A wall of pasted text is the moment the external editor shines.
+        if text.len() > 1000 || text.lines().count() > 10 {
+            self.maybe_show_tip(crate::tips::Tip::ExternalEditor);
+        }
         self.bottom_pane.handle_paste(text);
     }
 
@@ -5565,6 +5925,17 @@ impl ChatWidget {
             self.queued_user_messages
                 .push_back(QueuedUserMessage::new(user_message, action));
             self.refresh_pending_input_preview();
+        } else if let Some(remaining) = self.rate_limit_cooldown_remaining() {
+            self.queued_user_messages
+                .push_back(QueuedUserMessage::new(user_message, action));
+            self.refresh_pending_input_preview();
+            self.add_info_message(
+                format!(
+                    "Rate limited — message queued; sending in {}.",
+                    format_cooldown_remaining(remaining)
+                ),
+                None,
+            );
         } else {
             self.submit_user_message(user_message);
         }
@@ -5596,6 +5967,25 @@ impl ChatWidget {
         }
     }
 
+    /// Deny the pending exec approval `id` and hand the user-edited command
+    /// back to the agent as an explicit instruction, so the next attempt runs
+    /// the amended version.
+    pub(crate) fn amend_exec_command(&mut self, id: String, command: Vec<String>) {
+        let thread_id = self.thread_id.unwrap_or_default();
+        self.app_event_tx.exec_approval(
+            thread_id,
+            id,
+            codex_protocol::protocol::ReviewDecision::Denied,
+        );
+        let script = strip_bash_lc_and_escape(&command);
+        self.submit_user_message(
+            format!(
+                "I edited the proposed command; run this version instead:\n\n```bash\n{script}\n```"
+            )
+            .into(),
+        );
+    }
+
     fn submit_user_message(&mut self, user_message: UserMessage) {
         let _ = self
             .submit_user_message_with_shell_escape_policy(user_message, ShellEscapePolicy::Allow);
@@ -5613,6 +6003,11 @@ impl ChatWidget {
             self.refresh_pending_input_preview();
             return None;
         }
+        // Remember the message for the error tray's retry action; shell
+        // escapes run locally and are not retryable turns.
+        if !user_message.text.is_empty() && !user_message.text.starts_with('!') {
+            self.last_submitted_user_message = Some(user_message.clone());
+        }
         let UserMessage {
             text,
             local_images,
@@ -5780,6 +6175,12 @@ impl ChatWidget {
             }
         }
 
+        // Refresh tracked files that changed on disk, then re-send pinned
+        // context so it survives compaction.
+        self.record_mentioned_file_versions(&mention_bindings);
+        items.extend(self.stale_file_refresh_inputs());
+        items.extend(self.pinned_context_inputs());
+
         let effective_mode = self.effective_collaboration_mode();
         if effective_mode.model().trim().is_empty() {
             self.add_error_message(
@@ -6925,8 +7326,11 @@ impl ChatWidget {
                 }
             }
             EventMsg::TurnComplete(TurnCompleteEvent {
-                last_agent_message, ..
+                last_agent_message,
+                request_params,
+                ..
             }) => {
+                self.last_turn_request_params = request_params;
                 self.on_task_complete(last_agent_message, from_replay);
             }
             EventMsg::TokenCount(ev) => {
@@ -7185,6 +7589,10 @@ impl ChatWidget {
                         Some(self.config.cwd.as_path()),
                         &mut rendered,
                     );
+                    crate::file_references::annotate_file_references(
+                        &mut rendered,
+                        self.config.cwd.as_path(),
+                    );
                     let body_cell = AgentMessageCell::new(rendered, /*is_first_line*/ false);
                     self.app_event_tx
                         .send(AppEvent::InsertHistoryCell(Box::new(body_cell)));
@@ -7243,6 +7651,12 @@ impl ChatWidget {
     fn on_user_message_event(&mut self, event: UserMessageEvent) {
         self.last_rendered_user_message_event =
             Some(Self::rendered_user_message_event_from_event(&event));
+        if !event.message.trim().is_empty() {
+            self.recent_user_prompts.push(event.message.clone());
+            if self.recent_user_prompts.len() > MAX_RECENT_USER_PROMPTS {
+                self.recent_user_prompts.remove(0);
+            }
+        }
         let remote_image_urls = event.images.unwrap_or_default();
         if !event.message.trim().is_empty()
             || !event.text_elements.is_empty()
@@ -7288,6 +7702,18 @@ impl ChatWidget {
     }
 
     fn notify(&mut self, notification: Notification) {
+        // The bell is independent of desktop-notification filtering: it is
+        // aimed at users watching an unfocused terminal.
+        let bell = match &notification {
+            Notification::AgentTurnComplete { .. } => self.config.tui_bell.on_complete,
+            Notification::ExecApprovalRequested { .. }
+            | Notification::EditApprovalRequested { .. }
+            | Notification::ElicitationRequested { .. } => self.config.tui_bell.on_approval,
+            Notification::PlanModePrompt { .. } => BellStyle::None,
+        };
+        if bell != BellStyle::None {
+            self.app_event_tx.send(AppEvent::RingBell { style: bell });
+        }
         if !notification.allowed_for(&self.config.tui_notifications.notifications) {
             return;
         }
@@ -7324,6 +7750,10 @@ impl ChatWidget {
         if self.suppress_queue_autosend {
             return;
         }
+        if self.rate_limit_cooldown_active() {
+            // Queued inputs auto-send when the cool-down window opens.
+            return;
+        }
         if self.is_user_turn_pending_or_running() {
             return;
         }
@@ -7490,6 +7920,53 @@ impl ChatWidget {
         }
     }
 
+    /// Renders remaining budget for each limit configured under `[limits]`.
+    /// Turn counting happens in core, so only the configured cap is shown
+    /// for `max_turns`.
+    pub(crate) fn add_limits_output(&mut self) {
+        let limits = &self.config.limits;
+        let total_tokens = self
+            .token_info
+            .as_ref()
+            .map(|ti| ti.total_token_usage.blended_total())
+            .unwrap_or(0);
+        let mut lines: Vec<String> = Vec::new();
+        if let (Some(max_cost), Some(rate)) = (limits.max_cost_usd, limits.cost_per_1m_tokens_usd)
+            && max_cost > 0.0
+            && rate > 0.0
+        {
+            let cost = rate * total_tokens as f64 / 1_000_000.0;
+            lines.push(format!(
+                "cost: ${cost:.2} used of ${max_cost:.2} (${remaining:.2} remaining)",
+                remaining = (max_cost - cost).max(0.0)
+            ));
+        }
+        if let Some(max_tokens) = limits.max_total_tokens.filter(|max| *max > 0) {
+            lines.push(format!(
+                "tokens: {total_tokens} used of {max_tokens} ({remaining} remaining)",
+                remaining = (max_tokens - total_tokens).max(0)
+            ));
+        }
+        if let Some(max_turns) = limits.max_turns.filter(|max| *max > 0) {
+            lines.push(format!("turns: limit {max_turns}"));
+        }
+        if let Some(max_minutes) = limits.max_wall_clock_minutes.filter(|max| *max > 0) {
+            let elapsed_minutes = self.session_start_time.elapsed().as_secs() / 60;
+            lines.push(format!(
+                "wall clock: {elapsed_minutes}m used of {max_minutes}m"
+            ));
+        }
+        if lines.is_empty() {
+            self.add_info_message(
+                "No session limits configured. Set [limits] in config.toml to add a budget."
+                    .to_string(),
+                None,
+            );
+        } else {
+            self.add_info_message(format!("Session limits — {}", lines.join("; ")), None);
+        }
+    }
+
     pub(crate) fn add_debug_config_output(&mut self) {
         self.add_to_history(crate::debug_config::new_debug_config_output(
             &self.config,
@@ -7497,6 +7974,69 @@ impl ChatWidget {
         ));
     }
 
+    /// `/debug-ui`: terminal and rendering diagnostics for triaging layout
+    /// glitch reports across terminals.
+    pub(crate) fn add_debug_ui_output(&mut self) {
+        let diagnostics = crate::debug_ui::collect_ui_diagnostics(self.enhanced_keys_supported);
+        self.add_to_history(crate::debug_ui::new_debug_ui_output(&diagnostics));
+    }
+
+    /// `/system`: shows how the final system prompt is composed so
+    /// `system_prompt_extra` additions and instruction overrides can be
+    /// audited without capturing a request log.
+    pub(crate) fn add_system_prompt_output(&mut self) {
+        let mut lines: Vec<Line<'static>> = vec!["/system".magenta().into(), "".into()];
+        match &self.config.base_instructions {
+            Some(base) => {
+                lines.push("Base instructions (overridden via config):".bold().into());
+                lines.extend(base.lines().map(|line| format!("  {line}").into()));
+            }
+            None => lines.push(
+                format!(
+                    "Base instructions: built-in instructions for `{}`.",
+                    self.current_model()
+                )
+                .into(),
+            ),
+        }
+        lines.push("".into());
+        match &self.config.system_prompt_extra {
+            Some(extra) => {
+                lines.push("Appended via `system_prompt_extra`:".bold().into());
+                lines.extend(extra.lines().map(|line| format!("  {line}").into()));
+            }
+            None => lines.push("No `system_prompt_extra` configured.".dim().into()),
+        }
+        self.add_to_history(PlainHistoryCell::new(lines));
+    }
+
+    pub(crate) fn add_turn_details_output(&mut self) {
+        let mut lines: Vec<Line<'static>> = vec!["/details".magenta().into(), "".into()];
+        match &self.last_turn_request_params {
+            Some(params) => {
+                lines.push("Last turn request parameters:".bold().into());
+                lines.push(format!("  Model: {}", params.model).into());
+                let effort = params
+                    .effort
+                    .map(|effort| effort.to_string())
+                    .unwrap_or_else(|| "default".to_string());
+                lines.push(format!("  Reasoning effort: {effort}").into());
+                if let Some(temperature) = params.temperature {
+                    lines.push(format!("  Temperature: {temperature}").into());
+                }
+                if let Some(seed) = params.seed {
+                    lines.push(format!("  Seed: {seed}").into());
+                }
+                match &params.provider_request_id {
+                    Some(id) => lines.push(format!("  Provider request id: {id}").into()),
+                    None => lines.push("  Provider request id: unknown".dim().into()),
+                }
+            }
+            None => lines.push("No completed turn in this session yet.".dim().into()),
+        }
+        self.add_to_history(PlainHistoryCell::new(lines));
+    }
+
     fn open_status_line_setup(&mut self) {
         let configured_status_line_items = self.configured_status_line_items();
         let view = StatusLineSetupView::new(
@@ -8731,7 +9271,7 @@ impl ChatWidget {
         let guardian_approval_enabled = self.config.features.enabled(Feature::GuardianApproval);
         let current_review_policy = self.config.approvals_reviewer;
         let mut items: Vec<SelectionItem> = Vec::new();
-        let presets: Vec<ApprovalPreset> = builtin_approval_presets();
+        let presets: Vec<ApprovalPreset> = self.approval_presets_with_custom();
 
         #[cfg(target_os = "windows")]
         let windows_sandbox_level = WindowsSandboxLevel::from_config(&self.config);
@@ -8765,8 +9305,15 @@ impl ChatWidget {
             } else {
                 preset.label.to_string()
             };
-            let base_description =
-                Some(preset.description.replace(" (Identical to Agent mode)", ""));
+            let matrix = Self::permissions_matrix_summary(preset.approval, &preset.sandbox);
+            let base_description = {
+                let description = preset.description.replace(" (Identical to Agent mode)", "");
+                if description.is_empty() {
+                    Some(matrix)
+                } else {
+                    Some(format!("{description} ({matrix})"))
+                }
+            };
             let approval_disabled_reason = match self
                 .config
                 .permissions
@@ -8779,7 +9326,7 @@ impl ChatWidget {
             let default_disabled_reason = approval_disabled_reason
                 .clone()
                 .or_else(|| guardian_disabled_reason(false));
-            let requires_confirmation = preset.id == "full-access"
+            let requires_confirmation = matches!(preset.sandbox, SandboxPolicy::DangerFullAccess)
                 && !self
                     .config
                     .notices
@@ -8948,6 +9495,98 @@ impl ChatWidget {
         self.bottom_pane.show_view(Box::new(view));
     }
 
+    /// Pushes the live bindings for rebindable shortcuts into the footer so
+    /// displayed hints follow `[tui.keybindings]` overrides.
+    pub(crate) fn refresh_shortcut_key_hints(&mut self, keymap: &crate::keymap::TuiKeymap) {
+        self.bottom_pane.set_rebindable_shortcut_keys(
+            key_hint::for_action(keymap, crate::keymap::KeymapAction::LaunchExternalEditor),
+            key_hint::for_action(keymap, crate::keymap::KeymapAction::OpenTranscript),
+        );
+    }
+
+    pub(crate) fn open_keybindings_view(&mut self) {
+        let keymap = crate::keymap::build_keymap(self.config.tui_keybindings.as_ref());
+        let view = KeybindingsView::new(&keymap, self.app_event_tx.clone());
+        self.bottom_pane.show_view(Box::new(view));
+    }
+
+    /// Opens the action palette: every keymap action with its current chord,
+    /// generated from [`crate::keymap::TUI_KEYBINDING_ACTIONS`] so new actions
+    /// appear without hand-maintaining a list. Enter runs the selected action.
+    pub(crate) fn open_action_palette(&mut self) {
+        let keymap = crate::keymap::build_keymap(self.config.tui_keybindings.as_ref());
+        let mut items = Vec::new();
+        for (action_name, action) in crate::keymap::TUI_KEYBINDING_ACTIONS.iter().copied() {
+            let sequences = keymap.sequences_for(crate::keymap::KeymapContext::Composer, action);
+            let binding = if sequences.is_empty() {
+                "unbound".to_string()
+            } else {
+                sequences
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            items.push(SelectionItem {
+                name: action.label().to_string(),
+                description: Some(binding),
+                search_value: Some(format!("{action_name} {}", action.label())),
+                dismiss_on_select: true,
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::DispatchKeymapAction(action));
+                })],
+                ..Default::default()
+            });
+        }
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Actions".to_string()),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            is_searchable: true,
+            search_placeholder: Some("Type to search actions".to_string()),
+            ..Default::default()
+        });
+    }
+
+    /// Opens the saved-macro picker. Enter replays the selected macro's key
+    /// events into the composer.
+    pub(crate) fn open_macro_picker(&mut self) {
+        let macros = crate::macros::load_macros(self.config.codex_home.as_path());
+        if macros.is_empty() {
+            self.add_info_message(
+                "No saved macros yet. Record one with the macro-record-start action.".to_string(),
+                /*hint*/ None,
+            );
+            return;
+        }
+        let items = macros
+            .into_iter()
+            .map(|(name, steps)| {
+                let event_name = name.clone();
+                SelectionItem {
+                    name: name.clone(),
+                    description: Some(format!("{} key(s): {}", steps.len(), steps.join(" "))),
+                    search_value: Some(name),
+                    dismiss_on_select: true,
+                    actions: vec![Box::new(move |tx| {
+                        tx.send(AppEvent::PlayMacro(event_name.clone()));
+                    })],
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Play macro".to_string()),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            is_searchable: true,
+            search_placeholder: Some("Type to search macros".to_string()),
+            ..Default::default()
+        });
+    }
+
     fn approval_preset_actions(
         approval: AskForApproval,
         sandbox: SandboxPolicy,
@@ -8984,6 +9623,62 @@ impl ChatWidget {
         })]
     }
 
+    /// Built-in approval presets followed by any custom `[approval_presets]`
+    /// entries from config, sorted by name. Custom entries cannot shadow a
+    /// built-in id.
+    fn approval_presets_with_custom(&self) -> Vec<ApprovalPreset> {
+        let mut presets = builtin_approval_presets();
+        if let Some(custom) = &self.config.approval_presets {
+            let mut entries: Vec<_> = custom.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (name, preset) in entries {
+                if presets.iter().any(|existing| existing.id == *name) {
+                    continue;
+                }
+                presets.push(ApprovalPreset {
+                    id: name.clone(),
+                    label: preset.label.clone().unwrap_or_else(|| name.clone()),
+                    description: preset.description.clone().unwrap_or_default(),
+                    approval: preset.approval_policy,
+                    sandbox: Self::sandbox_policy_for_mode(preset.sandbox_mode),
+                });
+            }
+        }
+        presets
+    }
+
+    fn sandbox_policy_for_mode(mode: SandboxMode) -> SandboxPolicy {
+        match mode {
+            SandboxMode::ReadOnly => SandboxPolicy::new_read_only_policy(),
+            SandboxMode::WorkspaceWrite => SandboxPolicy::new_workspace_write_policy(),
+            SandboxMode::DangerFullAccess => SandboxPolicy::DangerFullAccess,
+        }
+    }
+
+    /// One-line "approval · sandbox" summary shown next to each preset in the
+    /// permissions picker.
+    fn permissions_matrix_summary(approval: AskForApproval, sandbox: &SandboxPolicy) -> String {
+        let sandbox_label = match sandbox {
+            SandboxPolicy::DangerFullAccess => "danger-full-access",
+            SandboxPolicy::ReadOnly { .. } => "read-only",
+            SandboxPolicy::WorkspaceWrite { .. } => "workspace-write",
+        };
+        format!("approval: {approval} · sandbox: {sandbox_label}")
+    }
+
+    /// Label of the preset matching the current approval and sandbox
+    /// policies; falls back to the approval policy name when no preset
+    /// matches.
+    pub(crate) fn current_approval_preset_label(&self) -> String {
+        let current_approval = self.config.permissions.approval_policy.value();
+        let current_sandbox = self.config.permissions.sandbox_policy.get();
+        self.approval_presets_with_custom()
+            .into_iter()
+            .find(|preset| Self::preset_matches_current(current_approval, current_sandbox, preset))
+            .map(|preset| preset.label)
+            .unwrap_or_else(|| current_approval.to_string())
+    }
+
     fn preset_matches_current(
         current_approval: AskForApproval,
         current_sandbox: &SandboxPolicy,
@@ -9525,6 +10220,184 @@ impl ChatWidget {
     pub(crate) fn clear_windows_sandbox_setup_status(&mut self) {}
 
     /// Set the approval policy in the widget's config copy.
+    /// Whether the session is in hard read-only mode: a read-only sandbox
+    /// with approvals disabled, so nothing can escalate to a mutating command.
+    pub(crate) fn is_read_only_mode(&self) -> bool {
+        self.config.permissions.approval_policy.value() == AskForApproval::Never
+            && matches!(
+                self.config.permissions.sandbox_policy.get(),
+                SandboxPolicy::ReadOnly { .. }
+            )
+    }
+
+    /// Toggles `/readonly`: hard-disables apply_patch and mutating commands by
+    /// switching to a read-only sandbox with approvals off, or restores the
+    /// permissions that were active before the mode was enabled.
+    pub(crate) fn toggle_read_only_mode(&mut self) {
+        if self.is_read_only_mode() {
+            let (approval_policy, sandbox_policy) = self.read_only_restore.take().unwrap_or((
+                AskForApproval::default(),
+                SandboxPolicy::new_workspace_write_policy(),
+            ));
+            if let Err(err) = self.set_sandbox_policy(sandbox_policy.clone()) {
+                self.add_error_message(format!("Failed to disable read-only mode: {err}"));
+                return;
+            }
+            self.set_approval_policy(approval_policy);
+            self.submit_op(AppCommand::override_turn_context(
+                /*cwd*/ None,
+                Some(approval_policy),
+                /*approvals_reviewer*/ None,
+                Some(sandbox_policy),
+                /*windows_sandbox_level*/ None,
+                /*model*/ None,
+                /*effort*/ None,
+                /*summary*/ None,
+                /*service_tier*/ None,
+                /*collaboration_mode*/ None,
+                /*personality*/ None,
+            ));
+            self.add_info_message("Read-only mode disabled.".to_string(), None);
+        } else {
+            let previous = (
+                self.config.permissions.approval_policy.value(),
+                self.config.permissions.sandbox_policy.get().clone(),
+            );
+            let sandbox_policy = SandboxPolicy::new_read_only_policy();
+            if let Err(err) = self.set_sandbox_policy(sandbox_policy.clone()) {
+                self.add_error_message(format!("Failed to enable read-only mode: {err}"));
+                return;
+            }
+            self.set_approval_policy(AskForApproval::Never);
+            self.read_only_restore = Some(previous);
+            self.submit_op(AppCommand::override_turn_context(
+                /*cwd*/ None,
+                Some(AskForApproval::Never),
+                /*approvals_reviewer*/ None,
+                Some(sandbox_policy),
+                /*windows_sandbox_level*/ None,
+                /*model*/ None,
+                /*effort*/ None,
+                /*summary*/ None,
+                /*service_tier*/ None,
+                /*collaboration_mode*/ None,
+                /*personality*/ None,
+            ));
+            self.add_info_message(
+                "Read-only mode enabled: Codex cannot modify files or run mutating commands. Run /readonly again to restore the previous permissions.".to_string(),
+                None,
+            );
+        }
+        self.update_collaboration_mode_indicator();
+    }
+
+    /// `/auto [<duration>|off]`: switch to auto-approval (`never` ask) for a
+    /// limited time, then restore the previous approval policy with a notice
+    /// so an elevated mode cannot be forgotten.
+    pub(crate) fn handle_auto_command(&mut self, args: &str) {
+        let args = args.trim();
+        if args.eq_ignore_ascii_case("off") {
+            if self.auto_mode_revert.is_some() {
+                self.end_auto_mode("Auto mode disabled");
+            } else {
+                self.add_info_message("Auto mode is not active.".to_string(), /*hint*/ None);
+            }
+            return;
+        }
+        let duration = if args.is_empty() {
+            Some(DEFAULT_AUTO_MODE_DURATION)
+        } else {
+            parse_auto_duration(args)
+        };
+        let Some(duration) = duration else {
+            self.add_info_message(
+                format!("Could not parse `{args}` as a duration."),
+                Some("Use /auto [<duration>|off], e.g. /auto 15m.".to_string()),
+            );
+            return;
+        };
+        if let Err(err) = self
+            .config
+            .permissions
+            .approval_policy
+            .can_set(&AskForApproval::Never)
+        {
+            self.add_error_message(format!("Failed to enable auto mode: {err}"));
+            return;
+        }
+        let previous = self.config.permissions.approval_policy.value();
+        self.auto_mode_generation += 1;
+        let generation = self.auto_mode_generation;
+        // Re-arming extends the window but keeps the original policy as the
+        // revert target; otherwise a second `/auto` would "restore" `never`.
+        let revert_to = self
+            .auto_mode_revert
+            .take()
+            .map_or(previous, |(policy, _)| policy);
+        self.auto_mode_revert = Some((revert_to, generation));
+        self.set_approval_policy(AskForApproval::Never);
+        self.submit_op(AppCommand::override_turn_context(
+            /*cwd*/ None,
+            Some(AskForApproval::Never),
+            /*approvals_reviewer*/ None,
+            /*sandbox_policy*/ None,
+            /*windows_sandbox_level*/ None,
+            /*model*/ None,
+            /*effort*/ None,
+            /*summary*/ None,
+            /*service_tier*/ None,
+            /*collaboration_mode*/ None,
+            /*personality*/ None,
+        ));
+        let tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(duration).await;
+            tx.send(AppEvent::AutoModeExpired { generation });
+        });
+        self.add_info_message(
+            format!(
+                "Auto mode enabled for {}: commands run without approval prompts. Run /auto off to end it early.",
+                format_auto_duration(duration)
+            ),
+            /*hint*/ None,
+        );
+    }
+
+    /// Expiry timer callback for a `/auto` grant; stale generations (the
+    /// grant was re-armed or ended early) are ignored.
+    pub(crate) fn handle_auto_mode_expired(&mut self, generation: u64) {
+        if self
+            .auto_mode_revert
+            .is_some_and(|(_, armed)| armed == generation)
+        {
+            self.end_auto_mode("Auto mode expired");
+        }
+    }
+
+    fn end_auto_mode(&mut self, reason: &str) {
+        let Some((approval_policy, _)) = self.auto_mode_revert.take() else {
+            return;
+        };
+        self.set_approval_policy(approval_policy);
+        self.submit_op(AppCommand::override_turn_context(
+            /*cwd*/ None,
+            Some(approval_policy),
+            /*approvals_reviewer*/ None,
+            /*sandbox_policy*/ None,
+            /*windows_sandbox_level*/ None,
+            /*model*/ None,
+            /*effort*/ None,
+            /*summary*/ None,
+            /*service_tier*/ None,
+            /*collaboration_mode*/ None,
+            /*personality*/ None,
+        ));
+        self.add_info_message(
+            format!("{reason}: approval policy restored to `{approval_policy}`."),
+            /*hint*/ None,
+        );
+    }
+
     pub(crate) fn set_approval_policy(&mut self, policy: AskForApproval) {
         if let Err(err) = self.config.permissions.approval_policy.set(policy) {
             tracing::warn!(%err, "failed to set approval_policy on chat config");
@@ -9987,6 +10860,9 @@ impl ChatWidget {
     }
 
     fn collaboration_mode_indicator(&self) -> Option<CollaborationModeIndicator> {
+        if self.is_read_only_mode() {
+            return Some(CollaborationModeIndicator::ReadOnly);
+        }
         if !self.collaboration_modes_enabled() {
             return None;
         }
@@ -10153,6 +11029,40 @@ impl ChatWidget {
         self.request_redraw();
     }
 
+    /// Show `tip` if it has never fired before, and persist the updated seen
+    /// set. No-op when `tui.tips = false` or the tip already showed.
+    pub(crate) fn maybe_show_tip(&mut self, tip: crate::tips::Tip) {
+        if let Some(message) = self.tips.take(tip) {
+            self.add_info_message(message.to_string(), /*hint*/ None);
+            self.app_event_tx
+                .send(AppEvent::PersistSeenTips(self.tips.seen_for_persistence()));
+        }
+    }
+
+    /// `/tips` without arguments: report the current state.
+    pub(crate) fn add_tips_status(&mut self) {
+        let status = if self.tips.enabled() {
+            format!("Tips are enabled; {} shown so far.", self.tips.seen_count())
+        } else {
+            "Tips are disabled (tui.tips = false).".to_string()
+        };
+        self.add_info_message(
+            status,
+            Some("Use /tips reset to show every tip again.".to_string()),
+        );
+    }
+
+    /// `/tips reset`: forget all seen tips and clear the persisted state.
+    pub(crate) fn reset_tips(&mut self) {
+        self.tips.reset();
+        self.app_event_tx
+            .send(AppEvent::PersistSeenTips(Vec::new()));
+        self.add_info_message(
+            "Tips reset; each one can show again.".to_string(),
+            /*hint*/ None,
+        );
+    }
+
     pub(crate) fn add_memories_enable_notice(&mut self) {
         self.add_to_history(history_cell::new_warning_event(
             MEMORIES_ENABLE_NOTICE.to_string(),
@@ -10998,6 +11908,33 @@ impl ChatWidget {
         self.bottom_pane.show_view(Box::new(view));
     }
 
+    /// Opens the editable `/suggest-commit` popup pre-filled with `suggestion`;
+    /// accepting hands the message to the agent to run the actual `git commit`.
+    pub(crate) fn open_commit_suggestion_popup(&mut self, suggestion: String) {
+        let tx = self.app_event_tx.clone();
+        let view = CustomPromptView::new(
+            "Suggested commit message".to_string(),
+            "Edit the message and press Enter to commit".to_string(),
+            /*initial_text*/ suggestion,
+            /*context_label*/ None,
+            Box::new(move |message: String| {
+                let trimmed = message.trim().to_string();
+                if trimmed.is_empty() {
+                    return;
+                }
+                tx.send(AppEvent::SubmitCommitMessage(trimmed));
+            }),
+        );
+        self.bottom_pane.show_view(Box::new(view));
+    }
+
+    pub(crate) fn submit_commit_message(&mut self, message: String) {
+        let text = format!(
+            "Commit the current uncommitted changes with exactly this commit message:\n\n```\n{message}\n```"
+        );
+        self.submit_user_message(text.into());
+    }
+
     pub(crate) fn token_usage(&self) -> TokenUsage {
         self.token_info
             .as_ref()
@@ -11152,6 +12089,21 @@ impl Drop for ChatWidget {
 impl Renderable for ChatWidget {
     fn render(&self, area: Rect, buf: &mut Buffer) {
         self.as_renderable().render(area, buf);
+        // While a modal bottom-pane view (approval, picker) has focus, dim
+        // everything above it so the focused surface is obvious. This is a
+        // pure style pass over the rendered buffer, not a re-render.
+        if self.bottom_pane.has_active_view() {
+            let pane_height = self
+                .bottom_pane
+                .desired_height(area.width)
+                .saturating_add(1) // the pane is inset one row from the content above
+                .min(area.height);
+            let backdrop = Rect {
+                height: area.height - pane_height,
+                ..area
+            };
+            buf.set_style(backdrop, crate::style::backdrop_dim_style());
+        }
         self.last_rendered_width.set(Some(area.width as usize));
     }
 
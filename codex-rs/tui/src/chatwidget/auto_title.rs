@@ -0,0 +1,94 @@
+//! Automatic thread title generation.
+//!
+//! Threads the user never names get a short descriptive title derived from
+//! their first message once the first turn completes. The title goes through
+//! the regular `thread/name/set` flow, so it shows up in the resume picker
+//! and the terminal title like a manual rename — only the confirmation cell
+//! is suppressed. `/rename` keeps working and wins over the generated title.
+
+use super::*;
+
+/// Longest auto-generated title; longer first messages are cut at a word
+/// boundary and marked with an ellipsis.
+const MAX_AUTO_TITLE_WIDTH: usize = 48;
+
+impl ChatWidget {
+    /// Names the thread after the first completed exchange if the user has
+    /// not named it already. Runs at most once per session.
+    pub(super) fn maybe_auto_generate_thread_title(&mut self) {
+        if self.thread_auto_title_attempted {
+            return;
+        }
+        self.thread_auto_title_attempted = true;
+        if self
+            .thread_name
+            .as_deref()
+            .is_some_and(|name| !name.is_empty())
+            || self.thread_rename_block_message.is_some()
+        {
+            return;
+        }
+        let Some(message) = self.last_submitted_user_message.as_ref() else {
+            return;
+        };
+        let Some(title) = derive_thread_title(&message.text) else {
+            return;
+        };
+        self.suppress_rename_confirmation = true;
+        self.app_event_tx.set_thread_name(title);
+    }
+}
+
+/// Local heuristic: the first non-empty line of the message, stripped of
+/// leading markdown markers and collapsed whitespace, truncated at a word
+/// boundary.
+fn derive_thread_title(text: &str) -> Option<String> {
+    let line = text.lines().find(|line| !line.trim().is_empty())?;
+    let line = line.trim_start_matches(['#', '>', '-', '*', ' ', '\t']);
+    let collapsed = line.split_whitespace().collect::<Vec<_>>().join(" ");
+    let title = crate::legacy_core::util::normalize_thread_name(&collapsed)?;
+    if title.chars().count() <= MAX_AUTO_TITLE_WIDTH {
+        return Some(title);
+    }
+    let truncated: String = title.chars().take(MAX_AUTO_TITLE_WIDTH).collect();
+    let cut = truncated.rfind(' ').unwrap_or(truncated.len());
+    Some(format!("{}…", truncated[..cut].trim_end()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn title_comes_from_first_non_empty_line() {
+        assert_eq!(
+            derive_thread_title("\n\nFix the resume picker\nmore detail"),
+            Some("Fix the resume picker".to_string())
+        );
+    }
+
+    #[test]
+    fn markdown_markers_and_whitespace_are_stripped() {
+        assert_eq!(
+            derive_thread_title("## Add   a\tlinter   rule"),
+            Some("Add a linter rule".to_string())
+        );
+    }
+
+    #[test]
+    fn long_messages_are_cut_at_a_word_boundary() {
+        let title = derive_thread_title(
+            "Please refactor the configuration loader so that profiles layer cleanly",
+        )
+        .expect("title");
+        assert!(title.ends_with('…'), "got {title:?}");
+        assert!(title.chars().count() <= MAX_AUTO_TITLE_WIDTH + 1);
+        assert!(!title.contains("cleanly"));
+    }
+
+    #[test]
+    fn empty_messages_produce_no_title() {
+        assert_eq!(derive_thread_title("   \n\t\n"), None);
+    }
+}
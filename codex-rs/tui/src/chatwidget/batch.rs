@@ -0,0 +1,221 @@
+//! `/batch` — sequential multi-task runner for `ChatWidget`.
+//!
+//! A batch is a list of prompts loaded from a file. Each entry runs as a
+//! regular turn (so ghost snapshots checkpoint each task as usual); the next
+//! entry auto-submits when the previous turn completes, or waits for
+//! `/batch continue` when `tui.batch_pause_between_tasks` is set.
+
+use super::*;
+
+/// Progress through an active `/batch` run.
+pub(super) struct BatchState {
+    tasks: Vec<String>,
+    next_index: usize,
+    awaiting_continue: bool,
+}
+
+impl BatchState {
+    fn progress_label(&self) -> String {
+        format!(
+            "{completed}/{total}",
+            completed = self.next_index,
+            total = self.tasks.len()
+        )
+    }
+}
+
+/// Extracts one task per line, stripping list markers (`- `, `* `, `1. `) and
+/// skipping blank lines and `#` headings so plain text and markdown lists both
+/// work.
+fn parse_batch_tasks(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let without_bullet = line
+                .strip_prefix("- ")
+                .or_else(|| line.strip_prefix("* "))
+                .unwrap_or(line);
+            without_bullet
+                .split_once(". ")
+                .filter(|(ordinal, _)| ordinal.chars().all(|c| c.is_ascii_digit()))
+                .map(|(_, rest)| rest)
+                .unwrap_or(without_bullet)
+                .to_string()
+        })
+        .filter(|task| !task.is_empty())
+        .collect()
+}
+
+impl ChatWidget {
+    /// Entry point for `/batch` and its subcommands.
+    pub(super) fn handle_batch_command(&mut self, args: &str) {
+        let trimmed = args.trim();
+        match trimmed.to_ascii_lowercase().as_str() {
+            "" | "status" => self.show_batch_status(),
+            "continue" => self.continue_batch(),
+            "stop" => self.stop_batch(),
+            _ => self.start_batch_from_file(trimmed),
+        }
+    }
+
+    fn show_batch_status(&mut self) {
+        let message = match &self.batch {
+            Some(batch) => {
+                let state = if batch.awaiting_continue {
+                    " — paused; run /batch continue"
+                } else {
+                    ""
+                };
+                format!(
+                    "Batch in progress: {} tasks complete{state}.",
+                    batch.progress_label()
+                )
+            }
+            None => "No batch running. Usage: /batch <tasks-file> | continue | stop".to_string(),
+        };
+        self.add_info_message(message, None);
+    }
+
+    fn start_batch_from_file(&mut self, path_arg: &str) {
+        if self.batch.is_some() {
+            self.add_error_message(
+                "A batch is already running; finish it or run /batch stop first.".to_string(),
+            );
+            return;
+        }
+        let path = self.config.cwd.as_path().join(path_arg);
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) => {
+                self.add_error_message(format!("Failed to read {}: {err}", path.display()));
+                return;
+            }
+        };
+        let tasks = parse_batch_tasks(&contents);
+        if tasks.is_empty() {
+            self.add_error_message(format!("No tasks found in {}.", path.display()));
+            return;
+        }
+        self.add_info_message(
+            format!("Starting batch: {} tasks from {path_arg}.", tasks.len()),
+            None,
+        );
+        self.batch = Some(BatchState {
+            tasks,
+            next_index: 0,
+            awaiting_continue: false,
+        });
+        self.submit_next_batch_task();
+    }
+
+    fn continue_batch(&mut self) {
+        match self.batch.as_mut() {
+            Some(batch) if batch.awaiting_continue => {
+                batch.awaiting_continue = false;
+                self.submit_next_batch_task();
+            }
+            Some(_) => {
+                self.add_info_message("Batch is not waiting for confirmation.".to_string(), None);
+            }
+            None => {
+                self.add_error_message("No batch running.".to_string());
+            }
+        }
+    }
+
+    fn stop_batch(&mut self) {
+        match self.batch.take() {
+            Some(batch) => {
+                self.add_info_message(
+                    format!(
+                        "Batch stopped after {} tasks complete.",
+                        batch.progress_label()
+                    ),
+                    None,
+                );
+            }
+            None => {
+                self.add_error_message("No batch running.".to_string());
+            }
+        }
+    }
+
+    /// Called when a turn completes; advances or pauses the batch.
+    pub(super) fn maybe_advance_batch(&mut self) {
+        let pause_between_tasks = self.config.tui_batch_pause_between_tasks;
+        let Some(batch) = self.batch.as_mut() else {
+            return;
+        };
+        if batch.awaiting_continue {
+            return;
+        }
+        if batch.next_index >= batch.tasks.len() {
+            let total = batch.tasks.len();
+            self.batch = None;
+            self.add_info_message(format!("Batch complete: {total} tasks."), None);
+            return;
+        }
+        if pause_between_tasks {
+            batch.awaiting_continue = true;
+            let progress = batch.progress_label();
+            self.add_info_message(
+                format!("Batch task complete ({progress}). Run /batch continue for the next task."),
+                None,
+            );
+            return;
+        }
+        self.submit_next_batch_task();
+    }
+
+    fn submit_next_batch_task(&mut self) {
+        let Some(batch) = self.batch.as_mut() else {
+            return;
+        };
+        let Some(task) = batch.tasks.get(batch.next_index).cloned() else {
+            return;
+        };
+        batch.next_index += 1;
+        let task_number = batch.next_index;
+        let total = batch.tasks.len();
+        self.add_info_message(
+            format!(
+                "Batch task {task_number} of {total}: {}",
+                truncate_text(&task, 80)
+            ),
+            None,
+        );
+        self.submit_user_message(UserMessage::from(task));
+    }
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use super::parse_batch_tasks;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_markdown_and_plain_lists() {
+        let tasks = parse_batch_tasks(
+            "# refactors\n\n- rename Foo to Bar\n* drop the legacy flag\n1. tidy imports\nplain task\n",
+        );
+        assert_eq!(
+            tasks,
+            vec![
+                "rename Foo to Bar".to_string(),
+                "drop the legacy flag".to_string(),
+                "tidy imports".to_string(),
+                "plain task".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_blank_lines_and_headings() {
+        assert_eq!(
+            parse_batch_tasks("# only a heading\n\n\n"),
+            Vec::<String>::new()
+        );
+    }
+}
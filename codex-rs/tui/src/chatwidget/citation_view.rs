@@ -0,0 +1,98 @@
+//! `/citations` tray and source previews for `ChatWidget`.
+//!
+//! The markdown renderer collapses `【F:path†Lx-Ly】` citations into
+//! superscript markers; this module lists the sources behind those markers
+//! and expands a selected one into a highlighted preview of the cited lines.
+
+use ratatui::text::Span;
+
+use super::*;
+
+impl ChatWidget {
+    /// Entry point for `/citations`: lists the sources cited by the last
+    /// response; selecting one previews the cited lines.
+    pub(super) fn open_citations_tray(&mut self) {
+        if self.last_agent_citations.is_empty() {
+            self.add_info_message("The last response has no citations.".to_string(), None);
+            return;
+        }
+        let items = self
+            .last_agent_citations
+            .iter()
+            .enumerate()
+            .map(|(index, citation)| SelectionItem {
+                name: format!(
+                    "{} {}:{}",
+                    crate::citations::superscript_marker(index + 1),
+                    citation.path,
+                    citation.location()
+                ),
+                description: Some("press enter to preview the cited lines".to_string()),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::ShowCitationPreview { index });
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            })
+            .collect();
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Citations".to_string()),
+            subtitle: Some("Sources cited by the last response.".to_string()),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            ..Default::default()
+        });
+    }
+
+    /// Inserts a highlighted preview of the cited lines into the transcript.
+    pub(crate) fn show_citation_preview(&mut self, index: usize) {
+        let Some(citation) = self.last_agent_citations.get(index).cloned() else {
+            return;
+        };
+        let path = Path::new(&citation.path);
+        let resolved = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.config.cwd.as_path().join(path)
+        };
+        let content = match std::fs::read_to_string(&resolved) {
+            Ok(content) => content,
+            Err(err) => {
+                self.add_error_message(format!("Failed to read {}: {err}", resolved.display()));
+                return;
+            }
+        };
+        let start = citation.start_line.max(1) as usize;
+        let end = (citation.end_line as usize).max(start);
+        let snippet: String = content
+            .lines()
+            .skip(start - 1)
+            .take(end - start + 1)
+            .map(|line| format!("{line}\n"))
+            .collect();
+        if snippet.is_empty() {
+            self.add_error_message(format!("{} has no line {start}.", resolved.display()));
+            return;
+        }
+        let lang = resolved
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let mut lines: Vec<Line<'static>> = vec![
+            "".into(),
+            Line::from(format!("{}:{}", citation.path, citation.location())).dim(),
+        ];
+        for (offset, highlighted) in
+            crate::render::highlight::highlight_code_to_lines(&snippet, &lang)
+                .into_iter()
+                .enumerate()
+        {
+            let mut line = highlighted;
+            line.spans
+                .insert(0, Span::from(format!("{:>5} ", start + offset)).dim());
+            lines.push(line);
+        }
+        self.add_to_history(history_cell::PlainHistoryCell::new(lines));
+    }
+}
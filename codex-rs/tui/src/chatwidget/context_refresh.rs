@@ -0,0 +1,177 @@
+//! Stale-file detection for attached context.
+//!
+//! The widget remembers the version of each file it has attached to the
+//! conversation (pins and `@` mentions). When one of those files changes on
+//! disk — whether the agent edited it or something external did — the next
+//! turn automatically re-attaches the updated content so the model never
+//! reasons over a stale snapshot.
+
+use super::*;
+
+/// Cap on tracked files so a long session cannot hoard file snapshots.
+const MAX_TRACKED_FILES: usize = 64;
+
+/// Files smaller than this are always attached in full; the diff header
+/// overhead is not worth it.
+const DIFF_ATTACHMENT_MIN_BYTES: usize = 2048;
+
+/// How a file should be attached, given what was previously sent.
+pub(super) enum FileAttachment {
+    /// Send the full current content.
+    Full,
+    /// The content matches the previously-sent version exactly.
+    Unchanged,
+    /// Send a unified diff against the previously-sent version.
+    Diff(String),
+}
+
+/// Picks the cheapest faithful representation of `current`: a unified diff
+/// against `previous` when one exists, the file is large, and the diff is
+/// actually smaller — otherwise the full content.
+pub(super) fn plan_file_attachment(previous: Option<&str>, current: &str) -> FileAttachment {
+    let Some(previous) = previous else {
+        return FileAttachment::Full;
+    };
+    if current.len() < DIFF_ATTACHMENT_MIN_BYTES {
+        return FileAttachment::Full;
+    }
+    if previous == current {
+        return FileAttachment::Unchanged;
+    }
+    let patch = diffy::create_patch(previous, current).to_string();
+    if patch.len() < current.len() {
+        FileAttachment::Diff(patch)
+    } else {
+        FileAttachment::Full
+    }
+}
+
+impl ChatWidget {
+    /// Records `content` as the version of `path` the model has seen.
+    pub(super) fn record_sent_file_version(&mut self, path: PathBuf, content: String) {
+        if !self.sent_file_versions.contains_key(&path)
+            && self.sent_file_versions.len() >= MAX_TRACKED_FILES
+        {
+            return;
+        }
+        self.sent_file_versions.insert(path, content);
+    }
+
+    /// Marks `@`-mentioned files as seen so later edits trigger a refresh.
+    pub(super) fn record_mentioned_file_versions(&mut self, mention_bindings: &[MentionBinding]) {
+        let paths: Vec<PathBuf> = mention_bindings
+            .iter()
+            .filter(|binding| !binding.path.contains("://"))
+            .map(|binding| self.config.cwd.as_path().join(&binding.path))
+            .collect();
+        for path in paths {
+            if let Ok(content) = std::fs::read_to_string(&path) {
+                self.record_sent_file_version(path, content);
+            }
+        }
+    }
+
+    /// Re-attaches any tracked file that changed on disk since it was last
+    /// sent, and updates the recorded versions. Deleted files are dropped from
+    /// tracking with a note so the model hears about the removal once.
+    pub(super) fn stale_file_refresh_inputs(&mut self) -> Vec<UserInput> {
+        let mut inputs = Vec::new();
+        let mut updates: Vec<(PathBuf, Option<String>)> = Vec::new();
+        for (path, sent_content) in &self.sent_file_versions {
+            if self.is_pinned_file(path) {
+                // Pins re-send the current content every turn already.
+                continue;
+            }
+            match std::fs::read_to_string(path) {
+                Ok(current) if &current != sent_content => {
+                    let body = match plan_file_attachment(Some(sent_content), &current) {
+                        FileAttachment::Diff(patch) => format!(
+                            "This file changed since you last saw it; here is a unified diff \
+                             against the version you saw.\n{patch}"
+                        ),
+                        _ => format!(
+                            "This file changed since you last saw it; here is the current \
+                             content.\n{current}"
+                        ),
+                    };
+                    inputs.push(UserInput::Text {
+                        text: format!(
+                            "<file_refresh path=\"{path}\">\n{body}\n</file_refresh>",
+                            path = path.display()
+                        ),
+                        text_elements: Vec::new(),
+                    });
+                    updates.push((path.clone(), Some(current)));
+                }
+                Ok(_) => {}
+                Err(_) => {
+                    inputs.push(UserInput::Text {
+                        text: format!(
+                            "<file_refresh path=\"{path}\">\nThis file was deleted or is no \
+                             longer readable.\n</file_refresh>",
+                            path = path.display()
+                        ),
+                        text_elements: Vec::new(),
+                    });
+                    updates.push((path.clone(), None));
+                }
+            }
+        }
+        for (path, content) in updates {
+            match content {
+                Some(content) => {
+                    self.sent_file_versions.insert(path, content);
+                }
+                None => {
+                    self.sent_file_versions.remove(&path);
+                }
+            }
+        }
+        inputs
+    }
+}
+
+#[cfg(test)]
+mod context_refresh_tests {
+    use super::DIFF_ATTACHMENT_MIN_BYTES;
+    use super::FileAttachment;
+    use super::plan_file_attachment;
+
+    #[test]
+    fn full_content_when_no_previous_version() {
+        assert!(matches!(
+            plan_file_attachment(None, "fn main() {}\n"),
+            FileAttachment::Full
+        ));
+    }
+
+    #[test]
+    fn full_content_for_small_files() {
+        assert!(matches!(
+            plan_file_attachment(Some("old\n"), "new\n"),
+            FileAttachment::Full
+        ));
+    }
+
+    #[test]
+    fn unchanged_when_content_matches() {
+        let content = "line\n".repeat(DIFF_ATTACHMENT_MIN_BYTES);
+        assert!(matches!(
+            plan_file_attachment(Some(&content), &content),
+            FileAttachment::Unchanged
+        ));
+    }
+
+    #[test]
+    fn diff_when_patch_is_smaller_than_full_content() {
+        let previous = "line\n".repeat(DIFF_ATTACHMENT_MIN_BYTES);
+        let current = format!("{previous}one more line\n");
+        match plan_file_attachment(Some(&previous), &current) {
+            FileAttachment::Diff(patch) => {
+                assert!(patch.len() < current.len());
+                assert!(patch.contains("+one more line"));
+            }
+            _ => panic!("expected a diff attachment"),
+        }
+    }
+}
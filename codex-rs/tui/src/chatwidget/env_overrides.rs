@@ -0,0 +1,129 @@
+//! `/env` session environment overrides for `ChatWidget`.
+//!
+//! `/env` renders the environment the agent's commands will actually run
+//! with — the shell environment policy (inherit, excludes, `include_only`,
+//! `set`) is applied first, so the listing matches what a spawned process
+//! sees. `/env set` and `/env unset` adjust that environment for the rest of
+//! the session; the deltas are forwarded to core via [`Op::OverrideShellEnv`],
+//! which records them in the session settings so they persist with the
+//! session metadata. `/env secret` only affects the transcript: the variable
+//! keeps its real value but is redacted whenever `/env` prints it.
+
+use super::*;
+
+use std::collections::BTreeSet;
+
+use codex_protocol::protocol::ShellEnvOverrides;
+
+/// Shown in place of the real value for names marked via `/env secret`.
+const REDACTED_ENV_VALUE: &str = "•••••";
+
+const ENV_USAGE: &str = "Usage: /env [set KEY=VALUE | unset KEY | secret KEY]";
+
+/// Session-scoped `/env` state: the accumulated overrides (mirroring what core
+/// applies to spawned commands) plus the names redacted in the transcript.
+#[derive(Default)]
+pub(super) struct EnvOverridesState {
+    overrides: ShellEnvOverrides,
+    secret_keys: BTreeSet<String>,
+}
+
+fn is_valid_env_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl ChatWidget {
+    /// Entry point for `/env` without args: list the effective environment.
+    pub(super) fn add_env_output(&mut self) {
+        let mut policy = self.config.permissions.shell_environment_policy.clone();
+        crate::legacy_core::exec_env::apply_shell_env_overrides(
+            &mut policy,
+            &self.env_overrides.overrides,
+        );
+        let env = crate::legacy_core::exec_env::create_env(&policy, self.thread_id);
+        let mut entries: Vec<(String, String)> = env.into_iter().collect();
+        entries.sort();
+        for (name, value) in &mut entries {
+            if self.env_overrides.secret_keys.contains(name) {
+                *value = REDACTED_ENV_VALUE.to_string();
+            }
+        }
+        self.add_to_history(history_cell::new_env_output(entries));
+    }
+
+    /// Entry point for `/env` with args: `set KEY=VALUE`, `unset KEY`, or
+    /// `secret KEY`.
+    pub(super) fn handle_env_command(&mut self, args: &str) {
+        let (subcommand, rest) = match args.split_once(char::is_whitespace) {
+            Some((subcommand, rest)) => (subcommand, rest.trim()),
+            None => (args, ""),
+        };
+        match subcommand.to_ascii_lowercase().as_str() {
+            "set" => {
+                let Some((name, value)) = rest.split_once('=') else {
+                    self.add_error_message(format!("/env set needs KEY=VALUE. {ENV_USAGE}"));
+                    return;
+                };
+                let name = name.trim();
+                if !is_valid_env_var_name(name) {
+                    self.add_error_message(format!("'{name}' is not a valid variable name."));
+                    return;
+                }
+                let mut delta = ShellEnvOverrides::default();
+                delta.set.insert(name.to_string(), value.to_string());
+                self.apply_env_overrides(delta);
+                let shown = if self.env_overrides.secret_keys.contains(name) {
+                    REDACTED_ENV_VALUE
+                } else {
+                    value
+                };
+                self.add_info_message(
+                    format!("Set {name}={shown} for commands this session."),
+                    /*hint*/ None,
+                );
+            }
+            "unset" => {
+                if !is_valid_env_var_name(rest) {
+                    self.add_error_message(format!(
+                        "/env unset needs a variable name. {ENV_USAGE}"
+                    ));
+                    return;
+                }
+                let mut delta = ShellEnvOverrides::default();
+                delta.unset.push(rest.to_string());
+                self.apply_env_overrides(delta);
+                self.add_info_message(
+                    format!("Unset {rest} for commands this session."),
+                    /*hint*/ None,
+                );
+            }
+            "secret" => {
+                if !is_valid_env_var_name(rest) {
+                    self.add_error_message(format!(
+                        "/env secret needs a variable name. {ENV_USAGE}"
+                    ));
+                    return;
+                }
+                self.env_overrides.secret_keys.insert(rest.to_string());
+                self.add_info_message(
+                    format!("{rest} will be redacted in /env output."),
+                    /*hint*/ None,
+                );
+            }
+            _ => self.add_error_message(ENV_USAGE.to_string()),
+        }
+    }
+
+    /// Records the delta locally (so `/env` output reflects it immediately)
+    /// and forwards it to core, where it is merged into the session
+    /// configuration and applied to every subsequently spawned command.
+    fn apply_env_overrides(&mut self, delta: ShellEnvOverrides) {
+        self.env_overrides.overrides.merge(delta.clone());
+        self.submit_op(Op::OverrideShellEnv { overrides: delta });
+    }
+}
@@ -0,0 +1,119 @@
+//! Follow-up actions for turn-ending errors.
+//!
+//! When a turn fails, the widget renders the error cell and then offers a
+//! small tray of next steps — retry the message, switch model, inspect the
+//! debug log, or copy the error — instead of leaving the user at a dead-end
+//! red line.
+
+use super::*;
+
+/// How many trailing lines of the debug log the transcript preview shows.
+const DEBUG_LOG_TAIL_LINES: usize = 40;
+
+impl ChatWidget {
+    /// Opens the follow-up tray after a turn-ending error.
+    pub(super) fn open_error_actions_tray(&mut self) {
+        let mut items: Vec<SelectionItem> = Vec::new();
+        if self.last_submitted_user_message.is_some() {
+            items.push(SelectionItem {
+                name: "Retry last message".to_string(),
+                description: Some("send the previous message again".to_string()),
+                actions: vec![Box::new(|tx| {
+                    tx.send(AppEvent::RetryLastUserMessage);
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            });
+        }
+        items.push(SelectionItem {
+            name: "Retry with a different model".to_string(),
+            description: Some("choose a model, then send the message again".to_string()),
+            actions: vec![Box::new(|tx| {
+                tx.send(AppEvent::OpenModelPopup);
+            })],
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+        items.push(SelectionItem {
+            name: "Show debug log".to_string(),
+            description: Some("insert the tail of codex-tui.log into the transcript".to_string()),
+            actions: vec![Box::new(|tx| {
+                tx.send(AppEvent::ShowDebugLogTail);
+            })],
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+        items.push(SelectionItem {
+            name: "Copy error".to_string(),
+            description: Some("copy the error message to the clipboard".to_string()),
+            actions: vec![Box::new(|tx| {
+                tx.send(AppEvent::CopyLastError);
+            })],
+            dismiss_on_select: true,
+            ..Default::default()
+        });
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(crate::i18n::tr("error.turn_failed")),
+            subtitle: Some("Choose how to continue.".to_string()),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            ..Default::default()
+        });
+    }
+
+    /// Re-sends the message that preceded the failed turn.
+    pub(crate) fn retry_last_user_message(&mut self) {
+        let Some(message) = self.last_submitted_user_message.clone() else {
+            self.add_error_message("No message to retry.".to_string());
+            return;
+        };
+        self.submit_user_message(message);
+    }
+
+    /// Copies the last turn-ending error message to the clipboard.
+    pub(crate) fn copy_last_error(&mut self) {
+        let Some(error) = self.last_turn_error.clone() else {
+            self.add_error_message("No error to copy.".to_string());
+            return;
+        };
+        match crate::clipboard_copy::copy_to_clipboard(&error) {
+            Ok(lease) => {
+                self.clipboard_lease = lease;
+                self.add_to_history(history_cell::new_info_event(
+                    "Copied error to clipboard".into(),
+                    /*hint*/ None,
+                ));
+            }
+            Err(error) => {
+                self.add_error_message(format!("Copy failed: {error}"));
+            }
+        }
+        self.request_redraw();
+    }
+
+    /// Inserts the tail of the TUI debug log into the transcript.
+    pub(crate) fn show_debug_log_tail(&mut self) {
+        let log_path = match crate::legacy_core::config::log_dir(&self.config) {
+            Ok(dir) => dir.join("codex-tui.log"),
+            Err(err) => {
+                self.add_error_message(format!("Failed to locate the log directory: {err}"));
+                return;
+            }
+        };
+        let content = match std::fs::read_to_string(&log_path) {
+            Ok(content) => content,
+            Err(err) => {
+                self.add_error_message(format!("Failed to read {}: {err}", log_path.display()));
+                return;
+            }
+        };
+        let all: Vec<&str> = content.lines().collect();
+        let start = all.len().saturating_sub(DEBUG_LOG_TAIL_LINES);
+        let mut lines: Vec<Line<'static>> =
+            vec!["".into(), Line::from(log_path.display().to_string()).dim()];
+        for entry in &all[start..] {
+            lines.push(Line::from(entry.to_string()).dim());
+        }
+        self.add_to_history(history_cell::PlainHistoryCell::new(lines));
+    }
+}
@@ -0,0 +1,202 @@
+//! Context pinning for `ChatWidget`.
+//!
+//! Pinned files and messages are re-sent with every submitted turn, so they
+//! survive `/compact` and context-window pruning by construction. The `/pin`
+//! tray lists each pinned item with its approximate token cost; selecting an
+//! entry unpins it.
+
+use super::*;
+
+/// A transcript item the user marked as always-included.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum PinnedItem {
+    /// A workspace file whose current content is attached each turn.
+    File(PathBuf),
+    /// A verbatim message (for example a pinned agent response).
+    Message(String),
+}
+
+impl PinnedItem {
+    fn label(&self) -> String {
+        match self {
+            PinnedItem::File(path) => path.display().to_string(),
+            PinnedItem::Message(text) => {
+                format!("message: {}", truncate_text(text.trim(), 48))
+            }
+        }
+    }
+}
+
+/// Rough token estimate used for the tray display; the usual ~4 chars/token
+/// heuristic is plenty for "is this pin expensive" decisions.
+fn estimate_tokens(text: &str) -> i64 {
+    (text.len() as i64).div_ceil(4)
+}
+
+impl ChatWidget {
+    /// Whether `path` is currently pinned as a file.
+    pub(super) fn is_pinned_file(&self, path: &Path) -> bool {
+        self.pinned_context
+            .iter()
+            .any(|item| matches!(item, PinnedItem::File(pinned) if pinned == path))
+    }
+
+    /// Entry point for `/pin`. Without args, opens the pinned-items tray.
+    pub(super) fn handle_pin_command(&mut self, args: &str) {
+        let trimmed = args.trim();
+        if trimmed.is_empty() {
+            self.open_pinned_items_tray();
+            return;
+        }
+        if trimmed.eq_ignore_ascii_case("last") {
+            match self.last_agent_markdown.clone() {
+                Some(markdown) if !markdown.is_empty() => {
+                    self.pin_item(PinnedItem::Message(markdown));
+                }
+                _ => self.add_error_message("No agent message to pin yet.".to_string()),
+            }
+            return;
+        }
+        let path = self.config.cwd.as_path().join(trimmed);
+        if !path.is_file() {
+            self.add_error_message(format!(
+                "{} is not a file. Usage: /pin <file> — or /pin last to pin the last response.",
+                path.display()
+            ));
+            return;
+        }
+        self.pin_item(PinnedItem::File(path));
+    }
+
+    fn pin_item(&mut self, item: PinnedItem) {
+        if self.pinned_context.contains(&item) {
+            self.add_info_message(format!("Already pinned: {}.", item.label()), None);
+            return;
+        }
+        let label = item.label();
+        self.pinned_context.push(item);
+        self.add_info_message(
+            format!("Pinned {label} — it will be re-sent every turn. /pin to manage."),
+            None,
+        );
+    }
+
+    pub(crate) fn unpin_context_item(&mut self, index: usize) {
+        if index < self.pinned_context.len() {
+            let item = self.pinned_context.remove(index);
+            self.add_info_message(format!("Unpinned {}.", item.label()), None);
+        }
+    }
+
+    fn open_pinned_items_tray(&mut self) {
+        if self.pinned_context.is_empty() {
+            self.add_info_message(
+                "Nothing pinned. Usage: /pin <file> | /pin last".to_string(),
+                None,
+            );
+            return;
+        }
+        let items = self
+            .pinned_context
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let tokens = match item {
+                    PinnedItem::File(path) => std::fs::read_to_string(path)
+                        .map(|content| estimate_tokens(&content))
+                        .unwrap_or(0),
+                    PinnedItem::Message(text) => estimate_tokens(text),
+                };
+                SelectionItem {
+                    name: item.label(),
+                    description: Some(format!(
+                        "~{} tokens per turn — press enter to unpin",
+                        format_tokens_compact(tokens)
+                    )),
+                    actions: vec![Box::new(move |tx| {
+                        tx.send(AppEvent::UnpinContextItem { index });
+                    })],
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Pinned context".to_string()),
+            subtitle: Some("Re-sent every turn; selecting an item unpins it.".to_string()),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            ..Default::default()
+        });
+    }
+
+    /// Builds the always-included inputs appended to every submitted turn.
+    /// Large pinned files the model has already seen are re-sent as a diff
+    /// against the previously-sent version (or a short unchanged marker).
+    pub(super) fn pinned_context_inputs(&mut self) -> Vec<UserInput> {
+        let mut inputs = Vec::new();
+        let mut missing: Vec<PathBuf> = Vec::new();
+        let mut sent: Vec<(PathBuf, String)> = Vec::new();
+        for item in &self.pinned_context {
+            match item {
+                PinnedItem::File(path) => match std::fs::read_to_string(path) {
+                    Ok(content) => {
+                        let previous = self.sent_file_versions.get(path).map(String::as_str);
+                        let body = match context_refresh::plan_file_attachment(previous, &content) {
+                            context_refresh::FileAttachment::Full => content.clone(),
+                            context_refresh::FileAttachment::Unchanged => {
+                                "(unchanged since previously sent)".to_string()
+                            }
+                            context_refresh::FileAttachment::Diff(patch) => format!(
+                                "Unified diff against the previously-sent version:\n{patch}"
+                            ),
+                        };
+                        inputs.push(UserInput::Text {
+                            text: format!(
+                                "<pinned_context path=\"{path}\">\n{body}\n</pinned_context>",
+                                path = path.display()
+                            ),
+                            text_elements: Vec::new(),
+                        });
+                        sent.push((path.clone(), content));
+                    }
+                    Err(_) => missing.push(path.clone()),
+                },
+                PinnedItem::Message(text) => {
+                    inputs.push(UserInput::Text {
+                        text: format!("<pinned_context>\n{text}\n</pinned_context>"),
+                        text_elements: Vec::new(),
+                    });
+                }
+            }
+        }
+        for (path, content) in sent {
+            self.record_sent_file_version(path, content);
+        }
+        for path in missing {
+            self.pinned_context
+                .retain(|item| item != &PinnedItem::File(path.clone()));
+            self.add_info_message(
+                format!(
+                    "Unpinned {} — the file is no longer readable.",
+                    path.display()
+                ),
+                None,
+            );
+        }
+        inputs
+    }
+}
+
+#[cfg(test)]
+mod pins_tests {
+    use super::estimate_tokens;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn token_estimate_rounds_up() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abc"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+    }
+}
@@ -223,6 +223,12 @@ impl ChatWidget {
             SlashCommand::Permissions => {
                 self.open_permissions_popup();
             }
+            SlashCommand::Readonly => {
+                self.toggle_read_only_mode();
+            }
+            SlashCommand::Auto => {
+                self.handle_auto_command("");
+            }
             SlashCommand::ElevateSandbox => {
                 #[cfg(target_os = "windows")]
                 {
@@ -296,6 +302,9 @@ impl ChatWidget {
             SlashCommand::Copy => {
                 self.copy_last_agent_markdown();
             }
+            SlashCommand::Citations => {
+                self.open_citations_tray();
+            }
             SlashCommand::Diff => {
                 self.add_diff_in_progress();
                 let tx = self.app_event_tx.clone();
@@ -313,12 +322,53 @@ impl ChatWidget {
                     tx.send(AppEvent::DiffResult(text));
                 });
             }
+            SlashCommand::SuggestCommit => {
+                let tx = self.app_event_tx.clone();
+                let prompts = self.recent_user_prompts.clone();
+                tokio::spawn(async move {
+                    match get_git_diff().await {
+                        Ok((true, diff)) if !diff.trim().is_empty() => {
+                            let suggestion =
+                                crate::commit_suggestion::suggest_commit_message(&diff, &prompts);
+                            tx.send(AppEvent::CommitSuggestionReady(suggestion));
+                        }
+                        Ok((true, _)) => {
+                            tx.send(AppEvent::InsertHistoryCell(Box::new(
+                                history_cell::new_info_event(
+                                    "No uncommitted changes to describe.".to_string(),
+                                    /*hint*/ None,
+                                ),
+                            )));
+                        }
+                        Ok((false, _)) => {
+                            tx.send(AppEvent::InsertHistoryCell(Box::new(
+                                history_cell::new_error_event(
+                                    "`/suggest-commit` requires a git repository.".to_string(),
+                                ),
+                            )));
+                        }
+                        Err(e) => {
+                            tx.send(AppEvent::InsertHistoryCell(Box::new(
+                                history_cell::new_error_event(format!(
+                                    "Failed to compute diff: {e}"
+                                )),
+                            )));
+                        }
+                    }
+                });
+            }
             SlashCommand::Mention => {
                 self.insert_str("@");
             }
             SlashCommand::Skills => {
                 self.open_skills_menu();
             }
+            SlashCommand::Batch => {
+                self.handle_batch_command("");
+            }
+            SlashCommand::Pin => {
+                self.handle_pin_command("");
+            }
             SlashCommand::Status => {
                 if self.should_prefetch_rate_limits() {
                     let request_id = self.next_status_refresh_request_id;
@@ -334,9 +384,33 @@ impl ChatWidget {
                     );
                 }
             }
+            SlashCommand::Limits => {
+                self.add_limits_output();
+            }
             SlashCommand::DebugConfig => {
                 self.add_debug_config_output();
             }
+            SlashCommand::DebugUi => {
+                self.add_debug_ui_output();
+            }
+            SlashCommand::Help => {
+                self.app_event_tx
+                    .send(AppEvent::OpenHelpTopic(/*topic*/ None));
+            }
+            SlashCommand::Tips => {
+                self.add_tips_status();
+            }
+            SlashCommand::Stats => {
+                let codex_home = self.config.codex_home.as_path().to_path_buf();
+                let tx = self.app_event_tx.clone();
+                tokio::task::spawn_blocking(move || {
+                    let markdown = match crate::usage_stats::collect(&codex_home) {
+                        Ok(stats) => crate::usage_stats::render_markdown(&stats),
+                        Err(err) => format!("Failed to compute usage stats: {err}"),
+                    };
+                    tx.send(AppEvent::UsageStatsResult(markdown));
+                });
+            }
             SlashCommand::Title => {
                 self.open_terminal_title_setup();
             }
@@ -346,9 +420,21 @@ impl ChatWidget {
             SlashCommand::Theme => {
                 self.open_theme_picker();
             }
+            SlashCommand::Keybindings => {
+                self.open_keybindings_view();
+            }
             SlashCommand::Ps => {
                 self.add_ps_output();
             }
+            SlashCommand::Env => {
+                self.add_env_output();
+            }
+            SlashCommand::System => {
+                self.add_system_prompt_output();
+            }
+            SlashCommand::Details => {
+                self.add_turn_details_output();
+            }
             SlashCommand::Stop => {
                 self.clean_background_terminals();
             }
@@ -547,6 +633,43 @@ impl ChatWidget {
                 "verbose" => self.add_mcp_output(McpServerStatusDetail::Full),
                 _ => self.add_error_message("Usage: /mcp [verbose]".to_string()),
             },
+            SlashCommand::Tips => match trimmed.to_ascii_lowercase().as_str() {
+                "reset" => self.reset_tips(),
+                _ => self.add_error_message("Usage: /tips [reset]".to_string()),
+            },
+            SlashCommand::Stats if !trimmed.is_empty() => {
+                if trimmed.eq_ignore_ascii_case("export") {
+                    let codex_home = self.config.codex_home.as_path().to_path_buf();
+                    match crate::usage_stats::collect(&codex_home)
+                        .and_then(|stats| crate::usage_stats::export_json(&codex_home, &stats))
+                    {
+                        Ok(path) => self.add_info_message(
+                            format!("Wrote usage stats to {}", path.display()),
+                            /*hint*/ None,
+                        ),
+                        Err(err) => {
+                            self.add_error_message(format!("Failed to export usage stats: {err}"));
+                        }
+                    }
+                } else {
+                    self.add_error_message("Usage: /stats [export]".to_string());
+                }
+            }
+            SlashCommand::Help if !trimmed.is_empty() => {
+                match trimmed
+                    .to_ascii_lowercase()
+                    .parse::<crate::help_topics::HelpTopic>()
+                {
+                    Ok(topic) => {
+                        self.app_event_tx.send(AppEvent::OpenHelpTopic(Some(topic)));
+                    }
+                    Err(_) => {
+                        self.add_error_message(format!(
+                            "Unknown help topic '{trimmed}'. Usage: /help [keybindings|config|sandbox]"
+                        ));
+                    }
+                }
+            }
             SlashCommand::Rename if !trimmed.is_empty() => {
                 if !self.ensure_thread_rename_allowed() {
                     return;
@@ -611,6 +734,18 @@ impl ChatWidget {
                 self.app_event_tx
                     .send(AppEvent::BeginWindowsSandboxGrantReadRoot { path: args });
             }
+            SlashCommand::Batch if !trimmed.is_empty() => {
+                self.handle_batch_command(trimmed);
+            }
+            SlashCommand::Pin if !trimmed.is_empty() => {
+                self.handle_pin_command(trimmed);
+            }
+            SlashCommand::Env if !trimmed.is_empty() => {
+                self.handle_env_command(trimmed);
+            }
+            SlashCommand::Auto if !trimmed.is_empty() => {
+                self.handle_auto_command(trimmed);
+            }
             _ => self.dispatch_command(cmd),
         }
         if source == SlashCommandDispatchSource::Live {
@@ -719,8 +854,13 @@ impl ChatWidget {
         match cmd {
             SlashCommand::Fast
             | SlashCommand::Status
+            | SlashCommand::Limits
             | SlashCommand::DebugConfig
+            | SlashCommand::DebugUi
             | SlashCommand::Ps
+            | SlashCommand::Env
+            | SlashCommand::System
+            | SlashCommand::Details
             | SlashCommand::Stop
             | SlashCommand::MemoryDrop
             | SlashCommand::MemoryUpdate
@@ -729,8 +869,15 @@ impl ChatWidget {
             | SlashCommand::Plugins
             | SlashCommand::Rollout
             | SlashCommand::Copy
+            | SlashCommand::Citations
             | SlashCommand::Diff
+            | SlashCommand::SuggestCommit
             | SlashCommand::Rename
+            | SlashCommand::Help
+            | SlashCommand::Tips
+            | SlashCommand::Stats
+            | SlashCommand::Batch
+            | SlashCommand::Pin
             | SlashCommand::TestApproval => QueueDrain::Continue,
             SlashCommand::Feedback
             | SlashCommand::New
@@ -753,6 +900,8 @@ impl ChatWidget {
             | SlashCommand::Permissions
             | SlashCommand::ElevateSandbox
             | SlashCommand::SandboxReadRoot
+            | SlashCommand::Readonly
+            | SlashCommand::Auto
             | SlashCommand::Experimental
             | SlashCommand::Memories
             | SlashCommand::Quit
@@ -762,7 +911,8 @@ impl ChatWidget {
             | SlashCommand::Skills
             | SlashCommand::Title
             | SlashCommand::Statusline
-            | SlashCommand::Theme => QueueDrain::Stop,
+            | SlashCommand::Theme
+            | SlashCommand::Keybindings => QueueDrain::Stop,
         }
     }
 
@@ -478,6 +478,16 @@ impl ChatWidget {
                     .unwrap_or_else(|| "weekly".to_string());
                 self.status_line_limit_display(window, &label)
             }
+            StatusLineItem::RateLimitCooldown => {
+                let remaining = self.rate_limit_cooldown_remaining()?;
+                // Keep the countdown ticking while it is visible.
+                self.frame_requester
+                    .schedule_frame_in(Duration::from_secs(1));
+                Some(format!(
+                    "Rate limit resets in {}",
+                    format_cooldown_remaining(remaining)
+                ))
+            }
             StatusLineItem::CodexVersion => Some(CODEX_CLI_VERSION.to_string()),
             StatusLineItem::ContextWindowSize => self
                 .status_line_context_window_size()
@@ -502,6 +512,7 @@ impl ChatWidget {
                 let trimmed = name.trim();
                 (!trimmed.is_empty()).then(|| trimmed.to_string())
             }),
+            StatusLineItem::ApprovalPreset => Some(self.current_approval_preset_label()),
         }
     }
 
@@ -654,6 +654,7 @@ async fn unified_exec_wait_after_final_agent_message_snapshot() {
             last_agent_message: Some("Final response.".into()),
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
 
@@ -699,6 +700,7 @@ async fn unified_exec_wait_before_streamed_agent_message_snapshot() {
             last_agent_message: None,
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
 
@@ -766,6 +768,7 @@ async fn unified_exec_waiting_multiple_empty_snapshots() {
             last_agent_message: None,
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
 
@@ -846,6 +849,7 @@ async fn unified_exec_non_empty_then_empty_snapshots() {
             last_agent_message: None,
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
 
@@ -1359,6 +1363,7 @@ async fn turn_complete_keeps_unified_exec_processes() {
             last_agent_message: None,
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
 
@@ -166,6 +166,7 @@ pub(super) async fn make_chatwidget_manual(
         placeholder_text: "Ask Codex to do anything".to_string(),
         disable_paste_burst: false,
         animations_enabled: cfg.animations,
+        spinner_style: crate::spinner::SpinnerStyle::from_config(cfg.tui_spinner.as_ref()),
         skills: None,
     });
     bottom.set_collaboration_modes_enabled(/*enabled*/ true);
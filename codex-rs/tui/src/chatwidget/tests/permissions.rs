@@ -1,4 +1,6 @@
 use super::*;
+use codex_config::config_toml::ApprovalPresetToml;
+use codex_protocol::config_types::SandboxMode;
 use pretty_assertions::assert_eq;
 
 #[tokio::test]
@@ -70,6 +72,110 @@ async fn preset_matching_accepts_workspace_write_with_extra_roots() {
     );
 }
 
+#[tokio::test]
+async fn approvals_popup_includes_custom_presets() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
+
+    chat.set_feature_enabled(Feature::GuardianApproval, /*enabled*/ false);
+    chat.config.approval_presets = Some(HashMap::from([(
+        "paranoid".to_string(),
+        ApprovalPresetToml {
+            label: Some("Paranoid".to_string()),
+            description: Some("Ask about everything.".to_string()),
+            approval_policy: AskForApproval::UnlessTrusted,
+            sandbox_mode: SandboxMode::ReadOnly,
+        },
+    )]));
+    chat.open_approvals_popup();
+
+    let popup = render_bottom_popup(&chat, /*width*/ 100);
+    assert!(
+        popup.contains("Paranoid"),
+        "expected custom preset label in approvals popup: {popup}"
+    );
+    assert!(
+        popup.contains("approval: untrusted · sandbox: read-only"),
+        "expected custom preset matrix in approvals popup: {popup}"
+    );
+    assert!(
+        popup.contains("approval: on-request · sandbox: workspace-write"),
+        "expected built-in preset matrix in approvals popup: {popup}"
+    );
+}
+
+#[tokio::test]
+async fn status_line_reports_active_approval_preset_label() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
+
+    assert_eq!(chat.current_approval_preset_label(), "Read Only");
+
+    chat.config.approval_presets = Some(HashMap::from([(
+        "paranoid".to_string(),
+        ApprovalPresetToml {
+            label: Some("Paranoid".to_string()),
+            description: None,
+            approval_policy: AskForApproval::UnlessTrusted,
+            sandbox_mode: SandboxMode::ReadOnly,
+        },
+    )]));
+    chat.config
+        .permissions
+        .approval_policy
+        .set(AskForApproval::UnlessTrusted)
+        .expect("set approval policy");
+
+    assert_eq!(chat.current_approval_preset_label(), "Paranoid");
+}
+
+#[tokio::test]
+async fn auto_command_reverts_to_previous_policy_on_expiry() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
+
+    let previous = chat.config.permissions.approval_policy.value();
+    chat.handle_auto_command("15m");
+    assert_eq!(
+        chat.config.permissions.approval_policy.value(),
+        AskForApproval::Never
+    );
+
+    // A stale generation (e.g. from a timer armed before `/auto` was re-run)
+    // must not end the grant.
+    chat.handle_auto_mode_expired(chat.auto_mode_generation - 1);
+    assert_eq!(
+        chat.config.permissions.approval_policy.value(),
+        AskForApproval::Never
+    );
+
+    chat.handle_auto_mode_expired(chat.auto_mode_generation);
+    assert_eq!(chat.config.permissions.approval_policy.value(), previous);
+}
+
+#[tokio::test]
+async fn auto_command_off_restores_previous_policy() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
+
+    let previous = chat.config.permissions.approval_policy.value();
+    chat.handle_auto_command("");
+    assert_eq!(
+        chat.config.permissions.approval_policy.value(),
+        AskForApproval::Never
+    );
+
+    chat.handle_auto_command("off");
+    assert_eq!(chat.config.permissions.approval_policy.value(), previous);
+}
+
+#[test]
+fn auto_command_durations_parse_with_unit_suffixes() {
+    assert_eq!(parse_auto_duration("15m"), Some(Duration::from_secs(900)));
+    assert_eq!(parse_auto_duration("90s"), Some(Duration::from_secs(90)));
+    assert_eq!(parse_auto_duration("2h"), Some(Duration::from_secs(7200)));
+    assert_eq!(parse_auto_duration("10"), Some(Duration::from_secs(600)));
+    assert_eq!(parse_auto_duration("0m"), None);
+    assert_eq!(parse_auto_duration("m"), None);
+    assert_eq!(parse_auto_duration("15x"), None);
+}
+
 #[tokio::test]
 async fn full_access_confirmation_popup_snapshot() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
@@ -648,6 +648,7 @@ async fn plan_implementation_popup_skips_replayed_turn_complete() {
         last_agent_message: Some("Plan details".to_string()),
         completed_at: None,
         duration_ms: None,
+        request_params: None,
     })]);
 
     let popup = render_bottom_popup(&chat, /*width*/ 80);
@@ -674,6 +675,7 @@ async fn plan_implementation_popup_shows_once_when_replay_precedes_live_turn_com
         last_agent_message: Some("Plan details".to_string()),
         completed_at: None,
         duration_ms: None,
+        request_params: None,
     })]);
     let replay_popup = render_bottom_popup(&chat, /*width*/ 80);
     assert!(
@@ -688,6 +690,7 @@ async fn plan_implementation_popup_shows_once_when_replay_precedes_live_turn_com
             last_agent_message: Some("Plan details".to_string()),
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
 
@@ -711,6 +714,7 @@ async fn plan_implementation_popup_shows_once_when_replay_precedes_live_turn_com
             last_agent_message: Some("Plan details".to_string()),
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
     let duplicate_popup = render_bottom_popup(&chat, /*width*/ 80);
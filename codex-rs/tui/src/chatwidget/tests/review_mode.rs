@@ -314,6 +314,7 @@ async fn steer_rejection_queues_review_follow_up_before_existing_queued_messages
             last_agent_message: None,
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
 
@@ -335,6 +336,7 @@ async fn steer_rejection_queues_review_follow_up_before_existing_queued_messages
             last_agent_message: None,
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
 
@@ -1315,6 +1315,7 @@ async fn status_line_branch_refreshes_after_turn_complete() {
             last_agent_message: None,
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
 
@@ -1665,6 +1666,7 @@ async fn multiple_agent_messages_in_single_turn_emit_multiple_headers() {
             last_agent_message: None,
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
 
@@ -2610,6 +2612,7 @@ printf 'fenced within fenced\n'
             last_agent_message: None,
             completed_at: None,
             duration_ms: None,
+            request_params: None,
         }),
     });
     for lines in drain_insert_history(&mut rx) {
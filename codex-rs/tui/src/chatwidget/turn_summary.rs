@@ -0,0 +1,188 @@
+//! Per-turn activity ledger for `ChatWidget`.
+//!
+//! Tracks what a turn actually did — files touched with line counts,
+//! commands run, and how test-looking commands fared — and renders a compact
+//! summary cell after turns that modified files, so the transcript stays
+//! scannable without expanding every tool call.
+
+use codex_protocol::protocol::FileChange;
+
+use super::*;
+
+/// Activity recorded while a turn is running.
+#[derive(Default)]
+pub(super) struct TurnActivity {
+    /// Lines added/removed per touched file, in first-touched order.
+    files: Vec<(PathBuf, i64, i64)>,
+    /// Patch changes awaiting a success/failure end event, keyed by call id.
+    pending_patches: HashMap<String, Vec<(PathBuf, i64, i64)>>,
+    /// Number of commands the agent ran this turn.
+    commands_run: usize,
+    /// Whether any test-looking command ran, and whether they all passed.
+    ran_tests: bool,
+    tests_passed: bool,
+}
+
+impl TurnActivity {
+    /// Records the changes of a patch that has started applying; they count
+    /// toward the ledger only once the end event reports success.
+    pub(super) fn note_patch_begin(
+        &mut self,
+        call_id: &str,
+        changes: &HashMap<PathBuf, FileChange>,
+    ) {
+        let counted = changes
+            .iter()
+            .map(|(path, change)| {
+                let (added, removed) = count_change_lines(change);
+                (path.clone(), added, removed)
+            })
+            .collect();
+        self.pending_patches.insert(call_id.to_string(), counted);
+    }
+
+    /// Commits or discards a pending patch once its outcome is known.
+    pub(super) fn note_patch_end(&mut self, call_id: &str, success: bool) {
+        let Some(counted) = self.pending_patches.remove(call_id) else {
+            return;
+        };
+        if !success {
+            return;
+        }
+        for (path, added, removed) in counted {
+            match self.files.iter_mut().find(|(seen, _, _)| *seen == path) {
+                Some((_, seen_added, seen_removed)) => {
+                    *seen_added += added;
+                    *seen_removed += removed;
+                }
+                None => self.files.push((path, added, removed)),
+            }
+        }
+    }
+
+    /// Records a finished agent command.
+    pub(super) fn note_exec_end(&mut self, command_display: &str, exit_code: i32) {
+        self.commands_run += 1;
+        if looks_like_test_command(command_display) {
+            if !self.ran_tests {
+                self.tests_passed = true;
+            }
+            self.ran_tests = true;
+            self.tests_passed &= exit_code == 0;
+        }
+    }
+
+    fn has_file_changes(&self) -> bool {
+        !self.files.is_empty()
+    }
+}
+
+/// Lines added/removed by a single file change.
+fn count_change_lines(change: &FileChange) -> (i64, i64) {
+    match change {
+        FileChange::Add { content } => (content.lines().count() as i64, 0),
+        FileChange::Delete { content } => (0, content.lines().count() as i64),
+        FileChange::Update { unified_diff, .. } => {
+            let mut added = 0;
+            let mut removed = 0;
+            for line in unified_diff.lines() {
+                if line.starts_with('+') && !line.starts_with("+++") {
+                    added += 1;
+                } else if line.starts_with('-') && !line.starts_with("---") {
+                    removed += 1;
+                }
+            }
+            (added, removed)
+        }
+    }
+}
+
+/// Best-effort detection of commands that run a test suite.
+fn looks_like_test_command(command_display: &str) -> bool {
+    const TEST_MARKERS: [&str; 7] = [
+        "cargo test",
+        "cargo nextest",
+        "pytest",
+        "npm test",
+        "go test",
+        "jest",
+        "vitest",
+    ];
+    TEST_MARKERS
+        .iter()
+        .any(|marker| command_display.contains(marker))
+}
+
+impl ChatWidget {
+    /// Builds the "what changed this turn" cell, or `None` when the turn did
+    /// not modify any files. Consumes the recorded activity either way.
+    pub(super) fn turn_activity_summary_cell(&mut self) -> Option<PlainHistoryCell> {
+        let activity = std::mem::take(&mut self.turn_activity);
+        if !activity.has_file_changes() {
+            return None;
+        }
+        let total_added: i64 = activity.files.iter().map(|(_, added, _)| added).sum();
+        let total_removed: i64 = activity.files.iter().map(|(_, _, removed)| removed).sum();
+        let file_count = activity.files.len();
+        let mut header = format!(
+            "Changed this turn: {file_count} {} (+{total_added} -{total_removed}) · {} {}",
+            if file_count == 1 { "file" } else { "files" },
+            activity.commands_run,
+            if activity.commands_run == 1 {
+                "command"
+            } else {
+                "commands"
+            },
+        );
+        if activity.ran_tests {
+            header.push_str(if activity.tests_passed {
+                " · tests passed"
+            } else {
+                " · tests failed"
+            });
+        }
+        let mut lines: Vec<Line<'static>> = vec!["".into(), Line::from(header).dim()];
+        for (path, added, removed) in activity.files {
+            let display = crate::diff_render::display_path_for(&path, self.config.cwd.as_path());
+            lines.push(Line::from(format!("  {display} +{added} -{removed}")).dim());
+        }
+        Some(PlainHistoryCell::new(lines))
+    }
+}
+
+#[cfg(test)]
+mod turn_summary_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn update_diff_counts_added_and_removed_lines() {
+        let change = FileChange::Update {
+            unified_diff: "--- a/x.rs\n+++ b/x.rs\n@@ -1,2 +1,2 @@\n-old\n+new\n+extra\n"
+                .to_string(),
+            move_path: None,
+        };
+        assert_eq!(count_change_lines(&change), (2, 1));
+    }
+
+    #[test]
+    fn test_commands_are_detected() {
+        assert!(looks_like_test_command("cargo test -p codex-tui"));
+        assert!(looks_like_test_command("python -m pytest tests/"));
+        assert!(!looks_like_test_command("cargo build --workspace"));
+    }
+
+    #[test]
+    fn failed_patches_do_not_count() {
+        let mut activity = TurnActivity::default();
+        let changes = HashMap::from([(
+            PathBuf::from("a.rs"),
+            FileChange::Add {
+                content: "fn a() {}\n".to_string(),
+            },
+        )]);
+        activity.note_patch_begin("call-1", &changes);
+        activity.note_patch_end("call-1", /*success*/ false);
+        assert!(!activity.has_file_changes());
+    }
+}
@@ -0,0 +1,137 @@
+//! Parsing and display of `【F:path†Lstart-Lend】` source citations.
+//!
+//! Models emit citations in a bracketed wire format that reads poorly in a
+//! transcript. The markdown path replaces each bracket with a compact
+//! superscript marker (`¹`, `²`, …); `/citations` lists the sources behind
+//! the markers of the last response and previews the cited lines.
+
+use regex_lite::Regex;
+use std::sync::LazyLock;
+
+/// A single source citation extracted from an agent message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Citation {
+    pub(crate) path: String,
+    pub(crate) start_line: u32,
+    pub(crate) end_line: u32,
+}
+
+impl Citation {
+    /// Location suffix like `L12` or `L12-L20`.
+    pub(crate) fn location(&self) -> String {
+        if self.start_line == self.end_line {
+            format!("L{}", self.start_line)
+        } else {
+            format!("L{}-L{}", self.start_line, self.end_line)
+        }
+    }
+}
+
+static CITATION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"【F:([^†】]+)†L(\d+)(?:-L(\d+))?】").expect("citation regex should compile")
+});
+
+/// Replaces raw citation brackets in `source` with superscript markers and
+/// returns the citations in marker order. Repeated citations of the same
+/// location reuse the earlier marker.
+pub(crate) fn strip_citations(source: &str) -> (String, Vec<Citation>) {
+    let mut citations: Vec<Citation> = Vec::new();
+    let mut stripped = String::with_capacity(source.len());
+    let mut last = 0usize;
+    for captures in CITATION_RE.captures_iter(source) {
+        let whole = captures.get(0).expect("match 0 is always present");
+        let path = captures
+            .get(1)
+            .expect("path group is always present")
+            .as_str()
+            .to_string();
+        let start_line: u32 = captures
+            .get(2)
+            .and_then(|m| m.as_str().parse().ok())
+            .unwrap_or(1);
+        let end_line: u32 = captures
+            .get(3)
+            .and_then(|m| m.as_str().parse().ok())
+            .unwrap_or(start_line)
+            .max(start_line);
+        let citation = Citation {
+            path,
+            start_line,
+            end_line,
+        };
+        let number = match citations.iter().position(|seen| *seen == citation) {
+            Some(index) => index + 1,
+            None => {
+                citations.push(citation);
+                citations.len()
+            }
+        };
+        stripped.push_str(&source[last..whole.start()]);
+        // Citations usually follow a space in the prose; attach the marker
+        // to the preceding word instead.
+        if stripped.ends_with(' ') {
+            stripped.pop();
+        }
+        stripped.push_str(&superscript_marker(number));
+        last = whole.end();
+    }
+    stripped.push_str(&source[last..]);
+    (stripped, citations)
+}
+
+/// Renders `number` with Unicode superscript digits, e.g. `12` → `¹²`.
+pub(crate) fn superscript_marker(number: usize) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    number
+        .to_string()
+        .chars()
+        .map(|c| DIGITS[c.to_digit(10).expect("decimal digit") as usize])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn strips_brackets_into_superscript_markers() {
+        let (stripped, citations) =
+            strip_citations("Before 【F:/x.rs†L1】 and 【F:/y.rs†L3-L9】 after.");
+        assert_eq!(stripped, "Before¹ and² after.");
+        assert_eq!(
+            citations,
+            vec![
+                Citation {
+                    path: "/x.rs".to_string(),
+                    start_line: 1,
+                    end_line: 1,
+                },
+                Citation {
+                    path: "/y.rs".to_string(),
+                    start_line: 3,
+                    end_line: 9,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn repeated_citations_reuse_markers() {
+        let (stripped, citations) = strip_citations("One 【F:/x.rs†L1】 two 【F:/x.rs†L1】.");
+        assert_eq!(stripped, "One¹ two¹.");
+        assert_eq!(citations.len(), 1);
+    }
+
+    #[test]
+    fn prose_without_citations_is_unchanged() {
+        let (stripped, citations) = strip_citations("No citations here.");
+        assert_eq!(stripped, "No citations here.");
+        assert!(citations.is_empty());
+    }
+
+    #[test]
+    fn marker_numbers_past_nine_use_all_digits() {
+        assert_eq!(superscript_marker(12), "¹²");
+    }
+}
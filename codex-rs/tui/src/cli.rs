@@ -11,6 +11,12 @@ pub struct Cli {
     #[arg(value_name = "PROMPT", value_hint = clap::ValueHint::Other)]
     pub prompt: Option<String>,
 
+    /// Start the session from a named template defined under
+    /// `[templates.<name>]` in config.toml (instructions, pinned files,
+    /// skills, initial task checklist).
+    #[arg(long = "template", value_name = "NAME")]
+    pub template: Option<String>,
+
     /// Optional image(s) to attach to the initial prompt.
     #[arg(long = "image", short = 'i', value_name = "FILE", value_delimiter = ',', num_args = 1..)]
     pub images: Vec<PathBuf>,
@@ -94,6 +100,15 @@ pub struct Cli {
     )]
     pub dangerously_bypass_approvals_and_sandbox: bool,
 
+    /// Hard-disable file modifications and mutating commands for the session
+    /// (read-only sandbox with approvals off). Toggle later with `/readonly`.
+    #[arg(
+        long = "read-only",
+        default_value_t = false,
+        conflicts_with_all = ["sandbox_mode", "approval_policy", "full_auto", "dangerously_bypass_approvals_and_sandbox"]
+    )]
+    pub read_only: bool,
+
     /// Tell the agent to use the specified directory as its working root.
     /// In remote mode, the path is forwarded to the server and resolved there.
     #[clap(long = "cd", short = 'C', value_name = "DIR")]
@@ -115,6 +130,11 @@ pub struct Cli {
     #[arg(long = "no-alt-screen", default_value_t = false)]
     pub no_alt_screen: bool,
 
+    /// Measure startup phase timings (config load, terminal init, theme
+    /// resolution, grammar warm-up) and report them in the transcript.
+    #[arg(long = "profile-startup", default_value_t = false)]
+    pub profile_startup: bool,
+
     #[clap(skip)]
     pub config_overrides: CliConfigOverrides,
 }
@@ -1,3 +1,78 @@
+use std::sync::OnceLock;
+
+/// Minimum WCAG contrast ratio from `tui.accessibility.min_contrast`,
+/// clamped to the meaningful 1.0–21.0 range. `None` disables enforcement.
+static MIN_CONTRAST: OnceLock<Option<f64>> = OnceLock::new();
+
+/// Record the configured minimum contrast ratio. Called once at startup with
+/// the final resolved config, before anything renders.
+pub(crate) fn init_min_contrast(min_contrast: Option<f64>) {
+    let clamped = min_contrast.map(|ratio| ratio.clamp(1.0, 21.0));
+    if MIN_CONTRAST.set(clamped).is_err() {
+        tracing::debug!("init_min_contrast called more than once; value unchanged");
+    }
+}
+
+/// Adapt a resolved foreground color for display against the terminal
+/// background, enforcing the configured minimum contrast ratio. Returns the
+/// color unchanged when `tui.accessibility.min_contrast` is unset.
+pub(crate) fn adapt_color_for_terminal(fg: (u8, u8, u8), bg: (u8, u8, u8)) -> (u8, u8, u8) {
+    match MIN_CONTRAST.get().copied().flatten() {
+        Some(min) => ensure_min_contrast(fg, bg, min),
+        None => fg,
+    }
+}
+
+/// Nudge `fg` toward white (on dark backgrounds) or black (on light ones)
+/// until it meets `min` contrast against `bg`, preserving as much of the
+/// original hue as the target allows. Binary-searches the blend amount; the
+/// result saturates at pure white/black when even that cannot reach `min`.
+pub(crate) fn ensure_min_contrast(fg: (u8, u8, u8), bg: (u8, u8, u8), min: f64) -> (u8, u8, u8) {
+    if contrast_ratio(fg, bg) >= min {
+        return fg;
+    }
+    let target = if is_light(bg) {
+        (0, 0, 0)
+    } else {
+        (255, 255, 255)
+    };
+    if contrast_ratio(target, bg) < min {
+        return target;
+    }
+    // Invariant: `lo` fails the ratio, `hi` meets it.
+    let (mut lo, mut hi) = (0.0_f32, 1.0_f32);
+    for _ in 0..8 {
+        let mid = (lo + hi) / 2.0;
+        if contrast_ratio(blend(target, fg, mid), bg) >= min {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    blend(target, fg, hi)
+}
+
+/// WCAG 2.x contrast ratio between two colors, in the range 1.0–21.0.
+pub(crate) fn contrast_ratio(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let la = relative_luminance(a);
+    let lb = relative_luminance(b);
+    let (lighter, darker) = if la >= lb { (la, lb) } else { (lb, la) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// WCAG relative luminance of an sRGB color.
+fn relative_luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    fn channel(c: u8) -> f64 {
+        let c = c as f64 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+    0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+}
+
 pub(crate) fn is_light(bg: (u8, u8, u8)) -> bool {
     let (r, g, b) = bg;
     let y = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
@@ -73,3 +148,46 @@ pub(crate) fn perceptual_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> f32 {
 
     (dl * dl + da * da + db * db).sqrt()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contrast_ratio_matches_wcag_reference_points() {
+        assert!((contrast_ratio((255, 255, 255), (0, 0, 0)) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio((128, 128, 128), (128, 128, 128)) - 1.0).abs() < 0.01);
+        // Symmetric in its arguments.
+        assert_eq!(
+            contrast_ratio((200, 30, 30), (10, 10, 10)),
+            contrast_ratio((10, 10, 10), (200, 30, 30)),
+        );
+    }
+
+    #[test]
+    fn ensure_min_contrast_leaves_compliant_colors_alone() {
+        let fg = (255, 255, 255);
+        assert_eq!(ensure_min_contrast(fg, (0, 0, 0), 4.5), fg);
+    }
+
+    #[test]
+    fn ensure_min_contrast_brightens_dim_colors_on_dark_backgrounds() {
+        let adapted = ensure_min_contrast((60, 60, 60), (0, 0, 0), 4.5);
+        assert!(contrast_ratio(adapted, (0, 0, 0)) >= 4.5);
+    }
+
+    #[test]
+    fn ensure_min_contrast_darkens_pale_colors_on_light_backgrounds() {
+        let adapted = ensure_min_contrast((230, 230, 180), (255, 255, 255), 4.5);
+        assert!(contrast_ratio(adapted, (255, 255, 255)) >= 4.5);
+    }
+
+    #[test]
+    fn ensure_min_contrast_saturates_at_the_extreme() {
+        // Nothing reaches 30:1; expect pure white on black.
+        assert_eq!(
+            ensure_min_contrast((60, 60, 60), (0, 0, 0), 21.0),
+            (255, 255, 255)
+        );
+    }
+}
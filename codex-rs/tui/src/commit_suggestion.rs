@@ -0,0 +1,221 @@
+//! Heuristic conventional-commits suggestion for `/suggest-commit`.
+//!
+//! Works entirely locally: the change type comes from what kinds of files the
+//! uncommitted diff touches, the scope from their shared leading path
+//! component, and the summary from the most recent user prompt in the session,
+//! falling back to the changed file list. The result pre-fills an editable
+//! popup, so the heuristics only need to land close.
+
+/// Builds a `type(scope): summary` commit message from the uncommitted diff
+/// (as produced by [`crate::get_git_diff::get_git_diff`], which may contain
+/// ANSI color codes) and the session's recent user prompts, newest last.
+pub(crate) fn suggest_commit_message(diff: &str, recent_user_prompts: &[String]) -> String {
+    let diff = strip_ansi(diff);
+    let files = changed_files(&diff);
+    let has_new_files = diff.lines().any(|line| line.starts_with("new file mode"));
+    let prompt = recent_user_prompts
+        .iter()
+        .rev()
+        .map(|prompt| prompt.trim())
+        .find(|prompt| !prompt.is_empty() && !prompt.starts_with('/'));
+
+    let commit_type = commit_type(&files, has_new_files, prompt);
+    let header = match scope(&files) {
+        Some(scope) => format!("{commit_type}({scope}): "),
+        None => format!("{commit_type}: "),
+    };
+    let summary = summary(prompt, &files, MAX_HEADER_LEN.saturating_sub(header.len()));
+    format!("{header}{summary}")
+}
+
+/// Conventional limit for the whole header line.
+const MAX_HEADER_LEN: usize = 72;
+
+fn commit_type(files: &[String], has_new_files: bool, prompt: Option<&str>) -> &'static str {
+    if !files.is_empty() && files.iter().all(|file| is_docs(file)) {
+        return "docs";
+    }
+    if !files.is_empty() && files.iter().all(|file| is_test(file)) {
+        return "test";
+    }
+    if !files.is_empty() && files.iter().all(|file| is_manifest(file)) {
+        return "chore";
+    }
+    let prompt = prompt.unwrap_or_default().to_lowercase();
+    if ["fix", "bug", "crash", "regression", "broken"]
+        .iter()
+        .any(|needle| prompt.contains(needle))
+    {
+        return "fix";
+    }
+    if ["refactor", "rename", "clean up", "cleanup", "simplify"]
+        .iter()
+        .any(|needle| prompt.contains(needle))
+    {
+        return "refactor";
+    }
+    if has_new_files {
+        return "feat";
+    }
+    "chore"
+}
+
+/// The scope is the first path component every changed file shares; files at
+/// the repository root (or a generic `src/` prefix) leave the scope off.
+fn scope(files: &[String]) -> Option<String> {
+    let mut components = files
+        .iter()
+        .filter_map(|file| file.split('/').next().filter(|_| file.contains('/')));
+    let first = components.next()?;
+    if components.all(|component| component == first)
+        && files.iter().all(|file| file.contains('/'))
+        && first != "src"
+    {
+        Some(first.to_string())
+    } else {
+        None
+    }
+}
+
+fn summary(prompt: Option<&str>, files: &[String], budget: usize) -> String {
+    if let Some(prompt) = prompt {
+        let first_line = prompt.lines().next().unwrap_or_default().trim();
+        let mut summary = first_line.trim_end_matches('.').to_string();
+        if let Some(first) = summary.get(..1) {
+            summary = format!("{}{}", first.to_lowercase(), &summary[1..]);
+        }
+        if !summary.is_empty() {
+            return truncate_at_word(&summary, budget);
+        }
+    }
+    let Some(first_file) = files.first() else {
+        return "update working tree".to_string();
+    };
+    let name = first_file.rsplit('/').next().unwrap_or(first_file);
+    let summary = match files.len() {
+        1 => format!("update {name}"),
+        2 => format!("update {name} and 1 more file"),
+        n => format!("update {name} and {} more files", n - 1),
+    };
+    truncate_at_word(&summary, budget)
+}
+
+fn truncate_at_word(text: &str, budget: usize) -> String {
+    if text.chars().count() <= budget {
+        return text.to_string();
+    }
+    let truncated: String = text.chars().take(budget).collect();
+    match truncated.rfind(' ') {
+        Some(cut) if cut > 0 => truncated[..cut].to_string(),
+        _ => truncated,
+    }
+}
+
+/// Collects the post-image paths from `diff --git` headers, skipping the
+/// `/dev/null` placeholder used for untracked-file diffs.
+fn changed_files(diff: &str) -> Vec<String> {
+    let mut files = Vec::new();
+    for line in diff.lines() {
+        let Some(rest) = line.strip_prefix("diff --git ") else {
+            continue;
+        };
+        let Some(b_path) = rest.split(" b/").nth(1) else {
+            continue;
+        };
+        if b_path.is_empty() || b_path == "dev/null" {
+            continue;
+        }
+        let b_path = b_path.to_string();
+        if !files.contains(&b_path) {
+            files.push(b_path);
+        }
+    }
+    files
+}
+
+fn is_docs(file: &str) -> bool {
+    file.starts_with("docs/") || file.ends_with(".md")
+}
+
+fn is_test(file: &str) -> bool {
+    file.contains("tests/") || file.ends_with("_tests.rs") || file.ends_with("_test.rs")
+}
+
+fn is_manifest(file: &str) -> bool {
+    let name = file.rsplit('/').next().unwrap_or(file);
+    matches!(
+        name.rsplit('.').next(),
+        Some("toml" | "lock" | "yml" | "yaml" | "json")
+    )
+}
+
+/// Drops ANSI escape sequences so `git diff --color` output parses cleanly.
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            out.push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'[') {
+            chars.next();
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn diff_for(files: &[(&str, bool)]) -> String {
+        let mut diff = String::new();
+        for (file, is_new) in files {
+            diff.push_str(&format!("diff --git a/{file} b/{file}\n"));
+            if *is_new {
+                diff.push_str("new file mode 100644\n");
+            }
+            diff.push_str("@@ -0,0 +1 @@\n+line\n");
+        }
+        diff
+    }
+
+    #[test]
+    fn docs_only_diff_suggests_docs_type_and_scope() {
+        let diff = diff_for(&[("docs/install.md", false), ("docs/config.md", false)]);
+        let message = suggest_commit_message(&diff, &[]);
+        assert_eq!(message, "docs(docs): update install.md and 1 more file");
+    }
+
+    #[test]
+    fn fix_prompt_wins_and_becomes_the_summary() {
+        let diff = diff_for(&[("tui/src/app.rs", false)]);
+        let prompts = vec![
+            "/diff".to_string(),
+            "Fix the crash when resizing the window.".to_string(),
+        ];
+        let message = suggest_commit_message(&diff, &prompts);
+        assert_eq!(message, "fix(tui): fix the crash when resizing the window");
+    }
+
+    #[test]
+    fn new_files_without_prompt_suggest_feat() {
+        let diff = diff_for(&[("core/src/widget.rs", true)]);
+        let message = suggest_commit_message(&diff, &[]);
+        assert_eq!(message, "feat(core): update widget.rs");
+    }
+
+    #[test]
+    fn ansi_colored_headers_still_parse() {
+        let diff = "\u{1b}[1mdiff --git a/README.md b/README.md\u{1b}[m\n";
+        let message = suggest_commit_message(diff, &[]);
+        assert_eq!(message, "docs: update README.md");
+    }
+}
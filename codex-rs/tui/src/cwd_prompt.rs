@@ -96,7 +96,7 @@ pub(crate) async fn run_cwd_selection_prompt(
         if let Some(event) = events.next().await {
             match event {
                 TuiEvent::Key(key_event) => screen.handle_key(key_event),
-                TuiEvent::Paste(_) => {}
+                TuiEvent::Mouse(_) | TuiEvent::Paste(_) => {}
                 TuiEvent::Draw => {
                     tui.draw(u16::MAX, |frame| {
                         frame.render_widget_ref(&screen, frame.area());
@@ -117,7 +117,7 @@ pub(crate) async fn run_cwd_selection_prompt(
     }
 }
 
-struct CwdPromptScreen {
+pub(crate) struct CwdPromptScreen {
     request_frame: FrameRequester,
     action: CwdPromptAction,
     current_cwd: String,
@@ -128,7 +128,7 @@ struct CwdPromptScreen {
 }
 
 impl CwdPromptScreen {
-    fn new(
+    pub(crate) fn new(
         request_frame: FrameRequester,
         action: CwdPromptAction,
         current_cwd: String,
@@ -0,0 +1,254 @@
+use crate::history_cell::PlainHistoryCell;
+use crate::terminal_palette;
+use crate::terminal_palette::StdoutColorLevel;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+// Rolling render timings captured by `record_frame` on every draw. Stored as
+// micros in atomics so recording stays lock-free on the draw path.
+static LAST_FRAME_MICROS: AtomicU64 = AtomicU64::new(0);
+static MAX_FRAME_MICROS: AtomicU64 = AtomicU64::new(0);
+static TOTAL_FRAME_MICROS: AtomicU64 = AtomicU64::new(0);
+static FRAME_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Record how long one frame took to draw. Called from `Tui::draw`.
+pub(crate) fn record_frame(duration: Duration) {
+    let micros = u64::try_from(duration.as_micros()).unwrap_or(u64::MAX);
+    LAST_FRAME_MICROS.store(micros, Ordering::Relaxed);
+    MAX_FRAME_MICROS.fetch_max(micros, Ordering::Relaxed);
+    TOTAL_FRAME_MICROS.fetch_add(micros, Ordering::Relaxed);
+    FRAME_COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
+#[derive(Clone, Copy, Default)]
+pub(crate) struct FrameTimings {
+    pub(crate) frames: u64,
+    pub(crate) last: Duration,
+    pub(crate) average: Duration,
+    pub(crate) max: Duration,
+}
+
+fn frame_timings() -> FrameTimings {
+    let frames = FRAME_COUNT.load(Ordering::Relaxed);
+    if frames == 0 {
+        return FrameTimings::default();
+    }
+    FrameTimings {
+        frames,
+        last: Duration::from_micros(LAST_FRAME_MICROS.load(Ordering::Relaxed)),
+        average: Duration::from_micros(TOTAL_FRAME_MICROS.load(Ordering::Relaxed) / frames),
+        max: Duration::from_micros(MAX_FRAME_MICROS.load(Ordering::Relaxed)),
+    }
+}
+
+/// Everything `/debug-ui` reports, collected up front so rendering stays pure
+/// and testable.
+pub(crate) struct UiDiagnostics {
+    /// Terminal size in (columns, rows).
+    pub(crate) terminal_size: Option<(u16, u16)>,
+    /// Cell size in pixels as (width, height), when the terminal reports its
+    /// window size in pixels.
+    pub(crate) cell_pixels: Option<(f32, f32)>,
+    pub(crate) color_level: StdoutColorLevel,
+    pub(crate) foreground: Option<(u8, u8, u8)>,
+    pub(crate) background: Option<(u8, u8, u8)>,
+    pub(crate) enhanced_keys_supported: bool,
+    pub(crate) timings: FrameTimings,
+}
+
+pub(crate) fn collect_ui_diagnostics(enhanced_keys_supported: bool) -> UiDiagnostics {
+    UiDiagnostics {
+        terminal_size: crossterm::terminal::size().ok(),
+        cell_pixels: query_cell_pixels(),
+        color_level: terminal_palette::stdout_color_level(),
+        foreground: terminal_palette::default_fg(),
+        background: terminal_palette::default_bg(),
+        enhanced_keys_supported,
+        timings: frame_timings(),
+    }
+}
+
+#[cfg(unix)]
+fn query_cell_pixels() -> Option<(f32, f32)> {
+    let window = crossterm::terminal::window_size().ok()?;
+    if window.columns == 0 || window.rows == 0 || window.width == 0 || window.height == 0 {
+        return None;
+    }
+    Some((
+        f32::from(window.width) / f32::from(window.columns),
+        f32::from(window.height) / f32::from(window.rows),
+    ))
+}
+
+#[cfg(not(unix))]
+fn query_cell_pixels() -> Option<(f32, f32)> {
+    None
+}
+
+pub(crate) fn new_debug_ui_output(diagnostics: &UiDiagnostics) -> PlainHistoryCell {
+    PlainHistoryCell::new(render_debug_ui_lines(diagnostics))
+}
+
+fn render_debug_ui_lines(diagnostics: &UiDiagnostics) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = vec!["/debug-ui".magenta().into(), "".into()];
+
+    lines.push("Terminal:".bold().into());
+    match diagnostics.terminal_size {
+        Some((columns, rows)) => lines.push(format!("  - size: {columns}x{rows} cells").into()),
+        None => lines.push("  - size: <unknown>".dim().into()),
+    }
+    match diagnostics.cell_pixels {
+        Some((width, height)) => {
+            let aspect = width / height;
+            lines.push(format!("  - cell: {width:.1}x{height:.1} px (aspect {aspect:.2})").into());
+        }
+        None => lines.push(
+            "  - cell: <unknown> (terminal did not report pixel size)"
+                .dim()
+                .into(),
+        ),
+    }
+    lines.push(
+        format!(
+            "  - colors: {}",
+            format_color_level(diagnostics.color_level)
+        )
+        .into(),
+    );
+    lines.push(rgb_line("foreground", diagnostics.foreground));
+    lines.push(rgb_line("background", diagnostics.background));
+    let enhancements = if diagnostics.enhanced_keys_supported {
+        "enabled"
+    } else {
+        "not supported"
+    };
+    lines.push(format!("  - keyboard enhancements: {enhancements}").into());
+
+    lines.push("".into());
+    lines.push("Rendering:".bold().into());
+    let timings = diagnostics.timings;
+    if timings.frames == 0 {
+        lines.push("  - no frames drawn yet".dim().into());
+    } else {
+        lines.push(format!("  - frames drawn: {}", timings.frames).into());
+        lines.push(
+            format!(
+                "  - draw time: last {}, avg {}, max {}",
+                format_frame_duration(timings.last),
+                format_frame_duration(timings.average),
+                format_frame_duration(timings.max),
+            )
+            .into(),
+        );
+    }
+
+    lines
+}
+
+fn format_color_level(level: StdoutColorLevel) -> &'static str {
+    match level {
+        StdoutColorLevel::TrueColor => "truecolor",
+        StdoutColorLevel::Ansi256 => "256-color",
+        StdoutColorLevel::Ansi16 => "16-color",
+        StdoutColorLevel::Unknown => "unknown",
+    }
+}
+
+fn rgb_line(name: &str, rgb: Option<(u8, u8, u8)>) -> Line<'static> {
+    match rgb {
+        Some((r, g, b)) => format!("  - {name}: #{r:02x}{g:02x}{b:02x}").into(),
+        None => format!("  - {name}: <not detected>").dim().into(),
+    }
+}
+
+fn format_frame_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    if micros < 1_000 {
+        format!("{micros}µs")
+    } else {
+        format!("{:.1}ms", micros as f64 / 1_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameTimings;
+    use super::StdoutColorLevel;
+    use super::UiDiagnostics;
+    use super::format_frame_duration;
+    use super::render_debug_ui_lines;
+    use std::time::Duration;
+
+    fn render_to_text(lines: &[ratatui::text::Line<'static>]) -> String {
+        lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn debug_ui_output_reports_detected_values() {
+        let diagnostics = UiDiagnostics {
+            terminal_size: Some((120, 40)),
+            cell_pixels: Some((9.0, 18.0)),
+            color_level: StdoutColorLevel::TrueColor,
+            foreground: Some((0xc0, 0xc0, 0xc0)),
+            background: Some((0x1e, 0x1e, 0x2e)),
+            enhanced_keys_supported: true,
+            timings: FrameTimings {
+                frames: 42,
+                last: Duration::from_micros(850),
+                average: Duration::from_micros(1_200),
+                max: Duration::from_micros(15_400),
+            },
+        };
+
+        let rendered = render_to_text(&render_debug_ui_lines(&diagnostics));
+        assert!(rendered.contains("  - size: 120x40 cells"));
+        assert!(rendered.contains("  - cell: 9.0x18.0 px (aspect 0.50)"));
+        assert!(rendered.contains("  - colors: truecolor"));
+        assert!(rendered.contains("  - background: #1e1e2e"));
+        assert!(rendered.contains("  - keyboard enhancements: enabled"));
+        assert!(rendered.contains("  - frames drawn: 42"));
+        assert!(rendered.contains("  - draw time: last 850µs, avg 1.2ms, max 15.4ms"));
+    }
+
+    #[test]
+    fn debug_ui_output_marks_missing_values() {
+        let diagnostics = UiDiagnostics {
+            terminal_size: None,
+            cell_pixels: None,
+            color_level: StdoutColorLevel::Unknown,
+            foreground: None,
+            background: None,
+            enhanced_keys_supported: false,
+            timings: FrameTimings::default(),
+        };
+
+        let rendered = render_to_text(&render_debug_ui_lines(&diagnostics));
+        assert!(rendered.contains("  - size: <unknown>"));
+        assert!(rendered.contains("  - cell: <unknown> (terminal did not report pixel size)"));
+        assert!(rendered.contains("  - background: <not detected>"));
+        assert!(rendered.contains("  - keyboard enhancements: not supported"));
+        assert!(rendered.contains("  - no frames drawn yet"));
+    }
+
+    #[test]
+    fn frame_durations_format_in_micros_then_millis() {
+        assert_eq!(format_frame_duration(Duration::from_micros(999)), "999µs");
+        assert_eq!(format_frame_duration(Duration::from_micros(1_000)), "1.0ms");
+        assert_eq!(
+            format_frame_duration(Duration::from_micros(15_449)),
+            "15.4ms"
+        );
+    }
+}
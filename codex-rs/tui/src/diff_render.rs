@@ -75,6 +75,37 @@ const LIGHT_256_ADD_NUM_BG_IDX: u8 = 157;
 const LIGHT_256_DEL_NUM_BG_IDX: u8 = 217;
 const LIGHT_256_GUTTER_FG_IDX: u8 = 236;
 
+// Colorblind-safe palettes (`tui.accessibility.colorblind`).  Red-green
+// deficiencies (deuteranopia/protanopia) swap add/del to blue/orange;
+// tritanopia (blue-yellow) swaps to teal/magenta.  Values were picked to sit
+// at roughly the same lightness as the default palette so diff lines keep
+// their weight, only the hue axis changes.
+const CB_RG_DARK_TC_ADD_LINE_BG_RGB: (u8, u8, u8) = (27, 48, 76); // #1B304C
+const CB_RG_DARK_TC_DEL_LINE_BG_RGB: (u8, u8, u8) = (77, 53, 20); // #4D3514
+const CB_RG_LIGHT_TC_ADD_LINE_BG_RGB: (u8, u8, u8) = (221, 235, 255); // #ddebff
+const CB_RG_LIGHT_TC_DEL_LINE_BG_RGB: (u8, u8, u8) = (255, 237, 213); // #ffedd5
+const CB_RG_LIGHT_TC_ADD_NUM_BG_RGB: (u8, u8, u8) = (184, 214, 255); // #b8d6ff
+const CB_RG_LIGHT_TC_DEL_NUM_BG_RGB: (u8, u8, u8) = (255, 216, 168); // #ffd8a8
+const CB_TRI_DARK_TC_ADD_LINE_BG_RGB: (u8, u8, u8) = (19, 58, 58); // #133A3A
+const CB_TRI_DARK_TC_DEL_LINE_BG_RGB: (u8, u8, u8) = (74, 29, 64); // #4A1D40
+const CB_TRI_LIGHT_TC_ADD_LINE_BG_RGB: (u8, u8, u8) = (214, 245, 245); // #d6f5f5
+const CB_TRI_LIGHT_TC_DEL_LINE_BG_RGB: (u8, u8, u8) = (252, 228, 246); // #fce4f6
+const CB_TRI_LIGHT_TC_ADD_NUM_BG_RGB: (u8, u8, u8) = (166, 226, 226); // #a6e2e2
+const CB_TRI_LIGHT_TC_DEL_NUM_BG_RGB: (u8, u8, u8) = (244, 194, 231); // #f4c2e7
+
+const CB_RG_DARK_256_ADD_LINE_BG_IDX: u8 = 17;
+const CB_RG_DARK_256_DEL_LINE_BG_IDX: u8 = 94;
+const CB_RG_LIGHT_256_ADD_LINE_BG_IDX: u8 = 153;
+const CB_RG_LIGHT_256_DEL_LINE_BG_IDX: u8 = 223;
+const CB_RG_LIGHT_256_ADD_NUM_BG_IDX: u8 = 117;
+const CB_RG_LIGHT_256_DEL_NUM_BG_IDX: u8 = 215;
+const CB_TRI_DARK_256_ADD_LINE_BG_IDX: u8 = 23;
+const CB_TRI_DARK_256_DEL_LINE_BG_IDX: u8 = 53;
+const CB_TRI_LIGHT_256_ADD_LINE_BG_IDX: u8 = 159;
+const CB_TRI_LIGHT_256_DEL_LINE_BG_IDX: u8 = 225;
+const CB_TRI_LIGHT_256_ADD_NUM_BG_IDX: u8 = 116;
+const CB_TRI_LIGHT_256_DEL_NUM_BG_IDX: u8 = 218;
+
 use crate::color::is_light;
 use crate::color::perceptual_distance;
 use crate::exec_command::relativize_to_home;
@@ -93,11 +124,29 @@ use crate::terminal_palette::default_bg;
 use crate::terminal_palette::indexed_color;
 use crate::terminal_palette::rgb_color;
 use crate::terminal_palette::stdout_color_level;
+use codex_config::types::ColorblindPalette;
 use codex_git_utils::get_git_repo_root;
 use codex_protocol::protocol::FileChange;
 use codex_terminal_detection::TerminalName;
 use codex_terminal_detection::terminal_info;
 
+/// Colorblind palette from `tui.accessibility.colorblind`, recorded once at
+/// startup. `None` keeps the default red/green palette.
+static COLORBLIND_PALETTE: std::sync::OnceLock<Option<ColorblindPalette>> =
+    std::sync::OnceLock::new();
+
+/// Record the configured colorblind palette. Called once at startup with the
+/// final resolved config, before anything renders.
+pub(crate) fn init_colorblind_palette(palette: Option<ColorblindPalette>) {
+    if COLORBLIND_PALETTE.set(palette).is_err() {
+        tracing::debug!("init_colorblind_palette called more than once; value unchanged");
+    }
+}
+
+fn colorblind_palette() -> Option<ColorblindPalette> {
+    COLORBLIND_PALETTE.get().copied().flatten()
+}
+
 /// Classifies a diff line for gutter sign rendering and style selection.
 ///
 /// `Insert` renders with a `+` sign and green text, `Delete` with `-` and red
@@ -238,6 +287,11 @@ fn resolve_diff_backgrounds_for(
     let Some(level) = RichDiffColorLevel::from_diff_color_level(color_level) else {
         return resolved;
     };
+    // A colorblind palette is an explicit accessibility request; theme scope
+    // backgrounds (typically red/green) must not override it.
+    if colorblind_palette().is_some() {
+        return resolved;
+    }
 
     if let Some(rgb) = scope_backgrounds.inserted {
         resolved.add = Some(color_from_rgb_for_level(rgb, level));
@@ -278,7 +332,7 @@ fn color_from_rgb_for_level(rgb: (u8, u8, u8), color_level: RichDiffColorLevel)
 /// Skips the first 16 entries (system colors) because their actual RGB
 /// values depend on the user's terminal configuration and are unreliable
 /// for distance calculations.
-fn quantize_rgb_to_ansi256(target: (u8, u8, u8)) -> Color {
+pub(crate) fn quantize_rgb_to_ansi256(target: (u8, u8, u8)) -> Color {
     let best_index = XTERM_COLORS
         .iter()
         .enumerate()
@@ -352,6 +406,183 @@ pub(crate) fn create_diff_summary(
     render_changes_block(rows, wrap_cols, cwd)
 }
 
+/// `git diff --stat`-style header block for a patch's change set: one aligned
+/// row per file, sorted by path, with a scaled `+`/`-` bar.
+pub(crate) fn create_diff_stat_header(
+    changes: &HashMap<PathBuf, FileChange>,
+    cwd: &Path,
+    wrap_cols: usize,
+) -> Vec<RtLine<'static>> {
+    let rows = collect_rows(changes);
+    let stat_rows: Vec<(String, usize, usize)> = rows
+        .iter()
+        .map(|row| {
+            let mut path = display_path_for(&row.path, cwd);
+            if let Some(move_path) = &row.move_path {
+                path.push_str(&format!(" \u{2192} {}", display_path_for(move_path, cwd)));
+            }
+            (path, row.added, row.removed)
+        })
+        .collect();
+    render_diff_stat_block(&stat_rows, wrap_cols)
+}
+
+/// Per-file stat rows parsed from raw unified diff text (`git diff` output),
+/// for the `/diff` pager header. Rows are (post-image path, added, removed).
+pub(crate) fn diff_stat_rows_from_unified(text: &str) -> Vec<(String, usize, usize)> {
+    let mut rows: Vec<(String, usize, usize)> = Vec::new();
+    for line in text.lines() {
+        if let Some(rest) = line.strip_prefix("diff --git a/") {
+            let path = rest
+                .split_once(" b/")
+                .map(|(_, post)| post)
+                .unwrap_or(rest)
+                .to_string();
+            rows.push((path, 0, 0));
+        } else if let Some((_, added, removed)) = rows.last_mut() {
+            if line.starts_with("+++") || line.starts_with("---") {
+                continue;
+            } else if line.starts_with('+') {
+                *added += 1;
+            } else if line.starts_with('-') {
+                *removed += 1;
+            }
+        }
+    }
+    rows
+}
+
+/// A stageable hunk extracted from unified diff text: `patch` is the file
+/// header plus the single hunk body, newline-terminated and ready to pipe to
+/// `git apply --cached`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnifiedDiffHunk {
+    /// Index of the hunk's `@@` line within the parsed lines.
+    pub(crate) line_index: usize,
+    /// File header plus hunk body.
+    pub(crate) patch: String,
+}
+
+/// Splits plain unified diff lines into per-hunk patches, each carrying the
+/// enclosing file header so hunks apply independently.
+pub(crate) fn collect_unified_hunks(lines: &[String]) -> Vec<UnifiedDiffHunk> {
+    fn flush(
+        header: &[String],
+        hunk: &mut Option<(usize, Vec<String>)>,
+        hunks: &mut Vec<UnifiedDiffHunk>,
+    ) {
+        if let Some((line_index, body)) = hunk.take() {
+            let mut patch = String::new();
+            for line in header.iter().chain(body.iter()) {
+                patch.push_str(line);
+                patch.push('\n');
+            }
+            hunks.push(UnifiedDiffHunk { line_index, patch });
+        }
+    }
+
+    let mut hunks: Vec<UnifiedDiffHunk> = Vec::new();
+    let mut header: Vec<String> = Vec::new();
+    let mut hunk: Option<(usize, Vec<String>)> = None;
+    for (idx, line) in lines.iter().enumerate() {
+        if line.starts_with("diff --git ") {
+            flush(&header, &mut hunk, &mut hunks);
+            header = vec![line.clone()];
+        } else if line.starts_with("@@") {
+            flush(&header, &mut hunk, &mut hunks);
+            hunk = Some((idx, vec![line.clone()]));
+        } else if let Some((_, body)) = hunk.as_mut() {
+            body.push(line.clone());
+        } else if !header.is_empty() {
+            header.push(line.clone());
+        }
+    }
+    flush(&header, &mut hunk, &mut hunks);
+    hunks
+}
+
+/// Renders stat rows as aligned `path | total ++--` lines. Bars are scaled to
+/// the remaining width when the largest row would overflow, mirroring git.
+pub(crate) fn render_diff_stat_block(
+    rows: &[(String, usize, usize)],
+    wrap_cols: usize,
+) -> Vec<RtLine<'static>> {
+    let display_width =
+        |text: &str| -> usize { text.chars().map(|ch| ch.width().unwrap_or(0)).sum() };
+    let path_width = rows
+        .iter()
+        .map(|(path, _, _)| display_width(path))
+        .max()
+        .unwrap_or(0)
+        .min(wrap_cols.saturating_sub(10).max(8));
+    let count_width = rows
+        .iter()
+        .map(|(_, added, removed)| (added + removed).to_string().len())
+        .max()
+        .unwrap_or(1);
+    let bar_budget = wrap_cols
+        .saturating_sub(path_width + count_width + 5)
+        .max(1);
+    let max_total = rows
+        .iter()
+        .map(|(_, added, removed)| added + removed)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    rows.iter()
+        .map(|(path, added, removed)| {
+            let total = added + removed;
+            let (plus, minus) = if max_total <= bar_budget {
+                (*added, *removed)
+            } else {
+                let scale = |n: usize| -> usize {
+                    if n == 0 {
+                        0
+                    } else {
+                        (n * bar_budget / max_total).max(1)
+                    }
+                };
+                (scale(*added), scale(*removed))
+            };
+            let path = if display_width(path) > path_width {
+                truncate_path_left(path, path_width)
+            } else {
+                path.clone()
+            };
+            let mut spans: Vec<RtSpan<'static>> = Vec::new();
+            spans.push(" ".into());
+            spans.push(format!("{path:<path_width$}").into());
+            spans.push(" | ".dim());
+            spans.push(format!("{total:>count_width$} ").into());
+            if plus > 0 {
+                spans.push("+".repeat(plus).green());
+            }
+            if minus > 0 {
+                spans.push("-".repeat(minus).red());
+            }
+            RtLine::from(spans)
+        })
+        .collect()
+}
+
+/// Keeps the tail of an over-wide path, prefixing an ellipsis, so the most
+/// specific components survive stat-column truncation.
+fn truncate_path_left(path: &str, width: usize) -> String {
+    let mut tail: Vec<char> = Vec::new();
+    let mut used = 1; // ellipsis
+    for ch in path.chars().rev() {
+        let ch_width = ch.width().unwrap_or(0);
+        if used + ch_width > width {
+            break;
+        }
+        used += ch_width;
+        tail.push(ch);
+    }
+    tail.push('\u{2026}');
+    tail.into_iter().rev().collect()
+}
+
 // Shared row for per-file presentation
 #[derive(Clone)]
 struct Row {
@@ -1046,7 +1277,8 @@ fn diff_theme() -> DiffTheme {
 /// Return the [`DiffColorLevel`] for the current terminal session.
 ///
 /// This is the environment-reading adapter: it samples runtime signals
-/// (`supports-color` level, terminal name, `WT_SESSION`, and `FORCE_COLOR`)
+/// (`supports-color` level, terminal name, `WT_SESSION`, `FORCE_COLOR`, and
+/// a forced `tui.color_mode`)
 /// and forwards them to [`diff_color_level_for_terminal`].
 ///
 /// Keeping env reads in this thin wrapper lets
@@ -1056,7 +1288,7 @@ fn diff_color_level() -> DiffColorLevel {
         stdout_color_level(),
         terminal_info().name,
         std::env::var_os("WT_SESSION").is_some(),
-        has_force_color_override(),
+        has_force_color_override() || crate::terminal_palette::color_mode_is_forced(),
     )
 }
 
@@ -1155,20 +1387,107 @@ fn style_context() -> Style {
 }
 
 fn add_line_bg(theme: DiffTheme, color_level: RichDiffColorLevel) -> Color {
+    add_line_bg_for(theme, color_level, colorblind_palette())
+}
+
+/// Pure variant of [`add_line_bg`], separated from the global palette
+/// singleton so tests can pass arbitrary palettes.
+fn add_line_bg_for(
+    theme: DiffTheme,
+    color_level: RichDiffColorLevel,
+    colorblind: Option<ColorblindPalette>,
+) -> Color {
+    let (dark_tc, dark_256, light_tc, light_256) = match colorblind {
+        None => (
+            DARK_TC_ADD_LINE_BG_RGB,
+            DARK_256_ADD_LINE_BG_IDX,
+            LIGHT_TC_ADD_LINE_BG_RGB,
+            LIGHT_256_ADD_LINE_BG_IDX,
+        ),
+        Some(ColorblindPalette::Deuteranopia | ColorblindPalette::Protanopia) => (
+            CB_RG_DARK_TC_ADD_LINE_BG_RGB,
+            CB_RG_DARK_256_ADD_LINE_BG_IDX,
+            CB_RG_LIGHT_TC_ADD_LINE_BG_RGB,
+            CB_RG_LIGHT_256_ADD_LINE_BG_IDX,
+        ),
+        Some(ColorblindPalette::Tritanopia) => (
+            CB_TRI_DARK_TC_ADD_LINE_BG_RGB,
+            CB_TRI_DARK_256_ADD_LINE_BG_IDX,
+            CB_TRI_LIGHT_TC_ADD_LINE_BG_RGB,
+            CB_TRI_LIGHT_256_ADD_LINE_BG_IDX,
+        ),
+    };
     match (theme, color_level) {
-        (DiffTheme::Dark, RichDiffColorLevel::TrueColor) => rgb_color(DARK_TC_ADD_LINE_BG_RGB),
-        (DiffTheme::Dark, RichDiffColorLevel::Ansi256) => indexed_color(DARK_256_ADD_LINE_BG_IDX),
-        (DiffTheme::Light, RichDiffColorLevel::TrueColor) => rgb_color(LIGHT_TC_ADD_LINE_BG_RGB),
-        (DiffTheme::Light, RichDiffColorLevel::Ansi256) => indexed_color(LIGHT_256_ADD_LINE_BG_IDX),
+        (DiffTheme::Dark, RichDiffColorLevel::TrueColor) => rgb_color(dark_tc),
+        (DiffTheme::Dark, RichDiffColorLevel::Ansi256) => indexed_color(dark_256),
+        (DiffTheme::Light, RichDiffColorLevel::TrueColor) => rgb_color(light_tc),
+        (DiffTheme::Light, RichDiffColorLevel::Ansi256) => indexed_color(light_256),
     }
 }
 
 fn del_line_bg(theme: DiffTheme, color_level: RichDiffColorLevel) -> Color {
+    del_line_bg_for(theme, color_level, colorblind_palette())
+}
+
+/// Pure variant of [`del_line_bg`]; mirror of [`add_line_bg_for`].
+fn del_line_bg_for(
+    theme: DiffTheme,
+    color_level: RichDiffColorLevel,
+    colorblind: Option<ColorblindPalette>,
+) -> Color {
+    let (dark_tc, dark_256, light_tc, light_256) = match colorblind {
+        None => (
+            DARK_TC_DEL_LINE_BG_RGB,
+            DARK_256_DEL_LINE_BG_IDX,
+            LIGHT_TC_DEL_LINE_BG_RGB,
+            LIGHT_256_DEL_LINE_BG_IDX,
+        ),
+        Some(ColorblindPalette::Deuteranopia | ColorblindPalette::Protanopia) => (
+            CB_RG_DARK_TC_DEL_LINE_BG_RGB,
+            CB_RG_DARK_256_DEL_LINE_BG_IDX,
+            CB_RG_LIGHT_TC_DEL_LINE_BG_RGB,
+            CB_RG_LIGHT_256_DEL_LINE_BG_IDX,
+        ),
+        Some(ColorblindPalette::Tritanopia) => (
+            CB_TRI_DARK_TC_DEL_LINE_BG_RGB,
+            CB_TRI_DARK_256_DEL_LINE_BG_IDX,
+            CB_TRI_LIGHT_TC_DEL_LINE_BG_RGB,
+            CB_TRI_LIGHT_256_DEL_LINE_BG_IDX,
+        ),
+    };
     match (theme, color_level) {
-        (DiffTheme::Dark, RichDiffColorLevel::TrueColor) => rgb_color(DARK_TC_DEL_LINE_BG_RGB),
-        (DiffTheme::Dark, RichDiffColorLevel::Ansi256) => indexed_color(DARK_256_DEL_LINE_BG_IDX),
-        (DiffTheme::Light, RichDiffColorLevel::TrueColor) => rgb_color(LIGHT_TC_DEL_LINE_BG_RGB),
-        (DiffTheme::Light, RichDiffColorLevel::Ansi256) => indexed_color(LIGHT_256_DEL_LINE_BG_IDX),
+        (DiffTheme::Dark, RichDiffColorLevel::TrueColor) => rgb_color(dark_tc),
+        (DiffTheme::Dark, RichDiffColorLevel::Ansi256) => indexed_color(dark_256),
+        (DiffTheme::Light, RichDiffColorLevel::TrueColor) => rgb_color(light_tc),
+        (DiffTheme::Light, RichDiffColorLevel::Ansi256) => indexed_color(light_256),
+    }
+}
+
+/// Foreground used for inserted content and `+` signs: green by default,
+/// shifted off the red-green axis when a colorblind palette is active.
+fn add_fg() -> Color {
+    add_fg_for(colorblind_palette())
+}
+
+fn add_fg_for(colorblind: Option<ColorblindPalette>) -> Color {
+    match colorblind {
+        None => Color::Green,
+        Some(ColorblindPalette::Deuteranopia | ColorblindPalette::Protanopia) => Color::Blue,
+        Some(ColorblindPalette::Tritanopia) => Color::Cyan,
+    }
+}
+
+/// Foreground used for deleted content and `-` signs; mirror of [`add_fg`].
+/// ANSI yellow stands in for orange on the red-green palettes.
+fn del_fg() -> Color {
+    del_fg_for(colorblind_palette())
+}
+
+fn del_fg_for(colorblind: Option<ColorblindPalette>) -> Color {
+    match colorblind {
+        None => Color::Red,
+        Some(ColorblindPalette::Deuteranopia | ColorblindPalette::Protanopia) => Color::Yellow,
+        Some(ColorblindPalette::Tritanopia) => Color::Magenta,
     }
 }
 
@@ -1181,16 +1500,38 @@ fn light_gutter_fg(color_level: DiffColorLevel) -> Color {
 }
 
 fn light_add_num_bg(color_level: RichDiffColorLevel) -> Color {
+    let (tc, idx) = match colorblind_palette() {
+        None => (LIGHT_TC_ADD_NUM_BG_RGB, LIGHT_256_ADD_NUM_BG_IDX),
+        Some(ColorblindPalette::Deuteranopia | ColorblindPalette::Protanopia) => (
+            CB_RG_LIGHT_TC_ADD_NUM_BG_RGB,
+            CB_RG_LIGHT_256_ADD_NUM_BG_IDX,
+        ),
+        Some(ColorblindPalette::Tritanopia) => (
+            CB_TRI_LIGHT_TC_ADD_NUM_BG_RGB,
+            CB_TRI_LIGHT_256_ADD_NUM_BG_IDX,
+        ),
+    };
     match color_level {
-        RichDiffColorLevel::TrueColor => rgb_color(LIGHT_TC_ADD_NUM_BG_RGB),
-        RichDiffColorLevel::Ansi256 => indexed_color(LIGHT_256_ADD_NUM_BG_IDX),
+        RichDiffColorLevel::TrueColor => rgb_color(tc),
+        RichDiffColorLevel::Ansi256 => indexed_color(idx),
     }
 }
 
 fn light_del_num_bg(color_level: RichDiffColorLevel) -> Color {
+    let (tc, idx) = match colorblind_palette() {
+        None => (LIGHT_TC_DEL_NUM_BG_RGB, LIGHT_256_DEL_NUM_BG_IDX),
+        Some(ColorblindPalette::Deuteranopia | ColorblindPalette::Protanopia) => (
+            CB_RG_LIGHT_TC_DEL_NUM_BG_RGB,
+            CB_RG_LIGHT_256_DEL_NUM_BG_IDX,
+        ),
+        Some(ColorblindPalette::Tritanopia) => (
+            CB_TRI_LIGHT_TC_DEL_NUM_BG_RGB,
+            CB_TRI_LIGHT_256_DEL_NUM_BG_IDX,
+        ),
+    };
     match color_level {
-        RichDiffColorLevel::TrueColor => rgb_color(LIGHT_TC_DEL_NUM_BG_RGB),
-        RichDiffColorLevel::Ansi256 => indexed_color(LIGHT_256_DEL_NUM_BG_IDX),
+        RichDiffColorLevel::TrueColor => rgb_color(tc),
+        RichDiffColorLevel::Ansi256 => indexed_color(idx),
     }
 }
 
@@ -1228,7 +1569,7 @@ fn style_sign_add(
     diff_backgrounds: ResolvedDiffBackgrounds,
 ) -> Style {
     match theme {
-        DiffTheme::Light => Style::default().fg(Color::Green),
+        DiffTheme::Light => Style::default().fg(add_fg()),
         DiffTheme::Dark => style_add(theme, color_level, diff_backgrounds),
     }
 }
@@ -1240,7 +1581,7 @@ fn style_sign_del(
     diff_backgrounds: ResolvedDiffBackgrounds,
 ) -> Style {
     match theme {
-        DiffTheme::Light => Style::default().fg(Color::Red),
+        DiffTheme::Light => Style::default().fg(del_fg()),
         DiffTheme::Dark => style_del(theme, color_level, diff_backgrounds),
     }
 }
@@ -1262,17 +1603,17 @@ fn style_add(
     diff_backgrounds: ResolvedDiffBackgrounds,
 ) -> Style {
     match (theme, color_level, diff_backgrounds.add) {
-        (_, DiffColorLevel::Ansi16, _) => Style::default().fg(Color::Green),
+        (_, DiffColorLevel::Ansi16, _) => Style::default().fg(add_fg()),
         (DiffTheme::Light, DiffColorLevel::TrueColor, Some(bg))
         | (DiffTheme::Light, DiffColorLevel::Ansi256, Some(bg)) => Style::default().bg(bg),
         (DiffTheme::Dark, DiffColorLevel::TrueColor, Some(bg))
         | (DiffTheme::Dark, DiffColorLevel::Ansi256, Some(bg)) => {
-            Style::default().fg(Color::Green).bg(bg)
+            Style::default().fg(add_fg()).bg(bg)
         }
         (DiffTheme::Light, DiffColorLevel::TrueColor, None)
         | (DiffTheme::Light, DiffColorLevel::Ansi256, None) => Style::default(),
         (DiffTheme::Dark, DiffColorLevel::TrueColor, None)
-        | (DiffTheme::Dark, DiffColorLevel::Ansi256, None) => Style::default().fg(Color::Green),
+        | (DiffTheme::Dark, DiffColorLevel::Ansi256, None) => Style::default().fg(add_fg()),
     }
 }
 
@@ -1286,17 +1627,17 @@ fn style_del(
     diff_backgrounds: ResolvedDiffBackgrounds,
 ) -> Style {
     match (theme, color_level, diff_backgrounds.del) {
-        (_, DiffColorLevel::Ansi16, _) => Style::default().fg(Color::Red),
+        (_, DiffColorLevel::Ansi16, _) => Style::default().fg(del_fg()),
         (DiffTheme::Light, DiffColorLevel::TrueColor, Some(bg))
         | (DiffTheme::Light, DiffColorLevel::Ansi256, Some(bg)) => Style::default().bg(bg),
         (DiffTheme::Dark, DiffColorLevel::TrueColor, Some(bg))
         | (DiffTheme::Dark, DiffColorLevel::Ansi256, Some(bg)) => {
-            Style::default().fg(Color::Red).bg(bg)
+            Style::default().fg(del_fg()).bg(bg)
         }
         (DiffTheme::Light, DiffColorLevel::TrueColor, None)
         | (DiffTheme::Light, DiffColorLevel::Ansi256, None) => Style::default(),
         (DiffTheme::Dark, DiffColorLevel::TrueColor, None)
-        | (DiffTheme::Dark, DiffColorLevel::Ansi256, None) => Style::default().fg(Color::Red),
+        | (DiffTheme::Dark, DiffColorLevel::Ansi256, None) => Style::default().fg(del_fg()),
     }
 }
 
@@ -1356,6 +1697,51 @@ mod tests {
         assert_eq!(del_sign.fg, Some(Color::Red));
         assert_eq!(del_sign.bg, None);
     }
+
+    #[test]
+    fn colorblind_palettes_avoid_the_red_green_axis() {
+        for palette in [
+            ColorblindPalette::Deuteranopia,
+            ColorblindPalette::Protanopia,
+        ] {
+            assert_eq!(add_fg_for(Some(palette)), Color::Blue);
+            assert_eq!(del_fg_for(Some(palette)), Color::Yellow);
+        }
+        assert_eq!(add_fg_for(Some(ColorblindPalette::Tritanopia)), Color::Cyan);
+        assert_eq!(
+            del_fg_for(Some(ColorblindPalette::Tritanopia)),
+            Color::Magenta
+        );
+        // None keeps the default red/green pairing.
+        assert_eq!(add_fg_for(None), Color::Green);
+        assert_eq!(del_fg_for(None), Color::Red);
+    }
+
+    #[test]
+    fn colorblind_palettes_swap_line_backgrounds() {
+        let palette = Some(ColorblindPalette::Deuteranopia);
+        assert_eq!(
+            add_line_bg_for(DiffTheme::Dark, RichDiffColorLevel::TrueColor, palette),
+            rgb_color(CB_RG_DARK_TC_ADD_LINE_BG_RGB)
+        );
+        assert_eq!(
+            del_line_bg_for(DiffTheme::Light, RichDiffColorLevel::Ansi256, palette),
+            indexed_color(CB_RG_LIGHT_256_DEL_LINE_BG_IDX)
+        );
+        assert_eq!(
+            add_line_bg_for(
+                DiffTheme::Dark,
+                RichDiffColorLevel::TrueColor,
+                Some(ColorblindPalette::Tritanopia)
+            ),
+            rgb_color(CB_TRI_DARK_TC_ADD_LINE_BG_RGB)
+        );
+        assert_eq!(
+            add_line_bg_for(DiffTheme::Dark, RichDiffColorLevel::TrueColor, None),
+            rgb_color(DARK_TC_ADD_LINE_BG_RGB)
+        );
+    }
+
     fn diff_summary_for_tests(changes: &HashMap<PathBuf, FileChange>) -> Vec<RtLine<'static>> {
         create_diff_summary(changes, &PathBuf::from("/"), /*wrap_cols*/ 80)
     }
@@ -1594,6 +1980,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn diff_stat_block_aligns_and_scales_bars() {
+        let rows = vec![
+            ("a.txt".to_string(), 1, 1),
+            ("dir/longer.rs".to_string(), 40, 0),
+        ];
+        let lines = render_diff_stat_block(&rows, /*wrap_cols*/ 28);
+        let text: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        // 28 columns leave an 8-cell bar budget, so the 40-line row scales
+        // down while the small row keeps one cell per side.
+        assert_eq!(
+            text,
+            vec![
+                " a.txt         |  2 +-".to_string(),
+                " dir/longer.rs | 40 ++++++++".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_unified_hunks_pairs_each_hunk_with_its_file_header() {
+        let lines: Vec<String> = [
+            "diff --git a/a.txt b/a.txt",
+            "index 0000000..1111111 100644",
+            "--- a/a.txt",
+            "+++ b/a.txt",
+            "@@ -1 +1 @@",
+            "-one",
+            "+one changed",
+            "@@ -9 +9 @@",
+            "-nine",
+            "+nine changed",
+            "diff --git a/b.txt b/b.txt",
+            "--- a/b.txt",
+            "+++ b/b.txt",
+            "@@ -1 +1 @@",
+            "-x",
+            "+y",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+        let hunks = collect_unified_hunks(&lines);
+        assert_eq!(
+            hunks.iter().map(|h| h.line_index).collect::<Vec<_>>(),
+            vec![4, 7, 13]
+        );
+        assert_eq!(
+            hunks[1].patch,
+            "diff --git a/a.txt b/a.txt\nindex 0000000..1111111 100644\n--- a/a.txt\n+++ b/a.txt\n@@ -9 +9 @@\n-nine\n+nine changed\n"
+        );
+        assert!(hunks[2].patch.starts_with("diff --git a/b.txt b/b.txt\n"));
+    }
+
+    #[test]
+    fn diff_stat_rows_parse_unified_output() {
+        let text = concat!(
+            "diff --git a/a.txt b/a.txt\n",
+            "index 0000000..1111111 100644\n",
+            "--- a/a.txt\n",
+            "+++ b/a.txt\n",
+            "@@ -1 +1 @@\n",
+            "-one\n",
+            "+one changed\n",
+            "diff --git a/b.txt b/b.txt\n",
+            "new file mode 100644\n",
+            "--- /dev/null\n",
+            "+++ b/b.txt\n",
+            "@@ -0,0 +1 @@\n",
+            "+new\n",
+        );
+        assert_eq!(
+            diff_stat_rows_from_unified(text),
+            vec![("a.txt".to_string(), 1, 1), ("b.txt".to_string(), 1, 0)]
+        );
+    }
+
     #[test]
     fn ui_snapshot_apply_add_block() {
         let mut changes: HashMap<PathBuf, FileChange> = HashMap::new();
@@ -5,8 +5,10 @@ pub(crate) use model::CommandOutput;
 #[cfg(test)]
 pub(crate) use model::ExecCall;
 pub(crate) use model::ExecCell;
+pub(crate) use render::OutputFold;
 pub(crate) use render::OutputLinesParams;
 pub(crate) use render::TOOL_CALL_MAX_LINES;
+pub(crate) use render::init_output_folding;
 pub(crate) use render::new_active_exec_command;
 pub(crate) use render::output_lines;
 pub(crate) use render::spinner;
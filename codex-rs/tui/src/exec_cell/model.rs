@@ -147,7 +147,7 @@ impl ExecCell {
             return false;
         };
         let output = call.output.get_or_insert_with(CommandOutput::default);
-        output.aggregated_output.push_str(chunk);
+        append_with_carriage_returns(&mut output.aggregated_output, chunk);
         true
     }
 
@@ -165,6 +165,44 @@ impl ExecCell {
     }
 }
 
+/// Append `chunk` to `buffer`, honoring carriage returns the way a terminal
+/// would: a bare `\r` rewinds to the start of the current line so the next
+/// write replaces it. This collapses progress-bar output (`wget`, `pip`,
+/// `cargo`) into a single in-place updating line instead of hundreds of
+/// appended partials. `\r\n` is passed through as a plain newline, even when
+/// the pair is split across chunks: a trailing `\r` stays in the buffer as a
+/// pending marker until the next chunk disambiguates it.
+pub(crate) fn append_with_carriage_returns(buffer: &mut String, chunk: &str) {
+    for ch in chunk.chars() {
+        match ch {
+            '\r' => buffer.push('\r'),
+            '\n' => {
+                if buffer.ends_with('\r') {
+                    buffer.pop();
+                }
+                buffer.push('\n');
+            }
+            _ => {
+                if buffer.ends_with('\r') {
+                    let line_start = buffer.rfind('\n').map(|i| i + 1).unwrap_or(0);
+                    buffer.truncate(line_start);
+                }
+                buffer.push(ch);
+            }
+        }
+    }
+}
+
+/// Collapse carriage-return overwrites in `text`, keeping only the final state
+/// of each in-place-updated line. Used at render time for output that reached
+/// the cell without going through [`ExecCell::append_output`] (turn-end and
+/// resumed-thread payloads carry the raw aggregate).
+pub(crate) fn normalize_carriage_returns(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    append_with_carriage_returns(&mut out, text);
+    out
+}
+
 impl ExecCall {
     pub(crate) fn is_user_shell_command(&self) -> bool {
         matches!(self.source, ExecCommandSource::UserShell)
@@ -1,18 +1,22 @@
+use std::sync::OnceLock;
 use std::time::Instant;
 
 use super::model::CommandOutput;
 use super::model::ExecCall;
 use super::model::ExecCell;
+use super::model::normalize_carriage_returns;
 use crate::exec_command::strip_bash_lc_and_escape;
 use crate::history_cell::HistoryCell;
+use crate::history_cell::TranscriptCellKind;
 use crate::render::highlight::highlight_bash_to_lines;
+use crate::render::line_utils::ansi_styled_line;
 use crate::render::line_utils::prefix_lines;
 use crate::render::line_utils::push_owned_lines;
 use crate::shimmer::shimmer_spans;
 use crate::wrapping::RtOptions;
 use crate::wrapping::adaptive_wrap_line;
 use crate::wrapping::adaptive_wrap_lines;
-use codex_ansi_escape::ansi_escape_line;
+use codex_config::types::OutputFoldingToml;
 use codex_protocol::parse_command::ParsedCommand;
 use codex_protocol::protocol::ExecCommandSource;
 use codex_shell_command::bash::extract_bash_command;
@@ -28,6 +32,67 @@ use unicode_width::UnicodeWidthStr;
 
 pub(crate) const TOOL_CALL_MAX_LINES: usize = 5;
 const USER_SHELL_TOOL_CALL_MAX_LINES: usize = 50;
+
+/// `[tui.output_folding]` settings captured once at startup, like the other
+/// render-path configuration.
+static OUTPUT_FOLDING: OnceLock<OutputFoldingToml> = OnceLock::new();
+
+pub(crate) fn init_output_folding(folding: OutputFoldingToml) {
+    if OUTPUT_FOLDING.set(folding).is_err() {
+        tracing::debug!("output folding initialized more than once");
+    }
+}
+
+/// How `output_lines` truncates a command's output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum OutputFold {
+    /// Head-and-tail truncation at `line_limit` (the default rendering).
+    #[default]
+    HeadTail,
+    /// Keep only the last `line_limit` lines (folded successful command).
+    TailOnly,
+    /// Keep everything (expanded failed command).
+    Full,
+}
+
+/// Key used for per-tool overrides in `[tui.output_folding]`.
+fn folding_tool_key(source: ExecCommandSource) -> &'static str {
+    match source {
+        ExecCommandSource::Agent => "exec",
+        ExecCommandSource::UserShell => "user_shell",
+        ExecCommandSource::UnifiedExecStartup | ExecCommandSource::UnifiedExecInteraction => {
+            "unified_exec"
+        }
+    }
+}
+
+/// Folding decision for a finished call, from exit status and configuration.
+fn output_fold_for(source: ExecCommandSource, exit_code: i32) -> OutputFold {
+    let Some(folding) = OUTPUT_FOLDING.get() else {
+        return OutputFold::HeadTail;
+    };
+    let tool_enabled = folding
+        .tools
+        .get(folding_tool_key(source))
+        .copied()
+        .unwrap_or(true);
+    if !folding.enabled || !tool_enabled {
+        return OutputFold::HeadTail;
+    }
+    if exit_code == 0 {
+        OutputFold::TailOnly
+    } else {
+        OutputFold::Full
+    }
+}
+
+fn success_tail_lines() -> usize {
+    OUTPUT_FOLDING
+        .get()
+        .and_then(|folding| folding.success_tail_lines)
+        .unwrap_or(TOOL_CALL_MAX_LINES)
+        .max(1)
+}
 const MAX_INTERACTION_PREVIEW_CHARS: usize = 80;
 const TRANSCRIPT_HINT: &str = "ctrl + t to view transcript";
 
@@ -36,6 +101,7 @@ pub(crate) struct OutputLinesParams {
     pub(crate) only_err: bool,
     pub(crate) include_angle_pipe: bool,
     pub(crate) include_prefix: bool,
+    pub(crate) fold: OutputFold,
 }
 
 pub(crate) fn new_active_exec_command(
@@ -106,6 +172,7 @@ pub(crate) fn output_lines(
         only_err,
         include_angle_pipe,
         include_prefix,
+        fold,
     } = params;
     let CommandOutput {
         aggregated_output, ..
@@ -125,14 +192,34 @@ pub(crate) fn output_lines(
         }
     };
 
-    let src = aggregated_output;
+    // Turn-end and resume payloads carry raw carriage-return progress output;
+    // streamed chunks were already collapsed in `ExecCell::append_output`.
+    let normalized;
+    let src = if aggregated_output.contains('\r') {
+        normalized = normalize_carriage_returns(aggregated_output);
+        &normalized
+    } else {
+        aggregated_output
+    };
     let lines: Vec<&str> = src.lines().collect();
     let total = lines.len();
     let mut out: Vec<Line<'static>> = Vec::new();
 
-    let head_end = total.min(line_limit);
+    // Lines `[0, head_end)` and `[tail_start, total)` are rendered with an
+    // ellipsis for the `omitted` lines in between.
+    let (head_end, tail_start, omitted) = match fold {
+        OutputFold::Full => (total, total, None),
+        OutputFold::TailOnly if total > line_limit => {
+            (0, total - line_limit, Some(total - line_limit))
+        }
+        OutputFold::TailOnly => (total, total, None),
+        OutputFold::HeadTail if total > 2 * line_limit => {
+            (line_limit, total - line_limit, Some(total - 2 * line_limit))
+        }
+        OutputFold::HeadTail => (total.min(line_limit), total.min(line_limit), None),
+    };
     for (i, raw) in lines[..head_end].iter().enumerate() {
-        let mut line = ansi_escape_line(raw);
+        let mut line = ansi_styled_line(raw);
         let prefix = if !include_prefix {
             ""
         } else if i == 0 && include_angle_pipe {
@@ -147,24 +234,12 @@ pub(crate) fn output_lines(
         out.push(line);
     }
 
-    let show_ellipsis = total > 2 * line_limit;
-    let omitted = if show_ellipsis {
-        Some(total - 2 * line_limit)
-    } else {
-        None
-    };
-    if show_ellipsis {
-        let omitted = total - 2 * line_limit;
+    if let Some(omitted) = omitted {
         out.push(ExecCell::output_ellipsis_line(omitted));
     }
 
-    let tail_start = if show_ellipsis {
-        total - line_limit
-    } else {
-        head_end
-    };
     for raw in lines[tail_start..].iter() {
-        let mut line = ansi_escape_line(raw);
+        let mut line = ansi_styled_line(raw);
         if include_prefix {
             line.spans.insert(0, "    ".into());
         }
@@ -180,19 +255,34 @@ pub(crate) fn output_lines(
     }
 }
 
+/// Recolors a tool-call prefix span when the active theme defines the
+/// `tool_call_accent` palette slot; otherwise the span keeps its default
+/// hardcoded color.
+fn apply_tool_call_accent(span: Span<'static>) -> Span<'static> {
+    match crate::style::tool_call_accent_fg() {
+        Some(fg) => span.fg(fg),
+        None => span,
+    }
+}
+
 pub(crate) fn spinner(start_time: Option<Instant>, animations_enabled: bool) -> Span<'static> {
+    let bullet = crate::glyphs::glyph("•", "*");
     if !animations_enabled {
-        return "•".dim();
+        return bullet.dim();
     }
     let elapsed = start_time.map(|st| st.elapsed()).unwrap_or_default();
     if supports_color::on_cached(supports_color::Stream::Stdout)
         .map(|level| level.has_16m)
         .unwrap_or(false)
     {
-        shimmer_spans("•")[0].clone()
+        shimmer_spans(bullet)[0].clone()
     } else {
         let blink_on = (elapsed.as_millis() / 600).is_multiple_of(2);
-        if blink_on { "•".into() } else { "◦".dim() }
+        if blink_on {
+            bullet.into()
+        } else {
+            crate::glyphs::glyph("◦", "o").dim()
+        }
     }
 }
 
@@ -205,6 +295,14 @@ impl HistoryCell for ExecCell {
         }
     }
 
+    fn tool_call_kind(&self) -> Option<&'static str> {
+        Some("exec")
+    }
+
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::Command
+    }
+
     fn transcript_lines(&self, width: u16) -> Vec<Line<'static>> {
         let mut lines: Vec<Line<'static>> = vec![];
         for (i, call) in self.iter_calls().enumerate() {
@@ -216,7 +314,7 @@ impl HistoryCell for ExecCell {
             let cmd_display = adaptive_wrap_lines(
                 &highlighted_script,
                 RtOptions::new(width as usize)
-                    .initial_indent("$ ".magenta().into())
+                    .initial_indent(apply_tool_call_accent("$ ".magenta()).into())
                     .subsequent_indent("    ".into()),
             );
             lines.extend(cmd_display);
@@ -225,7 +323,7 @@ impl HistoryCell for ExecCell {
                 if !call.is_unified_exec_interaction() {
                     let wrap_width = width.max(1) as usize;
                     let wrap_opts = RtOptions::new(wrap_width);
-                    for unwrapped in output.formatted_output.lines().map(ansi_escape_line) {
+                    for unwrapped in output.formatted_output.lines().map(ansi_styled_line) {
                         let wrapped = adaptive_wrap_line(&unwrapped, wrap_opts.clone());
                         push_owned_lines(&wrapped, &mut lines);
                     }
@@ -346,7 +444,8 @@ impl ExecCell {
 
             for (title, line) in call_lines {
                 let line = Line::from(line);
-                let initial_indent = Line::from(vec![title.cyan(), " ".into()]);
+                let initial_indent =
+                    Line::from(vec![apply_tool_call_accent(title.cyan()), " ".into()]);
                 let subsequent_indent = " ".repeat(initial_indent.width()).into();
                 let wrapped = adaptive_wrap_line(
                     &line,
@@ -440,10 +539,14 @@ impl ExecCell {
         }
 
         if let Some(output) = call.output.as_ref() {
-            let line_limit = if call.is_user_shell_command() {
-                USER_SHELL_TOOL_CALL_MAX_LINES
-            } else {
-                TOOL_CALL_MAX_LINES
+            let fold = output_fold_for(call.source, output.exit_code);
+            let line_limit = match fold {
+                OutputFold::TailOnly => success_tail_lines(),
+                OutputFold::Full => usize::MAX,
+                OutputFold::HeadTail if call.is_user_shell_command() => {
+                    USER_SHELL_TOOL_CALL_MAX_LINES
+                }
+                OutputFold::HeadTail => TOOL_CALL_MAX_LINES,
             };
             let raw_output = output_lines(
                 Some(output),
@@ -452,12 +555,16 @@ impl ExecCell {
                     only_err: false,
                     include_angle_pipe: false,
                     include_prefix: false,
+                    fold,
                 },
             );
-            let display_limit = if call.is_user_shell_command() {
-                USER_SHELL_TOOL_CALL_MAX_LINES
-            } else {
-                layout.output_max_lines
+            let display_limit = match fold {
+                OutputFold::TailOnly => line_limit.saturating_add(1),
+                OutputFold::Full => usize::MAX,
+                OutputFold::HeadTail if call.is_user_shell_command() => {
+                    USER_SHELL_TOOL_CALL_MAX_LINES
+                }
+                OutputFold::HeadTail => layout.output_max_lines,
             };
 
             if raw_output.lines.is_empty() {
@@ -749,6 +856,7 @@ mod tests {
                 only_err: false,
                 include_angle_pipe: false,
                 include_prefix: false,
+                fold: OutputFold::HeadTail,
             },
         );
         let output_wrap_width = layout.output_block.wrap_width(width);
@@ -857,6 +965,144 @@ mod tests {
         );
     }
 
+    #[test]
+    fn carriage_return_progress_collapses_to_latest_line() {
+        let output = CommandOutput {
+            exit_code: 0,
+            aggregated_output: "Downloading 10%\rDownloading 55%\rDownloading 100%\nDone\n"
+                .to_string(),
+            formatted_output: String::new(),
+        };
+        let raw_output = output_lines(
+            Some(&output),
+            OutputLinesParams {
+                line_limit: 10,
+                only_err: false,
+                include_angle_pipe: false,
+                include_prefix: false,
+                fold: OutputFold::HeadTail,
+            },
+        );
+
+        let rendered: Vec<String> = raw_output.lines.iter().map(render_line_text).collect();
+        assert_eq!(rendered, vec!["Downloading 100%", "Done"]);
+    }
+
+    #[test]
+    fn crlf_split_across_chunks_stays_a_newline() {
+        let mut buffer = String::new();
+        super::super::model::append_with_carriage_returns(&mut buffer, "line one\r");
+        super::super::model::append_with_carriage_returns(&mut buffer, "\nline two\n");
+        assert_eq!(buffer, "line one\nline two\n");
+
+        let mut buffer = String::new();
+        super::super::model::append_with_carriage_returns(&mut buffer, "50%\r");
+        super::super::model::append_with_carriage_returns(&mut buffer, "100%\n");
+        assert_eq!(buffer, "100%\n");
+    }
+
+    #[test]
+    fn sgr_sequences_render_as_styled_spans() {
+        let output = CommandOutput {
+            exit_code: 0,
+            aggregated_output: "\u{1b}[31merror\u{1b}[0m: oh no\n".to_string(),
+            formatted_output: String::new(),
+        };
+        let raw_output = output_lines(
+            Some(&output),
+            OutputLinesParams {
+                line_limit: 10,
+                only_err: false,
+                include_angle_pipe: false,
+                include_prefix: false,
+                fold: OutputFold::HeadTail,
+            },
+        );
+
+        let line = &raw_output.lines[0];
+        assert_eq!(render_line_text(line), "error: oh no");
+        let colored = line
+            .spans
+            .iter()
+            .find(|span| span.content.as_ref() == "error")
+            .expect("colored span");
+        assert_eq!(colored.style.fg, Some(Color::Red));
+        assert!(colored.style.add_modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn tail_only_fold_keeps_only_the_last_lines() {
+        let output = CommandOutput {
+            exit_code: 0,
+            aggregated_output: (1..=7).map(|n| n.to_string()).join("\n"),
+            formatted_output: String::new(),
+        };
+
+        let rendered: Vec<String> = output_lines(
+            Some(&output),
+            OutputLinesParams {
+                line_limit: 2,
+                only_err: false,
+                include_angle_pipe: false,
+                include_prefix: false,
+                fold: OutputFold::TailOnly,
+            },
+        )
+        .lines
+        .iter()
+        .map(render_line_text)
+        .collect();
+
+        assert_eq!(
+            rendered.len(),
+            3,
+            "expected ellipsis plus tail: {rendered:?}"
+        );
+        assert!(
+            rendered[0].contains("… +5 lines"),
+            "expected every leading line to be folded, got: {rendered:?}"
+        );
+        assert_eq!(&rendered[1..], ["6".to_string(), "7".to_string()]);
+    }
+
+    #[test]
+    fn full_fold_keeps_every_line() {
+        let output = CommandOutput {
+            exit_code: 1,
+            aggregated_output: (1..=7).map(|n| n.to_string()).join("\n"),
+            formatted_output: String::new(),
+        };
+
+        let rendered: Vec<String> = output_lines(
+            Some(&output),
+            OutputLinesParams {
+                line_limit: 2,
+                only_err: false,
+                include_angle_pipe: false,
+                include_prefix: false,
+                fold: OutputFold::Full,
+            },
+        )
+        .lines
+        .iter()
+        .map(render_line_text)
+        .collect();
+
+        assert_eq!(
+            rendered,
+            (1..=7).map(|n| n.to_string()).collect::<Vec<_>>(),
+            "expected the full output with no ellipsis"
+        );
+    }
+
+    #[test]
+    fn fold_defaults_to_head_tail_until_initialized() {
+        assert_eq!(
+            output_fold_for(ExecCommandSource::Agent, /*exit_code*/ 0),
+            OutputFold::HeadTail
+        );
+    }
+
     #[test]
     fn output_lines_ellipsis_includes_transcript_hint() {
         let output = CommandOutput {
@@ -872,6 +1118,7 @@ mod tests {
                 only_err: false,
                 include_angle_pipe: false,
                 include_prefix: false,
+                fold: OutputFold::HeadTail,
             },
         )
         .lines
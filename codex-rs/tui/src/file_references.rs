@@ -0,0 +1,165 @@
+//! Inline annotation of file references in agent output.
+//!
+//! Agent messages frequently cite locations like `core/src/lib.rs:42`. This
+//! pass finds those references in rendered lines, checks them against the
+//! filesystem, styles real ones like links, and follows ones that do not
+//! exist with a dim "not found" marker so hallucinated paths are easy to
+//! spot.
+
+use ratatui::style::Style;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::text::Span;
+use regex_lite::Regex;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Matches `path/to/file.rs:123` style references: a path with an extension
+/// followed by a colon and a line number. Requiring the line number keeps
+/// ordinary prose and bare file names from being rewritten.
+static FILE_REFERENCE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(/?(?:[\w.\-]+/)*[\w\-]+\.[A-Za-z0-9]+):(\d+)")
+        .expect("file reference regex should compile")
+});
+
+/// Annotates `path:line` references in rendered lines in place. Existing
+/// references get the link style; missing ones get a dim `(not found)`
+/// marker. Relative paths resolve against `cwd`.
+pub(crate) fn annotate_file_references(lines: &mut [Line<'static>], cwd: &Path) {
+    let mut existence: HashMap<String, bool> = HashMap::new();
+    for line in lines.iter_mut() {
+        if !line
+            .spans
+            .iter()
+            .any(|span| FILE_REFERENCE_RE.is_match(&span.content))
+        {
+            continue;
+        }
+        let mut new_spans: Vec<Span<'static>> = Vec::with_capacity(line.spans.len());
+        for span in std::mem::take(&mut line.spans) {
+            if !FILE_REFERENCE_RE.is_match(&span.content) {
+                new_spans.push(span);
+                continue;
+            }
+            let style = span.style;
+            let content = span.content.into_owned();
+            let mut last = 0usize;
+            for captures in FILE_REFERENCE_RE.captures_iter(&content) {
+                let whole = captures.get(0).expect("match 0 is always present");
+                // Skip matches embedded in a larger token, such as the
+                // host:port portion of a URL.
+                if whole.start() > 0
+                    && matches!(
+                        content.as_bytes()[whole.start() - 1],
+                        b'/' | b':' | b'.' | b'@'
+                    )
+                {
+                    new_spans.push(Span::styled(content[last..whole.end()].to_string(), style));
+                    last = whole.end();
+                    continue;
+                }
+                let path = captures
+                    .get(1)
+                    .expect("path group is always present")
+                    .as_str();
+                let exists = *existence.entry(path.to_string()).or_insert_with(|| {
+                    let candidate = Path::new(path);
+                    if candidate.is_absolute() {
+                        candidate.is_file()
+                    } else {
+                        cwd.join(candidate).is_file()
+                    }
+                });
+                if whole.start() > last {
+                    new_spans.push(Span::styled(
+                        content[last..whole.start()].to_string(),
+                        style,
+                    ));
+                }
+                if exists {
+                    new_spans.push(Span::styled(
+                        whole.as_str().to_string(),
+                        style.patch(Style::new().cyan().underlined()),
+                    ));
+                } else {
+                    new_spans.push(Span::styled(whole.as_str().to_string(), style));
+                    new_spans.push(Span::styled(
+                        " (not found)".to_string(),
+                        Style::new().dim().italic(),
+                    ));
+                }
+                last = whole.end();
+            }
+            if last < content.len() {
+                new_spans.push(Span::styled(content[last..].to_string(), style));
+            }
+        }
+        line.spans = new_spans;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn line_to_string(line: &Line<'static>) -> String {
+        line.spans.iter().map(|span| span.content.clone()).collect()
+    }
+
+    #[test]
+    fn existing_reference_is_styled_as_link() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        std::fs::write(dir.path().join("main.rs"), "fn main() {}\n").expect("write file");
+        let mut lines = vec![Line::from("See main.rs:1 for details.")];
+        annotate_file_references(&mut lines, dir.path());
+        assert_eq!(line_to_string(&lines[0]), "See main.rs:1 for details.");
+        let link_span = lines[0]
+            .spans
+            .iter()
+            .find(|span| span.content == "main.rs:1")
+            .expect("reference span");
+        assert_eq!(
+            link_span.style,
+            Style::new().cyan().underlined(),
+            "existing references use the link style"
+        );
+    }
+
+    #[test]
+    fn missing_reference_gets_not_found_marker() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let mut lines = vec![Line::from("See src/ghost.rs:7 for details.")];
+        annotate_file_references(&mut lines, dir.path());
+        assert_eq!(
+            line_to_string(&lines[0]),
+            "See src/ghost.rs:7 (not found) for details."
+        );
+    }
+
+    #[test]
+    fn url_host_and_port_is_untouched() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let mut lines = vec![Line::from("Connect to http://example.com:443/path first.")];
+        annotate_file_references(&mut lines, dir.path());
+        assert_eq!(
+            line_to_string(&lines[0]),
+            "Connect to http://example.com:443/path first."
+        );
+        assert!(
+            lines[0]
+                .spans
+                .iter()
+                .all(|span| !span.content.contains("not found"))
+        );
+    }
+
+    #[test]
+    fn prose_without_line_numbers_is_untouched() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let mut lines = vec![Line::from("Edit main.rs and run the tests at 12:30.")];
+        annotate_file_references(&mut lines, dir.path());
+        assert_eq!(lines[0].spans.len(), 1);
+    }
+}
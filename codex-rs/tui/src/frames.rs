@@ -68,4 +68,17 @@ pub(crate) const ALL_VARIANTS: &[&[&str]] = &[
     &FRAMES_SLUG,
 ];
 
+/// Kebab-case names for the entries of [`ALL_VARIANTS`], in the same order.
+/// These are the styles accepted by `tui.welcome_cycle_animation`.
+pub(crate) const VARIANT_NAMES: &[&str] = &[
+    "default", "codex", "openai", "blocks", "dots", "hash", "hbars", "vbars", "shapes", "slug",
+];
+
+/// Index into [`ALL_VARIANTS`] for a configured variant name.
+pub(crate) fn variant_idx_for_name(name: &str) -> Option<usize> {
+    VARIANT_NAMES
+        .iter()
+        .position(|candidate| *candidate == name)
+}
+
 pub(crate) const FRAME_TICK_DEFAULT: Duration = Duration::from_millis(80);
@@ -101,6 +101,35 @@ async fn run_git_capture_diff(args: &[&str]) -> io::Result<String> {
     }
 }
 
+/// Applies `patch` to the index (`reverse` unstages). Non-zero exit status is
+/// reported with git's stderr so the caller can surface why a hunk refused to
+/// apply.
+pub(crate) async fn stage_patch(patch: &str, reverse: bool) -> io::Result<()> {
+    let mut args = vec!["apply", "--cached", "--whitespace=nowarn"];
+    if reverse {
+        args.push("--reverse");
+    }
+    args.push("-");
+    let mut child = Command::new("git")
+        .args(&args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        use tokio::io::AsyncWriteExt;
+        stdin.write_all(patch.as_bytes()).await?;
+    }
+    let output = child.wait_with_output().await?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
+}
+
 /// Determine if the current directory is inside a Git repository.
 async fn inside_git_repo() -> io::Result<bool> {
     let status = Command::new("git")
@@ -0,0 +1,80 @@
+//! Unicode capability probe and ASCII-only rendering mode.
+//!
+//! Some terminals and fonts lack the box-drawing, braille, and bullet glyphs
+//! the TUI uses for card borders, selection markers, and spinners. At startup
+//! the TUI probes the locale for UTF-8 support; `[tui] ascii_only` overrides
+//! the probe in either direction. Render paths consult [`ascii_only`] (or the
+//! [`glyph`] helper) and swap the affected glyphs for ASCII equivalents.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+static ASCII_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// Applies the `[tui] ascii_only` override or, when unset, the locale probe.
+/// Called once at startup before anything renders.
+pub(crate) fn init_ascii_only(config_override: Option<bool>) {
+    let ascii_only = config_override.unwrap_or_else(|| !locale_supports_unicode());
+    ASCII_ONLY.store(ascii_only, Ordering::Relaxed);
+}
+
+/// Whether rendering should avoid non-ASCII glyphs.
+pub(crate) fn ascii_only() -> bool {
+    ASCII_ONLY.load(Ordering::Relaxed)
+}
+
+/// Returns `unicode` unless ASCII-only mode is active, then `ascii`.
+pub(crate) fn glyph<'a>(unicode: &'a str, ascii: &'a str) -> &'a str {
+    if ascii_only() { ascii } else { unicode }
+}
+
+/// Best-effort probe: terminals that render the glyphs we use advertise UTF-8
+/// through the locale. Checked in the usual precedence order.
+fn locale_supports_unicode() -> bool {
+    locale_supports_unicode_in(|key| std::env::var(key).ok())
+}
+
+/// Inner implementation with an injectable environment for testing.
+fn locale_supports_unicode_in(lookup: impl Fn(&str) -> Option<String>) -> bool {
+    for key in ["LC_ALL", "LC_CTYPE", "LANG"] {
+        match lookup(key) {
+            Some(value) if !value.is_empty() => {
+                return value.to_ascii_lowercase().contains("utf");
+            }
+            _ => {}
+        }
+    }
+    // No locale information at all; assume unicode rather than degrade
+    // modern setups that simply do not export a locale.
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf8_locales_support_unicode() {
+        assert!(locale_supports_unicode_in(|_| Some(
+            "en_US.UTF-8".to_string()
+        )));
+        assert!(locale_supports_unicode_in(|_| Some("C.utf8".to_string())));
+    }
+
+    #[test]
+    fn non_utf8_locales_do_not() {
+        assert!(!locale_supports_unicode_in(|_| Some("POSIX".to_string())));
+        assert!(!locale_supports_unicode_in(|_| Some(
+            "en_US.ISO8859-1".to_string()
+        )));
+    }
+
+    #[test]
+    fn lc_all_takes_precedence_and_missing_locale_assumes_unicode() {
+        assert!(locale_supports_unicode_in(|key| match key {
+            "LC_ALL" => Some("en_US.UTF-8".to_string()),
+            _ => Some("POSIX".to_string()),
+        }));
+        assert!(locale_supports_unicode_in(|_| None));
+    }
+}
@@ -0,0 +1,87 @@
+//! Built-in help topics for `/help <topic>`.
+//!
+//! Topic documents are markdown files embedded at compile time from
+//! `help_docs/` and rendered through the normal markdown pipeline into the
+//! pager overlay, so they pick up the same styling and search support as
+//! other long-form views.
+
+use strum::IntoEnumIterator;
+use strum_macros::AsRefStr;
+use strum_macros::EnumIter;
+use strum_macros::EnumString;
+use strum_macros::IntoStaticStr;
+
+/// A help topic that can be opened with `/help <topic>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, EnumIter, AsRefStr, IntoStaticStr)]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum HelpTopic {
+    Keybindings,
+    Config,
+    Sandbox,
+}
+
+impl HelpTopic {
+    /// Pager title rendered in the overlay header.
+    pub(crate) fn title(self) -> &'static str {
+        match self {
+            HelpTopic::Keybindings => "H E L P · K E Y B I N D I N G S",
+            HelpTopic::Config => "H E L P · C O N F I G",
+            HelpTopic::Sandbox => "H E L P · S A N D B O X",
+        }
+    }
+
+    /// One-line summary shown in the topic index.
+    pub(crate) fn summary(self) -> &'static str {
+        match self {
+            HelpTopic::Keybindings => "composer, transcript, and pager keys",
+            HelpTopic::Config => "config.toml settings and profiles",
+            HelpTopic::Sandbox => "sandbox modes and granting extra access",
+        }
+    }
+
+    /// The embedded markdown document for this topic.
+    pub(crate) fn markdown(self) -> &'static str {
+        match self {
+            HelpTopic::Keybindings => include_str!("../help_docs/keybindings.md"),
+            HelpTopic::Config => include_str!("../help_docs/config.md"),
+            HelpTopic::Sandbox => include_str!("../help_docs/sandbox.md"),
+        }
+    }
+}
+
+/// Markdown index listing every topic, shown by a bare `/help`.
+pub(crate) fn index_markdown() -> String {
+    let mut out = String::from("# Help\n\nOpen a topic with `/help <topic>`:\n\n");
+    for topic in HelpTopic::iter() {
+        let name: &'static str = topic.into();
+        out.push_str(&format!("- `{name}` — {}\n", topic.summary()));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::str::FromStr;
+
+    #[test]
+    fn topics_parse_from_kebab_case_names() {
+        assert_eq!(
+            HelpTopic::from_str("keybindings"),
+            Ok(HelpTopic::Keybindings)
+        );
+        assert_eq!(HelpTopic::from_str("config"), Ok(HelpTopic::Config));
+        assert_eq!(HelpTopic::from_str("sandbox"), Ok(HelpTopic::Sandbox));
+        assert!(HelpTopic::from_str("nope").is_err());
+    }
+
+    #[test]
+    fn index_lists_every_topic() {
+        let index = index_markdown();
+        for topic in HelpTopic::iter() {
+            let name: &'static str = topic.into();
+            assert!(index.contains(name), "index missing topic {name}");
+        }
+    }
+}
@@ -10,9 +10,11 @@
 //! bumps the active-cell revision tracked by `ChatWidget`, so the cache key changes whenever the
 //! rendered transcript output can change.
 
+use crate::diff_render::create_diff_stat_header;
 use crate::diff_render::create_diff_summary;
 use crate::diff_render::display_path_for;
 use crate::exec_cell::CommandOutput;
+use crate::exec_cell::OutputFold;
 use crate::exec_cell::OutputLinesParams;
 use crate::exec_cell::TOOL_CALL_MAX_LINES;
 use crate::exec_cell::output_lines;
@@ -23,6 +25,7 @@ use crate::legacy_core::config::Config;
 use crate::legacy_core::web_search_detail;
 use crate::live_wrap::take_prefix_by_width;
 use crate::markdown::append_markdown;
+use crate::render::line_utils::ansi_styled_line;
 use crate::render::line_utils::line_to_static;
 use crate::render::line_utils::prefix_lines;
 use crate::render::line_utils::push_owned_lines;
@@ -34,6 +37,7 @@ use crate::test_support::PathBufExt;
 #[cfg(test)]
 use crate::test_support::test_path_buf;
 use crate::text_formatting::format_and_truncate_tool_result;
+use crate::text_formatting::truncate_ansi_tool_result;
 use crate::text_formatting::truncate_text;
 use crate::tooltips;
 use crate::ui_consts::LIVE_PREFIX_COLS;
@@ -45,6 +49,7 @@ use crate::wrapping::adaptive_wrap_lines;
 use base64::Engine;
 use codex_app_server_protocol::McpServerStatus;
 use codex_app_server_protocol::McpServerStatusDetail;
+use codex_config::config_toml::ProjectConfig;
 use codex_config::types::McpServerTransportConfig;
 #[cfg(test)]
 use codex_mcp::qualified_mcp_tool_name_prefix;
@@ -100,6 +105,25 @@ pub(crate) use hook_cell::HookCell;
 pub(crate) use hook_cell::new_active_hook_cell;
 pub(crate) use hook_cell::new_completed_hook_cell;
 
+/// Broad cell category used by the transcript overlay's type filter.
+///
+/// The default is [`Other`](TranscriptCellKind::Other); cells only need to
+/// override [`HistoryCell::transcript_kind`] when they belong to one of the
+/// filterable categories.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum TranscriptCellKind {
+    /// A message typed by the user.
+    User,
+    /// An agent answer (streamed markdown).
+    Agent,
+    /// A command execution.
+    Command,
+    /// An error surfaced in the history.
+    Error,
+    #[default]
+    Other,
+}
+
 /// Represents an event to display in the conversation history. Returns its
 /// `Vec<Line<'static>>` representation to make it easier to display in a
 /// scrollable list.
@@ -165,10 +189,30 @@ pub(crate) trait HistoryCell: std::fmt::Debug + Send + Sync + Any {
             .unwrap_or(0)
     }
 
+    /// Identifies tool-call cells that support collapsing, e.g. `exec`,
+    /// `mcp`, or `patch`. `None` (the default) means the cell always renders
+    /// in full. The kind doubles as the key into the `[tui]`
+    /// `collapsed_tool_calls` config table.
+    fn tool_call_kind(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the collapsed rendering for tool-call cells: the header line
+    /// plus a dim count of the hidden remainder. Collapsing only affects the
+    /// main viewport; the transcript overlay always shows the full output.
+    fn collapsed_display_lines(&self, width: u16) -> Vec<Line<'static>> {
+        collapse_lines_to_header(self.display_lines(width))
+    }
+
     fn is_stream_continuation(&self) -> bool {
         false
     }
 
+    /// Category used by the transcript overlay to filter cells by type.
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::default()
+    }
+
     /// Returns a coarse "animation tick" when transcript output is time-dependent.
     ///
     /// The transcript overlay caches the rendered output of the in-flight active cell, so cells
@@ -184,6 +228,19 @@ pub(crate) trait HistoryCell: std::fmt::Debug + Send + Sync + Any {
     }
 }
 
+/// Truncates rendered tool-call output to its first line and appends a dim
+/// count of the hidden remainder. Shared default for
+/// `HistoryCell::collapsed_display_lines`.
+pub(crate) fn collapse_lines_to_header(lines: Vec<Line<'static>>) -> Vec<Line<'static>> {
+    if lines.len() <= 1 {
+        return lines;
+    }
+    let hidden = lines.len() - 1;
+    let mut collapsed: Vec<Line<'static>> = lines.into_iter().take(1).collect();
+    collapsed.push(Line::from(format!("  … +{hidden} lines (ctrl + t to view transcript)")).dim());
+    collapsed
+}
+
 impl Renderable for Box<dyn HistoryCell> {
     fn render(&self, area: Rect, buf: &mut Buffer) {
         let lines = self.display_lines(area.width);
@@ -303,6 +360,10 @@ fn trim_trailing_blank_lines(mut lines: Vec<Line<'static>>) -> Vec<Line<'static>
 }
 
 impl HistoryCell for UserHistoryCell {
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::User
+    }
+
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         let wrap_width = width
             .saturating_sub(
@@ -485,6 +546,10 @@ impl HistoryCell for AgentMessageCell {
     fn is_stream_continuation(&self) -> bool {
         !self.is_first_line
     }
+
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::Agent
+    }
 }
 
 #[derive(Debug)]
@@ -794,6 +859,20 @@ pub(crate) fn new_unified_exec_processes_output(
     CompositeHistoryCell::new(vec![Box::new(command), Box::new(summary)])
 }
 
+pub(crate) fn new_env_output(entries: Vec<(String, String)>) -> CompositeHistoryCell {
+    let command = PlainHistoryCell::new(vec!["/env".magenta().into()]);
+    let lines: Vec<Line<'static>> = if entries.is_empty() {
+        vec!["(no environment variables)".dim().into()]
+    } else {
+        entries
+            .into_iter()
+            .map(|(name, value)| vec![format!("{name}=").into(), value.dim()].into())
+            .collect()
+    };
+    let listing = PlainHistoryCell::new(lines);
+    CompositeHistoryCell::new(vec![Box::new(command), Box::new(listing)])
+}
+
 fn truncate_exec_snippet(full_cmd: &str) -> String {
     let mut snippet = match full_cmd.split_once('\n') {
         Some((first, _)) => format!("{first} ..."),
@@ -1037,6 +1116,23 @@ impl HistoryCell for PatchHistoryCell {
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         create_diff_summary(&self.changes, &self.cwd, width as usize)
     }
+
+    fn transcript_lines(&self, width: u16) -> Vec<Line<'static>> {
+        let mut lines = create_diff_summary(&self.changes, &self.cwd, width as usize);
+        // Multi-file patches get a `git diff --stat`-style block under the
+        // summary line so the transcript shows the shape of the change before
+        // the per-file hunks.
+        if self.changes.len() > 1 && !lines.is_empty() {
+            let mut stat = create_diff_stat_header(&self.changes, &self.cwd, width as usize);
+            stat.push(Line::default());
+            lines.splice(1..1, stat);
+        }
+        lines
+    }
+
+    fn tool_call_kind(&self) -> Option<&'static str> {
+        Some("patch")
+    }
 }
 
 #[derive(Debug)]
@@ -1095,7 +1191,23 @@ fn with_border_internal(
 
     let mut out = Vec::with_capacity(lines.len() + 2);
     let border_inner_width = content_width + 2;
-    out.push(vec![format!("╭{}╮", "─".repeat(border_inner_width)).dim()].into());
+    let (top, bottom, horizontal, vertical) = if crate::glyphs::ascii_only() {
+        (("+", "+"), ("+", "+"), "-", "|")
+    } else {
+        (("╭", "╮"), ("╰", "╯"), "─", "│")
+    };
+    out.push(
+        vec![
+            format!(
+                "{}{}{}",
+                top.0,
+                horizontal.repeat(border_inner_width),
+                top.1
+            )
+            .dim(),
+        ]
+        .into(),
+    );
 
     for line in lines.into_iter() {
         let used_width: usize = line
@@ -1104,16 +1216,27 @@ fn with_border_internal(
             .sum();
         let span_count = line.spans.len();
         let mut spans: Vec<Span<'static>> = Vec::with_capacity(span_count + 4);
-        spans.push(Span::from("│ ").dim());
+        spans.push(Span::from(format!("{vertical} ")).dim());
         spans.extend(line.into_iter());
         if used_width < content_width {
             spans.push(Span::from(" ".repeat(content_width - used_width)).dim());
         }
-        spans.push(Span::from(" │").dim());
+        spans.push(Span::from(format!(" {vertical}")).dim());
         out.push(Line::from(spans));
     }
 
-    out.push(vec![format!("╰{}╯", "─".repeat(border_inner_width)).dim()].into());
+    out.push(
+        vec![
+            format!(
+                "{}{}{}",
+                bottom.0,
+                horizontal.repeat(border_inner_width),
+                bottom.1
+            )
+            .dim(),
+        ]
+        .into(),
+    );
 
     out
 }
@@ -1203,6 +1326,16 @@ pub(crate) fn new_session_info(
     .with_yolo_mode(has_yolo_permissions(approval_policy, &sandbox_policy));
     let mut parts: Vec<Box<dyn HistoryCell>> = vec![Box::new(header)];
 
+    let project_defaults = project_defaults_summary(&config.active_project);
+    if !project_defaults.is_empty() {
+        let lines: Vec<Line<'static>> = vec![
+            format!("  Using project defaults: {}", project_defaults.join(", "))
+                .dim()
+                .into(),
+        ];
+        parts.push(Box::new(PlainHistoryCell { lines }));
+    }
+
     if is_first_event {
         // Help lines below the header (new copy and list)
         let help_lines: Vec<Line<'static>> = vec![
@@ -1264,6 +1397,25 @@ pub(crate) fn new_session_info(
     SessionInfoCell(CompositeHistoryCell { parts })
 }
 
+/// Human-readable summary of the per-project defaults from the
+/// `[projects]` table that apply to this session, if any.
+fn project_defaults_summary(project: &ProjectConfig) -> Vec<String> {
+    let mut summary = Vec::new();
+    if let Some(model) = &project.model {
+        summary.push(format!("model {model}"));
+    }
+    if let Some(effort) = project.model_reasoning_effort {
+        summary.push(format!("reasoning {effort}"));
+    }
+    if let Some(sandbox_mode) = project.sandbox_mode {
+        summary.push(format!("sandbox {sandbox_mode}"));
+    }
+    if let Some(approval_policy) = project.approval_policy {
+        summary.push(format!("approval {approval_policy}"));
+    }
+    summary
+}
+
 pub(crate) fn is_yolo_mode(config: &Config) -> bool {
     has_yolo_permissions(
         config.permissions.approval_policy.value(),
@@ -1557,7 +1709,13 @@ impl McpToolCallCell {
 
         match content.raw {
             rmcp::model::RawContent::Text(text) => {
-                format_and_truncate_tool_result(&text.text, TOOL_CALL_MAX_LINES, width)
+                // Grapheme-based truncation can cut an SGR sequence in half,
+                // so colored output is truncated on line boundaries instead.
+                if text.text.contains('\u{1b}') {
+                    truncate_ansi_tool_result(&text.text, TOOL_CALL_MAX_LINES)
+                } else {
+                    format_and_truncate_tool_result(&text.text, TOOL_CALL_MAX_LINES, width)
+                }
             }
             rmcp::model::RawContent::Image(_) => "<image content>".to_string(),
             rmcp::model::RawContent::Audio(_) => "<audio content>".to_string(),
@@ -1622,7 +1780,10 @@ impl HistoryCell for McpToolCallCell {
                         for block in content {
                             let text = Self::render_content_block(block, detail_wrap_width);
                             for segment in text.split('\n') {
-                                let line = Line::from(segment.to_string().dim());
+                                let mut line = ansi_styled_line(segment);
+                                for span in &mut line.spans {
+                                    span.style = span.style.add_modifier(Modifier::DIM);
+                                }
                                 let wrapped = adaptive_wrap_line(
                                     &line,
                                     RtOptions::new(detail_wrap_width)
@@ -1664,6 +1825,10 @@ impl HistoryCell for McpToolCallCell {
         lines
     }
 
+    fn tool_call_kind(&self) -> Option<&'static str> {
+        Some("mcp")
+    }
+
     fn transcript_animation_tick(&self) -> Option<u64> {
         if !self.animations_enabled || self.result.is_some() {
             return None;
@@ -2254,12 +2419,29 @@ pub(crate) fn new_info_event(message: String, hint: Option<String>) -> PlainHist
     PlainHistoryCell { lines }
 }
 
-pub(crate) fn new_error_event(message: String) -> PlainHistoryCell {
+pub(crate) fn new_error_event(message: String) -> ErrorHistoryCell {
     // Use a hair space (U+200A) to create a subtle, near-invisible separation
     // before the text. VS16 is intentionally omitted to keep spacing tighter
     // in terminals like Ghostty.
     let lines: Vec<Line<'static>> = vec![vec![format!("■ {message}").red()].into()];
-    PlainHistoryCell { lines }
+    ErrorHistoryCell { lines }
+}
+
+/// Like [`PlainHistoryCell`], but reporting the `Error` transcript kind so
+/// the transcript overlay's type filter can isolate errors.
+#[derive(Debug)]
+pub(crate) struct ErrorHistoryCell {
+    lines: Vec<Line<'static>>,
+}
+
+impl HistoryCell for ErrorHistoryCell {
+    fn display_lines(&self, _width: u16) -> Vec<Line<'static>> {
+        self.lines.clone()
+    }
+
+    fn transcript_kind(&self) -> TranscriptCellKind {
+        TranscriptCellKind::Error
+    }
 }
 
 /// A transient history cell that shows an animated spinner while the MCP
@@ -2617,6 +2799,7 @@ pub(crate) fn new_patch_apply_failure(stderr: String) -> PlainHistoryCell {
                 only_err: true,
                 include_angle_pipe: true,
                 include_prefix: true,
+                fold: OutputFold::HeadTail,
             },
         );
         lines.extend(output.lines);
@@ -4865,4 +5048,28 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn collapse_keeps_header_and_counts_hidden_lines() {
+        let lines = vec![
+            Line::from("• Ran cargo build"),
+            Line::from("  └ output line 1"),
+            Line::from("    output line 2"),
+        ];
+        let rendered = render_lines(&collapse_lines_to_header(lines));
+        assert_eq!(
+            rendered,
+            vec![
+                "• Ran cargo build".to_string(),
+                "  … +2 lines (ctrl + t to view transcript)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn collapse_leaves_single_line_output_alone() {
+        let lines = vec![Line::from("• Ran true")];
+        let rendered = render_lines(&collapse_lines_to_header(lines));
+        assert_eq!(rendered, vec!["• Ran true".to_string()]);
+    }
 }
@@ -0,0 +1,370 @@
+//! Spill-to-disk budget for committed history cells.
+//!
+//! Long-running sessions accumulate every committed `HistoryCell` in memory,
+//! including fully rendered lines for cells the user will rarely scroll back
+//! to. When a budget is configured (`[tui.history_budget]`), the oldest cells
+//! beyond the budget have their transcript lines serialized to a per-session
+//! spool file and are replaced by a compact [`SpilledHistoryCell`] that
+//! rehydrates the lines from disk on demand (e.g. when the transcript overlay
+//! scrolls them back into view). Rehydrated lines are held in a small shared
+//! LRU cache so scrolling stays cheap without re-growing resident memory.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use codex_config::types::HistoryBudgetToml;
+use ratatui::style::Color;
+use ratatui::style::Modifier;
+use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::text::Span;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::history_cell::HistoryCell;
+use crate::history_cell::UserHistoryCell;
+
+/// Width used to measure a cell's resident footprint. The estimate only needs
+/// to be stable, not exact, so a fixed width avoids re-measuring on resize.
+const MEASURE_WIDTH: u16 = 80;
+
+/// The most recent cells are always kept resident regardless of budget so the
+/// active viewport never reads from disk.
+const KEEP_RECENT_CELLS: usize = 16;
+
+/// Number of rehydrated cells kept in the shared cache.
+const REHYDRATION_CACHE_CAPACITY: usize = 32;
+
+/// Resolved in-memory budget for committed history cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct HistoryBudget {
+    pub(crate) max_resident_cells: usize,
+    pub(crate) max_resident_bytes: u64,
+}
+
+impl HistoryBudget {
+    /// Returns `None` when no limit is configured, which preserves the
+    /// previous keep-everything behavior.
+    pub(crate) fn from_toml(toml: Option<&HistoryBudgetToml>) -> Option<Self> {
+        let toml = toml?;
+        if toml.max_resident_cells.is_none() && toml.max_resident_bytes.is_none() {
+            return None;
+        }
+        Some(Self {
+            max_resident_cells: toml.max_resident_cells.unwrap_or(usize::MAX),
+            max_resident_bytes: toml.max_resident_bytes.unwrap_or(u64::MAX),
+        })
+    }
+}
+
+/// Byte range of one serialized cell within the spool file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SpoolRange {
+    offset: u64,
+    len: u64,
+}
+
+/// Minimal serialized form of a styled span. Only the style attributes the
+/// transcript actually uses survive a spill round-trip; anything else falls
+/// back to the default style.
+#[derive(Debug, Serialize, Deserialize)]
+struct SpilledSpan {
+    text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fg: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    bold: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    dim: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    italic: bool,
+}
+
+impl SpilledSpan {
+    fn from_span(span: &Span<'_>) -> Self {
+        Self {
+            text: span.content.to_string(),
+            fg: span.style.fg.map(|c| c.to_string()),
+            bold: span.style.add_modifier.contains(Modifier::BOLD),
+            dim: span.style.add_modifier.contains(Modifier::DIM),
+            italic: span.style.add_modifier.contains(Modifier::ITALIC),
+        }
+    }
+
+    fn into_span(self) -> Span<'static> {
+        let mut style = Style::default();
+        if let Some(fg) = self.fg.as_deref()
+            && let Ok(color) = Color::from_str(fg)
+        {
+            style = style.fg(color);
+        }
+        if self.bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        if self.dim {
+            style = style.add_modifier(Modifier::DIM);
+        }
+        if self.italic {
+            style = style.add_modifier(Modifier::ITALIC);
+        }
+        Span::styled(self.text, style)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SpilledLines(Vec<Vec<SpilledSpan>>);
+
+impl SpilledLines {
+    fn from_lines(lines: &[Line<'_>]) -> Self {
+        Self(
+            lines
+                .iter()
+                .map(|line| line.spans.iter().map(SpilledSpan::from_span).collect())
+                .collect(),
+        )
+    }
+
+    fn into_lines(self) -> Vec<Line<'static>> {
+        self.0
+            .into_iter()
+            .map(|spans| {
+                Line::from(
+                    spans
+                        .into_iter()
+                        .map(SpilledSpan::into_span)
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect()
+    }
+}
+
+struct SpoolInner {
+    file: File,
+    next_offset: u64,
+    /// LRU of recently rehydrated cells, keyed by spool offset.
+    cache: VecDeque<(u64, Arc<Vec<Line<'static>>>)>,
+}
+
+/// Append-only per-session spool holding serialized transcript lines for
+/// spilled cells. Backed by an anonymous temp file that the OS reclaims when
+/// the session ends.
+pub(crate) struct HistorySpool {
+    inner: Mutex<SpoolInner>,
+}
+
+impl std::fmt::Debug for HistorySpool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HistorySpool").finish_non_exhaustive()
+    }
+}
+
+impl HistorySpool {
+    pub(crate) fn new() -> std::io::Result<Self> {
+        Ok(Self {
+            inner: Mutex::new(SpoolInner {
+                file: tempfile::tempfile()?,
+                next_offset: 0,
+                cache: VecDeque::new(),
+            }),
+        })
+    }
+
+    fn append(&self, lines: &[Line<'_>]) -> std::io::Result<SpoolRange> {
+        let payload = serde_json::to_vec(&SpilledLines::from_lines(lines))?;
+        let mut inner = self.inner.lock().expect("spool lock poisoned");
+        let offset = inner.next_offset;
+        inner.file.seek(SeekFrom::Start(offset))?;
+        inner.file.write_all(&payload)?;
+        inner.next_offset = offset + payload.len() as u64;
+        Ok(SpoolRange {
+            offset,
+            len: payload.len() as u64,
+        })
+    }
+
+    fn read(&self, range: SpoolRange) -> std::io::Result<Arc<Vec<Line<'static>>>> {
+        let mut inner = self.inner.lock().expect("spool lock poisoned");
+        if let Some((_, lines)) = inner
+            .cache
+            .iter()
+            .find(|(offset, _)| *offset == range.offset)
+        {
+            return Ok(lines.clone());
+        }
+        inner.file.seek(SeekFrom::Start(range.offset))?;
+        let mut payload = vec![0u8; usize::try_from(range.len).unwrap_or(0)];
+        inner.file.read_exact(&mut payload)?;
+        let spilled: SpilledLines = serde_json::from_slice(&payload)?;
+        let lines = Arc::new(spilled.into_lines());
+        inner.cache.push_back((range.offset, lines.clone()));
+        while inner.cache.len() > REHYDRATION_CACHE_CAPACITY {
+            inner.cache.pop_front();
+        }
+        Ok(lines)
+    }
+}
+
+/// Placeholder for a cell whose rendered lines live in the spool file.
+#[derive(Debug)]
+pub(crate) struct SpilledHistoryCell {
+    spool: Arc<HistorySpool>,
+    range: SpoolRange,
+    is_stream_continuation: bool,
+}
+
+impl HistoryCell for SpilledHistoryCell {
+    fn display_lines(&self, _width: u16) -> Vec<Line<'static>> {
+        match self.spool.read(self.range) {
+            Ok(lines) => lines.as_ref().clone(),
+            Err(_) => vec![Line::from("[history unavailable]")],
+        }
+    }
+
+    fn is_stream_continuation(&self) -> bool {
+        self.is_stream_continuation
+    }
+}
+
+/// Estimates the resident footprint of a cell by summing the bytes of its
+/// transcript span contents at a fixed measurement width.
+fn estimate_resident_bytes(cell: &Arc<dyn HistoryCell>) -> u64 {
+    cell.transcript_lines(MEASURE_WIDTH)
+        .iter()
+        .flat_map(|line| line.spans.iter())
+        .map(|span| span.content.len() as u64)
+        .sum()
+}
+
+/// Spills the oldest eligible cells until the resident set fits the budget.
+///
+/// User messages are never spilled because backtracking counts them by
+/// downcast; the most recent [`KEEP_RECENT_CELLS`] cells stay resident so the
+/// live viewport never blocks on disk.
+pub(crate) fn enforce_budget(
+    cells: &mut [Arc<dyn HistoryCell>],
+    spool: &Arc<HistorySpool>,
+    budget: HistoryBudget,
+) {
+    let resident: Vec<usize> = cells
+        .iter()
+        .enumerate()
+        .filter(|(_, cell)| !cell.as_any().is::<SpilledHistoryCell>())
+        .map(|(i, _)| i)
+        .collect();
+    let mut resident_bytes: u64 = resident
+        .iter()
+        .map(|&i| estimate_resident_bytes(&cells[i]))
+        .sum();
+    let mut resident_count = resident.len();
+
+    let spill_cutoff = cells.len().saturating_sub(KEEP_RECENT_CELLS);
+    for &i in &resident {
+        if i >= spill_cutoff {
+            break;
+        }
+        if resident_count <= budget.max_resident_cells
+            && resident_bytes <= budget.max_resident_bytes
+        {
+            break;
+        }
+        let cell = &cells[i];
+        if cell.as_any().is::<UserHistoryCell>() {
+            continue;
+        }
+        let lines = cell.transcript_lines(MEASURE_WIDTH);
+        let Ok(range) = spool.append(&lines) else {
+            // Spilling is strictly an optimization; on I/O failure keep the
+            // cell resident rather than lose it.
+            continue;
+        };
+        let bytes = estimate_resident_bytes(cell);
+        cells[i] = Arc::new(SpilledHistoryCell {
+            spool: spool.clone(),
+            range,
+            is_stream_continuation: cell.is_stream_continuation(),
+        });
+        resident_bytes = resident_bytes.saturating_sub(bytes);
+        resident_count -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history_cell::PlainHistoryCell;
+    use pretty_assertions::assert_eq;
+    use ratatui::style::Stylize;
+
+    fn plain_cell(text: &str) -> Arc<dyn HistoryCell> {
+        Arc::new(PlainHistoryCell::new(vec![Line::from(text.to_string())]))
+    }
+
+    fn rendered(cell: &Arc<dyn HistoryCell>) -> Vec<String> {
+        cell.display_lines(MEASURE_WIDTH)
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn spills_oldest_cells_beyond_budget_and_rehydrates() {
+        let spool = Arc::new(HistorySpool::new().expect("spool"));
+        let mut cells: Vec<Arc<dyn HistoryCell>> = (0..KEEP_RECENT_CELLS + 4)
+            .map(|i| plain_cell(&format!("cell {i}")))
+            .collect();
+        let budget = HistoryBudget {
+            max_resident_cells: KEEP_RECENT_CELLS + 2,
+            max_resident_bytes: u64::MAX,
+        };
+
+        enforce_budget(&mut cells, &spool, budget);
+
+        assert!(cells[0].as_any().is::<SpilledHistoryCell>());
+        assert!(cells[1].as_any().is::<SpilledHistoryCell>());
+        assert!(!cells[2].as_any().is::<SpilledHistoryCell>());
+        assert_eq!(rendered(&cells[0]), vec!["cell 0".to_string()]);
+        assert_eq!(rendered(&cells[1]), vec!["cell 1".to_string()]);
+    }
+
+    #[test]
+    fn recent_cells_stay_resident_even_when_over_budget() {
+        let spool = Arc::new(HistorySpool::new().expect("spool"));
+        let mut cells: Vec<Arc<dyn HistoryCell>> = (0..KEEP_RECENT_CELLS)
+            .map(|i| plain_cell(&format!("cell {i}")))
+            .collect();
+        let budget = HistoryBudget {
+            max_resident_cells: 1,
+            max_resident_bytes: 1,
+        };
+
+        enforce_budget(&mut cells, &spool, budget);
+
+        assert!(
+            cells
+                .iter()
+                .all(|cell| !cell.as_any().is::<SpilledHistoryCell>())
+        );
+    }
+
+    #[test]
+    fn spill_round_trip_preserves_basic_styling() {
+        let spool = Arc::new(HistorySpool::new().expect("spool"));
+        let line = Line::from(vec!["plain ".into(), "bold".bold(), " cyan".cyan()]);
+        let range = spool.append(std::slice::from_ref(&line)).expect("append");
+        let restored = spool.read(range).expect("read");
+        assert_eq!(restored.as_ref().clone(), vec![line]);
+    }
+}
@@ -0,0 +1,135 @@
+//! Message localization for user-facing TUI strings.
+//!
+//! Catalogs are flat `section.key = "string"` TOML files embedded from
+//! `tui/locales/`; English is the source of truth and every other locale is
+//! an overlay on top of it. The active locale comes from `[tui] language` or,
+//! when unset, the `LC_ALL`/`LC_MESSAGES`/`LANG` environment. Render paths
+//! fetch strings with [`tr`]; strftime patterns for locale-aware timestamps
+//! live in the catalogs too (see the `time.*` keys).
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static EN_CATALOG: &str = include_str!("../locales/en.toml");
+static ES_CATALOG: &str = include_str!("../locales/es.toml");
+
+type Catalog = HashMap<String, String>;
+
+static ENGLISH: OnceLock<Catalog> = OnceLock::new();
+static ACTIVE: OnceLock<Catalog> = OnceLock::new();
+
+/// Resolves the active locale from the `[tui] language` override or the
+/// locale environment. Called once at startup before anything renders;
+/// without it every lookup falls back to English.
+pub(crate) fn init(language: Option<&str>) {
+    let language = language
+        .map(str::to_string)
+        .or_else(|| locale_language_in(|key| std::env::var(key).ok()));
+    let catalog = match language.as_deref() {
+        Some("es") => parse_catalog(ES_CATALOG),
+        Some("en") | None => Catalog::new(),
+        Some(other) => {
+            tracing::warn!("no translations for language {other}; falling back to English");
+            Catalog::new()
+        }
+    };
+    let _ = ACTIVE.set(catalog);
+}
+
+/// Looks up a `section.key` string in the active locale, falling back to the
+/// English catalog. Unknown keys return the key itself so a typo shows up on
+/// screen instead of panicking.
+pub(crate) fn tr(key: &str) -> String {
+    if let Some(value) = ACTIVE.get().and_then(|catalog| catalog.get(key)) {
+        return value.clone();
+    }
+    english()
+        .get(key)
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
+
+fn english() -> &'static Catalog {
+    ENGLISH.get_or_init(|| parse_catalog(EN_CATALOG))
+}
+
+/// Flattens a catalog's `[section]` tables into `section.key` entries.
+fn parse_catalog(source: &str) -> Catalog {
+    let table: toml::Table = toml::from_str(source).expect("embedded locale catalogs are valid");
+    let mut catalog = Catalog::new();
+    for (section, value) in table {
+        let toml::Value::Table(entries) = value else {
+            continue;
+        };
+        for (key, value) in entries {
+            if let toml::Value::String(value) = value {
+                catalog.insert(format!("{section}.{key}"), value);
+            }
+        }
+    }
+    catalog
+}
+
+/// Extracts the bare language code (`es` from `es_MX.UTF-8`) from the locale
+/// environment, checked in the usual precedence order.
+fn locale_language_in(lookup: impl Fn(&str) -> Option<String>) -> Option<String> {
+    for key in ["LC_ALL", "LC_MESSAGES", "LANG"] {
+        match lookup(key) {
+            Some(value) if !value.is_empty() => {
+                let language = value
+                    .split(['_', '.', '@'])
+                    .next()
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+                return (!language.is_empty()).then_some(language);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn english_catalog_resolves_known_keys() {
+        assert_eq!(tr("approval.yes_proceed"), "Yes, proceed");
+        assert_eq!(tr("time.hour_minute"), "%H:%M");
+    }
+
+    #[test]
+    fn unknown_keys_return_the_key() {
+        assert_eq!(tr("no.such.key"), "no.such.key");
+    }
+
+    #[test]
+    fn every_spanish_key_exists_in_english() {
+        let english = parse_catalog(EN_CATALOG);
+        for key in parse_catalog(ES_CATALOG).keys() {
+            assert!(english.contains_key(key), "es.toml has unknown key {key}");
+        }
+    }
+
+    #[test]
+    fn locale_language_strips_region_and_encoding() {
+        assert_eq!(
+            locale_language_in(|_| Some("es_MX.UTF-8".to_string())),
+            Some("es".to_string())
+        );
+        assert_eq!(locale_language_in(|_| None), None);
+    }
+
+    #[test]
+    fn lc_all_takes_precedence() {
+        assert_eq!(
+            locale_language_in(|key| match key {
+                "LC_ALL" => Some("es_ES.UTF-8".to_string()),
+                _ => Some("en_US.UTF-8".to_string()),
+            }),
+            Some("es".to_string())
+        );
+    }
+}
@@ -53,6 +53,24 @@ pub(crate) const fn ctrl_alt(key: KeyCode) -> KeyBinding {
     KeyBinding::new(key, KeyModifiers::CONTROL.union(KeyModifiers::ALT))
 }
 
+/// Resolves the binding to display for a rebindable keymap action: the first
+/// single-chord sequence bound in the composer context. Returns `None` when
+/// the action is unbound or only reachable through two-chord sequences, which
+/// a [`KeyBinding`] cannot display; callers should hide the hint rather than
+/// advertise a default the user has remapped away.
+pub(crate) fn for_action(
+    keymap: &crate::keymap::TuiKeymap,
+    action: crate::keymap::KeymapAction,
+) -> Option<KeyBinding> {
+    keymap
+        .sequences_for(crate::keymap::KeymapContext::Composer, action)
+        .iter()
+        .find_map(|sequence| match sequence.chords() {
+            [chord] => Some(KeyBinding::new(chord.code, chord.modifiers)),
+            _ => None,
+        })
+}
+
 fn modifiers_to_string(modifiers: KeyModifiers) -> String {
     let mut result = String::new();
     if modifiers.contains(KeyModifiers::CONTROL) {
@@ -110,3 +128,38 @@ pub(crate) fn is_altgr(mods: KeyModifiers) -> bool {
 pub(crate) fn is_altgr(_mods: KeyModifiers) -> bool {
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keymap::KeymapAction;
+    use crate::keymap::build_keymap;
+    use codex_config::types::KeybindingsToml;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn for_action_tracks_rebinds_and_unbinds() {
+        let keymap = build_keymap(None);
+        assert_eq!(
+            for_action(&keymap, KeymapAction::OpenTranscript),
+            Some(ctrl(KeyCode::Char('t')))
+        );
+
+        let mut keybindings = KeybindingsToml::default();
+        keybindings
+            .composer
+            .insert("transcript".to_string(), "f6".to_string());
+        keybindings
+            .composer
+            .insert("external-editor".to_string(), "none".to_string());
+        let keymap = build_keymap(Some(&keybindings));
+        assert_eq!(
+            for_action(&keymap, KeymapAction::OpenTranscript),
+            Some(plain(KeyCode::F(6)))
+        );
+        assert_eq!(
+            for_action(&keymap, KeymapAction::LaunchExternalEditor),
+            None
+        );
+    }
+}
@@ -0,0 +1,1446 @@
+//! Key chord parsing and resolution for TUI shortcuts.
+//!
+//! A [`KeyChord`] is a single key press plus modifiers (`ctrl+t`, `g`). A
+//! [`KeySequence`] is one or two chords pressed in order (`ctrl+x ctrl+s`,
+//! `g g`), which is what makes emacs/vim-style leader bindings expressible.
+//! [`TuiKeymap`] maps sequences to [`KeymapAction`]s and [`ChordResolver`]
+//! holds the "first chord of two seen, waiting for the second" state,
+//! including the pending-chord timeout.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_config::types::KeybindingPreset;
+use codex_config::types::KeybindingProfileToml;
+use codex_config::types::KeybindingsToml;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use crossterm::event::KeyModifiers;
+
+/// How long a pending first chord stays armed before it is discarded. Kept
+/// short so a half-typed sequence does not silently swallow composer input.
+pub(crate) const PENDING_CHORD_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Default window for double-press bindings (the same chord twice, e.g.
+/// `esc esc`). Much shorter than [`PENDING_CHORD_TIMEOUT`] because the first
+/// press is delivered normally, so the window only decides whether a repeat
+/// counts as "pressed twice"; overridden by `double_press_ms` in
+/// `[tui.keybindings]`.
+pub(crate) const DOUBLE_PRESS_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Key spec that unbinds an action instead of giving it a sequence. Reserved
+/// before parsing, so `none` can never name a key.
+const UNBOUND_SPEC: &str = "none";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct KeyChord {
+    pub(crate) code: KeyCode,
+    pub(crate) modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    pub(crate) fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Normalizes a raw key event into the chord it represents. Shift is
+    /// folded into the character for character keys: classic terminals
+    /// already deliver the shifted character (`Shift+g` arrives as `G`),
+    /// while the enhanced keyboard protocol reports the base character plus
+    /// `SHIFT` (`ctrl+shift+p` arrives as `p`), so both forms hash like the
+    /// parsed spec.
+    pub(crate) fn from_key_event(event: &KeyEvent) -> Self {
+        match event.code {
+            KeyCode::Char(c) => Self {
+                code: KeyCode::Char(if event.modifiers.contains(KeyModifiers::SHIFT) {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }),
+                modifiers: event.modifiers - KeyModifiers::SHIFT,
+            },
+            _ => Self {
+                code: event.code,
+                modifiers: event.modifiers,
+            },
+        }
+    }
+
+    /// Parses a single chord token such as `ctrl+t`, `alt+enter` or `g`.
+    /// Everything before the final `+` must be a modifier; the last segment
+    /// names the key.
+    pub(crate) fn parse(token: &str) -> Result<Self, KeymapError> {
+        let parts: Vec<&str> = token.split('+').collect();
+        let Some((key, modifier_parts)) = parts.split_last() else {
+            return Err(KeymapError::EmptyChord);
+        };
+        let mut modifiers = KeyModifiers::NONE;
+        for part in modifier_parts {
+            modifiers |= match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "opt" | "option" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                "cmd" | "super" => KeyModifiers::SUPER,
+                _ => return Err(KeymapError::UnknownModifier(part.to_string())),
+            };
+        }
+        let code = parse_key_code(key)?;
+        // Shift on a character key is redundant with the shifted character
+        // itself; normalize so `shift+g` and `G` hash identically.
+        if let KeyCode::Char(c) = code
+            && modifiers.contains(KeyModifiers::SHIFT)
+        {
+            return Ok(Self {
+                code: KeyCode::Char(c.to_ascii_uppercase()),
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            });
+        }
+        Ok(Self { code, modifiers })
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "shift+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SUPER) {
+            write!(f, "cmd+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "f{n}"),
+            KeyCode::Enter => write!(f, "enter"),
+            KeyCode::Esc => write!(f, "esc"),
+            KeyCode::Tab => write!(f, "tab"),
+            KeyCode::Backspace => write!(f, "backspace"),
+            KeyCode::Up => write!(f, "up"),
+            KeyCode::Down => write!(f, "down"),
+            KeyCode::Left => write!(f, "left"),
+            KeyCode::Right => write!(f, "right"),
+            KeyCode::Home => write!(f, "home"),
+            KeyCode::End => write!(f, "end"),
+            KeyCode::PageUp => write!(f, "pageup"),
+            KeyCode::PageDown => write!(f, "pagedown"),
+            KeyCode::Delete => write!(f, "delete"),
+            KeyCode::Insert => write!(f, "insert"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+fn parse_key_code(key: &str) -> Result<KeyCode, KeymapError> {
+    let lower = key.to_ascii_lowercase();
+    if let Some(digits) = lower.strip_prefix('f')
+        && let Ok(n) = digits.parse::<u8>()
+        && (1..=24).contains(&n)
+    {
+        return Ok(KeyCode::F(n));
+    }
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(KeyCode::Char(c));
+    }
+    Ok(match lower.as_str() {
+        "space" => KeyCode::Char(' '),
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" => KeyCode::Insert,
+        _ => return Err(KeymapError::UnknownKey(key.to_string())),
+    })
+}
+
+/// One or two chords pressed in order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct KeySequence(Vec<KeyChord>);
+
+impl KeySequence {
+    /// Parses a whitespace-separated chord sequence such as `ctrl+x ctrl+s`
+    /// or `g g`. At most two chords are supported.
+    pub(crate) fn parse(spec: &str) -> Result<Self, KeymapError> {
+        let chords = spec
+            .split_whitespace()
+            .map(KeyChord::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        match chords.len() {
+            0 => Err(KeymapError::EmptyChord),
+            1 | 2 => Ok(Self(chords)),
+            _ => Err(KeymapError::TooManyChords(spec.to_string())),
+        }
+    }
+
+    pub(crate) fn chords(&self) -> &[KeyChord] {
+        &self.0
+    }
+
+    /// Whether this is a double-press sequence: the same chord twice, such as
+    /// `esc esc`. Double-press bindings resolve differently from leader
+    /// sequences — the first press is delivered normally and only a repeat
+    /// within the double-press window triggers the action.
+    pub(crate) fn is_double_press(&self) -> bool {
+        matches!(self.0.as_slice(), [first, second] if first == second)
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, chord) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{chord}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub(crate) enum KeymapError {
+    #[error("empty key chord")]
+    EmptyChord,
+    #[error("unknown key `{0}`")]
+    UnknownKey(String),
+    #[error("unknown modifier `{0}`")]
+    UnknownModifier(String),
+    #[error("`{0}` has more than two chords; sequences support at most two")]
+    TooManyChords(String),
+    #[error("`{sequence}` is bound to both `{kept}` and `{dropped}`")]
+    Conflict {
+        sequence: String,
+        kept: String,
+        dropped: String,
+    },
+}
+
+/// Global actions dispatchable through the keymap. These mirror the shortcuts
+/// `App::handle_key_event` used to hardcode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum KeymapAction {
+    /// Open the full transcript overlay (default `ctrl+t`).
+    OpenTranscript,
+    /// Clear the terminal UI and redraw the header (default `ctrl+l`).
+    ClearScreen,
+    /// Edit the composer draft in the external `$EDITOR` (default `ctrl+g`).
+    LaunchExternalEditor,
+    /// Toggle collapsed rendering of tool calls (default `ctrl+x`).
+    ToggleToolCallsCollapsed,
+    /// Open the fuzzy action palette (default `ctrl+shift+p`).
+    OpenActionPalette,
+    /// Start recording composer keystrokes into a macro (unbound by default;
+    /// reachable through the action palette).
+    MacroRecordStart,
+    /// Stop recording and persist the macro to `macros.json` in `CODEX_HOME`.
+    MacroRecordStop,
+    /// Pick a saved macro and replay its keystrokes into the composer.
+    MacroPlay,
+    /// Suspend mouse capture and the alternate screen so the terminal's
+    /// native text selection works; any key press restores them (unbound by
+    /// default; reachable through the action palette).
+    ToggleTextSelection,
+}
+
+/// Action names accepted in `[tui.keybindings]`, paired with the action they
+/// rebind.
+pub(crate) const TUI_KEYBINDING_ACTIONS: &[(&str, KeymapAction)] = &[
+    ("transcript", KeymapAction::OpenTranscript),
+    ("clear-screen", KeymapAction::ClearScreen),
+    ("external-editor", KeymapAction::LaunchExternalEditor),
+    ("toggle-tool-calls", KeymapAction::ToggleToolCallsCollapsed),
+    ("action-palette", KeymapAction::OpenActionPalette),
+    ("macro-record-start", KeymapAction::MacroRecordStart),
+    ("macro-record-stop", KeymapAction::MacroRecordStop),
+    ("macro-play", KeymapAction::MacroPlay),
+    ("toggle-text-selection", KeymapAction::ToggleTextSelection),
+];
+
+impl KeymapAction {
+    /// Short human label shown in the `/keybindings` overlay and the action
+    /// palette.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            KeymapAction::OpenTranscript => "open the transcript overlay",
+            KeymapAction::ClearScreen => "clear the screen and redraw",
+            KeymapAction::LaunchExternalEditor => "edit the draft in $EDITOR",
+            KeymapAction::ToggleToolCallsCollapsed => "toggle collapsed tool calls",
+            KeymapAction::OpenActionPalette => "open the action palette",
+            KeymapAction::MacroRecordStart => "start recording a macro",
+            KeymapAction::MacroRecordStop => "stop recording and save the macro",
+            KeymapAction::MacroPlay => "replay a saved macro",
+            KeymapAction::ToggleTextSelection => "select text with the terminal",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        TUI_KEYBINDING_ACTIONS
+            .iter()
+            .find(|(action_name, _)| *action_name == name)
+            .map(|(_, action)| *action)
+    }
+
+    /// The `[tui.keybindings]` name for this action, used in conflict
+    /// messages.
+    fn name(self) -> &'static str {
+        TUI_KEYBINDING_ACTIONS
+            .iter()
+            .find(|(_, action)| *action == self)
+            .map(|(name, _)| *name)
+            .unwrap_or("unknown")
+    }
+}
+
+/// Where a key press happened. Context-specific bindings shadow global ones;
+/// global bindings only apply in the composer because pager overlays have
+/// always owned every key while open.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum KeymapContext {
+    /// The main chat view with the composer focused.
+    Composer,
+    /// A pager overlay (transcript, diff, static views) is open.
+    Pager,
+}
+
+impl KeymapContext {
+    /// Name of this context's `[tui.keybindings.<context>]` sub-table, also
+    /// used as its display label.
+    pub(crate) fn config_key(self) -> &'static str {
+        match self {
+            KeymapContext::Composer => "composer",
+            KeymapContext::Pager => "pager",
+        }
+    }
+}
+
+/// The terminal identity used to select `[tui.keybindings.profiles.<name>]`
+/// blocks.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TerminalEnvironment {
+    pub(crate) term_program: Option<String>,
+    pub(crate) term: Option<String>,
+}
+
+impl TerminalEnvironment {
+    /// Reads `$TERM_PROGRAM` and `$TERM` from the process environment.
+    pub(crate) fn detect() -> Self {
+        Self {
+            term_program: std::env::var("TERM_PROGRAM").ok(),
+            term: std::env::var("TERM").ok(),
+        }
+    }
+
+    /// Whether `profile` applies in this terminal: every matcher the profile
+    /// specifies must match its variable. A profile without matchers never
+    /// applies — silently binding everywhere would defeat the point of
+    /// scoping it.
+    fn matches(&self, profile: &KeybindingProfileToml) -> bool {
+        if profile.term_program.is_none() && profile.term.is_none() {
+            return false;
+        }
+        let matches_var = |pattern: &Option<String>, value: &Option<String>| match pattern {
+            None => true,
+            Some(pattern) => value
+                .as_deref()
+                .is_some_and(|value| matcher_matches(pattern, value)),
+        };
+        matches_var(&profile.term_program, &self.term_program)
+            && matches_var(&profile.term, &self.term)
+    }
+}
+
+/// Case-insensitive matcher with an optional trailing `*` wildcard, e.g.
+/// `tmux*` matches `tmux-256color`.
+fn matcher_matches(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.to_ascii_lowercase();
+    let value = value.to_ascii_lowercase();
+    match pattern.strip_suffix('*') {
+        Some(prefix) => value.starts_with(prefix),
+        None => value == pattern,
+    }
+}
+
+enum SequenceMatch {
+    Action(KeymapAction),
+    /// The pressed chords are a proper prefix of at least one bound sequence.
+    /// `double_press_only` is true when every such sequence is a double-press
+    /// (same chord twice), which means the press can be delivered instead of
+    /// swallowed while the repeat window is armed.
+    Prefix {
+        double_press_only: bool,
+    },
+    Unbound,
+}
+
+pub(crate) struct TuiKeymap {
+    bindings: HashMap<KeySequence, KeymapAction>,
+    context_bindings: HashMap<KeymapContext, HashMap<KeySequence, KeymapAction>>,
+    /// How long after a delivered first press a repeat still completes a
+    /// double-press binding.
+    double_press_window: Duration,
+}
+
+impl TuiKeymap {
+    /// The built-in bindings for `preset`. [`KeybindingPreset::Default`] is
+    /// equivalent to the historical hardcoded shortcuts; the vim and emacs
+    /// presets restyle the same actions as leader sequences so users do not
+    /// have to rebind every action by hand. Presets cover the actions the
+    /// keymap dispatches today — they do not change composer editing itself.
+    /// Specs are parsed so defaults and user overrides go through the same
+    /// code path.
+    pub(crate) fn default_bindings(preset: KeybindingPreset) -> Self {
+        let mut keymap = Self {
+            bindings: HashMap::new(),
+            context_bindings: HashMap::new(),
+            double_press_window: DOUBLE_PRESS_TIMEOUT,
+        };
+        match preset {
+            KeybindingPreset::Default => {
+                keymap.bind_default("ctrl+t", KeymapAction::OpenTranscript);
+                keymap.bind_default("ctrl+l", KeymapAction::ClearScreen);
+                keymap.bind_default("ctrl+g", KeymapAction::LaunchExternalEditor);
+                keymap.bind_default("ctrl+x", KeymapAction::ToggleToolCallsCollapsed);
+                keymap.bind_default("ctrl+shift+p", KeymapAction::OpenActionPalette);
+            }
+            KeybindingPreset::Vim => {
+                // Space-leader sequences; `ctrl+l` stays as vim's redraw. A
+                // lone `space` that outlives the pending-chord timeout is
+                // dropped, which is the usual leader-key trade-off and why
+                // this table is opt-in.
+                keymap.bind_default("space t", KeymapAction::OpenTranscript);
+                keymap.bind_default("ctrl+l", KeymapAction::ClearScreen);
+                keymap.bind_default("space e", KeymapAction::LaunchExternalEditor);
+                keymap.bind_default("space c", KeymapAction::ToggleToolCallsCollapsed);
+                keymap.bind_default("ctrl+shift+p", KeymapAction::OpenActionPalette);
+            }
+            KeybindingPreset::Emacs => {
+                // `ctrl+x` as a prefix, readline-style: `ctrl+l` clears the
+                // screen and `ctrl+x ctrl+e` mirrors edit-and-execute-command.
+                keymap.bind_default("ctrl+x t", KeymapAction::OpenTranscript);
+                keymap.bind_default("ctrl+l", KeymapAction::ClearScreen);
+                keymap.bind_default("ctrl+x ctrl+e", KeymapAction::LaunchExternalEditor);
+                keymap.bind_default("ctrl+x c", KeymapAction::ToggleToolCallsCollapsed);
+                keymap.bind_default("ctrl+shift+p", KeymapAction::OpenActionPalette);
+            }
+        }
+        keymap
+    }
+
+    /// Builds the effective keymap: the built-in table for the configured
+    /// `preset`, with flat `[tui.keybindings]` entries rebinding actions
+    /// globally and the per-context sub-tables layered on top. An entry whose
+    /// spec is `"none"` unbinds the action instead. Invalid entries are
+    /// logged and skipped so one typo does not disable the rest of the table.
+    ///
+    /// A conflict — one sequence bound to two different actions — is resolved
+    /// by priority: the later entry wins (user entries always beat defaults)
+    /// and the losing binding is auto-unbound, recorded as a warning for the
+    /// caller to surface. With `strict = true` in `[tui.keybindings]` the
+    /// first conflict is returned as [`KeymapError::Conflict`] instead.
+    pub(crate) fn from_keybindings(
+        keybindings: Option<&KeybindingsToml>,
+    ) -> Result<(Self, Vec<String>), KeymapError> {
+        Self::from_keybindings_with_environment(keybindings, &TerminalEnvironment::detect())
+    }
+
+    /// [`TuiKeymap::from_keybindings`] with an explicit terminal environment,
+    /// which decides which `[tui.keybindings.profiles.<name>]` blocks apply.
+    /// Matching profiles are merged after the base table in name order, so a
+    /// later profile wins when two of them rebind the same action.
+    pub(crate) fn from_keybindings_with_environment(
+        keybindings: Option<&KeybindingsToml>,
+        environment: &TerminalEnvironment,
+    ) -> Result<(Self, Vec<String>), KeymapError> {
+        let Some(keybindings) = keybindings else {
+            return Ok((
+                Self::default_bindings(KeybindingPreset::Default),
+                Vec::new(),
+            ));
+        };
+        let mut keymap = Self::default_bindings(keybindings.preset);
+        if let Some(double_press_ms) = keybindings.double_press_ms {
+            keymap.double_press_window = Duration::from_millis(double_press_ms);
+        }
+        let mut resolution = ConflictResolution {
+            strict: keybindings.strict,
+            warnings: Vec::new(),
+        };
+        keymap.apply_table(
+            &keybindings.global,
+            &keybindings.composer,
+            &keybindings.pager,
+            &mut resolution,
+        )?;
+        for profile in keybindings
+            .profiles
+            .values()
+            .filter(|profile| environment.matches(profile))
+        {
+            keymap.apply_table(
+                &profile.global,
+                &profile.composer,
+                &profile.pager,
+                &mut resolution,
+            )?;
+        }
+        Ok((keymap, resolution.warnings))
+    }
+
+    /// Applies one table's flat and per-context entries; shared by the base
+    /// `[tui.keybindings]` table and the terminal profiles layered on top.
+    fn apply_table(
+        &mut self,
+        global: &BTreeMap<String, String>,
+        composer: &BTreeMap<String, String>,
+        pager: &BTreeMap<String, String>,
+        resolution: &mut ConflictResolution,
+    ) -> Result<(), KeymapError> {
+        for (action_name, spec) in global {
+            self.rebind_global(action_name, spec, resolution)?;
+        }
+        for (action_name, spec) in composer {
+            self.bind_context(KeymapContext::Composer, action_name, spec, resolution)?;
+        }
+        for (action_name, spec) in pager {
+            self.bind_context(KeymapContext::Pager, action_name, spec, resolution)?;
+        }
+        Ok(())
+    }
+
+    fn bind_default(&mut self, spec: &str, action: KeymapAction) {
+        match KeySequence::parse(spec) {
+            Ok(sequence) => {
+                self.bindings.insert(sequence, action);
+            }
+            Err(err) => {
+                tracing::error!("invalid default keybinding `{spec}`: {err}");
+            }
+        }
+    }
+
+    /// Rebinds `action` globally: its previous sequences are removed so an
+    /// override replaces the default rather than adding an alias. The spec
+    /// `"none"` stops there, leaving the action unbound entirely — the only
+    /// way to turn off a default binding without giving it a new key.
+    fn rebind_global(
+        &mut self,
+        action_name: &str,
+        spec: &str,
+        resolution: &mut ConflictResolution,
+    ) -> Result<(), KeymapError> {
+        let Some(action) = KeymapAction::from_name(action_name) else {
+            tracing::error!("unknown keybinding action `{action_name}`");
+            return Ok(());
+        };
+        if spec.trim() == UNBOUND_SPEC {
+            self.bindings.retain(|_, bound| *bound != action);
+            return Ok(());
+        }
+        match KeySequence::parse(spec) {
+            Ok(sequence) => {
+                self.bindings.retain(|_, bound| *bound != action);
+                resolve_conflict(&mut self.bindings, sequence, action, resolution)
+            }
+            Err(err) => {
+                tracing::error!("invalid keybinding `{spec}` for `{action_name}`: {err}");
+                Ok(())
+            }
+        }
+    }
+
+    /// Binds `action` only within `context`, shadowing any global binding for
+    /// the same sequence there. The spec `"none"` instead drops the action's
+    /// entries from the context sub-table.
+    fn bind_context(
+        &mut self,
+        context: KeymapContext,
+        action_name: &str,
+        spec: &str,
+        resolution: &mut ConflictResolution,
+    ) -> Result<(), KeymapError> {
+        let Some(action) = KeymapAction::from_name(action_name) else {
+            tracing::error!("unknown keybinding action `{action_name}`");
+            return Ok(());
+        };
+        if spec.trim() == UNBOUND_SPEC {
+            if let Some(bindings) = self.context_bindings.get_mut(&context) {
+                bindings.retain(|_, bound| *bound != action);
+            }
+            return Ok(());
+        }
+        match KeySequence::parse(spec) {
+            Ok(sequence) => {
+                let bindings = self.context_bindings.entry(context).or_default();
+                resolve_conflict(bindings, sequence, action, resolution)
+            }
+            Err(err) => {
+                tracing::error!("invalid keybinding `{spec}` for `{action_name}`: {err}");
+                Ok(())
+            }
+        }
+    }
+
+    /// Sequences that trigger `action` in `context`, sorted by their display
+    /// form. The composer includes global bindings because they only apply
+    /// there; the pager lists only its own sub-table entries.
+    pub(crate) fn sequences_for(
+        &self,
+        context: KeymapContext,
+        action: KeymapAction,
+    ) -> Vec<KeySequence> {
+        let bound_to = |bindings: &HashMap<KeySequence, KeymapAction>| {
+            bindings
+                .iter()
+                .filter(|(_, bound)| **bound == action)
+                .map(|(sequence, _)| sequence.clone())
+                .collect::<Vec<_>>()
+        };
+        let mut sequences = self
+            .context_bindings
+            .get(&context)
+            .map(bound_to)
+            .unwrap_or_default();
+        if context == KeymapContext::Composer {
+            sequences.extend(bound_to(&self.bindings));
+        }
+        sequences.sort_by_key(ToString::to_string);
+        sequences.dedup();
+        sequences
+    }
+
+    /// The global table as `action name -> sorted key specs`, in
+    /// [`TUI_KEYBINDING_ACTIONS`] order. This is the serialization path for
+    /// `codex keybindings dump`: specs render through [`KeySequence`]'s
+    /// display form, so dumped entries parse back into the same bindings.
+    pub(crate) fn global_table(&self) -> Vec<(&'static str, Vec<String>)> {
+        table_entries(&self.bindings)
+    }
+
+    /// Like [`TuiKeymap::global_table`], but for `context`'s sub-table only —
+    /// no global fallthrough, mirroring the `[tui.keybindings.<context>]`
+    /// layout in config.
+    pub(crate) fn context_table(&self, context: KeymapContext) -> Vec<(&'static str, Vec<String>)> {
+        self.context_bindings
+            .get(&context)
+            .map(table_entries)
+            .unwrap_or_default()
+    }
+
+    /// Whether any binding is scoped to `context`.
+    pub(crate) fn has_context_bindings(&self, context: KeymapContext) -> bool {
+        self.context_bindings
+            .get(&context)
+            .is_some_and(|bindings| !bindings.is_empty())
+    }
+
+    fn lookup(&self, context: KeymapContext, chords: &[KeyChord]) -> SequenceMatch {
+        let context_bindings = self.context_bindings.get(&context);
+        let sequence = KeySequence(chords.to_vec());
+        if let Some(action) = context_bindings.and_then(|bindings| bindings.get(&sequence)) {
+            return SequenceMatch::Action(*action);
+        }
+        // Pager overlays own every key while open, so global bindings apply
+        // only in the composer.
+        if context == KeymapContext::Composer
+            && let Some(action) = self.bindings.get(&sequence)
+        {
+            return SequenceMatch::Action(*action);
+        }
+        let starts_with = |sequence: &&KeySequence| {
+            sequence.chords().len() > chords.len() && sequence.chords().starts_with(chords)
+        };
+        let continuations = context_bindings
+            .into_iter()
+            .flat_map(HashMap::keys)
+            .filter(starts_with)
+            .chain(
+                (context == KeymapContext::Composer)
+                    .then_some(&self.bindings)
+                    .into_iter()
+                    .flat_map(HashMap::keys)
+                    .filter(starts_with),
+            )
+            .collect::<Vec<_>>();
+        if continuations.is_empty() {
+            SequenceMatch::Unbound
+        } else {
+            SequenceMatch::Prefix {
+                double_press_only: continuations
+                    .iter()
+                    .all(|sequence| sequence.is_double_press()),
+            }
+        }
+    }
+}
+
+/// Renders one binding table as `action name -> sorted key specs`, keeping
+/// only bound actions and listing them in [`TUI_KEYBINDING_ACTIONS`] order.
+fn table_entries(
+    bindings: &HashMap<KeySequence, KeymapAction>,
+) -> Vec<(&'static str, Vec<String>)> {
+    TUI_KEYBINDING_ACTIONS
+        .iter()
+        .filter_map(|(name, action)| {
+            let mut specs: Vec<String> = bindings
+                .iter()
+                .filter(|(_, bound)| *bound == *action)
+                .map(|(sequence, _)| sequence.to_string())
+                .collect();
+            if specs.is_empty() {
+                return None;
+            }
+            specs.sort();
+            Some((*name, specs))
+        })
+        .collect()
+}
+
+/// Tracks conflict handling while a keymap is being built: whether a conflict
+/// is fatal, and the auto-resolution warnings accumulated so far.
+struct ConflictResolution {
+    strict: bool,
+    warnings: Vec<String>,
+}
+
+/// Inserts `sequence -> action`, handling the case where the sequence already
+/// triggers a different action in the same table. Lenient mode drops the
+/// earlier binding and records what was unbound; strict mode errors.
+fn resolve_conflict(
+    bindings: &mut HashMap<KeySequence, KeymapAction>,
+    sequence: KeySequence,
+    action: KeymapAction,
+    resolution: &mut ConflictResolution,
+) -> Result<(), KeymapError> {
+    if let Some(previous) = bindings.insert(sequence.clone(), action)
+        && previous != action
+    {
+        if resolution.strict {
+            return Err(KeymapError::Conflict {
+                sequence: sequence.to_string(),
+                kept: action.name().to_string(),
+                dropped: previous.name().to_string(),
+            });
+        }
+        resolution.warnings.push(format!(
+            "`{sequence}` now triggers `{kept}`; its `{dropped}` binding was unbound",
+            kept = action.name(),
+            dropped = previous.name(),
+        ));
+    }
+    Ok(())
+}
+
+/// Lenient [`TuiKeymap::from_keybindings`]: conflict warnings are dropped and
+/// a strict-mode conflict falls back to the defaults. Used where the keymap is
+/// rebuilt after startup and there is no good place to fail.
+pub(crate) fn build_keymap(keybindings: Option<&KeybindingsToml>) -> TuiKeymap {
+    match TuiKeymap::from_keybindings(keybindings) {
+        Ok((keymap, _warnings)) => keymap,
+        Err(err) => {
+            tracing::error!("invalid [tui.keybindings]: {err}");
+            TuiKeymap::default_bindings(
+                keybindings
+                    .map(|keybindings| keybindings.preset)
+                    .unwrap_or_default(),
+            )
+        }
+    }
+}
+
+/// What a key press resolved to, given the keymap and any pending chord.
+pub(crate) enum ChordResolution {
+    /// A bound sequence completed.
+    Action(KeymapAction),
+    /// The chord is the first half of at least one bound sequence; it was
+    /// swallowed and the caller should surface the pending prefix.
+    Pending(KeyChord),
+    /// Nothing matched; deliver these events to the regular key handlers in
+    /// order (two events when a pending first chord turned out not to start a
+    /// bound sequence after all).
+    Passthrough(Vec<KeyEvent>),
+}
+
+/// Tracks an armed first chord between key presses.
+///
+/// A pending chord that outlives [`PENDING_CHORD_TIMEOUT`] is discarded: it
+/// was already consumed as a potential sequence prefix, and replaying stale
+/// input into the composer seconds later would be more surprising than
+/// dropping it.
+#[derive(Default)]
+pub(crate) struct ChordResolver {
+    pending: Option<PendingChord>,
+}
+
+struct PendingChord {
+    event: KeyEvent,
+    chord: KeyChord,
+    at: Instant,
+    /// True when the press was already passed through to the regular key
+    /// handlers because it only arms double-press bindings. A delivered press
+    /// must never be replayed, and its repeat window is the (shorter)
+    /// double-press window.
+    delivered: bool,
+}
+
+impl ChordResolver {
+    pub(crate) fn press(
+        &mut self,
+        keymap: &TuiKeymap,
+        context: KeymapContext,
+        event: KeyEvent,
+        now: Instant,
+    ) -> ChordResolution {
+        let chord = KeyChord::from_key_event(&event);
+        if let Some(pending) = self.pending.take() {
+            let window = if pending.delivered {
+                keymap.double_press_window
+            } else {
+                PENDING_CHORD_TIMEOUT
+            };
+            if now.duration_since(pending.at) <= window {
+                match keymap.lookup(context, &[pending.chord, chord]) {
+                    SequenceMatch::Action(action) => return ChordResolution::Action(action),
+                    // Sequences are capped at two chords, so anything that is
+                    // not a completed binding sends both swallowed keys
+                    // through unchanged. A delivered first press already
+                    // reached the handlers, so only the new event is left —
+                    // fall through and resolve it as a fresh press instead.
+                    SequenceMatch::Prefix { .. } | SequenceMatch::Unbound => {
+                        if !pending.delivered {
+                            return ChordResolution::Passthrough(vec![pending.event, event]);
+                        }
+                    }
+                }
+            }
+            // An expired pending chord is simply dropped; see the struct docs.
+        }
+        match keymap.lookup(context, &[chord]) {
+            SequenceMatch::Action(action) => ChordResolution::Action(action),
+            SequenceMatch::Prefix { double_press_only } => {
+                self.pending = Some(PendingChord {
+                    event,
+                    chord,
+                    at: now,
+                    delivered: double_press_only,
+                });
+                if double_press_only {
+                    // Arming a double-press must not swallow the first press:
+                    // `esc` keeps its normal behavior and only the repeat
+                    // triggers the binding.
+                    ChordResolution::Passthrough(vec![event])
+                } else {
+                    ChordResolution::Pending(chord)
+                }
+            }
+            SequenceMatch::Unbound => ChordResolution::Passthrough(vec![event]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn two_chord_keymap() -> TuiKeymap {
+        let mut keymap = TuiKeymap::default_bindings(KeybindingPreset::Default);
+        keymap.bind_default("ctrl+x ctrl+s", KeymapAction::ClearScreen);
+        keymap.bind_default("g g", KeymapAction::OpenTranscript);
+        keymap
+    }
+
+    #[test]
+    fn parses_single_and_two_step_sequences() {
+        let single = KeySequence::parse("ctrl+t").expect("single chord");
+        assert_eq!(
+            single.chords(),
+            &[KeyChord::new(KeyCode::Char('t'), KeyModifiers::CONTROL)]
+        );
+
+        let double = KeySequence::parse("ctrl+x ctrl+s").expect("two chords");
+        assert_eq!(double.chords().len(), 2);
+        assert_eq!(double.to_string(), "ctrl+x ctrl+s");
+
+        assert_eq!(
+            KeySequence::parse("g g g"),
+            Err(KeymapError::TooManyChords("g g g".to_string()))
+        );
+        // `ctrl+q+t` fails because `q` is not a modifier.
+        assert_eq!(
+            KeySequence::parse("ctrl+q+t"),
+            Err(KeymapError::UnknownModifier("q".to_string()))
+        );
+    }
+
+    #[test]
+    fn two_step_sequence_resolves_through_pending_state() {
+        let keymap = two_chord_keymap();
+        let mut resolver = ChordResolver::default();
+        let now = Instant::now();
+
+        let first = resolver.press(
+            &keymap,
+            KeymapContext::Composer,
+            press(KeyCode::Char('x'), KeyModifiers::CONTROL),
+            now,
+        );
+        assert!(matches!(
+            first,
+            ChordResolution::Pending(chord) if chord.to_string() == "ctrl+x"
+        ));
+
+        let second = resolver.press(
+            &keymap,
+            KeymapContext::Composer,
+            press(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            now + Duration::from_millis(300),
+        );
+        assert!(matches!(
+            second,
+            ChordResolution::Action(KeymapAction::ClearScreen)
+        ));
+    }
+
+    #[test]
+    fn shifted_character_chords_match_both_key_event_forms() {
+        let keymap = TuiKeymap::default_bindings(KeybindingPreset::Default);
+        let mut resolver = ChordResolver::default();
+
+        // Enhanced keyboard protocol: base character plus SHIFT.
+        let enhanced = resolver.press(
+            &keymap,
+            KeymapContext::Composer,
+            press(
+                KeyCode::Char('p'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+            ),
+            Instant::now(),
+        );
+        assert!(matches!(
+            enhanced,
+            ChordResolution::Action(KeymapAction::OpenActionPalette)
+        ));
+
+        // Shifted-character form: the terminal delivers `P` directly.
+        let shifted = resolver.press(
+            &keymap,
+            KeymapContext::Composer,
+            press(
+                KeyCode::Char('P'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+            ),
+            Instant::now(),
+        );
+        assert!(matches!(
+            shifted,
+            ChordResolution::Action(KeymapAction::OpenActionPalette)
+        ));
+    }
+
+    #[test]
+    fn unfinished_sequence_passes_both_keys_through() {
+        let keymap = two_chord_keymap();
+        let mut resolver = ChordResolver::default();
+        let now = Instant::now();
+
+        let g = press(KeyCode::Char('g'), KeyModifiers::NONE);
+        assert!(matches!(
+            resolver.press(&keymap, KeymapContext::Composer, g, now),
+            ChordResolution::Pending(_)
+        ));
+
+        let h = press(KeyCode::Char('h'), KeyModifiers::NONE);
+        match resolver.press(
+            &keymap,
+            KeymapContext::Composer,
+            h,
+            now + Duration::from_millis(100),
+        ) {
+            ChordResolution::Passthrough(events) => assert_eq!(events, vec![g, h]),
+            _ => panic!("expected passthrough of both swallowed keys"),
+        }
+    }
+
+    #[test]
+    fn pending_chord_expires_after_timeout() {
+        let keymap = two_chord_keymap();
+        let mut resolver = ChordResolver::default();
+        let now = Instant::now();
+
+        let g = press(KeyCode::Char('g'), KeyModifiers::NONE);
+        assert!(matches!(
+            resolver.press(&keymap, KeymapContext::Composer, g, now),
+            ChordResolution::Pending(_)
+        ));
+
+        // Past the timeout the second `g` no longer completes the sequence; it
+        // starts a fresh pending chord instead.
+        let resolution = resolver.press(
+            &keymap,
+            KeymapContext::Composer,
+            g,
+            now + PENDING_CHORD_TIMEOUT + Duration::from_millis(1),
+        );
+        assert!(matches!(resolution, ChordResolution::Pending(_)));
+    }
+
+    #[test]
+    fn single_chord_defaults_still_resolve_directly() {
+        let keymap = TuiKeymap::default_bindings(KeybindingPreset::Default);
+        let mut resolver = ChordResolver::default();
+        let resolution = resolver.press(
+            &keymap,
+            KeymapContext::Composer,
+            press(KeyCode::Char('t'), KeyModifiers::CONTROL),
+            Instant::now(),
+        );
+        assert!(matches!(
+            resolution,
+            ChordResolution::Action(KeymapAction::OpenTranscript)
+        ));
+    }
+
+    #[test]
+    fn per_context_overrides_shadow_global_bindings() {
+        let mut keybindings = KeybindingsToml::default();
+        keybindings
+            .global
+            .insert("transcript".to_string(), "ctrl+o".to_string());
+        keybindings
+            .pager
+            .insert("clear-screen".to_string(), "ctrl+l".to_string());
+        let keymap = build_keymap(Some(&keybindings));
+
+        // The flat entry replaced the default `ctrl+t` binding.
+        let ctrl_t = KeyChord::parse("ctrl+t").expect("chord");
+        let ctrl_o = KeyChord::parse("ctrl+o").expect("chord");
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[ctrl_t]),
+            SequenceMatch::Unbound
+        ));
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[ctrl_o]),
+            SequenceMatch::Action(KeymapAction::OpenTranscript)
+        ));
+
+        // The pager table binds only in the pager; global bindings stay out
+        // of the pager context.
+        let ctrl_l = KeyChord::parse("ctrl+l").expect("chord");
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Pager, &[ctrl_l]),
+            SequenceMatch::Action(KeymapAction::ClearScreen)
+        ));
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Pager, &[ctrl_o]),
+            SequenceMatch::Unbound
+        ));
+        assert!(keymap.has_context_bindings(KeymapContext::Pager));
+        assert!(!keymap.has_context_bindings(KeymapContext::Composer));
+    }
+
+    #[test]
+    fn invalid_override_entries_are_skipped() {
+        let mut keybindings = KeybindingsToml::default();
+        keybindings
+            .global
+            .insert("transcript".to_string(), "ctrl+t ctrl+t ctrl+t".to_string());
+        keybindings
+            .global
+            .insert("no-such-action".to_string(), "ctrl+o".to_string());
+        let keymap = build_keymap(Some(&keybindings));
+
+        // The default binding survives a bad override.
+        let ctrl_t = KeyChord::parse("ctrl+t").expect("chord");
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[ctrl_t]),
+            SequenceMatch::Action(KeymapAction::OpenTranscript)
+        ));
+    }
+
+    #[test]
+    fn none_spec_disables_a_default_binding() {
+        let mut keybindings = KeybindingsToml::default();
+        keybindings
+            .global
+            .insert("transcript".to_string(), "none".to_string());
+        // `strict` stays happy: unbinding cannot conflict with anything.
+        keybindings.strict = true;
+        let (keymap, warnings) =
+            TuiKeymap::from_keybindings(Some(&keybindings)).expect("none is not a conflict");
+        assert_eq!(warnings, Vec::<String>::new());
+
+        let ctrl_t = KeyChord::parse("ctrl+t").expect("chord");
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[ctrl_t]),
+            SequenceMatch::Unbound
+        ));
+        assert!(
+            keymap
+                .sequences_for(KeymapContext::Composer, KeymapAction::OpenTranscript)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn none_spec_drops_context_entries_only() {
+        let mut keybindings = KeybindingsToml::default();
+        keybindings
+            .pager
+            .insert("transcript".to_string(), "none".to_string());
+        let keymap = build_keymap(Some(&keybindings));
+
+        // The pager sub-table stays empty, and the global default still binds
+        // in the composer.
+        assert!(
+            keymap
+                .sequences_for(KeymapContext::Pager, KeymapAction::OpenTranscript)
+                .is_empty()
+        );
+        let ctrl_t = KeyChord::parse("ctrl+t").expect("chord");
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[ctrl_t]),
+            SequenceMatch::Action(KeymapAction::OpenTranscript)
+        ));
+    }
+
+    #[test]
+    fn conflicting_override_unbinds_the_loser_with_a_warning() {
+        let mut keybindings = KeybindingsToml::default();
+        // `ctrl+l` is the default `clear-screen` binding.
+        keybindings
+            .global
+            .insert("transcript".to_string(), "ctrl+l".to_string());
+        let (keymap, warnings) =
+            TuiKeymap::from_keybindings(Some(&keybindings)).expect("lenient build succeeds");
+
+        let ctrl_l = KeyChord::parse("ctrl+l").expect("chord");
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[ctrl_l]),
+            SequenceMatch::Action(KeymapAction::OpenTranscript)
+        ));
+        // The default `clear-screen` binding was dropped rather than aliased.
+        assert!(
+            keymap
+                .sequences_for(KeymapContext::Composer, KeymapAction::ClearScreen)
+                .is_empty()
+        );
+        assert_eq!(
+            warnings,
+            vec!["`ctrl+l` now triggers `transcript`; its `clear-screen` binding was unbound"]
+        );
+    }
+
+    #[test]
+    fn strict_mode_turns_conflicts_into_errors() {
+        let mut keybindings = KeybindingsToml::default();
+        keybindings.strict = true;
+        keybindings
+            .global
+            .insert("transcript".to_string(), "ctrl+l".to_string());
+        assert_eq!(
+            TuiKeymap::from_keybindings(Some(&keybindings)).map(|(_, warnings)| warnings),
+            Err(KeymapError::Conflict {
+                sequence: "ctrl+l".to_string(),
+                kept: "transcript".to_string(),
+                dropped: "clear-screen".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn presets_swap_the_default_table_before_overrides() {
+        let mut keybindings = KeybindingsToml::default();
+        keybindings.preset = KeybindingPreset::Vim;
+        keybindings
+            .global
+            .insert("external-editor".to_string(), "space v".to_string());
+        let (keymap, warnings) =
+            TuiKeymap::from_keybindings(Some(&keybindings)).expect("lenient build succeeds");
+        assert_eq!(warnings, Vec::<String>::new());
+
+        // The vim table replaced the default one wholesale...
+        let ctrl_t = KeyChord::parse("ctrl+t").expect("chord");
+        let space = KeyChord::parse("space").expect("chord");
+        let t = KeyChord::parse("t").expect("chord");
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[ctrl_t]),
+            SequenceMatch::Unbound
+        ));
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[space, t]),
+            SequenceMatch::Action(KeymapAction::OpenTranscript)
+        ));
+
+        // ...and overrides still merge on top of the preset.
+        let v = KeyChord::parse("v").expect("chord");
+        let e = KeyChord::parse("e").expect("chord");
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[space, v]),
+            SequenceMatch::Action(KeymapAction::LaunchExternalEditor)
+        ));
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[space, e]),
+            SequenceMatch::Unbound
+        ));
+
+        let emacs = build_keymap(Some(&KeybindingsToml {
+            preset: KeybindingPreset::Emacs,
+            ..Default::default()
+        }));
+        let ctrl_x = KeyChord::parse("ctrl+x").expect("chord");
+        let ctrl_e = KeyChord::parse("ctrl+e").expect("chord");
+        assert!(matches!(
+            emacs.lookup(KeymapContext::Composer, &[ctrl_x, ctrl_e]),
+            SequenceMatch::Action(KeymapAction::LaunchExternalEditor)
+        ));
+        assert!(matches!(
+            emacs.lookup(KeymapContext::Composer, &[ctrl_x]),
+            SequenceMatch::Prefix { .. }
+        ));
+    }
+
+    #[test]
+    fn sequences_for_reports_defaults_and_context_overrides() {
+        let mut keybindings = KeybindingsToml::default();
+        keybindings
+            .pager
+            .insert("transcript".to_string(), "q".to_string());
+        let keymap = build_keymap(Some(&keybindings));
+
+        let display = |context, action| {
+            keymap
+                .sequences_for(context, action)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            display(KeymapContext::Composer, KeymapAction::OpenTranscript),
+            vec!["ctrl+t"]
+        );
+        assert_eq!(
+            display(KeymapContext::Pager, KeymapAction::OpenTranscript),
+            vec!["q"]
+        );
+        // Global bindings do not leak into the pager listing.
+        assert_eq!(
+            display(KeymapContext::Pager, KeymapAction::ClearScreen),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn terminal_profiles_merge_after_the_base_table() {
+        let mut keybindings = KeybindingsToml::default();
+        keybindings
+            .global
+            .insert("transcript".to_string(), "ctrl+o".to_string());
+        let profile = KeybindingProfileToml {
+            term: Some("tmux*".to_string()),
+            global: BTreeMap::from([("transcript".to_string(), "ctrl+y".to_string())]),
+            ..Default::default()
+        };
+        keybindings.profiles.insert("tmux".to_string(), profile);
+
+        let tmux = TerminalEnvironment {
+            term_program: None,
+            term: Some("tmux-256color".to_string()),
+        };
+        let (keymap, warnings) =
+            TuiKeymap::from_keybindings_with_environment(Some(&keybindings), &tmux)
+                .expect("build keymap");
+        assert_eq!(warnings, Vec::<String>::new());
+
+        // The profile's rebind replaced the base override, not aliased it.
+        let ctrl_o = KeyChord::parse("ctrl+o").expect("chord");
+        let ctrl_y = KeyChord::parse("ctrl+y").expect("chord");
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[ctrl_y]),
+            SequenceMatch::Action(KeymapAction::OpenTranscript)
+        ));
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[ctrl_o]),
+            SequenceMatch::Unbound
+        ));
+
+        // A terminal the matcher does not cover keeps the base table.
+        let xterm = TerminalEnvironment {
+            term_program: None,
+            term: Some("xterm-256color".to_string()),
+        };
+        let (keymap, _) = TuiKeymap::from_keybindings_with_environment(Some(&keybindings), &xterm)
+            .expect("build keymap");
+        assert!(matches!(
+            keymap.lookup(KeymapContext::Composer, &[ctrl_o]),
+            SequenceMatch::Action(KeymapAction::OpenTranscript)
+        ));
+    }
+
+    #[test]
+    fn profile_matchers_are_case_insensitive_with_trailing_wildcard() {
+        assert!(matcher_matches("tmux*", "tmux-256color"));
+        assert!(matcher_matches("vscode", "VSCODE"));
+        assert!(matcher_matches("iTerm*", "iterm.app"));
+        assert!(!matcher_matches("tmux", "tmux-256color"));
+    }
+
+    #[test]
+    fn profile_with_both_matchers_requires_both() {
+        let profile = KeybindingProfileToml {
+            term_program: Some("tmux".to_string()),
+            term: Some("screen*".to_string()),
+            ..Default::default()
+        };
+
+        let both = TerminalEnvironment {
+            term_program: Some("tmux".to_string()),
+            term: Some("screen-256color".to_string()),
+        };
+        assert!(both.matches(&profile));
+
+        let term_only = TerminalEnvironment {
+            term_program: Some("iTerm.app".to_string()),
+            term: Some("screen-256color".to_string()),
+        };
+        assert!(!term_only.matches(&profile));
+
+        // No matchers means the profile never applies anywhere.
+        assert!(!both.matches(&KeybindingProfileToml::default()));
+    }
+
+    #[test]
+    fn double_press_delivers_first_press_and_fires_on_repeat() {
+        let mut keymap = TuiKeymap::default_bindings(KeybindingPreset::Default);
+        keymap.bind_default("esc esc", KeymapAction::OpenTranscript);
+        let mut resolver = ChordResolver::default();
+        let now = Instant::now();
+
+        // The first esc is passed through (it keeps its normal behavior) but
+        // arms the repeat window.
+        let esc = press(KeyCode::Esc, KeyModifiers::NONE);
+        match resolver.press(&keymap, KeymapContext::Composer, esc, now) {
+            ChordResolution::Passthrough(events) => assert_eq!(events, vec![esc]),
+            _ => panic!("expected the first esc to pass through"),
+        }
+
+        let repeat = resolver.press(
+            &keymap,
+            KeymapContext::Composer,
+            esc,
+            now + Duration::from_millis(200),
+        );
+        assert!(matches!(
+            repeat,
+            ChordResolution::Action(KeymapAction::OpenTranscript)
+        ));
+    }
+
+    #[test]
+    fn double_press_window_is_configurable() {
+        let mut keybindings = KeybindingsToml::default();
+        keybindings.double_press_ms = Some(100);
+        keybindings
+            .global
+            .insert("transcript".to_string(), "esc esc".to_string());
+        let keymap = build_keymap(Some(&keybindings));
+        let mut resolver = ChordResolver::default();
+        let now = Instant::now();
+
+        let esc = press(KeyCode::Esc, KeyModifiers::NONE);
+        assert!(matches!(
+            resolver.press(&keymap, KeymapContext::Composer, esc, now),
+            ChordResolution::Passthrough(_)
+        ));
+
+        // Past the window the repeat does not fire; it is delivered and arms
+        // a fresh double-press window instead.
+        let late = resolver.press(
+            &keymap,
+            KeymapContext::Composer,
+            esc,
+            now + Duration::from_millis(150),
+        );
+        match late {
+            ChordResolution::Passthrough(events) => assert_eq!(events, vec![esc]),
+            _ => panic!("expected a late repeat to pass through"),
+        }
+    }
+
+    #[test]
+    fn chord_prefixing_a_leader_sequence_is_still_swallowed() {
+        let mut keymap = TuiKeymap::default_bindings(KeybindingPreset::Default);
+        // `g` starts both a double-press and a leader sequence; it must be
+        // swallowed because delivering it could not be undone if `g h` fires.
+        keymap.bind_default("g g", KeymapAction::OpenTranscript);
+        keymap.bind_default("g h", KeymapAction::ClearScreen);
+        let mut resolver = ChordResolver::default();
+
+        let g = press(KeyCode::Char('g'), KeyModifiers::NONE);
+        assert!(matches!(
+            resolver.press(&keymap, KeymapContext::Composer, g, Instant::now()),
+            ChordResolution::Pending(_)
+        ));
+    }
+
+    #[test]
+    fn mismatched_repeat_after_double_press_resolves_freshly() {
+        let mut keymap = TuiKeymap::default_bindings(KeybindingPreset::Default);
+        keymap.bind_default("esc esc", KeymapAction::OpenTranscript);
+        let mut resolver = ChordResolver::default();
+        let now = Instant::now();
+
+        let esc = press(KeyCode::Esc, KeyModifiers::NONE);
+        assert!(matches!(
+            resolver.press(&keymap, KeymapContext::Composer, esc, now),
+            ChordResolution::Passthrough(_)
+        ));
+
+        // A different key inside the window is not replayed alongside the
+        // already-delivered esc; it resolves on its own (here: a default
+        // single-chord binding).
+        let follow_up = resolver.press(
+            &keymap,
+            KeymapContext::Composer,
+            press(KeyCode::Char('t'), KeyModifiers::CONTROL),
+            now + Duration::from_millis(50),
+        );
+        assert!(matches!(
+            follow_up,
+            ChordResolution::Action(KeymapAction::OpenTranscript)
+        ));
+    }
+
+    #[test]
+    fn shift_on_character_keys_is_normalized() {
+        assert_eq!(
+            KeyChord::parse("shift+g").expect("chord"),
+            KeyChord::new(KeyCode::Char('G'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            KeyChord::from_key_event(&press(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            KeyChord::new(KeyCode::Char('G'), KeyModifiers::NONE)
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! `codex keybindings dump`: renders the fully merged keymap — built-in
+//! preset plus `[tui.keybindings]` overrides — in a form users can inspect,
+//! diff, or paste into another machine's config.
+
+use clap::ValueEnum;
+use codex_config::types::KeybindingPreset;
+use codex_config::types::KeybindingsToml;
+
+use crate::keymap::KeymapContext;
+use crate::keymap::TUI_KEYBINDING_ACTIONS;
+use crate::keymap::TuiKeymap;
+
+/// Output format for `codex keybindings dump --format`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum KeybindingsDumpFormat {
+    /// A `[tui.keybindings]` table that reproduces the merged keymap when
+    /// pasted into config.toml.
+    #[default]
+    Toml,
+    /// A machine-readable object, for diffing or tooling.
+    Json,
+    /// A human-readable table with action descriptions.
+    Markdown,
+}
+
+/// Renders the merged keymap for `keybindings` (the `[tui.keybindings]` value
+/// from config, if any). Conflict auto-resolution warnings are folded into
+/// the output rather than dropped, since a dump is exactly where a user goes
+/// to understand why a binding is not what they wrote.
+pub fn dump_keybindings(
+    keybindings: Option<&KeybindingsToml>,
+    format: KeybindingsDumpFormat,
+) -> Result<String, String> {
+    let (keymap, warnings) =
+        TuiKeymap::from_keybindings(keybindings).map_err(|err| err.to_string())?;
+    let preset = keybindings
+        .map(|keybindings| keybindings.preset)
+        .unwrap_or_default();
+    let double_press_ms = keybindings.and_then(|keybindings| keybindings.double_press_ms);
+    Ok(match format {
+        KeybindingsDumpFormat::Toml => render_toml(&keymap, preset, double_press_ms, &warnings),
+        KeybindingsDumpFormat::Json => render_json(&keymap, preset, double_press_ms, &warnings),
+        KeybindingsDumpFormat::Markdown => render_markdown(&keymap, preset, &warnings),
+    })
+}
+
+fn preset_name(preset: KeybindingPreset) -> &'static str {
+    match preset {
+        KeybindingPreset::Default => "default",
+        KeybindingPreset::Vim => "vim",
+        KeybindingPreset::Emacs => "emacs",
+    }
+}
+
+fn context_tables(keymap: &TuiKeymap) -> [(&'static str, Vec<(&'static str, Vec<String>)>); 2] {
+    [
+        (
+            KeymapContext::Composer.config_key(),
+            keymap.context_table(KeymapContext::Composer),
+        ),
+        (
+            KeymapContext::Pager.config_key(),
+            keymap.context_table(KeymapContext::Pager),
+        ),
+    ]
+}
+
+fn render_toml(
+    keymap: &TuiKeymap,
+    preset: KeybindingPreset,
+    double_press_ms: Option<u64>,
+    warnings: &[String],
+) -> String {
+    let mut out = String::new();
+    for warning in warnings {
+        out.push_str(&format!("# warning: {warning}\n"));
+    }
+    out.push_str("[tui.keybindings]\n");
+    out.push_str(&format!(
+        "preset = {}\n",
+        toml::Value::from(preset_name(preset))
+    ));
+    if let Some(double_press_ms) = double_press_ms {
+        out.push_str(&format!("double_press_ms = {double_press_ms}\n"));
+    }
+    for (name, specs) in keymap.global_table() {
+        // Config holds one spec per action per table, so a merged table never
+        // has more than one; be defensive anyway.
+        if let Some(spec) = specs.first() {
+            out.push_str(&format!("{name} = {}\n", toml::Value::from(spec.as_str())));
+        }
+    }
+    for (context, entries) in context_tables(keymap) {
+        if entries.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n[tui.keybindings.{context}]\n"));
+        for (name, specs) in entries {
+            if let Some(spec) = specs.first() {
+                out.push_str(&format!("{name} = {}\n", toml::Value::from(spec.as_str())));
+            }
+        }
+    }
+    out
+}
+
+fn render_json(
+    keymap: &TuiKeymap,
+    preset: KeybindingPreset,
+    double_press_ms: Option<u64>,
+    warnings: &[String],
+) -> String {
+    let table_to_json = |entries: Vec<(&'static str, Vec<String>)>| {
+        entries
+            .into_iter()
+            .map(|(name, specs)| (name.to_string(), serde_json::json!(specs)))
+            .collect::<serde_json::Map<_, _>>()
+    };
+    let mut object = serde_json::Map::new();
+    object.insert("preset".to_string(), serde_json::json!(preset_name(preset)));
+    if let Some(double_press_ms) = double_press_ms {
+        object.insert(
+            "double_press_ms".to_string(),
+            serde_json::json!(double_press_ms),
+        );
+    }
+    if !warnings.is_empty() {
+        object.insert("warnings".to_string(), serde_json::json!(warnings));
+    }
+    object.insert(
+        "global".to_string(),
+        serde_json::Value::Object(table_to_json(keymap.global_table())),
+    );
+    for (context, entries) in context_tables(keymap) {
+        if !entries.is_empty() {
+            object.insert(
+                context.to_string(),
+                serde_json::Value::Object(table_to_json(entries)),
+            );
+        }
+    }
+    let mut rendered = serde_json::to_string_pretty(&serde_json::Value::Object(object))
+        .unwrap_or_else(|_| "{}".to_string());
+    rendered.push('\n');
+    rendered
+}
+
+fn render_markdown(keymap: &TuiKeymap, preset: KeybindingPreset, warnings: &[String]) -> String {
+    let label_for = |name: &str| {
+        TUI_KEYBINDING_ACTIONS
+            .iter()
+            .find(|(action_name, _)| *action_name == name)
+            .map(|(_, action)| action.label())
+            .unwrap_or("")
+    };
+    let mut out = String::new();
+    out.push_str("# Codex keybindings\n\n");
+    out.push_str(&format!("Preset: `{}`\n", preset_name(preset)));
+    for warning in warnings {
+        out.push_str(&format!("\n> Warning: {warning}\n"));
+    }
+    let mut sections = vec![("global", keymap.global_table())];
+    sections.extend(context_tables(keymap));
+    for (section, entries) in sections {
+        if entries.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n## {section}\n\n"));
+        out.push_str("| Action | Keys | Description |\n");
+        out.push_str("| --- | --- | --- |\n");
+        for (name, specs) in entries {
+            let keys = specs
+                .iter()
+                .map(|spec| format!("`{spec}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            out.push_str(&format!("| {name} | {keys} | {} |\n", label_for(name)));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::collections::BTreeMap;
+
+    fn overrides(global: &[(&str, &str)]) -> KeybindingsToml {
+        KeybindingsToml {
+            preset: KeybindingPreset::Default,
+            strict: false,
+            double_press_ms: None,
+            profiles: BTreeMap::new(),
+            pager: BTreeMap::new(),
+            composer: BTreeMap::new(),
+            global: global
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn toml_dump_covers_defaults() {
+        let dump = dump_keybindings(None, KeybindingsDumpFormat::Toml).expect("dump");
+        assert!(dump.starts_with("[tui.keybindings]\n"));
+        assert!(dump.contains("preset = \"default\""));
+        assert!(dump.contains("transcript = \"ctrl+t\""));
+        assert!(dump.contains("clear-screen = \"ctrl+l\""));
+    }
+
+    #[test]
+    fn toml_dump_reflects_overrides() {
+        let keybindings = overrides(&[("transcript", "ctrl+y")]);
+        let dump = dump_keybindings(Some(&keybindings), KeybindingsDumpFormat::Toml).expect("dump");
+        assert!(dump.contains("transcript = \"ctrl+y\""));
+        assert!(!dump.contains("\"ctrl+t\""));
+    }
+
+    #[test]
+    fn json_dump_parses_and_lists_bindings() {
+        let dump = dump_keybindings(None, KeybindingsDumpFormat::Json).expect("dump");
+        let value: serde_json::Value = serde_json::from_str(&dump).expect("valid json");
+        assert_eq!(value["preset"], "default");
+        assert_eq!(value["global"]["transcript"][0], "ctrl+t");
+    }
+
+    #[test]
+    fn markdown_dump_includes_action_labels() {
+        let dump = dump_keybindings(None, KeybindingsDumpFormat::Markdown).expect("dump");
+        assert!(dump.contains("| transcript | `ctrl+t` | open the transcript overlay |"));
+    }
+
+    #[test]
+    fn conflict_warnings_surface_in_toml_dump() {
+        let keybindings = overrides(&[("transcript", "ctrl+l")]);
+        let dump = dump_keybindings(Some(&keybindings), KeybindingsDumpFormat::Toml).expect("dump");
+        assert!(dump.contains("# warning:"), "expected warning, got {dump}");
+    }
+}
@@ -104,45 +104,61 @@ mod audio_device {
     }
 }
 mod bottom_pane;
+mod cell_renderers;
 mod chatwidget;
+mod citations;
 mod cli;
 mod clipboard_copy;
 mod clipboard_paste;
 mod collaboration_modes;
 mod color;
+mod commit_suggestion;
 pub(crate) mod custom_terminal;
 pub use custom_terminal::Terminal;
 mod cwd_prompt;
 mod debug_config;
+mod debug_ui;
 mod diff_render;
 mod exec_cell;
 mod exec_command;
 mod external_agent_config_migration;
 mod external_agent_config_migration_startup;
 mod external_editor;
+mod file_references;
 mod file_search;
 mod frames;
 mod get_git_diff;
+mod glyphs;
+mod help_topics;
 mod history_cell;
+mod history_spill;
+mod i18n;
 pub(crate) mod insert_history;
 pub use insert_history::insert_history_lines;
 mod key_hint;
+mod keymap;
+mod keymap_dump;
+pub use keymap_dump::KeybindingsDumpFormat;
+pub use keymap_dump::dump_keybindings;
 mod line_truncation;
 pub(crate) mod live_wrap;
 pub use live_wrap::RowBuilder;
 mod local_chatgpt_auth;
+mod macros;
 mod markdown;
 mod markdown_render;
 mod markdown_stream;
 mod mention_codec;
 mod model_catalog;
 mod model_migration;
+mod mousemap;
 mod multi_agents;
 mod notifications;
 pub(crate) mod onboarding;
 mod oss_selection;
 mod pager_overlay;
 pub(crate) mod public_widgets;
+mod remote_session;
 mod render;
 mod resume_picker;
 mod selection_list;
@@ -150,24 +166,37 @@ mod session_log;
 mod shimmer;
 mod skills_helpers;
 mod slash_command;
+mod spinner;
+mod startup_profile;
 mod status;
 mod status_indicator_widget;
+mod stdin_capture;
 mod streaming;
 mod style;
 mod terminal_palette;
 mod terminal_title;
 mod text_formatting;
 mod theme_picker;
+mod theme_watcher;
+mod tips;
 mod tooltips;
 mod tui;
+mod tutorial;
 mod ui_consts;
+mod ui_snapshot;
+pub use tutorial::run_tutorial;
+pub use ui_snapshot::UiSnapshotScreen;
+pub use ui_snapshot::run_ui_snapshot;
 pub(crate) mod update_action;
+mod usage_stats;
 pub use update_action::UpdateAction;
+pub use update_action::resolve_update_action;
 mod update_prompt;
 mod updates;
 mod version;
 #[cfg(not(target_os = "linux"))]
 mod voice;
+mod windows_console;
 #[cfg(target_os = "linux")]
 #[allow(dead_code)]
 mod voice {
@@ -225,7 +254,8 @@ mod voice {
 
 mod wrapping;
 
-#[cfg(test)]
+// Also compiled outside tests: `codex ui snapshot` renders screens through
+// the VT100 backend.
 pub(crate) mod test_backend;
 #[cfg(test)]
 pub(crate) mod test_support;
@@ -662,6 +692,9 @@ pub async fn run_main(
     remote: Option<String>,
     remote_auth_token: Option<String>,
 ) -> std::io::Result<AppExitInfo> {
+    // Piped stdin becomes a `<stdin>` context block on the initial prompt;
+    // this also hands fd 0 back to the terminal before `tui::init()` runs.
+    stdin_capture::attach_piped_stdin(&mut cli.prompt);
     let remote_url = remote;
     if let (Some(websocket_url), Some(_)) = (remote_url.as_deref(), remote_auth_token.as_ref()) {
         validate_remote_auth_token_transport(websocket_url).map_err(std::io::Error::other)?;
@@ -687,6 +720,10 @@ pub async fn run_main(
             Some(SandboxMode::DangerFullAccess),
             Some(AskForApproval::Never),
         )
+    } else if cli.read_only {
+        // Hard read-only: nothing can escalate past the sandbox because
+        // approvals are disabled entirely.
+        (Some(SandboxMode::ReadOnly), Some(AskForApproval::Never))
     } else {
         (
             cli.sandbox_mode.map(Into::<SandboxMode>::into),
@@ -706,7 +743,7 @@ pub async fn run_main(
     let raw_overrides = cli.config_overrides.raw_overrides.clone();
     // `oss` model provider.
     let overrides_cli = codex_utils_cli::CliConfigOverrides { raw_overrides };
-    let cli_kv_overrides = match overrides_cli.parse_overrides() {
+    let mut cli_kv_overrides = match overrides_cli.parse_overrides() {
         // Parse `-c` overrides from the CLI.
         Ok(v) => v,
         #[allow(clippy::print_stderr)]
@@ -771,6 +808,48 @@ pub async fn run_main(
         tracing::warn!(error = %err, "failed to run personality migration");
     }
 
+    // Resolve a `--template` selection early so unknown names fail fast and
+    // the template's skills ride the session-flags config layer like `-c`
+    // overrides do.
+    #[allow(clippy::print_stderr)]
+    let template = match cli.template.as_deref() {
+        Some(name) => match config_toml.templates.get(name) {
+            Some(template) => Some(template.clone()),
+            None => {
+                let mut known: Vec<&str> =
+                    config_toml.templates.keys().map(String::as_str).collect();
+                known.sort_unstable();
+                if known.is_empty() {
+                    eprintln!(
+                        "Unknown template `{name}`: no [templates.<name>] entries are defined in config.toml."
+                    );
+                } else {
+                    eprintln!(
+                        "Unknown template `{name}`. Available templates: {}",
+                        known.join(", ")
+                    );
+                }
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+    if let Some(template) = &template
+        && !template.skills.is_empty()
+    {
+        let skills = template
+            .skills
+            .iter()
+            .map(|name| {
+                let mut entry = toml::value::Table::new();
+                entry.insert("name".to_string(), toml::Value::String(name.clone()));
+                entry.insert("enabled".to_string(), toml::Value::Boolean(true));
+                toml::Value::Table(entry)
+            })
+            .collect();
+        cli_kv_overrides.push(("skills.config".to_string(), toml::Value::Array(skills)));
+    }
+
     let chatgpt_base_url = config_toml
         .chatgpt_base_url
         .clone()
@@ -839,12 +918,32 @@ pub async fn run_main(
         ..Default::default()
     };
 
+    if cli.profile_startup {
+        startup_profile::enable();
+    }
+    let config_load_started = std::time::Instant::now();
     let config = load_config_or_exit(
         cli_kv_overrides.clone(),
         overrides.clone(),
         cloud_requirements.clone(),
     )
     .await;
+    startup_profile::record("config load", config_load_started.elapsed());
+
+    // Reconcile the local sessions tree with a configured sync directory so
+    // sessions recorded on other machines show up in the resume picker.
+    if let Some(sync_dir) = config.sessions_sync_dir.clone() {
+        let codex_home = config.codex_home.to_path_buf();
+        match tokio::task::spawn_blocking(move || {
+            codex_rollout::sync::sync_sessions(&codex_home, &sync_dir)
+        })
+        .await
+        {
+            Ok(Ok(report)) => tracing::debug!("session sync: {report:?}"),
+            Ok(Err(err)) => tracing::warn!("session sync failed: {err}"),
+            Err(err) => tracing::warn!("session sync task failed: {err}"),
+        }
+    }
 
     #[allow(clippy::print_stderr)]
     match check_execpolicy_for_warnings(&config.config_layer_stack).await {
@@ -1037,10 +1136,13 @@ async fn run_ratatui_app(
         tracing::error!("panic: {info}");
         prev_hook(info);
     }));
-    let mut terminal = tui::init()?;
+    let mut terminal = startup_profile::phase("terminal init", tui::init)?;
     terminal.clear()?;
+    crate::render::highlight::prewarm_in_background();
 
     let mut tui = Tui::new(terminal);
+    #[cfg(feature = "automation")]
+    tui::automation::start_if_configured(&tui);
     let mut terminal_restore_guard = TerminalRestoreGuard::new();
 
     #[cfg(not(debug_assertions))]
@@ -1368,15 +1470,64 @@ async fn run_ratatui_app(
         _ => config,
     };
 
+    // The assumed background must be in place before theme resolution and
+    // contrast adaptation consult the (possibly undetectable) terminal colors.
+    crate::terminal_palette::init_assumed_background(config.tui_assume_background.as_deref());
+
     // Configure syntax highlighting theme from the final config — onboarding
     // and resume/fork can both reload config with a different tui_theme, so
-    // this must happen after the last possible reload.
+    // this must happen after the last possible reload. The `[tui.syntax]`
+    // overrides install first so the initial theme resolution already
+    // includes them.
+    let theme_started = std::time::Instant::now();
+    if let Some(w) =
+        crate::render::highlight::init_syntax_style_overrides(&config.tui_syntax_styles)
+    {
+        config.startup_warnings.push(w);
+    }
     if let Some(w) = crate::render::highlight::set_theme_override(
         config.tui_theme.clone(),
         find_codex_home().ok().map(AbsolutePathBuf::into_path_buf),
     ) {
         config.startup_warnings.push(w);
     }
+    startup_profile::record("theme resolution", theme_started.elapsed());
+
+    // Same reasoning for the glyph mode: resolve it from the final config,
+    // before anything renders.
+    crate::glyphs::init_ascii_only(config.tui_ascii_only);
+    crate::i18n::init(config.tui_language.as_deref());
+    crate::terminal_palette::init_color_mode(config.tui_color_mode);
+    crate::color::init_min_contrast(config.tui_accessibility_min_contrast);
+    crate::diff_render::init_colorblind_palette(config.tui_accessibility_colorblind);
+    crate::markdown_render::init_code_block_overflow(config.tui_code_block_overflow);
+    crate::exec_cell::init_output_folding(config.tui_output_folding.clone());
+
+    // Background startup garbage collection, if the [storage] policy asks
+    // for it. Failures are logged, never surfaced.
+    if config.storage.gc_on_startup.unwrap_or(false)
+        && !crate::legacy_core::storage_gc::policy_is_noop(&config.storage)
+    {
+        let codex_home = config.codex_home.to_path_buf();
+        let policy = config.storage.clone();
+        tokio::task::spawn_blocking(move || {
+            match crate::legacy_core::storage_gc::collect_garbage(
+                &codex_home,
+                &policy,
+                /*dry_run*/ false,
+            ) {
+                Ok(report) if report.files_removed > 0 => {
+                    tracing::info!(
+                        "startup gc removed {} rollout(s), reclaimed {} bytes",
+                        report.files_removed,
+                        report.bytes_reclaimed
+                    );
+                }
+                Ok(_) => {}
+                Err(err) => tracing::warn!("startup gc failed: {err}"),
+            }
+        });
+    }
 
     set_default_client_residency_requirement(config.enforce_residency.value());
     let active_profile = config.active_profile.clone();
@@ -1385,15 +1536,47 @@ async fn run_ratatui_app(
         && trust_decision_was_made
         && WindowsSandboxLevel::from_config(&config) == WindowsSandboxLevel::Disabled;
 
+    // Apply the selected template after the last possible config reload so
+    // its instructions land in the final config.
+    let mut template_prompt: Option<String> = None;
+    if let Some(template) = template {
+        if let Some(instructions) = template.instructions.clone() {
+            config.user_instructions = Some(match config.user_instructions.take() {
+                Some(existing) => format!("{existing}\n\n{instructions}"),
+                None => instructions,
+            });
+        }
+        if !template.checklist.is_empty() {
+            let items: Vec<String> = template
+                .checklist
+                .iter()
+                .map(|item| format!("- [ ] {item}"))
+                .collect();
+            template_prompt = Some(format!(
+                "Work through this task checklist in order:\n{}",
+                items.join("\n")
+            ));
+        }
+        config.active_template = Some(template);
+    }
+
     let Cli {
         prompt,
         images,
         no_alt_screen,
         ..
     } = cli;
+    // A template checklist seeds the first turn; a user-provided prompt is
+    // appended after it.
+    let prompt = match (template_prompt, prompt) {
+        (Some(checklist), Some(prompt)) => Some(format!("{checklist}\n\n{prompt}")),
+        (Some(checklist), None) => Some(checklist),
+        (None, prompt) => prompt,
+    };
 
     let use_alt_screen = determine_alt_screen_mode(no_alt_screen, config.tui_alternate_screen);
     tui.set_alt_screen_enabled(use_alt_screen);
+    tui.set_mouse_capture_enabled(config.tui_mouse.as_ref().is_some_and(|mouse| mouse.enabled));
     let app_server = match app_server {
         Some(app_server) => app_server,
         None => match start_app_server(
@@ -2004,7 +2187,7 @@ mod tests {
     async fn windows_shows_trust_prompt_without_sandbox() -> std::io::Result<()> {
         let temp_dir = TempDir::new()?;
         let mut config = build_config(&temp_dir).await?;
-        config.active_project = ProjectConfig { trust_level: None };
+        config.active_project = ProjectConfig::default();
         config.set_windows_sandbox_enabled(/*value*/ false);
 
         let should_show = should_show_trust_screen(&config);
@@ -2127,7 +2310,7 @@ mod tests {
     async fn windows_shows_trust_prompt_with_sandbox() -> std::io::Result<()> {
         let temp_dir = TempDir::new()?;
         let mut config = build_config(&temp_dir).await?;
-        config.active_project = ProjectConfig { trust_level: None };
+        config.active_project = ProjectConfig::default();
         config.set_windows_sandbox_enabled(/*value*/ true);
 
         let should_show = should_show_trust_screen(&config);
@@ -2151,6 +2334,7 @@ mod tests {
         let mut config = build_config(&temp_dir).await?;
         config.active_project = ProjectConfig {
             trust_level: Some(TrustLevel::Untrusted),
+            ..Default::default()
         };
 
         let should_show = should_show_trust_screen(&config);
@@ -0,0 +1,150 @@
+//! Keyboard macro recording and replay for the TUI.
+//!
+//! A macro is an ordered list of key chords captured from the composer while
+//! recording is armed (`macro-record-start` / `macro-record-stop` keymap
+//! actions) and replayed later with `macro-play`. Macros persist across
+//! sessions as chord spec strings (`ctrl+t`, `enter`, …) in
+//! `macros.json` under `CODEX_HOME`, so they stay hand-editable: renaming a
+//! macro or tweaking a step is a text edit, and the same [`KeyChord`] parser
+//! that reads keybinding config reads the steps back.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crossterm::event::KeyEvent;
+
+use crate::keymap::KeyChord;
+
+const MACROS_FILE: &str = "macros.json";
+
+/// Captures key events between `macro-record-start` and `macro-record-stop`.
+/// Only unbound (passthrough) keys are fed in, so the stop chord itself and
+/// other shortcut activations never end up inside the recording.
+#[derive(Default)]
+pub(crate) struct MacroRecorder {
+    recording: Option<Vec<KeyEvent>>,
+}
+
+impl MacroRecorder {
+    pub(crate) fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Starts a fresh recording, discarding any recording already in flight.
+    pub(crate) fn start(&mut self) {
+        self.recording = Some(Vec::new());
+    }
+
+    pub(crate) fn record_key(&mut self, event: &KeyEvent) {
+        if let Some(events) = &mut self.recording {
+            events.push(*event);
+        }
+    }
+
+    /// Ends the recording and returns the captured steps as chord specs, or
+    /// `None` if no recording was in progress.
+    pub(crate) fn stop(&mut self) -> Option<Vec<String>> {
+        let events = self.recording.take()?;
+        Some(
+            events
+                .iter()
+                .map(|event| KeyChord::from_key_event(event).to_string())
+                .collect(),
+        )
+    }
+}
+
+fn macros_path(codex_home: &Path) -> PathBuf {
+    codex_home.join(MACROS_FILE)
+}
+
+/// Loads all saved macros, sorted by name. A missing or unreadable file is an
+/// empty set rather than an error so the picker always opens.
+pub(crate) fn load_macros(codex_home: &Path) -> BTreeMap<String, Vec<String>> {
+    let Ok(raw) = fs::read_to_string(macros_path(codex_home)) else {
+        return BTreeMap::new();
+    };
+    serde_json::from_str(&raw).unwrap_or_default()
+}
+
+/// Persists `steps` under `name`, overwriting any macro with the same name.
+pub(crate) fn save_macro(codex_home: &Path, name: &str, steps: Vec<String>) -> std::io::Result<()> {
+    let mut macros = load_macros(codex_home);
+    macros.insert(name.to_string(), steps);
+    let json = serde_json::to_string_pretty(&macros)?;
+    fs::create_dir_all(codex_home)?;
+    fs::write(macros_path(codex_home), format!("{json}\n"))
+}
+
+/// Picks the first unused `macro-N` name so freshly recorded macros never
+/// clobber each other; users rename them by editing `macros.json`.
+pub(crate) fn next_macro_name(macros: &BTreeMap<String, Vec<String>>) -> String {
+    (1..)
+        .map(|n| format!("macro-{n}"))
+        .find(|name| !macros.contains_key(name))
+        .unwrap_or_else(|| "macro".to_string())
+}
+
+/// Turns saved chord specs back into key events for replay. Steps that no
+/// longer parse (e.g. after a bad hand edit) are skipped so the rest of the
+/// macro still plays.
+pub(crate) fn decode_macro(steps: &[String]) -> Vec<KeyEvent> {
+    steps
+        .iter()
+        .filter_map(|step| KeyChord::parse(step).ok())
+        .map(|chord| KeyEvent::new(chord.code, chord.modifiers))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyCode;
+    use crossterm::event::KeyModifiers;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn recorder_round_trips_keys_through_chord_specs() {
+        let mut recorder = MacroRecorder::default();
+        assert!(recorder.stop().is_none());
+
+        recorder.start();
+        recorder.record_key(&KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE));
+        recorder.record_key(&KeyEvent::new(KeyCode::Char('i'), KeyModifiers::SHIFT));
+        recorder.record_key(&KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT));
+        let steps = recorder.stop().expect("recording in progress");
+        assert_eq!(steps, vec!["h", "I", "alt+enter"]);
+        assert!(!recorder.is_recording());
+
+        let replayed = decode_macro(&steps);
+        assert_eq!(
+            replayed,
+            vec![
+                KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE),
+                KeyEvent::new(KeyCode::Char('I'), KeyModifiers::NONE),
+                KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT),
+            ]
+        );
+    }
+
+    #[test]
+    fn macros_persist_and_names_never_collide() {
+        let home = tempfile::tempdir().expect("tempdir");
+        assert!(load_macros(home.path()).is_empty());
+
+        save_macro(home.path(), "macro-1", vec!["a".to_string()]).expect("save");
+        save_macro(
+            home.path(),
+            "macro-2",
+            vec!["b".to_string(), "enter".to_string()],
+        )
+        .expect("save");
+
+        let macros = load_macros(home.path());
+        assert_eq!(macros.len(), 2);
+        assert_eq!(macros["macro-2"], vec!["b", "enter"]);
+        assert_eq!(next_macro_name(&macros), "macro-3");
+    }
+}
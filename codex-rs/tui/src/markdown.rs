@@ -11,8 +11,11 @@ pub(crate) fn append_markdown(
     cwd: Option<&Path>,
     lines: &mut Vec<Line<'static>>,
 ) {
+    // Collapse raw `【F:path†Lx-Ly】` citations into superscript markers
+    // before rendering; `/citations` previews the sources behind them.
+    let (markdown_source, _citations) = crate::citations::strip_citations(markdown_source);
     let rendered = crate::markdown_render::render_markdown_text_with_width_and_cwd(
-        markdown_source,
+        &markdown_source,
         width,
         cwd,
     );
@@ -38,18 +41,12 @@ mod tests {
     }
 
     #[test]
-    fn citations_render_as_plain_text() {
+    fn citations_render_as_superscript_markers() {
         let src = "Before 【F:/x.rs†L1】\nAfter 【F:/x.rs†L3】\n";
         let mut out = Vec::new();
         append_markdown(src, /*width*/ None, /*cwd*/ None, &mut out);
         let rendered = lines_to_strings(&out);
-        assert_eq!(
-            rendered,
-            vec![
-                "Before 【F:/x.rs†L1】".to_string(),
-                "After 【F:/x.rs†L3】".to_string()
-            ]
-        );
+        assert_eq!(rendered, vec!["Before¹".to_string(), "After²".to_string()]);
     }
 
     #[test]
@@ -9,6 +9,8 @@ use crate::render::highlight::highlight_code_to_lines;
 use crate::render::line_utils::line_to_static;
 use crate::wrapping::RtOptions;
 use crate::wrapping::adaptive_wrap_line;
+use crate::wrapping::word_wrap_line;
+use codex_config::types::CodeBlockOverflow;
 use codex_utils_string::normalize_markdown_hash_location_suffix;
 use dirs::home_dir;
 use pulldown_cmark::CodeBlockKind;
@@ -27,8 +29,24 @@ use regex_lite::Regex;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::LazyLock;
+use std::sync::OnceLock;
 use url::Url;
 
+/// Configured `[tui] code_block_overflow` mode.
+static CODE_BLOCK_OVERFLOW: OnceLock<CodeBlockOverflow> = OnceLock::new();
+
+/// Applies `[tui] code_block_overflow`. Called once at startup before
+/// anything renders.
+pub(crate) fn init_code_block_overflow(mode: Option<CodeBlockOverflow>) {
+    if CODE_BLOCK_OVERFLOW.set(mode.unwrap_or_default()).is_err() {
+        tracing::debug!("init_code_block_overflow called more than once; value unchanged");
+    }
+}
+
+fn code_block_overflow() -> CodeBlockOverflow {
+    CODE_BLOCK_OVERFLOW.get().copied().unwrap_or_default()
+}
+
 struct MarkdownStyles {
     h1: Style,
     h2: Style,
@@ -627,7 +645,8 @@ where
     fn flush_current_line(&mut self) {
         if let Some(line) = self.current_line_content.take() {
             let style = self.current_line_style;
-            // NB we don't wrap code in code blocks, in order to preserve whitespace for copy/paste.
+            // NB by default we don't wrap code in code blocks, in order to preserve whitespace
+            // for copy/paste; `code_block_overflow = "indicator"` opts into marked wrapping.
             if !self.current_line_in_code_block
                 && let Some(width) = self.wrap_width
             {
@@ -638,6 +657,28 @@ where
                     let owned = line_to_static(&wrapped).style(style);
                     self.text.lines.push(owned);
                 }
+            } else if self.current_line_in_code_block
+                && code_block_overflow() == CodeBlockOverflow::Indicator
+                && let Some(width) = self.wrap_width
+            {
+                // `[tui] code_block_overflow = "indicator"`: wrap the line
+                // ourselves so every continuation row keeps the code's own
+                // indentation and carries a visible marker, instead of the
+                // terminal silently hard-wrapping at the right edge.
+                use ratatui::style::Stylize;
+                let mut line = line;
+                let code_indent = take_leading_whitespace(&mut line);
+                let mut initial = self.current_initial_indent.clone();
+                initial.push(Span::from(code_indent.clone()));
+                let mut subsequent = self.current_subsequent_indent.clone();
+                subsequent.push(Span::from(code_indent));
+                subsequent.push(Span::from(crate::glyphs::glyph("\u{21b3} ", "> ")).dim());
+                let opts = RtOptions::new(width)
+                    .initial_indent(initial.into())
+                    .subsequent_indent(subsequent.into());
+                for wrapped in word_wrap_line(&line, opts) {
+                    self.text.lines.push(line_to_static(&wrapped).style(style));
+                }
             } else {
                 let mut spans = self.current_initial_indent.clone();
                 let mut line = line;
@@ -726,6 +767,34 @@ where
     }
 }
 
+/// Splits the leading spaces/tabs off `line`, returning them so indicator
+/// wrapping can re-apply the code's indentation to every continuation row.
+fn take_leading_whitespace(line: &mut Line<'static>) -> String {
+    let mut indent = String::new();
+    loop {
+        let (ws, rest) = match line.spans.first() {
+            Some(first) => {
+                let content = first.content.as_ref();
+                let rest = content.trim_start_matches([' ', '\t']);
+                let ws_len = content.len() - rest.len();
+                if ws_len == 0 {
+                    break;
+                }
+                (content[..ws_len].to_string(), rest.to_string())
+            }
+            None => break,
+        };
+        indent.push_str(&ws);
+        if rest.is_empty() {
+            line.spans.remove(0);
+        } else {
+            line.spans[0].content = rest.into();
+            break;
+        }
+    }
+    indent
+}
+
 fn is_local_path_like_link(dest_url: &str) -> bool {
     dest_url.starts_with("file://")
         || dest_url.starts_with('/')
@@ -1133,4 +1202,20 @@ mod tests {
             "CRLF code block should not produce extra blank lines: {lines:?}"
         );
     }
+    #[test]
+    fn take_leading_whitespace_splits_indent_across_spans() {
+        let mut line = Line::from(vec![Span::from("    "), Span::from("  if x {")]);
+        assert_eq!(take_leading_whitespace(&mut line), "      ");
+        assert_eq!(
+            line.spans
+                .iter()
+                .map(|s| s.content.as_ref())
+                .collect::<String>(),
+            "if x {"
+        );
+
+        let mut unindented = Line::from("let y = 1;");
+        assert_eq!(take_leading_whitespace(&mut unindented), "");
+        assert_eq!(unindented.spans[0].content.as_ref(), "let y = 1;");
+    }
 }
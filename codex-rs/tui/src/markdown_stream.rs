@@ -67,7 +67,8 @@ impl MarkdownStreamCollector {
 
         let out_slice = &rendered[self.committed_line_count..complete_line_count];
 
-        let out = out_slice.to_vec();
+        let mut out = out_slice.to_vec();
+        crate::file_references::annotate_file_references(&mut out, self.cwd.as_path());
         self.committed_line_count = complete_line_count;
         out
     }
@@ -94,11 +95,12 @@ impl MarkdownStreamCollector {
         let mut rendered: Vec<Line<'static>> = Vec::new();
         markdown::append_markdown(&source, self.width, Some(self.cwd.as_path()), &mut rendered);
 
-        let out = if self.committed_line_count >= rendered.len() {
+        let mut out = if self.committed_line_count >= rendered.len() {
             Vec::new()
         } else {
             rendered[self.committed_line_count..].to_vec()
         };
+        crate::file_references::annotate_file_references(&mut out, self.cwd.as_path());
 
         // Reset collector state for next stream.
         self.clear();
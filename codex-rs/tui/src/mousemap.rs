@@ -0,0 +1,207 @@
+//! Mouse gesture mapping for TUI input, the pointer-shaped sibling of
+//! [`crate::keymap`].
+//!
+//! A [`MouseGesture`] is a recognized physical input (wheel tick, button
+//! press) and a [`MouseAction`] is what the app does with it. [`MouseMap`]
+//! holds the gesture table, seeded with defaults and remapped through
+//! `[tui.mouse]` entries. Mouse capture itself is opt-in: without a
+//! `[tui.mouse]` section the terminal keeps the mouse for text selection and
+//! native scrollback, and none of this runs.
+
+use std::collections::HashMap;
+
+use codex_config::types::MouseToml;
+use crossterm::event::MouseButton;
+use crossterm::event::MouseEvent;
+use crossterm::event::MouseEventKind;
+
+/// Physical mouse inputs the map recognizes. Motion and button-release events
+/// are dropped before they reach the map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum MouseGesture {
+    ScrollUp,
+    ScrollDown,
+    LeftClick,
+    RightClick,
+    MiddleClick,
+}
+
+/// What a gesture triggers. Scroll actions are synthesized as arrow keys so
+/// they drive whatever currently owns Up/Down (the pager, a selection list,
+/// the composer) — the same translation terminals apply for "alternate
+/// scroll" in the alt screen, extended to inline mode and remappable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MouseAction {
+    /// Scroll up one step (synthesized as the Up arrow).
+    ScrollUp,
+    /// Scroll down one step (synthesized as the Down arrow).
+    ScrollDown,
+    /// Return focus to the composer, closing a pager overlay if one is open.
+    FocusComposer,
+}
+
+/// Gesture names accepted as `[tui.mouse]` keys.
+const MOUSE_GESTURES: &[(&str, MouseGesture)] = &[
+    ("scroll-up", MouseGesture::ScrollUp),
+    ("scroll-down", MouseGesture::ScrollDown),
+    ("left-click", MouseGesture::LeftClick),
+    ("right-click", MouseGesture::RightClick),
+    ("middle-click", MouseGesture::MiddleClick),
+];
+
+/// Action names accepted as `[tui.mouse]` values. `none` unbinds a gesture
+/// and is handled separately.
+const MOUSE_ACTIONS: &[(&str, MouseAction)] = &[
+    ("scroll-up", MouseAction::ScrollUp),
+    ("scroll-down", MouseAction::ScrollDown),
+    ("focus-composer", MouseAction::FocusComposer),
+];
+
+impl MouseGesture {
+    fn from_name(name: &str) -> Option<Self> {
+        MOUSE_GESTURES
+            .iter()
+            .find(|(gesture_name, _)| *gesture_name == name)
+            .map(|(_, gesture)| *gesture)
+    }
+
+    /// The gesture a raw mouse event represents, if it is one we recognize.
+    fn from_event(event: &MouseEvent) -> Option<Self> {
+        match event.kind {
+            MouseEventKind::ScrollUp => Some(MouseGesture::ScrollUp),
+            MouseEventKind::ScrollDown => Some(MouseGesture::ScrollDown),
+            MouseEventKind::Down(MouseButton::Left) => Some(MouseGesture::LeftClick),
+            MouseEventKind::Down(MouseButton::Right) => Some(MouseGesture::RightClick),
+            MouseEventKind::Down(MouseButton::Middle) => Some(MouseGesture::MiddleClick),
+            _ => None,
+        }
+    }
+}
+
+impl MouseAction {
+    fn from_name(name: &str) -> Option<Self> {
+        MOUSE_ACTIONS
+            .iter()
+            .find(|(action_name, _)| *action_name == name)
+            .map(|(_, action)| *action)
+    }
+}
+
+/// Maps mouse gestures to actions. Built once from `[tui.mouse]` and
+/// consulted for every captured mouse event.
+pub(crate) struct MouseMap {
+    bindings: HashMap<MouseGesture, MouseAction>,
+}
+
+impl MouseMap {
+    /// The built-in gesture table: the wheel scrolls and a left click brings
+    /// the composer back. Right and middle clicks are unbound by default.
+    pub(crate) fn default_bindings() -> Self {
+        let mut bindings = HashMap::new();
+        bindings.insert(MouseGesture::ScrollUp, MouseAction::ScrollUp);
+        bindings.insert(MouseGesture::ScrollDown, MouseAction::ScrollDown);
+        bindings.insert(MouseGesture::LeftClick, MouseAction::FocusComposer);
+        Self { bindings }
+    }
+
+    /// Builds the effective map: the defaults with `[tui.mouse]` entries
+    /// remapping gestures on top. `none` unbinds a gesture. Invalid entries
+    /// are logged and skipped so one typo does not disable the rest of the
+    /// table, mirroring how `[tui.keybindings]` overrides are applied.
+    pub(crate) fn from_mouse_toml(mouse: Option<&MouseToml>) -> Self {
+        let mut map = Self::default_bindings();
+        let Some(mouse) = mouse else {
+            return map;
+        };
+        for (gesture_name, action_name) in &mouse.bindings {
+            let Some(gesture) = MouseGesture::from_name(gesture_name) else {
+                tracing::error!("unknown mouse gesture `{gesture_name}`");
+                continue;
+            };
+            if action_name == "none" {
+                map.bindings.remove(&gesture);
+                continue;
+            }
+            let Some(action) = MouseAction::from_name(action_name) else {
+                tracing::error!("unknown mouse action `{action_name}` for `{gesture_name}`");
+                continue;
+            };
+            map.bindings.insert(gesture, action);
+        }
+        map
+    }
+
+    /// The action bound to the gesture `event` represents, if any.
+    pub(crate) fn resolve(&self, event: &MouseEvent) -> Option<MouseAction> {
+        MouseGesture::from_event(event)
+            .and_then(|gesture| self.bindings.get(&gesture))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use pretty_assertions::assert_eq;
+
+    fn mouse_event(kind: MouseEventKind) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn default_map_scrolls_and_focuses() {
+        let map = MouseMap::default_bindings();
+        assert_eq!(
+            map.resolve(&mouse_event(MouseEventKind::ScrollUp)),
+            Some(MouseAction::ScrollUp)
+        );
+        assert_eq!(
+            map.resolve(&mouse_event(MouseEventKind::Down(MouseButton::Left))),
+            Some(MouseAction::FocusComposer)
+        );
+        assert_eq!(
+            map.resolve(&mouse_event(MouseEventKind::Down(MouseButton::Right))),
+            None
+        );
+        // Drags and releases never resolve, regardless of bindings.
+        assert_eq!(
+            map.resolve(&mouse_event(MouseEventKind::Up(MouseButton::Left))),
+            None
+        );
+    }
+
+    #[test]
+    fn overrides_remap_and_none_unbinds() {
+        let mut mouse = MouseToml::default();
+        mouse
+            .bindings
+            .insert("right-click".to_string(), "scroll-up".to_string());
+        mouse
+            .bindings
+            .insert("left-click".to_string(), "none".to_string());
+        mouse
+            .bindings
+            .insert("no-such-gesture".to_string(), "scroll-up".to_string());
+        let map = MouseMap::from_mouse_toml(Some(&mouse));
+
+        assert_eq!(
+            map.resolve(&mouse_event(MouseEventKind::Down(MouseButton::Right))),
+            Some(MouseAction::ScrollUp)
+        );
+        assert_eq!(
+            map.resolve(&mouse_event(MouseEventKind::Down(MouseButton::Left))),
+            None
+        );
+        // Unrelated defaults survive a bad entry.
+        assert_eq!(
+            map.resolve(&mouse_event(MouseEventKind::ScrollDown)),
+            Some(MouseAction::ScrollDown)
+        );
+    }
+}
@@ -1,5 +1,5 @@
 mod auth;
 pub(crate) mod onboarding_screen;
-mod trust_directory;
+pub(crate) mod trust_directory;
 pub(crate) use auth::mark_url_hyperlink;
 mod welcome;
@@ -95,6 +95,7 @@ impl OnboardingScreen {
             !matches!(login_status, LoginStatus::NotAuthenticated),
             tui.frame_requester(),
             config.animations,
+            config.tui_welcome_cycle_animation.as_deref(),
         )));
         if show_login_screen {
             let highlighted_mode = match forced_login_method {
@@ -481,6 +482,7 @@ pub(crate) async fn run_onboarding_app(
                         TuiEvent::Paste(text) => {
                             onboarding_screen.handle_paste(text);
                         }
+                        TuiEvent::Mouse(_) => {}
                         TuiEvent::Draw => {
                             if !did_full_clear_after_success
                                 && onboarding_screen.steps.iter().any(|step| {
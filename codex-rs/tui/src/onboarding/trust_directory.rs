@@ -46,34 +46,35 @@ impl WidgetRef for &TrustDirectoryWidget {
 
         column.push(Line::from(vec![
             "> ".into(),
-            "You are in ".bold(),
+            crate::i18n::tr("trust.you_are_in").bold(),
             self.cwd.to_string_lossy().to_string().into(),
         ]));
         column.push("");
 
         column.push(
-            Paragraph::new(
-                "Do you trust the contents of this directory? Working with untrusted \
-                 contents comes with higher risk of prompt injection. Trusting the \
-                 directory allows project-local config, hooks, and exec policies to load."
-                    .to_string(),
-            )
-            .wrap(Wrap { trim: true })
-            .inset(Insets::tlbr(
-                /*top*/ 0, /*left*/ 2, /*bottom*/ 0, /*right*/ 0,
-            )),
+            Paragraph::new(crate::i18n::tr("trust.prompt"))
+                .wrap(Wrap { trim: true })
+                .inset(Insets::tlbr(
+                    /*top*/ 0, /*left*/ 2, /*bottom*/ 0, /*right*/ 0,
+                )),
         );
         column.push("");
 
-        let options: Vec<(&str, TrustDirectorySelection)> = vec![
-            ("Yes, continue", TrustDirectorySelection::Trust),
-            ("No, quit", TrustDirectorySelection::Quit),
+        let options: Vec<(String, TrustDirectorySelection)> = vec![
+            (
+                crate::i18n::tr("trust.yes_continue"),
+                TrustDirectorySelection::Trust,
+            ),
+            (
+                crate::i18n::tr("trust.no_quit"),
+                TrustDirectorySelection::Quit,
+            ),
         ];
 
         for (idx, (text, selection)) in options.iter().enumerate() {
             column.push(selection_option_row(
                 idx,
-                text.to_string(),
+                text.clone(),
                 self.highlighted == *selection,
             ));
         }
@@ -5,6 +5,7 @@ use crossterm::event::KeyModifiers;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::Widget;
+use ratatui::style::Style;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
 use ratatui::widgets::Clear;
@@ -14,8 +15,12 @@ use ratatui::widgets::Wrap;
 use std::cell::Cell;
 
 use crate::ascii_animation::AsciiAnimation;
+use crate::frames::ALL_VARIANTS;
+use crate::frames::variant_idx_for_name;
 use crate::onboarding::onboarding_screen::KeyboardHandler;
 use crate::onboarding::onboarding_screen::StepStateProvider;
+use crate::terminal_palette::StdoutColorLevel;
+use crate::terminal_palette::rgb_color;
 use crate::tui::FrameRequester;
 
 use super::onboarding_screen::StepState;
@@ -51,10 +56,33 @@ impl WelcomeWidget {
         is_logged_in: bool,
         request_frame: FrameRequester,
         animations_enabled: bool,
+        welcome_animation: Option<&str>,
     ) -> Self {
+        let mut animations_enabled = animations_enabled;
+        let animation = match welcome_animation {
+            // `none` turns off just the welcome animation for slow terminals
+            // without disabling the other `tui.animations`.
+            Some("none") => {
+                animations_enabled = false;
+                AsciiAnimation::new(request_frame)
+            }
+            Some("random") => {
+                let mut animation = AsciiAnimation::new(request_frame);
+                animation.pick_random_variant();
+                animation
+            }
+            Some(name) => match variant_idx_for_name(name) {
+                Some(idx) => AsciiAnimation::with_variants(request_frame, ALL_VARIANTS, idx),
+                None => {
+                    tracing::warn!("unknown welcome_cycle_animation style `{name}`; using default");
+                    AsciiAnimation::new(request_frame)
+                }
+            },
+            None => AsciiAnimation::new(request_frame),
+        };
         Self {
             is_logged_in,
-            animation: AsciiAnimation::new(request_frame),
+            animation,
             animations_enabled,
             animations_suppressed: Cell::new(false),
             layout_area: Cell::new(None),
@@ -86,8 +114,9 @@ impl WidgetRef for &WelcomeWidget {
 
         let mut lines: Vec<Line> = Vec::new();
         if show_animation {
+            let style = animation_style();
             let frame = self.animation.current_frame();
-            lines.extend(frame.lines().map(Into::into));
+            lines.extend(frame.lines().map(|line| Line::styled(line, style)));
             lines.push("".into());
         }
         lines.push(Line::from(vec![
@@ -103,6 +132,23 @@ impl WidgetRef for &WelcomeWidget {
     }
 }
 
+/// Frame style derived from the active syntax theme so the welcome animation
+/// matches the configured palette. Falls back to the terminal's default
+/// foreground when the theme has no usable accent or the terminal cannot
+/// display rich colors.
+fn animation_style() -> Style {
+    let Some(rgb) = crate::render::highlight::theme_accent_fg() else {
+        return Style::default();
+    };
+    match crate::terminal_palette::stdout_color_level() {
+        StdoutColorLevel::TrueColor => Style::default().fg(rgb_color(rgb)),
+        StdoutColorLevel::Ansi256 => {
+            Style::default().fg(crate::diff_render::quantize_rgb_to_ansi256(rgb))
+        }
+        StdoutColorLevel::Ansi16 | StdoutColorLevel::Unknown => Style::default(),
+    }
+}
+
 impl StepStateProvider for WelcomeWidget {
     fn get_step_state(&self) -> StepState {
         match self.is_logged_in {
@@ -139,6 +185,7 @@ mod tests {
             /*is_logged_in*/ false,
             FrameRequester::test_dummy(),
             /*animations_enabled*/ true,
+            /*welcome_animation*/ None,
         );
         let area = Rect::new(0, 0, MIN_ANIMATION_WIDTH, MIN_ANIMATION_HEIGHT);
         let mut buf = Buffer::empty(area);
@@ -155,6 +202,7 @@ mod tests {
             /*is_logged_in*/ false,
             FrameRequester::test_dummy(),
             /*animations_enabled*/ true,
+            /*welcome_animation*/ None,
         );
         let area = Rect::new(0, 0, MIN_ANIMATION_WIDTH, MIN_ANIMATION_HEIGHT - 1);
         let mut buf = Buffer::empty(area);
@@ -164,6 +212,36 @@ mod tests {
         assert_eq!(welcome_row, Some(0));
     }
 
+    #[test]
+    fn welcome_animation_none_disables_animation() {
+        let widget = WelcomeWidget::new(
+            /*is_logged_in*/ false,
+            FrameRequester::test_dummy(),
+            /*animations_enabled*/ true,
+            /*welcome_animation*/ Some("none"),
+        );
+        let area = Rect::new(0, 0, MIN_ANIMATION_WIDTH, MIN_ANIMATION_HEIGHT);
+        let mut buf = Buffer::empty(area);
+        (&widget).render(area, &mut buf);
+
+        let welcome_row = row_containing(&buf, "Welcome");
+        assert_eq!(welcome_row, Some(0));
+    }
+
+    #[test]
+    fn welcome_animation_config_selects_named_variant() {
+        let widget = WelcomeWidget::new(
+            /*is_logged_in*/ false,
+            FrameRequester::test_dummy(),
+            /*animations_enabled*/ true,
+            /*welcome_animation*/ Some("dots"),
+        );
+        assert!(
+            crate::frames::FRAMES_DOTS.contains(&widget.animation.current_frame()),
+            "expected welcome_cycle_animation = \"dots\" to select the dots variant"
+        );
+    }
+
     #[test]
     fn ctrl_dot_changes_animation_variant() {
         let mut widget = WelcomeWidget {
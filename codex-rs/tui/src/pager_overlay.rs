@@ -18,8 +18,12 @@
 use std::io::Result;
 use std::sync::Arc;
 
+use crate::app_event::AppEvent;
+use crate::app_event_sender::AppEventSender;
 use crate::chatwidget::ActiveCellTranscriptKey;
+use crate::diff_render::UnifiedDiffHunk;
 use crate::history_cell::HistoryCell;
+use crate::history_cell::TranscriptCellKind;
 use crate::history_cell::UserHistoryCell;
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
@@ -59,6 +63,26 @@ impl Overlay {
         Self::Static(StaticOverlay::with_title(lines, title))
     }
 
+    pub(crate) fn new_static_searchable(lines: Vec<Line<'static>>, title: String) -> Self {
+        Self::Static(StaticOverlay::with_searchable_lines(lines, title))
+    }
+
+    /// Searchable overlay for unified diff text; `f`/`F` jump between the
+    /// per-file `diff --git` sections and `[`/`]` select hunks for staging.
+    pub(crate) fn new_static_diff(
+        lines: Vec<Line<'static>>,
+        title: String,
+        hunks: Vec<UnifiedDiffHunk>,
+        app_event_tx: AppEventSender,
+    ) -> Self {
+        Self::Static(StaticOverlay::with_diff_lines(
+            lines,
+            title,
+            hunks,
+            app_event_tx,
+        ))
+    }
+
     pub(crate) fn new_static_with_renderables(
         renderables: Vec<Box<dyn Renderable>>,
         title: String,
@@ -102,14 +126,31 @@ const KEY_ESC: KeyBinding = key_hint::plain(KeyCode::Esc);
 const KEY_ENTER: KeyBinding = key_hint::plain(KeyCode::Enter);
 const KEY_CTRL_T: KeyBinding = key_hint::ctrl(KeyCode::Char('t'));
 const KEY_CTRL_C: KeyBinding = key_hint::ctrl(KeyCode::Char('c'));
+const KEY_SLASH: KeyBinding = key_hint::plain(KeyCode::Char('/'));
+const KEY_N: KeyBinding = key_hint::plain(KeyCode::Char('n'));
+const KEY_F: KeyBinding = key_hint::plain(KeyCode::Char('f'));
+const KEY_SHIFT_F: KeyBinding = key_hint::shift(KeyCode::Char('F'));
+const KEY_S: KeyBinding = key_hint::plain(KeyCode::Char('s'));
+const KEY_U: KeyBinding = key_hint::plain(KeyCode::Char('u'));
+const KEY_OPEN_BRACKET: KeyBinding = key_hint::plain(KeyCode::Char('['));
+const KEY_CLOSE_BRACKET: KeyBinding = key_hint::plain(KeyCode::Char(']'));
+const KEY_SHIFT_N: KeyBinding = key_hint::shift(KeyCode::Char('N'));
+const KEY_M: KeyBinding = key_hint::plain(KeyCode::Char('m'));
+const KEY_APOSTROPHE: KeyBinding = key_hint::plain(KeyCode::Char('\''));
+const KEY_T: KeyBinding = key_hint::plain(KeyCode::Char('t'));
 
 // Common pager navigation hints rendered on the first line
 const PAGER_KEY_HINTS: &[(&[KeyBinding], &str)] = &[
     (&[KEY_UP, KEY_DOWN], "to scroll"),
     (&[KEY_PAGE_UP, KEY_PAGE_DOWN], "to page"),
     (&[KEY_HOME, KEY_END], "to jump"),
+    (&[KEY_LEFT, KEY_RIGHT], "to pan"),
+    (&[KEY_M, KEY_APOSTROPHE], "to mark/return"),
 ];
 
+/// Columns panned per left/right key press.
+const H_SCROLL_STEP: u16 = 8;
+
 // Render a single line of key hints from (key(s), description) pairs.
 fn render_key_hints(area: Rect, buf: &mut Buffer, pairs: &[(&[KeyBinding], &str)]) {
     let mut spans: Vec<Span<'static>> = vec![" ".into()];
@@ -135,11 +176,31 @@ fn render_key_hints(area: Rect, buf: &mut Buffer, pairs: &[(&[KeyBinding], &str)
 struct PagerView {
     renderables: Vec<Box<dyn Renderable>>,
     scroll_offset: usize,
+    /// Columns panned to the right. Content is laid out at the terminal width
+    /// plus this value, so long lines progressively unwrap as panning reveals
+    /// them instead of staying hard-wrapped.
+    h_scroll: u16,
     title: String,
     last_content_height: Option<usize>,
     last_rendered_height: Option<usize>,
     /// If set, on next render ensure this chunk is visible.
     pending_scroll_chunk: Option<usize>,
+    /// Numbered scroll positions set with `m<digit>`; `'<digit>` returns to
+    /// one. Marks live only as long as this pager session.
+    marks: [Option<usize>; 10],
+    /// Scroll position before the last mark jump, so `''` can return to it.
+    last_jump_origin: Option<usize>,
+    /// Set after `m` or `'` was pressed; the next key completes the action.
+    pending_mark: Option<PendingMark>,
+}
+
+/// Which half of a two-keystroke mark action is in flight.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PendingMark {
+    /// `m` was pressed; the next digit records the current position.
+    Set,
+    /// `'` was pressed; the next digit (or another `'`) jumps to a mark.
+    Jump,
 }
 
 impl PagerView {
@@ -147,10 +208,14 @@ impl PagerView {
         Self {
             renderables,
             scroll_offset,
+            h_scroll: 0,
             title,
             last_content_height: None,
             last_rendered_height: None,
             pending_scroll_chunk: None,
+            marks: [None; 10],
+            last_jump_origin: None,
+            pending_mark: None,
         }
     }
 
@@ -166,7 +231,7 @@ impl PagerView {
         self.render_header(area, buf);
         let content_area = self.content_area(area);
         self.update_last_content_height(content_area.height);
-        let content_height = self.content_height(content_area.width);
+        let content_height = self.content_height(content_area.width.saturating_add(self.h_scroll));
         self.last_rendered_height = Some(content_height);
         // If there is a pending request to scroll a specific chunk into view,
         // satisfy it now that wrapping is up to date for this width.
@@ -191,11 +256,12 @@ impl PagerView {
     }
 
     fn render_content(&self, area: Rect, buf: &mut Buffer) {
+        let render_width = area.width.saturating_add(self.h_scroll);
         let mut y = -(self.scroll_offset as isize);
         let mut drawn_bottom = area.y;
         for renderable in &self.renderables {
             let top = y;
-            let height = renderable.desired_height(area.width) as isize;
+            let height = renderable.desired_height(render_width) as isize;
             y += height;
             let bottom = y;
             if bottom < area.y as isize {
@@ -205,12 +271,17 @@ impl PagerView {
                 break;
             }
             if top < 0 {
-                let drawn = render_offset_content(area, buf, &**renderable, (-top) as u16);
+                let drawn =
+                    render_offset_content(area, buf, &**renderable, (-top) as u16, self.h_scroll);
                 drawn_bottom = drawn_bottom.max(area.y + drawn);
             } else {
                 let draw_height = (height as u16).min(area.height.saturating_sub(top as u16));
                 let draw_area = Rect::new(area.x, area.y + top as u16, area.width, draw_height);
-                renderable.render(draw_area, buf);
+                if self.h_scroll == 0 {
+                    renderable.render(draw_area, buf);
+                } else {
+                    render_offset_content(draw_area, buf, &**renderable, 0, self.h_scroll);
+                }
                 drawn_bottom = drawn_bottom.max(draw_area.y.saturating_add(draw_area.height));
             }
         }
@@ -236,9 +307,13 @@ impl PagerView {
         let sep_y = content_area.bottom();
         let sep_rect = Rect::new(full_area.x, sep_y, full_area.width, 1);
 
-        Span::from("─".repeat(sep_rect.width as usize))
-            .dim()
-            .render_ref(sep_rect, buf);
+        // Themes can recolor the scroll-position bar via the `scrollbar`
+        // palette slot; the themed color replaces the DIM fallback outright.
+        let bar_span = |content: String| match crate::style::scrollbar_fg() {
+            Some(fg) => Span::from(content).fg(fg),
+            None => Span::from(content).dim(),
+        };
+        bar_span("─".repeat(sep_rect.width as usize)).render_ref(sep_rect, buf);
         let percent = if total_len == 0 {
             100
         } else {
@@ -253,12 +328,55 @@ impl PagerView {
         let pct_text = format!(" {percent}% ");
         let pct_w = pct_text.chars().count() as u16;
         let pct_x = sep_rect.x + sep_rect.width - pct_w - 1;
-        Span::from(pct_text)
-            .dim()
-            .render_ref(Rect::new(pct_x, sep_rect.y, pct_w, 1), buf);
+        bar_span(pct_text).render_ref(Rect::new(pct_x, sep_rect.y, pct_w, 1), buf);
+
+        if let Some(pending) = self.pending_mark {
+            let label = match pending {
+                PendingMark::Set => " set mark: 0-9 ",
+                PendingMark::Jump => " go to mark: 0-9 or ' ",
+            };
+            let label_w = (label.chars().count() as u16).min(sep_rect.width);
+            Span::from(label)
+                .dim()
+                .render_ref(Rect::new(sep_rect.x + 1, sep_rect.y, label_w, 1), buf);
+        }
+    }
+
+    /// Completes a two-keystroke mark action started by `m` or `'`.
+    ///
+    /// Any key that is not a valid completion cancels the action. Jumping
+    /// records the position the jump started from so `''` can return to it.
+    fn complete_mark_action(&mut self, pending: PendingMark, key_event: KeyEvent) {
+        let KeyCode::Char(c) = key_event.code else {
+            return;
+        };
+        match pending {
+            PendingMark::Set => {
+                if let Some(slot) = c.to_digit(10) {
+                    self.marks[slot as usize] = Some(self.scroll_offset);
+                }
+            }
+            PendingMark::Jump => {
+                let target = if c == '\'' {
+                    self.last_jump_origin
+                } else {
+                    c.to_digit(10).and_then(|slot| self.marks[slot as usize])
+                };
+                if let Some(offset) = target {
+                    self.last_jump_origin = Some(self.scroll_offset);
+                    self.scroll_offset = offset;
+                }
+            }
+        }
     }
 
     fn handle_key_event(&mut self, tui: &mut tui::Tui, key_event: KeyEvent) -> Result<()> {
+        if let Some(pending) = self.pending_mark.take() {
+            self.complete_mark_action(pending, key_event);
+            tui.frame_requester()
+                .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+            return Ok(());
+        }
         match key_event {
             e if KEY_UP.is_press(e) || KEY_K.is_press(e) => {
                 self.scroll_offset = self.scroll_offset.saturating_sub(1);
@@ -287,12 +405,24 @@ impl PagerView {
                 let half_page = (area.height as usize).saturating_add(1) / 2;
                 self.scroll_offset = self.scroll_offset.saturating_sub(half_page);
             }
+            e if KEY_LEFT.is_press(e) => {
+                self.h_scroll = self.h_scroll.saturating_sub(H_SCROLL_STEP);
+            }
+            e if KEY_RIGHT.is_press(e) => {
+                self.h_scroll = self.h_scroll.saturating_add(H_SCROLL_STEP);
+            }
             e if KEY_HOME.is_press(e) => {
                 self.scroll_offset = 0;
             }
             e if KEY_END.is_press(e) => {
                 self.scroll_offset = usize::MAX;
             }
+            e if KEY_M.is_press(e) => {
+                self.pending_mark = Some(PendingMark::Set);
+            }
+            e if KEY_APOSTROPHE.is_press(e) => {
+                self.pending_mark = Some(PendingMark::Jump);
+            }
             _ => {
                 return Ok(());
             }
@@ -354,13 +484,14 @@ impl PagerView {
         if area.height == 0 || idx >= self.renderables.len() {
             return;
         }
+        let width = area.width.saturating_add(self.h_scroll);
         let first = self
             .renderables
             .iter()
             .take(idx)
-            .map(|r| r.desired_height(area.width) as usize)
+            .map(|r| r.desired_height(width) as usize)
             .sum();
-        let last = first + self.renderables[idx].desired_height(area.width) as usize;
+        let last = first + self.renderables[idx].desired_height(width) as usize;
         let current_top = self.scroll_offset;
         let current_bottom = current_top.saturating_add(area.height.saturating_sub(1) as usize);
         if first < current_top {
@@ -420,6 +551,52 @@ impl Renderable for CellRenderable {
     }
 }
 
+/// Transcript type filter, cycled with `t`. Filtering only hides cells from
+/// the overlay; the committed cell list is untouched.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum TranscriptFilter {
+    #[default]
+    All,
+    User,
+    Agent,
+    Commands,
+    Errors,
+}
+
+impl TranscriptFilter {
+    fn next(self) -> Self {
+        match self {
+            Self::All => Self::User,
+            Self::User => Self::Agent,
+            Self::Agent => Self::Commands,
+            Self::Commands => Self::Errors,
+            Self::Errors => Self::All,
+        }
+    }
+
+    fn admits(self, kind: TranscriptCellKind) -> bool {
+        match self {
+            Self::All => true,
+            Self::User => kind == TranscriptCellKind::User,
+            Self::Agent => kind == TranscriptCellKind::Agent,
+            Self::Commands => kind == TranscriptCellKind::Command,
+            Self::Errors => kind == TranscriptCellKind::Error,
+        }
+    }
+
+    /// Overlay title reflecting the active filter.
+    fn title(self) -> String {
+        let suffix = match self {
+            Self::All => return "T R A N S C R I P T".to_string(),
+            Self::User => "user messages",
+            Self::Agent => "agent answers",
+            Self::Commands => "commands",
+            Self::Errors => "errors",
+        };
+        format!("T R A N S C R I P T  ·  {suffix}")
+    }
+}
+
 pub(crate) struct TranscriptOverlay {
     /// Pager UI state and the renderables currently displayed.
     ///
@@ -428,6 +605,9 @@ pub(crate) struct TranscriptOverlay {
     view: PagerView,
     /// Committed transcript cells (does not include the live tail).
     cells: Vec<Arc<dyn HistoryCell>>,
+    /// Active type filter; when not `All`, `view.renderables` only contains
+    /// the committed cells whose kind the filter admits.
+    filter: TranscriptFilter,
     highlight_cell: Option<usize>,
     /// Cache key for the render-only live tail appended after committed cells.
     live_tail_key: Option<LiveTailKey>,
@@ -455,13 +635,15 @@ impl TranscriptOverlay {
     /// This overlay does not own the "active cell"; callers may optionally append a live tail via
     /// `sync_live_tail` during draws to reflect in-flight activity.
     pub(crate) fn new(transcript_cells: Vec<Arc<dyn HistoryCell>>) -> Self {
+        let filter = TranscriptFilter::default();
         Self {
             view: PagerView::new(
-                Self::render_cells(&transcript_cells, /*highlight_cell*/ None),
-                "T R A N S C R I P T".to_string(),
+                Self::render_cells(&transcript_cells, /*highlight_cell*/ None, filter),
+                filter.title(),
                 usize::MAX,
             ),
             cells: transcript_cells,
+            filter,
             highlight_cell: None,
             live_tail_key: None,
             is_done: false,
@@ -471,12 +653,14 @@ impl TranscriptOverlay {
     fn render_cells(
         cells: &[Arc<dyn HistoryCell>],
         highlight_cell: Option<usize>,
+        filter: TranscriptFilter,
     ) -> Vec<Box<dyn Renderable>> {
+        let mut rendered = 0usize;
         cells
             .iter()
             .enumerate()
-            .flat_map(|(i, c)| {
-                let mut v: Vec<Box<dyn Renderable>> = Vec::new();
+            .filter(|(_, c)| filter.admits(c.transcript_kind()))
+            .map(|(i, c)| {
                 let mut cell_renderable = if c.as_any().is::<UserHistoryCell>() {
                     Box::new(CachedRenderable::new(CellRenderable {
                         cell: c.clone(),
@@ -492,7 +676,7 @@ impl TranscriptOverlay {
                         style: Style::default(),
                     })) as Box<dyn Renderable>
                 };
-                if !c.is_stream_continuation() && i > 0 {
+                if !c.is_stream_continuation() && rendered > 0 {
                     cell_renderable = Box::new(InsetRenderable::new(
                         cell_renderable,
                         Insets::tlbr(
@@ -500,12 +684,54 @@ impl TranscriptOverlay {
                         ),
                     ));
                 }
-                v.push(cell_renderable);
-                v
+                rendered += 1;
+                cell_renderable
             })
             .collect()
     }
 
+    /// Number of committed cells admitted by the active filter; everything in
+    /// `view.renderables` past this count is the live tail.
+    fn visible_cell_count(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|c| self.filter.admits(c.transcript_kind()))
+            .count()
+    }
+
+    /// Maps a committed cell index to its renderable chunk index under the
+    /// active filter. `None` when the cell is filtered out.
+    fn renderable_index_of(&self, cell_idx: usize) -> Option<usize> {
+        if !self
+            .cells
+            .get(cell_idx)
+            .is_some_and(|c| self.filter.admits(c.transcript_kind()))
+        {
+            return None;
+        }
+        Some(
+            self.cells
+                .iter()
+                .take(cell_idx)
+                .filter(|c| self.filter.admits(c.transcript_kind()))
+                .count(),
+        )
+    }
+
+    /// Advance the type filter to the next mode and rebuild the view.
+    ///
+    /// The live tail is detached before the filter flips: `take_live_tail_renderable` compares
+    /// against the visible cell count, which is about to change.
+    fn cycle_filter(&mut self) {
+        let tail_renderable = self.take_live_tail_renderable();
+        self.filter = self.filter.next();
+        self.view.title = self.filter.title();
+        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell, self.filter);
+        if let Some(tail) = tail_renderable {
+            self.view.renderables.push(tail);
+        }
+    }
+
     /// Insert a committed history cell while keeping any cached live tail.
     ///
     /// The live tail is temporarily removed, the committed cells are rebuilt,
@@ -518,10 +744,10 @@ impl TranscriptOverlay {
     /// insertion to preserve the "follow along" behavior.
     pub(crate) fn insert_cell(&mut self, cell: Arc<dyn HistoryCell>) {
         let follow_bottom = self.view.is_scrolled_to_bottom();
-        let had_prior_cells = !self.cells.is_empty();
+        let had_prior_cells = self.visible_cell_count() > 0;
         let tail_renderable = self.take_live_tail_renderable();
         self.cells.push(cell);
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell, self.filter);
         if let Some(tail) = tail_renderable {
             let tail = if !had_prior_cells
                 && self
@@ -617,8 +843,10 @@ impl TranscriptOverlay {
     pub(crate) fn set_highlight_cell(&mut self, cell: Option<usize>) {
         self.highlight_cell = cell;
         self.rebuild_renderables();
-        if let Some(idx) = self.highlight_cell {
-            self.view.scroll_chunk_into_view(idx);
+        if let Some(idx) = self.highlight_cell
+            && let Some(chunk) = self.renderable_index_of(idx)
+        {
+            self.view.scroll_chunk_into_view(chunk);
         }
     }
 
@@ -632,7 +860,7 @@ impl TranscriptOverlay {
 
     fn rebuild_renderables(&mut self) {
         let tail_renderable = self.take_live_tail_renderable();
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell, self.filter);
         if let Some(tail) = tail_renderable {
             self.view.renderables.push(tail);
         }
@@ -644,7 +872,8 @@ impl TranscriptOverlay {
     /// cell renderables, so this relies on the live tail always being the final entry in
     /// `view.renderables` when present.
     fn take_live_tail_renderable(&mut self) -> Option<Box<dyn Renderable>> {
-        (self.view.renderables.len() > self.cells.len()).then(|| self.view.renderables.pop())?
+        (self.view.renderables.len() > self.visible_cell_count())
+            .then(|| self.view.renderables.pop())?
     }
 
     fn live_tail_renderable(
@@ -671,6 +900,7 @@ impl TranscriptOverlay {
         render_key_hints(line1, buf, PAGER_KEY_HINTS);
 
         let mut pairs: Vec<(&[KeyBinding], &str)> = vec![(&[KEY_Q], "to quit")];
+        pairs.push((&[KEY_T], "to filter by type"));
         if self.highlight_cell.is_some() {
             pairs.push((&[KEY_ESC, KEY_LEFT], "to edit prev"));
             pairs.push((&[KEY_RIGHT], "to edit next"));
@@ -698,6 +928,14 @@ impl TranscriptOverlay {
                     self.is_done = true;
                     Ok(())
                 }
+                // Mark keys pending in the pager take precedence so `m`/`'`
+                // followed by `t` cancels instead of changing the filter.
+                e if self.view.pending_mark.is_none() && KEY_T.is_press(e) => {
+                    self.cycle_filter();
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
                 other => self.view.handle_key_event(tui, other),
             },
             TuiEvent::Draw => {
@@ -721,6 +959,29 @@ impl TranscriptOverlay {
 
 pub(crate) struct StaticOverlay {
     view: PagerView,
+    /// Lowercased plain-text copy of each renderable chunk, present only for
+    /// overlays built from per-line renderables via `with_searchable_lines`.
+    search_texts: Option<Vec<String>>,
+    /// Query being typed after `/`, shown in the footer until committed.
+    search_input: Option<String>,
+    /// Last committed search query, used by `n`/`N`.
+    search_query: Option<String>,
+    /// Chunk index of the current match.
+    search_match: Option<usize>,
+    /// Chunk indices of `diff --git` file headers, present only for overlays
+    /// built via `with_diff_lines`.
+    file_chunks: Vec<usize>,
+    /// Index into `file_chunks` of the file last jumped to with `f`/`F`.
+    current_file: Option<usize>,
+    /// Stageable hunks, present only for overlays built via `with_diff_lines`
+    /// from a real git diff.
+    hunks: Vec<UnifiedDiffHunk>,
+    /// Index into `hunks` of the hunk selected with `[`/`]`.
+    current_hunk: Option<usize>,
+    /// Original lines, kept so hunk selection can restyle its `@@` chunk.
+    diff_lines: Vec<Line<'static>>,
+    /// Sender used to hand staging requests back to the app layer.
+    app_event_tx: Option<AppEventSender>,
     is_done: bool,
 }
 
@@ -730,9 +991,76 @@ impl StaticOverlay {
         Self::with_renderables(vec![Box::new(CachedRenderable::new(paragraph))], title)
     }
 
+    /// Builds an overlay with one renderable per line so `/` search can jump
+    /// to the matching line with `PagerView::scroll_chunk_into_view`.
+    pub(crate) fn with_searchable_lines(lines: Vec<Line<'static>>, title: String) -> Self {
+        let search_texts = lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .collect();
+        let renderables = lines
+            .into_iter()
+            .map(|line| {
+                Box::new(CachedRenderable::new(
+                    Paragraph::new(line).wrap(Wrap { trim: false }),
+                )) as Box<dyn Renderable>
+            })
+            .collect();
+        Self {
+            search_texts: Some(search_texts),
+            ..Self::with_renderables(renderables, title)
+        }
+    }
+
+    /// Builds a searchable overlay that also records the chunk index of each
+    /// `diff --git` file header, so `f`/`F` can jump between files, and the
+    /// stageable hunks, so `[`/`]` plus `s`/`u` can stage or unstage them.
+    pub(crate) fn with_diff_lines(
+        lines: Vec<Line<'static>>,
+        title: String,
+        hunks: Vec<UnifiedDiffHunk>,
+        app_event_tx: AppEventSender,
+    ) -> Self {
+        let file_chunks = lines
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, line)| {
+                let text: String = line
+                    .spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect();
+                text.starts_with("diff --git ").then_some(idx)
+            })
+            .collect();
+        Self {
+            file_chunks,
+            hunks,
+            diff_lines: lines.clone(),
+            app_event_tx: Some(app_event_tx),
+            ..Self::with_searchable_lines(lines, title)
+        }
+    }
+
     pub(crate) fn with_renderables(renderables: Vec<Box<dyn Renderable>>, title: String) -> Self {
         Self {
             view: PagerView::new(renderables, title, /*scroll_offset*/ 0),
+            search_texts: None,
+            search_input: None,
+            search_query: None,
+            search_match: None,
+            file_chunks: Vec::new(),
+            current_file: None,
+            hunks: Vec::new(),
+            current_hunk: None,
+            diff_lines: Vec::new(),
+            app_event_tx: None,
             is_done: false,
         }
     }
@@ -741,10 +1069,153 @@ impl StaticOverlay {
         let line1 = Rect::new(area.x, area.y, area.width, 1);
         let line2 = Rect::new(area.x, area.y.saturating_add(1), area.width, 1);
         render_key_hints(line1, buf, PAGER_KEY_HINTS);
-        let pairs: Vec<(&[KeyBinding], &str)> = vec![(&[KEY_Q], "to quit")];
+        if let Some(input) = &self.search_input {
+            Paragraph::new(Line::from(format!(" /{input}\u{2588}"))).render_ref(line2, buf);
+            return;
+        }
+        let mut pairs: Vec<(&[KeyBinding], &str)> = Vec::new();
+        if self.search_texts.is_some() {
+            pairs.push((&[KEY_SLASH], "to search"));
+            if self.search_query.is_some() {
+                pairs.push((&[KEY_N, KEY_SHIFT_N], "next/prev match"));
+            }
+        }
+        if !self.file_chunks.is_empty() {
+            pairs.push((&[KEY_F, KEY_SHIFT_F], "next/prev file"));
+        }
+        if !self.hunks.is_empty() {
+            pairs.push((&[KEY_OPEN_BRACKET, KEY_CLOSE_BRACKET], "to select hunk"));
+        }
+        if self.current_hunk.is_some() {
+            pairs.push((&[KEY_S, KEY_U], "stage/unstage"));
+        }
+        pairs.push((&[KEY_Q], "to quit"));
         render_key_hints(line2, buf, &pairs);
     }
 
+    /// Finds the next chunk matching `query`, scanning forward (or backward)
+    /// from the current match with wrap-around.
+    fn find_match(&self, query: &str, forward: bool) -> Option<usize> {
+        let texts = self.search_texts.as_ref()?;
+        if texts.is_empty() {
+            return None;
+        }
+        let len = texts.len();
+        let start = match (self.search_match, forward) {
+            (Some(idx), true) => (idx + 1) % len,
+            (Some(idx), false) => (idx + len - 1) % len,
+            (None, _) => 0,
+        };
+        let query = query.to_lowercase();
+        (0..len)
+            .map(|step| {
+                if forward {
+                    (start + step) % len
+                } else {
+                    (start + len - step) % len
+                }
+            })
+            .find(|&idx| texts[idx].contains(&query))
+    }
+
+    /// Cycles hunk selection with wrap-around, restyling the `@@` chunk of
+    /// the selected hunk and scrolling it into view.
+    fn select_hunk(&mut self, forward: bool) {
+        let len = self.hunks.len();
+        if len == 0 {
+            return;
+        }
+        let next = match (self.current_hunk, forward) {
+            (Some(idx), true) => (idx + 1) % len,
+            (Some(idx), false) => (idx + len - 1) % len,
+            (None, true) => 0,
+            (None, false) => len - 1,
+        };
+        self.set_hunk_selection(Some(next));
+        self.view
+            .scroll_chunk_into_view(self.hunks[next].line_index);
+    }
+
+    fn set_hunk_selection(&mut self, next: Option<usize>) {
+        if let Some(prev) = self.current_hunk.take() {
+            let chunk = self.hunks[prev].line_index;
+            self.view.renderables[chunk] =
+                diff_line_renderable(self.diff_lines[chunk].clone(), /*selected*/ false);
+        }
+        if let Some(idx) = next {
+            let chunk = self.hunks[idx].line_index;
+            self.view.renderables[chunk] =
+                diff_line_renderable(self.diff_lines[chunk].clone(), /*selected*/ true);
+        }
+        self.current_hunk = next;
+    }
+
+    /// Hands the selected hunk to the app layer for `git apply --cached`.
+    fn stage_selected_hunk(&self, reverse: bool) {
+        let (Some(idx), Some(tx)) = (self.current_hunk, self.app_event_tx.as_ref()) else {
+            return;
+        };
+        tx.send(AppEvent::StageDiffHunk {
+            patch: self.hunks[idx].patch.clone(),
+            reverse,
+        });
+    }
+
+    /// Cycles to the next (or previous) file section with wrap-around.
+    fn jump_to_file(&mut self, forward: bool) {
+        let len = self.file_chunks.len();
+        if len == 0 {
+            return;
+        }
+        let next = match (self.current_file, forward) {
+            (Some(idx), true) => (idx + 1) % len,
+            (Some(idx), false) => (idx + len - 1) % len,
+            (None, true) => 0,
+            (None, false) => len - 1,
+        };
+        self.current_file = Some(next);
+        self.view.scroll_chunk_into_view(self.file_chunks[next]);
+    }
+
+    fn jump_to_match(&mut self, forward: bool) {
+        let Some(query) = self.search_query.clone() else {
+            return;
+        };
+        if let Some(idx) = self.find_match(&query, forward) {
+            self.search_match = Some(idx);
+            self.view.scroll_chunk_into_view(idx);
+        }
+    }
+
+    /// Handles a key while the `/` search prompt is active. Returns `true`
+    /// when the key was consumed.
+    fn handle_search_input_key(&mut self, key_event: KeyEvent) -> bool {
+        let Some(input) = self.search_input.as_mut() else {
+            return false;
+        };
+        match key_event.code {
+            KeyCode::Esc => {
+                self.search_input = None;
+            }
+            KeyCode::Enter => {
+                let input = self.search_input.take().unwrap_or_default();
+                if !input.is_empty() {
+                    self.search_query = Some(input);
+                    self.search_match = None;
+                    self.jump_to_match(/*forward*/ true);
+                }
+            }
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => {
+                input.push(c);
+            }
+            _ => return false,
+        }
+        true
+    }
+
     pub(crate) fn render(&mut self, area: Rect, buf: &mut Buffer) {
         let top_h = area.height.saturating_sub(3);
         let top = Rect::new(area.x, area.y, area.width, top_h);
@@ -758,6 +1229,67 @@ impl StaticOverlay {
     pub(crate) fn handle_event(&mut self, tui: &mut tui::Tui, event: TuiEvent) -> Result<()> {
         match event {
             TuiEvent::Key(key_event) => match key_event {
+                e if self.search_input.is_some() && self.handle_search_input_key(e) => {
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if self.search_texts.is_some() && KEY_SLASH.is_press(e) => {
+                    self.search_input = Some(String::new());
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if self.search_query.is_some() && KEY_N.is_press(e) => {
+                    self.jump_to_match(/*forward*/ true);
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if self.search_query.is_some() && KEY_SHIFT_N.is_press(e) => {
+                    self.jump_to_match(/*forward*/ false);
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if !self.file_chunks.is_empty() && KEY_F.is_press(e) => {
+                    self.jump_to_file(/*forward*/ true);
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if !self.file_chunks.is_empty() && KEY_SHIFT_F.is_press(e) => {
+                    self.jump_to_file(/*forward*/ false);
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if !self.hunks.is_empty() && KEY_CLOSE_BRACKET.is_press(e) => {
+                    self.select_hunk(/*forward*/ true);
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if !self.hunks.is_empty() && KEY_OPEN_BRACKET.is_press(e) => {
+                    self.select_hunk(/*forward*/ false);
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if self.current_hunk.is_some() && KEY_ESC.is_press(e) => {
+                    self.set_hunk_selection(None);
+                    tui.frame_requester()
+                        .schedule_frame_in(crate::tui::TARGET_FRAME_INTERVAL);
+                    Ok(())
+                }
+                e if self.current_hunk.is_some() && KEY_S.is_press(e) => {
+                    self.stage_selected_hunk(/*reverse*/ false);
+                    Ok(())
+                }
+                e if self.current_hunk.is_some() && KEY_U.is_press(e) => {
+                    self.stage_selected_hunk(/*reverse*/ true);
+                    Ok(())
+                }
                 e if KEY_Q.is_press(e) || KEY_CTRL_C.is_press(e) => {
                     self.is_done = true;
                     Ok(())
@@ -778,17 +1310,33 @@ impl StaticOverlay {
     }
 }
 
+/// Renders `renderable` into a scratch buffer laid out `h_scroll` columns
+/// wider and `scroll_offset` rows taller than `area`, then blits the visible
+/// window. This is how partially-visible chunks and horizontal panning are
+/// drawn without the renderables knowing about either offset.
+/// Single-line renderable matching `with_searchable_lines` construction,
+/// optionally reversed to mark the selected hunk header.
+fn diff_line_renderable(line: Line<'static>, selected: bool) -> Box<dyn Renderable> {
+    let mut paragraph = Paragraph::new(line).wrap(Wrap { trim: false });
+    if selected {
+        paragraph = paragraph.style(Style::default().reversed());
+    }
+    Box::new(CachedRenderable::new(paragraph))
+}
+
 fn render_offset_content(
     area: Rect,
     buf: &mut Buffer,
     renderable: &dyn Renderable,
     scroll_offset: u16,
+    h_scroll: u16,
 ) -> u16 {
-    let height = renderable.desired_height(area.width);
+    let render_width = area.width.saturating_add(h_scroll);
+    let height = renderable.desired_height(render_width);
     let mut tall_buf = Buffer::empty(Rect::new(
         0,
         0,
-        area.width,
+        render_width,
         height.min(area.height + scroll_offset),
     ));
     renderable.render(*tall_buf.area(), &mut tall_buf);
@@ -798,7 +1346,7 @@ fn render_offset_content(
     for y in 0..copy_height {
         let src_y = y + scroll_offset;
         for x in 0..area.width {
-            buf[(area.x + x, area.y + y)] = tall_buf[(x, src_y)].clone();
+            buf[(area.x + x, area.y + y)] = tall_buf[(x + h_scroll, src_y)].clone();
         }
     }
 
@@ -842,6 +1390,30 @@ mod tests {
         }
     }
 
+    /// Test cell reporting a fixed transcript kind, for filter tests.
+    #[derive(Debug)]
+    struct KindCell {
+        kind: TranscriptCellKind,
+        lines: Vec<Line<'static>>,
+    }
+
+    impl crate::history_cell::HistoryCell for KindCell {
+        fn display_lines(&self, _width: u16) -> Vec<Line<'static>> {
+            self.lines.clone()
+        }
+
+        fn transcript_kind(&self) -> TranscriptCellKind {
+            self.kind
+        }
+    }
+
+    fn kind_cell(kind: TranscriptCellKind, text: &str) -> Arc<dyn HistoryCell> {
+        Arc::new(KindCell {
+            kind,
+            lines: vec![Line::from(text.to_string())],
+        })
+    }
+
     fn paragraph_block(label: &str, lines: usize) -> Box<dyn Renderable> {
         let text = Text::from(
             (0..lines)
@@ -954,6 +1526,58 @@ mod tests {
         assert_eq!(calls.get(), 1);
     }
 
+    #[test]
+    fn transcript_filter_cycles_by_cell_type() {
+        let mut overlay = TranscriptOverlay::new(vec![
+            kind_cell(TranscriptCellKind::User, "user"),
+            kind_cell(TranscriptCellKind::Agent, "agent"),
+            kind_cell(TranscriptCellKind::Command, "cmd"),
+            kind_cell(TranscriptCellKind::Error, "err"),
+            kind_cell(TranscriptCellKind::Other, "note"),
+        ]);
+        assert_eq!(overlay.view.renderables.len(), 5);
+
+        overlay.cycle_filter();
+        assert_eq!(overlay.view.renderables.len(), 1, "only the user message");
+        assert!(overlay.view.title.contains("user messages"));
+
+        overlay.cycle_filter();
+        assert!(overlay.view.title.contains("agent answers"));
+        overlay.cycle_filter();
+        assert!(overlay.view.title.contains("commands"));
+        overlay.cycle_filter();
+        assert_eq!(overlay.view.renderables.len(), 1, "only the error");
+        assert!(overlay.view.title.contains("errors"));
+
+        overlay.cycle_filter();
+        assert_eq!(overlay.view.renderables.len(), 5, "back to everything");
+        assert_eq!(overlay.view.title, "T R A N S C R I P T");
+    }
+
+    #[test]
+    fn transcript_filter_keeps_live_tail() {
+        let mut overlay =
+            TranscriptOverlay::new(vec![kind_cell(TranscriptCellKind::Command, "cmd")]);
+        overlay.sync_live_tail(
+            /*width*/ 40,
+            Some(ActiveCellTranscriptKey {
+                revision: 1,
+                is_stream_continuation: false,
+                animation_tick: None,
+            }),
+            |_| Some(vec![Line::from("tail")]),
+        );
+        assert_eq!(overlay.view.renderables.len(), 2);
+
+        // `user messages` hides the command but must not drop the live tail.
+        overlay.cycle_filter();
+        assert_eq!(overlay.view.renderables.len(), 1, "tail only");
+
+        overlay.cycle_filter();
+        overlay.cycle_filter();
+        assert_eq!(overlay.view.renderables.len(), 2, "command plus tail");
+    }
+
     fn buffer_to_text(buf: &Buffer, area: Rect) -> String {
         let mut out = String::new();
         for y in area.y..area.bottom() {
@@ -1277,6 +1901,63 @@ mod tests {
         assert_eq!(pv.scroll_offset, 0);
     }
 
+    #[test]
+    fn pager_view_marks_set_and_return() {
+        use crossterm::event::KeyModifiers;
+
+        let mut pv = PagerView::new(
+            vec![paragraph_block("a", /*lines*/ 30)],
+            "T".to_string(),
+            /*scroll_offset*/ 5,
+        );
+
+        pv.complete_mark_action(
+            PendingMark::Set,
+            KeyEvent::new(KeyCode::Char('1'), KeyModifiers::NONE),
+        );
+        pv.scroll_offset = 20;
+        pv.complete_mark_action(
+            PendingMark::Jump,
+            KeyEvent::new(KeyCode::Char('1'), KeyModifiers::NONE),
+        );
+        assert_eq!(pv.scroll_offset, 5, "expected '1 to return to mark 1");
+
+        // `''` returns to where the previous jump started.
+        pv.complete_mark_action(
+            PendingMark::Jump,
+            KeyEvent::new(KeyCode::Char('\''), KeyModifiers::NONE),
+        );
+        assert_eq!(pv.scroll_offset, 20, "expected '' to return to jump origin");
+    }
+
+    #[test]
+    fn pager_view_jump_to_unset_mark_is_noop() {
+        use crossterm::event::KeyModifiers;
+
+        let mut pv = PagerView::new(
+            vec![paragraph_block("a", /*lines*/ 30)],
+            "T".to_string(),
+            /*scroll_offset*/ 7,
+        );
+
+        pv.complete_mark_action(
+            PendingMark::Jump,
+            KeyEvent::new(KeyCode::Char('3'), KeyModifiers::NONE),
+        );
+        assert_eq!(pv.scroll_offset, 7);
+
+        // A non-digit completion cancels the action without moving.
+        pv.complete_mark_action(
+            PendingMark::Set,
+            KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
+        );
+        pv.complete_mark_action(
+            PendingMark::Jump,
+            KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
+        );
+        assert_eq!(pv.scroll_offset, 7);
+    }
+
     #[test]
     fn pager_view_is_scrolled_to_bottom_accounts_for_wrapped_height() {
         let mut pv = PagerView::new(
@@ -0,0 +1,67 @@
+//! SSH / high-latency link detection and the derived low-bandwidth mode.
+//!
+//! Remote terminals over SSH pay per-byte and per-round-trip costs that local
+//! emulators do not: high frame rates waste link bandwidth, truecolor
+//! gradients re-style every cell on every tick, and OSC color queries can
+//! stall for seconds on terminals that forward them slowly (or never answer).
+//! When an SSH session is detected the TUI drops to a lower frame rate,
+//! enters streaming catch-up mode sooner, renders shimmer without RGB
+//! gradients, and skips default-color queries.
+//!
+//! `CODEX_TUI_LOW_BANDWIDTH=1|0` forces the mode on or off regardless of
+//! detection, for users on fast SSH links or slow local terminals.
+
+use std::sync::OnceLock;
+
+/// Returns `true` when the process appears to run inside an SSH session.
+pub(crate) fn is_ssh_session() -> bool {
+    std::env::var_os("SSH_CONNECTION").is_some()
+        || std::env::var_os("SSH_CLIENT").is_some()
+        || std::env::var_os("SSH_TTY").is_some()
+}
+
+/// Returns the cached low-bandwidth decision for this process.
+pub(crate) fn low_bandwidth_mode() -> bool {
+    static MODE: OnceLock<bool> = OnceLock::new();
+    *MODE.get_or_init(|| {
+        low_bandwidth_mode_from(
+            std::env::var("CODEX_TUI_LOW_BANDWIDTH").ok().as_deref(),
+            is_ssh_session(),
+        )
+    })
+}
+
+fn low_bandwidth_mode_from(override_var: Option<&str>, ssh_session: bool) -> bool {
+    match override_var {
+        Some("1") | Some("true") => true,
+        Some("0") | Some("false") => false,
+        _ => ssh_session,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ssh_detection_drives_default() {
+        assert!(low_bandwidth_mode_from(
+            /*override_var*/ None, /*ssh_session*/ true
+        ));
+        assert!(!low_bandwidth_mode_from(
+            /*override_var*/ None, /*ssh_session*/ false
+        ));
+    }
+
+    #[test]
+    fn explicit_override_wins_over_detection() {
+        assert!(!low_bandwidth_mode_from(
+            Some("0"),
+            /*ssh_session*/ true
+        ));
+        assert!(low_bandwidth_mode_from(
+            Some("1"),
+            /*ssh_session*/ false
+        ));
+    }
+}
@@ -2,14 +2,15 @@
 //!
 //! Wraps [syntect] with the [two_face] grammar and theme bundles to provide
 //! ~250-language syntax highlighting and 32 bundled color themes.  The module
-//! owns four process-global singletons:
+//! owns five process-global singletons:
 //!
 //! | Singleton | Type | Purpose |
 //! |---|---|---|
 //! | `SYNTAX_SET` | `OnceLock<SyntaxSet>` | Grammar database, immutable after init |
 //! | `THEME` | `OnceLock<RwLock<Theme>>` | Active color theme, swappable at runtime |
 //! | `THEME_OVERRIDE` | `OnceLock<Option<String>>` | Persisted user preference (write-once) |
-//! | `CODEX_HOME` | `OnceLock<Option<PathBuf>>` | Root for custom `.tmTheme` discovery |
+//! | `CODEX_HOME` | `OnceLock<Option<PathBuf>>` | Root for custom theme discovery |
+//! | `SYNTAX_STYLE_OVERRIDES` | `OnceLock<Vec<ThemeItem>>` | `[tui.syntax]` per-scope overrides (write-once) |
 //!
 //! **Lifecycle:** call [`set_theme_override`] once at startup (after the final
 //! config is resolved) to persist the user preference and seed the `THEME`
@@ -21,22 +22,30 @@
 //! (returns `None`) to prevent pathological CPU/memory usage.  Callers must
 //! fall back to plain unstyled text.
 
+use codex_config::types::SyntaxStyleToml;
 use ratatui::style::Color as RtColor;
 use ratatui::style::Modifier;
 use ratatui::style::Style;
 use ratatui::text::Line;
 use ratatui::text::Span;
+use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::OnceLock;
 use std::sync::RwLock;
 use syntect::easy::HighlightLines;
 use syntect::highlighting::Color as SyntectColor;
 use syntect::highlighting::FontStyle;
 use syntect::highlighting::Highlighter;
+use syntect::highlighting::ScopeSelectors;
 use syntect::highlighting::Style as SyntectStyle;
+use syntect::highlighting::StyleModifier;
 use syntect::highlighting::Theme;
+use syntect::highlighting::ThemeItem;
 use syntect::highlighting::ThemeSet;
+use syntect::highlighting::ThemeSettings;
 use syntect::parsing::Scope;
 use syntect::parsing::SyntaxReference;
 use syntect::parsing::SyntaxSet;
@@ -49,6 +58,7 @@ static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
 static THEME: OnceLock<RwLock<Theme>> = OnceLock::new();
 static THEME_OVERRIDE: OnceLock<Option<String>> = OnceLock::new();
 static CODEX_HOME: OnceLock<Option<PathBuf>> = OnceLock::new();
+static SYNTAX_STYLE_OVERRIDES: OnceLock<Vec<ThemeItem>> = OnceLock::new();
 
 // Syntect/bat encode ANSI palette semantics in alpha:
 // `a=0` => indexed ANSI palette via RGB payload, `a=1` => terminal default.
@@ -60,6 +70,20 @@ fn syntax_set() -> &'static SyntaxSet {
     SYNTAX_SET.get_or_init(two_face::syntax::extra_newlines)
 }
 
+/// Deserializes the grammar database on a background thread so the first
+/// highlighted code block does not stall the render loop. Safe to call more
+/// than once; `OnceLock` makes the warm-up idempotent.
+pub(crate) fn prewarm_in_background() {
+    if SYNTAX_SET.get().is_some() {
+        return;
+    }
+    std::thread::spawn(|| {
+        let started = std::time::Instant::now();
+        syntax_set();
+        crate::startup_profile::record("grammar warm-up", started.elapsed());
+    });
+}
+
 // NOTE: We intentionally do NOT emit a runtime diagnostic when an ANSI-family
 // theme (ansi, base16, base16-256) lacks the expected alpha-channel marker
 // encoding.  If the upstream two_face/syntect theme format changes, the
@@ -100,8 +124,9 @@ pub(crate) fn set_theme_override(
     warning
 }
 
-/// Check whether a theme name resolves to a bundled theme or a custom
-/// `.tmTheme` file.  Returns a user-facing warning when it does not.
+/// Check whether a theme name resolves to a bundled theme or a custom theme
+/// file (`.tmTheme` or `.toml`).  Returns a user-facing warning when it does
+/// not.
 pub(crate) fn validate_theme_name(name: Option<&str>, codex_home: Option<&Path>) -> Option<String> {
     let name = name?;
     let custom_theme_path_display = codex_home
@@ -114,20 +139,26 @@ pub(crate) fn validate_theme_name(name: Option<&str>, codex_home: Option<&Path>)
     // Custom themes must parse successfully; an unreadable/invalid file should
     // still surface a startup warning so users can diagnose configuration issues.
     if let Some(home) = codex_home {
-        let custom_path = custom_theme_path(name, home);
-        if custom_path.is_file() {
+        for path in [
+            custom_theme_path(name, home),
+            custom_toml_theme_path(name, home),
+        ] {
+            if !path.is_file() {
+                continue;
+            }
             if load_custom_theme(name, home).is_some() {
                 return None;
             }
             return Some(format!(
-                "Custom theme \"{name}\" at {custom_theme_path_display} could not \
-                 be loaded (invalid .tmTheme format). Falling back to the default theme."
+                "Custom theme \"{name}\" at {} could not be loaded (invalid \
+                 theme definition). Falling back to the default theme.",
+                path.display()
             ));
         }
     }
     Some(format!(
         "Theme \"{name}\" not found. Using the default theme. \
-         To use a custom theme, place a .tmTheme file at \
+         To use a custom theme, place a .tmTheme or .toml theme file at \
          {custom_theme_path_display}."
     ))
 }
@@ -171,14 +202,225 @@ fn parse_theme_name(name: &str) -> Option<EmbeddedThemeName> {
     }
 }
 
-/// Build the expected path for a custom theme file.
+/// Build the expected path for a custom `.tmTheme` file.
 fn custom_theme_path(name: &str, codex_home: &Path) -> PathBuf {
     codex_home.join("themes").join(format!("{name}.tmTheme"))
 }
 
-/// Try to load a custom `.tmTheme` file from `{codex_home}/themes/{name}.tmTheme`.
+/// Build the expected path for a custom TOML theme definition.
+fn custom_toml_theme_path(name: &str, codex_home: &Path) -> PathBuf {
+    codex_home.join("themes").join(format!("{name}.toml"))
+}
+
+/// Try to load a custom theme from `{codex_home}/themes/{name}.*`.  Prefers
+/// the TextMate `.tmTheme` format and falls back to a TOML theme definition
+/// with the same name.
 fn load_custom_theme(name: &str, codex_home: &Path) -> Option<Theme> {
-    ThemeSet::get_theme(custom_theme_path(name, codex_home)).ok()
+    ThemeSet::get_theme(custom_theme_path(name, codex_home))
+        .ok()
+        .or_else(|| load_custom_toml_theme(name, codex_home))
+}
+
+/// Try to load a TOML theme definition from `{codex_home}/themes/{name}.toml`.
+fn load_custom_toml_theme(name: &str, codex_home: &Path) -> Option<Theme> {
+    let raw = std::fs::read_to_string(custom_toml_theme_path(name, codex_home)).ok()?;
+    parse_toml_theme(&raw)
+}
+
+/// On-disk schema for a TOML theme definition:
+///
+/// ```toml
+/// name = "My Theme"
+///
+/// [palette]
+/// foreground = "#c0caf5"
+/// background = "#1a1b26"
+/// caret = "#c0caf5"
+/// selection = "#283457"
+///
+/// user_message_bg = "#24283b"
+/// composer_border = "#7aa2f7"
+/// status_spinner = "#bb9af7"
+/// scrollbar = "#3b4261"
+/// dim_text = "#565f89"
+/// tool_call_accent = "#7dcfff"
+///
+/// [styles]
+/// keyword = { fg = "#bb9af7", bold = true }
+/// comment = { fg = "#565f89", italic = true }
+/// "markup.inserted" = { bg = "#20303b" }
+/// ```
+///
+/// Every key is optional; `styles` keys are TextMate scope selectors.  Beyond
+/// the four syntect palette slots, the `[palette]` table accepts UI chrome
+/// colors (`user_message_bg`, `composer_border`, `status_spinner`,
+/// `scrollbar`, `dim_text`, `tool_call_accent`) so a theme can restyle the
+/// full interface, not just code blocks.  These are carried inside the
+/// syntect [`Theme`] as synthetic `codex.ui.*` scope items, which keeps the
+/// picker's live preview and cancel-restore working without extra state; see
+/// [`theme_ui_color_rgbs`].
+#[derive(Deserialize)]
+struct TomlThemeFile {
+    name: Option<String>,
+    #[serde(default)]
+    palette: TomlThemePalette,
+    #[serde(default)]
+    styles: BTreeMap<String, TomlThemeStyle>,
+}
+
+#[derive(Default, Deserialize)]
+struct TomlThemePalette {
+    foreground: Option<String>,
+    background: Option<String>,
+    caret: Option<String>,
+    selection: Option<String>,
+    user_message_bg: Option<String>,
+    composer_border: Option<String>,
+    status_spinner: Option<String>,
+    scrollbar: Option<String>,
+    dim_text: Option<String>,
+    tool_call_accent: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TomlThemeStyle {
+    fg: Option<String>,
+    bg: Option<String>,
+    #[serde(default)]
+    bold: bool,
+    #[serde(default)]
+    italic: bool,
+    #[serde(default)]
+    underline: bool,
+}
+
+/// Synthetic scope names used to smuggle UI chrome colors through a syntect
+/// [`Theme`].  `.tmTheme` authors can target the same scopes directly.
+const UI_SCOPE_USER_MESSAGE_BG: &str = "codex.ui.user_message_bg";
+const UI_SCOPE_COMPOSER_BORDER: &str = "codex.ui.composer_border";
+const UI_SCOPE_STATUS_SPINNER: &str = "codex.ui.status_spinner";
+const UI_SCOPE_SCROLLBAR: &str = "codex.ui.scrollbar";
+const UI_SCOPE_DIM_TEXT: &str = "codex.ui.dim_text";
+const UI_SCOPE_TOOL_CALL_ACCENT: &str = "codex.ui.tool_call_accent";
+
+/// Parse a TOML theme definition into a syntect [`Theme`].  Returns `None`
+/// when the document, a color, or a scope selector is malformed so the
+/// configuration mistake surfaces as a startup warning instead of a silently
+/// miscolored theme.
+fn parse_toml_theme(raw: &str) -> Option<Theme> {
+    let file: TomlThemeFile = toml::from_str(raw).ok()?;
+    let settings = ThemeSettings {
+        foreground: optional_hex_color(file.palette.foreground.as_deref())?,
+        background: optional_hex_color(file.palette.background.as_deref())?,
+        caret: optional_hex_color(file.palette.caret.as_deref())?,
+        selection: optional_hex_color(file.palette.selection.as_deref())?,
+        ..ThemeSettings::default()
+    };
+    let mut scopes = Vec::with_capacity(file.styles.len());
+    for (selector, style) in &file.styles {
+        scopes.push(toml_style_theme_item(
+            selector,
+            style.fg.as_deref(),
+            style.bg.as_deref(),
+            style.bold,
+            style.italic,
+            style.underline,
+        )?);
+    }
+    // UI chrome palette entries travel as synthetic scope items so they stay
+    // attached to the theme value through preview/restore/hot-swap flows.
+    // `user_message_bg` is a background slot; the rest are foregrounds.
+    for (scope_name, color, is_background) in [
+        (
+            UI_SCOPE_USER_MESSAGE_BG,
+            file.palette.user_message_bg.as_deref(),
+            true,
+        ),
+        (
+            UI_SCOPE_COMPOSER_BORDER,
+            file.palette.composer_border.as_deref(),
+            false,
+        ),
+        (
+            UI_SCOPE_STATUS_SPINNER,
+            file.palette.status_spinner.as_deref(),
+            false,
+        ),
+        (UI_SCOPE_SCROLLBAR, file.palette.scrollbar.as_deref(), false),
+        (UI_SCOPE_DIM_TEXT, file.palette.dim_text.as_deref(), false),
+        (
+            UI_SCOPE_TOOL_CALL_ACCENT,
+            file.palette.tool_call_accent.as_deref(),
+            false,
+        ),
+    ] {
+        let Some(color) = optional_hex_color(color)? else {
+            continue;
+        };
+        scopes.push(ThemeItem {
+            scope: ScopeSelectors::from_str(scope_name).ok()?,
+            style: StyleModifier {
+                foreground: (!is_background).then_some(color),
+                background: is_background.then_some(color),
+                font_style: None,
+            },
+        });
+    }
+    Some(Theme {
+        name: file.name,
+        author: None,
+        settings,
+        scopes,
+    })
+}
+
+/// Build a syntect [`ThemeItem`] from the TOML style shape shared by theme
+/// file `[styles]` tables and `[tui.syntax]` config overrides.  Returns
+/// `None` when the scope selector or a color is malformed.
+fn toml_style_theme_item(
+    selector: &str,
+    fg: Option<&str>,
+    bg: Option<&str>,
+    bold: bool,
+    italic: bool,
+    underline: bool,
+) -> Option<ThemeItem> {
+    let mut font_style = FontStyle::empty();
+    font_style.set(FontStyle::BOLD, bold);
+    font_style.set(FontStyle::ITALIC, italic);
+    font_style.set(FontStyle::UNDERLINE, underline);
+    Some(ThemeItem {
+        scope: ScopeSelectors::from_str(selector).ok()?,
+        style: StyleModifier {
+            foreground: optional_hex_color(fg)?,
+            background: optional_hex_color(bg)?,
+            font_style: (!font_style.is_empty()).then_some(font_style),
+        },
+    })
+}
+
+/// Parse an optional `#rrggbb` value.  Absent is fine; a malformed value
+/// rejects the whole theme (outer `None`).
+fn optional_hex_color(value: Option<&str>) -> Option<Option<SyntectColor>> {
+    match value {
+        None => Some(None),
+        Some(hex) => Some(Some(parse_hex_color(hex)?)),
+    }
+}
+
+/// Parse a `#rrggbb` hex string into an opaque syntect color.
+fn parse_hex_color(value: &str) -> Option<SyntectColor> {
+    let hex = value.strip_prefix('#')?;
+    // The ASCII guard keeps the byte slices below from landing mid-codepoint.
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    Some(SyntectColor {
+        r: u8::from_str_radix(&hex[0..2], 16).ok()?,
+        g: u8::from_str_radix(&hex[2..4], 16).ok()?,
+        b: u8::from_str_radix(&hex[4..6], 16).ok()?,
+        a: OPAQUE_ALPHA,
+    })
 }
 
 fn adaptive_default_theme_selection() -> (EmbeddedThemeName, &'static str) {
@@ -200,9 +442,109 @@ pub(crate) fn adaptive_default_theme_name() -> &'static str {
     adaptive_default_theme_selection().1
 }
 
+/// Re-resolve the adaptive default theme after the detected terminal
+/// background changes, e.g. when the user flips their terminal between dark
+/// and light profiles mid-session and a focus-in requeries OSC 11.
+///
+/// Does nothing when the user pinned an explicit theme, when the theme has
+/// not been initialized yet, or when the change would not flip the adaptive
+/// dark/light selection.
+pub(crate) fn refresh_theme_for_background_change(
+    previous_bg: Option<(u8, u8, u8)>,
+    new_bg: Option<(u8, u8, u8)>,
+) {
+    // An explicitly configured theme is never swapped out from under the user.
+    if matches!(THEME_OVERRIDE.get(), Some(Some(_))) {
+        return;
+    }
+    if THEME.get().is_none() {
+        return;
+    }
+    let previously_light = previous_bg.map(crate::color::is_light);
+    let now_light = new_bg.map(crate::color::is_light);
+    if previously_light == now_light {
+        return;
+    }
+    set_syntax_theme(build_default_theme());
+}
+
+/// Install the `[tui.syntax]` per-scope style overrides from the final
+/// resolved config.  Like [`set_theme_override`], call once at startup before
+/// theme resolution; the items are then layered onto every theme resolved
+/// afterwards — bundled, custom, picker previews, and hot reloads alike.
+///
+/// Returns a user-facing warning naming the entries whose scope selector or
+/// color failed to parse; the valid entries still apply.
+pub(crate) fn init_syntax_style_overrides(
+    styles: &BTreeMap<String, SyntaxStyleToml>,
+) -> Option<String> {
+    let (items, invalid) = syntax_override_items(styles);
+    if SYNTAX_STYLE_OVERRIDES.set(items).is_err() {
+        tracing::debug!("init_syntax_style_overrides called more than once; overrides unchanged");
+    }
+    if invalid.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "Ignoring invalid [tui.syntax] {}: {}. Keys must be TextMate scope \
+         selectors and colors must be \"#rrggbb\" hex values.",
+        if invalid.len() == 1 {
+            "entry"
+        } else {
+            "entries"
+        },
+        invalid.join(", ")
+    ))
+}
+
+/// Convert `[tui.syntax]` entries into syntect theme items, collecting the
+/// keys of entries that fail to parse.
+fn syntax_override_items(
+    styles: &BTreeMap<String, SyntaxStyleToml>,
+) -> (Vec<ThemeItem>, Vec<String>) {
+    let mut items = Vec::with_capacity(styles.len());
+    let mut invalid = Vec::new();
+    for (selector, style) in styles {
+        match toml_style_theme_item(
+            selector,
+            style.fg.as_deref(),
+            style.bg.as_deref(),
+            style.bold,
+            style.italic,
+            style.underline,
+        ) {
+            Some(item) => items.push(item),
+            None => invalid.push(format!("\"{selector}\"")),
+        }
+    }
+    (items, invalid)
+}
+
+/// Layer the configured `[tui.syntax]` items over `theme`.  Appended after
+/// the theme's own items so they win for equally specific selectors.
+fn with_syntax_style_overrides(theme: Theme) -> Theme {
+    match SYNTAX_STYLE_OVERRIDES.get() {
+        Some(items) if !items.is_empty() => theme_with_override_items(theme, items),
+        _ => theme,
+    }
+}
+
+/// Pure layering helper, separated from the global override singleton so
+/// tests can pass arbitrary item sets.
+fn theme_with_override_items(mut theme: Theme, items: &[ThemeItem]) -> Theme {
+    theme.scopes.extend(items.iter().cloned());
+    theme
+}
+
 /// Build the theme from current override/default-theme settings.
 /// Extracted from the old `theme()` init closure so it can be reused.
 fn resolve_theme_with_override(name: Option<&str>, codex_home: Option<&Path>) -> Theme {
+    with_syntax_style_overrides(resolve_named_or_default_theme(name, codex_home))
+}
+
+/// Resolve `name` to a bundled or custom theme, falling back to the adaptive
+/// default, without applying `[tui.syntax]` overrides.
+fn resolve_named_or_default_theme(name: Option<&str>, codex_home: Option<&Path>) -> Theme {
     let ts = two_face::theme::extra();
 
     // Honor user-configured theme if valid.
@@ -211,7 +553,8 @@ fn resolve_theme_with_override(name: Option<&str>, codex_home: Option<&Path>) ->
         if let Some(theme_name) = parse_theme_name(name) {
             return ts.get(theme_name).clone();
         }
-        // 2. Try loading {CODEX_HOME}/themes/{name}.tmTheme from disk.
+        // 2. Try loading a {CODEX_HOME}/themes/{name}.tmTheme or .toml theme
+        //    file from disk.
         if let Some(home) = codex_home
             && let Some(theme) = load_custom_theme(name, home)
         {
@@ -254,6 +597,46 @@ pub(crate) fn current_syntax_theme() -> Theme {
     }
 }
 
+/// Selection background from the active syntax theme, blended over the
+/// terminal background when the theme specifies alpha. `None` when the theme
+/// has no (visible) selection color.
+pub(crate) fn theme_selection_bg() -> Option<(u8, u8, u8)> {
+    let selection = current_syntax_theme().settings.selection?;
+    if selection.a == 0 {
+        return None;
+    }
+    let rgb = (selection.r, selection.g, selection.b);
+    if selection.a == 255 {
+        return Some(rgb);
+    }
+    match crate::terminal_palette::default_bg() {
+        Some(bg) => Some(crate::color::blend(rgb, bg, f32::from(selection.a) / 255.0)),
+        None => Some(rgb),
+    }
+}
+
+/// Accent foreground from the active syntax theme, for chrome that wants to
+/// match the theme (e.g. the welcome animation). Prefers the `keyword` scope
+/// color and falls back to the caret and then the plain theme foreground.
+pub(crate) fn theme_accent_fg() -> Option<(u8, u8, u8)> {
+    let theme = current_syntax_theme();
+    theme_accent_fg_for_theme(&theme)
+}
+
+fn theme_accent_fg_for_theme(theme: &Theme) -> Option<(u8, u8, u8)> {
+    let highlighter = Highlighter::new(theme);
+    scope_foreground_rgb(&highlighter, "keyword")
+        .or_else(|| theme.settings.caret.map(|c| (c.r, c.g, c.b)))
+        .or_else(|| theme.settings.foreground.map(|c| (c.r, c.g, c.b)))
+}
+
+/// Extract the foreground color for a single TextMate scope, if defined.
+fn scope_foreground_rgb(highlighter: &Highlighter<'_>, scope_name: &str) -> Option<(u8, u8, u8)> {
+    let scope = Scope::new(scope_name).ok()?;
+    let fg = highlighter.style_mod_for_stack(&[scope]).foreground?;
+    Some((fg.r, fg.g, fg.b))
+}
+
 /// Raw RGB background colors extracted from syntax theme diff/markup scopes.
 ///
 /// These are theme-provided colors, not yet adapted for any particular color
@@ -298,6 +681,42 @@ fn scope_background_rgb(highlighter: &Highlighter<'_>, scope_name: &str) -> Opti
     Some((bg.r, bg.g, bg.b))
 }
 
+/// Raw RGB colors for UI chrome slots a theme may define via the `[palette]`
+/// extras of a TOML theme (or `codex.ui.*` scopes in a `.tmTheme`).
+///
+/// Every field is `None` for themes that do not define the slot — which is
+/// all bundled themes — so consumers fall back to their existing hardcoded
+/// styling and rendering is unchanged unless a theme opts in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct ThemeUiColorRgbs {
+    pub user_message_bg: Option<(u8, u8, u8)>,
+    pub composer_border: Option<(u8, u8, u8)>,
+    pub status_spinner: Option<(u8, u8, u8)>,
+    pub scrollbar: Option<(u8, u8, u8)>,
+    pub dim_text: Option<(u8, u8, u8)>,
+    pub tool_call_accent: Option<(u8, u8, u8)>,
+}
+
+/// Query the active syntax theme for UI chrome colors.
+pub(crate) fn theme_ui_color_rgbs() -> ThemeUiColorRgbs {
+    let theme = current_syntax_theme();
+    theme_ui_color_rgbs_for_theme(&theme)
+}
+
+/// Pure extraction helper, separated from the global theme singleton so tests
+/// can pass arbitrary themes.
+fn theme_ui_color_rgbs_for_theme(theme: &Theme) -> ThemeUiColorRgbs {
+    let highlighter = Highlighter::new(theme);
+    ThemeUiColorRgbs {
+        user_message_bg: scope_background_rgb(&highlighter, UI_SCOPE_USER_MESSAGE_BG),
+        composer_border: scope_foreground_rgb(&highlighter, UI_SCOPE_COMPOSER_BORDER),
+        status_spinner: scope_foreground_rgb(&highlighter, UI_SCOPE_STATUS_SPINNER),
+        scrollbar: scope_foreground_rgb(&highlighter, UI_SCOPE_SCROLLBAR),
+        dim_text: scope_foreground_rgb(&highlighter, UI_SCOPE_DIM_TEXT),
+        tool_call_accent: scope_foreground_rgb(&highlighter, UI_SCOPE_TOOL_CALL_ACCENT),
+    }
+}
+
 /// Return the configured kebab-case theme name when it resolves; otherwise
 /// return the adaptive auto-detected default theme name.
 ///
@@ -319,34 +738,34 @@ pub(crate) fn configured_theme_name() -> String {
 }
 
 /// Resolve a theme name to a `Theme` (bundled or custom). Returns `None`
-/// when the name is unknown and no matching `.tmTheme` file exists.
+/// when the name is unknown and no matching theme file exists.
 pub(crate) fn resolve_theme_by_name(name: &str, codex_home: Option<&Path>) -> Option<Theme> {
     let ts = two_face::theme::extra();
     // Bundled theme?
     if let Some(embedded) = parse_theme_name(name) {
-        return Some(ts.get(embedded).clone());
+        return Some(with_syntax_style_overrides(ts.get(embedded).clone()));
     }
-    // Custom .tmTheme file?
+    // Custom theme file (.tmTheme or .toml)?
     if let Some(home) = codex_home
         && let Some(theme) = load_custom_theme(name, home)
     {
-        return Some(theme);
+        return Some(with_syntax_style_overrides(theme));
     }
     None
 }
 
 /// A theme available in the picker, either bundled or loaded from a custom
-/// `.tmTheme` file under `{CODEX_HOME}/themes/`.
+/// theme file under `{CODEX_HOME}/themes/`.
 pub(crate) struct ThemeEntry {
     /// Kebab-case identifier used for config persistence and theme resolution.
     pub name: String,
-    /// `true` when this entry was discovered from a `.tmTheme` file on disk
-    /// rather than the embedded two-face bundle.
+    /// `true` when this entry was discovered from a `.tmTheme` or `.toml`
+    /// theme file on disk rather than the embedded two-face bundle.
     pub is_custom: bool,
 }
 
-/// List all available theme names: bundled themes + custom `.tmTheme` files
-/// found in `{codex_home}/themes/`.
+/// List all available theme names: bundled themes + custom `.tmTheme` and
+/// `.toml` theme files found in `{codex_home}/themes/`.
 pub(crate) fn list_available_themes(codex_home: Option<&Path>) -> Vec<ThemeEntry> {
     let mut entries: Vec<ThemeEntry> = BUILTIN_THEME_NAMES
         .iter()
@@ -362,17 +781,23 @@ pub(crate) fn list_available_themes(codex_home: Option<&Path>) -> Vec<ThemeEntry
         if let Ok(read_dir) = std::fs::read_dir(&themes_dir) {
             for entry in read_dir.flatten() {
                 let path = entry.path();
-                if path.extension().and_then(|e| e.to_str()) == Some("tmTheme")
-                    && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
-                {
-                    let name = stem.to_string();
-                    let is_valid_theme = ThemeSet::get_theme(&path).is_ok();
-                    if is_valid_theme && !entries.iter().any(|e| e.name == name) {
-                        entries.push(ThemeEntry {
-                            name,
-                            is_custom: true,
-                        });
-                    }
+                let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                    continue;
+                };
+                let is_valid_theme = match path.extension().and_then(|e| e.to_str()) {
+                    Some("tmTheme") => ThemeSet::get_theme(&path).is_ok(),
+                    Some("toml") => std::fs::read_to_string(&path)
+                        .ok()
+                        .and_then(|raw| parse_toml_theme(&raw))
+                        .is_some(),
+                    _ => continue,
+                };
+                let name = stem.to_string();
+                if is_valid_theme && !entries.iter().any(|e| e.name == name) {
+                    entries.push(ThemeEntry {
+                        name,
+                        is_custom: true,
+                    });
                 }
             }
         }
@@ -469,12 +894,30 @@ fn convert_syntect_color(color: SyntectColor) -> Option<RtColor> {
         ANSI_ALPHA_INDEX => Some(ansi_palette_color(color.r)),
         // alpha 0x01 means "use terminal default foreground/background".
         ANSI_ALPHA_DEFAULT => None,
-        OPAQUE_ALPHA => Some(RtColor::Rgb(color.r, color.g, color.b)),
+        OPAQUE_ALPHA => Some(contrast_adapted_rgb(color)),
         // Non-ANSI alpha values appear in some bundled themes; treat as plain RGB.
-        _ => Some(RtColor::Rgb(color.r, color.g, color.b)),
+        _ => Some(contrast_adapted_rgb(color)),
     }
 }
 
+/// Build an RGB ratatui color, routed through the minimum-contrast correction
+/// from `tui.accessibility.min_contrast` against the detected terminal
+/// background. Custom `.tmTheme` palettes pass through here too, so user
+/// overrides no longer bypass adaptation. A no-op when the option is unset or
+/// the background is unknown.
+///
+/// `clippy::disallowed_methods` is explicitly allowed here because this helper
+/// intentionally constructs `ratatui::style::Color::Rgb`.
+#[allow(clippy::disallowed_methods)]
+fn contrast_adapted_rgb(color: SyntectColor) -> RtColor {
+    let rgb = (color.r, color.g, color.b);
+    let (r, g, b) = match crate::terminal_palette::default_bg() {
+        Some(bg) => crate::color::adapt_color_for_terminal(rgb, bg),
+        None => rgb,
+    };
+    RtColor::Rgb(r, g, b)
+}
+
 /// Convert a syntect `Style` to a ratatui `Style`.
 ///
 /// Most themes produce RGB colors. The built-in `ansi`/`base16`/`base16-256`
@@ -698,6 +1141,26 @@ mod tests {
         .unwrap();
     }
 
+    fn write_minimal_toml_theme(path: &Path) {
+        std::fs::write(
+            path,
+            r##"name = "Test Toml"
+
+[palette]
+foreground = "#c0caf5"
+background = "#1a1b26"
+caret = "#c0caf5"
+selection = "#283457"
+
+[styles]
+keyword = { fg = "#bb9af7", bold = true }
+comment = { fg = "#565f89", italic = true }
+"markup.inserted" = { bg = "#20303b" }
+"##,
+        )
+        .unwrap();
+    }
+
     fn write_tmtheme_with_diff_backgrounds(
         path: &Path,
         inserted_scope: &str,
@@ -1328,6 +1791,162 @@ mod tests {
         assert!(load_custom_theme("nonexistent", dir.path()).is_none());
     }
 
+    #[test]
+    fn load_custom_theme_from_toml_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let themes_dir = dir.path().join("themes");
+        std::fs::create_dir(&themes_dir).unwrap();
+        write_minimal_toml_theme(&themes_dir.join("toml-custom.toml"));
+
+        let theme = load_custom_theme("toml-custom", dir.path())
+            .expect("should load .toml theme definition from themes dir");
+
+        assert_eq!(theme.name.as_deref(), Some("Test Toml"));
+        assert_eq!(
+            theme.settings.foreground,
+            Some(SyntectColor {
+                r: 0xc0,
+                g: 0xca,
+                b: 0xf5,
+                a: OPAQUE_ALPHA,
+            })
+        );
+        let highlighter = Highlighter::new(&theme);
+        let style = highlighter.style_mod_for_stack(&[Scope::new("keyword").unwrap()]);
+        assert_eq!(
+            style.foreground,
+            Some(SyntectColor {
+                r: 0xbb,
+                g: 0x9a,
+                b: 0xf7,
+                a: OPAQUE_ALPHA,
+            })
+        );
+        assert_eq!(style.font_style, Some(FontStyle::BOLD));
+    }
+
+    #[test]
+    fn load_custom_theme_prefers_tmtheme_over_toml() {
+        let dir = tempfile::tempdir().unwrap();
+        let themes_dir = dir.path().join("themes");
+        std::fs::create_dir(&themes_dir).unwrap();
+        write_minimal_tmtheme(&themes_dir.join("both.tmTheme"));
+        write_minimal_toml_theme(&themes_dir.join("both.toml"));
+
+        let theme = load_custom_theme("both", dir.path()).expect("should load theme");
+        assert_eq!(
+            theme.name.as_deref(),
+            Some("Test"),
+            "the .tmTheme file should win when both formats are present"
+        );
+    }
+
+    #[test]
+    fn parse_toml_theme_rejects_malformed_input() {
+        // Broken TOML, a bad hex color, and a bad scope selector (scopes cap
+        // at eight atoms) all reject the whole theme so validate_theme_name
+        // can surface a warning.
+        assert!(parse_toml_theme("not [ valid toml").is_none());
+        assert!(parse_toml_theme("[palette]\nforeground = \"#zzzzzz\"").is_none());
+        assert!(
+            parse_toml_theme("[styles]\n\"a.b.c.d.e.f.g.h.i\" = { fg = \"#ffffff\" }").is_none()
+        );
+    }
+
+    #[test]
+    fn parse_toml_theme_extracts_ui_palette_colors() {
+        let theme = parse_toml_theme(
+            r##"[palette]
+foreground = "#c0caf5"
+user_message_bg = "#24283b"
+composer_border = "#7aa2f7"
+status_spinner = "#bb9af7"
+scrollbar = "#3b4261"
+dim_text = "#565f89"
+tool_call_accent = "#7dcfff"
+"##,
+        )
+        .expect("theme with UI palette entries should parse");
+        let ui = theme_ui_color_rgbs_for_theme(&theme);
+        assert_eq!(ui.user_message_bg, Some((0x24, 0x28, 0x3b)));
+        assert_eq!(ui.composer_border, Some((0x7a, 0xa2, 0xf7)));
+        assert_eq!(ui.status_spinner, Some((0xbb, 0x9a, 0xf7)));
+        assert_eq!(ui.scrollbar, Some((0x3b, 0x42, 0x61)));
+        assert_eq!(ui.dim_text, Some((0x56, 0x5f, 0x89)));
+        assert_eq!(ui.tool_call_accent, Some((0x7d, 0xcf, 0xff)));
+    }
+
+    #[test]
+    fn themes_without_ui_palette_entries_yield_no_ui_colors() {
+        // Bundled themes and minimal custom themes define no `codex.ui.*`
+        // scopes, so every slot stays None and chrome keeps its hardcoded
+        // styling.
+        let toml_theme =
+            parse_toml_theme("[palette]\nforeground = \"#c0caf5\"").expect("minimal theme");
+        assert_eq!(
+            theme_ui_color_rgbs_for_theme(&toml_theme),
+            ThemeUiColorRgbs::default()
+        );
+        let bundled = resolve_theme_by_name("dracula", /*codex_home*/ None)
+            .expect("bundled theme should resolve");
+        assert_eq!(
+            theme_ui_color_rgbs_for_theme(&bundled),
+            ThemeUiColorRgbs::default()
+        );
+    }
+
+    #[test]
+    fn parse_toml_theme_rejects_malformed_ui_palette_color() {
+        assert!(parse_toml_theme("[palette]\nuser_message_bg = \"#zzzzzz\"").is_none());
+    }
+
+    #[test]
+    fn syntax_overrides_layer_over_a_bundled_theme() {
+        let mut styles = BTreeMap::new();
+        styles.insert(
+            "keyword".to_string(),
+            SyntaxStyleToml {
+                fg: Some("#ff0000".to_string()),
+                bold: true,
+                ..SyntaxStyleToml::default()
+            },
+        );
+        let (items, invalid) = syntax_override_items(&styles);
+        assert_eq!(invalid, Vec::<String>::new());
+
+        let base = two_face::theme::extra()
+            .get(EmbeddedThemeName::Dracula)
+            .clone();
+        let theme = theme_with_override_items(base, &items);
+        let highlighter = Highlighter::new(&theme);
+        assert_eq!(
+            scope_foreground_rgb(&highlighter, "keyword"),
+            Some((255, 0, 0))
+        );
+    }
+
+    #[test]
+    fn invalid_syntax_override_entries_are_skipped_and_reported() {
+        let mut styles = BTreeMap::new();
+        styles.insert(
+            "comment".to_string(),
+            SyntaxStyleToml {
+                fg: Some("#zzzzzz".to_string()),
+                ..SyntaxStyleToml::default()
+            },
+        );
+        styles.insert(
+            "string".to_string(),
+            SyntaxStyleToml {
+                fg: Some("#00ff00".to_string()),
+                ..SyntaxStyleToml::default()
+            },
+        );
+        let (items, invalid) = syntax_override_items(&styles);
+        assert_eq!(items.len(), 1);
+        assert_eq!(invalid, vec!["\"comment\"".to_string()]);
+    }
+
     #[test]
     fn validate_theme_name_none_for_bundled() {
         // Bundled themes should never produce a warning.
@@ -1383,6 +2002,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_theme_name_none_when_custom_toml_is_valid() {
+        let dir = tempfile::tempdir().unwrap();
+        let themes_dir = dir.path().join("themes");
+        std::fs::create_dir(&themes_dir).unwrap();
+        write_minimal_toml_theme(&themes_dir.join("my-toml.toml"));
+        assert!(
+            validate_theme_name(Some("my-toml"), Some(dir.path())).is_none(),
+            "should not warn when custom .toml theme parses successfully"
+        );
+    }
+
+    #[test]
+    fn validate_theme_name_warns_when_custom_toml_is_invalid() {
+        let dir = tempfile::tempdir().unwrap();
+        let themes_dir = dir.path().join("themes");
+        std::fs::create_dir(&themes_dir).unwrap();
+        std::fs::write(themes_dir.join("my-toml.toml"), "not [ valid toml").unwrap();
+        let warning = validate_theme_name(Some("my-toml"), Some(dir.path()));
+        assert!(
+            warning
+                .as_deref()
+                .is_some_and(|msg| msg.contains("could not be loaded")),
+            "should warn when custom .toml theme exists but cannot be parsed"
+        );
+    }
+
+    #[test]
+    fn list_available_themes_includes_valid_toml_themes() {
+        let dir = tempfile::tempdir().unwrap();
+        let themes_dir = dir.path().join("themes");
+        std::fs::create_dir(&themes_dir).unwrap();
+        write_minimal_toml_theme(&themes_dir.join("toml-custom.toml"));
+        std::fs::write(themes_dir.join("broken-toml.toml"), "not [ valid toml").unwrap();
+
+        let entries = list_available_themes(Some(dir.path()));
+
+        assert!(
+            entries
+                .iter()
+                .any(|entry| entry.name == "toml-custom" && entry.is_custom),
+            "expected valid .toml theme to be listed"
+        );
+        assert!(
+            !entries.iter().any(|entry| entry.name == "broken-toml"),
+            "expected invalid .toml theme to be excluded from list"
+        );
+    }
+
     #[test]
     fn list_available_themes_excludes_invalid_custom_files() {
         let dir = tempfile::tempdir().unwrap();
@@ -1,3 +1,4 @@
+use codex_ansi_escape::ansi_escape_line;
 use ratatui::text::Line;
 use ratatui::text::Span;
 
@@ -17,6 +18,28 @@ pub fn line_to_static(line: &Line<'_>) -> Line<'static> {
     }
 }
 
+/// Parse ANSI SGR sequences in `s` into a styled line, routing any RGB
+/// foreground colors through the minimum-contrast adaptation against the
+/// detected terminal background so colored tool output (`cargo`, `pytest`,
+/// ...) stays readable regardless of theme. A no-op passthrough when the
+/// background is unknown.
+///
+/// `clippy::disallowed_methods` is explicitly allowed here because this helper
+/// intentionally re-constructs `ratatui::style::Color::Rgb`.
+#[allow(clippy::disallowed_methods)]
+pub fn ansi_styled_line(s: &str) -> Line<'static> {
+    let mut line = ansi_escape_line(s);
+    if let Some(bg) = crate::terminal_palette::default_bg() {
+        for span in &mut line.spans {
+            if let Some(ratatui::style::Color::Rgb(r, g, b)) = span.style.fg {
+                let (r, g, b) = crate::color::adapt_color_for_terminal((r, g, b), bg);
+                span.style.fg = Some(ratatui::style::Color::Rgb(r, g, b));
+            }
+        }
+    }
+    line
+}
+
 /// Append owned copies of borrowed lines to `out`.
 pub fn push_owned_lines<'a>(src: &[Line<'a>], out: &mut Vec<Line<'static>>) {
     for l in src {
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::path::Path;
 use std::path::PathBuf;
@@ -109,12 +110,27 @@ enum ProviderFilter {
 
 type PageLoader = Arc<dyn Fn(PageLoadRequest) + Send + Sync>;
 
+/// Sends a rename for a listed thread to the background app-server task.
+type ThreadRenamer = Arc<dyn Fn(ThreadId, String) + Send + Sync>;
+
+/// Sends a batch of thread archivals to the background app-server task.
+type ThreadArchiver = Arc<dyn Fn(Vec<ThreadId>) + Send + Sync>;
+
 enum BackgroundEvent {
     PageLoaded {
         request_token: usize,
         search_token: Option<usize>,
         page: std::io::Result<PickerPage>,
     },
+    ThreadRenamed {
+        thread_id: ThreadId,
+        name: String,
+        result: std::io::Result<()>,
+    },
+    ThreadsArchived {
+        archived: Vec<ThreadId>,
+        result: std::io::Result<()>,
+    },
 }
 
 #[derive(Clone)]
@@ -136,6 +152,13 @@ struct PickerPage {
 /// git branch, working directory, and conversation preview. Users can toggle
 /// between sorting by creation time and last-updated time using the Tab key.
 ///
+/// `Ctrl+R` renames the selected session in place; the new name is stored via
+/// `thread/name/set` and shown immediately in the list.
+///
+/// Sessions whose first prompts are near-identical (typically accidental
+/// restarts) collapse into their most recent row with an "N similar" badge;
+/// `Ctrl+D` (pressed twice) archives the hidden duplicates.
+///
 /// Sessions are loaded on-demand via cursor-based pagination. The backend
 /// `thread/list` API returns pages ordered by the selected sort key, and the
 /// picker deduplicates across pages to handle overlapping windows when new
@@ -158,13 +181,17 @@ pub async fn run_resume_picker_with_app_server(
     } else {
         app_server.remote_cwd_override().map(Path::to_path_buf)
     };
+    let (page_loader, renamer, archiver) =
+        spawn_app_server_page_loader(app_server, cwd_filter, include_non_interactive, bg_tx);
     run_session_picker_with_loader(
         tui,
         config,
         show_all,
         SessionPickerAction::Resume,
         is_remote,
-        spawn_app_server_page_loader(app_server, cwd_filter, include_non_interactive, bg_tx),
+        page_loader,
+        Some(renamer),
+        Some(archiver),
         bg_rx,
     )
     .await
@@ -183,15 +210,18 @@ pub async fn run_fork_picker_with_app_server(
     } else {
         app_server.remote_cwd_override().map(Path::to_path_buf)
     };
+    let (page_loader, renamer, archiver) = spawn_app_server_page_loader(
+        app_server, cwd_filter, /*include_non_interactive*/ false, bg_tx,
+    );
     run_session_picker_with_loader(
         tui,
         config,
         show_all,
         SessionPickerAction::Fork,
         is_remote,
-        spawn_app_server_page_loader(
-            app_server, cwd_filter, /*include_non_interactive*/ false, bg_tx,
-        ),
+        page_loader,
+        Some(renamer),
+        Some(archiver),
         bg_rx,
     )
     .await
@@ -204,6 +234,8 @@ async fn run_session_picker_with_loader(
     action: SessionPickerAction,
     is_remote: bool,
     page_loader: PageLoader,
+    renamer: Option<ThreadRenamer>,
+    archiver: Option<ThreadArchiver>,
     bg_rx: mpsc::UnboundedReceiver<BackgroundEvent>,
 ) -> Result<SessionSelection> {
     let alt = AltScreenGuard::enter(tui);
@@ -224,6 +256,8 @@ async fn run_session_picker_with_loader(
     let mut state = PickerState::new(
         alt.tui.frame_requester(),
         page_loader,
+        renamer,
+        archiver,
         provider_filter,
         show_all,
         filter_cwd,
@@ -269,41 +303,106 @@ async fn run_session_picker_with_loader(
     Ok(SessionSelection::StartFresh)
 }
 
+enum LoaderRequest {
+    Page(PageLoadRequest),
+    Rename { thread_id: ThreadId, name: String },
+    Archive { thread_ids: Vec<ThreadId> },
+}
+
 fn spawn_app_server_page_loader(
     app_server: AppServerSession,
     cwd_filter: Option<PathBuf>,
     include_non_interactive: bool,
     bg_tx: mpsc::UnboundedSender<BackgroundEvent>,
-) -> PageLoader {
-    let (request_tx, mut request_rx) = mpsc::unbounded_channel::<PageLoadRequest>();
+) -> (PageLoader, ThreadRenamer, ThreadArchiver) {
+    let (request_tx, mut request_rx) = mpsc::unbounded_channel::<LoaderRequest>();
 
     tokio::spawn(async move {
         let mut app_server = app_server;
         while let Some(request) = request_rx.recv().await {
-            let cursor = request.cursor.map(|PageCursor::AppServer(cursor)| cursor);
-            let page = load_app_server_page(
-                &mut app_server,
-                cursor,
-                cwd_filter.as_deref(),
-                request.provider_filter,
-                request.sort_key,
-                include_non_interactive,
-            )
-            .await;
-            let _ = bg_tx.send(BackgroundEvent::PageLoaded {
-                request_token: request.request_token,
-                search_token: request.search_token,
-                page,
-            });
+            match request {
+                LoaderRequest::Page(request) => {
+                    let cursor = request.cursor.map(|PageCursor::AppServer(cursor)| cursor);
+                    let page = load_app_server_page(
+                        &mut app_server,
+                        cursor,
+                        cwd_filter.as_deref(),
+                        request.provider_filter,
+                        request.sort_key,
+                        include_non_interactive,
+                    )
+                    .await;
+                    let _ = bg_tx.send(BackgroundEvent::PageLoaded {
+                        request_token: request.request_token,
+                        search_token: request.search_token,
+                        page,
+                    });
+                }
+                LoaderRequest::Rename { thread_id, name } => {
+                    let result = app_server
+                        .thread_set_name(thread_id, name.clone())
+                        .await
+                        .map_err(std::io::Error::other);
+                    let _ = bg_tx.send(BackgroundEvent::ThreadRenamed {
+                        thread_id,
+                        name,
+                        result,
+                    });
+                }
+                LoaderRequest::Archive { thread_ids } => {
+                    let mut archived = Vec::with_capacity(thread_ids.len());
+                    let mut result = Ok(());
+                    for thread_id in thread_ids {
+                        match app_server.thread_archive(thread_id).await {
+                            Ok(()) => archived.push(thread_id),
+                            Err(err) => {
+                                result = Err(std::io::Error::other(err));
+                                break;
+                            }
+                        }
+                    }
+                    let _ = bg_tx.send(BackgroundEvent::ThreadsArchived { archived, result });
+                }
+            }
         }
         if let Err(err) = app_server.shutdown().await {
             warn!(%err, "Failed to shut down app-server picker session");
         }
     });
 
-    Arc::new(move |request: PageLoadRequest| {
-        let _ = request_tx.send(request);
-    })
+    let rename_tx = request_tx.clone();
+    let archive_tx = request_tx.clone();
+    (
+        Arc::new(move |request: PageLoadRequest| {
+            let _ = request_tx.send(LoaderRequest::Page(request));
+        }),
+        Arc::new(move |thread_id: ThreadId, name: String| {
+            let _ = rename_tx.send(LoaderRequest::Rename { thread_id, name });
+        }),
+        Arc::new(move |thread_ids: Vec<ThreadId>| {
+            let _ = archive_tx.send(LoaderRequest::Archive { thread_ids });
+        }),
+    )
+}
+
+/// Normalizes a first-prompt preview for duplicate detection: lowercase,
+/// alphanumeric words only, capped so long prompts compare by prefix. Returns
+/// `None` when nothing substantial is left to compare.
+fn similarity_key(preview: &str) -> Option<String> {
+    const MAX_KEY_LEN: usize = 64;
+    let normalized: String = preview
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let key: String = normalized
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(MAX_KEY_LEN)
+        .collect();
+    (!key.is_empty()).then_some(key)
 }
 
 /// Returns the human-readable column header for the given sort key.
@@ -349,6 +448,15 @@ struct PickerState {
     next_request_token: usize,
     next_search_token: usize,
     page_loader: PageLoader,
+    renamer: Option<ThreadRenamer>,
+    rename: Option<RenameState>,
+    archiver: Option<ThreadArchiver>,
+    /// Duplicate threads keyed by the visible representative row, rebuilt on
+    /// every filter pass.
+    duplicate_groups: HashMap<ThreadId, Vec<ThreadId>>,
+    /// Set after the first Ctrl+D; a second Ctrl+D on the same row archives.
+    pending_bulk_archive: Option<ThreadId>,
+    inline_notice: Option<String>,
     view_rows: Option<usize>,
     provider_filter: ProviderFilter,
     show_all: bool,
@@ -365,6 +473,12 @@ struct PaginationState {
     loading: LoadingState,
 }
 
+/// In-progress rename of the selected row (Ctrl+R).
+struct RenameState {
+    thread_id: ThreadId,
+    buffer: String,
+}
+
 #[derive(Clone, Copy, Debug)]
 enum LoadingState {
     Idle,
@@ -486,6 +600,8 @@ impl PickerState {
     fn new(
         requester: FrameRequester,
         page_loader: PageLoader,
+        renamer: Option<ThreadRenamer>,
+        archiver: Option<ThreadArchiver>,
         provider_filter: ProviderFilter,
         show_all: bool,
         filter_cwd: Option<PathBuf>,
@@ -510,6 +626,12 @@ impl PickerState {
             next_request_token: 0,
             next_search_token: 0,
             page_loader,
+            renamer,
+            rename: None,
+            archiver,
+            duplicate_groups: HashMap::new(),
+            pending_bulk_archive: None,
+            inline_notice: None,
             view_rows: None,
             provider_filter,
             show_all,
@@ -526,6 +648,12 @@ impl PickerState {
 
     async fn handle_key(&mut self, key: KeyEvent) -> Result<Option<SessionSelection>> {
         self.inline_error = None;
+        self.inline_notice = None;
+        let pending_bulk_archive = self.pending_bulk_archive.take();
+        if self.rename.is_some() {
+            self.handle_rename_key(key);
+            return Ok(None);
+        }
         match key {
             KeyEvent {
                 code: KeyCode::Esc, ..
@@ -640,6 +768,20 @@ impl PickerState {
                 self.toggle_sort_key();
                 self.request_frame();
             }
+            KeyEvent {
+                code: KeyCode::Char('r'),
+                modifiers,
+                ..
+            } if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.start_rename();
+            }
+            KeyEvent {
+                code: KeyCode::Char('d'),
+                modifiers,
+                ..
+            } if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.handle_bulk_archive(pending_bulk_archive);
+            }
             KeyEvent {
                 code: KeyCode::Backspace,
                 ..
@@ -667,6 +809,92 @@ impl PickerState {
         Ok(None)
     }
 
+    fn start_rename(&mut self) {
+        if self.renamer.is_none() {
+            return;
+        }
+        let Some(row) = self.filtered_rows.get(self.selected) else {
+            return;
+        };
+        let Some(thread_id) = row.thread_id else {
+            return;
+        };
+        self.rename = Some(RenameState {
+            thread_id,
+            buffer: row.thread_name.clone().unwrap_or_default(),
+        });
+        self.request_frame();
+    }
+
+    fn handle_rename_key(&mut self, key: KeyEvent) {
+        match key {
+            KeyEvent {
+                code: KeyCode::Esc, ..
+            } => self.rename = None,
+            KeyEvent {
+                code: KeyCode::Enter,
+                ..
+            } => {
+                if let Some(RenameState { thread_id, buffer }) = self.rename.take()
+                    && let Some(renamer) = self.renamer.as_ref()
+                    && let Some(name) = crate::legacy_core::util::normalize_thread_name(&buffer)
+                {
+                    renamer(thread_id, name);
+                }
+            }
+            KeyEvent {
+                code: KeyCode::Backspace,
+                ..
+            } => {
+                if let Some(rename) = self.rename.as_mut() {
+                    rename.buffer.pop();
+                }
+            }
+            KeyEvent {
+                code: KeyCode::Char(c),
+                modifiers,
+                ..
+            } => {
+                if !modifiers.contains(KeyModifiers::CONTROL)
+                    && !modifiers.contains(KeyModifiers::ALT)
+                    && let Some(rename) = self.rename.as_mut()
+                {
+                    rename.buffer.push(c);
+                }
+            }
+            _ => {}
+        }
+        self.request_frame();
+    }
+
+    /// First Ctrl+D asks for confirmation, a second on the same row archives
+    /// the hidden duplicates of the selected session.
+    fn handle_bulk_archive(&mut self, pending: Option<ThreadId>) {
+        let Some(archiver) = self.archiver.as_ref() else {
+            return;
+        };
+        let Some(thread_id) = self
+            .filtered_rows
+            .get(self.selected)
+            .and_then(|r| r.thread_id)
+        else {
+            return;
+        };
+        let Some(duplicates) = self.duplicate_groups.get(&thread_id) else {
+            return;
+        };
+        if pending == Some(thread_id) {
+            archiver(duplicates.clone());
+        } else {
+            self.pending_bulk_archive = Some(thread_id);
+            self.inline_notice = Some(format!(
+                "Archive {} similar session(s)? Press ctrl + d again to confirm",
+                duplicates.len()
+            ));
+        }
+        self.request_frame();
+    }
+
     fn start_initial_load(&mut self) {
         self.relative_time_reference = Some(Utc::now());
         self.reset_pagination();
@@ -720,6 +948,45 @@ impl PickerState {
                 let completed_token = pending.search_token.or(search_token);
                 self.continue_search_if_token_matches(completed_token);
             }
+            BackgroundEvent::ThreadRenamed {
+                thread_id,
+                name,
+                result,
+            } => {
+                match result {
+                    Ok(()) => {
+                        for row in self
+                            .all_rows
+                            .iter_mut()
+                            .chain(self.filtered_rows.iter_mut())
+                            .filter(|row| row.thread_id == Some(thread_id))
+                        {
+                            row.thread_name = Some(name.clone());
+                        }
+                    }
+                    Err(err) => {
+                        self.inline_error = Some(format!("Failed to rename session: {err}"));
+                    }
+                }
+                self.request_frame();
+            }
+            BackgroundEvent::ThreadsArchived { archived, result } => {
+                if !archived.is_empty() {
+                    self.all_rows
+                        .retain(|row| !row.thread_id.is_some_and(|id| archived.contains(&id)));
+                    self.apply_filter();
+                }
+                match result {
+                    Ok(()) => {
+                        self.inline_notice =
+                            Some(format!("Archived {} similar session(s)", archived.len()));
+                    }
+                    Err(err) => {
+                        self.inline_error = Some(format!("Failed to archive sessions: {err}"));
+                    }
+                }
+                self.request_frame();
+            }
         }
         Ok(())
     }
@@ -769,6 +1036,7 @@ impl PickerState {
             let q = self.query.to_lowercase();
             self.filtered_rows = base_iter.filter(|r| r.matches_query(&q)).cloned().collect();
         }
+        self.group_duplicate_rows();
         if self.selected >= self.filtered_rows.len() {
             self.selected = self.filtered_rows.len().saturating_sub(1);
         }
@@ -779,6 +1047,38 @@ impl PickerState {
         self.request_frame();
     }
 
+    /// Collapses rows whose first prompts are near-identical (accidental
+    /// restarts) into the most recent one, recording the hidden duplicates so
+    /// the representative can show a badge and offer bulk archival.
+    fn group_duplicate_rows(&mut self) {
+        self.duplicate_groups.clear();
+        let mut deduped: Vec<Row> = Vec::with_capacity(self.filtered_rows.len());
+        let mut key_to_index: HashMap<String, usize> = HashMap::new();
+        for row in self.filtered_rows.drain(..) {
+            let key = row.thread_id.and(similarity_key(&row.preview));
+            match key {
+                Some(key) => match key_to_index.get(&key) {
+                    Some(&index) => {
+                        if let (Some(representative), Some(duplicate)) =
+                            (deduped[index].thread_id, row.thread_id)
+                        {
+                            self.duplicate_groups
+                                .entry(representative)
+                                .or_default()
+                                .push(duplicate);
+                        }
+                    }
+                    None => {
+                        key_to_index.insert(key, deduped.len());
+                        deduped.push(row);
+                    }
+                },
+                None => deduped.push(row),
+            }
+        }
+        self.filtered_rows = deduped;
+    }
+
     fn row_matches_filter(&self, row: &Row) -> bool {
         if self.show_all {
             return true;
@@ -1058,25 +1358,39 @@ fn draw_picker(tui: &mut Tui, state: &PickerState) -> std::io::Result<()> {
 
         // Hint line
         let action_label = state.action.action_label();
-        let hint_line: Line = vec![
-            key_hint::plain(KeyCode::Enter).into(),
-            format!(" to {action_label} ").dim(),
-            "    ".dim(),
-            key_hint::plain(KeyCode::Esc).into(),
-            " to start new ".dim(),
-            "    ".dim(),
-            key_hint::ctrl(KeyCode::Char('c')).into(),
-            " to quit ".dim(),
-            "    ".dim(),
-            key_hint::plain(KeyCode::Tab).into(),
-            " to toggle sort ".dim(),
-            "    ".dim(),
-            key_hint::plain(KeyCode::Up).into(),
-            "/".dim(),
-            key_hint::plain(KeyCode::Down).into(),
-            " to browse".dim(),
-        ]
-        .into();
+        let hint_line: Line = if state.rename.is_some() {
+            vec![
+                key_hint::plain(KeyCode::Enter).into(),
+                " to save ".dim(),
+                "    ".dim(),
+                key_hint::plain(KeyCode::Esc).into(),
+                " to cancel".dim(),
+            ]
+            .into()
+        } else {
+            vec![
+                key_hint::plain(KeyCode::Enter).into(),
+                format!(" to {action_label} ").dim(),
+                "    ".dim(),
+                key_hint::plain(KeyCode::Esc).into(),
+                " to start new ".dim(),
+                "    ".dim(),
+                key_hint::ctrl(KeyCode::Char('c')).into(),
+                " to quit ".dim(),
+                "    ".dim(),
+                key_hint::plain(KeyCode::Tab).into(),
+                " to toggle sort ".dim(),
+                "    ".dim(),
+                key_hint::ctrl(KeyCode::Char('r')).into(),
+                " to rename ".dim(),
+                "    ".dim(),
+                key_hint::plain(KeyCode::Up).into(),
+                "/".dim(),
+                key_hint::plain(KeyCode::Down).into(),
+                " to browse".dim(),
+            ]
+            .into()
+        };
         frame.render_widget_ref(hint_line, hint);
     })
 }
@@ -1085,6 +1399,12 @@ fn search_line(state: &PickerState) -> Line<'_> {
     if let Some(error) = state.inline_error.as_deref() {
         return Line::from(error.red());
     }
+    if let Some(notice) = state.inline_notice.as_deref() {
+        return Line::from(notice.to_string().dim());
+    }
+    if let Some(rename) = state.rename.as_ref() {
+        return Line::from(format!("Rename: {}", rename.buffer));
+    }
     if state.query.is_empty() {
         return Line::from("Type to search".dim());
     }
@@ -1188,6 +1508,13 @@ fn render_list(
         if add_leading_gap {
             preview_width = preview_width.saturating_sub(2);
         }
+        let similar_badge = row
+            .thread_id
+            .and_then(|id| state.duplicate_groups.get(&id))
+            .map(|duplicates| format!("  {} similar (ctrl + d to archive)", duplicates.len()));
+        if let Some(badge) = similar_badge.as_deref() {
+            preview_width = preview_width.saturating_sub(UnicodeWidthStr::width(badge));
+        }
         let preview = truncate_text(row.display_preview(), preview_width);
         let mut spans: Vec<Span> = vec![marker];
         if let Some(created) = created_span {
@@ -1210,8 +1537,14 @@ fn render_list(
             spans.push("  ".into());
         }
         spans.push(preview.into());
+        if let Some(badge) = similar_badge {
+            spans.push(badge.dim());
+        }
 
-        let line: Line = spans.into();
+        let mut line: Line = spans.into();
+        if is_sel && let Some(bg) = crate::style::selection_bg() {
+            line.style = line.style.bg(bg);
+        }
         let rect = Rect::new(area.x, y, area.width, 1);
         frame.render_widget_ref(line, rect);
         y = y.saturating_add(1);
@@ -1597,6 +1930,8 @@ mod tests {
         let state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::Any,
             /*show_all*/ false,
             /*filter_cwd*/ None,
@@ -1627,6 +1962,8 @@ mod tests {
         let mut state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::MatchDefault(String::from("openai")),
             /*show_all*/ true,
             /*filter_cwd*/ None,
@@ -1705,6 +2042,8 @@ mod tests {
         let mut state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::MatchDefault(String::from("openai")),
             /*show_all*/ true,
             /*filter_cwd*/ None,
@@ -1737,6 +2076,8 @@ mod tests {
         let mut state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::MatchDefault(String::from("openai")),
             /*show_all*/ true,
             /*filter_cwd*/ None,
@@ -1797,6 +2138,8 @@ mod tests {
         let mut state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::MatchDefault(String::from("openai")),
             /*show_all*/ true,
             /*filter_cwd*/ None,
@@ -1875,6 +2218,8 @@ mod tests {
         let mut state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::MatchDefault(String::from("openai")),
             /*show_all*/ true,
             /*filter_cwd*/ None,
@@ -1904,6 +2249,8 @@ mod tests {
         let mut state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::MatchDefault(String::from("openai")),
             /*show_all*/ true,
             /*filter_cwd*/ None,
@@ -1951,6 +2298,8 @@ mod tests {
         let mut state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::MatchDefault(String::from("openai")),
             /*show_all*/ true,
             /*filter_cwd*/ None,
@@ -1990,6 +2339,8 @@ mod tests {
         let mut state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::MatchDefault(String::from("openai")),
             /*show_all*/ true,
             /*filter_cwd*/ None,
@@ -2059,6 +2410,8 @@ mod tests {
         let mut state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::MatchDefault(String::from("openai")),
             /*show_all*/ true,
             /*filter_cwd*/ None,
@@ -2106,6 +2459,8 @@ mod tests {
         let mut state = PickerState::new(
             FrameRequester::test_dummy(),
             loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
             ProviderFilter::MatchDefault(String::from("openai")),
             /*show_all*/ true,
             /*filter_cwd*/ None,
@@ -2215,4 +2570,55 @@ mod tests {
         assert!(!state.search_state.is_active());
         assert!(state.pagination.reached_scan_cap);
     }
+
+    #[test]
+    fn similarity_key_ignores_case_punctuation_and_spacing() {
+        assert_eq!(
+            similarity_key("Fix the   resume picker!"),
+            similarity_key("fix the resume picker")
+        );
+        assert_eq!(similarity_key("  ...  "), None);
+    }
+
+    #[test]
+    fn near_identical_first_prompts_collapse_into_one_row() {
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state = PickerState::new(
+            FrameRequester::test_dummy(),
+            loader,
+            /*renamer*/ None,
+            /*archiver*/ None,
+            ProviderFilter::Any,
+            /*show_all*/ true,
+            /*filter_cwd*/ None,
+            SessionPickerAction::Resume,
+        );
+        let representative_id = ThreadId::new();
+        let duplicate_id = ThreadId::new();
+        let mut row = Row {
+            path: None,
+            preview: String::from("Fix the resume picker"),
+            thread_id: Some(representative_id),
+            thread_name: None,
+            created_at: None,
+            updated_at: None,
+            cwd: None,
+            git_branch: None,
+        };
+        state.all_rows.push(row.clone());
+        row.thread_id = Some(duplicate_id);
+        row.preview = String::from("fix the resume picker!");
+        state.all_rows.push(row.clone());
+        row.thread_id = Some(ThreadId::new());
+        row.preview = String::from("something else entirely");
+        state.all_rows.push(row);
+
+        state.apply_filter();
+
+        assert_eq!(state.filtered_rows.len(), 2);
+        assert_eq!(
+            state.duplicate_groups.get(&representative_id),
+            Some(&vec![duplicate_id])
+        );
+    }
 }
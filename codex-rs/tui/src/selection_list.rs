@@ -27,7 +27,11 @@ pub(crate) fn selection_option_row_with_dim(
         format!("  {}. ", index + 1)
     };
     let style = if is_selected {
-        Style::default().cyan()
+        let style = Style::default().cyan();
+        match crate::style::selection_bg() {
+            Some(bg) => style.bg(bg),
+            None => style,
+        }
     } else if dim {
         Style::default().dim()
     } else {
@@ -20,6 +20,8 @@ pub enum SlashCommand {
     ElevateSandbox,
     #[strum(serialize = "sandbox-add-read-dir")]
     SandboxReadRoot,
+    Readonly,
+    Auto,
     Experimental,
     Memories,
     Skills,
@@ -34,15 +36,28 @@ pub enum SlashCommand {
     Collab,
     Agent,
     Side,
+    Batch,
+    Pin,
     // Undo,
     Copy,
+    Citations,
     Diff,
+    SuggestCommit,
     Mention,
     Status,
+    Limits,
+    Env,
+    System,
+    Details,
+    Help,
+    Tips,
+    Stats,
     DebugConfig,
+    DebugUi,
     Title,
     Statusline,
     Theme,
+    Keybindings,
     Mcp,
     Apps,
     Plugins,
@@ -84,14 +99,29 @@ impl SlashCommand {
             // SlashCommand::Undo => "ask Codex to undo a turn",
             SlashCommand::Quit | SlashCommand::Exit => "exit Codex",
             SlashCommand::Copy => "copy last response as markdown",
+            SlashCommand::Citations => "preview sources cited by the last response",
             SlashCommand::Diff => "show git diff (including untracked files)",
+            SlashCommand::SuggestCommit => {
+                "suggest a conventional commit message for the current diff"
+            }
             SlashCommand::Mention => "mention a file",
             SlashCommand::Skills => "use skills to improve how Codex performs specific tasks",
             SlashCommand::Status => "show current session configuration and token usage",
+            SlashCommand::Limits => "show remaining session budget from [limits]",
+            SlashCommand::Env => {
+                "show or change env vars for commands: /env [set K=V | unset K | secret K]"
+            }
+            SlashCommand::System => "show how the final system prompt is composed",
+            SlashCommand::Details => "show the last turn's request parameters",
+            SlashCommand::Help => "browse help topics: /help <topic>",
+            SlashCommand::Tips => "show tip status; /tips reset shows every tip again",
+            SlashCommand::Stats => "show local usage stats; /stats export writes JSON",
             SlashCommand::DebugConfig => "show config layers and requirement sources for debugging",
+            SlashCommand::DebugUi => "show terminal size, color and render timing diagnostics",
             SlashCommand::Title => "configure which items appear in the terminal title",
             SlashCommand::Statusline => "configure which items appear in the status line",
             SlashCommand::Theme => "choose a syntax highlighting theme",
+            SlashCommand::Keybindings => "view and rebind keyboard shortcuts",
             SlashCommand::Ps => "list background terminals",
             SlashCommand::Stop => "stop all background terminals",
             SlashCommand::MemoryDrop => "DO NOT USE",
@@ -107,12 +137,20 @@ impl SlashCommand {
             SlashCommand::Collab => "change collaboration mode (experimental)",
             SlashCommand::Agent | SlashCommand::MultiAgents => "switch the active agent thread",
             SlashCommand::Side => "start a side conversation in an ephemeral fork",
+            SlashCommand::Batch => {
+                "run tasks from a file sequentially: /batch <file> | continue | stop"
+            }
+            SlashCommand::Pin => "pin a file or message to every turn: /pin [<file>|last]",
             SlashCommand::Approvals => "choose what Codex is allowed to do",
             SlashCommand::Permissions => "choose what Codex is allowed to do",
             SlashCommand::ElevateSandbox => "set up elevated agent sandbox",
             SlashCommand::SandboxReadRoot => {
                 "let sandbox read a directory: /sandbox-add-read-dir <absolute_path>"
             }
+            SlashCommand::Readonly => "toggle read-only exploration mode (blocks file changes)",
+            SlashCommand::Auto => {
+                "auto-approve commands for a limited time: /auto [<duration>|off]"
+            }
             SlashCommand::Experimental => "toggle experimental features",
             SlashCommand::Memories => "configure memory use and generation",
             SlashCommand::Mcp => "list configured MCP tools; use /mcp verbose for details",
@@ -142,6 +180,13 @@ impl SlashCommand {
                 | SlashCommand::Side
                 | SlashCommand::Resume
                 | SlashCommand::SandboxReadRoot
+                | SlashCommand::Help
+                | SlashCommand::Tips
+                | SlashCommand::Stats
+                | SlashCommand::Batch
+                | SlashCommand::Pin
+                | SlashCommand::Env
+                | SlashCommand::Auto
         )
     }
 
@@ -169,6 +214,8 @@ impl SlashCommand {
             | SlashCommand::Permissions
             | SlashCommand::ElevateSandbox
             | SlashCommand::SandboxReadRoot
+            | SlashCommand::Readonly
+            | SlashCommand::Auto
             | SlashCommand::Experimental
             | SlashCommand::Memories
             | SlashCommand::Review
@@ -178,12 +225,22 @@ impl SlashCommand {
             | SlashCommand::MemoryDrop
             | SlashCommand::MemoryUpdate => false,
             SlashCommand::Diff
+            | SlashCommand::SuggestCommit
             | SlashCommand::Copy
+            | SlashCommand::Citations
             | SlashCommand::Rename
             | SlashCommand::Mention
             | SlashCommand::Skills
             | SlashCommand::Status
+            | SlashCommand::Limits
+            | SlashCommand::Env
+            | SlashCommand::System
+            | SlashCommand::Details
+            | SlashCommand::Help
+            | SlashCommand::Tips
+            | SlashCommand::Stats
             | SlashCommand::DebugConfig
+            | SlashCommand::DebugUi
             | SlashCommand::Ps
             | SlashCommand::Stop
             | SlashCommand::Mcp
@@ -193,6 +250,8 @@ impl SlashCommand {
             | SlashCommand::Quit
             | SlashCommand::Exit
             | SlashCommand::Side => true,
+            SlashCommand::Batch => true,
+            SlashCommand::Pin => true,
             SlashCommand::Rollout => true,
             SlashCommand::TestApproval => true,
             SlashCommand::Realtime => true,
@@ -202,6 +261,7 @@ impl SlashCommand {
             SlashCommand::Statusline => false,
             SlashCommand::Theme => false,
             SlashCommand::Title => false,
+            SlashCommand::Keybindings => false,
         }
     }
 
@@ -210,6 +270,8 @@ impl SlashCommand {
             SlashCommand::SandboxReadRoot => cfg!(target_os = "windows"),
             SlashCommand::Copy => !cfg!(target_os = "android"),
             SlashCommand::Rollout | SlashCommand::TestApproval => cfg!(debug_assertions),
+            // Hidden from the popup; typing `/debug-ui` still dispatches it.
+            SlashCommand::DebugUi => false,
             _ => true,
         }
     }
@@ -0,0 +1,111 @@
+//! Configurable status-row spinner styles.
+//!
+//! The default spinner is the shimmering bullet from `exec_cell`; `[tui]
+//! spinner` selects a frame-based alternative (`dots`, `line`, `moon`, a
+//! custom frame list, or `none` for a static reduced-motion marker).
+
+use std::time::Duration;
+
+use codex_config::types::SpinnerToml;
+
+/// How long each frame of a frame-based spinner stays on screen.
+const FRAME_INTERVAL_MS: u128 = 120;
+
+const DOTS_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const LINE_FRAMES: [&str; 4] = ["-", "\\", "|", "/"];
+const MOON_FRAMES: [&str; 8] = ["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"];
+
+/// A frame-based spinner resolved from `[tui] spinner`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SpinnerStyle {
+    frames: Vec<String>,
+}
+
+impl SpinnerStyle {
+    /// Resolves the configured spinner. `None` keeps the built-in shimmer
+    /// bullet; unknown names and empty frame lists also fall back to it.
+    pub(crate) fn from_config(spinner: Option<&SpinnerToml>) -> Option<Self> {
+        let ascii_only = crate::glyphs::ascii_only();
+        let frames: Vec<String> = match spinner? {
+            SpinnerToml::Named(name) => match name.as_str() {
+                // In ASCII-only mode the braille and moon frames degrade to
+                // the line spinner rather than rendering as tofu.
+                "dots" | "moon" if ascii_only => LINE_FRAMES.map(str::to_string).to_vec(),
+                "dots" => DOTS_FRAMES.map(str::to_string).to_vec(),
+                "line" => LINE_FRAMES.map(str::to_string).to_vec(),
+                "moon" => MOON_FRAMES.map(str::to_string).to_vec(),
+                // Reduced motion: a single frame that never animates.
+                "none" => vec![crate::glyphs::glyph("•", "*").to_string()],
+                other => {
+                    tracing::warn!("unknown tui.spinner name: {other}");
+                    return None;
+                }
+            },
+            SpinnerToml::Frames(frames) => frames.clone(),
+        };
+        if frames.is_empty() {
+            return None;
+        }
+        Some(Self { frames })
+    }
+
+    /// Whether the spinner has a single frame and never animates.
+    pub(crate) fn is_static(&self) -> bool {
+        self.frames.len() <= 1
+    }
+
+    /// Returns the frame to show `elapsed` after the spinner started.
+    pub(crate) fn frame_at(&self, elapsed: Duration) -> &str {
+        let index = (elapsed.as_millis() / FRAME_INTERVAL_MS) as usize % self.frames.len();
+        &self.frames[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn named_spinner_cycles_through_its_frames() {
+        let style = SpinnerStyle::from_config(Some(&SpinnerToml::Named("line".to_string())))
+            .expect("built-in spinner");
+        assert_eq!(style.frame_at(Duration::ZERO), "-");
+        assert_eq!(style.frame_at(Duration::from_millis(120)), "\\");
+        // Wraps around after the last frame.
+        assert_eq!(style.frame_at(Duration::from_millis(480)), "-");
+        assert!(!style.is_static());
+    }
+
+    #[test]
+    fn none_spinner_is_static() {
+        let style = SpinnerStyle::from_config(Some(&SpinnerToml::Named("none".to_string())))
+            .expect("built-in spinner");
+        assert!(style.is_static());
+        assert_eq!(style.frame_at(Duration::from_secs(5)), "•");
+    }
+
+    #[test]
+    fn custom_frames_are_used_verbatim() {
+        let style = SpinnerStyle::from_config(Some(&SpinnerToml::Frames(vec![
+            ".".to_string(),
+            "o".to_string(),
+            "O".to_string(),
+        ])))
+        .expect("custom spinner");
+        assert_eq!(style.frame_at(Duration::from_millis(240)), "O");
+    }
+
+    #[test]
+    fn unknown_names_and_empty_frame_lists_fall_back() {
+        assert_eq!(
+            SpinnerStyle::from_config(Some(&SpinnerToml::Named("sparkles".to_string()))),
+            None
+        );
+        assert_eq!(
+            SpinnerStyle::from_config(Some(&SpinnerToml::Frames(Vec::new()))),
+            None
+        );
+        assert_eq!(SpinnerStyle::from_config(None), None);
+    }
+}
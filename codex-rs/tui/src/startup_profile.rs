@@ -0,0 +1,106 @@
+//! Startup phase timing support for `--profile-startup`.
+//!
+//! Recording is disabled unless [`enable`] is called before the phases run, so
+//! the guards compile down to an atomic load on the normal startup path. Each
+//! phase is recorded once with its wall-clock duration; the collected report
+//! is rendered into the transcript after the UI becomes interactive.
+
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static PHASES: OnceLock<Mutex<Vec<(&'static str, Duration)>>> = OnceLock::new();
+
+fn phases() -> &'static Mutex<Vec<(&'static str, Duration)>> {
+    PHASES.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Turns on phase recording. Must be called before the phases being measured.
+pub(crate) fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub(crate) fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Times a phase while `f` runs and records it when profiling is enabled.
+///
+/// The closure always runs; only the bookkeeping is conditional.
+pub(crate) fn phase<T>(label: &'static str, f: impl FnOnce() -> T) -> T {
+    if !is_enabled() {
+        return f();
+    }
+    let started = Instant::now();
+    let value = f();
+    record(label, started.elapsed());
+    value
+}
+
+/// Records an externally measured phase (for async or detached work).
+pub(crate) fn record(label: &'static str, duration: Duration) {
+    if !is_enabled() {
+        return;
+    }
+    tracing::info!("startup phase {label}: {duration:?}");
+    phases()
+        .lock()
+        .expect("phase lock poisoned")
+        .push((label, duration));
+}
+
+/// Renders the collected report as transcript lines, or `None` when profiling
+/// is disabled or nothing was recorded.
+pub(crate) fn report_lines() -> Option<Vec<Line<'static>>> {
+    if !is_enabled() {
+        return None;
+    }
+    let phases = phases().lock().expect("phase lock poisoned");
+    if phases.is_empty() {
+        return None;
+    }
+    let total: Duration = phases.iter().map(|(_, d)| *d).sum();
+    let mut lines = vec![Line::from("Startup profile".bold())];
+    for (label, duration) in phases.iter() {
+        lines.push(Line::from(vec![
+            format!("  {label}: ").into(),
+            format!("{:.1}ms", duration.as_secs_f64() * 1000.0).dim(),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        "  total measured: ".into(),
+        format!("{:.1}ms", total.as_secs_f64() * 1000.0).dim(),
+    ]));
+    Some(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn report_includes_recorded_phases_once_enabled() {
+        enable();
+        record("test phase", Duration::from_millis(12));
+        let lines = report_lines().expect("report");
+        let rendered: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect();
+        assert_eq!(rendered[0], "Startup profile");
+        assert!(rendered.iter().any(|l| l.contains("test phase: 12.0ms")));
+    }
+}
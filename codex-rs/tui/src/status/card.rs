@@ -55,6 +55,7 @@ struct StatusContextWindowData {
 pub(crate) struct StatusTokenUsageData {
     total: i64,
     input: i64,
+    cached: i64,
     output: i64,
     context_window: Option<StatusContextWindowData>,
 }
@@ -323,6 +324,7 @@ impl StatusHistoryCell {
         let token_usage = StatusTokenUsageData {
             total: total_usage.blended_total(),
             input: total_usage.non_cached_input(),
+            cached: total_usage.cached_input(),
             output: total_usage.output_tokens,
             context_window,
         };
@@ -362,17 +364,26 @@ impl StatusHistoryCell {
         let input_fmt = format_tokens_compact(self.token_usage.input);
         let output_fmt = format_tokens_compact(self.token_usage.output);
 
-        vec![
+        let mut spans = vec![
             Span::from(total_fmt),
             Span::from(" total "),
             Span::from(" (").dim(),
             Span::from(input_fmt).dim(),
             Span::from(" input").dim(),
+        ];
+        if self.token_usage.cached > 0 {
+            let cached_fmt = format_tokens_compact(self.token_usage.cached);
+            spans.push(Span::from(" (").dim());
+            spans.push(Span::from(cached_fmt).dim());
+            spans.push(Span::from(" cached)").dim());
+        }
+        spans.extend([
             Span::from(" + ").dim(),
             Span::from(output_fmt).dim(),
             Span::from(" output").dim(),
             Span::from(")").dim(),
-        ]
+        ]);
+        spans
     }
 
     fn context_window_spans(&self) -> Option<Vec<Span<'static>>> {
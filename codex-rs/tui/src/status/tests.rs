@@ -551,7 +551,7 @@ async fn status_snapshot_hides_when_has_no_credits_flag() {
 }
 
 #[tokio::test]
-async fn status_card_token_usage_excludes_cached_tokens() {
+async fn status_card_token_usage_shows_cached_input_savings() {
     let temp_home = TempDir::new().expect("temp home");
     let mut config = test_config(&temp_home).await;
     config.model = Some("gpt-5.1-codex-max".to_string());
@@ -591,8 +591,10 @@ async fn status_card_token_usage_excludes_cached_tokens() {
     let rendered = render_lines(&composite.display_lines(/*width*/ 120));
 
     assert!(
-        rendered.iter().all(|line| !line.contains("cached")),
-        "cached tokens should not be displayed, got: {rendered:?}"
+        rendered
+            .iter()
+            .any(|line| line.contains("1K input (200 cached) + 900 output")),
+        "cached input tokens should be displayed next to input, got: {rendered:?}"
     );
 }
 
@@ -24,6 +24,7 @@ use crate::key_hint;
 use crate::line_truncation::truncate_line_with_ellipsis_if_overflow;
 use crate::render::renderable::Renderable;
 use crate::shimmer::shimmer_spans;
+use crate::spinner::SpinnerStyle;
 use crate::text_formatting::capitalize_first;
 use crate::tui::FrameRequester;
 use crate::wrapping::RtOptions;
@@ -54,6 +55,10 @@ pub(crate) struct StatusIndicatorWidget {
     app_event_tx: AppEventSender,
     frame_requester: FrameRequester,
     animations_enabled: bool,
+    /// Configured frame-based spinner; `None` uses the shimmer bullet.
+    spinner_style: Option<SpinnerStyle>,
+    /// How long a turn must run before the interrupt hint appears.
+    interrupt_hint_after: Duration,
 }
 
 // Format elapsed seconds into a compact human-friendly form used by the status line.
@@ -78,6 +83,7 @@ impl StatusIndicatorWidget {
         app_event_tx: AppEventSender,
         frame_requester: FrameRequester,
         animations_enabled: bool,
+        spinner_style: Option<SpinnerStyle>,
     ) -> Self {
         Self {
             header: String::from("Working"),
@@ -92,6 +98,8 @@ impl StatusIndicatorWidget {
             app_event_tx,
             frame_requester,
             animations_enabled,
+            spinner_style,
+            interrupt_hint_after: Duration::ZERO,
         }
     }
 
@@ -99,6 +107,12 @@ impl StatusIndicatorWidget {
         self.app_event_tx.interrupt();
     }
 
+    /// Sets how long a turn must run before the interrupt hint appears next
+    /// to the elapsed time.
+    pub(crate) fn set_interrupt_hint_delay(&mut self, delay: Duration) {
+        self.interrupt_hint_after = delay;
+    }
+
     /// Update the animated header label (left of the brackets).
     pub(crate) fn update_header(&mut self, header: String) {
         self.header = header;
@@ -247,7 +261,28 @@ impl Renderable for StatusIndicatorWidget {
         let pretty_elapsed = fmt_elapsed_compact(elapsed_duration.as_secs());
 
         let mut spans = Vec::with_capacity(5);
-        spans.push(spinner(Some(self.last_resume_at), self.animations_enabled));
+        // Themes can pin the spinner color via the `status_spinner` palette
+        // slot; without it, frame-based spinners keep the default styling.
+        let spinner_fg = crate::style::status_spinner_fg();
+        match &self.spinner_style {
+            Some(style) if self.animations_enabled && !style.is_static() => {
+                let frame = style
+                    .frame_at(now.duration_since(self.last_resume_at))
+                    .to_string();
+                spans.push(match spinner_fg {
+                    Some(fg) => frame.fg(fg),
+                    None => frame.into(),
+                });
+            }
+            Some(style) => {
+                let frame = style.frame_at(Duration::ZERO).to_string();
+                spans.push(match spinner_fg {
+                    Some(fg) => frame.fg(fg),
+                    None => frame.dim(),
+                });
+            }
+            None => spans.push(spinner(Some(self.last_resume_at), self.animations_enabled)),
+        }
         spans.push(" ".into());
         if self.animations_enabled {
             spans.extend(shimmer_spans(&self.header));
@@ -255,20 +290,21 @@ impl Renderable for StatusIndicatorWidget {
             spans.push(self.header.clone().into());
         }
         spans.push(" ".into());
-        if self.show_interrupt_hint {
+        let dim_style = crate::style::dim_text_style();
+        if self.show_interrupt_hint && elapsed_duration >= self.interrupt_hint_after {
             spans.extend(vec![
-                format!("({pretty_elapsed} • ").dim(),
+                Span::styled(format!("({pretty_elapsed} • "), dim_style),
                 key_hint::plain(KeyCode::Esc).into(),
-                " to interrupt)".dim(),
+                Span::styled(" to interrupt)", dim_style),
             ]);
         } else {
-            spans.push(format!("({pretty_elapsed})").dim());
+            spans.push(Span::styled(format!("({pretty_elapsed})"), dim_style));
         }
         if let Some(message) = &self.inline_message {
             // Keep optional context after elapsed/interrupt text so that core
             // interrupt affordances stay in a fixed visual location.
-            spans.push(" · ".dim());
-            spans.push(message.clone().dim());
+            spans.push(Span::styled(" · ", dim_style));
+            spans.push(Span::styled(message.clone(), dim_style));
         }
 
         let mut lines = Vec::new();
@@ -322,6 +358,7 @@ mod tests {
             tx,
             crate::tui::FrameRequester::test_dummy(),
             /*animations_enabled*/ true,
+            /*spinner_style*/ None,
         );
 
         // Render into a fixed-size test terminal and snapshot the backend.
@@ -340,6 +377,7 @@ mod tests {
             tx,
             crate::tui::FrameRequester::test_dummy(),
             /*animations_enabled*/ true,
+            /*spinner_style*/ None,
         );
 
         // Render into a fixed-size test terminal and snapshot the backend.
@@ -358,6 +396,7 @@ mod tests {
             tx,
             crate::tui::FrameRequester::test_dummy(),
             /*animations_enabled*/ false,
+            /*spinner_style*/ None,
         );
         w.update_details(
             Some("A man a plan a canal panama".to_string()),
@@ -387,6 +426,7 @@ mod tests {
             tx,
             crate::tui::FrameRequester::test_dummy(),
             /*animations_enabled*/ true,
+            /*spinner_style*/ None,
         );
 
         let baseline = Instant::now();
@@ -412,6 +452,7 @@ mod tests {
             tx,
             crate::tui::FrameRequester::test_dummy(),
             /*animations_enabled*/ true,
+            /*spinner_style*/ None,
         );
         w.update_details(
             Some("abcd abcd abcd abcd".to_string()),
@@ -436,6 +477,7 @@ mod tests {
             tx,
             crate::tui::FrameRequester::test_dummy(),
             /*animations_enabled*/ true,
+            /*spinner_style*/ None,
         );
         w.update_details(
             Some("cargo test -p codex-core and then cargo test -p codex-tui".to_string()),
@@ -0,0 +1,175 @@
+//! Captures piped stdin as context for the first prompt.
+//!
+//! `somecmd | codex "explain this output"` should not require pasting the
+//! command output by hand: when stdin is a pipe we read it up front, wrap it
+//! in a `<stdin>` block appended to the initial prompt (mirroring the format
+//! `codex exec` uses), and then rebind fd 0 to the controlling terminal so
+//! the TUI can still read key events. Binary or oversized input is dropped
+//! with a note rather than stuffed into the prompt.
+
+#[cfg(unix)]
+use std::io::Read;
+
+/// Piped stdin larger than this is dropped instead of attached; huge inputs
+/// would blow the first turn's context without the user ever seeing them.
+#[cfg(any(unix, test))]
+pub(crate) const MAX_STDIN_ATTACHMENT_BYTES: usize = 1024 * 1024;
+
+/// What a piped-stdin read turned out to be.
+#[cfg(any(unix, test))]
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum StdinCapture {
+    /// Decoded text worth attaching to the prompt.
+    Attachment(String),
+    /// Nothing but whitespace; attach nothing and say nothing.
+    Empty,
+    /// NUL bytes or invalid UTF-8: almost certainly not prompt material.
+    Binary,
+    /// More than [`MAX_STDIN_ATTACHMENT_BYTES`] of input.
+    TooLarge,
+}
+
+/// If stdin is a pipe, folds its content into `prompt` as a `<stdin>` block
+/// and points fd 0 back at the controlling terminal. Must run before
+/// [`crate::tui::init`], which refuses a non-terminal stdin.
+#[cfg(unix)]
+#[allow(clippy::print_stderr)]
+pub(crate) fn attach_piped_stdin(prompt: &mut Option<String>) {
+    use std::io::IsTerminal;
+    use std::os::fd::AsRawFd;
+
+    if std::io::stdin().is_terminal() {
+        return;
+    }
+    // Without a controlling terminal there is nothing to hand key input to,
+    // so leave stdin alone and let `tui::init()` report its usual error.
+    let Ok(tty) = std::fs::File::open("/dev/tty") else {
+        return;
+    };
+
+    let capture = capture_stdin(std::io::stdin().lock());
+
+    // SAFETY: dup2 onto fd 0 only replaces the exhausted pipe; the original
+    // descriptor needs no restoration because the pipe has been fully read.
+    unsafe {
+        libc::dup2(tty.as_raw_fd(), libc::STDIN_FILENO);
+    }
+    drop(tty);
+
+    match capture {
+        StdinCapture::Attachment(text) => {
+            *prompt = Some(prompt_with_stdin_context(prompt.take(), &text));
+        }
+        StdinCapture::Empty => {}
+        StdinCapture::Binary => {
+            eprintln!("Ignoring piped stdin: input looks binary (not valid UTF-8 text).");
+        }
+        StdinCapture::TooLarge => {
+            eprintln!("Ignoring piped stdin: input exceeds {MAX_STDIN_ATTACHMENT_BYTES} bytes.");
+        }
+    }
+}
+
+#[cfg(not(unix))]
+pub(crate) fn attach_piped_stdin(_prompt: &mut Option<String>) {}
+
+/// Reads at most one byte past the cap so oversized input is detected
+/// without buffering all of it.
+#[cfg(unix)]
+fn capture_stdin(reader: impl Read) -> StdinCapture {
+    let mut bytes = Vec::new();
+    let limit = (MAX_STDIN_ATTACHMENT_BYTES as u64).saturating_add(1);
+    let mut capped = reader.take(limit);
+    if capped.read_to_end(&mut bytes).is_err() {
+        // A broken pipe mid-read leaves nothing trustworthy to attach.
+        return StdinCapture::Empty;
+    }
+    // Drain whatever remains past the cap so the writing side never blocks
+    // on a full pipe after we rebind fd 0.
+    let oversized = bytes.len() > MAX_STDIN_ATTACHMENT_BYTES;
+    if oversized {
+        let _ = std::io::copy(&mut capped.into_inner(), &mut std::io::sink());
+    }
+    classify_stdin_bytes(&bytes, oversized)
+}
+
+#[cfg(any(unix, test))]
+fn classify_stdin_bytes(bytes: &[u8], oversized: bool) -> StdinCapture {
+    if oversized {
+        return StdinCapture::TooLarge;
+    }
+    if bytes.contains(&0) {
+        return StdinCapture::Binary;
+    }
+    let Ok(text) = std::str::from_utf8(bytes) else {
+        return StdinCapture::Binary;
+    };
+    if text.trim().is_empty() {
+        StdinCapture::Empty
+    } else {
+        StdinCapture::Attachment(text.to_string())
+    }
+}
+
+/// Wraps the captured text in a `<stdin>` block below the prompt, matching
+/// the format `codex exec` uses for the same situation. Without a prompt the
+/// block alone seeds the composer.
+#[cfg(any(unix, test))]
+fn prompt_with_stdin_context(prompt: Option<String>, stdin_text: &str) -> String {
+    let mut combined = match prompt {
+        Some(prompt) => format!("{prompt}\n\n<stdin>\n{stdin_text}"),
+        None => format!("<stdin>\n{stdin_text}"),
+    };
+    if !stdin_text.ends_with('\n') {
+        combined.push('\n');
+    }
+    combined.push_str("</stdin>");
+    combined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn utf8_input_becomes_an_attachment() {
+        assert_eq!(
+            classify_stdin_bytes(b"error: it broke\n", false),
+            StdinCapture::Attachment("error: it broke\n".to_string())
+        );
+    }
+
+    #[test]
+    fn binary_and_oversized_input_are_rejected() {
+        assert_eq!(
+            classify_stdin_bytes(b"\x00\x01\x02", false),
+            StdinCapture::Binary
+        );
+        assert_eq!(
+            classify_stdin_bytes(&[0xFF, 0xFE, 0x41], false),
+            StdinCapture::Binary
+        );
+        assert_eq!(classify_stdin_bytes(b"fine", true), StdinCapture::TooLarge);
+    }
+
+    #[test]
+    fn whitespace_only_input_attaches_nothing() {
+        assert_eq!(
+            classify_stdin_bytes(b"  \n\t\n", false),
+            StdinCapture::Empty
+        );
+    }
+
+    #[test]
+    fn stdin_block_is_appended_below_the_prompt() {
+        assert_eq!(
+            prompt_with_stdin_context(Some("explain this output".to_string()), "line one\n"),
+            "explain this output\n\n<stdin>\nline one\n</stdin>"
+        );
+        assert_eq!(
+            prompt_with_stdin_context(None, "no trailing newline"),
+            "<stdin>\nno trailing newline\n</stdin>"
+        );
+    }
+}
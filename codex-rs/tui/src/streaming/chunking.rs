@@ -265,10 +265,14 @@ impl AdaptiveChunkingPolicy {
 ///
 /// Either depth or age pressure is sufficient to trigger catch-up.
 fn should_enter_catch_up(snapshot: QueueSnapshot) -> bool {
-    snapshot.queued_lines >= ENTER_QUEUE_DEPTH_LINES
-        || snapshot
-            .oldest_age
-            .is_some_and(|oldest| oldest >= ENTER_OLDEST_AGE)
+    // High-latency links prefer fewer, larger drains: halve the enter
+    // thresholds so catch-up batching kicks in sooner.
+    let (depth, age) = if crate::remote_session::low_bandwidth_mode() {
+        (ENTER_QUEUE_DEPTH_LINES / 2, ENTER_OLDEST_AGE / 2)
+    } else {
+        (ENTER_QUEUE_DEPTH_LINES, ENTER_OLDEST_AGE)
+    };
+    snapshot.queued_lines >= depth || snapshot.oldest_age.is_some_and(|oldest| oldest >= age)
 }
 
 /// Returns whether queue pressure is low enough to begin exit hysteresis.
@@ -3,6 +3,7 @@ use crate::color::is_light;
 use crate::terminal_palette::best_color;
 use crate::terminal_palette::default_bg;
 use ratatui::style::Color;
+use ratatui::style::Modifier;
 use ratatui::style::Style;
 
 pub fn user_message_style() -> Style {
@@ -14,7 +15,11 @@ pub fn proposed_plan_style() -> Style {
 }
 
 /// Returns the style for a user-authored message using the provided terminal background.
+/// Prefers the active theme's `user_message_bg` slot over the default tint.
 pub fn user_message_style_for(terminal_bg: Option<(u8, u8, u8)>) -> Style {
+    if let Some(rgb) = crate::render::highlight::theme_ui_color_rgbs().user_message_bg {
+        return Style::default().bg(best_color(rgb));
+    }
     match terminal_bg {
         Some(bg) => Style::default().bg(user_message_bg(bg)),
         None => Style::default(),
@@ -42,3 +47,72 @@ pub fn user_message_bg(terminal_bg: (u8, u8, u8)) -> Color {
 pub fn proposed_plan_bg(terminal_bg: (u8, u8, u8)) -> Color {
     user_message_bg(terminal_bg)
 }
+
+/// Style patch for the inactive transcript region while a modal surface
+/// (approval, picker, pager) has focus. Applied over the already-rendered
+/// buffer so the underlying cells keep their content — how DIM renders is up
+/// to the terminal theme.
+pub fn backdrop_dim_style() -> Style {
+    Style::default().add_modifier(Modifier::DIM)
+}
+
+/// Foreground for the composer's prompt chevron, when the active theme
+/// defines the `composer_border` palette slot.
+pub fn composer_border_fg() -> Option<Color> {
+    crate::render::highlight::theme_ui_color_rgbs()
+        .composer_border
+        .map(best_color)
+}
+
+/// Foreground for the status-row spinner, when the active theme defines the
+/// `status_spinner` palette slot.
+pub fn status_spinner_fg() -> Option<Color> {
+    crate::render::highlight::theme_ui_color_rgbs()
+        .status_spinner
+        .map(best_color)
+}
+
+/// Foreground for scroll-position chrome (the pager's bottom bar), when the
+/// active theme defines the `scrollbar` palette slot.
+pub fn scrollbar_fg() -> Option<Color> {
+    crate::render::highlight::theme_ui_color_rgbs()
+        .scrollbar
+        .map(best_color)
+}
+
+/// Accent foreground for tool-call prefixes (the `$` on commands, exploring
+/// titles), when the active theme defines the `tool_call_accent` slot.
+pub fn tool_call_accent_fg() -> Option<Color> {
+    crate::render::highlight::theme_ui_color_rgbs()
+        .tool_call_accent
+        .map(best_color)
+}
+
+/// Style for de-emphasized text. Uses the active theme's `dim_text` color
+/// when defined; otherwise falls back to the terminal's DIM attribute, whose
+/// rendering is up to the terminal theme.
+pub fn dim_text_style() -> Style {
+    match crate::render::highlight::theme_ui_color_rgbs().dim_text {
+        Some(rgb) => Style::default().fg(best_color(rgb)),
+        None => Style::default().add_modifier(Modifier::DIM),
+    }
+}
+
+/// Background for the selected row in list and popup components. Prefers the
+/// active syntax theme's selection color; otherwise tints the detected
+/// terminal background, so selection never depends on bold/color rendering
+/// alone. `None` when neither source is available.
+pub fn selection_bg() -> Option<Color> {
+    if let Some(rgb) = crate::render::highlight::theme_selection_bg() {
+        return Some(best_color(rgb));
+    }
+    let bg = default_bg()?;
+    // Slightly stronger tint than user_message_bg so the selected row stands
+    // out next to message backgrounds.
+    let (top, alpha) = if is_light(bg) {
+        ((0, 0, 0), 0.08)
+    } else {
+        ((255, 255, 255), 0.18)
+    };
+    Some(best_color(blend(top, bg, alpha)))
+}
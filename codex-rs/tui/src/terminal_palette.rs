@@ -1,5 +1,7 @@
 use crate::color::perceptual_distance;
+use codex_config::types::ColorMode;
 use ratatui::style::Color;
+use std::sync::OnceLock;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum StdoutColorLevel {
@@ -9,7 +11,43 @@ pub enum StdoutColorLevel {
     Unknown,
 }
 
+/// Forced color depth from `tui.color_mode`, for terminals that lie about
+/// truecolor support (or multiplexers that strip it). `Auto` defers to
+/// capability detection.
+static COLOR_MODE: OnceLock<ColorMode> = OnceLock::new();
+
+/// Record the configured `tui.color_mode` value. Called once at startup with
+/// the final resolved config, before anything renders.
+pub(crate) fn init_color_mode(mode: ColorMode) {
+    if COLOR_MODE.set(mode).is_err() {
+        tracing::debug!("init_color_mode called more than once; value unchanged");
+    }
+}
+
+/// Effective color depth: the configured `tui.color_mode` when it names an
+/// explicit mode, otherwise the capability detected from the environment.
 pub fn stdout_color_level() -> StdoutColorLevel {
+    match COLOR_MODE.get().copied().unwrap_or_default() {
+        ColorMode::Truecolor => StdoutColorLevel::TrueColor,
+        ColorMode::Ansi256 => StdoutColorLevel::Ansi256,
+        ColorMode::Ansi16 => StdoutColorLevel::Ansi16,
+        ColorMode::Auto => detected_stdout_color_level(),
+    }
+}
+
+/// Whether `tui.color_mode` names an explicit depth. Forced modes must also
+/// suppress heuristic promotions (e.g. the Windows Terminal truecolor
+/// upgrade), the same way `FORCE_COLOR` does.
+pub(crate) fn color_mode_is_forced() -> bool {
+    !matches!(
+        COLOR_MODE.get().copied().unwrap_or_default(),
+        ColorMode::Auto
+    )
+}
+
+/// Color depth detected from the environment, before any `tui.color_mode`
+/// override is applied.
+fn detected_stdout_color_level() -> StdoutColorLevel {
     match supports_color::on_cached(supports_color::Stream::Stdout) {
         Some(level) if level.has_16m => StdoutColorLevel::TrueColor,
         Some(level) if level.has_256 => StdoutColorLevel::Ansi256,
@@ -30,26 +68,88 @@ pub fn indexed_color(index: u8) -> Color {
 
 /// Returns the closest color to the target color that the terminal can display.
 pub fn best_color(target: (u8, u8, u8)) -> Color {
-    let color_level = stdout_color_level();
-    if color_level == StdoutColorLevel::TrueColor {
-        rgb_color(target)
-    } else if color_level == StdoutColorLevel::Ansi256
-        && let Some((i, _)) = xterm_fixed_colors().min_by(|(_, a), (_, b)| {
+    best_color_for_level(stdout_color_level(), target)
+}
+
+/// Quantization logic behind [`best_color`], kept pure for testability:
+/// truecolor passes through, ANSI-256 and ANSI-16 snap to the nearest
+/// palette entry by perceptual distance, and an unknown depth falls back to
+/// the terminal default.
+fn best_color_for_level(color_level: StdoutColorLevel, target: (u8, u8, u8)) -> Color {
+    let nearest = match color_level {
+        StdoutColorLevel::TrueColor => return rgb_color(target),
+        StdoutColorLevel::Ansi256 => nearest_palette_index(xterm_fixed_colors(), target),
+        StdoutColorLevel::Ansi16 => nearest_palette_index(ansi16_colors(), target),
+        StdoutColorLevel::Unknown => None,
+    };
+    match nearest {
+        Some(i) => indexed_color(i as u8),
+        None => Color::default(),
+    }
+}
+
+/// Index of the palette entry closest to `target` by perceptual (CIE76 Lab)
+/// distance; see [`perceptual_distance`].
+fn nearest_palette_index(
+    palette: impl Iterator<Item = (usize, (u8, u8, u8))>,
+    target: (u8, u8, u8),
+) -> Option<usize> {
+    palette
+        .min_by(|(_, a), (_, b)| {
             perceptual_distance(*a, target)
                 .partial_cmp(&perceptual_distance(*b, target))
                 .unwrap_or(std::cmp::Ordering::Equal)
         })
-    {
-        indexed_color(i as u8)
-    } else {
-        Color::default()
-    }
+        .map(|(i, _)| i)
 }
 
 pub fn requery_default_colors() {
     imp::requery_default_colors();
 }
 
+/// Manually configured background from `tui.assume_background`, for terminals
+/// that refuse OSC 11 queries. An explicit user statement, so it takes
+/// precedence over detection.
+static ASSUMED_BG: OnceLock<Option<(u8, u8, u8)>> = OnceLock::new();
+
+/// Record the configured `tui.assume_background` value. Called once at
+/// startup with the final resolved config, before theme resolution.
+pub(crate) fn init_assumed_background(hex: Option<&str>) {
+    let parsed = hex.and_then(|value| {
+        let rgb = parse_hex_rgb(value);
+        if rgb.is_none() {
+            tracing::warn!(
+                "Ignoring invalid tui.assume_background value {value:?}; expected \"#RRGGBB\""
+            );
+        }
+        rgb
+    });
+    if ASSUMED_BG.set(parsed).is_err() {
+        tracing::debug!("init_assumed_background called more than once; value unchanged");
+    }
+}
+
+fn parse_hex_rgb(value: &str) -> Option<(u8, u8, u8)> {
+    let hex = value.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some((r, g, b))
+}
+
+fn assumed_default_colors() -> Option<DefaultColors> {
+    let bg = ASSUMED_BG.get().copied().flatten()?;
+    let fg = if crate::color::is_light(bg) {
+        (0, 0, 0)
+    } else {
+        (255, 255, 255)
+    };
+    Some(DefaultColors { fg, bg })
+}
+
 #[derive(Clone, Copy)]
 pub struct DefaultColors {
     fg: (u8, u8, u8),
@@ -57,7 +157,7 @@ pub struct DefaultColors {
 }
 
 pub fn default_colors() -> Option<DefaultColors> {
-    imp::default_colors()
+    assumed_default_colors().or_else(imp::default_colors)
 }
 
 pub fn default_fg() -> Option<(u8, u8, u8)> {
@@ -129,9 +229,59 @@ mod imp {
     }
 
     fn query_default_colors() -> std::io::Result<Option<DefaultColors>> {
+        // Some terminals reached over SSH answer OSC 10/11 slowly or not at
+        // all; skip the round-trip entirely in low-bandwidth mode.
+        if crate::remote_session::low_bandwidth_mode() {
+            return Ok(None);
+        }
         let fg = query_foreground_color()?.and_then(color_to_tuple);
         let bg = query_background_color()?.and_then(color_to_tuple);
-        Ok(fg.zip(bg).map(|(fg, bg)| DefaultColors { fg, bg }))
+        Ok(fg
+            .zip(bg)
+            .map(|(fg, bg)| DefaultColors { fg, bg })
+            .or_else(environment_default_colors))
+    }
+
+    /// Best-effort guess for terminals that refuse OSC 10/11 queries.
+    fn environment_default_colors() -> Option<DefaultColors> {
+        colorfgbg_default_colors().or_else(terminal_identity_default_colors)
+    }
+
+    /// `COLORFGBG` is exported by rxvt-descended terminals as `"fg;bg"`
+    /// (sometimes `"fg;default;bg"`) using ANSI palette indices.
+    fn colorfgbg_default_colors() -> Option<DefaultColors> {
+        let value = std::env::var("COLORFGBG").ok()?;
+        let mut parts = value.split(';');
+        let fg = palette_index_color(parts.next()?)?;
+        let bg = palette_index_color(parts.last()?)?;
+        Some(DefaultColors { fg, bg })
+    }
+
+    /// Approximate an ANSI palette index with the Xterm default colors. The
+    /// first 16 entries vary by theme, but light-vs-dark survives the
+    /// approximation, which is all the adaptive theme needs.
+    fn palette_index_color(index: &str) -> Option<(u8, u8, u8)> {
+        let index: usize = index.trim().parse().ok()?;
+        super::XTERM_COLORS.get(index).copied()
+    }
+
+    /// Terminals whose default scheme is well known even without a query.
+    fn terminal_identity_default_colors() -> Option<DefaultColors> {
+        if std::env::var("TERM_PROGRAM").is_ok_and(|program| program == "Apple_Terminal") {
+            // Apple Terminal ships with a white default profile.
+            return Some(DefaultColors {
+                fg: (0, 0, 0),
+                bg: (255, 255, 255),
+            });
+        }
+        if std::env::var("TERM").is_ok_and(|term| term == "linux") {
+            // The Linux console is grey-on-black.
+            return Some(DefaultColors {
+                fg: (170, 170, 170),
+                bg: (0, 0, 0),
+            });
+        }
+        None
     }
 
     fn color_to_tuple(color: CrosstermColor) -> Option<(u8, u8, u8)> {
@@ -158,6 +308,14 @@ fn xterm_fixed_colors() -> impl Iterator<Item = (usize, (u8, u8, u8))> {
     XTERM_COLORS.into_iter().enumerate().skip(16)
 }
 
+/// The 16 basic ANSI colors. Their actual RGB values depend on the terminal
+/// theme, so this matches against the Xterm defaults — hue and rough
+/// lightness survive the approximation, which is what degraded 16-color
+/// output needs.
+fn ansi16_colors() -> impl Iterator<Item = (usize, (u8, u8, u8))> {
+    XTERM_COLORS.into_iter().enumerate().take(16)
+}
+
 // Xterm colors; derived from https://ss64.com/bash/syntax-colors.html
 pub const XTERM_COLORS: [(u8, u8, u8); 256] = [
     // The first 16 colors vary based on terminal theme, so these are likely not the actual colors
@@ -420,3 +578,62 @@ pub const XTERM_COLORS: [(u8, u8, u8); 256] = [
     (228, 228, 228), // 254 Grey89
     (238, 238, 238), // 255 Grey93
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn best_color_passes_rgb_through_in_truecolor_mode() {
+        assert_eq!(
+            best_color_for_level(StdoutColorLevel::TrueColor, (0x1e, 0x1e, 0x2e)),
+            rgb_color((0x1e, 0x1e, 0x2e))
+        );
+    }
+
+    #[test]
+    fn best_color_quantizes_to_the_xterm_palette_in_256_mode() {
+        // Exact palette entries map to their own index.
+        assert_eq!(
+            best_color_for_level(StdoutColorLevel::Ansi256, (0, 0, 95)),
+            indexed_color(17)
+        );
+        // Near misses snap to the perceptually closest entry, never a
+        // theme-dependent system slot (indices 0–15).
+        let Color::Indexed(index) = best_color_for_level(StdoutColorLevel::Ansi256, (1, 1, 94))
+        else {
+            panic!("expected an indexed color");
+        };
+        assert!(index >= 16);
+    }
+
+    #[test]
+    fn best_color_quantizes_to_the_basic_palette_in_16_mode() {
+        assert_eq!(
+            best_color_for_level(StdoutColorLevel::Ansi16, (255, 0, 0)),
+            indexed_color(9)
+        );
+        let Color::Indexed(index) = best_color_for_level(StdoutColorLevel::Ansi16, (40, 160, 50))
+        else {
+            panic!("expected an indexed color");
+        };
+        assert!(index < 16);
+    }
+
+    #[test]
+    fn best_color_falls_back_to_terminal_default_when_depth_is_unknown() {
+        assert_eq!(
+            best_color_for_level(StdoutColorLevel::Unknown, (10, 20, 30)),
+            Color::default()
+        );
+    }
+
+    #[test]
+    fn parse_hex_rgb_accepts_rrggbb_and_rejects_everything_else() {
+        assert_eq!(parse_hex_rgb("#1e1e2e"), Some((0x1e, 0x1e, 0x2e)));
+        assert_eq!(parse_hex_rgb("#FFFFFF"), Some((255, 255, 255)));
+        assert_eq!(parse_hex_rgb("1e1e2e"), None);
+        assert_eq!(parse_hex_rgb("#fff"), None);
+        assert_eq!(parse_hex_rgb("#gggggg"), None);
+    }
+}
@@ -87,6 +87,19 @@ pub(crate) fn format_json_compact(text: &str) -> Option<String> {
     Some(result)
 }
 
+/// Line-based truncation for tool output that carries ANSI escape sequences:
+/// grapheme truncation could split an escape sequence in half, so keep whole
+/// lines and let the renderer wrap them instead.
+pub(crate) fn truncate_ansi_tool_result(text: &str, max_lines: usize) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.len() <= max_lines {
+        return text.trim_end_matches('\n').to_string();
+    }
+    let mut truncated = lines[..max_lines].join("\n");
+    truncated.push_str("\n...");
+    truncated
+}
+
 /// Truncate `text` to `max_graphemes` graphemes. Using graphemes to avoid accidentally truncating in the middle of a multi-codepoint character.
 pub(crate) fn truncate_text(text: &str, max_graphemes: usize) -> String {
     let mut graphemes = text.grapheme_indices(true);
@@ -359,6 +372,20 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn test_truncate_ansi_tool_result_keeps_whole_lines() {
+        let text = "\u{1b}[31mone\u{1b}[0m\ntwo\nthree\nfour";
+        let truncated = truncate_ansi_tool_result(text, /*max_lines*/ 2);
+        assert_eq!(truncated, "\u{1b}[31mone\u{1b}[0m\ntwo\n...");
+    }
+
+    #[test]
+    fn test_truncate_ansi_tool_result_short_input_unchanged() {
+        let text = "\u{1b}[32mok\u{1b}[0m\n";
+        let truncated = truncate_ansi_tool_result(text, /*max_lines*/ 5);
+        assert_eq!(truncated, "\u{1b}[32mok\u{1b}[0m");
+    }
+
     #[test]
     fn test_truncate_text() {
         let text = "Hello, world!";
@@ -0,0 +1,88 @@
+//! Hot-reloads the syntax theme when the user edits `config.toml`.
+//!
+//! Watches `$CODEX_HOME/config.toml` through the shared notify-based file
+//! watcher and emits [`AppEvent::ThemeConfigFileChanged`] whenever it changes.
+//! The app then re-reads the `[tui] theme` key and swaps the runtime theme in
+//! place, so iterating on a custom palette no longer requires a relaunch.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::app_event::AppEvent;
+use crate::app_event_sender::AppEventSender;
+use crate::legacy_core::file_watcher::FileWatcher;
+use crate::legacy_core::file_watcher::ThrottledWatchReceiver;
+use crate::legacy_core::file_watcher::WatchPath;
+
+/// Minimum delay between change notifications; editors typically write the
+/// file several times per save.
+const NOTIFY_THROTTLE: Duration = Duration::from_millis(250);
+
+/// Starts watching `config.toml` and forwards change notifications as app
+/// events. The watcher lives for the rest of the process; failing to start it
+/// only disables hot reload, so errors are logged and otherwise ignored.
+pub(crate) fn spawn_theme_watcher(codex_home: PathBuf, app_event_tx: AppEventSender) {
+    let config_path = codex_home.join("config.toml");
+    let file_watcher = match FileWatcher::new() {
+        Ok(watcher) => Arc::new(watcher),
+        Err(err) => {
+            tracing::warn!("theme hot reload disabled: {err}");
+            return;
+        }
+    };
+    let (subscriber, rx) = file_watcher.add_subscriber();
+    let registration = subscriber.register_paths(vec![WatchPath {
+        path: config_path,
+        recursive: false,
+    }]);
+    let mut rx = ThrottledWatchReceiver::new(rx, NOTIFY_THROTTLE);
+    tokio::spawn(async move {
+        // Keep the watcher, subscription and registration alive for as long
+        // as events can arrive.
+        let _file_watcher = file_watcher;
+        let _subscriber = subscriber;
+        let _registration = registration;
+        while rx.recv().await.is_some() {
+            app_event_tx.send(AppEvent::ThemeConfigFileChanged);
+        }
+    });
+}
+
+/// Reads the `[tui] theme` value from `config.toml`. Returns `None` when the
+/// key is absent or the file cannot currently be read or parsed (editors may
+/// save intermediate states).
+pub(crate) fn read_tui_theme(codex_home: &Path) -> Option<String> {
+    let contents = std::fs::read_to_string(codex_home.join("config.toml")).ok()?;
+    let value: toml::Value = contents.parse().ok()?;
+    value.get("tui")?.get("theme")?.as_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_tui_theme;
+
+    #[test]
+    fn read_tui_theme_returns_configured_name() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(
+            dir.path().join("config.toml"),
+            "[tui]\ntheme = \"dracula\"\n",
+        )
+        .expect("write config");
+        assert_eq!(read_tui_theme(dir.path()), Some("dracula".to_string()));
+    }
+
+    #[test]
+    fn read_tui_theme_handles_missing_key_and_bad_toml() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        assert_eq!(read_tui_theme(dir.path()), None);
+
+        std::fs::write(dir.path().join("config.toml"), "[tui]\n").expect("write config");
+        assert_eq!(read_tui_theme(dir.path()), None);
+
+        std::fs::write(dir.path().join("config.toml"), "[tui\n").expect("write config");
+        assert_eq!(read_tui_theme(dir.path()), None);
+    }
+}
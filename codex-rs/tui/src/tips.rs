@@ -0,0 +1,132 @@
+//! One-time contextual tips for undiscoverable features.
+//!
+//! Unlike [`tooltips`](crate::tooltips) (random startup banners), tips fire in
+//! response to something the user just did — finishing a first turn, pasting a
+//! wall of text — and each one shows at most once, ever. Seen tips persist in
+//! `[notice].seen_tips` in `config.toml`; `tui.tips = false` disables the
+//! system and `/tips reset` forgets everything so the tips can show again.
+
+use std::collections::BTreeSet;
+
+/// A single one-time tip. The key is what persists, so renaming a variant
+/// without keeping its key would re-show the tip to everyone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Tip {
+    /// Shown after the first completed turn.
+    TranscriptOverlay,
+    /// Shown the first time a collapsible tool call lands in the history.
+    CollapsedToolCalls,
+    /// Shown the first time a large paste hits the composer.
+    ExternalEditor,
+}
+
+impl Tip {
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            Tip::TranscriptOverlay => "transcript_overlay",
+            Tip::CollapsedToolCalls => "collapsed_tool_calls",
+            Tip::ExternalEditor => "external_editor",
+        }
+    }
+
+    pub(crate) fn message(self) -> &'static str {
+        match self {
+            Tip::TranscriptOverlay => "Tip: press ctrl + t to view the full session transcript.",
+            Tip::CollapsedToolCalls => {
+                "Tip: press ctrl + x to collapse or expand tool call output."
+            }
+            Tip::ExternalEditor => {
+                "Tip: press ctrl + g to edit your message in your external editor."
+            }
+        }
+    }
+}
+
+/// Tracks which tips have fired, both from earlier sessions (loaded from
+/// config) and this one.
+pub(crate) struct TipTracker {
+    enabled: bool,
+    seen: BTreeSet<String>,
+}
+
+impl TipTracker {
+    pub(crate) fn new(enabled: bool, seen: &[String]) -> Self {
+        Self {
+            enabled,
+            seen: seen.iter().cloned().collect(),
+        }
+    }
+
+    /// Return the tip's message the first time it fires; `None` when tips are
+    /// disabled or this tip already showed.
+    pub(crate) fn take(&mut self, tip: Tip) -> Option<&'static str> {
+        if !self.enabled {
+            return None;
+        }
+        self.seen
+            .insert(tip.key().to_string())
+            .then(|| tip.message())
+    }
+
+    pub(crate) fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub(crate) fn seen_count(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// The full seen set, for persisting to `[notice].seen_tips`.
+    pub(crate) fn seen_for_persistence(&self) -> Vec<String> {
+        self.seen.iter().cloned().collect()
+    }
+
+    /// Forget every seen tip (`/tips reset`).
+    pub(crate) fn reset(&mut self) {
+        self.seen.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn each_tip_fires_at_most_once() {
+        let mut tracker = TipTracker::new(/*enabled*/ true, &[]);
+        assert_eq!(
+            tracker.take(Tip::TranscriptOverlay),
+            Some(Tip::TranscriptOverlay.message())
+        );
+        assert_eq!(tracker.take(Tip::TranscriptOverlay), None);
+        assert_eq!(
+            tracker.seen_for_persistence(),
+            vec!["transcript_overlay".to_string()]
+        );
+    }
+
+    #[test]
+    fn tips_seen_in_earlier_sessions_stay_seen() {
+        let mut tracker = TipTracker::new(/*enabled*/ true, &["external_editor".to_string()]);
+        assert_eq!(tracker.take(Tip::ExternalEditor), None);
+    }
+
+    #[test]
+    fn disabled_tracker_never_fires_or_records() {
+        let mut tracker = TipTracker::new(/*enabled*/ false, &[]);
+        assert_eq!(tracker.take(Tip::CollapsedToolCalls), None);
+        assert_eq!(tracker.seen_count(), 0);
+    }
+
+    #[test]
+    fn reset_lets_tips_fire_again() {
+        let mut tracker = TipTracker::new(/*enabled*/ true, &[]);
+        tracker.take(Tip::TranscriptOverlay);
+        tracker.reset();
+        assert_eq!(
+            tracker.take(Tip::TranscriptOverlay),
+            Some(Tip::TranscriptOverlay.message())
+        );
+    }
+}
@@ -11,15 +11,19 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
+use std::time::Instant;
 
 use crossterm::Command;
 use crossterm::SynchronizedUpdate;
 use crossterm::event::DisableBracketedPaste;
 use crossterm::event::DisableFocusChange;
+use crossterm::event::DisableMouseCapture;
 use crossterm::event::EnableBracketedPaste;
 use crossterm::event::EnableFocusChange;
+use crossterm::event::EnableMouseCapture;
 use crossterm::event::KeyEvent;
 use crossterm::event::KeyboardEnhancementFlags;
+use crossterm::event::MouseEvent;
 use crossterm::event::PopKeyboardEnhancementFlags;
 use crossterm::event::PushKeyboardEnhancementFlags;
 use crossterm::terminal::EnterAlternateScreen;
@@ -49,6 +53,8 @@ use crate::tui::job_control::SuspendContext;
 use codex_config::types::NotificationCondition;
 use codex_config::types::NotificationMethod;
 
+#[cfg(feature = "automation")]
+pub(crate) mod automation;
 mod event_stream;
 mod frame_rate_limiter;
 mod frame_requester;
@@ -166,6 +172,8 @@ impl Command for DisableAlternateScroll {
 fn restore_common(should_disable_raw_mode: bool) -> Result<()> {
     // Pop may fail on platforms that didn't support the push; ignore errors.
     let _ = execute!(stdout(), PopKeyboardEnhancementFlags);
+    // Harmless when capture was never enabled.
+    let _ = execute!(stdout(), DisableMouseCapture);
     execute!(stdout(), DisableBracketedPaste)?;
     let _ = execute!(stdout(), DisableFocusChange);
     if should_disable_raw_mode {
@@ -273,10 +281,19 @@ fn set_panic_hook() {
 #[derive(Clone, Debug)]
 pub enum TuiEvent {
     Key(KeyEvent),
+    /// Only delivered while mouse capture is enabled via `[tui.mouse]`.
+    Mouse(MouseEvent),
     Paste(String),
     Draw,
 }
 
+/// What text-selection mode suspended, so only those features come back when
+/// it ends.
+struct TextSelectionRestore {
+    mouse_capture: bool,
+    alt_screen: bool,
+}
+
 pub struct Tui {
     frame_requester: FrameRequester,
     draw_tx: broadcast::Sender<()>,
@@ -296,6 +313,15 @@ pub struct Tui {
     is_zellij: bool,
     // When false, enter_alt_screen() becomes a no-op (for Zellij scrollback support)
     alt_screen_enabled: bool,
+    // True when `[tui.mouse]` opted into mouse capture; re-applied after
+    // external programs restore the terminal.
+    mouse_capture_enabled: bool,
+    // Set while text-selection mode has mouse capture and the alt screen
+    // suspended; remembers what to re-enable on the next key press.
+    text_selection_restore: Option<TextSelectionRestore>,
+    /// Plain-text copy of the last rendered viewport, for the automation channel.
+    #[cfg(feature = "automation")]
+    last_frame: Arc<std::sync::Mutex<String>>,
 }
 
 impl Tui {
@@ -305,7 +331,9 @@ impl Tui {
 
         // Detect keyboard enhancement support before any EventStream is created so the
         // crossterm poller can acquire its lock without contention.
-        let enhanced_keys_supported = supports_keyboard_enhancement().unwrap_or(false);
+        let enhanced_keys_supported = crate::windows_console::enhanced_keys_supported(
+            supports_keyboard_enhancement().unwrap_or(false),
+        );
         // Cache this to avoid contention with the event reader.
         supports_color::on_cached(supports_color::Stream::Stdout);
         let _ = crate::terminal_palette::default_colors();
@@ -330,6 +358,10 @@ impl Tui {
             notification_condition: NotificationCondition::default(),
             is_zellij,
             alt_screen_enabled: true,
+            mouse_capture_enabled: false,
+            text_selection_restore: None,
+            #[cfg(feature = "automation")]
+            last_frame: Arc::default(),
         }
     }
 
@@ -338,6 +370,21 @@ impl Tui {
         self.alt_screen_enabled = enabled;
     }
 
+    /// Enable or disable mouse capture. Capturing takes the mouse away from
+    /// the terminal emulator, so this is only turned on when `[tui.mouse]`
+    /// opted in.
+    pub fn set_mouse_capture_enabled(&mut self, enabled: bool) {
+        self.mouse_capture_enabled = enabled;
+        let command = if enabled {
+            execute!(stdout(), EnableMouseCapture)
+        } else {
+            execute!(stdout(), DisableMouseCapture)
+        };
+        if let Err(err) = command {
+            tracing::warn!("failed to toggle mouse capture: {err}");
+        }
+    }
+
     pub fn set_notification_settings(
         &mut self,
         method: NotificationMethod,
@@ -398,6 +445,10 @@ impl Tui {
         if let Err(err) = set_modes() {
             tracing::warn!("failed to re-enable terminal modes after external program: {err}");
         }
+        // `restore` disabled mouse capture along with the other modes.
+        if self.mouse_capture_enabled {
+            let _ = execute!(stdout(), EnableMouseCapture);
+        }
         // After the external program `f` finishes, reset terminal state and flush any buffered keypresses.
         flush_terminal_input_buffer();
 
@@ -409,6 +460,23 @@ impl Tui {
         output
     }
 
+    /// Emit the terminal BEL for the audio bell.
+    pub fn ring_audio_bell(&mut self) {
+        use std::io::Write;
+        let mut out = stdout();
+        let _ = out.write_all(b"\x07");
+        let _ = out.flush();
+    }
+
+    /// Toggles DECSCNM reverse video, used as a brief full-screen flash for
+    /// the visual bell. Returns whether the escape sequence was written.
+    pub fn set_reverse_video(&mut self, enabled: bool) -> bool {
+        use std::io::Write;
+        let mut out = stdout();
+        let seq: &[u8] = if enabled { b"\x1b[?5h" } else { b"\x1b[?5l" };
+        out.write_all(seq).and_then(|()| out.flush()).is_ok()
+    }
+
     /// Emit a desktop notification now if the terminal is unfocused.
     /// Returns true if a notification was posted.
     pub fn notify(&mut self, message: impl AsRef<str>) -> bool {
@@ -437,6 +505,13 @@ impl Tui {
         }
     }
 
+    /// Handle for the scripted automation channel: shares the event broker
+    /// for key injection and the last rendered frame for snapshot queries.
+    #[cfg(feature = "automation")]
+    pub(crate) fn automation_handle(&self) -> automation::AutomationHandle {
+        automation::AutomationHandle::new(self.event_broker.clone(), self.last_frame.clone())
+    }
+
     pub fn event_stream(&self) -> Pin<Box<dyn Stream<Item = TuiEvent> + Send + 'static>> {
         #[cfg(unix)]
         let stream = TuiEventStream::new(
@@ -493,6 +568,43 @@ impl Tui {
         Ok(())
     }
 
+    pub fn text_selection_active(&self) -> bool {
+        self.text_selection_restore.is_some()
+    }
+
+    /// Suspend mouse capture and the alternate screen so the terminal's
+    /// native text selection works. [`Tui::end_text_selection`] restores
+    /// whatever was suspended.
+    pub fn begin_text_selection(&mut self) {
+        if self.text_selection_restore.is_some() {
+            return;
+        }
+        let restore = TextSelectionRestore {
+            mouse_capture: self.mouse_capture_enabled,
+            alt_screen: self.is_alt_screen_active(),
+        };
+        if restore.mouse_capture {
+            let _ = execute!(stdout(), DisableMouseCapture);
+        }
+        if restore.alt_screen {
+            let _ = self.leave_alt_screen();
+        }
+        self.text_selection_restore = Some(restore);
+    }
+
+    /// Re-enable whatever [`Tui::begin_text_selection`] suspended.
+    pub fn end_text_selection(&mut self) {
+        let Some(restore) = self.text_selection_restore.take() else {
+            return;
+        };
+        if restore.mouse_capture && self.mouse_capture_enabled {
+            let _ = execute!(stdout(), EnableMouseCapture);
+        }
+        if restore.alt_screen {
+            let _ = self.enter_alt_screen();
+        }
+    }
+
     pub fn insert_history_lines(&mut self, lines: Vec<Line<'static>>) {
         self.pending_history_lines.extend(lines);
         self.frame_requester().schedule_frame();
@@ -632,9 +744,21 @@ impl Tui {
                 self.suspend_context.set_cursor_y(inline_area_bottom);
             }
 
-            terminal.draw(|frame| {
+            #[cfg(feature = "automation")]
+            let last_frame = self.last_frame.clone();
+            let draw_started = Instant::now();
+            let result = terminal.draw(|frame| {
                 draw_fn(frame);
-            })
+                #[cfg(feature = "automation")]
+                {
+                    *last_frame
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner) =
+                        automation::render_buffer_text(frame.buffer, frame.viewport_area);
+                }
+            });
+            crate::debug_ui::record_frame(draw_started.elapsed());
+            result
         })?
     }
 
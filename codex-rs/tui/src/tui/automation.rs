@@ -0,0 +1,257 @@
+//! Scriptable automation channel for end-to-end TUI tests.
+//!
+//! Compiled only with the `automation` cargo feature. When
+//! `CODEX_TUI_AUTOMATION_SOCKET` names a path, the TUI listens on a Unix
+//! socket there and accepts newline-delimited commands:
+//!
+//! - `key <spec>` injects a key press, e.g. `key ctrl+t`, `key shift+tab`,
+//!   `key enter`, `key a`;
+//! - `paste <text>` injects a bracketed paste;
+//! - `snapshot` replies with the plain text of the last rendered frame.
+//!
+//! Every reply — `ok`, `error: ...`, or snapshot text — is terminated by a
+//! NUL byte so multi-line snapshots frame cleanly. Injected keys travel
+//! through the same [`EventBroker`] as terminal input, so downstream
+//! packagers and plugin authors can exercise custom keymaps and themes
+//! exactly as a user would drive them.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crossterm::event::Event;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use crossterm::event::KeyModifiers;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use super::event_stream::EventBroker;
+
+/// Shared state the automation server needs from [`super::Tui`]: the event
+/// broker for injection and the last rendered frame for snapshot queries.
+#[derive(Clone)]
+pub(crate) struct AutomationHandle {
+    broker: Arc<EventBroker>,
+    last_frame: Arc<Mutex<String>>,
+}
+
+impl AutomationHandle {
+    pub(crate) fn new(broker: Arc<EventBroker>, last_frame: Arc<Mutex<String>>) -> Self {
+        Self { broker, last_frame }
+    }
+
+    fn inject_key(&self, key: KeyEvent) {
+        self.broker.inject_event(Event::Key(key));
+    }
+
+    fn inject_paste(&self, text: String) {
+        self.broker.inject_event(Event::Paste(text));
+    }
+
+    fn snapshot(&self) -> String {
+        self.last_frame
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// Starts the automation server when `CODEX_TUI_AUTOMATION_SOCKET` is set.
+/// No-op otherwise; the channel never runs unless explicitly requested.
+pub(crate) fn start_if_configured(tui: &super::Tui) {
+    let Ok(path) = std::env::var("CODEX_TUI_AUTOMATION_SOCKET") else {
+        return;
+    };
+    if path.is_empty() {
+        return;
+    }
+    let handle = tui.automation_handle();
+    #[cfg(unix)]
+    std::thread::spawn(move || {
+        if let Err(err) = serve(path, handle) {
+            tracing::warn!("automation channel failed: {err}");
+        }
+    });
+    #[cfg(not(unix))]
+    {
+        let _ = handle;
+        tracing::warn!("automation channel requires Unix sockets; ignoring {path}");
+    }
+}
+
+#[cfg(unix)]
+fn serve(path: String, handle: AutomationHandle) -> std::io::Result<()> {
+    let _ = std::fs::remove_file(&path);
+    let listener = std::os::unix::net::UnixListener::bind(&path)?;
+    loop {
+        let (stream, _) = listener.accept()?;
+        let handle = handle.clone();
+        std::thread::spawn(move || {
+            if let Err(err) = serve_connection(stream, handle) {
+                tracing::debug!("automation connection closed: {err}");
+            }
+        });
+    }
+}
+
+#[cfg(unix)]
+fn serve_connection(
+    stream: std::os::unix::net::UnixStream,
+    handle: AutomationHandle,
+) -> std::io::Result<()> {
+    use std::io::BufRead;
+    use std::io::BufReader;
+    use std::io::Write;
+
+    let reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    for line in reader.lines() {
+        let reply = match run_command(line?.trim(), &handle) {
+            Ok(Some(snapshot)) => snapshot,
+            Ok(None) => "ok".to_string(),
+            Err(err) => format!("error: {err}"),
+        };
+        writer.write_all(reply.as_bytes())?;
+        writer.write_all(b"\0")?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+/// Executes one command line. `Ok(Some(_))` carries a snapshot reply;
+/// `Ok(None)` acknowledges an injection.
+fn run_command(line: &str, handle: &AutomationHandle) -> Result<Option<String>, String> {
+    let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+    match command {
+        "" => Ok(None),
+        "key" => {
+            handle.inject_key(parse_key_spec(rest)?);
+            Ok(None)
+        }
+        "paste" => {
+            handle.inject_paste(rest.to_string());
+            Ok(None)
+        }
+        "snapshot" => Ok(Some(handle.snapshot())),
+        other => Err(format!("unknown command {other:?}")),
+    }
+}
+
+/// Parses a key spec: zero or more `ctrl`/`alt`/`shift` modifiers joined
+/// with `+`, then a named key (`enter`, `esc`, `tab`, arrows, `f1`..) or a
+/// single character. An uppercase character implies `shift`.
+fn parse_key_spec(spec: &str) -> Result<KeyEvent, String> {
+    let mut parts: Vec<&str> = spec.split('+').collect();
+    let key = parts
+        .pop()
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| format!("missing key in spec {spec:?}"))?;
+    let mut modifiers = KeyModifiers::NONE;
+    for part in parts {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            other => return Err(format!("unknown modifier {other:?}")),
+        }
+    }
+    let code = match key.to_ascii_lowercase().as_str() {
+        "enter" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "delete" => KeyCode::Delete,
+        lower => {
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => {
+                    if c.is_ascii_uppercase() {
+                        modifiers |= KeyModifiers::SHIFT;
+                    }
+                    KeyCode::Char(c)
+                }
+                _ => match lower.strip_prefix('f').and_then(|n| n.parse().ok()) {
+                    Some(n) => KeyCode::F(n),
+                    None => return Err(format!("unknown key {key:?}")),
+                },
+            }
+        }
+    };
+    Ok(KeyEvent::new(code, modifiers))
+}
+
+/// Renders the viewport rows of a frame buffer as plain text, one line per
+/// row, trailing whitespace trimmed.
+pub(crate) fn render_buffer_text(buffer: &Buffer, viewport: Rect) -> String {
+    let mut out = String::new();
+    for y in viewport.top()..viewport.bottom() {
+        let mut line = String::new();
+        for x in viewport.left()..viewport.right() {
+            if let Some(cell) = buffer.cell((x, y)) {
+                line.push_str(cell.symbol());
+            }
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn key_specs_parse_modifiers_and_named_keys() {
+        assert_eq!(
+            parse_key_spec("ctrl+t"),
+            Ok(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            parse_key_spec("shift+tab"),
+            Ok(KeyEvent::new(KeyCode::Tab, KeyModifiers::SHIFT))
+        );
+        assert_eq!(
+            parse_key_spec("enter"),
+            Ok(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        );
+        assert_eq!(
+            parse_key_spec("f5"),
+            Ok(KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE))
+        );
+    }
+
+    #[test]
+    fn uppercase_characters_imply_shift() {
+        assert_eq!(
+            parse_key_spec("A"),
+            Ok(KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT))
+        );
+    }
+
+    #[test]
+    fn bad_specs_are_rejected() {
+        assert!(parse_key_spec("").is_err());
+        assert!(parse_key_spec("hyper+x").is_err());
+        assert!(parse_key_spec("ctrl+").is_err());
+        assert!(parse_key_spec("notakey").is_err());
+    }
+
+    #[test]
+    fn buffer_text_trims_trailing_whitespace() {
+        let area = Rect::new(0, 0, 8, 2);
+        let mut buffer = Buffer::empty(area);
+        buffer.set_string(0, 0, "hello", ratatui::style::Style::default());
+        assert_eq!(render_buffer_text(&buffer, area), "hello\n\n");
+    }
+}
@@ -51,6 +51,9 @@ pub trait EventSource: Send + 'static {
 pub struct EventBroker<S: EventSource = CrosstermEventSource> {
     state: Mutex<EventBrokerState<S>>,
     resume_events_tx: watch::Sender<()>,
+    /// Events queued by the automation channel, drained ahead of the source.
+    #[cfg(feature = "automation")]
+    injected: Mutex<std::collections::VecDeque<Event>>,
 }
 
 /// Tracks state of underlying [`EventSource`].
@@ -83,6 +86,8 @@ impl<S: EventSource + Default> EventBroker<S> {
         Self {
             state: Mutex::new(EventBrokerState::Start),
             resume_events_tx,
+            #[cfg(feature = "automation")]
+            injected: Mutex::new(std::collections::VecDeque::new()),
         }
     }
 
@@ -105,6 +110,25 @@ impl<S: EventSource + Default> EventBroker<S> {
         let _ = self.resume_events_tx.send(());
     }
 
+    /// Queue an event as if it had arrived from the terminal. The watch send
+    /// wakes any [`TuiEventStream`] parked waiting on stdin.
+    #[cfg(feature = "automation")]
+    pub fn inject_event(&self, event: Event) {
+        self.injected
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push_back(event);
+        let _ = self.resume_events_tx.send(());
+    }
+
+    #[cfg(feature = "automation")]
+    fn take_injected(&self) -> Option<Event> {
+        self.injected
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .pop_front()
+    }
+
     /// Subscribe to a notification that fires whenever [`Self::resume_events`] is called.
     ///
     /// This is used to wake `poll_crossterm_event` when it is paused and waiting for the
@@ -172,13 +196,20 @@ impl<S: EventSource + Default + Unpin> TuiEventStream<S> {
 
     /// Poll the shared crossterm stream for the next mapped `TuiEvent`.
     ///
-    /// This skips events we don't use (mouse events, etc.) and keeps polling until it yields
+    /// This skips events we don't use (mouse motion, etc.) and keeps polling until it yields
     /// a mapped event, hits `Pending`, or sees EOF/error. When the broker is paused, it drops
     /// the underlying stream and returns `Pending` to fully release stdin.
     pub fn poll_crossterm_event(&mut self, cx: &mut Context<'_>) -> Poll<Option<TuiEvent>> {
-        // Some crossterm events map to None (e.g. FocusLost, mouse); loop so we keep polling
+        // Some crossterm events map to None (e.g. FocusLost, mouse motion); loop so we keep polling
         // until we return a mapped event, hit Pending, or see EOF/error.
         loop {
+            #[cfg(feature = "automation")]
+            if let Some(event) = self.broker.take_injected() {
+                match self.map_crossterm_event(event) {
+                    Some(mapped) => return Poll::Ready(Some(mapped)),
+                    None => continue,
+                }
+            }
             let poll_result = {
                 let mut state = self
                     .broker
@@ -233,9 +264,19 @@ impl<S: EventSource + Default + Unpin> TuiEventStream<S> {
         }
     }
 
-    /// Map a crossterm event to a [`TuiEvent`], skipping events we don't use (mouse events, etc.).
+    /// Map a crossterm event to a [`TuiEvent`], skipping events we don't use
+    /// (mouse motion, focus bookkeeping, etc.).
     fn map_crossterm_event(&mut self, event: Event) -> Option<TuiEvent> {
         match event {
+            // Mouse events only arrive while capture is enabled. Motion and
+            // button-release events are dropped here so the app is not woken
+            // for every pixel the pointer moves.
+            Event::Mouse(mouse_event) => match mouse_event.kind {
+                crossterm::event::MouseEventKind::ScrollUp
+                | crossterm::event::MouseEventKind::ScrollDown
+                | crossterm::event::MouseEventKind::Down(_) => Some(TuiEvent::Mouse(mouse_event)),
+                _ => None,
+            },
             Event::Key(key_event) => {
                 #[cfg(unix)]
                 if crate::tui::job_control::SUSPEND_KEY.is_press(key_event) {
@@ -248,7 +289,15 @@ impl<S: EventSource + Default + Unpin> TuiEventStream<S> {
             Event::Paste(pasted) => Some(TuiEvent::Paste(pasted)),
             Event::FocusGained => {
                 self.terminal_focused.store(true, Ordering::Relaxed);
+                // The user may have switched their terminal between dark and
+                // light profiles while we were unfocused; requery OSC 10/11
+                // and re-resolve the adaptive theme if the background flipped.
+                let previous_bg = crate::terminal_palette::default_bg();
                 crate::terminal_palette::requery_default_colors();
+                crate::render::highlight::refresh_theme_for_background_change(
+                    previous_bg,
+                    crate::terminal_palette::default_bg(),
+                );
                 Some(TuiEvent::Draw)
             }
             Event::FocusLost => {
@@ -12,10 +12,33 @@ use std::time::Instant;
 /// A 120 FPS minimum frame interval (≈8.33ms).
 pub(super) const MIN_FRAME_INTERVAL: Duration = Duration::from_nanos(8_333_334);
 
+/// A 30 FPS minimum frame interval used on detected high-latency links.
+pub(super) const LOW_BANDWIDTH_FRAME_INTERVAL: Duration = Duration::from_nanos(33_333_334);
+
+/// Returns the minimum frame interval for this process, honoring the
+/// low-bandwidth mode derived from SSH detection.
+pub(super) fn min_frame_interval() -> Duration {
+    if crate::remote_session::low_bandwidth_mode() {
+        LOW_BANDWIDTH_FRAME_INTERVAL
+    } else {
+        MIN_FRAME_INTERVAL
+    }
+}
+
 /// Remembers the most recent emitted draw, allowing deadlines to be clamped forward.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub(super) struct FrameRateLimiter {
     last_emitted_at: Option<Instant>,
+    min_interval: Duration,
+}
+
+impl Default for FrameRateLimiter {
+    fn default() -> Self {
+        Self {
+            last_emitted_at: None,
+            min_interval: min_frame_interval(),
+        }
+    }
 }
 
 impl FrameRateLimiter {
@@ -25,7 +48,7 @@ impl FrameRateLimiter {
             return requested;
         };
         let min_allowed = last_emitted_at
-            .checked_add(MIN_FRAME_INTERVAL)
+            .checked_add(self.min_interval)
             .unwrap_or(last_emitted_at);
         requested.max(min_allowed)
     }
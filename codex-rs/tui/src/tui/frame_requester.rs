@@ -56,9 +56,9 @@ impl FrameRequester {
     }
 }
 
-#[cfg(test)]
 impl FrameRequester {
-    /// Create a no-op frame requester for tests.
+    /// Create a no-op frame requester for tests and offline snapshot
+    /// rendering (`codex ui snapshot`).
     pub(crate) fn test_dummy() -> Self {
         let (tx, _rx) = mpsc::unbounded_channel();
         FrameRequester {
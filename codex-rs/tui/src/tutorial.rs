@@ -0,0 +1,226 @@
+//! Implements `codex tutorial`: a guided, fully offline walkthrough of the
+//! core interactions — composing a prompt, approving a command, reading a
+//! diff, backtracking, and resuming a session.
+//!
+//! Every screen is rendered from the real UI components through the VT100
+//! backend (the same machinery as `codex ui snapshot`), so what the tutorial
+//! shows is exactly what the real session looks like, and each step waits for
+//! the actual keybinding it teaches. Screens are embedded as plain text so
+//! they flow with the surrounding prose. Nothing runs and nothing is written;
+//! it is safe to mash keys.
+
+use std::collections::HashMap;
+use std::io::IsTerminal;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::protocol::FileChange;
+use color_eyre::eyre::Result;
+use color_eyre::eyre::eyre;
+use crossterm::event::Event;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEventKind;
+use crossterm::event::KeyModifiers;
+use ratatui::Terminal;
+use ratatui::text::Text;
+use ratatui::widgets::Paragraph;
+use ratatui::widgets::Widget as _;
+
+use crate::test_backend::VT100Backend;
+use crate::ui_snapshot::UiSnapshotScreen;
+use crate::ui_snapshot::run_ui_snapshot;
+
+/// The key a step waits for, shown to the user as `label`.
+struct StepKey {
+    label: &'static str,
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+const ENTER: StepKey = StepKey {
+    label: "enter",
+    code: KeyCode::Enter,
+    modifiers: KeyModifiers::NONE,
+};
+
+const CTRL_T: StepKey = StepKey {
+    label: "ctrl + t",
+    code: KeyCode::Char('t'),
+    modifiers: KeyModifiers::CONTROL,
+};
+
+const ESC: StepKey = StepKey {
+    label: "esc",
+    code: KeyCode::Esc,
+    modifiers: KeyModifiers::NONE,
+};
+
+/// Run the interactive tutorial. Returns an error when stdin is not a
+/// terminal, since every step waits for a key press.
+pub fn run_tutorial(codex_home: Option<&Path>) -> Result<()> {
+    if !std::io::stdin().is_terminal() {
+        return Err(eyre!("codex tutorial needs an interactive terminal"));
+    }
+    let mut out = std::io::stdout();
+
+    section(
+        &mut out,
+        "Welcome to Codex",
+        &[
+            "This five-step tour walks through the keybindings you will use",
+            "every session. Everything below is simulated — no commands run",
+            "and nothing is written to disk. Press ctrl + c at any time to",
+            "leave the tutorial.",
+        ],
+        None,
+    )?;
+    wait_for(&mut out, &ENTER, "to begin")?;
+
+    section(
+        &mut out,
+        "1. Composing a prompt",
+        &[
+            "In a session, just type and press enter to send. Messages that",
+            "start with \"/\" are commands — try /help first. While Codex is",
+            "working you can keep typing; your message is queued and sent",
+            "when the turn ends.",
+        ],
+        None,
+    )?;
+    wait_for(&mut out, &ENTER, "as if sending a prompt")?;
+
+    let approval = run_ui_snapshot(
+        UiSnapshotScreen::Approval,
+        None,
+        codex_home,
+        /*ansi*/ false,
+    )?;
+    section(
+        &mut out,
+        "2. Approving a command",
+        &[
+            "When Codex wants to run something outside the sandbox, it asks",
+            "first. This is the real approval prompt:",
+        ],
+        Some(&approval),
+    )?;
+    wait_for(&mut out, &ENTER, "to approve the highlighted option")?;
+
+    let diff = render_sample_diff()?;
+    section(
+        &mut out,
+        "3. Viewing a diff",
+        &[
+            "Type /diff at any point to see every change Codex has made so",
+            "far, rendered like this:",
+        ],
+        Some(&diff),
+    )?;
+    wait_for(
+        &mut out,
+        &CTRL_T,
+        "— in a session it opens the full transcript",
+    )?;
+
+    section(
+        &mut out,
+        "4. Backtracking",
+        &[
+            "Pressed enter too soon, or want to steer an earlier turn? Press",
+            "esc twice to highlight a previous user message, edit it, and",
+            "resend — the conversation rewinds to that point.",
+        ],
+        None,
+    )?;
+    wait_for(&mut out, &ESC, "to practice backtracking")?;
+
+    section(
+        &mut out,
+        "5. Resuming",
+        &[
+            "Sessions persist. Run `codex resume` to pick up any previous",
+            "conversation exactly where it left off, or `codex resume --last`",
+            "for the most recent one.",
+            "",
+            "That's the whole loop. Run `codex` to start for real.",
+        ],
+        None,
+    )?;
+    wait_for(&mut out, &ENTER, "to finish")?;
+    writeln!(out)?;
+    Ok(())
+}
+
+/// Print a step heading, its body, and an optional pre-rendered screen.
+fn section(out: &mut impl Write, title: &str, body: &[&str], screen: Option<&str>) -> Result<()> {
+    writeln!(out)?;
+    writeln!(out, "## {title}")?;
+    writeln!(out)?;
+    for line in body {
+        writeln!(out, "{line}")?;
+    }
+    if let Some(screen) = screen {
+        writeln!(out)?;
+        write!(out, "{screen}")?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Block until the user presses `key` (teaching it hands-on). Ctrl+C exits.
+fn wait_for(out: &mut impl Write, key: &StepKey, hint: &str) -> Result<()> {
+    writeln!(out)?;
+    write!(out, "Press {} {hint}... ", key.label)?;
+    out.flush()?;
+    crossterm::terminal::enable_raw_mode()?;
+    let result = loop {
+        match crossterm::event::read() {
+            Ok(Event::Key(event)) if event.kind == KeyEventKind::Press => {
+                if event.code == KeyCode::Char('c')
+                    && event.modifiers.contains(KeyModifiers::CONTROL)
+                {
+                    break Err(eyre!("tutorial interrupted"));
+                }
+                if event.code == key.code && event.modifiers == key.modifiers {
+                    break Ok(());
+                }
+            }
+            Ok(_) => {}
+            Err(err) => break Err(err.into()),
+        }
+    };
+    crossterm::terminal::disable_raw_mode()?;
+    writeln!(out, "done.")?;
+    result
+}
+
+/// A small, stable diff rendered through the real diff renderer.
+fn render_sample_diff() -> Result<String> {
+    let original = "pub fn greet() {\n    println!(\"Hello!\");\n}\n";
+    let modified = "pub fn greet(name: &str) {\n    println!(\"Hello, {name}!\");\n}\n";
+    let mut changes = HashMap::new();
+    changes.insert(
+        PathBuf::from("src/greet.rs"),
+        FileChange::Update {
+            unified_diff: diffy::create_patch(original, modified).to_string(),
+            move_path: None,
+        },
+    );
+    let width: u16 = 72;
+    let lines = crate::diff_render::create_diff_summary(
+        &changes,
+        Path::new("/workspace/project"),
+        usize::from(width),
+    );
+    let height = u16::try_from(lines.len()).unwrap_or(u16::MAX).max(1);
+    let mut terminal = Terminal::new(VT100Backend::new(width, height))?;
+    terminal.draw(|frame| {
+        Paragraph::new(Text::from(lines)).render(frame.area(), frame.buffer_mut());
+    })?;
+    let mut text = terminal.backend().to_string();
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    Ok(text)
+}
@@ -0,0 +1,139 @@
+//! Implements `codex ui snapshot`: renders a named screen to a VT100 text
+//! snapshot so theme authors and keymap preset contributors have a reviewable
+//! golden artifact when they change visuals or defaults.
+//!
+//! Each screen is rendered at a fixed size with synthetic data, so the output
+//! is stable across machines and diffs cleanly; only the selected theme
+//! changes it. Plain text is emitted by default, matching the in-tree insta
+//! snapshots; `--ansi` keeps the escape sequences so colors survive.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::ReviewDecision;
+use color_eyre::eyre::Result;
+use color_eyre::eyre::eyre;
+use ratatui::Terminal;
+use tokio::sync::mpsc::unbounded_channel;
+
+use crate::app_event_sender::AppEventSender;
+use crate::bottom_pane::ApprovalOverlay;
+use crate::bottom_pane::ApprovalRequest;
+use crate::bottom_pane::BottomPaneView;
+use crate::cwd_prompt::CwdPromptAction;
+use crate::cwd_prompt::CwdPromptScreen;
+use crate::onboarding::trust_directory::TrustDirectorySelection;
+use crate::onboarding::trust_directory::TrustDirectoryWidget;
+use crate::render::highlight;
+use crate::test_backend::VT100Backend;
+use crate::tui::FrameRequester;
+use codex_features::Features;
+
+/// Screen rendered by `codex ui snapshot`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum UiSnapshotScreen {
+    /// Directory-trust onboarding prompt.
+    Trust,
+    /// Resume/fork working-directory prompt.
+    Cwd,
+    /// Command approval overlay.
+    Approval,
+}
+
+const SNAPSHOT_WIDTH: u16 = 80;
+const SNAPSHOT_HEIGHT: u16 = 14;
+
+/// Renders `screen` with `theme` (the configured theme when `None`) and
+/// returns the snapshot text.
+pub fn run_ui_snapshot(
+    screen: UiSnapshotScreen,
+    theme: Option<&str>,
+    codex_home: Option<&Path>,
+    ansi: bool,
+) -> Result<String> {
+    if let Some(name) = theme {
+        let theme = highlight::resolve_theme_by_name(name, codex_home).ok_or_else(|| {
+            let available = highlight::list_available_themes(codex_home)
+                .into_iter()
+                .map(|entry| entry.name)
+                .collect::<Vec<_>>()
+                .join(", ");
+            eyre!("unknown theme {name:?}; available themes: {available}")
+        })?;
+        highlight::set_syntax_theme(theme);
+    }
+    match screen {
+        UiSnapshotScreen::Trust => render_trust(ansi),
+        UiSnapshotScreen::Cwd => render_cwd(ansi),
+        UiSnapshotScreen::Approval => render_approval(ansi),
+    }
+}
+
+fn render_trust(ansi: bool) -> Result<String> {
+    use ratatui::widgets::WidgetRef as _;
+
+    let widget = TrustDirectoryWidget {
+        codex_home: PathBuf::from("/home/user/.codex"),
+        cwd: PathBuf::from("/workspace/project"),
+        trust_target: PathBuf::from("/workspace/project"),
+        show_windows_create_sandbox_hint: false,
+        should_quit: false,
+        selection: None,
+        highlighted: TrustDirectorySelection::Trust,
+        error: None,
+    };
+    let mut terminal = Terminal::new(VT100Backend::new(SNAPSHOT_WIDTH, SNAPSHOT_HEIGHT))?;
+    terminal.draw(|frame| (&widget).render_ref(frame.area(), frame.buffer_mut()))?;
+    Ok(snapshot_text(terminal.backend(), ansi))
+}
+
+fn render_cwd(ansi: bool) -> Result<String> {
+    let screen = CwdPromptScreen::new(
+        FrameRequester::test_dummy(),
+        CwdPromptAction::Resume,
+        "/workspace/current".to_string(),
+        "/workspace/session".to_string(),
+    );
+    let mut terminal = Terminal::new(VT100Backend::new(SNAPSHOT_WIDTH, SNAPSHOT_HEIGHT))?;
+    terminal.draw(|frame| frame.render_widget_ref(&screen, frame.area()))?;
+    Ok(snapshot_text(terminal.backend(), ansi))
+}
+
+fn render_approval(ansi: bool) -> Result<String> {
+    let (tx, _rx) = unbounded_channel();
+    let request = ApprovalRequest::Exec {
+        thread_id: ThreadId::new(),
+        thread_label: None,
+        id: "snapshot".to_string(),
+        command: vec![
+            "git".to_string(),
+            "push".to_string(),
+            "origin".to_string(),
+            "main".to_string(),
+        ],
+        reason: Some("push the reviewed changes".to_string()),
+        available_decisions: vec![ReviewDecision::Approved, ReviewDecision::Abort],
+        cwd: None,
+        network_approval_context: None,
+        additional_permissions: None,
+    };
+    let view = ApprovalOverlay::new(request, AppEventSender::new(tx), Features::with_defaults());
+    let height = view.desired_height(SNAPSHOT_WIDTH).max(1);
+    let mut terminal = Terminal::new(VT100Backend::new(SNAPSHOT_WIDTH, height))?;
+    terminal.draw(|frame| view.render(frame.area(), frame.buffer_mut()))?;
+    Ok(snapshot_text(terminal.backend(), ansi))
+}
+
+fn snapshot_text(backend: &VT100Backend, ansi: bool) -> String {
+    let mut text = if ansi {
+        String::from_utf8_lossy(&backend.vt100().screen().contents_formatted()).into_owned()
+    } else {
+        backend.to_string()
+    };
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
@@ -1,6 +1,5 @@
-#[cfg(any(not(debug_assertions), test))]
+use codex_config::types::UpdateChannel;
 use codex_install_context::InstallContext;
-#[cfg(any(not(debug_assertions), test))]
 use codex_install_context::StandalonePlatform;
 
 /// Update action the CLI should perform after the TUI exits.
@@ -19,7 +18,6 @@ pub enum UpdateAction {
 }
 
 impl UpdateAction {
-    #[cfg(any(not(debug_assertions), test))]
     pub(crate) fn from_install_context(context: &InstallContext) -> Option<Self> {
         match context {
             InstallContext::Npm => Some(UpdateAction::NpmGlobalLatest),
@@ -35,6 +33,28 @@ impl UpdateAction {
 
     /// Returns the list of command-line arguments for invoking the update.
     pub fn command_args(self) -> (&'static str, &'static [&'static str]) {
+        self.command_args_for_channel(UpdateChannel::Stable)
+    }
+
+    /// Returns the update command for the given release channel. Only the npm
+    /// and bun installs can target the `beta` dist-tag; the other install
+    /// methods always track the stable release.
+    pub fn command_args_for_channel(
+        self,
+        channel: UpdateChannel,
+    ) -> (&'static str, &'static [&'static str]) {
+        match (self, channel) {
+            (UpdateAction::NpmGlobalLatest, UpdateChannel::Beta) => {
+                ("npm", &["install", "-g", "@openai/codex@beta"])
+            }
+            (UpdateAction::BunGlobalLatest, UpdateChannel::Beta) => {
+                ("bun", &["install", "-g", "@openai/codex@beta"])
+            }
+            (action, _) => action.stable_command_args(),
+        }
+    }
+
+    fn stable_command_args(self) -> (&'static str, &'static [&'static str]) {
         match self {
             UpdateAction::NpmGlobalLatest => ("npm", &["install", "-g", "@openai/codex"]),
             UpdateAction::BunGlobalLatest => ("bun", &["install", "-g", "@openai/codex"]),
@@ -52,12 +72,24 @@ impl UpdateAction {
 
     /// Returns string representation of the command-line arguments for invoking the update.
     pub fn command_str(self) -> String {
-        let (command, args) = self.command_args();
+        self.command_str_for_channel(UpdateChannel::Stable)
+    }
+
+    /// Returns string representation of the update command for the given
+    /// release channel.
+    pub fn command_str_for_channel(self, channel: UpdateChannel) -> String {
+        let (command, args) = self.command_args_for_channel(channel);
         shlex::try_join(std::iter::once(command).chain(args.iter().copied()))
             .unwrap_or_else(|_| format!("{command} {}", args.join(" ")))
     }
 }
 
+/// Resolves the update action for the current install, if one is known. Used
+/// by `codex self-update` to pick the right package manager command.
+pub fn resolve_update_action() -> Option<UpdateAction> {
+    UpdateAction::from_install_context(InstallContext::current())
+}
+
 #[cfg(not(debug_assertions))]
 pub(crate) fn get_update_action() -> Option<UpdateAction> {
     UpdateAction::from_install_context(InstallContext::current())
@@ -107,6 +139,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn beta_channel_only_changes_package_manager_installs() {
+        assert_eq!(
+            UpdateAction::NpmGlobalLatest.command_args_for_channel(UpdateChannel::Beta),
+            ("npm", &["install", "-g", "@openai/codex@beta"][..])
+        );
+        assert_eq!(
+            UpdateAction::BunGlobalLatest.command_args_for_channel(UpdateChannel::Beta),
+            ("bun", &["install", "-g", "@openai/codex@beta"][..])
+        );
+        assert_eq!(
+            UpdateAction::BrewUpgrade.command_args_for_channel(UpdateChannel::Beta),
+            UpdateAction::BrewUpgrade.command_args()
+        );
+    }
+
     #[test]
     fn standalone_update_commands_rerun_latest_installer() {
         assert_eq!(
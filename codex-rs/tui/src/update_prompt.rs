@@ -52,20 +52,42 @@ pub(crate) async fn run_update_prompt_if_needed(
     let events = tui.event_stream();
     tokio::pin!(events);
 
-    while !screen.is_done() {
-        if let Some(event) = events.next().await {
-            match event {
-                TuiEvent::Key(key_event) => screen.handle_key(key_event),
-                TuiEvent::Paste(_) => {}
-                TuiEvent::Draw => {
-                    tui.draw(u16::MAX, |frame| {
-                        frame.render_widget_ref(&screen, frame.area());
-                    })?;
+    loop {
+        while !screen.is_done() {
+            if let Some(event) = events.next().await {
+                match event {
+                    TuiEvent::Key(key_event) => screen.handle_key(key_event),
+                    TuiEvent::Mouse(_) | TuiEvent::Paste(_) => {}
+                    TuiEvent::Draw => {
+                        tui.draw(u16::MAX, |frame| {
+                            frame.render_widget_ref(&screen, frame.area());
+                        })?;
+                    }
                 }
+            } else {
+                break;
             }
-        } else {
+        }
+        if screen.selection() != Some(UpdateSelection::ViewChangelog) {
             break;
         }
+        // Fetch and show the release notes, then fall back into the menu.
+        let notes = match updates::fetch_release_notes(&latest_version).await {
+            Ok(markdown) => {
+                crate::markdown_render::render_markdown_text_with_width(
+                    &markdown,
+                    Some(usize::from(tui.terminal.last_known_screen_size.width).saturating_sub(4)),
+                )
+                .lines
+            }
+            Err(err) => vec![Line::from(
+                format!("Could not load release notes: {err}").dim(),
+            )],
+        };
+        screen.show_notes(notes);
+        tui.draw(u16::MAX, |frame| {
+            frame.render_widget_ref(&screen, frame.area());
+        })?;
     }
 
     match screen.selection() {
@@ -73,7 +95,9 @@ pub(crate) async fn run_update_prompt_if_needed(
             tui.terminal.clear()?;
             Ok(UpdatePromptOutcome::RunUpdate(update_action))
         }
-        Some(UpdateSelection::NotNow) | None => Ok(UpdatePromptOutcome::Continue),
+        Some(UpdateSelection::NotNow) | Some(UpdateSelection::ViewChangelog) | None => {
+            Ok(UpdatePromptOutcome::Continue)
+        }
         Some(UpdateSelection::DontRemind) => {
             if let Err(err) = updates::dismiss_version(config, screen.latest_version()).await {
                 tracing::error!("Failed to persist update dismissal: {err}");
@@ -86,6 +110,7 @@ pub(crate) async fn run_update_prompt_if_needed(
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum UpdateSelection {
     UpdateNow,
+    ViewChangelog,
     NotNow,
     DontRemind,
 }
@@ -97,6 +122,9 @@ struct UpdatePromptScreen {
     update_action: UpdateAction,
     highlighted: UpdateSelection,
     selection: Option<UpdateSelection>,
+    /// Rendered release notes, shown instead of the menu until dismissed.
+    notes: Option<Vec<Line<'static>>>,
+    notes_scroll: u16,
 }
 
 impl UpdatePromptScreen {
@@ -112,9 +140,19 @@ impl UpdatePromptScreen {
             update_action,
             highlighted: UpdateSelection::UpdateNow,
             selection: None,
+            notes: None,
+            notes_scroll: 0,
         }
     }
 
+    /// Displays fetched release notes and re-arms the menu selection.
+    fn show_notes(&mut self, notes: Vec<Line<'static>>) {
+        self.notes = Some(notes);
+        self.notes_scroll = 0;
+        self.selection = None;
+        self.request_frame.schedule_frame();
+    }
+
     fn handle_key(&mut self, key_event: KeyEvent) {
         if key_event.kind == KeyEventKind::Release {
             return;
@@ -125,18 +163,42 @@ impl UpdatePromptScreen {
             self.select(UpdateSelection::NotNow);
             return;
         }
+        if self.notes.is_some() {
+            self.handle_notes_key(key_event);
+            return;
+        }
         match key_event.code {
             KeyCode::Up | KeyCode::Char('k') => self.set_highlight(self.highlighted.prev()),
             KeyCode::Down | KeyCode::Char('j') => self.set_highlight(self.highlighted.next()),
             KeyCode::Char('1') => self.select(UpdateSelection::UpdateNow),
-            KeyCode::Char('2') => self.select(UpdateSelection::NotNow),
-            KeyCode::Char('3') => self.select(UpdateSelection::DontRemind),
+            KeyCode::Char('2') => self.select(UpdateSelection::ViewChangelog),
+            KeyCode::Char('3') => self.select(UpdateSelection::NotNow),
+            KeyCode::Char('4') => self.select(UpdateSelection::DontRemind),
             KeyCode::Enter => self.select(self.highlighted),
             KeyCode::Esc => self.select(UpdateSelection::NotNow),
             _ => {}
         }
     }
 
+    fn handle_notes_key(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.notes_scroll = self.notes_scroll.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.notes_scroll = self.notes_scroll.saturating_add(1);
+            }
+            KeyCode::PageUp => self.notes_scroll = self.notes_scroll.saturating_sub(10),
+            KeyCode::PageDown => self.notes_scroll = self.notes_scroll.saturating_add(10),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => {
+                self.notes = None;
+                self.highlighted = UpdateSelection::UpdateNow;
+            }
+            _ => return,
+        }
+        self.request_frame.schedule_frame();
+    }
+
     fn set_highlight(&mut self, highlight: UpdateSelection) {
         if self.highlighted != highlight {
             self.highlighted = highlight;
@@ -166,7 +228,8 @@ impl UpdatePromptScreen {
 impl UpdateSelection {
     fn next(self) -> Self {
         match self {
-            UpdateSelection::UpdateNow => UpdateSelection::NotNow,
+            UpdateSelection::UpdateNow => UpdateSelection::ViewChangelog,
+            UpdateSelection::ViewChangelog => UpdateSelection::NotNow,
             UpdateSelection::NotNow => UpdateSelection::DontRemind,
             UpdateSelection::DontRemind => UpdateSelection::UpdateNow,
         }
@@ -175,7 +238,8 @@ impl UpdateSelection {
     fn prev(self) -> Self {
         match self {
             UpdateSelection::UpdateNow => UpdateSelection::DontRemind,
-            UpdateSelection::NotNow => UpdateSelection::UpdateNow,
+            UpdateSelection::ViewChangelog => UpdateSelection::UpdateNow,
+            UpdateSelection::NotNow => UpdateSelection::ViewChangelog,
             UpdateSelection::DontRemind => UpdateSelection::NotNow,
         }
     }
@@ -184,6 +248,30 @@ impl UpdateSelection {
 impl WidgetRef for &UpdatePromptScreen {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
         Clear.render(area, buf);
+        if let Some(notes) = &self.notes {
+            let mut column = ColumnRenderable::new();
+            column.push(Line::from(vec![
+                padded_emoji("  ✨").bold().cyan(),
+                format!("Release notes for {}", self.latest_version).bold(),
+            ]));
+            column.push("");
+            let max_scroll = (notes.len() as u16).saturating_sub(area.height.saturating_sub(4));
+            let scroll = self.notes_scroll.min(max_scroll);
+            for line in notes.iter().skip(usize::from(scroll)) {
+                column.push(line.clone().inset(Insets::tlbr(0, 2, 0, 0)));
+            }
+            column.push("");
+            column.push(
+                Line::from(vec![
+                    "Press ".dim(),
+                    key_hint::plain(KeyCode::Esc).into(),
+                    " to go back".dim(),
+                ])
+                .inset(Insets::tlbr(0, 2, 0, 0)),
+            );
+            column.render(area, buf);
+            return;
+        }
         let mut column = ColumnRenderable::new();
 
         let update_command = self.update_action.command_str();
@@ -218,11 +306,16 @@ impl WidgetRef for &UpdatePromptScreen {
         ));
         column.push(selection_option_row(
             1,
+            "View changelog".to_string(),
+            self.highlighted == UpdateSelection::ViewChangelog,
+        ));
+        column.push(selection_option_row(
+            2,
             "Skip".to_string(),
             self.highlighted == UpdateSelection::NotNow,
         ));
         column.push(selection_option_row(
-            2,
+            3,
             "Skip until next version".to_string(),
             self.highlighted == UpdateSelection::DontRemind,
         ));
@@ -279,16 +372,36 @@ mod tests {
     fn update_prompt_dismiss_option_leaves_prompt_in_normal_state() {
         let mut screen = new_prompt();
         screen.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        screen.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
         screen.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
         assert!(screen.is_done());
         assert_eq!(screen.selection(), Some(UpdateSelection::NotNow));
     }
 
+    #[test]
+    fn update_prompt_changelog_option_selects_view_changelog() {
+        let mut screen = new_prompt();
+        screen.handle_key(KeyEvent::new(KeyCode::Char('2'), KeyModifiers::NONE));
+        assert!(screen.is_done());
+        assert_eq!(screen.selection(), Some(UpdateSelection::ViewChangelog));
+    }
+
+    #[test]
+    fn update_prompt_notes_esc_returns_to_menu() {
+        let mut screen = new_prompt();
+        screen.show_notes(vec![Line::from("notes")]);
+        assert!(!screen.is_done());
+        screen.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(screen.notes.is_none());
+        assert!(!screen.is_done());
+    }
+
     #[test]
     fn update_prompt_dont_remind_selects_dismissal() {
         let mut screen = new_prompt();
         screen.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
         screen.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        screen.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
         screen.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
         assert!(screen.is_done());
         assert_eq!(screen.selection(), Some(UpdateSelection::DontRemind));
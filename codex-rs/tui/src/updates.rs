@@ -13,6 +13,7 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use crate::version::CODEX_CLI_VERSION;
+use codex_config::types::UpdateChannel;
 
 pub fn get_upgrade_version(config: &Config) -> Option<String> {
     if !config.check_for_update_on_startup || is_source_build_version(CODEX_CLI_VERSION) {
@@ -29,8 +30,9 @@ pub fn get_upgrade_version(config: &Config) -> Option<String> {
         // Refresh the cached latest version in the background so TUI startup
         // isn’t blocked by a network call. The UI reads the previously cached
         // value (if any) for this run; the next run shows the banner if needed.
+        let channel = config.update_channel;
         tokio::spawn(async move {
-            check_for_update(&version_file)
+            check_for_update(&version_file, channel)
                 .await
                 .inspect_err(|e| tracing::error!("Failed to update version: {e}"))
         });
@@ -58,10 +60,15 @@ const VERSION_FILENAME: &str = "version.json";
 // We use the latest version from the cask if installation is via homebrew - homebrew does not immediately pick up the latest release and can lag behind.
 const HOMEBREW_CASK_API_URL: &str = "https://formulae.brew.sh/api/cask/codex.json";
 const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/openai/codex/releases/latest";
+// Listing endpoint used for the beta channel; unlike `releases/latest` it
+// includes pre-releases, ordered newest first.
+const RELEASE_LIST_URL: &str = "https://api.github.com/repos/openai/codex/releases?per_page=1";
 
 #[derive(Deserialize, Debug, Clone)]
 struct ReleaseInfo {
     tag_name: String,
+    #[serde(default)]
+    body: Option<String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -78,7 +85,7 @@ fn read_version_info(version_file: &Path) -> anyhow::Result<VersionInfo> {
     Ok(serde_json::from_str(&contents)?)
 }
 
-async fn check_for_update(version_file: &Path) -> anyhow::Result<()> {
+async fn check_for_update(version_file: &Path, channel: UpdateChannel) -> anyhow::Result<()> {
     let latest_version = match update_action::get_update_action() {
         Some(UpdateAction::BrewUpgrade) => {
             let HomebrewCaskInfo { version } = create_client()
@@ -91,15 +98,28 @@ async fn check_for_update(version_file: &Path) -> anyhow::Result<()> {
             version
         }
         _ => {
-            let ReleaseInfo {
-                tag_name: latest_tag_name,
-            } = create_client()
-                .get(LATEST_RELEASE_URL)
-                .send()
-                .await?
-                .error_for_status()?
-                .json::<ReleaseInfo>()
-                .await?;
+            let latest_tag_name = match channel {
+                UpdateChannel::Stable => {
+                    create_client()
+                        .get(LATEST_RELEASE_URL)
+                        .send()
+                        .await?
+                        .error_for_status()?
+                        .json::<ReleaseInfo>()
+                        .await?
+                        .tag_name
+                }
+                UpdateChannel::Beta => create_client()
+                    .get(RELEASE_LIST_URL)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json::<Vec<ReleaseInfo>>()
+                    .await?
+                    .first()
+                    .map(|release| release.tag_name.clone())
+                    .ok_or_else(|| anyhow::anyhow!("release list is empty"))?,
+            };
             extract_version_from_latest_tag(&latest_tag_name)?
         }
     };
@@ -120,6 +140,22 @@ async fn check_for_update(version_file: &Path) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Fetches the release notes markdown for `version` from GitHub.
+pub async fn fetch_release_notes(version: &str) -> anyhow::Result<String> {
+    let url = format!("https://api.github.com/repos/openai/codex/releases/tags/rust-v{version}");
+    let release = create_client()
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<ReleaseInfo>()
+        .await?;
+    release
+        .body
+        .filter(|body| !body.trim().is_empty())
+        .ok_or_else(|| anyhow::anyhow!("release has no notes"))
+}
+
 fn is_newer(latest: &str, current: &str) -> Option<bool> {
     match (parse_version(latest), parse_version(current)) {
         (Some(l), Some(c)) => Some(l > c),
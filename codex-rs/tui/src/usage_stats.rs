@@ -0,0 +1,264 @@
+//! Purely local usage statistics for the `/stats` screen.
+//!
+//! Aggregates session metadata from the rollout files under
+//! `$CODEX_HOME/sessions` — sessions per week, token totals, top projects,
+//! and average turn latency — without any network egress. The scan is cheap
+//! enough to run on demand: only lines that plausibly carry the data we need
+//! are JSON-parsed.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::NaiveDate;
+use chrono::Utc;
+
+/// Aggregated, purely local usage statistics.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize)]
+pub(crate) struct UsageStats {
+    /// Total number of sessions found.
+    pub sessions: usize,
+    /// Sessions per ISO week, most recent first, as `("2026-W35", count)`.
+    pub sessions_per_week: Vec<(String, usize)>,
+    /// Sum of the final cumulative token totals across sessions that
+    /// recorded one.
+    pub total_tokens: u64,
+    /// Projects (session cwd) by session count, most used first.
+    pub top_projects: Vec<(String, usize)>,
+    /// Mean time from task start to task completion, in seconds.
+    pub avg_turn_latency_secs: Option<f64>,
+}
+
+/// Scans the sessions tree and aggregates usage statistics.
+pub(crate) fn collect(codex_home: &Path) -> io::Result<UsageStats> {
+    let mut stats = UsageStats::default();
+    let mut weeks: HashMap<String, usize> = HashMap::new();
+    let mut projects: HashMap<String, usize> = HashMap::new();
+    let mut latency_total = Duration::ZERO;
+    let mut latency_samples = 0u32;
+
+    for path in rollout_files(&codex_home.join(codex_rollout::SESSIONS_SUBDIR))? {
+        stats.sessions += 1;
+        if let Some(week) = iso_week_from_path(&path) {
+            *weeks.entry(week).or_default() += 1;
+        }
+
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let mut last_tokens = None;
+        let mut last_task_start: Option<DateTime<Utc>> = None;
+        for (index, line) in contents.lines().enumerate() {
+            if index == 0
+                && let Some(cwd) = parse_json_str_field(line, "cwd")
+            {
+                *projects.entry(cwd).or_default() += 1;
+            }
+            if line.contains("\"token_count\"")
+                && let Some(total) = parse_total_tokens(line)
+            {
+                last_tokens = Some(total);
+            }
+            if line.contains("\"task_started\"") {
+                last_task_start = parse_timestamp(line);
+            } else if line.contains("\"task_complete\"")
+                && let Some(start) = last_task_start.take()
+                && let Some(end) = parse_timestamp(line)
+                && let Ok(latency) = (end - start).to_std()
+            {
+                latency_total += latency;
+                latency_samples += 1;
+            }
+        }
+        stats.total_tokens += last_tokens.unwrap_or(0);
+    }
+
+    stats.sessions_per_week = {
+        let mut weeks: Vec<(String, usize)> = weeks.into_iter().collect();
+        weeks.sort_by(|a, b| b.0.cmp(&a.0));
+        weeks.truncate(8);
+        weeks
+    };
+    stats.top_projects = {
+        let mut projects: Vec<(String, usize)> = projects.into_iter().collect();
+        projects.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        projects.truncate(5);
+        projects
+    };
+    if latency_samples > 0 {
+        stats.avg_turn_latency_secs =
+            Some(latency_total.as_secs_f64() / f64::from(latency_samples));
+    }
+    Ok(stats)
+}
+
+/// Renders the stats as markdown for the pager overlay.
+pub(crate) fn render_markdown(stats: &UsageStats) -> String {
+    let mut out = String::from(
+        "# Usage stats\n\nAll data is computed locally from session files; nothing leaves this machine.\n\n",
+    );
+    out.push_str(&format!("- Sessions: **{}**\n", stats.sessions));
+    out.push_str(&format!("- Tokens used: **{}**\n", stats.total_tokens));
+    if let Some(latency) = stats.avg_turn_latency_secs {
+        out.push_str(&format!("- Average turn latency: **{latency:.1}s**\n"));
+    }
+    if !stats.sessions_per_week.is_empty() {
+        out.push_str("\n## Sessions per week\n\n");
+        for (week, count) in &stats.sessions_per_week {
+            out.push_str(&format!("- {week}: {count}\n"));
+        }
+    }
+    if !stats.top_projects.is_empty() {
+        out.push_str("\n## Top projects\n\n");
+        for (project, count) in &stats.top_projects {
+            out.push_str(&format!("- `{project}`: {count} session(s)\n"));
+        }
+    }
+    out.push_str("\nExport as JSON with `/stats export`.\n");
+    out
+}
+
+/// Writes the stats as pretty-printed JSON next to the config and returns the
+/// destination path.
+pub(crate) fn export_json(codex_home: &Path, stats: &UsageStats) -> io::Result<PathBuf> {
+    let path = codex_home.join("usage-stats.json");
+    let json = serde_json::to_string_pretty(stats)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    fs::write(&path, json)?;
+    Ok(path)
+}
+
+fn rollout_files(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                stack.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "jsonl") {
+                files.push(path);
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Derives the ISO week label (`2026-W35`) from the `YYYY/MM/DD` directory
+/// layout a rollout file lives in.
+fn iso_week_from_path(path: &Path) -> Option<String> {
+    let mut components = path
+        .parent()?
+        .components()
+        .rev()
+        .filter_map(|c| c.as_os_str().to_str());
+    let day: u32 = components.next()?.parse().ok()?;
+    let month: u32 = components.next()?.parse().ok()?;
+    let year: i32 = components.next()?.parse().ok()?;
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    let week = date.iso_week();
+    Some(format!("{}-W{:02}", week.year(), week.week()))
+}
+
+fn parse_json_str_field(line: &str, field: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    find_str(&value, field).map(str::to_string)
+}
+
+fn parse_timestamp(line: &str) -> Option<DateTime<Utc>> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    let timestamp = value.get("timestamp")?.as_str()?;
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Pulls the cumulative token total from a `token_count` rollout line without
+/// depending on the exact event payload nesting.
+fn parse_total_tokens(line: &str) -> Option<u64> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    find_value(&value, "total_token_usage")?
+        .get("total_tokens")?
+        .as_u64()
+}
+
+fn find_value<'a>(value: &'a serde_json::Value, key: &str) -> Option<&'a serde_json::Value> {
+    match value {
+        serde_json::Value::Object(map) => map
+            .get(key)
+            .or_else(|| map.values().find_map(|v| find_value(v, key))),
+        serde_json::Value::Array(items) => items.iter().find_map(|v| find_value(v, key)),
+        _ => None,
+    }
+}
+
+fn find_str<'a>(value: &'a serde_json::Value, key: &str) -> Option<&'a str> {
+    find_value(value, key)?.as_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn write_session(root: &Path, relative: &str, lines: &[&str]) {
+        let path = root.join(codex_rollout::SESSIONS_SUBDIR).join(relative);
+        fs::create_dir_all(path.parent().expect("parent")).expect("dirs");
+        fs::write(path, lines.join("\n")).expect("write");
+    }
+
+    #[test]
+    fn aggregates_sessions_tokens_and_projects() {
+        let home = tempfile::tempdir().expect("home");
+        write_session(
+            home.path(),
+            "2026/08/24/rollout-a.jsonl",
+            &[
+                r#"{"timestamp":"2026-08-24T10:00:00Z","type":"session_meta","payload":{"cwd":"/repo/a"}}"#,
+                r#"{"timestamp":"2026-08-24T10:00:01Z","type":"event_msg","payload":{"type":"task_started"}}"#,
+                r#"{"timestamp":"2026-08-24T10:00:11Z","type":"event_msg","payload":{"type":"task_complete"}}"#,
+                r#"{"timestamp":"2026-08-24T10:00:11Z","type":"event_msg","payload":{"type":"token_count","payload":{"info":{"total_token_usage":{"total_tokens":1200}}}}}"#,
+            ],
+        );
+        write_session(
+            home.path(),
+            "2026/08/25/rollout-b.jsonl",
+            &[
+                r#"{"timestamp":"2026-08-25T09:00:00Z","type":"session_meta","payload":{"cwd":"/repo/a"}}"#,
+            ],
+        );
+
+        let stats = collect(home.path()).expect("collect");
+
+        assert_eq!(stats.sessions, 2);
+        assert_eq!(stats.total_tokens, 1200);
+        assert_eq!(stats.top_projects, vec![("/repo/a".to_string(), 2)]);
+        assert_eq!(stats.sessions_per_week, vec![("2026-W35".to_string(), 2)]);
+        assert_eq!(stats.avg_turn_latency_secs, Some(10.0));
+    }
+
+    #[test]
+    fn export_writes_json_into_codex_home() {
+        let home = tempfile::tempdir().expect("home");
+        let stats = UsageStats {
+            sessions: 3,
+            ..Default::default()
+        };
+
+        let path = export_json(home.path(), &stats).expect("export");
+
+        let contents = fs::read_to_string(path).expect("read");
+        assert!(contents.contains("\"sessions\": 3"), "json: {contents}");
+    }
+}
@@ -0,0 +1,72 @@
+//! Windows console quirks shared across the TUI.
+//!
+//! Windows Terminal and legacy conhost differ in what they actually deliver
+//! for enhanced keyboard input even when the crossterm probe succeeds, and
+//! file references pasted or inserted on Windows arrive with backslash
+//! separators that the rest of the UI (mentions, file viewer jumps) expects
+//! in forward-slash form.
+
+/// Returns `true` when running inside Windows Terminal (as opposed to legacy
+/// conhost), including WSL sessions hosted by Windows Terminal.
+pub(crate) fn is_windows_terminal() -> bool {
+    std::env::var_os("WT_SESSION").is_some() || std::env::var_os("WT_PROFILE_ID").is_some()
+}
+
+/// Post-processes the crossterm keyboard-enhancement probe for Windows
+/// consoles.
+///
+/// Legacy conhost acknowledges the kitty keyboard protocol query but then
+/// drops modifier-disambiguated key events, so treating the probe result as
+/// authoritative breaks Shift+Enter and similar bindings. Windows Terminal
+/// implements the protocol properly. On non-Windows platforms the probe
+/// result is passed through unchanged.
+pub(crate) fn enhanced_keys_supported(probe_result: bool) -> bool {
+    if cfg!(windows) {
+        probe_result && is_windows_terminal()
+    } else {
+        probe_result
+    }
+}
+
+/// Normalizes path separators in a file reference for display and mention
+/// insertion. On Windows this converts backslashes to forward slashes so
+/// references round-trip through the mention codec and prompt parsing; on
+/// other platforms backslashes are legitimate filename characters and are
+/// left alone.
+pub(crate) fn normalize_file_reference(path: &str) -> String {
+    normalize_file_reference_for(path, cfg!(windows))
+}
+
+fn normalize_file_reference_for(path: &str, windows: bool) -> String {
+    if windows {
+        path.replace('\\', "/")
+    } else {
+        path.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn windows_references_use_forward_slashes() {
+        assert_eq!(
+            normalize_file_reference_for(r"src\main.rs", /*windows*/ true),
+            "src/main.rs"
+        );
+        assert_eq!(
+            normalize_file_reference_for(r"C:\repo\src\main.rs", /*windows*/ true),
+            "C:/repo/src/main.rs"
+        );
+    }
+
+    #[test]
+    fn non_windows_references_are_untouched() {
+        assert_eq!(
+            normalize_file_reference_for(r"weird\name.rs", /*windows*/ false),
+            r"weird\name.rs"
+        );
+    }
+}
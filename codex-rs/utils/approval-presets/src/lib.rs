@@ -5,11 +5,11 @@ use codex_protocol::protocol::SandboxPolicy;
 #[derive(Debug, Clone)]
 pub struct ApprovalPreset {
     /// Stable identifier for the preset.
-    pub id: &'static str,
+    pub id: String,
     /// Display label shown in UIs.
-    pub label: &'static str,
+    pub label: String,
     /// Short human description shown next to the label in UIs.
-    pub description: &'static str,
+    pub description: String,
     /// Approval policy to apply.
     pub approval: AskForApproval,
     /// Sandbox policy to apply.
@@ -22,23 +22,23 @@ pub struct ApprovalPreset {
 pub fn builtin_approval_presets() -> Vec<ApprovalPreset> {
     vec![
         ApprovalPreset {
-            id: "read-only",
-            label: "Read Only",
-            description: "Codex can read files in the current workspace. Approval is required to edit files or access the internet.",
+            id: "read-only".to_string(),
+            label: "Read Only".to_string(),
+            description: "Codex can read files in the current workspace. Approval is required to edit files or access the internet.".to_string(),
             approval: AskForApproval::OnRequest,
             sandbox: SandboxPolicy::new_read_only_policy(),
         },
         ApprovalPreset {
-            id: "auto",
-            label: "Default",
-            description: "Codex can read and edit files in the current workspace, and run commands. Approval is required to access the internet or edit other files. (Identical to Agent mode)",
+            id: "auto".to_string(),
+            label: "Default".to_string(),
+            description: "Codex can read and edit files in the current workspace, and run commands. Approval is required to access the internet or edit other files. (Identical to Agent mode)".to_string(),
             approval: AskForApproval::OnRequest,
             sandbox: SandboxPolicy::new_workspace_write_policy(),
         },
         ApprovalPreset {
-            id: "full-access",
-            label: "Full Access",
-            description: "Codex can edit files outside this workspace and access the internet without asking for approval. Exercise caution when using.",
+            id: "full-access".to_string(),
+            label: "Full Access".to_string(),
+            description: "Codex can edit files outside this workspace and access the internet without asking for approval. Exercise caution when using.".to_string(),
             approval: AskForApproval::Never,
             sandbox: SandboxPolicy::DangerFullAccess,
         },